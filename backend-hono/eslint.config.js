@@ -20,4 +20,34 @@ export default [
             '@typescript-eslint/no-unused-vars': ['warn', { argsIgnorePattern: '^_' }],
         },
     },
+    {
+        // The domain is published on its own (src/domain/index.ts), so it must not reach
+        // into the backend or pull in server-only packages
+        files: ['src/domain/**/*.ts'],
+        ignores: [
+            'src/domain/entity/ApiKey.ts',
+            'src/domain/entity/RefreshToken.ts',
+            'src/domain/entity/User.ts',
+            'src/domain/entity/Webhook.ts',
+            'src/domain/valueobject/Email.ts',
+            'src/domain/valueobject/HashedPassword.ts',
+            'src/domain/valueobject/JWT.ts',
+            'src/domain/valueobject/UserRole.ts',
+            'src/domain/valueobject/Username.ts',
+        ],
+        rules: {
+            'no-restricted-imports': [
+                'error',
+                {
+                    patterns: [
+                        {
+                            group: ['../../*', 'node:*'],
+                            message: 'The domain cannot depend on the backend or Node.js APIs.',
+                        },
+                    ],
+                    paths: ['bcryptjs', 'crypto', 'drizzle-orm', 'hono', 'jsonwebtoken', 'postgres', 'uuid'],
+                },
+            ],
+        },
+    },
 ];
@@ -8,9 +8,38 @@ const envSchema = z.object({
   HOST: z.string().default('127.0.0.1'),
   PORT: z.string().default('8080'),
   DATABASE_URL: z.string(),
+  // Comma-separated Postgres read replicas for GET requests
+  DATABASE_REPLICA_URLS: z.string().optional(),
+  // Node.js server only; Workers always use a single connection
+  DB_POOL_MAX: z.coerce.number().int().positive().default(10),
+  DB_IDLE_TIMEOUT: z.coerce.number().int().nonnegative().default(30), // seconds
+  // Seconds to let in-flight requests finish after SIGTERM before forcing exit
+  SHUTDOWN_TIMEOUT: z.coerce.number().int().positive().default(10),
+  // Reverse proxies in front of the Node.js server; their X-Forwarded-For gives the client IP
+  TRUST_PROXY: z.coerce.number().int().nonnegative().default(0),
   JWT_SECRET: z.string().min(32),
   GEMINI_API_KEY: z.string().optional(),
+  // Full-text search (/api/search); disabled when unset
+  MEILISEARCH_URL: z.string().url().optional(),
+  MEILISEARCH_API_KEY: z.string().optional(),
+  // Mail via Resend; without it, development prints mails to the console
+  RESEND_API_KEY: z.string().optional(),
+  MAIL_FROM: z.string().optional(),
+  // The frontend, for links in mails
+  APP_URL: z.string().url().optional(),
   ALLOWED_ORIGIN: z.string().default('http://localhost:5173'),
+  // "session" for server-side sessions in signed cookies instead of JWTs
+  AUTH_MODE: z.enum(['jwt', 'session']).default('jwt'),
+  // "on" to require CSRF tokens on cookie-based routes
+  CSRF_PROTECTION: z.enum(['on', 'off']).default('off'),
+  // PokeAPI entries an admin sync imports at once
+  SYNC_CONCURRENCY: z.coerce.number().int().positive().default(4),
+  // "on" to serve cries and sprites through this API instead of linking to upstream hosts
+  MEDIA_PROXY: z.enum(['on', 'off']).default('off'),
+  // "off" to leave response compression to a reverse proxy
+  COMPRESSION: z.enum(['on', 'off']).default('on'),
+  // Smallest response body in bytes worth compressing
+  COMPRESSION_MIN_SIZE: z.coerce.number().int().nonnegative().default(1024),
 });
 
 function validateEnv() {
@@ -1,11 +1,28 @@
+import { withReplicas } from 'drizzle-orm/pg-core';
 import { drizzle } from 'drizzle-orm/postgres-js';
 import postgres from 'postgres';
 import * as schema from './schema';
 
+export interface PoolOptions {
+  max: number; // connections
+  idleTimeout: number; // seconds before an idle connection is closed; 0 keeps it open
+  connectTimeout: number; // seconds
+}
+
+// Cloudflare Workers settings: a Worker handles one request at a time, so one connection
+const DEFAULT_POOL_OPTIONS: PoolOptions = { max: 1, idleTimeout: 0, connectTimeout: 30 };
+
+interface CachedDb {
+  db: ReturnType<typeof drizzle>;
+  client: postgres.Sql;
+}
+
 // Cache for database connections (per-worker lifecycle)
-const dbCache = new Map<string, ReturnType<typeof drizzle>>();
+const dbCache = new Map<string, CachedDb>();
 
-export function getDb(connectionString?: string) {
+// Options only apply when the pool for this connection string is first created, so the Node.js
+// server creates its pool at startup and later calls share it
+export function getDb(connectionString?: string, options: Partial<PoolOptions> = {}) {
   // For Node.js environment (development)
   if (!connectionString && typeof process !== 'undefined') {
     connectionString = process.env.DATABASE_URL;
@@ -16,21 +33,60 @@ export function getDb(connectionString?: string) {
   }
 
   // Return cached connection if exists
-  if (dbCache.has(connectionString)) {
-    return dbCache.get(connectionString)!;
+  const cached = dbCache.get(connectionString);
+  if (cached) {
+    return cached.db;
   }
 
   // Create new connection
+  const pool = { ...DEFAULT_POOL_OPTIONS, ...options };
   const client = postgres(connectionString, {
     // Cloudflare Workers compatible settings
     prepare: false,
-    max: 1, // Single connection for Workers
+    max: pool.max,
+    idle_timeout: pool.idleTimeout,
+    connect_timeout: pool.connectTimeout,
   });
 
   const db = drizzle(client, { schema });
-  dbCache.set(connectionString, db);
+  dbCache.set(connectionString, { db, client });
 
   return db;
 }
 
+// Where a request's reads go when replicas are configured. Replicas lag behind the primary, so
+// reads that must see a write just made ask for the primary.
+export type ReadConsistency = 'replica' | 'primary';
+
+// DATABASE_REPLICA_URLS is a comma-separated list of connection strings
+export function parseReplicaUrls(value?: string): string[] {
+  return (value ?? '')
+    .split(',')
+    .map((url) => url.trim())
+    .filter((url) => url !== '');
+}
+
+// Selects go to a random replica; writes, transactions and raw SQL go to the primary. Without
+// replicas, or with `primary` consistency, the primary handles everything.
+export function getReplicatedDb(
+  primaryUrl: string | undefined,
+  replicaUrls: string[],
+  consistency: ReadConsistency = 'replica',
+  options: Partial<PoolOptions> = {}
+): DbType {
+  const primary = getDb(primaryUrl, options);
+  const [first, ...rest] = replicaUrls.map((url) => getDb(url, options));
+  if (!first || consistency === 'primary') {
+    return primary;
+  }
+  return withReplicas(primary, [first, ...rest]);
+}
+
+// Waits up to `timeout` seconds for running queries, then closes every pool
+export async function closeDb(timeout: number): Promise<void> {
+  const pools = [...dbCache.values()];
+  dbCache.clear();
+  await Promise.all(pools.map(({ client }) => client.end({ timeout })));
+}
+
 export type DbType = ReturnType<typeof getDb>;
@@ -1,23 +1,86 @@
-import { pgTable, uuid, varchar, timestamp, boolean, integer, text } from 'drizzle-orm/pg-core';
+import {
+  pgTable,
+  serial,
+  uuid,
+  varchar,
+  timestamp,
+  boolean,
+  integer,
+  bigint,
+  real,
+  text,
+  primaryKey,
+  index,
+  uniqueIndex,
+} from 'drizzle-orm/pg-core';
 
 export const users = pgTable('users', {
   userId: uuid('user_id').primaryKey(),
   username: varchar('username', { length: 20 }).notNull().unique(),
   email: varchar('email', { length: 254 }).notNull().unique(),
   passwordHash: varchar('password_hash', { length: 60 }).notNull(),
+  role: varchar('role', { length: 20 }).notNull().default('user'), // user, admin
   createdAt: timestamp('created_at').notNull().defaultNow(),
   updatedAt: timestamp('updated_at').notNull().defaultNow(),
+  // Set when the account is deleted; the row stays, scrubbed, as the author of what was kept
+  deletedAt: timestamp('deleted_at'),
 });
 
-export const refreshTokens = pgTable('refresh_tokens', {
-  tokenId: uuid('token_id').primaryKey(),
+// Absent until the user first saves a preference
+export const userPreferences = pgTable('user_preferences', {
   userId: uuid('user_id')
-    .notNull()
+    .primaryKey()
     .references(() => users.userId),
-  tokenHash: varchar('token_hash', { length: 255 }).notNull().unique(),
+  language: varchar('language', { length: 10 }),
+  format: varchar('format', { length: 50 }),
+  level: integer('level').notNull().default(50),
+  theme: varchar('theme', { length: 10 }).notNull().default('system'),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+});
+
+// One row per issued token; the tokens a session rotates through share its session_id, and the
+// newest one's created_at is when the session was last refreshed
+export const refreshTokens = pgTable(
+  'refresh_tokens',
+  {
+    tokenId: uuid('token_id').primaryKey(),
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    // Tokens issued before sessions were tracked each count as their own session
+    sessionId: uuid('session_id').notNull().defaultRandom(),
+    tokenHash: varchar('token_hash', { length: 255 }).notNull().unique(),
+    userAgent: text('user_agent'),
+    ip: varchar('ip', { length: 45 }),
+    expiresAt: timestamp('expires_at').notNull(),
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+    // Updated at most every few minutes; tokens issued before this was tracked start from the
+    // migration
+    lastUsedAt: timestamp('last_used_at').notNull().defaultNow(),
+    revoked: boolean('revoked').notNull().default(false),
+  },
+  (table) => ({
+    userSessionIdx: index('refresh_tokens_user_session_idx').on(table.userId, table.sessionId),
+  })
+);
+
+// Consecutive failed logins per identifier ("email:..." or "ip:..."), for login backoff
+export const loginFailures = pgTable('login_failures', {
+  identifier: varchar('identifier', { length: 300 }).primaryKey(),
+  failures: integer('failures').notNull(),
+  lastFailedAt: timestamp('last_failed_at').notNull(),
+});
+
+// At most one pending email change per user, confirmed from the new address
+export const emailChanges = pgTable('email_changes', {
+  userId: uuid('user_id')
+    .primaryKey()
+    .references(() => users.userId),
+  newEmail: varchar('new_email', { length: 254 }).notNull(),
+  confirmTokenHash: varchar('confirm_token_hash', { length: 64 }).notNull().unique(),
+  cancelTokenHash: varchar('cancel_token_hash', { length: 64 }).notNull().unique(),
   expiresAt: timestamp('expires_at').notNull(),
-  createdAt: timestamp('created_at').notNull().defaultNow(),
-  revoked: boolean('revoked').notNull().default(false),
+  createdAt: timestamp('created_at').notNull(),
 });
 
 export const userPokemon = pgTable('user_pokemon', {
@@ -65,23 +128,849 @@ export const teams = pgTable('teams', {
   teamName: varchar('team_name', { length: 50 }).notNull(),
   createdAt: timestamp('created_at').notNull().defaultNow(),
   updatedAt: timestamp('updated_at').notNull().defaultNow(),
+  // Set while the team is in the trash; purged after the retention window
+  deletedAt: timestamp('deleted_at'),
+  // Incremented on every save, for optimistic locking
+  version: integer('version').notNull().default(1),
+  folder: varchar('folder', { length: 50 }),
+  // Set while the owner lets anyone see the team
+  sharedAt: timestamp('shared_at'),
+  sharedFormat: varchar('shared_format', { length: 50 }),
+  // Markdown, sanitized on the way in
+  notes: text('notes'),
 });
 
-export const teamPokemon = pgTable('team_pokemon', {
-  teamId: uuid('team_id')
+export const teamPokemon = pgTable(
+  'team_pokemon',
+  {
+    teamId: uuid('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    slot: integer('slot').notNull(), // 0-5
+    formId: integer('form_id').notNull(),
+    terastalType: varchar('terastal_type', { length: 20 }).notNull(),
+    notes: text('notes'),
+  },
+  (table) => ({
+    // "Teams containing X" looks members up by form
+    formIdx: index('team_pokemon_form_idx').on(table.formId, table.teamId),
+  })
+);
+
+// User-defined tags, normalized to lower case
+export const teamTags = pgTable(
+  'team_tags',
+  {
+    teamId: uuid('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    tag: varchar('tag', { length: 20 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.teamId, table.tag] }),
+    tagIdx: index('team_tags_tag_idx').on(table.tag),
+  })
+);
+
+// One row per user who likes a shared team
+export const teamLikes = pgTable(
+  'team_likes',
+  {
+    teamId: uuid('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.teamId, table.userId] }),
+  })
+);
+
+// Comments on shared teams; parent_id points at the first comment of the thread for replies
+export const teamComments = pgTable(
+  'team_comments',
+  {
+    commentId: uuid('comment_id').primaryKey(),
+    teamId: uuid('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    authorId: uuid('author_id')
+      .notNull()
+      .references(() => users.userId),
+    parentId: uuid('parent_id'),
+    body: text('body').notNull(),
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+    updatedAt: timestamp('updated_at').notNull().defaultNow(),
+    deletedAt: timestamp('deleted_at'),
+  },
+  (table) => ({
+    teamThreadIdx: index('team_comments_team_thread_idx').on(
+      table.teamId,
+      table.parentId,
+      table.createdAt
+    ),
+  })
+);
+
+// Users' reports of shared teams, kept until a moderator reviews the team
+export const teamFlags = pgTable(
+  'team_flags',
+  {
+    teamId: uuid('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    reason: varchar('reason', { length: 20 }).notNull(),
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.teamId, table.userId] }),
+  })
+);
+
+// Published text waiting for a moderator; reasons are comma-separated
+export const moderationQueue = pgTable(
+  'moderation_queue',
+  {
+    itemId: uuid('item_id').primaryKey(),
+    kind: varchar('kind', { length: 20 }).notNull(),
+    targetId: uuid('target_id').notNull(),
+    authorId: uuid('author_id')
+      .notNull()
+      .references(() => users.userId),
+    text: text('text').notNull(),
+    reasons: varchar('reasons', { length: 100 }).notNull(),
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    targetIdx: index('moderation_queue_target_idx').on(table.kind, table.targetId),
+  })
+);
+
+// In-app notifications; data is the JSON content for the type
+export const notifications = pgTable(
+  'notifications',
+  {
+    notificationId: uuid('notification_id').primaryKey(),
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    type: varchar('type', { length: 30 }).notNull(),
+    data: text('data').notNull(),
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+    readAt: timestamp('read_at'),
+  },
+  (table) => ({
+    userCreatedIdx: index('notifications_user_created_idx').on(table.userId, table.createdAt),
+  })
+);
+
+export const challengeRuns = pgTable('challenge_runs', {
+  runId: uuid('run_id').primaryKey(),
+  ownerId: uuid('owner_id')
     .notNull()
-    .references(() => teams.teamId),
-  slot: integer('slot').notNull(), // 0-5
-  formId: integer('form_id').notNull(),
-  terastalType: varchar('terastal_type', { length: 20 }).notNull(),
+    .references(() => users.userId),
+  name: varchar('name', { length: 50 }).notNull(),
+  gameVersion: varchar('game_version', { length: 50 }).notNull(),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+});
+
+// Species caught or used in a challenge run
+export const challengeRunSpecies = pgTable(
+  'challenge_run_species',
+  {
+    runId: uuid('run_id')
+      .notNull()
+      .references(() => challengeRuns.runId),
+    speciesId: integer('species_id').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.runId, table.speciesId] }),
+  })
+);
+
+// Named spreads a user runs damage calculations against, kept per format
+export const damageBenchmarks = pgTable(
+  'damage_benchmarks',
+  {
+    benchmarkId: uuid('benchmark_id').primaryKey(),
+    ownerId: uuid('owner_id')
+      .notNull()
+      .references(() => users.userId),
+    format: varchar('format', { length: 50 }).notNull(),
+    name: varchar('name', { length: 50 }).notNull(),
+    role: varchar('role', { length: 20 }).notNull(), // offensive, defensive
+    formId: integer('form_id').notNull(),
+    nature: varchar('nature', { length: 20 }).notNull(),
+    ability: varchar('ability', { length: 100 }),
+    item: varchar('item', { length: 100 }),
+    teraType: varchar('tera_type', { length: 20 }),
+    move: varchar('move', { length: 100 }), // offensive benchmarks only
+
+    // EVs (0-252)
+    evHp: integer('ev_hp').notNull().default(0),
+    evAttack: integer('ev_attack').notNull().default(0),
+    evDefense: integer('ev_defense').notNull().default(0),
+    evSpecialAttack: integer('ev_special_attack').notNull().default(0),
+    evSpecialDefense: integer('ev_special_defense').notNull().default(0),
+    evSpeed: integer('ev_speed').notNull().default(0),
+
+    // IVs (0-31)
+    ivHp: integer('iv_hp').notNull().default(31),
+    ivAttack: integer('iv_attack').notNull().default(31),
+    ivDefense: integer('iv_defense').notNull().default(31),
+    ivSpecialAttack: integer('iv_special_attack').notNull().default(31),
+    ivSpecialDefense: integer('iv_special_defense').notNull().default(31),
+    ivSpeed: integer('iv_speed').notNull().default(31),
+
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    ownerFormatIdx: index('damage_benchmarks_owner_format_idx').on(table.ownerId, table.format),
+  })
+);
+
+// Users' own Pokémon, private to their owner. IDs continue past the official ones (see
+// MIN_CUSTOM_FORM_ID), so team members and calculations refer to both the same way.
+export const customForms = pgTable(
+  'custom_forms',
+  {
+    formId: integer('form_id').primaryKey().generatedAlwaysAsIdentity({ startWith: 1000001 }),
+    ownerId: uuid('owner_id')
+      .notNull()
+      .references(() => users.userId),
+    name: varchar('name', { length: 50 }).notNull(),
+    type1: varchar('type1', { length: 20 }).notNull(),
+    type2: varchar('type2', { length: 20 }),
+    hp: integer('hp').notNull(),
+    attack: integer('attack').notNull(),
+    defense: integer('defense').notNull(),
+    specialAttack: integer('special_attack').notNull(),
+    specialDefense: integer('special_defense').notNull(),
+    speed: integer('speed').notNull(),
+    ability1: varchar('ability1', { length: 100 }).notNull(),
+    ability2: varchar('ability2', { length: 100 }),
+    hiddenAbility: varchar('hidden_ability', { length: 100 }),
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+    updatedAt: timestamp('updated_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    ownerIdx: index('custom_forms_owner_idx').on(table.ownerId),
+  })
+);
+
+// Draft leagues; the owner is the commissioner, who need not take part
+export const draftLeagues = pgTable('draft_leagues', {
+  leagueId: uuid('league_id').primaryKey(),
+  ownerId: uuid('owner_id')
+    .notNull()
+    .references(() => users.userId),
+  name: varchar('name', { length: 50 }).notNull(),
+  format: varchar('format', { length: 50 }).notNull(),
+  budget: integer('budget').notNull(),
+  rosterSize: integer('roster_size').notNull(),
+  // Bumped with each pick and undo, so two of them can never claim the same turn
+  pickCount: integer('pick_count').notNull().default(0),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
 });
 
+export const draftLeagueParticipants = pgTable(
+  'draft_league_participants',
+  {
+    leagueId: uuid('league_id')
+      .notNull()
+      .references(() => draftLeagues.leagueId),
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    position: integer('position').notNull(), // 0-based draft order
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.leagueId, table.userId] }),
+    userIdx: index('draft_league_participants_user_idx').on(table.userId),
+  })
+);
+
+// Pokémon that can be drafted in a league and their point costs
+export const draftLeaguePool = pgTable(
+  'draft_league_pool',
+  {
+    leagueId: uuid('league_id')
+      .notNull()
+      .references(() => draftLeagues.leagueId),
+    formId: integer('form_id').notNull(),
+    cost: integer('cost').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.leagueId, table.formId] }),
+  })
+);
+
+export const draftLeaguePicks = pgTable(
+  'draft_league_picks',
+  {
+    leagueId: uuid('league_id')
+      .notNull()
+      .references(() => draftLeagues.leagueId),
+    pickNumber: integer('pick_number').notNull(), // from 1
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    formId: integer('form_id').notNull(),
+    pickedAt: timestamp('picked_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.leagueId, table.pickNumber] }),
+    formIdx: uniqueIndex('draft_league_picks_form_idx').on(table.leagueId, table.formId),
+  })
+);
+
+// Single-elimination events; the organizer need not play
+export const tournaments = pgTable('tournaments', {
+  tournamentId: uuid('tournament_id').primaryKey(),
+  organizerId: uuid('organizer_id')
+    .notNull()
+    .references(() => users.userId),
+  name: varchar('name', { length: 50 }).notNull(),
+  format: varchar('format', { length: 50 }).notNull(),
+  status: varchar('status', { length: 20 }).notNull(), // registration, in_progress or complete
+  maxEntries: integer('max_entries').notNull(),
+  version: integer('version').notNull().default(1),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  startedAt: timestamp('started_at'),
+});
+
+// Team snapshots taken at registration. The team is not referenced, so deleting it later
+// leaves the entry as it was.
+export const tournamentEntries = pgTable(
+  'tournament_entries',
+  {
+    tournamentId: uuid('tournament_id')
+      .notNull()
+      .references(() => tournaments.tournamentId),
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    teamId: uuid('team_id').notNull(),
+    teamName: varchar('team_name', { length: 50 }).notNull(),
+    teamVersion: integer('team_version').notNull(),
+    seed: integer('seed'), // set when the bracket is drawn
+    registeredAt: timestamp('registered_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.tournamentId, table.userId] }),
+    userIdx: index('tournament_entries_user_idx').on(table.userId),
+  })
+);
+
+export const tournamentEntryMembers = pgTable(
+  'tournament_entry_members',
+  {
+    tournamentId: uuid('tournament_id')
+      .notNull()
+      .references(() => tournaments.tournamentId),
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    slot: integer('slot').notNull(),
+    formId: integer('form_id').notNull(),
+    terastalType: varchar('terastal_type', { length: 20 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.tournamentId, table.userId, table.slot] }),
+  })
+);
+
+export const tournamentMatches = pgTable(
+  'tournament_matches',
+  {
+    tournamentId: uuid('tournament_id')
+      .notNull()
+      .references(() => tournaments.tournamentId),
+    round: integer('round').notNull(), // from 1
+    position: integer('position').notNull(), // from 0 within the round
+    player1Id: uuid('player1_id').references(() => users.userId),
+    player2Id: uuid('player2_id').references(() => users.userId),
+    winnerId: uuid('winner_id').references(() => users.userId),
+    reportedAt: timestamp('reported_at'),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.tournamentId, table.round, table.position] }),
+  })
+);
+
+// Battles users log against their own teams
+export const battleRecords = pgTable(
+  'battle_records',
+  {
+    recordId: uuid('record_id').primaryKey(),
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    teamId: uuid('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    format: varchar('format', { length: 50 }),
+    result: varchar('result', { length: 4 }).notNull(), // win or loss
+    opponentName: varchar('opponent_name', { length: 50 }),
+    opponentArchetype: varchar('opponent_archetype', { length: 20 }),
+    notes: text('notes'),
+    playedAt: timestamp('played_at').notNull(),
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    userIdx: index('battle_records_user_idx').on(table.userId, table.playedAt),
+    teamIdx: index('battle_records_team_idx').on(table.teamId),
+  })
+);
+
+// The opponent's Pokémon as far as they were seen
+export const battleRecordOpponents = pgTable(
+  'battle_record_opponents',
+  {
+    recordId: uuid('record_id')
+      .notNull()
+      .references(() => battleRecords.recordId),
+    slot: integer('slot').notNull(),
+    formId: integer('form_id').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.recordId, table.slot] }),
+  })
+);
+
+// Per-user endpoints notified of team events
+export const webhooks = pgTable('webhooks', {
+  webhookId: uuid('webhook_id').primaryKey(),
+  ownerId: uuid('owner_id')
+    .notNull()
+    .references(() => users.userId),
+  url: text('url').notNull(),
+  secret: varchar('secret', { length: 64 }).notNull(), // HMAC key, needed in the clear to sign
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+});
+
+export const webhookEvents = pgTable(
+  'webhook_events',
+  {
+    webhookId: uuid('webhook_id')
+      .notNull()
+      .references(() => webhooks.webhookId),
+    eventType: varchar('event_type', { length: 50 }).notNull(), // team.created, team.updated
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.webhookId, table.eventType] }),
+  })
+);
+
+// One row per delivery attempt
+export const webhookDeliveries = pgTable(
+  'webhook_deliveries',
+  {
+    deliveryId: uuid('delivery_id').primaryKey(),
+    webhookId: uuid('webhook_id')
+      .notNull()
+      .references(() => webhooks.webhookId),
+    eventId: uuid('event_id').notNull(),
+    eventType: varchar('event_type', { length: 50 }).notNull(),
+    attempt: integer('attempt').notNull(), // 1-based
+    statusCode: integer('status_code'), // null when no response came back
+    error: text('error'),
+    succeeded: boolean('succeeded').notNull(),
+    attemptedAt: timestamp('attempted_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    webhookIdx: index('webhook_deliveries_webhook_idx').on(table.webhookId, table.attemptedAt),
+  })
+);
+
+// Team events written in the same transaction as the change they describe, then published by
+// the outbox relay. An event stays here until a relay has published it, so a crash between the
+// write and the publish only delays it.
+export const teamEventOutbox = pgTable(
+  'team_event_outbox',
+  {
+    eventId: uuid('event_id').primaryKey(), // receivers deduplicate on it
+    type: varchar('type', { length: 50 }).notNull(),
+    teamId: uuid('team_id').notNull(),
+    occurredAt: timestamp('occurred_at').notNull(),
+    // A relay publishing the event holds it until then; another relay may take it over after
+    claimedUntil: timestamp('claimed_until'),
+    publishedAt: timestamp('published_at'),
+  },
+  (table) => ({
+    pendingIdx: index('team_event_outbox_pending_idx').on(table.publishedAt, table.occurredAt),
+  })
+);
+
+// Running type quiz score per user
+export const typeQuizScores = pgTable('type_quiz_scores', {
+  userId: uuid('user_id')
+    .primaryKey()
+    .references(() => users.userId),
+  answered: integer('answered').notNull().default(0),
+  correct: integer('correct').notNull().default(0),
+  currentStreak: integer('current_streak').notNull().default(0),
+  bestStreak: integer('best_streak').notNull().default(0),
+  updatedAt: timestamp('updated_at').notNull().defaultNow(),
+});
+
+// Questions each user has answered, so a question only ever scores once
+export const typeQuizAnswers = pgTable(
+  'type_quiz_answers',
+  {
+    userId: uuid('user_id')
+      .notNull()
+      .references(() => users.userId),
+    questionId: bigint('question_id', { mode: 'number' }).notNull(), // unsigned 32-bit seed
+    correct: boolean('correct').notNull(),
+    answeredAt: timestamp('answered_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.userId, table.questionId] }),
+  })
+);
+
+// Keys for third-party apps; only the SHA-256 of the key is stored
+export const apiKeys = pgTable('api_keys', {
+  keyId: uuid('key_id').primaryKey(),
+  name: varchar('name', { length: 50 }).notNull(),
+  keyHash: varchar('key_hash', { length: 64 }).notNull().unique(),
+  displayPrefix: varchar('display_prefix', { length: 20 }).notNull(), // e.g. pdk_Ab12Cd34
+  dailyQuota: integer('daily_quota').notNull(),
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+  revokedAt: timestamp('revoked_at'),
+});
+
+export const apiKeyScopes = pgTable(
+  'api_key_scopes',
+  {
+    keyId: uuid('key_id')
+      .notNull()
+      .references(() => apiKeys.keyId),
+    scope: varchar('scope', { length: 20 }).notNull(), // pokemon, types, usage
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.keyId, table.scope] }),
+  })
+);
+
+// Requests per key per UTC day, counted against the daily quota
+export const apiKeyRequests = pgTable(
+  'api_key_requests',
+  {
+    keyId: uuid('key_id')
+      .notNull()
+      .references(() => apiKeys.keyId),
+    day: varchar('day', { length: 10 }).notNull(), // YYYY-MM-DD
+    requests: integer('requests').notNull().default(0),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.keyId, table.day] }),
+  })
+);
+
 // Master data tables (already exist in the database)
-export const pokemonForms = pgTable('pokemon_forms', {
-  formId: integer('form_id').primaryKey(),
-  speciesId: integer('species_id').notNull(),
+export const pokemonForms = pgTable(
+  'pokemon_forms',
+  {
+    formId: integer('form_id').primaryKey(),
+    speciesId: integer('species_id').notNull(),
+    name: text('name').notNull(),
+    nameJp: text('name_jp').notNull(),
+    type1: varchar('type1', { length: 20 }).notNull(),
+    type2: varchar('type2', { length: 20 }),
+    baseHp: integer('base_hp').notNull().default(1),
+    baseAttack: integer('base_attack').notNull().default(1),
+    baseDefense: integer('base_defense').notNull().default(1),
+    baseSpecialAttack: integer('base_special_attack').notNull().default(1),
+    baseSpecialDefense: integer('base_special_defense').notNull().default(1),
+    baseSpeed: integer('base_speed').notNull().default(1),
+    baseExperience: integer('base_experience'),
+    height: integer('height').notNull().default(0), // decimetres
+    weight: integer('weight').notNull().default(0), // hectograms
+    shinyAvailable: boolean('shiny_available').notNull().default(true),
+    shinyLocked: boolean('shiny_locked').notNull().default(false),
+    introducedIn: varchar('introduced_in', { length: 50 }).notNull().default('red-blue'),
+  },
+  (table) => ({
+    // Exact typeset lookups use both columns; "contains a type" also needs type2 on its own
+    typesIdx: index('pokemon_forms_types_idx').on(table.type1, table.type2),
+    type2Idx: index('pokemon_forms_type2_idx').on(table.type2),
+  })
+);
+
+export const pokemonFormAvailability = pgTable(
+  'pokemon_form_availability',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    gameVersion: varchar('game_version', { length: 50 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.gameVersion] }),
+    gameVersionIdx: index('pokemon_form_availability_game_version_idx').on(table.gameVersion),
+  })
+);
+
+export const pokemonFormAbilities = pgTable(
+  'pokemon_form_abilities',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    slot: varchar('slot', { length: 20 }).notNull(), // primary, secondary, hidden
+    ability: varchar('ability', { length: 100 }).notNull(), // PokeAPI identifier
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.slot] }),
+    // "Which Pokémon can have this ability"
+    abilityIdx: index('pokemon_form_abilities_ability_idx').on(table.ability),
+  })
+);
+
+// Every normalized name a form can be looked up by (see NameKey): English, kana and romaji
+export const pokemonNameIndex = pgTable(
+  'pokemon_name_index',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    key: varchar('key', { length: 100 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.key] }),
+    keyIdx: index('pokemon_name_index_key_idx').on(table.key),
+  })
+);
+
+// Where a form can be caught in the wild, per PokeAPI location area
+export const pokemonEncounters = pgTable(
+  'pokemon_encounters',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    gameVersion: varchar('game_version', { length: 50 }).notNull(),
+    location: varchar('location', { length: 100 }).notNull(), // e.g. kanto-route-1-area
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.gameVersion, table.location] }),
+    gameLocationIdx: index('pokemon_encounters_game_location_idx').on(
+      table.gameVersion,
+      table.location
+    ),
+  })
+);
+
+// Cries, animated sprites and 3D render availability per form, checked against the asset hosts
+export const pokemonFormMedia = pgTable('pokemon_form_media', {
+  formId: integer('form_id')
+    .primaryKey()
+    .references(() => pokemonForms.formId),
+  cryUrl: text('cry_url'),
+  legacyCryUrl: text('legacy_cry_url'),
+  spriteUrl: text('sprite_url'),
+  shinySpriteUrl: text('shiny_sprite_url'),
+  has3dModel: boolean('has_3d_model').notNull().default(false),
+  has3dShinyModel: boolean('has_3d_shiny_model').notNull().default(false),
+  checkedAt: timestamp('checked_at').notNull(),
+});
+
+export const moves = pgTable(
+  'moves',
+  {
+    name: varchar('name', { length: 100 }).primaryKey(), // PokeAPI identifier
+    moveId: integer('move_id').notNull(), // PokeAPI move ID
+    lookupKey: varchar('lookup_key', { length: 100 }).notNull(), // e.g. "closecombat"
+    nameJp: text('name_jp'),
+    type: varchar('type', { length: 20 }).notNull(),
+    damageClass: varchar('damage_class', { length: 20 }).notNull(),
+    power: integer('power'),
+    accuracy: integer('accuracy'),
+    priority: integer('priority').notNull().default(0),
+    pp: integer('pp'),
+    target: varchar('target', { length: 50 }).notNull().default('selected-pokemon'),
+    minHits: integer('min_hits'),
+    maxHits: integer('max_hits'),
+    effect: text('effect'), // PokeAPI short effect, English
+    effects: text('effects'), // MoveEffects as JSON; null until imported again
+  },
+  (table) => ({
+    lookupKeyIdx: uniqueIndex('moves_lookup_key_idx').on(table.lookupKey),
+    moveIdIdx: uniqueIndex('moves_move_id_idx').on(table.moveId),
+  })
+);
+
+export const abilities = pgTable(
+  'abilities',
+  {
+    name: varchar('name', { length: 100 }).primaryKey(), // PokeAPI identifier
+    abilityId: integer('ability_id').notNull(), // PokeAPI ability ID
+    lookupKey: varchar('lookup_key', { length: 100 }).notNull(), // e.g. "intimidate"
+    nameJp: text('name_jp'),
+    effect: text('effect'), // PokeAPI short effect, English
+    effectJp: text('effect_jp'), // latest Japanese flavor text
+  },
+  (table) => ({
+    lookupKeyIdx: uniqueIndex('abilities_lookup_key_idx').on(table.lookupKey),
+    abilityIdIdx: uniqueIndex('abilities_ability_id_idx').on(table.abilityId),
+  })
+);
+
+export const items = pgTable(
+  'items',
+  {
+    name: varchar('name', { length: 100 }).primaryKey(), // PokeAPI identifier
+    itemId: integer('item_id').notNull(), // PokeAPI item ID
+    lookupKey: varchar('lookup_key', { length: 100 }).notNull(), // e.g. "choicescarf"
+    nameJp: text('name_jp'),
+    category: varchar('category', { length: 50 }).notNull(), // PokeAPI item category
+    effect: text('effect'), // PokeAPI short effect, English
+    effectJp: text('effect_jp'), // latest Japanese flavor text
+  },
+  (table) => ({
+    lookupKeyIdx: uniqueIndex('items_lookup_key_idx').on(table.lookupKey),
+    itemIdIdx: uniqueIndex('items_item_id_idx').on(table.itemId),
+    categoryIdx: index('items_category_idx').on(table.category),
+  })
+);
+
+// One row per PokeAPI import run or admin change; the latest ID is the version of the Pokédex data
+export const dataImports = pgTable('data_imports', {
+  id: serial('id').primaryKey(),
+  kind: varchar('kind', { length: 20 }).notNull(), // species, moves, abilities, items, seed, ...
+  fromId: integer('from_id'),
+  toId: integer('to_id'),
+  importedAt: timestamp('imported_at').notNull().defaultNow(),
+});
+
+// Dataset entries as they were before an import, edit or rollback overwrote them. `version` is
+// the data version current at the time, so rolling back to it restores this copy.
+export const datasetSnapshots = pgTable(
+  'dataset_snapshots',
+  {
+    id: serial('id').primaryKey(),
+    version: integer('version').notNull(),
+    kind: varchar('kind', { length: 20 }).notNull(), // species, form, move, ability, item
+    entryId: integer('entry_id').notNull(), // species, form, move, ability or item ID
+    data: text('data').notNull(), // the entry as JSON
+    createdAt: timestamp('created_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    versionIdx: index('dataset_snapshots_version_idx').on(table.version),
+  })
+);
+
+// Memoized team analysis responses. The key hashes the analysis kind and its whole request, so
+// an edited team simply misses; `version` is the data version the result was computed from.
+export const analysisCache = pgTable('analysis_cache', {
+  cacheKey: varchar('cache_key', { length: 64 }).primaryKey(), // SHA-256, hex
+  kind: varchar('kind', { length: 20 }).notNull(), // threats, archetype, report
+  version: integer('version').notNull(),
+  result: text('result').notNull(), // the response as JSON
+  createdAt: timestamp('created_at').notNull().defaultNow(),
+});
+
+export const pokemonSpecies = pgTable('pokemon_species', {
+  speciesId: integer('species_id').primaryKey(),
   name: text('name').notNull(),
   nameJp: text('name_jp').notNull(),
-  type1: varchar('type1', { length: 20 }).notNull(),
-  type2: varchar('type2', { length: 20 }),
+  eggGroup1: varchar('egg_group1', { length: 20 }).notNull(),
+  eggGroup2: varchar('egg_group2', { length: 20 }),
+  genderRate: integer('gender_rate').notNull().default(-1), // eighths female, -1 = genderless
+  captureRate: integer('capture_rate').notNull().default(0),
+  category: varchar('category', { length: 20 }).notNull().default('ordinary'),
+  evolvesFromSpeciesId: integer('evolves_from_species_id'), // null for the first stage
 });
+
+// Regional Pokédex numbers; the National Dex number is the species ID
+export const pokemonSpeciesDexNumbers = pgTable(
+  'pokemon_species_dex_numbers',
+  {
+    speciesId: integer('species_id')
+      .notNull()
+      .references(() => pokemonSpecies.speciesId),
+    dex: varchar('dex', { length: 50 }).notNull(), // PokeAPI pokedex name, e.g. paldea
+    number: integer('number').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.speciesId, table.dex] }),
+    dexNumberIdx: uniqueIndex('pokemon_species_dex_numbers_dex_number_idx').on(
+      table.dex,
+      table.number
+    ),
+  })
+);
+
+export const pokemonLearnsets = pgTable(
+  'pokemon_learnsets',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    moveName: varchar('move_name', { length: 100 }).notNull(),
+    learnMethod: varchar('learn_method', { length: 20 }).notNull(), // level-up, egg, machine, tutor
+    level: integer('level'),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.moveName, table.learnMethod] }),
+    moveNameIdx: index('pokemon_learnsets_move_name_idx').on(table.moveName),
+  })
+);
+
+export const usageSnapshots = pgTable(
+  'usage_snapshots',
+  {
+    formatId: varchar('format_id', { length: 50 }).notNull(),
+    period: varchar('period', { length: 7 }).notNull(), // YYYY-MM
+    cutoff: integer('cutoff').notNull(),
+    battles: integer('battles').notNull(),
+    importedAt: timestamp('imported_at').notNull().defaultNow(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formatId, table.period] }),
+  })
+);
+
+export const usageStats = pgTable(
+  'usage_stats',
+  {
+    formatId: varchar('format_id', { length: 50 }).notNull(),
+    period: varchar('period', { length: 7 }).notNull(),
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    rank: integer('rank').notNull(),
+    usage: real('usage').notNull(), // 0-100
+    rawCount: integer('raw_count').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formatId, table.period, table.formId] }),
+  })
+);
+
+export const usageDetails = pgTable(
+  'usage_details',
+  {
+    formatId: varchar('format_id', { length: 50 }).notNull(),
+    period: varchar('period', { length: 7 }).notNull(),
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    category: varchar('category', { length: 20 }).notNull(), // move, item, ability, tera_type, spread
+    name: varchar('name', { length: 100 }).notNull(),
+    percentage: real('percentage').notNull(), // 0-100
+  },
+  (table) => ({
+    pk: primaryKey({
+      columns: [table.formatId, table.period, table.formId, table.category, table.name],
+    }),
+  })
+);
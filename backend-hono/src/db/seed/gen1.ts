@@ -0,0 +1,209 @@
+import { EggGroupSchema } from '../../domain/valueobject/EggGroup';
+import { GameVersion } from '../../domain/valueobject/GameVersion';
+import { PokemonTypeSchema } from '../../domain/valueobject/PokemonType';
+import { SpeciesCategory } from '../../domain/valueobject/SpeciesCategory';
+import { SeedSpecies } from '../../usecase/SeedService';
+
+// Demo/fixture data for the original 151 with current-generation types and base stats.
+// Run `npm run import:pokeapi` for authoritative data; this set deliberately leaves out
+// learnsets, abilities, base experience, alternate forms, version exclusives and event
+// distributions.
+//
+// id,name,name_jp,types,hp/atk/def/spa/spd/spe,egg_groups,female_eighths,capture_rate,height_dm,weight_hg
+// followed, for evolved species, by the pre-evolution's ID; it may lie outside this set (Pikachu
+// evolves from Pichu, #172)
+const GEN1_CSV = `
+1,Bulbasaur,フシギダネ,Grass/Poison,45/49/49/65/65/45,Monster/Grass,1,45,7,69
+2,Ivysaur,フシギソウ,Grass/Poison,60/62/63/80/80/60,Monster/Grass,1,45,10,130,1
+3,Venusaur,フシギバナ,Grass/Poison,80/82/83/100/100/80,Monster/Grass,1,45,20,1000,2
+4,Charmander,ヒトカゲ,Fire,39/52/43/60/50/65,Monster/Dragon,1,45,6,85
+5,Charmeleon,リザード,Fire,58/64/58/80/65/80,Monster/Dragon,1,45,11,190,4
+6,Charizard,リザードン,Fire/Flying,78/84/78/109/85/100,Monster/Dragon,1,45,17,905,5
+7,Squirtle,ゼニガメ,Water,44/48/65/50/64/43,Monster/Water1,1,45,5,90
+8,Wartortle,カメール,Water,59/63/80/65/80/58,Monster/Water1,1,45,10,225,7
+9,Blastoise,カメックス,Water,79/83/100/85/105/78,Monster/Water1,1,45,16,855,8
+10,Caterpie,キャタピー,Bug,45/30/35/20/20/45,Bug,4,255,3,29
+11,Metapod,トランセル,Bug,50/20/55/25/25/30,Bug,4,120,7,99,10
+12,Butterfree,バタフリー,Bug/Flying,60/45/50/90/80/70,Bug,4,45,11,320,11
+13,Weedle,ビードル,Bug/Poison,40/35/30/20/20/50,Bug,4,255,3,32
+14,Kakuna,コクーン,Bug/Poison,45/25/50/25/25/35,Bug,4,120,6,100,13
+15,Beedrill,スピアー,Bug/Poison,65/90/40/45/80/75,Bug,4,45,10,295,14
+16,Pidgey,ポッポ,Normal/Flying,40/45/40/35/35/56,Flying,4,255,3,18
+17,Pidgeotto,ピジョン,Normal/Flying,63/60/55/50/50/71,Flying,4,120,11,300,16
+18,Pidgeot,ピジョット,Normal/Flying,83/80/75/70/70/101,Flying,4,45,15,395,17
+19,Rattata,コラッタ,Normal,30/56/35/25/35/72,Field,4,255,3,35
+20,Raticate,ラッタ,Normal,55/81/60/50/70/97,Field,4,127,7,185,19
+21,Spearow,オニスズメ,Normal/Flying,40/60/30/31/31/70,Flying,4,255,3,20
+22,Fearow,オニドリル,Normal/Flying,65/90/65/61/61/100,Flying,4,90,12,380,21
+23,Ekans,アーボ,Poison,35/60/44/40/54/55,Field/Dragon,4,255,20,69
+24,Arbok,アーボック,Poison,60/95/69/65/79/80,Field/Dragon,4,90,35,650,23
+25,Pikachu,ピカチュウ,Electric,35/55/40/50/50/90,Field/Fairy,4,190,4,60,172
+26,Raichu,ライチュウ,Electric,60/90/55/90/80/110,Field/Fairy,4,75,8,300,25
+27,Sandshrew,サンド,Ground,50/75/85/20/30/40,Field,4,255,6,120
+28,Sandslash,サンドパン,Ground,75/100/110/45/55/65,Field,4,90,10,295,27
+29,Nidoran♀,ニドラン♀,Poison,55/47/52/40/40/41,Monster/Field,8,235,4,70
+30,Nidorina,ニドリーナ,Poison,70/62/67/55/55/56,Undiscovered,8,120,8,200,29
+31,Nidoqueen,ニドクイン,Poison/Ground,90/92/87/75/85/76,Undiscovered,8,45,13,600,30
+32,Nidoran♂,ニドラン♂,Poison,46/57/40/40/40/50,Monster/Field,0,235,5,90
+33,Nidorino,ニドリーノ,Poison,61/72/57/55/55/65,Monster/Field,0,120,9,195,32
+34,Nidoking,ニドキング,Poison/Ground,81/102/77/85/75/85,Monster/Field,0,45,14,620,33
+35,Clefairy,ピッピ,Fairy,70/45/48/60/65/35,Fairy,6,150,6,75,173
+36,Clefable,ピクシー,Fairy,95/70/73/95/90/60,Fairy,6,25,13,400,35
+37,Vulpix,ロコン,Fire,38/41/40/50/65/65,Field,6,190,6,99
+38,Ninetales,キュウコン,Fire,73/76/75/81/100/100,Field,6,75,11,199,37
+39,Jigglypuff,プリン,Normal/Fairy,115/45/20/45/25/20,Fairy,6,170,5,55,174
+40,Wigglytuff,プクリン,Normal/Fairy,140/70/45/85/50/45,Fairy,6,50,10,120,39
+41,Zubat,ズバット,Poison/Flying,40/45/35/30/40/55,Flying,4,255,8,75
+42,Golbat,ゴルバット,Poison/Flying,75/80/70/65/75/90,Flying,4,90,16,550,41
+43,Oddish,ナゾノクサ,Grass/Poison,45/50/55/75/65/30,Grass,4,255,5,54
+44,Gloom,クサイハナ,Grass/Poison,60/65/70/85/75/40,Grass,4,120,8,86,43
+45,Vileplume,ラフレシア,Grass/Poison,75/80/85/110/90/50,Grass,4,45,12,186,44
+46,Paras,パラス,Bug/Grass,35/70/55/45/55/25,Bug/Grass,4,190,3,54
+47,Parasect,パラセクト,Bug/Grass,60/95/80/60/80/30,Bug/Grass,4,75,10,295,46
+48,Venonat,コンパン,Bug/Poison,60/55/50/40/55/45,Bug,4,190,10,300
+49,Venomoth,モルフォン,Bug/Poison,70/65/60/90/75/90,Bug,4,75,15,125,48
+50,Diglett,ディグダ,Ground,10/55/25/35/45/95,Field,4,255,2,8
+51,Dugtrio,ダグトリオ,Ground,35/100/50/50/70/120,Field,4,50,7,333,50
+52,Meowth,ニャース,Normal,40/45/35/40/40/90,Field,4,255,4,42
+53,Persian,ペルシアン,Normal,65/70/60/65/65/115,Field,4,90,10,320,52
+54,Psyduck,コダック,Water,50/52/48/65/50/55,Water1/Field,4,190,8,196
+55,Golduck,ゴルダック,Water,80/82/78/95/80/85,Water1/Field,4,75,17,766,54
+56,Mankey,マンキー,Fighting,40/80/35/35/45/70,Field,4,190,5,280
+57,Primeape,オコリザル,Fighting,65/105/60/60/70/95,Field,4,75,10,320,56
+58,Growlithe,ガーディ,Fire,55/70/45/70/50/60,Field,2,190,7,190
+59,Arcanine,ウインディ,Fire,90/110/80/100/80/95,Field,2,75,19,1550,58
+60,Poliwag,ニョロモ,Water,40/50/40/40/40/90,Water1,4,255,6,124
+61,Poliwhirl,ニョロゾ,Water,65/65/65/50/50/90,Water1,4,120,10,200,60
+62,Poliwrath,ニョロボン,Water/Fighting,90/95/95/70/90/70,Water1,4,45,13,540,61
+63,Abra,ケーシィ,Psychic,25/20/15/105/55/90,HumanLike,2,200,9,195
+64,Kadabra,ユンゲラー,Psychic,40/35/30/120/70/105,HumanLike,2,100,13,565,63
+65,Alakazam,フーディン,Psychic,55/50/45/135/95/120,HumanLike,2,50,15,480,64
+66,Machop,ワンリキー,Fighting,70/80/50/35/35/35,HumanLike,2,180,8,195
+67,Machoke,ゴーリキー,Fighting,80/100/70/50/60/45,HumanLike,2,90,15,705,66
+68,Machamp,カイリキー,Fighting,90/130/80/65/85/55,HumanLike,2,45,16,1300,67
+69,Bellsprout,マダツボミ,Grass/Poison,50/75/35/70/30/40,Grass,4,255,7,40
+70,Weepinbell,ウツドン,Grass/Poison,65/90/50/85/45/55,Grass,4,120,10,64,69
+71,Victreebel,ウツボット,Grass/Poison,80/105/65/100/70/70,Grass,4,45,17,155,70
+72,Tentacool,メノクラゲ,Water/Poison,40/40/35/50/100/70,Water3,4,190,9,455
+73,Tentacruel,ドククラゲ,Water/Poison,80/70/65/80/120/100,Water3,4,60,16,550,72
+74,Geodude,イシツブテ,Rock/Ground,40/80/100/30/30/20,Mineral,4,255,4,200
+75,Graveler,ゴローン,Rock/Ground,55/95/115/45/45/35,Mineral,4,120,10,1050,74
+76,Golem,ゴローニャ,Rock/Ground,80/120/130/55/65/45,Mineral,4,45,14,3000,75
+77,Ponyta,ポニータ,Fire,50/85/55/65/65/90,Field,4,190,10,300
+78,Rapidash,ギャロップ,Fire,65/100/70/80/80/105,Field,4,60,17,950,77
+79,Slowpoke,ヤドン,Water/Psychic,90/65/65/40/40/15,Monster/Water1,4,190,12,360
+80,Slowbro,ヤドラン,Water/Psychic,95/75/110/100/80/30,Monster/Water1,4,75,16,785,79
+81,Magnemite,コイル,Electric/Steel,25/35/70/95/55/45,Mineral,-1,190,3,60
+82,Magneton,レアコイル,Electric/Steel,50/60/95/120/70/70,Mineral,-1,60,10,600,81
+83,Farfetch'd,カモネギ,Normal/Flying,52/90/55/58/62/60,Flying/Field,4,45,8,150
+84,Doduo,ドードー,Normal/Flying,35/85/45/35/35/75,Flying,4,190,14,392
+85,Dodrio,ドードリオ,Normal/Flying,60/110/70/60/60/110,Flying,4,45,18,852,84
+86,Seel,パウワウ,Water,65/45/55/45/70/45,Water1/Field,4,190,11,900
+87,Dewgong,ジュゴン,Water/Ice,90/70/80/70/95/70,Water1/Field,4,75,17,1200,86
+88,Grimer,ベトベター,Poison,80/80/50/40/50/25,Amorphous,4,190,9,300
+89,Muk,ベトベトン,Poison,105/105/75/65/100/50,Amorphous,4,75,12,300,88
+90,Shellder,シェルダー,Water,30/65/100/45/25/40,Water3,4,190,3,40
+91,Cloyster,パルシェン,Water/Ice,50/95/180/85/45/70,Water3,4,60,15,1325,90
+92,Gastly,ゴース,Ghost/Poison,30/35/30/100/35/80,Amorphous,4,190,13,1
+93,Haunter,ゴースト,Ghost/Poison,45/50/45/115/55/95,Amorphous,4,90,16,1,92
+94,Gengar,ゲンガー,Ghost/Poison,60/65/60/130/75/110,Amorphous,4,45,15,405,93
+95,Onix,イワーク,Rock/Ground,35/45/160/30/45/70,Mineral,4,45,88,2100
+96,Drowzee,スリープ,Psychic,60/48/45/43/90/42,HumanLike,4,190,10,324
+97,Hypno,スリーパー,Psychic,85/73/70/73/115/67,HumanLike,4,75,16,756,96
+98,Krabby,クラブ,Water,30/105/90/25/25/50,Water3,4,225,4,65
+99,Kingler,キングラー,Water,55/130/115/50/50/75,Water3,4,60,13,600,98
+100,Voltorb,ビリリダマ,Electric,40/30/50/55/55/100,Mineral,-1,190,5,104
+101,Electrode,マルマイン,Electric,60/50/70/80/80/150,Mineral,-1,60,12,666,100
+102,Exeggcute,タマタマ,Grass/Psychic,60/40/80/60/45/40,Grass,4,90,4,25
+103,Exeggutor,ナッシー,Grass/Psychic,95/95/85/125/75/55,Grass,4,45,20,1200,102
+104,Cubone,カラカラ,Ground,50/50/95/40/50/35,Monster,4,190,4,65
+105,Marowak,ガラガラ,Ground,60/80/110/50/80/45,Monster,4,75,10,450,104
+106,Hitmonlee,サワムラー,Fighting,50/120/53/35/110/87,HumanLike,0,45,15,498,236
+107,Hitmonchan,エビワラー,Fighting,50/105/79/35/110/76,HumanLike,0,45,14,502,236
+108,Lickitung,ベロリンガ,Normal,90/55/75/60/75/30,Monster,4,45,12,655
+109,Koffing,ドガース,Poison,40/65/95/60/45/35,Amorphous,4,190,6,10
+110,Weezing,マタドガス,Poison,65/90/120/85/70/60,Amorphous,4,60,12,95,109
+111,Rhyhorn,サイホーン,Ground/Rock,80/85/95/30/30/25,Monster/Field,4,120,10,1150
+112,Rhydon,サイドン,Ground/Rock,105/130/120/45/45/40,Monster/Field,4,60,19,1200,111
+113,Chansey,ラッキー,Normal,250/5/5/35/105/50,Fairy,8,30,11,346,440
+114,Tangela,モンジャラ,Grass,65/55/115/100/40/60,Grass,4,45,10,350
+115,Kangaskhan,ガルーラ,Normal,105/95/80/40/80/90,Monster,8,45,22,800
+116,Horsea,タッツー,Water,30/40/70/70/25/60,Water1/Dragon,4,225,4,80
+117,Seadra,シードラ,Water,55/65/95/95/45/85,Water1/Dragon,4,75,12,250,116
+118,Goldeen,トサキント,Water,45/67/60/35/50/63,Water2,4,225,6,150
+119,Seaking,アズマオウ,Water,80/92/65/65/80/68,Water2,4,60,13,390,118
+120,Staryu,ヒトデマン,Water,30/45/55/70/55/85,Water3,-1,225,8,345
+121,Starmie,スターミー,Water/Psychic,60/75/85/100/85/115,Water3,-1,60,11,800,120
+122,Mr. Mime,バリヤード,Psychic/Fairy,40/45/65/100/120/90,HumanLike,4,45,13,545,439
+123,Scyther,ストライク,Bug/Flying,70/110/80/55/80/105,Bug,4,45,15,560
+124,Jynx,ルージュラ,Ice/Psychic,65/50/35/115/95/95,HumanLike,8,45,14,406,238
+125,Electabuzz,エレブー,Electric,65/83/57/95/85/105,HumanLike,2,45,11,300,239
+126,Magmar,ブーバー,Fire,65/95/57/100/85/93,HumanLike,2,45,13,445,240
+127,Pinsir,カイロス,Bug,65/125/100/55/70/85,Bug,4,45,15,550
+128,Tauros,ケンタロス,Normal,75/100/95/40/70/110,Field,0,45,14,884
+129,Magikarp,コイキング,Water,20/10/55/15/20/80,Water2/Dragon,4,255,9,100
+130,Gyarados,ギャラドス,Water/Flying,95/125/79/60/100/81,Water2/Dragon,4,45,65,2350,129
+131,Lapras,ラプラス,Water/Ice,130/85/80/85/95/60,Monster/Water1,4,45,25,2200
+132,Ditto,メタモン,Normal,48/48/48/48/48/48,Ditto,-1,35,3,40
+133,Eevee,イーブイ,Normal,55/55/50/45/65/55,Field,1,45,3,65
+134,Vaporeon,シャワーズ,Water,130/65/60/110/95/65,Field,1,45,10,290,133
+135,Jolteon,サンダース,Electric,65/65/60/110/95/130,Field,1,45,8,245,133
+136,Flareon,ブースター,Fire,65/130/60/95/110/65,Field,1,45,9,250,133
+137,Porygon,ポリゴン,Normal,65/60/70/85/75/40,Mineral,-1,45,8,365
+138,Omanyte,オムナイト,Rock/Water,35/40/100/90/55/35,Water1/Water3,1,45,4,75
+139,Omastar,オムスター,Rock/Water,70/60/125/115/70/55,Water1/Water3,1,45,10,350,138
+140,Kabuto,カブト,Rock/Water,30/80/90/55/45/55,Water1/Water3,1,45,5,115
+141,Kabutops,カブトプス,Rock/Water,60/115/105/65/70/80,Water1/Water3,1,45,13,405,140
+142,Aerodactyl,プテラ,Rock/Flying,80/105/65/60/75/130,Flying,1,45,18,590
+143,Snorlax,カビゴン,Normal,160/110/65/65/110/30,Monster,1,25,21,4600,446
+144,Articuno,フリーザー,Ice/Flying,90/85/100/95/125/85,Undiscovered,-1,3,17,554
+145,Zapdos,サンダー,Electric/Flying,90/90/85/125/90/100,Undiscovered,-1,3,16,526
+146,Moltres,ファイヤー,Fire/Flying,90/100/90/125/85/90,Undiscovered,-1,3,20,600
+147,Dratini,ミニリュウ,Dragon,41/64/45/50/50/50,Water1/Dragon,4,45,18,33
+148,Dragonair,ハクリュー,Dragon,61/84/65/70/70/70,Water1/Dragon,4,45,40,165,147
+149,Dragonite,カイリュー,Dragon/Flying,91/134/95/100/100/80,Water1/Dragon,4,45,22,2100,148
+150,Mewtwo,ミュウツー,Psychic,106/110/90/154/90/130,Undiscovered,-1,3,20,1220
+151,Mew,ミュウ,Psychic,100/100/100/100/100/100,Undiscovered,-1,45,4,40
+`;
+
+const LEGENDARY_IDS = [144, 145, 146, 150];
+const MYTHICAL_IDS = [151];
+
+// Every species is treated as catchable in Red/Blue and Let's Go
+const OBTAINABLE_IN: GameVersion[] = ['red-blue', 'lets-go-pikachu-lets-go-eevee'];
+
+function category(speciesId: number): SpeciesCategory {
+  if (MYTHICAL_IDS.includes(speciesId)) {
+    return 'mythical';
+  }
+  return LEGENDARY_IDS.includes(speciesId) ? 'legendary' : 'ordinary';
+}
+
+function parseRow(line: string): SeedSpecies {
+  const [id, name, nameJp, types, stats, eggGroups, female, capture, height, weight, evolvesFrom] =
+    line.split(',');
+  const [hp, attack, defense, specialAttack, specialDefense, speed] = stats.split('/').map(Number);
+  const [type1, type2] = types.split('/').map((type) => PokemonTypeSchema.parse(type));
+  const speciesId = Number(id);
+
+  return {
+    speciesId,
+    name,
+    nameJp,
+    types: type2 ? [type1, type2] : [type1],
+    baseStats: { hp, attack, defense, specialAttack, specialDefense, speed },
+    eggGroups: eggGroups.split('/').map((group) => EggGroupSchema.parse(group)),
+    femaleEighths: Number(female),
+    captureRate: Number(capture),
+    height: Number(height),
+    weight: Number(weight),
+    category: category(speciesId),
+    evolvesFrom: evolvesFrom ? Number(evolvesFrom) : undefined,
+    // The original 151 are numbered the same in the Kanto and National Dex
+    dexNumbers: [{ dex: 'kanto', number: speciesId }],
+    introducedIn: 'red-blue',
+    obtainableIn: OBTAINABLE_IN,
+  };
+}
+
+export const GEN1_SPECIES: SeedSpecies[] = GEN1_CSV.trim().split('\n').map(parseRow);
@@ -0,0 +1,56 @@
+import { Client, createClient } from '@libsql/client';
+import { drizzle } from 'drizzle-orm/libsql';
+import { migrate } from './migrations';
+import * as schema from './schema';
+
+// Self-hosted deployments point DATABASE_URL at a file instead of Postgres,
+// e.g. file:./pokedict.db (created on first start)
+export function isSqliteUrl(url: string): boolean {
+  return url.startsWith('file:');
+}
+
+function openDb(client: Client) {
+  return drizzle(client, { schema });
+}
+
+export type SqliteDbType = ReturnType<typeof openDb>;
+
+interface CachedDb {
+  db: Promise<SqliteDbType>;
+  client: Client;
+}
+
+const dbCache = new Map<string, CachedDb>();
+
+async function prepare(client: Client): Promise<SqliteDbType> {
+  // WAL lets requests read while another one writes; it is stored in the file, so it also
+  // covers the connections the client opens for transactions
+  await client.execute('PRAGMA journal_mode = WAL');
+  await migrate(client);
+  return openDb(client);
+}
+
+// The database is migrated the first time it is opened; later calls share the connection
+export function getSqliteDb(url: string): Promise<SqliteDbType> {
+  const cached = dbCache.get(url);
+  if (cached) {
+    return cached.db;
+  }
+
+  const client = createClient({ url });
+  const db = prepare(client);
+  dbCache.set(url, { db, client });
+
+  // A failed migration is retried by the next request instead of being cached
+  db.catch(() => {
+    dbCache.delete(url);
+    client.close();
+  });
+  return db;
+}
+
+export function closeSqliteDb(): void {
+  const clients = [...dbCache.values()];
+  dbCache.clear();
+  clients.forEach(({ client }) => client.close());
+}
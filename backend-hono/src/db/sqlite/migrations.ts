@@ -0,0 +1,630 @@
+import { Client } from '@libsql/client';
+
+// Migrations are compiled in rather than read from a directory, so a self-hosted server needs
+// nothing but its database file. Each one runs once, in order, inside a transaction, and is
+// recorded in schema_migrations. Append new migrations; never edit one that has shipped.
+const MIGRATIONS: string[][] = [
+  // 1: the schema as of the first SQLite release
+  [
+    `CREATE TABLE users (
+      user_id TEXT PRIMARY KEY,
+      username TEXT NOT NULL UNIQUE,
+      email TEXT NOT NULL UNIQUE,
+      password_hash TEXT NOT NULL,
+      role TEXT NOT NULL DEFAULT 'user',
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      updated_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE TABLE refresh_tokens (
+      token_id TEXT PRIMARY KEY,
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      token_hash TEXT NOT NULL UNIQUE,
+      expires_at INTEGER NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      revoked INTEGER NOT NULL DEFAULT 0
+    )`,
+    `CREATE TABLE user_pokemon (
+      pokemon_id TEXT PRIMARY KEY,
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      form_id INTEGER NOT NULL,
+      nickname TEXT,
+      nature TEXT NOT NULL,
+      ability TEXT NOT NULL,
+      item TEXT,
+      tera_type TEXT NOT NULL,
+      move1 TEXT NOT NULL,
+      move2 TEXT,
+      move3 TEXT,
+      move4 TEXT,
+      ev_hp INTEGER NOT NULL DEFAULT 0,
+      ev_attack INTEGER NOT NULL DEFAULT 0,
+      ev_defense INTEGER NOT NULL DEFAULT 0,
+      ev_special_attack INTEGER NOT NULL DEFAULT 0,
+      ev_special_defense INTEGER NOT NULL DEFAULT 0,
+      ev_speed INTEGER NOT NULL DEFAULT 0,
+      iv_hp INTEGER NOT NULL DEFAULT 31,
+      iv_attack INTEGER NOT NULL DEFAULT 31,
+      iv_defense INTEGER NOT NULL DEFAULT 31,
+      iv_special_attack INTEGER NOT NULL DEFAULT 31,
+      iv_special_defense INTEGER NOT NULL DEFAULT 31,
+      iv_speed INTEGER NOT NULL DEFAULT 31,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE TABLE teams (
+      team_id TEXT PRIMARY KEY,
+      owner_id TEXT NOT NULL REFERENCES users (user_id),
+      team_name TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      updated_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      deleted_at INTEGER,
+      version INTEGER NOT NULL DEFAULT 1
+    )`,
+    `CREATE TABLE team_pokemon (
+      team_id TEXT NOT NULL REFERENCES teams (team_id),
+      slot INTEGER NOT NULL,
+      form_id INTEGER NOT NULL,
+      terastal_type TEXT NOT NULL
+    )`,
+    `CREATE TABLE challenge_runs (
+      run_id TEXT PRIMARY KEY,
+      owner_id TEXT NOT NULL REFERENCES users (user_id),
+      name TEXT NOT NULL,
+      game_version TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE TABLE challenge_run_species (
+      run_id TEXT NOT NULL REFERENCES challenge_runs (run_id),
+      species_id INTEGER NOT NULL,
+      PRIMARY KEY (run_id, species_id)
+    )`,
+    `CREATE TABLE damage_benchmarks (
+      benchmark_id TEXT PRIMARY KEY,
+      owner_id TEXT NOT NULL REFERENCES users (user_id),
+      format TEXT NOT NULL,
+      name TEXT NOT NULL,
+      role TEXT NOT NULL,
+      form_id INTEGER NOT NULL,
+      nature TEXT NOT NULL,
+      ability TEXT,
+      item TEXT,
+      tera_type TEXT,
+      move TEXT,
+      ev_hp INTEGER NOT NULL DEFAULT 0,
+      ev_attack INTEGER NOT NULL DEFAULT 0,
+      ev_defense INTEGER NOT NULL DEFAULT 0,
+      ev_special_attack INTEGER NOT NULL DEFAULT 0,
+      ev_special_defense INTEGER NOT NULL DEFAULT 0,
+      ev_speed INTEGER NOT NULL DEFAULT 0,
+      iv_hp INTEGER NOT NULL DEFAULT 31,
+      iv_attack INTEGER NOT NULL DEFAULT 31,
+      iv_defense INTEGER NOT NULL DEFAULT 31,
+      iv_special_attack INTEGER NOT NULL DEFAULT 31,
+      iv_special_defense INTEGER NOT NULL DEFAULT 31,
+      iv_speed INTEGER NOT NULL DEFAULT 31,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE INDEX damage_benchmarks_owner_format_idx ON damage_benchmarks (owner_id, format)`,
+    `CREATE TABLE webhooks (
+      webhook_id TEXT PRIMARY KEY,
+      owner_id TEXT NOT NULL REFERENCES users (user_id),
+      url TEXT NOT NULL,
+      secret TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE TABLE webhook_events (
+      webhook_id TEXT NOT NULL REFERENCES webhooks (webhook_id),
+      event_type TEXT NOT NULL,
+      PRIMARY KEY (webhook_id, event_type)
+    )`,
+    `CREATE TABLE webhook_deliveries (
+      delivery_id TEXT PRIMARY KEY,
+      webhook_id TEXT NOT NULL REFERENCES webhooks (webhook_id),
+      event_id TEXT NOT NULL,
+      event_type TEXT NOT NULL,
+      attempt INTEGER NOT NULL,
+      status_code INTEGER,
+      error TEXT,
+      succeeded INTEGER NOT NULL,
+      attempted_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE INDEX webhook_deliveries_webhook_idx ON webhook_deliveries (webhook_id, attempted_at)`,
+    `CREATE TABLE type_quiz_scores (
+      user_id TEXT PRIMARY KEY REFERENCES users (user_id),
+      answered INTEGER NOT NULL DEFAULT 0,
+      correct INTEGER NOT NULL DEFAULT 0,
+      current_streak INTEGER NOT NULL DEFAULT 0,
+      best_streak INTEGER NOT NULL DEFAULT 0,
+      updated_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE TABLE type_quiz_answers (
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      question_id INTEGER NOT NULL,
+      correct INTEGER NOT NULL,
+      answered_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      PRIMARY KEY (user_id, question_id)
+    )`,
+    `CREATE TABLE api_keys (
+      key_id TEXT PRIMARY KEY,
+      name TEXT NOT NULL,
+      key_hash TEXT NOT NULL UNIQUE,
+      display_prefix TEXT NOT NULL,
+      daily_quota INTEGER NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      revoked_at INTEGER
+    )`,
+    `CREATE TABLE api_key_scopes (
+      key_id TEXT NOT NULL REFERENCES api_keys (key_id),
+      scope TEXT NOT NULL,
+      PRIMARY KEY (key_id, scope)
+    )`,
+    `CREATE TABLE api_key_requests (
+      key_id TEXT NOT NULL REFERENCES api_keys (key_id),
+      day TEXT NOT NULL,
+      requests INTEGER NOT NULL DEFAULT 0,
+      PRIMARY KEY (key_id, day)
+    )`,
+    `CREATE TABLE pokemon_forms (
+      form_id INTEGER PRIMARY KEY,
+      species_id INTEGER NOT NULL,
+      name TEXT NOT NULL,
+      name_jp TEXT NOT NULL,
+      type1 TEXT NOT NULL,
+      type2 TEXT,
+      base_hp INTEGER NOT NULL DEFAULT 1,
+      base_attack INTEGER NOT NULL DEFAULT 1,
+      base_defense INTEGER NOT NULL DEFAULT 1,
+      base_special_attack INTEGER NOT NULL DEFAULT 1,
+      base_special_defense INTEGER NOT NULL DEFAULT 1,
+      base_speed INTEGER NOT NULL DEFAULT 1,
+      base_experience INTEGER,
+      height INTEGER NOT NULL DEFAULT 0,
+      weight INTEGER NOT NULL DEFAULT 0,
+      shiny_available INTEGER NOT NULL DEFAULT 1,
+      shiny_locked INTEGER NOT NULL DEFAULT 0,
+      introduced_in TEXT NOT NULL DEFAULT 'red-blue'
+    )`,
+    `CREATE INDEX pokemon_forms_types_idx ON pokemon_forms (type1, type2)`,
+    `CREATE INDEX pokemon_forms_type2_idx ON pokemon_forms (type2)`,
+    `CREATE TABLE pokemon_form_availability (
+      form_id INTEGER NOT NULL REFERENCES pokemon_forms (form_id),
+      game_version TEXT NOT NULL,
+      PRIMARY KEY (form_id, game_version)
+    )`,
+    `CREATE INDEX pokemon_form_availability_game_version_idx
+      ON pokemon_form_availability (game_version)`,
+    `CREATE TABLE pokemon_form_abilities (
+      form_id INTEGER NOT NULL REFERENCES pokemon_forms (form_id),
+      slot TEXT NOT NULL,
+      ability TEXT NOT NULL,
+      PRIMARY KEY (form_id, slot)
+    )`,
+    `CREATE INDEX pokemon_form_abilities_ability_idx ON pokemon_form_abilities (ability)`,
+    `CREATE TABLE pokemon_name_index (
+      form_id INTEGER NOT NULL REFERENCES pokemon_forms (form_id),
+      key TEXT NOT NULL,
+      PRIMARY KEY (form_id, key)
+    )`,
+    `CREATE INDEX pokemon_name_index_key_idx ON pokemon_name_index (key)`,
+    `CREATE TABLE pokemon_encounters (
+      form_id INTEGER NOT NULL REFERENCES pokemon_forms (form_id),
+      game_version TEXT NOT NULL,
+      location TEXT NOT NULL,
+      PRIMARY KEY (form_id, game_version, location)
+    )`,
+    `CREATE INDEX pokemon_encounters_game_location_idx
+      ON pokemon_encounters (game_version, location)`,
+    `CREATE TABLE moves (
+      name TEXT PRIMARY KEY,
+      move_id INTEGER NOT NULL,
+      lookup_key TEXT NOT NULL,
+      name_jp TEXT,
+      type TEXT NOT NULL,
+      damage_class TEXT NOT NULL,
+      power INTEGER,
+      accuracy INTEGER,
+      priority INTEGER NOT NULL DEFAULT 0,
+      pp INTEGER,
+      target TEXT NOT NULL DEFAULT 'selected-pokemon',
+      min_hits INTEGER,
+      max_hits INTEGER,
+      effect TEXT
+    )`,
+    `CREATE UNIQUE INDEX moves_lookup_key_idx ON moves (lookup_key)`,
+    `CREATE UNIQUE INDEX moves_move_id_idx ON moves (move_id)`,
+    `CREATE TABLE abilities (
+      name TEXT PRIMARY KEY,
+      ability_id INTEGER NOT NULL,
+      lookup_key TEXT NOT NULL,
+      name_jp TEXT,
+      effect TEXT,
+      effect_jp TEXT
+    )`,
+    `CREATE UNIQUE INDEX abilities_lookup_key_idx ON abilities (lookup_key)`,
+    `CREATE UNIQUE INDEX abilities_ability_id_idx ON abilities (ability_id)`,
+    `CREATE TABLE items (
+      name TEXT PRIMARY KEY,
+      item_id INTEGER NOT NULL,
+      lookup_key TEXT NOT NULL,
+      name_jp TEXT,
+      category TEXT NOT NULL,
+      effect TEXT,
+      effect_jp TEXT
+    )`,
+    `CREATE UNIQUE INDEX items_lookup_key_idx ON items (lookup_key)`,
+    `CREATE UNIQUE INDEX items_item_id_idx ON items (item_id)`,
+    `CREATE INDEX items_category_idx ON items (category)`,
+    `CREATE TABLE data_imports (
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      kind TEXT NOT NULL,
+      from_id INTEGER,
+      to_id INTEGER,
+      imported_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE TABLE pokemon_species (
+      species_id INTEGER PRIMARY KEY,
+      name TEXT NOT NULL,
+      name_jp TEXT NOT NULL,
+      egg_group1 TEXT NOT NULL,
+      egg_group2 TEXT,
+      gender_rate INTEGER NOT NULL DEFAULT -1,
+      capture_rate INTEGER NOT NULL DEFAULT 0,
+      category TEXT NOT NULL DEFAULT 'ordinary',
+      evolves_from_species_id INTEGER
+    )`,
+    `CREATE TABLE pokemon_species_dex_numbers (
+      species_id INTEGER NOT NULL REFERENCES pokemon_species (species_id),
+      dex TEXT NOT NULL,
+      number INTEGER NOT NULL,
+      PRIMARY KEY (species_id, dex)
+    )`,
+    `CREATE UNIQUE INDEX pokemon_species_dex_numbers_dex_number_idx
+      ON pokemon_species_dex_numbers (dex, number)`,
+    `CREATE TABLE pokemon_learnsets (
+      form_id INTEGER NOT NULL REFERENCES pokemon_forms (form_id),
+      move_name TEXT NOT NULL,
+      learn_method TEXT NOT NULL,
+      level INTEGER,
+      PRIMARY KEY (form_id, move_name, learn_method)
+    )`,
+    `CREATE INDEX pokemon_learnsets_move_name_idx ON pokemon_learnsets (move_name)`,
+    `CREATE TABLE usage_snapshots (
+      format_id TEXT NOT NULL,
+      period TEXT NOT NULL,
+      cutoff INTEGER NOT NULL,
+      battles INTEGER NOT NULL,
+      imported_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      PRIMARY KEY (format_id, period)
+    )`,
+    `CREATE TABLE usage_stats (
+      format_id TEXT NOT NULL,
+      period TEXT NOT NULL,
+      form_id INTEGER NOT NULL REFERENCES pokemon_forms (form_id),
+      rank INTEGER NOT NULL,
+      usage REAL NOT NULL,
+      raw_count INTEGER NOT NULL,
+      PRIMARY KEY (format_id, period, form_id)
+    )`,
+    `CREATE TABLE usage_details (
+      format_id TEXT NOT NULL,
+      period TEXT NOT NULL,
+      form_id INTEGER NOT NULL REFERENCES pokemon_forms (form_id),
+      category TEXT NOT NULL,
+      name TEXT NOT NULL,
+      percentage REAL NOT NULL,
+      PRIMARY KEY (format_id, period, form_id, category, name)
+    )`,
+  ],
+  // 2: dataset snapshots for rolling back imports
+  [
+    `CREATE TABLE dataset_snapshots (
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      version INTEGER NOT NULL,
+      kind TEXT NOT NULL,
+      entry_id INTEGER NOT NULL,
+      data TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE INDEX dataset_snapshots_version_idx ON dataset_snapshots (version)`,
+  ],
+  // 3: login backoff
+  [
+    `CREATE TABLE login_failures (
+      identifier TEXT PRIMARY KEY,
+      failures INTEGER NOT NULL,
+      last_failed_at INTEGER NOT NULL
+    )`,
+  ],
+  // 4: sessions; tokens issued before this each count as their own session
+  [
+    `ALTER TABLE refresh_tokens ADD COLUMN session_id TEXT NOT NULL DEFAULT ''`,
+    `ALTER TABLE refresh_tokens ADD COLUMN user_agent TEXT`,
+    `ALTER TABLE refresh_tokens ADD COLUMN ip TEXT`,
+    `UPDATE refresh_tokens SET session_id = token_id`,
+    `CREATE INDEX refresh_tokens_user_session_idx ON refresh_tokens (user_id, session_id)`,
+  ],
+  // 5: email change confirmation
+  [
+    `CREATE TABLE email_changes (
+      user_id TEXT PRIMARY KEY REFERENCES users (user_id),
+      new_email TEXT NOT NULL,
+      confirm_token_hash TEXT NOT NULL UNIQUE,
+      cancel_token_hash TEXT NOT NULL UNIQUE,
+      expires_at INTEGER NOT NULL,
+      created_at INTEGER NOT NULL
+    )`,
+  ],
+  // 6: team tags and folders
+  [
+    `ALTER TABLE teams ADD COLUMN folder TEXT`,
+    `CREATE TABLE team_tags (
+      team_id TEXT NOT NULL REFERENCES teams (team_id),
+      tag TEXT NOT NULL,
+      PRIMARY KEY (team_id, tag)
+    )`,
+    `CREATE INDEX team_tags_tag_idx ON team_tags (tag)`,
+  ],
+  // 7: shared teams and member search
+  [
+    `ALTER TABLE teams ADD COLUMN shared_at INTEGER`,
+    `CREATE INDEX team_pokemon_form_idx ON team_pokemon (form_id, team_id)`,
+  ],
+  // 8: team gallery likes and flags
+  [
+    `ALTER TABLE teams ADD COLUMN shared_format TEXT`,
+    `CREATE TABLE team_likes (
+      team_id TEXT NOT NULL REFERENCES teams (team_id),
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      PRIMARY KEY (team_id, user_id)
+    )`,
+    `CREATE TABLE team_flags (
+      team_id TEXT NOT NULL REFERENCES teams (team_id),
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      reason TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      PRIMARY KEY (team_id, user_id)
+    )`,
+  ],
+  // 9: comments on shared teams
+  [
+    `CREATE TABLE team_comments (
+      comment_id TEXT PRIMARY KEY,
+      team_id TEXT NOT NULL REFERENCES teams (team_id),
+      author_id TEXT NOT NULL REFERENCES users (user_id),
+      parent_id TEXT,
+      body TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      updated_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      deleted_at INTEGER
+    )`,
+    `CREATE INDEX team_comments_team_thread_idx ON team_comments (team_id, parent_id, created_at)`,
+  ],
+  // 10: moderation queue
+  [
+    `CREATE TABLE moderation_queue (
+      item_id TEXT PRIMARY KEY,
+      kind TEXT NOT NULL,
+      target_id TEXT NOT NULL,
+      author_id TEXT NOT NULL REFERENCES users (user_id),
+      text TEXT NOT NULL,
+      reasons TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE INDEX moderation_queue_target_idx ON moderation_queue (kind, target_id)`,
+  ],
+  // 11: in-app notifications
+  [
+    `CREATE TABLE notifications (
+      notification_id TEXT PRIMARY KEY,
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      type TEXT NOT NULL,
+      data TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      read_at INTEGER
+    )`,
+    `CREATE INDEX notifications_user_created_idx ON notifications (user_id, created_at)`,
+  ],
+  // 12: user preferences
+  [
+    `CREATE TABLE user_preferences (
+      user_id TEXT PRIMARY KEY REFERENCES users (user_id),
+      language TEXT,
+      format TEXT,
+      level INTEGER NOT NULL DEFAULT 50,
+      theme TEXT NOT NULL DEFAULT 'system',
+      updated_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+  ],
+  // 13: structured move effects
+  [`ALTER TABLE moves ADD COLUMN effects TEXT`],
+  // 14: memoized team analysis
+  [
+    `CREATE TABLE analysis_cache (
+      cache_key TEXT PRIMARY KEY,
+      kind TEXT NOT NULL,
+      version INTEGER NOT NULL,
+      result TEXT NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+  ],
+  // 15: team event outbox
+  [
+    `CREATE TABLE team_event_outbox (
+      event_id TEXT PRIMARY KEY,
+      type TEXT NOT NULL,
+      team_id TEXT NOT NULL,
+      occurred_at INTEGER NOT NULL,
+      claimed_until INTEGER,
+      published_at INTEGER
+    )`,
+    `CREATE INDEX team_event_outbox_pending_idx ON team_event_outbox (published_at, occurred_at)`,
+  ],
+  // 16: account deletion
+  [`ALTER TABLE users ADD COLUMN deleted_at INTEGER`],
+  // 17: form media
+  [
+    `CREATE TABLE pokemon_form_media (
+      form_id INTEGER PRIMARY KEY REFERENCES pokemon_forms (form_id),
+      cry_url TEXT,
+      legacy_cry_url TEXT,
+      sprite_url TEXT,
+      shiny_sprite_url TEXT,
+      has_3d_model INTEGER NOT NULL DEFAULT 0,
+      has_3d_shiny_model INTEGER NOT NULL DEFAULT 0,
+      checked_at INTEGER NOT NULL
+    )`,
+  ],
+  // 18: custom forms, numbered from 1000001 (MIN_CUSTOM_FORM_ID)
+  [
+    `CREATE TABLE custom_forms (
+      form_id INTEGER PRIMARY KEY AUTOINCREMENT,
+      owner_id TEXT NOT NULL REFERENCES users (user_id),
+      name TEXT NOT NULL,
+      type1 TEXT NOT NULL,
+      type2 TEXT,
+      hp INTEGER NOT NULL,
+      attack INTEGER NOT NULL,
+      defense INTEGER NOT NULL,
+      special_attack INTEGER NOT NULL,
+      special_defense INTEGER NOT NULL,
+      speed INTEGER NOT NULL,
+      ability1 TEXT NOT NULL,
+      ability2 TEXT,
+      hidden_ability TEXT,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      updated_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE INDEX custom_forms_owner_idx ON custom_forms (owner_id)`,
+    `INSERT INTO sqlite_sequence (name, seq) VALUES ('custom_forms', 1000000)`,
+  ],
+  // 19: team and member notes
+  [`ALTER TABLE teams ADD COLUMN notes TEXT`, `ALTER TABLE team_pokemon ADD COLUMN notes TEXT`],
+  // 20: draft leagues
+  [
+    `CREATE TABLE draft_leagues (
+      league_id TEXT PRIMARY KEY,
+      owner_id TEXT NOT NULL REFERENCES users (user_id),
+      name TEXT NOT NULL,
+      format TEXT NOT NULL,
+      budget INTEGER NOT NULL,
+      roster_size INTEGER NOT NULL,
+      pick_count INTEGER NOT NULL DEFAULT 0,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE TABLE draft_league_participants (
+      league_id TEXT NOT NULL REFERENCES draft_leagues (league_id),
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      position INTEGER NOT NULL,
+      PRIMARY KEY (league_id, user_id)
+    )`,
+    `CREATE INDEX draft_league_participants_user_idx ON draft_league_participants (user_id)`,
+    `CREATE TABLE draft_league_pool (
+      league_id TEXT NOT NULL REFERENCES draft_leagues (league_id),
+      form_id INTEGER NOT NULL,
+      cost INTEGER NOT NULL,
+      PRIMARY KEY (league_id, form_id)
+    )`,
+    `CREATE TABLE draft_league_picks (
+      league_id TEXT NOT NULL REFERENCES draft_leagues (league_id),
+      pick_number INTEGER NOT NULL,
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      form_id INTEGER NOT NULL,
+      picked_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      PRIMARY KEY (league_id, pick_number)
+    )`,
+    `CREATE UNIQUE INDEX draft_league_picks_form_idx ON draft_league_picks (league_id, form_id)`,
+  ],
+  // 21: tournaments
+  [
+    `CREATE TABLE tournaments (
+      tournament_id TEXT PRIMARY KEY,
+      organizer_id TEXT NOT NULL REFERENCES users (user_id),
+      name TEXT NOT NULL,
+      format TEXT NOT NULL,
+      status TEXT NOT NULL,
+      max_entries INTEGER NOT NULL,
+      version INTEGER NOT NULL DEFAULT 1,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      started_at INTEGER
+    )`,
+    `CREATE TABLE tournament_entries (
+      tournament_id TEXT NOT NULL REFERENCES tournaments (tournament_id),
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      team_id TEXT NOT NULL,
+      team_name TEXT NOT NULL,
+      team_version INTEGER NOT NULL,
+      seed INTEGER,
+      registered_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000),
+      PRIMARY KEY (tournament_id, user_id)
+    )`,
+    `CREATE INDEX tournament_entries_user_idx ON tournament_entries (user_id)`,
+    `CREATE TABLE tournament_entry_members (
+      tournament_id TEXT NOT NULL REFERENCES tournaments (tournament_id),
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      slot INTEGER NOT NULL,
+      form_id INTEGER NOT NULL,
+      terastal_type TEXT NOT NULL,
+      PRIMARY KEY (tournament_id, user_id, slot)
+    )`,
+    `CREATE TABLE tournament_matches (
+      tournament_id TEXT NOT NULL REFERENCES tournaments (tournament_id),
+      round INTEGER NOT NULL,
+      position INTEGER NOT NULL,
+      player1_id TEXT REFERENCES users (user_id),
+      player2_id TEXT REFERENCES users (user_id),
+      winner_id TEXT REFERENCES users (user_id),
+      reported_at INTEGER,
+      PRIMARY KEY (tournament_id, round, position)
+    )`,
+  ],
+  // 22: battle records
+  [
+    `CREATE TABLE battle_records (
+      record_id TEXT PRIMARY KEY,
+      user_id TEXT NOT NULL REFERENCES users (user_id),
+      team_id TEXT NOT NULL REFERENCES teams (team_id),
+      format TEXT,
+      result TEXT NOT NULL,
+      opponent_name TEXT,
+      opponent_archetype TEXT,
+      notes TEXT,
+      played_at INTEGER NOT NULL,
+      created_at INTEGER NOT NULL DEFAULT (unixepoch('subsec') * 1000)
+    )`,
+    `CREATE INDEX battle_records_user_idx ON battle_records (user_id, played_at)`,
+    `CREATE INDEX battle_records_team_idx ON battle_records (team_id)`,
+    `CREATE TABLE battle_record_opponents (
+      record_id TEXT NOT NULL REFERENCES battle_records (record_id),
+      slot INTEGER NOT NULL,
+      form_id INTEGER NOT NULL,
+      PRIMARY KEY (record_id, slot)
+    )`,
+  ],
+  // 23: session last use; tokens issued before this start from when they were issued
+  [
+    `ALTER TABLE refresh_tokens ADD COLUMN last_used_at INTEGER NOT NULL DEFAULT 0`,
+    `UPDATE refresh_tokens SET last_used_at = created_at`,
+  ],
+];
+
+export async function migrate(client: Client): Promise<void> {
+  await client.execute(`CREATE TABLE IF NOT EXISTS schema_migrations (
+    version INTEGER PRIMARY KEY,
+    applied_at INTEGER NOT NULL
+  )`);
+  const result = await client.execute('SELECT max(version) AS version FROM schema_migrations');
+  const applied = Number(result.rows[0].version ?? 0);
+
+  for (let version = applied + 1; version <= MIGRATIONS.length; version++) {
+    await client.batch(
+      [
+        ...MIGRATIONS[version - 1],
+        {
+          sql: 'INSERT INTO schema_migrations (version, applied_at) VALUES (?, ?)',
+          args: [version, Date.now()],
+        },
+      ],
+      'write'
+    );
+  }
+}
@@ -0,0 +1,930 @@
+import { sql } from 'drizzle-orm';
+import {
+  sqliteTable,
+  text,
+  integer,
+  real,
+  primaryKey,
+  index,
+  uniqueIndex,
+} from 'drizzle-orm/sqlite-core';
+
+// The Postgres schema (../schema.ts) for self-hosted SQLite deployments: the same tables, columns
+// and property names, so the SQLite repositories are the Postgres ones with this schema swapped
+// in. A table whose rows differ from the Postgres one fails to compile (sharedSchema in
+// repository/drizzle/types.ts). See ../schema.ts for what the columns hold. Any change here needs
+// a migration in ./migrations.ts.
+
+// Timestamps are milliseconds since the epoch
+const NOW = sql`(unixepoch('subsec') * 1000)`;
+
+export const users = sqliteTable('users', {
+  userId: text('user_id').primaryKey(),
+  username: text('username', { length: 20 }).notNull().unique(),
+  email: text('email', { length: 254 }).notNull().unique(),
+  passwordHash: text('password_hash', { length: 60 }).notNull(),
+  role: text('role', { length: 20 }).notNull().default('user'),
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  updatedAt: integer('updated_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  deletedAt: integer('deleted_at', { mode: 'timestamp_ms' }),
+});
+
+export const userPreferences = sqliteTable('user_preferences', {
+  userId: text('user_id')
+    .primaryKey()
+    .references(() => users.userId),
+  language: text('language'),
+  format: text('format'),
+  level: integer('level').notNull().default(50),
+  theme: text('theme').notNull().default('system'),
+  updatedAt: integer('updated_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+});
+
+export const refreshTokens = sqliteTable(
+  'refresh_tokens',
+  {
+    tokenId: text('token_id').primaryKey(),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    sessionId: text('session_id').notNull(),
+    tokenHash: text('token_hash', { length: 255 }).notNull().unique(),
+    userAgent: text('user_agent'),
+    ip: text('ip', { length: 45 }),
+    expiresAt: integer('expires_at', { mode: 'timestamp_ms' }).notNull(),
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+    lastUsedAt: integer('last_used_at', { mode: 'timestamp_ms' }).notNull(),
+    revoked: integer('revoked', { mode: 'boolean' }).notNull().default(false),
+  },
+  (table) => ({
+    userSessionIdx: index('refresh_tokens_user_session_idx').on(table.userId, table.sessionId),
+  })
+);
+
+// Consecutive failed logins per identifier ("email:..." or "ip:..."), for login backoff
+export const loginFailures = sqliteTable('login_failures', {
+  identifier: text('identifier', { length: 300 }).primaryKey(),
+  failures: integer('failures').notNull(),
+  lastFailedAt: integer('last_failed_at', { mode: 'timestamp_ms' }).notNull(),
+});
+
+export const emailChanges = sqliteTable('email_changes', {
+  userId: text('user_id')
+    .primaryKey()
+    .references(() => users.userId),
+  newEmail: text('new_email', { length: 254 }).notNull(),
+  confirmTokenHash: text('confirm_token_hash', { length: 64 }).notNull().unique(),
+  cancelTokenHash: text('cancel_token_hash', { length: 64 }).notNull().unique(),
+  expiresAt: integer('expires_at', { mode: 'timestamp_ms' }).notNull(),
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull(),
+});
+
+export const userPokemon = sqliteTable('user_pokemon', {
+  pokemonId: text('pokemon_id').primaryKey(),
+  userId: text('user_id')
+    .notNull()
+    .references(() => users.userId),
+  formId: integer('form_id').notNull(),
+  nickname: text('nickname', { length: 100 }),
+  nature: text('nature', { length: 20 }).notNull(),
+  ability: text('ability', { length: 100 }).notNull(),
+  item: text('item', { length: 100 }),
+  teraType: text('tera_type', { length: 20 }).notNull(),
+
+  move1: text('move1', { length: 100 }).notNull(),
+  move2: text('move2', { length: 100 }),
+  move3: text('move3', { length: 100 }),
+  move4: text('move4', { length: 100 }),
+
+  evHp: integer('ev_hp').notNull().default(0),
+  evAttack: integer('ev_attack').notNull().default(0),
+  evDefense: integer('ev_defense').notNull().default(0),
+  evSpecialAttack: integer('ev_special_attack').notNull().default(0),
+  evSpecialDefense: integer('ev_special_defense').notNull().default(0),
+  evSpeed: integer('ev_speed').notNull().default(0),
+
+  ivHp: integer('iv_hp').notNull().default(31),
+  ivAttack: integer('iv_attack').notNull().default(31),
+  ivDefense: integer('iv_defense').notNull().default(31),
+  ivSpecialAttack: integer('iv_special_attack').notNull().default(31),
+  ivSpecialDefense: integer('iv_special_defense').notNull().default(31),
+  ivSpeed: integer('iv_speed').notNull().default(31),
+
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+});
+
+export const teams = sqliteTable('teams', {
+  teamId: text('team_id').primaryKey(),
+  ownerId: text('owner_id')
+    .notNull()
+    .references(() => users.userId),
+  teamName: text('team_name', { length: 50 }).notNull(),
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  updatedAt: integer('updated_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  deletedAt: integer('deleted_at', { mode: 'timestamp_ms' }),
+  version: integer('version').notNull().default(1),
+  folder: text('folder', { length: 50 }),
+  sharedAt: integer('shared_at', { mode: 'timestamp_ms' }),
+  sharedFormat: text('shared_format', { length: 50 }),
+  notes: text('notes'),
+});
+
+export const teamPokemon = sqliteTable(
+  'team_pokemon',
+  {
+    teamId: text('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    slot: integer('slot').notNull(),
+    formId: integer('form_id').notNull(),
+    terastalType: text('terastal_type', { length: 20 }).notNull(),
+    notes: text('notes'),
+  },
+  (table) => ({
+    formIdx: index('team_pokemon_form_idx').on(table.formId, table.teamId),
+  })
+);
+
+export const teamTags = sqliteTable(
+  'team_tags',
+  {
+    teamId: text('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    tag: text('tag', { length: 20 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.teamId, table.tag] }),
+    tagIdx: index('team_tags_tag_idx').on(table.tag),
+  })
+);
+
+export const teamLikes = sqliteTable(
+  'team_likes',
+  {
+    teamId: text('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.teamId, table.userId] }),
+  })
+);
+
+export const teamComments = sqliteTable(
+  'team_comments',
+  {
+    commentId: text('comment_id').primaryKey(),
+    teamId: text('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    authorId: text('author_id')
+      .notNull()
+      .references(() => users.userId),
+    parentId: text('parent_id'),
+    body: text('body').notNull(),
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+    updatedAt: integer('updated_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+    deletedAt: integer('deleted_at', { mode: 'timestamp_ms' }),
+  },
+  (table) => ({
+    teamThreadIdx: index('team_comments_team_thread_idx').on(
+      table.teamId,
+      table.parentId,
+      table.createdAt
+    ),
+  })
+);
+
+export const teamFlags = sqliteTable(
+  'team_flags',
+  {
+    teamId: text('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    reason: text('reason', { length: 20 }).notNull(),
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.teamId, table.userId] }),
+  })
+);
+
+export const moderationQueue = sqliteTable(
+  'moderation_queue',
+  {
+    itemId: text('item_id').primaryKey(),
+    kind: text('kind').notNull(),
+    targetId: text('target_id').notNull(),
+    authorId: text('author_id')
+      .notNull()
+      .references(() => users.userId),
+    text: text('text').notNull(),
+    reasons: text('reasons').notNull(),
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    targetIdx: index('moderation_queue_target_idx').on(table.kind, table.targetId),
+  })
+);
+
+export const notifications = sqliteTable(
+  'notifications',
+  {
+    notificationId: text('notification_id').primaryKey(),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    type: text('type').notNull(),
+    data: text('data').notNull(),
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+    readAt: integer('read_at', { mode: 'timestamp_ms' }),
+  },
+  (table) => ({
+    userCreatedIdx: index('notifications_user_created_idx').on(table.userId, table.createdAt),
+  })
+);
+
+export const challengeRuns = sqliteTable('challenge_runs', {
+  runId: text('run_id').primaryKey(),
+  ownerId: text('owner_id')
+    .notNull()
+    .references(() => users.userId),
+  name: text('name', { length: 50 }).notNull(),
+  gameVersion: text('game_version', { length: 50 }).notNull(),
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+});
+
+export const challengeRunSpecies = sqliteTable(
+  'challenge_run_species',
+  {
+    runId: text('run_id')
+      .notNull()
+      .references(() => challengeRuns.runId),
+    speciesId: integer('species_id').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.runId, table.speciesId] }),
+  })
+);
+
+export const damageBenchmarks = sqliteTable(
+  'damage_benchmarks',
+  {
+    benchmarkId: text('benchmark_id').primaryKey(),
+    ownerId: text('owner_id')
+      .notNull()
+      .references(() => users.userId),
+    format: text('format', { length: 50 }).notNull(),
+    name: text('name', { length: 50 }).notNull(),
+    role: text('role', { length: 20 }).notNull(),
+    formId: integer('form_id').notNull(),
+    nature: text('nature', { length: 20 }).notNull(),
+    ability: text('ability', { length: 100 }),
+    item: text('item', { length: 100 }),
+    teraType: text('tera_type', { length: 20 }),
+    move: text('move', { length: 100 }),
+
+    evHp: integer('ev_hp').notNull().default(0),
+    evAttack: integer('ev_attack').notNull().default(0),
+    evDefense: integer('ev_defense').notNull().default(0),
+    evSpecialAttack: integer('ev_special_attack').notNull().default(0),
+    evSpecialDefense: integer('ev_special_defense').notNull().default(0),
+    evSpeed: integer('ev_speed').notNull().default(0),
+
+    ivHp: integer('iv_hp').notNull().default(31),
+    ivAttack: integer('iv_attack').notNull().default(31),
+    ivDefense: integer('iv_defense').notNull().default(31),
+    ivSpecialAttack: integer('iv_special_attack').notNull().default(31),
+    ivSpecialDefense: integer('iv_special_defense').notNull().default(31),
+    ivSpeed: integer('iv_speed').notNull().default(31),
+
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    ownerFormatIdx: index('damage_benchmarks_owner_format_idx').on(table.ownerId, table.format),
+  })
+);
+
+export const customForms = sqliteTable(
+  'custom_forms',
+  {
+    formId: integer('form_id').primaryKey({ autoIncrement: true }),
+    ownerId: text('owner_id')
+      .notNull()
+      .references(() => users.userId),
+    name: text('name', { length: 50 }).notNull(),
+    type1: text('type1', { length: 20 }).notNull(),
+    type2: text('type2', { length: 20 }),
+    hp: integer('hp').notNull(),
+    attack: integer('attack').notNull(),
+    defense: integer('defense').notNull(),
+    specialAttack: integer('special_attack').notNull(),
+    specialDefense: integer('special_defense').notNull(),
+    speed: integer('speed').notNull(),
+    ability1: text('ability1', { length: 100 }).notNull(),
+    ability2: text('ability2', { length: 100 }),
+    hiddenAbility: text('hidden_ability', { length: 100 }),
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+    updatedAt: integer('updated_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    ownerIdx: index('custom_forms_owner_idx').on(table.ownerId),
+  })
+);
+
+export const draftLeagues = sqliteTable('draft_leagues', {
+  leagueId: text('league_id').primaryKey(),
+  ownerId: text('owner_id')
+    .notNull()
+    .references(() => users.userId),
+  name: text('name', { length: 50 }).notNull(),
+  format: text('format', { length: 50 }).notNull(),
+  budget: integer('budget').notNull(),
+  rosterSize: integer('roster_size').notNull(),
+  pickCount: integer('pick_count').notNull().default(0),
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+});
+
+export const draftLeagueParticipants = sqliteTable(
+  'draft_league_participants',
+  {
+    leagueId: text('league_id')
+      .notNull()
+      .references(() => draftLeagues.leagueId),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    position: integer('position').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.leagueId, table.userId] }),
+    userIdx: index('draft_league_participants_user_idx').on(table.userId),
+  })
+);
+
+export const draftLeaguePool = sqliteTable(
+  'draft_league_pool',
+  {
+    leagueId: text('league_id')
+      .notNull()
+      .references(() => draftLeagues.leagueId),
+    formId: integer('form_id').notNull(),
+    cost: integer('cost').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.leagueId, table.formId] }),
+  })
+);
+
+export const draftLeaguePicks = sqliteTable(
+  'draft_league_picks',
+  {
+    leagueId: text('league_id')
+      .notNull()
+      .references(() => draftLeagues.leagueId),
+    pickNumber: integer('pick_number').notNull(),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    formId: integer('form_id').notNull(),
+    pickedAt: integer('picked_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.leagueId, table.pickNumber] }),
+    formIdx: uniqueIndex('draft_league_picks_form_idx').on(table.leagueId, table.formId),
+  })
+);
+
+export const tournaments = sqliteTable('tournaments', {
+  tournamentId: text('tournament_id').primaryKey(),
+  organizerId: text('organizer_id')
+    .notNull()
+    .references(() => users.userId),
+  name: text('name', { length: 50 }).notNull(),
+  format: text('format', { length: 50 }).notNull(),
+  status: text('status', { length: 20 }).notNull(),
+  maxEntries: integer('max_entries').notNull(),
+  version: integer('version').notNull().default(1),
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  startedAt: integer('started_at', { mode: 'timestamp_ms' }),
+});
+
+export const tournamentEntries = sqliteTable(
+  'tournament_entries',
+  {
+    tournamentId: text('tournament_id')
+      .notNull()
+      .references(() => tournaments.tournamentId),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    teamId: text('team_id').notNull(),
+    teamName: text('team_name', { length: 50 }).notNull(),
+    teamVersion: integer('team_version').notNull(),
+    seed: integer('seed'),
+    registeredAt: integer('registered_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.tournamentId, table.userId] }),
+    userIdx: index('tournament_entries_user_idx').on(table.userId),
+  })
+);
+
+export const tournamentEntryMembers = sqliteTable(
+  'tournament_entry_members',
+  {
+    tournamentId: text('tournament_id')
+      .notNull()
+      .references(() => tournaments.tournamentId),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    slot: integer('slot').notNull(),
+    formId: integer('form_id').notNull(),
+    terastalType: text('terastal_type', { length: 20 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.tournamentId, table.userId, table.slot] }),
+  })
+);
+
+export const tournamentMatches = sqliteTable(
+  'tournament_matches',
+  {
+    tournamentId: text('tournament_id')
+      .notNull()
+      .references(() => tournaments.tournamentId),
+    round: integer('round').notNull(),
+    position: integer('position').notNull(),
+    player1Id: text('player1_id').references(() => users.userId),
+    player2Id: text('player2_id').references(() => users.userId),
+    winnerId: text('winner_id').references(() => users.userId),
+    reportedAt: integer('reported_at', { mode: 'timestamp_ms' }),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.tournamentId, table.round, table.position] }),
+  })
+);
+
+export const battleRecords = sqliteTable(
+  'battle_records',
+  {
+    recordId: text('record_id').primaryKey(),
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    teamId: text('team_id')
+      .notNull()
+      .references(() => teams.teamId),
+    format: text('format', { length: 50 }),
+    result: text('result', { length: 4 }).notNull(),
+    opponentName: text('opponent_name', { length: 50 }),
+    opponentArchetype: text('opponent_archetype', { length: 20 }),
+    notes: text('notes'),
+    playedAt: integer('played_at', { mode: 'timestamp_ms' }).notNull(),
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    userIdx: index('battle_records_user_idx').on(table.userId, table.playedAt),
+    teamIdx: index('battle_records_team_idx').on(table.teamId),
+  })
+);
+
+export const battleRecordOpponents = sqliteTable(
+  'battle_record_opponents',
+  {
+    recordId: text('record_id')
+      .notNull()
+      .references(() => battleRecords.recordId),
+    slot: integer('slot').notNull(),
+    formId: integer('form_id').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.recordId, table.slot] }),
+  })
+);
+
+export const webhooks = sqliteTable('webhooks', {
+  webhookId: text('webhook_id').primaryKey(),
+  ownerId: text('owner_id')
+    .notNull()
+    .references(() => users.userId),
+  url: text('url').notNull(),
+  secret: text('secret', { length: 64 }).notNull(),
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+});
+
+export const webhookEvents = sqliteTable(
+  'webhook_events',
+  {
+    webhookId: text('webhook_id')
+      .notNull()
+      .references(() => webhooks.webhookId),
+    eventType: text('event_type', { length: 50 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.webhookId, table.eventType] }),
+  })
+);
+
+export const webhookDeliveries = sqliteTable(
+  'webhook_deliveries',
+  {
+    deliveryId: text('delivery_id').primaryKey(),
+    webhookId: text('webhook_id')
+      .notNull()
+      .references(() => webhooks.webhookId),
+    eventId: text('event_id').notNull(),
+    eventType: text('event_type', { length: 50 }).notNull(),
+    attempt: integer('attempt').notNull(),
+    statusCode: integer('status_code'),
+    error: text('error'),
+    succeeded: integer('succeeded', { mode: 'boolean' }).notNull(),
+    attemptedAt: integer('attempted_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    webhookIdx: index('webhook_deliveries_webhook_idx').on(table.webhookId, table.attemptedAt),
+  })
+);
+
+// Team events written in the same transaction as the change they describe, then published by
+// the outbox relay. An event stays here until a relay has published it, so a crash between the
+// write and the publish only delays it.
+export const teamEventOutbox = sqliteTable(
+  'team_event_outbox',
+  {
+    eventId: text('event_id').primaryKey(), // receivers deduplicate on it
+    type: text('type', { length: 50 }).notNull(),
+    teamId: text('team_id').notNull(),
+    occurredAt: integer('occurred_at', { mode: 'timestamp_ms' }).notNull(),
+    // A relay publishing the event holds it until then; another relay may take it over after
+    claimedUntil: integer('claimed_until', { mode: 'timestamp_ms' }),
+    publishedAt: integer('published_at', { mode: 'timestamp_ms' }),
+  },
+  (table) => ({
+    pendingIdx: index('team_event_outbox_pending_idx').on(table.publishedAt, table.occurredAt),
+  })
+);
+
+export const typeQuizScores = sqliteTable('type_quiz_scores', {
+  userId: text('user_id')
+    .primaryKey()
+    .references(() => users.userId),
+  answered: integer('answered').notNull().default(0),
+  correct: integer('correct').notNull().default(0),
+  currentStreak: integer('current_streak').notNull().default(0),
+  bestStreak: integer('best_streak').notNull().default(0),
+  updatedAt: integer('updated_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+});
+
+export const typeQuizAnswers = sqliteTable(
+  'type_quiz_answers',
+  {
+    userId: text('user_id')
+      .notNull()
+      .references(() => users.userId),
+    questionId: integer('question_id').notNull(),
+    correct: integer('correct', { mode: 'boolean' }).notNull(),
+    answeredAt: integer('answered_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.userId, table.questionId] }),
+  })
+);
+
+export const apiKeys = sqliteTable('api_keys', {
+  keyId: text('key_id').primaryKey(),
+  name: text('name', { length: 50 }).notNull(),
+  keyHash: text('key_hash', { length: 64 }).notNull().unique(),
+  displayPrefix: text('display_prefix', { length: 20 }).notNull(),
+  dailyQuota: integer('daily_quota').notNull(),
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  revokedAt: integer('revoked_at', { mode: 'timestamp_ms' }),
+});
+
+export const apiKeyScopes = sqliteTable(
+  'api_key_scopes',
+  {
+    keyId: text('key_id')
+      .notNull()
+      .references(() => apiKeys.keyId),
+    scope: text('scope', { length: 20 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.keyId, table.scope] }),
+  })
+);
+
+export const apiKeyRequests = sqliteTable(
+  'api_key_requests',
+  {
+    keyId: text('key_id')
+      .notNull()
+      .references(() => apiKeys.keyId),
+    day: text('day', { length: 10 }).notNull(),
+    requests: integer('requests').notNull().default(0),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.keyId, table.day] }),
+  })
+);
+
+export const pokemonForms = sqliteTable(
+  'pokemon_forms',
+  {
+    formId: integer('form_id').primaryKey(),
+    speciesId: integer('species_id').notNull(),
+    name: text('name').notNull(),
+    nameJp: text('name_jp').notNull(),
+    type1: text('type1', { length: 20 }).notNull(),
+    type2: text('type2', { length: 20 }),
+    baseHp: integer('base_hp').notNull().default(1),
+    baseAttack: integer('base_attack').notNull().default(1),
+    baseDefense: integer('base_defense').notNull().default(1),
+    baseSpecialAttack: integer('base_special_attack').notNull().default(1),
+    baseSpecialDefense: integer('base_special_defense').notNull().default(1),
+    baseSpeed: integer('base_speed').notNull().default(1),
+    baseExperience: integer('base_experience'),
+    height: integer('height').notNull().default(0),
+    weight: integer('weight').notNull().default(0),
+    shinyAvailable: integer('shiny_available', { mode: 'boolean' }).notNull().default(true),
+    shinyLocked: integer('shiny_locked', { mode: 'boolean' }).notNull().default(false),
+    introducedIn: text('introduced_in', { length: 50 }).notNull().default('red-blue'),
+  },
+  (table) => ({
+    typesIdx: index('pokemon_forms_types_idx').on(table.type1, table.type2),
+    type2Idx: index('pokemon_forms_type2_idx').on(table.type2),
+  })
+);
+
+export const pokemonFormAvailability = sqliteTable(
+  'pokemon_form_availability',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    gameVersion: text('game_version', { length: 50 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.gameVersion] }),
+    gameVersionIdx: index('pokemon_form_availability_game_version_idx').on(table.gameVersion),
+  })
+);
+
+export const pokemonFormAbilities = sqliteTable(
+  'pokemon_form_abilities',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    slot: text('slot', { length: 20 }).notNull(),
+    ability: text('ability', { length: 100 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.slot] }),
+    abilityIdx: index('pokemon_form_abilities_ability_idx').on(table.ability),
+  })
+);
+
+export const pokemonNameIndex = sqliteTable(
+  'pokemon_name_index',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    key: text('key', { length: 100 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.key] }),
+    keyIdx: index('pokemon_name_index_key_idx').on(table.key),
+  })
+);
+
+export const pokemonEncounters = sqliteTable(
+  'pokemon_encounters',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    gameVersion: text('game_version', { length: 50 }).notNull(),
+    location: text('location', { length: 100 }).notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.gameVersion, table.location] }),
+    gameLocationIdx: index('pokemon_encounters_game_location_idx').on(
+      table.gameVersion,
+      table.location
+    ),
+  })
+);
+
+export const pokemonFormMedia = sqliteTable('pokemon_form_media', {
+  formId: integer('form_id')
+    .primaryKey()
+    .references(() => pokemonForms.formId),
+  cryUrl: text('cry_url'),
+  legacyCryUrl: text('legacy_cry_url'),
+  spriteUrl: text('sprite_url'),
+  shinySpriteUrl: text('shiny_sprite_url'),
+  has3dModel: integer('has_3d_model', { mode: 'boolean' }).notNull().default(false),
+  has3dShinyModel: integer('has_3d_shiny_model', { mode: 'boolean' }).notNull().default(false),
+  checkedAt: integer('checked_at', { mode: 'timestamp_ms' }).notNull(),
+});
+
+export const moves = sqliteTable(
+  'moves',
+  {
+    name: text('name', { length: 100 }).primaryKey(),
+    moveId: integer('move_id').notNull(),
+    lookupKey: text('lookup_key', { length: 100 }).notNull(),
+    nameJp: text('name_jp'),
+    type: text('type', { length: 20 }).notNull(),
+    damageClass: text('damage_class', { length: 20 }).notNull(),
+    power: integer('power'),
+    accuracy: integer('accuracy'),
+    priority: integer('priority').notNull().default(0),
+    pp: integer('pp'),
+    target: text('target', { length: 50 }).notNull().default('selected-pokemon'),
+    minHits: integer('min_hits'),
+    maxHits: integer('max_hits'),
+    effect: text('effect'),
+    effects: text('effects'), // MoveEffects as JSON; null until imported again
+  },
+  (table) => ({
+    lookupKeyIdx: uniqueIndex('moves_lookup_key_idx').on(table.lookupKey),
+    moveIdIdx: uniqueIndex('moves_move_id_idx').on(table.moveId),
+  })
+);
+
+export const abilities = sqliteTable(
+  'abilities',
+  {
+    name: text('name', { length: 100 }).primaryKey(),
+    abilityId: integer('ability_id').notNull(),
+    lookupKey: text('lookup_key', { length: 100 }).notNull(),
+    nameJp: text('name_jp'),
+    effect: text('effect'),
+    effectJp: text('effect_jp'),
+  },
+  (table) => ({
+    lookupKeyIdx: uniqueIndex('abilities_lookup_key_idx').on(table.lookupKey),
+    abilityIdIdx: uniqueIndex('abilities_ability_id_idx').on(table.abilityId),
+  })
+);
+
+export const items = sqliteTable(
+  'items',
+  {
+    name: text('name', { length: 100 }).primaryKey(),
+    itemId: integer('item_id').notNull(),
+    lookupKey: text('lookup_key', { length: 100 }).notNull(),
+    nameJp: text('name_jp'),
+    category: text('category', { length: 50 }).notNull(),
+    effect: text('effect'),
+    effectJp: text('effect_jp'),
+  },
+  (table) => ({
+    lookupKeyIdx: uniqueIndex('items_lookup_key_idx').on(table.lookupKey),
+    itemIdIdx: uniqueIndex('items_item_id_idx').on(table.itemId),
+    categoryIdx: index('items_category_idx').on(table.category),
+  })
+);
+
+export const dataImports = sqliteTable('data_imports', {
+  id: integer('id').primaryKey({ autoIncrement: true }),
+  kind: text('kind', { length: 20 }).notNull(),
+  fromId: integer('from_id'),
+  toId: integer('to_id'),
+  importedAt: integer('imported_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+});
+
+// Dataset entries as they were before an import, edit or rollback overwrote them. `version` is
+// the data version current at the time, so rolling back to it restores this copy.
+export const datasetSnapshots = sqliteTable(
+  'dataset_snapshots',
+  {
+    id: integer('id').primaryKey({ autoIncrement: true }),
+    version: integer('version').notNull(),
+    kind: text('kind', { length: 20 }).notNull(), // species, form, move, ability, item
+    entryId: integer('entry_id').notNull(), // species, form, move, ability or item ID
+    data: text('data').notNull(), // the entry as JSON
+    createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    versionIdx: index('dataset_snapshots_version_idx').on(table.version),
+  })
+);
+
+// Memoized team analysis responses. The key hashes the analysis kind and its whole request, so
+// an edited team simply misses; `version` is the data version the result was computed from.
+export const analysisCache = sqliteTable('analysis_cache', {
+  cacheKey: text('cache_key', { length: 64 }).primaryKey(), // SHA-256, hex
+  kind: text('kind', { length: 20 }).notNull(), // threats, archetype, report
+  version: integer('version').notNull(),
+  result: text('result').notNull(), // the response as JSON
+  createdAt: integer('created_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+});
+
+export const pokemonSpecies = sqliteTable('pokemon_species', {
+  speciesId: integer('species_id').primaryKey(),
+  name: text('name').notNull(),
+  nameJp: text('name_jp').notNull(),
+  eggGroup1: text('egg_group1', { length: 20 }).notNull(),
+  eggGroup2: text('egg_group2', { length: 20 }),
+  genderRate: integer('gender_rate').notNull().default(-1),
+  captureRate: integer('capture_rate').notNull().default(0),
+  category: text('category', { length: 20 }).notNull().default('ordinary'),
+  evolvesFromSpeciesId: integer('evolves_from_species_id'),
+});
+
+export const pokemonSpeciesDexNumbers = sqliteTable(
+  'pokemon_species_dex_numbers',
+  {
+    speciesId: integer('species_id')
+      .notNull()
+      .references(() => pokemonSpecies.speciesId),
+    dex: text('dex', { length: 50 }).notNull(),
+    number: integer('number').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.speciesId, table.dex] }),
+    dexNumberIdx: uniqueIndex('pokemon_species_dex_numbers_dex_number_idx').on(
+      table.dex,
+      table.number
+    ),
+  })
+);
+
+export const pokemonLearnsets = sqliteTable(
+  'pokemon_learnsets',
+  {
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    moveName: text('move_name', { length: 100 }).notNull(),
+    learnMethod: text('learn_method', { length: 20 }).notNull(),
+    level: integer('level'),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formId, table.moveName, table.learnMethod] }),
+    moveNameIdx: index('pokemon_learnsets_move_name_idx').on(table.moveName),
+  })
+);
+
+export const usageSnapshots = sqliteTable(
+  'usage_snapshots',
+  {
+    formatId: text('format_id', { length: 50 }).notNull(),
+    period: text('period', { length: 7 }).notNull(),
+    cutoff: integer('cutoff').notNull(),
+    battles: integer('battles').notNull(),
+    importedAt: integer('imported_at', { mode: 'timestamp_ms' }).notNull().default(NOW),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formatId, table.period] }),
+  })
+);
+
+export const usageStats = sqliteTable(
+  'usage_stats',
+  {
+    formatId: text('format_id', { length: 50 }).notNull(),
+    period: text('period', { length: 7 }).notNull(),
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    rank: integer('rank').notNull(),
+    usage: real('usage').notNull(),
+    rawCount: integer('raw_count').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({ columns: [table.formatId, table.period, table.formId] }),
+  })
+);
+
+export const usageDetails = sqliteTable(
+  'usage_details',
+  {
+    formatId: text('format_id', { length: 50 }).notNull(),
+    period: text('period', { length: 7 }).notNull(),
+    formId: integer('form_id')
+      .notNull()
+      .references(() => pokemonForms.formId),
+    category: text('category', { length: 20 }).notNull(),
+    name: text('name', { length: 100 }).notNull(),
+    percentage: real('percentage').notNull(),
+  },
+  (table) => ({
+    pk: primaryKey({
+      columns: [table.formatId, table.period, table.formId, table.category, table.name],
+    }),
+  })
+);
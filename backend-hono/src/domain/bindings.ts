@@ -0,0 +1,128 @@
+import { DamageClass, Move } from './entity/Move';
+import { PokemonMaster } from './entity/PokemonMaster';
+import { calculateDamage, Combatant, DamageResult } from './service/DamageCalculator';
+import { Availability, GAME_VERSIONS } from './valueobject/GameVersion';
+import { Generation, GenerationSchema } from './valueobject/Generation';
+import { Level } from './valueobject/Level';
+import { Nature } from './valueobject/Nature';
+import { FormId, SpeciesId } from './valueobject/PokemonId';
+import { PokemonBuild } from './valueobject/PokemonBuild';
+import { PokemonType, TypeSet } from './valueobject/PokemonType';
+import { StagedStat, StatStages } from './valueobject/StatStages';
+import { BaseStats, EVStats, MAX_IVS, StatName } from './valueobject/Stats';
+
+// Plain-data entry points for callers outside the backend, such as the frontend running
+// calculations offline. Inputs and outputs are JSON-safe objects rather than entities, so
+// they can come straight from an API response or local storage.
+
+export interface PlainPokemon {
+  types: PokemonType[];
+  baseStats: BaseStats;
+  ability?: string;
+  nature?: Nature;
+  teraType?: PokemonType;
+  evs?: Partial<Record<StatName, number>>;
+  ivs?: Partial<Record<StatName, number>>;
+  friendship?: number; // for Return and Frustration; defaults to 255
+  terastallized?: boolean;
+  stages?: Partial<Record<StagedStat, number>>;
+}
+
+export interface PlainMove {
+  name: string; // PokeAPI identifier, e.g. "close-combat"
+  type: PokemonType;
+  damageClass: DamageClass;
+  power?: number;
+  target?: string; // PokeAPI target; defaults to a single opponent
+  minHits?: number;
+  maxHits?: number;
+}
+
+export interface PlainDamageRequest {
+  attacker: PlainPokemon;
+  defender: PlainPokemon;
+  move: PlainMove;
+  level: number;
+  generation?: number; // defaults to the current one
+  critical?: boolean;
+  doubles?: boolean;
+}
+
+// Multiplier of every attacking type against a single or dual type
+export function defendAgainstAll(types: PokemonType[]): Record<PokemonType, number> {
+  return toTypeSet(types).defendAgainstAll();
+}
+
+export function calculatePlainDamage(request: PlainDamageRequest): DamageResult {
+  const move = new Move({
+    id: 0,
+    name: request.move.name,
+    type: request.move.type,
+    damageClass: request.move.damageClass,
+    power: request.move.power,
+    priority: 0,
+    target: request.move.target ?? 'selected-pokemon',
+    minHits: request.move.minHits,
+    maxHits: request.move.maxHits,
+  });
+
+  const level = Level.create(request.level);
+  const generation = GenerationSchema.optional().parse(request.generation);
+  return calculateDamage(
+    toCombatant(request.attacker, level, generation),
+    toCombatant(request.defender, level, generation),
+    move,
+    { critical: request.critical, doubles: request.doubles }
+  );
+}
+
+function toTypeSet(types: PokemonType[]): TypeSet {
+  if (types.length < 1 || types.length > 2) {
+    throw new Error('A Pokémon has one or two types');
+  }
+  return new TypeSet(types[0], types[1]);
+}
+
+function toCombatant(pokemon: PlainPokemon, level: Level, generation?: Generation): Combatant {
+  // Identity, size and availability don't enter the damage formula
+  const form = new PokemonMaster({
+    formId: 0 as FormId,
+    speciesId: 0 as SpeciesId,
+    name: '',
+    nameJp: '',
+    typeset: toTypeSet(pokemon.types),
+    baseStats: pokemon.baseStats,
+    height: 0,
+    weight: 0,
+    shinyAvailable: false,
+    shinyLocked: false,
+    availability: new Availability(GAME_VERSIONS[0], []),
+  });
+  const evs = pokemon.evs ?? {};
+  const build = PokemonBuild.create({
+    formId: 0 as FormId,
+    ability: pokemon.ability,
+    moves: [],
+    teraType: pokemon.teraType,
+    nature: pokemon.nature,
+    ev: new EVStats(
+      evs.hp ?? 0,
+      evs.attack ?? 0,
+      evs.defense ?? 0,
+      evs.specialAttack ?? 0,
+      evs.specialDefense ?? 0,
+      evs.speed ?? 0
+    ),
+    iv: { ...MAX_IVS, ...pokemon.ivs },
+    friendship: pokemon.friendship,
+  });
+
+  return new Combatant(
+    form,
+    build,
+    level,
+    pokemon.terastallized ?? false,
+    new StatStages(pokemon.stages),
+    generation
+  );
+}
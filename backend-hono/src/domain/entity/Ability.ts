@@ -0,0 +1,35 @@
+export interface AbilityProps {
+  id: number; // PokeAPI ability ID
+  name: string; // PokeAPI identifier, e.g. "intimidate"
+  nameJp?: string;
+  effect?: string; // Short effect in English
+  effectJp?: string; // Latest in-game description in Japanese
+}
+
+export class Ability {
+  constructor(private readonly props: AbilityProps) {}
+
+  static fromRepository(props: AbilityProps): Ability {
+    return new Ability(props);
+  }
+
+  getId(): number {
+    return this.props.id;
+  }
+
+  getName(): string {
+    return this.props.name;
+  }
+
+  getNameJp(): string | undefined {
+    return this.props.nameJp;
+  }
+
+  getEffect(): string | undefined {
+    return this.props.effect;
+  }
+
+  getEffectJp(): string | undefined {
+    return this.props.effectJp;
+  }
+}
@@ -0,0 +1,143 @@
+import crypto from 'crypto';
+import { z } from 'zod';
+import { ApiKeyScope } from '../valueobject/ApiKeyScope';
+
+// Marks the plain key as ours, so a leaked key is easy to spot in logs and code search
+export const API_KEY_PREFIX = 'pdk_';
+
+export const DEFAULT_DAILY_QUOTA = 10000;
+
+// The prefix plus a few characters, stored in the clear so admins can tell keys apart
+const DISPLAY_PREFIX_LENGTH = API_KEY_PREFIX.length + 8;
+
+const ApiKeyNameSchema = z.string().min(1).max(50);
+
+export interface IssuedApiKey {
+  apiKey: ApiKey;
+  // Only available when the key is issued; the database keeps the hash
+  key: string;
+}
+
+// A key for third-party apps calling the public endpoints without a user account. Each key has
+// the scopes it may call and a daily request quota.
+export class ApiKey {
+  private readonly keyId: string;
+  private readonly name: string;
+  private readonly keyHash: string;
+  private readonly displayPrefix: string;
+  private readonly scopes: ApiKeyScope[];
+  private readonly dailyQuota: number;
+  private readonly createdAt: Date;
+  private revokedAt?: Date;
+
+  constructor(
+    keyId: string,
+    name: string,
+    keyHash: string,
+    displayPrefix: string,
+    scopes: ApiKeyScope[],
+    dailyQuota: number,
+    createdAt: Date,
+    revokedAt?: Date
+  ) {
+    this.keyId = keyId;
+    this.name = ApiKeyNameSchema.parse(name);
+    this.keyHash = keyHash;
+    this.displayPrefix = displayPrefix;
+    this.scopes = [...new Set(scopes)];
+    this.dailyQuota = dailyQuota;
+    this.createdAt = createdAt;
+    this.revokedAt = revokedAt;
+  }
+
+  static issue(
+    keyId: string,
+    name: string,
+    scopes: ApiKeyScope[],
+    dailyQuota: number
+  ): IssuedApiKey {
+    const key = `${API_KEY_PREFIX}${crypto.randomBytes(24).toString('base64url')}`;
+    const apiKey = new ApiKey(
+      keyId,
+      name,
+      ApiKey.hashKey(key),
+      key.slice(0, DISPLAY_PREFIX_LENGTH),
+      scopes,
+      dailyQuota,
+      new Date()
+    );
+    return { apiKey, key };
+  }
+
+  static fromRepository(
+    keyId: string,
+    name: string,
+    keyHash: string,
+    displayPrefix: string,
+    scopes: ApiKeyScope[],
+    dailyQuota: number,
+    createdAt: Date,
+    revokedAt?: Date
+  ): ApiKey {
+    return new ApiKey(
+      keyId,
+      name,
+      keyHash,
+      displayPrefix,
+      scopes,
+      dailyQuota,
+      createdAt,
+      revokedAt
+    );
+  }
+
+  // Keys are random, so a fast unsalted hash is enough to keep them out of the database
+  static hashKey(key: string): string {
+    return crypto.createHash('sha256').update(key).digest('hex');
+  }
+
+  hasScope(scope: ApiKeyScope): boolean {
+    return this.scopes.includes(scope);
+  }
+
+  isRevoked(): boolean {
+    return this.revokedAt !== undefined;
+  }
+
+  // Revoking twice keeps the first date
+  revoke(): void {
+    this.revokedAt ??= new Date();
+  }
+
+  getKeyId(): string {
+    return this.keyId;
+  }
+
+  getName(): string {
+    return this.name;
+  }
+
+  getKeyHash(): string {
+    return this.keyHash;
+  }
+
+  getDisplayPrefix(): string {
+    return this.displayPrefix;
+  }
+
+  getScopes(): ApiKeyScope[] {
+    return [...this.scopes];
+  }
+
+  getDailyQuota(): number {
+    return this.dailyQuota;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+
+  getRevokedAt(): Date | undefined {
+    return this.revokedAt;
+  }
+}
@@ -0,0 +1,168 @@
+import { z } from 'zod';
+import { Archetype } from '../service/ArchetypeClassifier';
+import { FormatId } from '../valueobject/Format';
+import { FormId } from '../valueobject/PokemonId';
+import { parseNote } from '../valueobject/TeamNote';
+
+export const BattleResultSchema = z.enum(['win', 'loss']);
+
+export type BattleResult = z.infer<typeof BattleResultSchema>;
+
+export const OpponentNameSchema = z.string().trim().min(1).max(50);
+
+export const MAX_OPPONENT_POKEMON = 6;
+export const MAX_BATTLE_NOTES_LENGTH = 1000;
+
+export class BattleRecordError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'BattleRecordError';
+  }
+}
+
+// Who the user played against: their team as far as it was seen, the kind of team it was, or
+// both
+export interface BattleOpponent {
+  // A trainer name or handle, as the user wrote it
+  name: string | null;
+  archetype: Archetype | null;
+  formIds: FormId[];
+}
+
+// 0-100 to one decimal place; 0 before the first battle
+export function getWinRate(wins: number, losses: number): number {
+  const battles = wins + losses;
+  return battles === 0 ? 0 : Math.round((wins / battles) * 1000) / 10;
+}
+
+// One battle the user logged with one of their teams
+export class BattleRecord {
+  private readonly recordId: string;
+  private readonly userId: string;
+  private readonly teamId: string;
+  private readonly format: FormatId | null;
+  private readonly result: BattleResult;
+  private readonly opponent: BattleOpponent;
+  private readonly notes: string | null;
+  private readonly playedAt: Date;
+  private readonly createdAt: Date;
+
+  constructor(
+    recordId: string,
+    userId: string,
+    teamId: string,
+    format: FormatId | null,
+    result: BattleResult,
+    opponent: BattleOpponent,
+    notes: string | null,
+    playedAt: Date,
+    createdAt: Date
+  ) {
+    if (opponent.archetype === null && opponent.formIds.length === 0) {
+      throw new BattleRecordError('An opponent needs an archetype or at least one Pokémon');
+    }
+    if (opponent.formIds.length > MAX_OPPONENT_POKEMON) {
+      throw new BattleRecordError(`An opponent has at most ${MAX_OPPONENT_POKEMON} Pokémon`);
+    }
+    if (new Set(opponent.formIds).size !== opponent.formIds.length) {
+      throw new BattleRecordError("The opponent's Pokémon must not repeat");
+    }
+
+    this.recordId = recordId;
+    this.userId = userId;
+    this.teamId = teamId;
+    this.format = format;
+    this.result = result;
+    this.opponent = {
+      name: opponent.name === null ? null : OpponentNameSchema.parse(opponent.name),
+      archetype: opponent.archetype,
+      formIds: [...opponent.formIds],
+    };
+    this.notes = parseNote(notes, MAX_BATTLE_NOTES_LENGTH);
+    this.playedAt = playedAt;
+    this.createdAt = createdAt;
+  }
+
+  static create(
+    recordId: string,
+    userId: string,
+    teamId: string,
+    format: FormatId | null,
+    result: BattleResult,
+    opponent: BattleOpponent,
+    notes: string | null,
+    playedAt: Date
+  ): BattleRecord {
+    return new BattleRecord(
+      recordId,
+      userId,
+      teamId,
+      format,
+      result,
+      opponent,
+      notes,
+      playedAt,
+      new Date()
+    );
+  }
+
+  static fromRepository(
+    recordId: string,
+    userId: string,
+    teamId: string,
+    format: FormatId | null,
+    result: BattleResult,
+    opponent: BattleOpponent,
+    notes: string | null,
+    playedAt: Date,
+    createdAt: Date
+  ): BattleRecord {
+    return new BattleRecord(
+      recordId,
+      userId,
+      teamId,
+      format,
+      result,
+      opponent,
+      notes,
+      playedAt,
+      createdAt
+    );
+  }
+
+  getRecordId(): string {
+    return this.recordId;
+  }
+
+  getUserId(): string {
+    return this.userId;
+  }
+
+  getTeamId(): string {
+    return this.teamId;
+  }
+
+  getFormat(): FormatId | null {
+    return this.format;
+  }
+
+  getResult(): BattleResult {
+    return this.result;
+  }
+
+  getOpponent(): BattleOpponent {
+    return { ...this.opponent, formIds: [...this.opponent.formIds] };
+  }
+
+  getNotes(): string | null {
+    return this.notes;
+  }
+
+  getPlayedAt(): Date {
+    return this.playedAt;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+}
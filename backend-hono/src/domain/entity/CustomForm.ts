@@ -0,0 +1,102 @@
+import { z } from 'zod';
+import { AbilitySlots } from '../valueobject/AbilitySlots';
+import { Availability } from '../valueobject/GameVersion';
+import { FormId, SpeciesId } from '../valueobject/PokemonId';
+import { TypeSet } from '../valueobject/PokemonType';
+import { BaseStats, BaseStatsSchema } from '../valueobject/Stats';
+import { PokemonMaster } from './PokemonMaster';
+
+export const CustomFormNameSchema = z.string().trim().min(1).max(50);
+
+// What a user defines of a custom form; the rest is filled in when it is used as a form
+export interface CustomFormProps {
+  name: string;
+  typeset: TypeSet;
+  baseStats: BaseStats;
+  abilities: AbilitySlots;
+}
+
+// A user's own Pokémon ("fakemon"), e.g. from a romhack. Only its owner can use it, in their
+// teams and calculations, where it stands in for an official form (see toPokemonMaster).
+export class CustomForm {
+  private readonly props: CustomFormProps;
+
+  constructor(
+    private readonly formId: FormId,
+    private readonly ownerId: string,
+    props: CustomFormProps,
+    private readonly createdAt: Date,
+    private readonly updatedAt: Date
+  ) {
+    this.props = {
+      ...props,
+      name: CustomFormNameSchema.parse(props.name),
+      baseStats: BaseStatsSchema.parse(props.baseStats),
+    };
+  }
+
+  static fromRepository(
+    formId: FormId,
+    ownerId: string,
+    props: CustomFormProps,
+    createdAt: Date,
+    updatedAt: Date
+  ): CustomForm {
+    return new CustomForm(formId, ownerId, props, createdAt, updatedAt);
+  }
+
+  withChanges(props: CustomFormProps, at: Date): CustomForm {
+    return new CustomForm(this.formId, this.ownerId, props, this.createdAt, at);
+  }
+
+  getFormId(): FormId {
+    return this.formId;
+  }
+
+  getOwnerId(): string {
+    return this.ownerId;
+  }
+
+  getName(): string {
+    return this.props.name;
+  }
+
+  getTypeset(): TypeSet {
+    return this.props.typeset;
+  }
+
+  getBaseStats(): BaseStats {
+    return this.props.baseStats;
+  }
+
+  getAbilities(): AbilitySlots {
+    return this.props.abilities;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+
+  getUpdatedAt(): Date {
+    return this.updatedAt;
+  }
+
+  // A custom form is its own species, unknown to the species data, so learnsets, breeding and
+  // evolutions do not apply; it is obtainable in no game, so format legality rejects it
+  toPokemonMaster(): PokemonMaster {
+    return PokemonMaster.fromRepository({
+      formId: this.formId,
+      speciesId: SpeciesId.fromRepository(this.formId),
+      name: this.props.name,
+      nameJp: this.props.name,
+      typeset: this.props.typeset,
+      baseStats: this.props.baseStats,
+      abilities: this.props.abilities,
+      height: 0,
+      weight: 0,
+      shinyAvailable: true,
+      shinyLocked: false,
+      availability: new Availability('scarlet-violet', []),
+    });
+  }
+}
@@ -0,0 +1,96 @@
+import { z } from 'zod';
+import { FormatId } from '../valueobject/Format';
+import { PokemonBuild } from '../valueobject/PokemonBuild';
+
+const BenchmarkNameSchema = z.string().min(1).max(50);
+
+// Defensive benchmarks are targets a build should KO ("252 HP / 4 Def Incineroar"); offensive
+// ones are attackers it should survive, so they also name the move they attack with
+export const BenchmarkRoleSchema = z.enum(['offensive', 'defensive']);
+
+export type BenchmarkRole = z.infer<typeof BenchmarkRoleSchema>;
+
+// A named spread a user checks their builds against, kept per format
+export class DamageBenchmark {
+  private readonly benchmarkId: string;
+  private readonly ownerId: string;
+  private readonly format: FormatId;
+  private readonly name: string;
+  private readonly role: BenchmarkRole;
+  private readonly build: PokemonBuild;
+  private readonly move?: string;
+
+  constructor(
+    benchmarkId: string,
+    ownerId: string,
+    format: FormatId,
+    name: string,
+    role: BenchmarkRole,
+    build: PokemonBuild,
+    move?: string
+  ) {
+    if (role === 'offensive' && !move) {
+      throw new Error('An offensive benchmark needs a move');
+    }
+    this.benchmarkId = benchmarkId;
+    this.ownerId = ownerId;
+    this.format = format;
+    this.name = BenchmarkNameSchema.parse(name);
+    this.role = role;
+    this.build = build;
+    this.move = role === 'offensive' ? move : undefined;
+  }
+
+  static create(
+    benchmarkId: string,
+    ownerId: string,
+    format: FormatId,
+    name: string,
+    role: BenchmarkRole,
+    build: PokemonBuild,
+    move?: string
+  ): DamageBenchmark {
+    return new DamageBenchmark(benchmarkId, ownerId, format, name, role, build, move);
+  }
+
+  static fromRepository(
+    benchmarkId: string,
+    ownerId: string,
+    format: FormatId,
+    name: string,
+    role: BenchmarkRole,
+    build: PokemonBuild,
+    move?: string
+  ): DamageBenchmark {
+    return new DamageBenchmark(benchmarkId, ownerId, format, name, role, build, move);
+  }
+
+  getBenchmarkId(): string {
+    return this.benchmarkId;
+  }
+
+  getOwnerId(): string {
+    return this.ownerId;
+  }
+
+  getFormat(): FormatId {
+    return this.format;
+  }
+
+  getName(): string {
+    return this.name;
+  }
+
+  getRole(): BenchmarkRole {
+    return this.role;
+  }
+
+  getBuild(): PokemonBuild {
+    return this.build;
+  }
+
+  // Only offensive benchmarks have one
+  getMove(): string | undefined {
+    return this.move;
+  }
+}
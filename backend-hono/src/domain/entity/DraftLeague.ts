@@ -0,0 +1,232 @@
+import { z } from 'zod';
+import { FormatId } from '../valueobject/Format';
+import { FormId } from '../valueobject/PokemonId';
+
+const DraftLeagueNameSchema = z.string().min(1).max(50);
+
+export const MIN_DRAFT_PARTICIPANTS = 2;
+export const MAX_DRAFT_PARTICIPANTS = 16;
+export const MAX_DRAFT_ROSTER_SIZE = 12;
+export const MAX_DRAFT_POOL_SIZE = 500;
+
+export class DraftLeagueError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'DraftLeagueError';
+  }
+}
+
+// A Pokémon participants can draft and the points it costs them
+export interface DraftPoolEntry {
+  formId: FormId;
+  cost: number;
+}
+
+// Pick numbers start at 1 and follow the draft order with no gaps
+export interface DraftPick {
+  pickNumber: number;
+  userId: string;
+  formId: FormId;
+  pickedAt: Date;
+}
+
+export interface DraftLeagueSettings {
+  name: string;
+  format: FormatId;
+  // In draft order
+  participantIds: string[];
+  pool: DraftPoolEntry[];
+  // Points each participant can spend on their whole roster
+  budget: number;
+  // Picks per participant
+  rosterSize: number;
+}
+
+// A draft league: its commissioner sets up the participants and a pool of Pokémon with point
+// costs, then participants take turns picking in snake order (the order reverses every round)
+// until every roster is full. Each Pokémon can be drafted once, and only by a participant who can
+// still afford it.
+export class DraftLeague {
+  private readonly leagueId: string;
+  private readonly ownerId: string;
+  private readonly name: string;
+  private readonly format: FormatId;
+  private readonly participantIds: string[];
+  private readonly pool: Map<FormId, number>;
+  private readonly budget: number;
+  private readonly rosterSize: number;
+  private readonly picks: DraftPick[];
+  private readonly createdAt: Date;
+
+  constructor(
+    leagueId: string,
+    ownerId: string,
+    settings: DraftLeagueSettings,
+    picks: DraftPick[],
+    createdAt: Date
+  ) {
+    const participantCount = new Set(settings.participantIds).size;
+    if (participantCount !== settings.participantIds.length) {
+      throw new DraftLeagueError('A user can only take part once');
+    }
+    if (participantCount < MIN_DRAFT_PARTICIPANTS || participantCount > MAX_DRAFT_PARTICIPANTS) {
+      throw new DraftLeagueError(
+        `A draft league needs ${MIN_DRAFT_PARTICIPANTS} to ${MAX_DRAFT_PARTICIPANTS} participants`
+      );
+    }
+    if (settings.rosterSize < 1 || settings.rosterSize > MAX_DRAFT_ROSTER_SIZE) {
+      throw new DraftLeagueError(`Rosters hold 1 to ${MAX_DRAFT_ROSTER_SIZE} Pokémon`);
+    }
+
+    this.leagueId = leagueId;
+    this.ownerId = ownerId;
+    this.name = DraftLeagueNameSchema.parse(settings.name);
+    this.format = settings.format;
+    this.participantIds = [...settings.participantIds];
+    this.pool = new Map(settings.pool.map((entry) => [entry.formId, entry.cost]));
+    this.budget = settings.budget;
+    this.rosterSize = settings.rosterSize;
+    this.picks = [...picks].sort((a, b) => a.pickNumber - b.pickNumber);
+    this.createdAt = createdAt;
+  }
+
+  static create(leagueId: string, ownerId: string, settings: DraftLeagueSettings): DraftLeague {
+    return new DraftLeague(leagueId, ownerId, settings, [], new Date());
+  }
+
+  static fromRepository(
+    leagueId: string,
+    ownerId: string,
+    settings: DraftLeagueSettings,
+    picks: DraftPick[],
+    createdAt: Date
+  ): DraftLeague {
+    return new DraftLeague(leagueId, ownerId, settings, picks, createdAt);
+  }
+
+  // Whose turn it is, or null once every roster is full
+  getCurrentPicker(): string | null {
+    if (this.isComplete()) {
+      return null;
+    }
+    const count = this.participantIds.length;
+    const round = Math.floor(this.picks.length / count);
+    const position = this.picks.length % count;
+    return this.participantIds[round % 2 === 0 ? position : count - 1 - position];
+  }
+
+  isComplete(): boolean {
+    return this.picks.length === this.participantIds.length * this.rosterSize;
+  }
+
+  isParticipant(userId: string): boolean {
+    return this.participantIds.includes(userId);
+  }
+
+  isPicked(formId: FormId): boolean {
+    return this.picks.some((pick) => pick.formId === formId);
+  }
+
+  // The cost of a Pokémon in the pool, or null when it is not in it
+  getCost(formId: FormId): number | null {
+    return this.pool.get(formId) ?? null;
+  }
+
+  getRemainingBudget(userId: string): number {
+    return this.picksOf(userId).reduce(
+      (left, pick) => left - (this.pool.get(pick.formId) ?? 0),
+      this.budget
+    );
+  }
+
+  // Whether the user can pick the Pokémon and still fill the rest of their roster with the
+  // cheapest Pokémon left in the pool
+  canAfford(userId: string, formId: FormId): boolean {
+    const cost = this.pool.get(formId);
+    if (cost === undefined) {
+      return false;
+    }
+    const slotsAfter = Math.max(this.rosterSize - this.picksOf(userId).length - 1, 0);
+    const reserve = [...this.pool]
+      .filter(([id]) => id !== formId && !this.isPicked(id))
+      .map(([, entryCost]) => entryCost)
+      .sort((a, b) => a - b)
+      .slice(0, slotsAfter)
+      .reduce((sum, entryCost) => sum + entryCost, 0);
+    return cost + reserve <= this.getRemainingBudget(userId);
+  }
+
+  makePick(userId: string, formId: FormId, pickedAt: Date): DraftPick {
+    if (this.getCurrentPicker() !== userId) {
+      throw new DraftLeagueError(`It is not ${userId}'s turn to pick`);
+    }
+    if (this.isPicked(formId)) {
+      throw new DraftLeagueError(`Form ${formId} is already drafted`);
+    }
+    if (!this.canAfford(userId, formId)) {
+      throw new DraftLeagueError(`Form ${formId} is not in the pool or over budget`);
+    }
+
+    const pick = { pickNumber: this.picks.length + 1, userId, formId, pickedAt };
+    this.picks.push(pick);
+    return pick;
+  }
+
+  undoLastPick(): DraftPick {
+    const pick = this.picks.pop();
+    if (!pick) {
+      throw new DraftLeagueError('No pick has been made yet');
+    }
+    return pick;
+  }
+
+  getLastPick(): DraftPick | null {
+    return this.picks[this.picks.length - 1] ?? null;
+  }
+
+  // The user's roster in the order they drafted it
+  picksOf(userId: string): DraftPick[] {
+    return this.picks.filter((pick) => pick.userId === userId);
+  }
+
+  getLeagueId(): string {
+    return this.leagueId;
+  }
+
+  // The commissioner
+  getOwnerId(): string {
+    return this.ownerId;
+  }
+
+  getName(): string {
+    return this.name;
+  }
+
+  getFormat(): FormatId {
+    return this.format;
+  }
+
+  getParticipantIds(): string[] {
+    return [...this.participantIds];
+  }
+
+  getPool(): DraftPoolEntry[] {
+    return [...this.pool].map(([formId, cost]) => ({ formId, cost }));
+  }
+
+  getBudget(): number {
+    return this.budget;
+  }
+
+  getRosterSize(): number {
+    return this.rosterSize;
+  }
+
+  getPicks(): DraftPick[] {
+    return [...this.picks];
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+}
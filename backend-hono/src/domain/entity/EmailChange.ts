@@ -0,0 +1,74 @@
+import { Email } from '../valueobject/Email';
+
+export const EMAIL_CHANGE_EXPIRY_HOURS = 24;
+
+// A user's pending move to a new email address; the address only changes once the new inbox
+// confirms it. A user has at most one pending change, and a new request replaces it.
+export class EmailChange {
+  constructor(
+    private readonly userId: string,
+    private readonly newEmail: Email,
+    private readonly confirmTokenHash: string,
+    private readonly cancelTokenHash: string,
+    private readonly expiresAt: Date,
+    private readonly createdAt: Date
+  ) {}
+
+  // The tokens are generated and hashed by the caller; only their hashes are kept
+  static request(
+    userId: string,
+    newEmail: Email,
+    confirmTokenHash: string,
+    cancelTokenHash: string,
+    now: Date
+  ): EmailChange {
+    const expiresAt = new Date(now.getTime() + EMAIL_CHANGE_EXPIRY_HOURS * 60 * 60 * 1000);
+    return new EmailChange(userId, newEmail, confirmTokenHash, cancelTokenHash, expiresAt, now);
+  }
+
+  static fromRepository(
+    userId: string,
+    newEmail: string,
+    confirmTokenHash: string,
+    cancelTokenHash: string,
+    expiresAt: Date,
+    createdAt: Date
+  ): EmailChange {
+    return new EmailChange(
+      userId,
+      Email.create(newEmail),
+      confirmTokenHash,
+      cancelTokenHash,
+      expiresAt,
+      createdAt
+    );
+  }
+
+  isExpired(now: Date): boolean {
+    return this.expiresAt <= now;
+  }
+
+  getUserId(): string {
+    return this.userId;
+  }
+
+  getNewEmail(): string {
+    return this.newEmail.getValue();
+  }
+
+  getConfirmTokenHash(): string {
+    return this.confirmTokenHash;
+  }
+
+  getCancelTokenHash(): string {
+    return this.cancelTokenHash;
+  }
+
+  getExpiresAt(): Date {
+    return this.expiresAt;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+}
@@ -0,0 +1,40 @@
+export interface ItemProps {
+  id: number; // PokeAPI item ID
+  name: string; // PokeAPI identifier, e.g. "choice-scarf"
+  nameJp?: string;
+  category: string; // PokeAPI item category, e.g. "held-items", "choice"
+  effect?: string; // Short effect in English
+  effectJp?: string; // Latest in-game description in Japanese
+}
+
+export class Item {
+  constructor(private readonly props: ItemProps) {}
+
+  static fromRepository(props: ItemProps): Item {
+    return new Item(props);
+  }
+
+  getId(): number {
+    return this.props.id;
+  }
+
+  getName(): string {
+    return this.props.name;
+  }
+
+  getNameJp(): string | undefined {
+    return this.props.nameJp;
+  }
+
+  getCategory(): string {
+    return this.props.category;
+  }
+
+  getEffect(): string | undefined {
+    return this.props.effect;
+  }
+
+  getEffectJp(): string | undefined {
+    return this.props.effectJp;
+  }
+}
@@ -0,0 +1,67 @@
+import { ContentKind, ContentReason } from '../service/ContentPolicy';
+
+// Published text the content policy wants a moderator to look at. The text is kept as it was
+// when queued, so the moderator sees what was flagged even if it has been edited since.
+export class ModerationItem {
+  constructor(
+    private readonly itemId: string,
+    private readonly kind: ContentKind,
+    // The team for a team name or nickname, the comment for a comment
+    private readonly targetId: string,
+    private readonly authorId: string,
+    private readonly text: string,
+    private readonly reasons: ContentReason[],
+    private readonly createdAt: Date
+  ) {}
+
+  static create(
+    itemId: string,
+    kind: ContentKind,
+    targetId: string,
+    authorId: string,
+    text: string,
+    reasons: ContentReason[]
+  ): ModerationItem {
+    return new ModerationItem(itemId, kind, targetId, authorId, text, reasons, new Date());
+  }
+
+  static fromRepository(
+    itemId: string,
+    kind: ContentKind,
+    targetId: string,
+    authorId: string,
+    text: string,
+    reasons: ContentReason[],
+    createdAt: Date
+  ): ModerationItem {
+    return new ModerationItem(itemId, kind, targetId, authorId, text, reasons, createdAt);
+  }
+
+  getItemId(): string {
+    return this.itemId;
+  }
+
+  getKind(): ContentKind {
+    return this.kind;
+  }
+
+  getTargetId(): string {
+    return this.targetId;
+  }
+
+  getAuthorId(): string {
+    return this.authorId;
+  }
+
+  getText(): string {
+    return this.text;
+  }
+
+  getReasons(): ContentReason[] {
+    return this.reasons;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+}
@@ -0,0 +1,140 @@
+import { z } from 'zod';
+import { getHazardSetBy, Hazard, MoveEffects } from '../valueobject/MoveEffects';
+import { PokemonType } from '../valueobject/PokemonType';
+
+export const DamageClassSchema = z.enum(['physical', 'special', 'status']);
+
+export type DamageClass = z.infer<typeof DamageClassSchema>;
+
+// PokeAPI move targets that hit more than one Pokemon
+const SPREAD_TARGETS = ['all-opponents', 'all-other-pokemon'];
+
+// Moves that ignore the damage formula; 'level' deals damage equal to the user's level
+const FIXED_DAMAGE_MOVES: Record<string, number | 'level'> = {
+  'seismic-toss': 'level',
+  'night-shade': 'level',
+  'dragon-rage': 40,
+  'sonic-boom': 20,
+};
+
+export interface MoveProps {
+  id: number; // PokeAPI move ID
+  name: string; // PokeAPI identifier, e.g. "close-combat"
+  nameJp?: string;
+  type: PokemonType;
+  damageClass: DamageClass;
+  power?: number;
+  accuracy?: number;
+  priority: number;
+  pp?: number;
+  target: string; // PokeAPI target, e.g. "selected-pokemon", "all-opponents"
+  minHits?: number;
+  maxHits?: number;
+  effect?: string; // PokeAPI short effect in English, e.g. "User receives 1/3 the damage inflicted"
+  // Structured side effects; absent for moves imported before they were recorded
+  effects?: MoveEffects;
+}
+
+export class Move {
+  constructor(private readonly props: MoveProps) {
+    if (props.priority < -7 || props.priority > 5) {
+      throw new Error('Move priority must be between -7 and 5');
+    }
+    if ((props.minHits ?? 1) > (props.maxHits ?? 1)) {
+      throw new Error('Move minHits cannot exceed maxHits');
+    }
+  }
+
+  static fromRepository(props: MoveProps): Move {
+    return new Move(props);
+  }
+
+  // Moves such as Hidden Power and Tera Blast take their type and category from the user
+  withChanges(changes: Partial<Pick<MoveProps, 'type' | 'damageClass' | 'power'>>): Move {
+    return new Move({ ...this.props, ...changes });
+  }
+
+  isDamaging(): boolean {
+    if (this.props.damageClass === 'status') {
+      return false;
+    }
+    return (this.props.power ?? 0) > 0 || this.props.name in FIXED_DAMAGE_MOVES;
+  }
+
+  isSpread(): boolean {
+    return SPREAD_TARGETS.includes(this.props.target);
+  }
+
+  isMultiHit(): boolean {
+    return (this.props.maxHits ?? 1) > 1;
+  }
+
+  getFixedDamage(level: number): number | undefined {
+    const fixed = FIXED_DAMAGE_MOVES[this.props.name];
+    return fixed === 'level' ? level : fixed;
+  }
+
+  isPhysical(): boolean {
+    return this.props.damageClass === 'physical';
+  }
+
+  getId(): number {
+    return this.props.id;
+  }
+
+  getName(): string {
+    return this.props.name;
+  }
+
+  getNameJp(): string | undefined {
+    return this.props.nameJp;
+  }
+
+  getType(): PokemonType {
+    return this.props.type;
+  }
+
+  getDamageClass(): DamageClass {
+    return this.props.damageClass;
+  }
+
+  getPower(): number | undefined {
+    return this.props.power;
+  }
+
+  getAccuracy(): number | undefined {
+    return this.props.accuracy;
+  }
+
+  getPriority(): number {
+    return this.props.priority;
+  }
+
+  getPp(): number | undefined {
+    return this.props.pp;
+  }
+
+  getTarget(): string {
+    return this.props.target;
+  }
+
+  getEffect(): string | undefined {
+    return this.props.effect;
+  }
+
+  getEffects(): MoveEffects | undefined {
+    return this.props.effects;
+  }
+
+  getHazard(): Hazard | undefined {
+    return getHazardSetBy(this.props.name);
+  }
+
+  getMinHits(): number {
+    return this.props.minHits ?? 1;
+  }
+
+  getMaxHits(): number {
+    return this.props.maxHits ?? 1;
+  }
+}
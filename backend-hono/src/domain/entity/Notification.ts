@@ -0,0 +1,87 @@
+import { z } from 'zod';
+
+const CommentNotificationSchema = z.object({
+  teamId: z.string(),
+  teamName: z.string(),
+  commentId: z.string(),
+  authorName: z.string(),
+});
+
+// What a notification says, by type; stored as JSON next to the type
+export const NotificationContentSchema = z.discriminatedUnion('type', [
+  // Someone commented on the user's shared team
+  CommentNotificationSchema.extend({ type: z.literal('team_comment') }),
+  // Someone answered the user's comment
+  CommentNotificationSchema.extend({ type: z.literal('comment_reply') }),
+  z.object({
+    type: z.literal('team_like'),
+    teamId: z.string(),
+    teamName: z.string(),
+    likes: z.number().int(),
+  }),
+  z.object({
+    type: z.literal('import_finished'),
+    created: z.number().int(),
+    updated: z.number().int(),
+  }),
+]);
+
+export type NotificationContent = z.infer<typeof NotificationContentSchema>;
+
+export type NotificationType = NotificationContent['type'];
+
+export class Notification {
+  constructor(
+    private readonly notificationId: string,
+    private readonly userId: string,
+    private readonly content: NotificationContent,
+    private readonly createdAt: Date,
+    private readonly readAt: Date | null = null
+  ) {}
+
+  static create(
+    notificationId: string,
+    userId: string,
+    content: NotificationContent
+  ): Notification {
+    return new Notification(notificationId, userId, content, new Date());
+  }
+
+  static fromRepository(
+    notificationId: string,
+    userId: string,
+    content: NotificationContent,
+    createdAt: Date,
+    readAt: Date | null
+  ): Notification {
+    return new Notification(notificationId, userId, content, createdAt, readAt);
+  }
+
+  getNotificationId(): string {
+    return this.notificationId;
+  }
+
+  getUserId(): string {
+    return this.userId;
+  }
+
+  getType(): NotificationType {
+    return this.content.type;
+  }
+
+  getContent(): NotificationContent {
+    return this.content;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+
+  getReadAt(): Date | null {
+    return this.readAt;
+  }
+
+  isRead(): boolean {
+    return this.readAt !== null;
+  }
+}
@@ -0,0 +1,110 @@
+import { AbilitySlots } from '../valueobject/AbilitySlots';
+import { Availability, GameVersion } from '../valueobject/GameVersion';
+import { FormId, SpeciesId } from '../valueobject/PokemonId';
+import { TypeSet } from '../valueobject/PokemonType';
+import { BaseStats } from '../valueobject/Stats';
+
+export interface PokemonMasterProps {
+  formId: FormId;
+  speciesId: SpeciesId;
+  name: string;
+  nameJp: string;
+  typeset: TypeSet;
+  baseStats: BaseStats;
+  // Missing for forms imported before ability data was
+  abilities?: AbilitySlots;
+  baseExperience?: number;
+  height: number; // decimetres
+  weight: number; // hectograms
+  shinyAvailable: boolean;
+  shinyLocked: boolean;
+  availability: Availability;
+}
+
+export class PokemonMaster {
+  constructor(private readonly props: PokemonMasterProps) {
+    if (props.height < 0 || props.weight < 0) {
+      throw new Error('Height and weight cannot be negative');
+    }
+  }
+
+  static fromRepository(props: PokemonMasterProps): PokemonMaster {
+    return new PokemonMaster(props);
+  }
+
+  // Identity and availability are fixed; everything else can be corrected by hand
+  withChanges(
+    changes: Partial<Omit<PokemonMasterProps, 'formId' | 'speciesId' | 'availability'>>
+  ): PokemonMaster {
+    return new PokemonMaster({ ...this.props, ...changes });
+  }
+
+  getFormId(): FormId {
+    return this.props.formId;
+  }
+
+  getSpeciesId(): SpeciesId {
+    return this.props.speciesId;
+  }
+
+  getName(): string {
+    return this.props.name;
+  }
+
+  getNameJp(): string {
+    return this.props.nameJp;
+  }
+
+  getTypeset(): TypeSet {
+    return this.props.typeset;
+  }
+
+  getBaseStats(): BaseStats {
+    return this.props.baseStats;
+  }
+
+  getBaseStatTotal(): number {
+    return Object.values(this.props.baseStats).reduce((sum, value) => sum + value, 0);
+  }
+
+  getAbilities(): AbilitySlots | undefined {
+    return this.props.abilities;
+  }
+
+  getBaseExperience(): number | undefined {
+    return this.props.baseExperience;
+  }
+
+  getHeight(): number {
+    return this.props.height;
+  }
+
+  getWeight(): number {
+    return this.props.weight;
+  }
+
+  getHeightMeters(): number {
+    return this.props.height / 10;
+  }
+
+  getWeightKg(): number {
+    return this.props.weight / 10;
+  }
+
+  getAvailability(): Availability {
+    return this.props.availability;
+  }
+
+  isObtainableIn(version: GameVersion): boolean {
+    return this.props.availability.isObtainableIn(version);
+  }
+
+  isShinyAvailable(): boolean {
+    return this.props.shinyAvailable;
+  }
+
+  // Shiny-locked forms (e.g. event legendaries) can't be shiny even when the species has been
+  isShinyLocked(): boolean {
+    return this.props.shinyLocked;
+  }
+}
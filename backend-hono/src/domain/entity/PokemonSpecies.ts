@@ -0,0 +1,137 @@
+import { z } from 'zod';
+import { RegionalDex, RegionalDexNumber } from '../valueobject/DexNumber';
+import { EggGroup } from '../valueobject/EggGroup';
+import { GenderRatio } from '../valueobject/GenderRatio';
+import { SpeciesId } from '../valueobject/PokemonId';
+import { SpeciesCategory } from '../valueobject/SpeciesCategory';
+
+export const CaptureRateSchema = z.number().int().min(0).max(255);
+
+export class PokemonSpecies {
+  private readonly captureRate: number;
+
+  constructor(
+    private readonly speciesId: SpeciesId,
+    private readonly name: string,
+    private readonly nameJp: string,
+    private readonly eggGroups: EggGroup[],
+    private readonly genderRatio: GenderRatio,
+    captureRate: number,
+    private readonly category: SpeciesCategory,
+    // The species this one evolves from; undefined for the first stage of a line
+    private readonly evolvesFromSpeciesId?: SpeciesId,
+    private readonly regionalDexNumbers: RegionalDexNumber[] = []
+  ) {
+    if (eggGroups.length === 0 || eggGroups.length > 2) {
+      throw new Error('A species must belong to one or two egg groups');
+    }
+    this.captureRate = CaptureRateSchema.parse(captureRate);
+  }
+
+  static fromRepository(
+    speciesId: SpeciesId,
+    name: string,
+    nameJp: string,
+    eggGroups: EggGroup[],
+    genderRatio: GenderRatio,
+    captureRate: number,
+    category: SpeciesCategory,
+    evolvesFromSpeciesId?: SpeciesId,
+    regionalDexNumbers: RegionalDexNumber[] = []
+  ): PokemonSpecies {
+    return new PokemonSpecies(
+      speciesId,
+      name,
+      nameJp,
+      eggGroups,
+      genderRatio,
+      captureRate,
+      category,
+      evolvesFromSpeciesId,
+      regionalDexNumbers
+    );
+  }
+
+  hasEggGroup(group: EggGroup): boolean {
+    return this.eggGroups.includes(group);
+  }
+
+  isDitto(): boolean {
+    return this.hasEggGroup('Ditto');
+  }
+
+  canBreed(): boolean {
+    return !this.hasEggGroup('Undiscovered');
+  }
+
+  getSharedEggGroups(other: PokemonSpecies): EggGroup[] {
+    return this.eggGroups.filter((group) => other.hasEggGroup(group));
+  }
+
+  hasCompatibleGenders(other: PokemonSpecies): boolean {
+    return (
+      (this.genderRatio.canBeMale() && other.genderRatio.canBeFemale()) ||
+      (this.genderRatio.canBeFemale() && other.genderRatio.canBeMale())
+    );
+  }
+
+  canBreedWith(other: PokemonSpecies): boolean {
+    if (!this.canBreed() || !other.canBreed()) {
+      return false;
+    }
+    // Ditto breeds with anything that can breed (genderless included), except another Ditto
+    if (this.isDitto() || other.isDitto()) {
+      return this.isDitto() !== other.isDitto();
+    }
+    if (!this.hasCompatibleGenders(other)) {
+      return false;
+    }
+    return this.getSharedEggGroups(other).length > 0;
+  }
+
+  getSpeciesId(): SpeciesId {
+    return this.speciesId;
+  }
+
+  getName(): string {
+    return this.name;
+  }
+
+  getNameJp(): string {
+    return this.nameJp;
+  }
+
+  getEggGroups(): EggGroup[] {
+    return [...this.eggGroups];
+  }
+
+  getGenderRatio(): GenderRatio {
+    return this.genderRatio;
+  }
+
+  getCaptureRate(): number {
+    return this.captureRate;
+  }
+
+  getCategory(): SpeciesCategory {
+    return this.category;
+  }
+
+  getEvolvesFromSpeciesId(): SpeciesId | undefined {
+    return this.evolvesFromSpeciesId;
+  }
+
+  getRegionalDexNumbers(): RegionalDexNumber[] {
+    return [...this.regionalDexNumbers];
+  }
+
+  // Undefined when the species is not in that dex
+  getRegionalDexNumber(dex: RegionalDex): number | undefined {
+    return this.regionalDexNumbers.find((entry) => entry.dex === dex)?.number;
+  }
+
+  // Babies such as Pichu count as the first stage
+  isFirstStage(): boolean {
+    return this.evolvesFromSpeciesId === undefined;
+  }
+}
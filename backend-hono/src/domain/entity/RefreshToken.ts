@@ -2,39 +2,109 @@ import crypto from 'crypto';
 
 const REFRESH_TOKEN_EXPIRY_DAYS = 30;
 
+// Session tokens are checked on every request, so their last use is only written this often
+const LAST_USED_INTERVAL_MS = 5 * 60 * 1000;
+
+// The device a session was signed in from, as reported by the request
+export interface SessionClient {
+  userAgent?: string;
+  ip?: string;
+}
+
+export interface IssuedSessionToken {
+  refreshToken: RefreshToken;
+  token: string; // Only available at issue time
+}
+
+// Each refresh rotates the token; the session ID stays the same, so one session is the chain of
+// tokens issued since a login, and at most one of them is valid at a time
 export class RefreshToken {
   constructor(
     private readonly tokenId: string,
     private readonly userId: string,
+    private readonly sessionId: string,
     private readonly tokenHash: string,
+    private readonly client: SessionClient,
     private readonly expiresAt: Date,
     private readonly createdAt: Date,
+    private lastUsedAt: Date,
     private revoked: boolean
   ) {}
 
-  static create(tokenId: string, userId: string, token: string): RefreshToken {
+  static create(
+    tokenId: string,
+    userId: string,
+    sessionId: string,
+    token: string,
+    client: SessionClient
+  ): RefreshToken {
     const tokenHash = crypto.createHash('sha256').update(token).digest('hex');
     const expiresAt = new Date();
     expiresAt.setDate(expiresAt.getDate() + REFRESH_TOKEN_EXPIRY_DAYS);
+    const now = new Date();
 
-    return new RefreshToken(tokenId, userId, tokenHash, expiresAt, new Date(), false);
+    return new RefreshToken(
+      tokenId,
+      userId,
+      sessionId,
+      tokenHash,
+      client,
+      expiresAt,
+      now,
+      now,
+      false
+    );
   }
 
   static fromRepository(
     tokenId: string,
     userId: string,
+    sessionId: string,
     tokenHash: string,
+    client: SessionClient,
     expiresAt: Date,
     createdAt: Date,
+    lastUsedAt: Date,
     revoked: boolean
   ): RefreshToken {
-    return new RefreshToken(tokenId, userId, tokenHash, expiresAt, createdAt, revoked);
+    return new RefreshToken(
+      tokenId,
+      userId,
+      sessionId,
+      tokenHash,
+      client,
+      expiresAt,
+      createdAt,
+      lastUsedAt,
+      revoked
+    );
+  }
+
+  // In session mode the cookie holds a random token instead of a JWT. It never rotates; the
+  // row is looked up on every request instead, so revoking it signs the session out at once.
+  static issueSessionToken(
+    tokenId: string,
+    userId: string,
+    sessionId: string,
+    client: SessionClient
+  ): IssuedSessionToken {
+    const token = crypto.randomBytes(32).toString('base64url');
+    return { refreshToken: RefreshToken.create(tokenId, userId, sessionId, token, client), token };
   }
 
   static hashToken(token: string): string {
     return crypto.createHash('sha256').update(token).digest('hex');
   }
 
+  // The next token in the same session; the client is updated to the one refreshing, falling
+  // back to what was known
+  rotate(tokenId: string, token: string, client: SessionClient): RefreshToken {
+    return RefreshToken.create(tokenId, this.userId, this.sessionId, token, {
+      userAgent: client.userAgent ?? this.client.userAgent,
+      ip: client.ip ?? this.client.ip,
+    });
+  }
+
   isValid(): boolean {
     return !this.revoked && this.expiresAt > new Date();
   }
@@ -43,6 +113,16 @@ export class RefreshToken {
     this.revoked = true;
   }
 
+  // Records a use of the token; returns false when the last one recorded is recent enough to
+  // keep, so there is nothing to save
+  markUsed(now: Date = new Date()): boolean {
+    if (now.getTime() - this.lastUsedAt.getTime() < LAST_USED_INTERVAL_MS) {
+      return false;
+    }
+    this.lastUsedAt = now;
+    return true;
+  }
+
   getTokenId(): string {
     return this.tokenId;
   }
@@ -51,10 +131,18 @@ export class RefreshToken {
     return this.userId;
   }
 
+  getSessionId(): string {
+    return this.sessionId;
+  }
+
   getTokenHash(): string {
     return this.tokenHash;
   }
 
+  getClient(): SessionClient {
+    return this.client;
+  }
+
   getExpiresAt(): Date {
     return this.expiresAt;
   }
@@ -63,6 +151,10 @@ export class RefreshToken {
     return this.createdAt;
   }
 
+  getLastUsedAt(): Date {
+    return this.lastUsedAt;
+  }
+
   isRevoked(): boolean {
     return this.revoked;
   }
@@ -0,0 +1,100 @@
+import { z } from 'zod';
+import { GameVersion } from '../valueobject/GameVersion';
+import { SpeciesId } from '../valueobject/PokemonId';
+
+const RunNameSchema = z.string().min(1).max(50);
+
+export class RunTrackerError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'RunTrackerError';
+  }
+}
+
+// A Nuzlocke-style challenge run: the game it is played in and the species already caught or
+// used, so the Pokédex can offer only what is still fair game
+export class RunTracker {
+  private readonly runId: string;
+  private readonly ownerId: string;
+  private readonly name: string;
+  private readonly gameVersion: GameVersion;
+  private readonly usedSpeciesIds: Set<SpeciesId>;
+  private readonly createdAt: Date;
+
+  constructor(
+    runId: string,
+    ownerId: string,
+    name: string,
+    gameVersion: GameVersion,
+    usedSpeciesIds: SpeciesId[],
+    createdAt: Date
+  ) {
+    this.runId = runId;
+    this.ownerId = ownerId;
+    this.name = RunNameSchema.parse(name);
+    this.gameVersion = gameVersion;
+    this.usedSpeciesIds = new Set(usedSpeciesIds);
+    this.createdAt = createdAt;
+  }
+
+  static create(
+    runId: string,
+    ownerId: string,
+    name: string,
+    gameVersion: GameVersion
+  ): RunTracker {
+    return new RunTracker(runId, ownerId, name, gameVersion, [], new Date());
+  }
+
+  static fromRepository(
+    runId: string,
+    ownerId: string,
+    name: string,
+    gameVersion: GameVersion,
+    usedSpeciesIds: SpeciesId[],
+    createdAt: Date
+  ): RunTracker {
+    return new RunTracker(runId, ownerId, name, gameVersion, usedSpeciesIds, createdAt);
+  }
+
+  markUsed(speciesId: SpeciesId): void {
+    if (this.usedSpeciesIds.has(speciesId)) {
+      throw new RunTrackerError(`Species ${speciesId} is already used in this run`);
+    }
+    this.usedSpeciesIds.add(speciesId);
+  }
+
+  unmarkUsed(speciesId: SpeciesId): void {
+    if (!this.usedSpeciesIds.delete(speciesId)) {
+      throw new RunTrackerError(`Species ${speciesId} is not used in this run`);
+    }
+  }
+
+  isUsed(speciesId: SpeciesId): boolean {
+    return this.usedSpeciesIds.has(speciesId);
+  }
+
+  getRunId(): string {
+    return this.runId;
+  }
+
+  getOwnerId(): string {
+    return this.ownerId;
+  }
+
+  getName(): string {
+    return this.name;
+  }
+
+  getGameVersion(): GameVersion {
+    return this.gameVersion;
+  }
+
+  getUsedSpeciesIds(): SpeciesId[] {
+    return [...this.usedSpeciesIds].sort((a, b) => a - b);
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+}
@@ -1,10 +1,37 @@
 import { z } from 'zod';
+import { FormatId } from '../valueobject/Format';
+import { MAX_MEMBER_NOTES_LENGTH, MAX_TEAM_NOTES_LENGTH, parseNote } from '../valueobject/TeamNote';
+import { TeamTag } from '../valueobject/TeamTag';
 
-const TeamNameSchema = z.string().min(1).max(50);
+const MAX_TEAM_NAME_LENGTH = 50;
+const TeamNameSchema = z.string().min(1).max(MAX_TEAM_NAME_LENGTH);
+
+// Folders are flat: a team is in one folder or none
+const TeamFolderSchema = z.string().trim().min(1).max(50);
+
+const COPY_SUFFIX = ' (copy)';
+
+// How long a deleted team stays in the trash before it is purged for good
+export const TEAM_RETENTION_DAYS = 30;
+const TEAM_RETENTION_MS = TEAM_RETENTION_DAYS * 24 * 60 * 60 * 1000;
+
+// Teams deleted before this moment are past the retention window
+export function getRetentionCutoff(): Date {
+  return new Date(Date.now() - TEAM_RETENTION_MS);
+}
 
 export interface PokemonForm {
   formId: number;
   terastalType: string;
+  // Why the set is there, as Markdown (see TeamNote)
+  notes?: string;
+}
+
+// Set while the owner lets anyone see the team
+export interface TeamSharing {
+  sharedAt: Date;
+  // The format the team is listed under in the gallery, if the owner picked one
+  format: FormatId | null;
 }
 
 export class TeamError extends Error {
@@ -19,12 +46,36 @@ export class Team {
   private readonly ownerId: string;
   private teamName: string;
   private pokemon: (PokemonForm | null)[];
+  private deletedAt: Date | null;
+  // The stored version this team was read at, for optimistic locking; 0 until first saved
+  private version: number;
+  private tags: TeamTag[];
+  private folder: string | null;
+  private sharing: TeamSharing | null;
+  private notes: string | null;
 
-  constructor(teamId: string, ownerId: string, teamName: string, pokemon: (PokemonForm | null)[]) {
+  constructor(
+    teamId: string,
+    ownerId: string,
+    teamName: string,
+    pokemon: (PokemonForm | null)[],
+    deletedAt: Date | null = null,
+    version = 0,
+    tags: TeamTag[] = [],
+    folder: string | null = null,
+    sharing: TeamSharing | null = null,
+    notes: string | null = null
+  ) {
     this.teamId = teamId;
     this.ownerId = ownerId;
     this.teamName = TeamNameSchema.parse(teamName);
     this.pokemon = pokemon.length === 6 ? pokemon : new Array(6).fill(null);
+    this.deletedAt = deletedAt;
+    this.version = version;
+    this.tags = tags;
+    this.folder = folder;
+    this.sharing = sharing;
+    this.notes = notes;
   }
 
   static create(teamId: string, ownerId: string, teamName: string): Team {
@@ -35,9 +86,44 @@ export class Team {
     teamId: string,
     ownerId: string,
     teamName: string,
-    pokemon: (PokemonForm | null)[]
+    pokemon: (PokemonForm | null)[],
+    deletedAt: Date | null = null,
+    version = 0,
+    tags: TeamTag[] = [],
+    folder: string | null = null,
+    sharing: TeamSharing | null = null,
+    notes: string | null = null
   ): Team {
-    return new Team(teamId, ownerId, teamName, pokemon);
+    return new Team(
+      teamId,
+      ownerId,
+      teamName,
+      pokemon,
+      deletedAt,
+      version,
+      tags,
+      folder,
+      sharing,
+      notes
+    );
+  }
+
+  // A new team with the same members, notes, tags and folder, named "<name> (copy)"; long names
+  // are cut to make room. The copy is not shared.
+  duplicate(teamId: string): Team {
+    const name = this.teamName.slice(0, MAX_TEAM_NAME_LENGTH - COPY_SUFFIX.length).trimEnd();
+    return new Team(
+      teamId,
+      this.ownerId,
+      `${name}${COPY_SUFFIX}`,
+      this.pokemon.map((member) => (member ? { ...member } : null)),
+      null,
+      0,
+      [...this.tags],
+      this.folder,
+      null,
+      this.notes
+    );
   }
 
   updateName(newName: string): void {
@@ -76,6 +162,98 @@ export class Team {
     });
   }
 
+  // Replaces the tags; fails when more than MAX_TEAM_TAGS remain after normalization
+  setTags(tags: string[]): void {
+    this.tags = TeamTag.createList(tags);
+  }
+
+  // Tags the team already has are kept once
+  addTags(tags: string[]): void {
+    this.tags = TeamTag.createList([...this.getTags(), ...tags]);
+  }
+
+  // Tags the team does not have are ignored
+  removeTags(tags: string[]): void {
+    const removed = new Set(tags.map(TeamTag.normalize));
+    this.tags = this.tags.filter((tag) => !removed.has(tag.getValue()));
+  }
+
+  // Sanitized for Markdown; null or empty notes remove them
+  setNotes(notes: string | null): void {
+    this.notes = parseNote(notes, MAX_TEAM_NOTES_LENGTH);
+  }
+
+  setMemberNotes(index: number, notes: string | null): void {
+    const member = this.pokemon[index];
+    if (!member) {
+      throw new TeamError(`Slot ${index} is empty`);
+    }
+    const parsed = parseNote(notes, MAX_MEMBER_NOTES_LENGTH);
+    this.pokemon[index] = { ...member, notes: parsed ?? undefined };
+  }
+
+  // null takes the team out of its folder
+  moveToFolder(folder: string | null): void {
+    this.folder = folder === null ? null : TeamFolderSchema.parse(folder);
+  }
+
+  // Lists the team in the gallery; sharing it again only changes the format and keeps its place
+  // among the most recent
+  share(format: FormatId | null = null): void {
+    this.sharing = { sharedAt: this.sharing?.sharedAt ?? new Date(), format };
+  }
+
+  unshare(): void {
+    this.sharing = null;
+  }
+
+  isShared(): boolean {
+    return this.sharing !== null;
+  }
+
+  getSharedAt(): Date | null {
+    return this.sharing?.sharedAt ?? null;
+  }
+
+  getSharedFormat(): FormatId | null {
+    return this.sharing?.format ?? null;
+  }
+
+  // Moves the team to the trash; it can be restored until the retention window ends
+  delete(): void {
+    if (this.deletedAt !== null) {
+      throw new TeamError('Team is already deleted');
+    }
+    this.deletedAt = new Date();
+  }
+
+  restore(): void {
+    if (this.deletedAt === null) {
+      throw new TeamError('Team is not deleted');
+    }
+    if (!this.isRestorable()) {
+      throw new TeamError('Team is past its retention window');
+    }
+    this.deletedAt = null;
+  }
+
+  isDeleted(): boolean {
+    return this.deletedAt !== null;
+  }
+
+  isRestorable(): boolean {
+    return this.deletedAt !== null && this.deletedAt > getRetentionCutoff();
+  }
+
+  getDeletedAt(): Date | null {
+    return this.deletedAt;
+  }
+
+  // When the purge job removes the team, or null while it is not in the trash
+  getPurgeAt(): Date | null {
+    return this.deletedAt ? new Date(this.deletedAt.getTime() + TEAM_RETENTION_MS) : null;
+  }
+
   getPokemonList(): PokemonForm[] {
     return this.pokemon.filter((p): p is PokemonForm => p !== null);
   }
@@ -92,6 +270,27 @@ export class Team {
     return this.teamName;
   }
 
+  getTags(): string[] {
+    return this.tags.map((tag) => tag.getValue());
+  }
+
+  getFolder(): string | null {
+    return this.folder;
+  }
+
+  getNotes(): string | null {
+    return this.notes;
+  }
+
+  getVersion(): number {
+    return this.version;
+  }
+
+  // Called by the repository once the team is stored as the next version
+  markSaved(): void {
+    this.version++;
+  }
+
   getPokemon(): (PokemonForm | null)[] {
     return [...this.pokemon];
   }
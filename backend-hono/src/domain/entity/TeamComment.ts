@@ -0,0 +1,125 @@
+import { z } from 'zod';
+import { CommentBodyValidationError } from '../valueobject/ValidationError';
+
+export const MAX_COMMENT_LENGTH = 1000;
+
+export const CommentBodySchema = z
+  .string()
+  .trim()
+  .min(1, 'Comment cannot be empty')
+  .max(MAX_COMMENT_LENGTH, `Comment must be ${MAX_COMMENT_LENGTH} characters or less`);
+
+function parseBody(body: string): string {
+  const result = CommentBodySchema.safeParse(body.normalize('NFC'));
+  if (!result.success) {
+    throw new CommentBodyValidationError(result.error);
+  }
+  return result.data;
+}
+
+// A comment on a shared team. Threads are one level deep: a reply to a reply joins the thread
+// of the comment it ultimately answers.
+export class TeamComment {
+  constructor(
+    private readonly commentId: string,
+    private readonly teamId: string,
+    private readonly authorId: string,
+    private readonly parentId: string | null,
+    private body: string,
+    private readonly createdAt: Date,
+    private updatedAt: Date,
+    private deletedAt: Date | null = null
+  ) {}
+
+  static create(
+    commentId: string,
+    teamId: string,
+    authorId: string,
+    body: string,
+    replyTo?: TeamComment
+  ): TeamComment {
+    const now = new Date();
+    return new TeamComment(
+      commentId,
+      teamId,
+      authorId,
+      replyTo ? (replyTo.parentId ?? replyTo.commentId) : null,
+      parseBody(body),
+      now,
+      now
+    );
+  }
+
+  static fromRepository(
+    commentId: string,
+    teamId: string,
+    authorId: string,
+    parentId: string | null,
+    body: string,
+    createdAt: Date,
+    updatedAt: Date,
+    deletedAt: Date | null
+  ): TeamComment {
+    return new TeamComment(
+      commentId,
+      teamId,
+      authorId,
+      parentId,
+      body,
+      createdAt,
+      updatedAt,
+      deletedAt
+    );
+  }
+
+  edit(body: string): void {
+    this.body = parseBody(body);
+    this.updatedAt = new Date();
+  }
+
+  // The text is dropped, but the comment stays as a placeholder so its replies keep their thread
+  delete(): void {
+    this.body = '';
+    this.deletedAt = new Date();
+  }
+
+  isDeleted(): boolean {
+    return this.deletedAt !== null;
+  }
+
+  isEdited(): boolean {
+    return this.updatedAt.getTime() !== this.createdAt.getTime();
+  }
+
+  getCommentId(): string {
+    return this.commentId;
+  }
+
+  getTeamId(): string {
+    return this.teamId;
+  }
+
+  getAuthorId(): string {
+    return this.authorId;
+  }
+
+  getParentId(): string | null {
+    return this.parentId;
+  }
+
+  getBody(): string {
+    return this.body;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+
+  getUpdatedAt(): Date {
+    return this.updatedAt;
+  }
+
+  getDeletedAt(): Date | null {
+    return this.deletedAt;
+  }
+}
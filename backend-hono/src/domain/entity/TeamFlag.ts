@@ -0,0 +1,48 @@
+import { z } from 'zod';
+
+export const TeamFlagReasonSchema = z.enum(['spam', 'offensive', 'impersonation', 'other']);
+
+export type TeamFlagReason = z.infer<typeof TeamFlagReasonSchema>;
+
+// A team flagged by this many users leaves the gallery until a moderator reviews it
+export const TEAM_FLAG_HIDE_THRESHOLD = 3;
+
+// A user's report that a shared team does not belong in the gallery. A user has at most one
+// flag per team; flagging again replaces the reason.
+export class TeamFlag {
+  constructor(
+    private readonly teamId: string,
+    private readonly userId: string,
+    private readonly reason: TeamFlagReason,
+    private readonly createdAt: Date
+  ) {}
+
+  static create(teamId: string, userId: string, reason: TeamFlagReason): TeamFlag {
+    return new TeamFlag(teamId, userId, reason, new Date());
+  }
+
+  static fromRepository(
+    teamId: string,
+    userId: string,
+    reason: TeamFlagReason,
+    createdAt: Date
+  ): TeamFlag {
+    return new TeamFlag(teamId, userId, reason, createdAt);
+  }
+
+  getTeamId(): string {
+    return this.teamId;
+  }
+
+  getUserId(): string {
+    return this.userId;
+  }
+
+  getReason(): TeamFlagReason {
+    return this.reason;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+}
@@ -0,0 +1,28 @@
+// A user's like of a shared team; a user likes a team at most once
+export class TeamLike {
+  constructor(
+    private readonly teamId: string,
+    private readonly userId: string,
+    private readonly createdAt: Date
+  ) {}
+
+  static create(teamId: string, userId: string): TeamLike {
+    return new TeamLike(teamId, userId, new Date());
+  }
+
+  static fromRepository(teamId: string, userId: string, createdAt: Date): TeamLike {
+    return new TeamLike(teamId, userId, createdAt);
+  }
+
+  getTeamId(): string {
+    return this.teamId;
+  }
+
+  getUserId(): string {
+    return this.userId;
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+}
@@ -0,0 +1,377 @@
+import { z } from 'zod';
+import { FormatId } from '../valueobject/Format';
+import { FormId } from '../valueobject/PokemonId';
+
+const TournamentNameSchema = z.string().min(1).max(50);
+
+export const TournamentStatusSchema = z.enum(['registration', 'in_progress', 'complete']);
+
+export type TournamentStatus = z.infer<typeof TournamentStatusSchema>;
+
+export const MIN_TOURNAMENT_ENTRIES = 2;
+export const MAX_TOURNAMENT_ENTRIES = 128;
+
+export class TournamentError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TournamentError';
+  }
+}
+
+export interface TournamentMember {
+  slot: number;
+  formId: FormId;
+  terastalType: string;
+}
+
+// A player's registration: a copy of their team as it was when they registered, so later edits
+// to the team do not change what they entered with
+export interface TournamentEntry {
+  userId: string;
+  teamId: string;
+  teamName: string;
+  // The team's version when it was copied
+  teamVersion: number;
+  members: TournamentMember[];
+  // 1 is the top seed; null until the bracket is drawn
+  seed: number | null;
+  registeredAt: Date;
+}
+
+// Rounds count from 1 and positions within a round from 0. The winner of a match plays on in
+// round + 1 at position / 2 (rounded down). A player missing in the first round is a bye.
+export interface TournamentMatch {
+  round: number;
+  position: number;
+  player1Id: string | null;
+  player2Id: string | null;
+  winnerId: string | null;
+  reportedAt: Date | null;
+}
+
+export interface TournamentStanding {
+  userId: string;
+  seed: number | null;
+  // Byes are not counted
+  wins: number;
+  losses: number;
+  // 1 for the champion, 2 for the runner-up, then shared by everyone who went out in the same
+  // round (3 for semi-finalists, 5 for quarter-finalists, ...); null while still in
+  placement: number | null;
+}
+
+// Seed order of the first round for a bracket of `size` (a power of two), so the top seeds meet
+// as late as possible: 1 v 8, 4 v 5, 2 v 7, 3 v 6 for eight players
+function bracketOrder(size: number): number[] {
+  let order = [1];
+  while (order.length < size) {
+    const next = order.length * 2 + 1;
+    order = order.flatMap((seed) => [seed, next - seed]);
+  }
+  return order;
+}
+
+// A single-elimination event. Players register one of their teams while registration is open;
+// starting it seeds them by registration order and draws the whole bracket, and reported
+// results move winners on until the final is decided.
+export class Tournament {
+  private readonly tournamentId: string;
+  private readonly organizerId: string;
+  private readonly name: string;
+  private readonly format: FormatId;
+  private readonly maxEntries: number;
+  private status: TournamentStatus;
+  private entries: TournamentEntry[];
+  private matches: TournamentMatch[];
+  private readonly createdAt: Date;
+  private startedAt: Date | null;
+  private version: number;
+
+  constructor(
+    tournamentId: string,
+    organizerId: string,
+    name: string,
+    format: FormatId,
+    maxEntries: number,
+    status: TournamentStatus,
+    entries: TournamentEntry[],
+    matches: TournamentMatch[],
+    createdAt: Date,
+    startedAt: Date | null,
+    version: number
+  ) {
+    if (maxEntries < MIN_TOURNAMENT_ENTRIES || maxEntries > MAX_TOURNAMENT_ENTRIES) {
+      throw new TournamentError(
+        `A tournament takes ${MIN_TOURNAMENT_ENTRIES} to ${MAX_TOURNAMENT_ENTRIES} entries`
+      );
+    }
+    this.tournamentId = tournamentId;
+    this.organizerId = organizerId;
+    this.name = TournamentNameSchema.parse(name);
+    this.format = format;
+    this.maxEntries = maxEntries;
+    this.status = status;
+    this.entries = [...entries].sort((a, b) => a.registeredAt.getTime() - b.registeredAt.getTime());
+    this.matches = [...matches].sort((a, b) => a.round - b.round || a.position - b.position);
+    this.createdAt = createdAt;
+    this.startedAt = startedAt;
+    this.version = version;
+  }
+
+  static create(
+    tournamentId: string,
+    organizerId: string,
+    name: string,
+    format: FormatId,
+    maxEntries: number
+  ): Tournament {
+    return new Tournament(
+      tournamentId,
+      organizerId,
+      name,
+      format,
+      maxEntries,
+      'registration',
+      [],
+      [],
+      new Date(),
+      null,
+      0
+    );
+  }
+
+  static fromRepository(
+    tournamentId: string,
+    organizerId: string,
+    name: string,
+    format: FormatId,
+    maxEntries: number,
+    status: TournamentStatus,
+    entries: TournamentEntry[],
+    matches: TournamentMatch[],
+    createdAt: Date,
+    startedAt: Date | null,
+    version: number
+  ): Tournament {
+    return new Tournament(
+      tournamentId,
+      organizerId,
+      name,
+      format,
+      maxEntries,
+      status,
+      entries,
+      matches,
+      createdAt,
+      startedAt,
+      version
+    );
+  }
+
+  // Registering again replaces the team but keeps the player's place in the seeding order
+  register(entry: Omit<TournamentEntry, 'seed'>): void {
+    if (this.status !== 'registration') {
+      throw new TournamentError('Registration is closed');
+    }
+    const existing = this.getEntry(entry.userId);
+    if (!existing && this.isFull()) {
+      throw new TournamentError(`The tournament is full (${this.maxEntries} entries)`);
+    }
+
+    const registered = {
+      ...entry,
+      seed: null,
+      registeredAt: existing?.registeredAt ?? entry.registeredAt,
+    };
+    this.entries = existing
+      ? this.entries.map((e) => (e.userId === entry.userId ? registered : e))
+      : [...this.entries, registered];
+  }
+
+  withdraw(userId: string): void {
+    if (this.status !== 'registration') {
+      throw new TournamentError('Registration is closed');
+    }
+    if (!this.getEntry(userId)) {
+      throw new TournamentError(`User ${userId} is not registered`);
+    }
+    this.entries = this.entries.filter((entry) => entry.userId !== userId);
+  }
+
+  // Seeds the players by registration order and draws every round. Top seeds get the byes
+  // when the number of players is not a power of two.
+  start(at: Date): void {
+    if (this.status !== 'registration') {
+      throw new TournamentError('The tournament has already started');
+    }
+    if (this.entries.length < MIN_TOURNAMENT_ENTRIES) {
+      throw new TournamentError(`A tournament needs at least ${MIN_TOURNAMENT_ENTRIES} entries`);
+    }
+
+    this.entries = this.entries.map((entry, index) => ({ ...entry, seed: index + 1 }));
+    const rounds = Math.ceil(Math.log2(this.entries.length));
+    const order = bracketOrder(2 ** rounds);
+    const playerBySeed = (seed: number) => this.entries[seed - 1]?.userId ?? null;
+
+    this.matches = [];
+    for (let round = 1; round <= rounds; round++) {
+      for (let position = 0; position < 2 ** (rounds - round); position++) {
+        this.matches.push({
+          round,
+          position,
+          player1Id: round === 1 ? playerBySeed(order[position * 2]) : null,
+          player2Id: round === 1 ? playerBySeed(order[position * 2 + 1]) : null,
+          winnerId: null,
+          reportedAt: null,
+        });
+      }
+    }
+    this.status = 'in_progress';
+    this.startedAt = at;
+
+    // Seeding never pairs two byes, so every first-round match has at least one player
+    for (const match of this.getRound(1)) {
+      if (!match.player1Id || !match.player2Id) {
+        this.decide(match, (match.player1Id ?? match.player2Id) as string, at);
+      }
+    }
+  }
+
+  reportResult(round: number, position: number, winnerId: string, at: Date): void {
+    const match = this.getMatch(round, position);
+    if (!match) {
+      throw new TournamentError(`There is no match ${position} in round ${round}`);
+    }
+    if (!match.player1Id || !match.player2Id || match.winnerId) {
+      throw new TournamentError(`Match ${position} in round ${round} cannot be reported`);
+    }
+    if (winnerId !== match.player1Id && winnerId !== match.player2Id) {
+      throw new TournamentError(`User ${winnerId} is not playing in this match`);
+    }
+    this.decide(match, winnerId, at);
+  }
+
+  // Rankings for everyone who entered, best first; players still in come before those out
+  getStandings(): TournamentStanding[] {
+    const rounds = this.getRoundCount();
+    return this.entries
+      .map((entry) => {
+        const played = this.matches.filter(
+          (match) =>
+            match.winnerId &&
+            match.player1Id &&
+            match.player2Id &&
+            (match.player1Id === entry.userId || match.player2Id === entry.userId)
+        );
+        const wins = played.filter((match) => match.winnerId === entry.userId).length;
+        const lost = played.find((match) => match.winnerId !== entry.userId);
+        let placement: number | null = null;
+        if (lost) {
+          placement = 2 ** (rounds - lost.round) + 1;
+        } else if (this.status === 'complete') {
+          placement = 1;
+        }
+        return {
+          userId: entry.userId,
+          seed: entry.seed,
+          wins,
+          losses: played.length - wins,
+          placement,
+        };
+      })
+      .sort(
+        (a, b) =>
+          (a.placement ?? 0) - (b.placement ?? 0) ||
+          b.wins - a.wins ||
+          (a.seed ?? Infinity) - (b.seed ?? Infinity)
+      );
+  }
+
+  private decide(match: TournamentMatch, winnerId: string, at: Date): void {
+    match.winnerId = winnerId;
+    match.reportedAt = at;
+
+    const next = this.getMatch(match.round + 1, Math.floor(match.position / 2));
+    if (!next) {
+      this.status = 'complete';
+      return;
+    }
+    if (match.position % 2 === 0) {
+      next.player1Id = winnerId;
+    } else {
+      next.player2Id = winnerId;
+    }
+  }
+
+  isFull(): boolean {
+    return this.entries.length >= this.maxEntries;
+  }
+
+  getEntry(userId: string): TournamentEntry | null {
+    return this.entries.find((entry) => entry.userId === userId) ?? null;
+  }
+
+  getMatch(round: number, position: number): TournamentMatch | null {
+    return (
+      this.matches.find((match) => match.round === round && match.position === position) ?? null
+    );
+  }
+
+  getRound(round: number): TournamentMatch[] {
+    return this.matches.filter((match) => match.round === round);
+  }
+
+  getRoundCount(): number {
+    return this.matches.reduce((rounds, match) => Math.max(rounds, match.round), 0);
+  }
+
+  getTournamentId(): string {
+    return this.tournamentId;
+  }
+
+  getOrganizerId(): string {
+    return this.organizerId;
+  }
+
+  getName(): string {
+    return this.name;
+  }
+
+  getFormat(): FormatId {
+    return this.format;
+  }
+
+  getMaxEntries(): number {
+    return this.maxEntries;
+  }
+
+  getStatus(): TournamentStatus {
+    return this.status;
+  }
+
+  // In registration order, which is also seed order once the bracket is drawn
+  getEntries(): TournamentEntry[] {
+    return [...this.entries];
+  }
+
+  getMatches(): TournamentMatch[] {
+    return this.matches.map((match) => ({ ...match }));
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+
+  getStartedAt(): Date | null {
+    return this.startedAt;
+  }
+
+  getVersion(): number {
+    return this.version;
+  }
+
+  // Called by the repository once the tournament is stored as the next version
+  markSaved(): void {
+    this.version++;
+  }
+}
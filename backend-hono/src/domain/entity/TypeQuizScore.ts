@@ -0,0 +1,72 @@
+// A user's running tally in the type quiz
+export class TypeQuizScore {
+  private readonly userId: string;
+  private answered: number;
+  private correct: number;
+  private currentStreak: number;
+  private bestStreak: number;
+
+  constructor(
+    userId: string,
+    answered: number,
+    correct: number,
+    currentStreak: number,
+    bestStreak: number
+  ) {
+    this.userId = userId;
+    this.answered = answered;
+    this.correct = correct;
+    this.currentStreak = currentStreak;
+    this.bestStreak = bestStreak;
+  }
+
+  static create(userId: string): TypeQuizScore {
+    return new TypeQuizScore(userId, 0, 0, 0, 0);
+  }
+
+  static fromRepository(
+    userId: string,
+    answered: number,
+    correct: number,
+    currentStreak: number,
+    bestStreak: number
+  ): TypeQuizScore {
+    return new TypeQuizScore(userId, answered, correct, currentStreak, bestStreak);
+  }
+
+  record(isCorrect: boolean): void {
+    this.answered++;
+    if (isCorrect) {
+      this.correct++;
+      this.currentStreak++;
+      this.bestStreak = Math.max(this.bestStreak, this.currentStreak);
+    } else {
+      this.currentStreak = 0;
+    }
+  }
+
+  getUserId(): string {
+    return this.userId;
+  }
+
+  getAnswered(): number {
+    return this.answered;
+  }
+
+  getCorrect(): number {
+    return this.correct;
+  }
+
+  // 0-100; 0 before the first answer
+  getAccuracy(): number {
+    return this.answered === 0 ? 0 : Math.round((this.correct / this.answered) * 1000) / 10;
+  }
+
+  getCurrentStreak(): number {
+    return this.currentStreak;
+  }
+
+  getBestStreak(): number {
+    return this.bestStreak;
+  }
+}
@@ -0,0 +1,130 @@
+import { z } from 'zod';
+import { FormatId } from '../valueobject/Format';
+import { Nature, NatureSchema } from '../valueobject/Nature';
+import { FormId } from '../valueobject/PokemonId';
+import { Stats } from '../valueobject/Stats';
+
+export const UsageCategorySchema = z.enum(['move', 'item', 'ability', 'tera_type', 'spread']);
+
+export type UsageCategory = z.infer<typeof UsageCategorySchema>;
+
+export interface UsageDetail {
+  name: string;
+  percentage: number; // 0-100
+}
+
+export interface SpreadUsage {
+  nature: Nature;
+  evs: Stats;
+  percentage: number;
+}
+
+// The most used option in each category, as a single representative set
+export interface CommonSet {
+  ability?: string;
+  item?: string;
+  moves: string[];
+  teraType?: string;
+  spread?: SpreadUsage;
+}
+
+export interface PokemonUsageProps {
+  formId: FormId;
+  rank: number;
+  usage: number; // 0-100
+  rawCount: number;
+  details: Record<UsageCategory, UsageDetail[]>;
+}
+
+export class PokemonUsage {
+  constructor(private readonly props: PokemonUsageProps) {}
+
+  static fromRepository(props: PokemonUsageProps): PokemonUsage {
+    return new PokemonUsage(props);
+  }
+
+  getFormId(): FormId {
+    return this.props.formId;
+  }
+
+  getRank(): number {
+    return this.props.rank;
+  }
+
+  getUsage(): number {
+    return this.props.usage;
+  }
+
+  getRawCount(): number {
+    return this.props.rawCount;
+  }
+
+  getDetails(category: UsageCategory): UsageDetail[] {
+    return [...this.props.details[category]].sort((a, b) => b.percentage - a.percentage);
+  }
+
+  getTop(category: UsageCategory): UsageDetail | undefined {
+    return this.getDetails(category)[0];
+  }
+
+  // Smogon spreads look like "Jolly:0/252/4/0/0/252"
+  getSpreads(): SpreadUsage[] {
+    const spreads: SpreadUsage[] = [];
+    for (const detail of this.getDetails('spread')) {
+      const [natureName, evText] = detail.name.split(':');
+      const nature = NatureSchema.safeParse(natureName);
+      const evs = (evText ?? '').split('/').map(Number);
+      if (!nature.success || evs.length !== 6 || evs.some(Number.isNaN)) {
+        continue;
+      }
+
+      const [hp, attack, defense, specialAttack, specialDefense, speed] = evs;
+      spreads.push({
+        nature: nature.data,
+        evs: { hp, attack, defense, specialAttack, specialDefense, speed },
+        percentage: detail.percentage,
+      });
+    }
+    return spreads;
+  }
+
+  getNatures(): UsageDetail[] {
+    const totals = new Map<string, number>();
+    for (const spread of this.getSpreads()) {
+      totals.set(spread.nature, (totals.get(spread.nature) ?? 0) + spread.percentage);
+    }
+    return [...totals.entries()]
+      .map(([name, percentage]) => ({ name, percentage }))
+      .sort((a, b) => b.percentage - a.percentage);
+  }
+
+  getCommonSet(): CommonSet {
+    return {
+      ability: this.getTop('ability')?.name,
+      item: this.getTop('item')?.name,
+      moves: this.getDetails('move')
+        .slice(0, 4)
+        .map((move) => move.name),
+      teraType: this.getTop('tera_type')?.name,
+      spread: this.getSpreads()[0],
+    };
+  }
+}
+
+export class UsageSnapshot {
+  constructor(
+    public readonly formatId: FormatId,
+    public readonly period: string, // YYYY-MM
+    public readonly cutoff: number,
+    public readonly battles: number,
+    private readonly entries: PokemonUsage[]
+  ) {
+    if (!/^\d{4}-\d{2}$/.test(period)) {
+      throw new Error('Usage period must be formatted as YYYY-MM');
+    }
+  }
+
+  getEntries(): PokemonUsage[] {
+    return [...this.entries].sort((a, b) => a.getRank() - b.getRank());
+  }
+}
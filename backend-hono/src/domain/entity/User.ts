@@ -1,22 +1,25 @@
-import { Username } from '../valueobject/Username';
+import { Username, UsernameMode } from '../valueobject/Username';
 import { Email } from '../valueobject/Email';
 import { HashedPassword } from '../valueobject/HashedPassword';
+import { UserRole } from '../valueobject/UserRole';
 
 export class User {
   constructor(
     private readonly userId: string,
     private readonly username: Username,
     private readonly email: Email,
-    private readonly hashedPassword: HashedPassword
+    private readonly hashedPassword: HashedPassword,
+    private readonly role: UserRole = 'user'
   ) {}
 
   static async create(
     userId: string,
     username: string,
     email: string,
-    plainPassword: string
+    plainPassword: string,
+    usernameMode: UsernameMode = 'ascii'
   ): Promise<User> {
-    const usernameVO = Username.create(username);
+    const usernameVO = Username.create(username, usernameMode);
     const emailVO = Email.create(email);
     const passwordVO = await HashedPassword.fromPlainText(plainPassword);
 
@@ -27,13 +30,14 @@ export class User {
     userId: string,
     username: string,
     email: string,
-    passwordHash: string
+    passwordHash: string,
+    role: UserRole
   ): User {
-    const usernameVO = Username.create(username);
+    const usernameVO = Username.fromRepository(username);
     const emailVO = Email.create(email);
     const passwordVO = HashedPassword.fromHash(passwordHash);
 
-    return new User(userId, usernameVO, emailVO, passwordVO);
+    return new User(userId, usernameVO, emailVO, passwordVO, role);
   }
 
   async verifyPassword(plainPassword: string): Promise<boolean> {
@@ -55,4 +59,12 @@ export class User {
   getPasswordHash(): string {
     return this.hashedPassword.getHash();
   }
+
+  getRole(): UserRole {
+    return this.role;
+  }
+
+  isAdmin(): boolean {
+    return this.role === 'admin';
+  }
 }
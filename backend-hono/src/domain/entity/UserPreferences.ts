@@ -0,0 +1,87 @@
+import { z } from 'zod';
+import { FormatId, FormatIdSchema } from '../valueobject/Format';
+import { Lang, LangSchema } from '../valueobject/Lang';
+import { CalculationMode, CalculationModeSchema } from '../valueobject/Level';
+
+export const ThemeSchema = z.enum(['system', 'light', 'dark']);
+
+export type Theme = z.infer<typeof ThemeSchema>;
+
+export interface PreferenceChanges {
+  // null follows the browser's Accept-Language
+  language?: Lang | null;
+  // null leaves analysis requests to name their format
+  format?: FormatId | null;
+  // Damage calculations and archetypes without a format use the level the user plays at
+  calculationMode?: CalculationMode;
+  theme?: Theme;
+}
+
+// Per-user defaults for what most requests would otherwise have to repeat. A user who never
+// saved any has the defaults, which change nothing about a request.
+export class UserPreferences {
+  constructor(
+    private readonly userId: string,
+    private language: Lang | null,
+    private format: FormatId | null,
+    private calculationMode: CalculationMode,
+    private theme: Theme,
+    private updatedAt: Date | null
+  ) {}
+
+  static defaults(userId: string): UserPreferences {
+    return new UserPreferences(userId, null, null, 'level50', 'system', null);
+  }
+
+  static fromRepository(
+    userId: string,
+    language: Lang | null,
+    format: FormatId | null,
+    calculationMode: CalculationMode,
+    theme: Theme,
+    updatedAt: Date
+  ): UserPreferences {
+    return new UserPreferences(userId, language, format, calculationMode, theme, updatedAt);
+  }
+
+  update(changes: PreferenceChanges): void {
+    if (changes.language !== undefined) {
+      this.language = LangSchema.nullable().parse(changes.language);
+    }
+    if (changes.format !== undefined) {
+      this.format = FormatIdSchema.nullable().parse(changes.format);
+    }
+    if (changes.calculationMode !== undefined) {
+      this.calculationMode = CalculationModeSchema.parse(changes.calculationMode);
+    }
+    if (changes.theme !== undefined) {
+      this.theme = ThemeSchema.parse(changes.theme);
+    }
+    this.updatedAt = new Date();
+  }
+
+  getUserId(): string {
+    return this.userId;
+  }
+
+  getLanguage(): Lang | null {
+    return this.language;
+  }
+
+  getFormat(): FormatId | null {
+    return this.format;
+  }
+
+  getCalculationMode(): CalculationMode {
+    return this.calculationMode;
+  }
+
+  getTheme(): Theme {
+    return this.theme;
+  }
+
+  // Null until first saved
+  getUpdatedAt(): Date | null {
+    return this.updatedAt;
+  }
+}
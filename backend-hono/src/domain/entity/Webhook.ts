@@ -0,0 +1,80 @@
+import crypto from 'crypto';
+import { TeamEventType } from '../event/TeamEvent';
+
+const SECRET_PREFIX = 'whsec_';
+
+// An endpoint of the user's that receives a signed POST for each team event it subscribes to
+export class Webhook {
+  private readonly webhookId: string;
+  private readonly ownerId: string;
+  private readonly url: string;
+  private readonly secret: string;
+  private readonly events: TeamEventType[];
+  private readonly createdAt: Date;
+
+  constructor(
+    webhookId: string,
+    ownerId: string,
+    url: string,
+    secret: string,
+    events: TeamEventType[],
+    createdAt: Date
+  ) {
+    this.webhookId = webhookId;
+    this.ownerId = ownerId;
+    this.url = url;
+    this.secret = secret;
+    this.events = [...new Set(events)];
+    this.createdAt = createdAt;
+  }
+
+  static create(webhookId: string, ownerId: string, url: string, events: TeamEventType[]): Webhook {
+    const secret = `${SECRET_PREFIX}${crypto.randomBytes(24).toString('base64url')}`;
+    return new Webhook(webhookId, ownerId, url, secret, events, new Date());
+  }
+
+  static fromRepository(
+    webhookId: string,
+    ownerId: string,
+    url: string,
+    secret: string,
+    events: TeamEventType[],
+    createdAt: Date
+  ): Webhook {
+    return new Webhook(webhookId, ownerId, url, secret, events, createdAt);
+  }
+
+  subscribesTo(type: TeamEventType): boolean {
+    return this.events.includes(type);
+  }
+
+  // HMAC-SHA256 over "<timestamp>.<body>"; the timestamp lets receivers reject old replays
+  sign(timestamp: number, body: string): string {
+    return crypto.createHmac('sha256', this.secret).update(`${timestamp}.${body}`).digest('hex');
+  }
+
+  getWebhookId(): string {
+    return this.webhookId;
+  }
+
+  getOwnerId(): string {
+    return this.ownerId;
+  }
+
+  getUrl(): string {
+    return this.url;
+  }
+
+  // The secret has to be kept in the clear to sign with; it is only shown when registering
+  getSecret(): string {
+    return this.secret;
+  }
+
+  getEvents(): TeamEventType[] {
+    return [...this.events];
+  }
+
+  getCreatedAt(): Date {
+    return this.createdAt;
+  }
+}
@@ -0,0 +1,59 @@
+import { Team } from '../entity/Team';
+import { TeamComment } from '../entity/TeamComment';
+
+// What users do that other users (or they themselves, later) want to hear about. Unlike team
+// events these never leave the process, so they carry no ID for deduplication.
+export type ActivityEvent = CommentPostedEvent | TeamLikedEvent | ImportFinishedEvent;
+
+export interface CommentPostedEvent {
+  type: 'comment.posted';
+  team: Team;
+  comment: TeamComment;
+  authorName: string;
+  // The author of the comment this one answers, for replies
+  repliedToId: string | null;
+  occurredAt: Date;
+}
+
+export interface TeamLikedEvent {
+  type: 'team.liked';
+  team: Team;
+  likes: number;
+  occurredAt: Date;
+}
+
+export interface ImportFinishedEvent {
+  type: 'import.finished';
+  userId: string;
+  created: number;
+  updated: number;
+  occurredAt: Date;
+}
+
+export function commentPosted(
+  team: Team,
+  comment: TeamComment,
+  authorName: string,
+  repliedToId: string | null
+): CommentPostedEvent {
+  return {
+    type: 'comment.posted',
+    team,
+    comment,
+    authorName,
+    repliedToId,
+    occurredAt: new Date(),
+  };
+}
+
+export function teamLiked(team: Team, likes: number): TeamLikedEvent {
+  return { type: 'team.liked', team, likes, occurredAt: new Date() };
+}
+
+export function importFinished(
+  userId: string,
+  created: number,
+  updated: number
+): ImportFinishedEvent {
+  return { type: 'import.finished', userId, created, updated, occurredAt: new Date() };
+}
@@ -0,0 +1,17 @@
+export type EventHandler<E> = (event: E) => Promise<void>;
+
+// In-process publish/subscribe: services publish what happened and subscribers (webhooks, ...)
+// react without the publisher knowing about them. Handlers run in subscription order.
+export class EventBus<E> {
+  private readonly handlers: EventHandler<E>[] = [];
+
+  subscribe(handler: EventHandler<E>): void {
+    this.handlers.push(handler);
+  }
+
+  async publish(event: E): Promise<void> {
+    for (const handler of this.handlers) {
+      await handler(event);
+    }
+  }
+}
@@ -0,0 +1,22 @@
+import { z } from 'zod';
+import { Team } from '../entity/Team';
+
+export const TeamEventTypeSchema = z.enum(['team.created', 'team.updated']);
+
+export type TeamEventType = z.infer<typeof TeamEventTypeSchema>;
+
+export interface TeamEvent {
+  // Stays the same across webhook retries, so receivers can drop duplicates
+  eventId: string;
+  type: TeamEventType;
+  team: Team;
+  occurredAt: Date;
+}
+
+export function teamCreated(eventId: string, team: Team): TeamEvent {
+  return { eventId, type: 'team.created', team, occurredAt: new Date() };
+}
+
+export function teamUpdated(eventId: string, team: Team): TeamEvent {
+  return { eventId, type: 'team.updated', team, occurredAt: new Date() };
+}
@@ -0,0 +1,67 @@
+// Public API of the Pokémon domain, built on its own with `npm run build:domain` and
+// published as `pokedict-backend/domain`. Everything exported here depends on zod only: no
+// web framework, database, Node.js API or auth library, so it also runs in a browser.
+//
+// Account modules (User, RefreshToken, Email, Username, HashedPassword, JWT, UserRole), API
+// keys (ApiKey, ApiKeyScope) and webhooks (Webhook) need bcrypt, JWT and Node's crypto, and stay
+// private to the backend.
+
+export * from './bindings';
+
+export * from './entity/Ability';
+export * from './entity/CustomForm';
+export * from './entity/Item';
+export * from './entity/Move';
+export * from './entity/PokemonMaster';
+export * from './entity/PokemonSpecies';
+
+export * from './service/AbilityModifiers';
+export * from './service/ArchetypeClassifier';
+export * from './service/BattleSimulator';
+export * from './service/CoverageSolver';
+export * from './service/DamageCalculator';
+export * from './service/DefensiveCore';
+export * from './service/DefensiveProfile';
+export * from './service/EntryHazards';
+export * from './service/MoveTypeResolver';
+export * from './service/Rng';
+export * from './service/SeededRandom';
+export * from './service/StatCalculator';
+export * from './service/TeamMatchup';
+export * from './service/TurnOrder';
+export * from './service/TypeQuiz';
+
+export * from './valueobject/AbilitySlots';
+export * from './valueobject/DexNumber';
+export * from './valueobject/EggGroup';
+export * from './valueobject/Encounter';
+export * from './valueobject/FormMedia';
+export * from './valueobject/Format';
+export * from './valueobject/Friendship';
+export * from './valueobject/Generation';
+export * from './valueobject/GameVersion';
+export * from './valueobject/GenderRatio';
+export * from './valueobject/HomeExport';
+export * from './valueobject/Identifier';
+export * from './valueobject/Interner';
+export * from './valueobject/Lang';
+export * from './valueobject/Learnset';
+export * from './valueobject/LegalityViolation';
+export * from './valueobject/Level';
+export * from './valueobject/MoveSet';
+export * from './valueobject/NameKey';
+export * from './valueobject/Nature';
+export * from './valueobject/Nickname';
+export * from './valueobject/PokemonId';
+export * from './valueobject/PokemonBuild';
+export * from './valueobject/PokemonType';
+export * from './valueobject/RetroStats';
+export * from './valueobject/ShowdownPaste';
+export * from './valueobject/SpeciesCategory';
+export * from './valueobject/StatStages';
+export * from './valueobject/Stats';
+export * from './valueobject/TeamCode';
+export * from './valueobject/TeamNote';
+export * from './valueobject/TeamTag';
+export * from './valueobject/TeamWarning';
+export * from './valueobject/ValidationError';
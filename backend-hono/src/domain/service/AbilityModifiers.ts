@@ -0,0 +1,197 @@
+import { Move } from '../entity/Move';
+import { toLookupKey } from '../valueobject/Identifier';
+import { PokemonType } from '../valueobject/PokemonType';
+import type { Combatant } from './DamageCalculator';
+
+// Points in the damage formula where a modifier applies, in evaluation order
+export const MODIFIER_STAGES = [
+  'effectiveness',
+  'basePower',
+  'attack',
+  'defense',
+  'stab',
+  'final',
+] as const;
+
+export type ModifierStage = (typeof MODIFIER_STAGES)[number];
+
+export interface DamageContext {
+  attacker: Combatant;
+  defender: Combatant;
+  // Partners on the field in doubles
+  attackerAlly?: Combatant;
+  defenderAlly?: Combatant;
+  move: Move;
+  critical: boolean;
+  // Type effectiveness before ability modifiers
+  effectiveness: number;
+  // The move's own type, when an -ate ability converted it (see MoveTypeResolver)
+  convertedFrom?: PokemonType;
+}
+
+export type ModifierSide = 'attacker' | 'defender' | 'attackerAlly' | 'defenderAlly';
+
+export interface DamageModifier {
+  ability: string;
+  // Whose ability this is; defender-side abilities are ignored by Mold Breaker and friends
+  side: ModifierSide;
+  stage: ModifierStage;
+  apply(value: number, context: DamageContext): number;
+}
+
+function attackerModifier(
+  ability: string,
+  stage: ModifierStage,
+  apply: DamageModifier['apply']
+): DamageModifier {
+  return { ability, side: 'attacker', stage, apply };
+}
+
+function defenderModifier(
+  ability: string,
+  stage: ModifierStage,
+  apply: DamageModifier['apply']
+): DamageModifier {
+  return { ability, side: 'defender', stage, apply };
+}
+
+function allyModifier(
+  ability: string,
+  side: 'attackerAlly' | 'defenderAlly',
+  stage: ModifierStage,
+  apply: DamageModifier['apply']
+): DamageModifier {
+  return { ability, side, stage, apply };
+}
+
+function typeBoost(ability: string, type: PokemonType, multiplier: number): DamageModifier {
+  return attackerModifier(ability, 'attack', (value, { move }) =>
+    move.getType() === type ? value * multiplier : value
+  );
+}
+
+// Aerilate and friends: the type change happens in MoveTypeResolver, the boost here
+function typeConversion(ability: string): DamageModifier {
+  return attackerModifier(ability, 'basePower', (value, { convertedFrom }) =>
+    convertedFrom ? value * 1.2 : value
+  );
+}
+
+function immunity(ability: string, ...types: PokemonType[]): DamageModifier {
+  return defenderModifier(ability, 'effectiveness', (value, { move }) =>
+    types.includes(move.getType()) ? 0 : value
+  );
+}
+
+const physicalOnly = (multiplier: number) => (value: number, context: DamageContext) =>
+  context.move.isPhysical() ? value * multiplier : value;
+
+const MODIFIERS: DamageModifier[] = [
+  // Attacker abilities
+  attackerModifier('Adaptability', 'stab', (value) => (value > 1 ? value + 0.5 : value)),
+  attackerModifier('Technician', 'basePower', (value) => (value <= 60 ? value * 1.5 : value)),
+  attackerModifier('Huge Power', 'attack', physicalOnly(2)),
+  attackerModifier('Pure Power', 'attack', physicalOnly(2)),
+  attackerModifier('Hustle', 'attack', physicalOnly(1.5)),
+  attackerModifier('Tinted Lens', 'final', (value, { effectiveness }) =>
+    effectiveness > 0 && effectiveness < 1 ? value * 2 : value
+  ),
+  attackerModifier('Sniper', 'final', (value, { critical }) => (critical ? value * 1.5 : value)),
+  attackerModifier('Neuroforce', 'final', (value, { effectiveness }) =>
+    effectiveness > 1 ? value * 1.25 : value
+  ),
+  typeBoost('Transistor', 'Electric', 1.3),
+  typeBoost("Dragon's Maw", 'Dragon', 1.5),
+  typeBoost('Steelworker', 'Steel', 1.5),
+  typeBoost('Water Bubble', 'Water', 2),
+  // After Technician, which looks at the unboosted power
+  typeConversion('Aerilate'),
+  typeConversion('Pixilate'),
+  typeConversion('Refrigerate'),
+  typeConversion('Galvanize'),
+
+  // Defender abilities
+  immunity('Levitate', 'Ground'),
+  immunity('Flash Fire', 'Fire'),
+  immunity('Well-Baked Body', 'Fire'),
+  immunity('Water Absorb', 'Water'),
+  immunity('Storm Drain', 'Water'),
+  immunity('Dry Skin', 'Water'),
+  immunity('Volt Absorb', 'Electric'),
+  immunity('Lightning Rod', 'Electric'),
+  immunity('Motor Drive', 'Electric'),
+  immunity('Sap Sipper', 'Grass'),
+  immunity('Earth Eater', 'Ground'),
+  defenderModifier('Wonder Guard', 'effectiveness', (value) => (value > 1 ? value : 0)),
+  defenderModifier('Thick Fat', 'attack', (value, { move }) =>
+    move.getType() === 'Fire' || move.getType() === 'Ice' ? value * 0.5 : value
+  ),
+  defenderModifier('Water Bubble', 'attack', (value, { move }) =>
+    move.getType() === 'Fire' ? value * 0.5 : value
+  ),
+  defenderModifier('Fur Coat', 'defense', physicalOnly(2)),
+  // Calculations assume the defender is at full HP
+  defenderModifier('Multiscale', 'final', (value) => value * 0.5),
+  defenderModifier('Shadow Shield', 'final', (value) => value * 0.5),
+  defenderModifier('Solid Rock', 'final', (value, { effectiveness }) =>
+    effectiveness > 1 ? value * 0.75 : value
+  ),
+  defenderModifier('Filter', 'final', (value, { effectiveness }) =>
+    effectiveness > 1 ? value * 0.75 : value
+  ),
+  defenderModifier('Prism Armor', 'final', (value, { effectiveness }) =>
+    effectiveness > 1 ? value * 0.75 : value
+  ),
+  defenderModifier('Ice Scales', 'final', (value, { move }) =>
+    move.isPhysical() ? value : value * 0.5
+  ),
+
+  // Partner abilities in doubles
+  allyModifier('Battery', 'attackerAlly', 'basePower', (value, { move }) =>
+    move.isPhysical() ? value : value * 1.3
+  ),
+  allyModifier('Power Spot', 'attackerAlly', 'basePower', (value) => value * 1.3),
+  allyModifier('Steely Spirit', 'attackerAlly', 'basePower', (value, { move }) =>
+    move.getType() === 'Steel' ? value * 1.5 : value
+  ),
+  allyModifier('Friend Guard', 'defenderAlly', 'final', (value) => value * 0.75),
+];
+
+// Shadow Shield and Prism Armor cannot be ignored, but the rest of the defender's ability can
+const UNBREAKABLE_ABILITIES = ['shadowshield', 'prismarmor'];
+const MOLD_BREAKER_ABILITIES = ['moldbreaker', 'teravolt', 'turboblaze'];
+
+function abilityKey(combatant: Combatant): string {
+  return toLookupKey(combatant.build.getAbility() ?? '');
+}
+
+// Modifiers from both sides' abilities that are active for this attack
+export function getActiveModifiers(context: DamageContext): DamageModifier[] {
+  const abilities: Record<ModifierSide, string | undefined> = {
+    attacker: abilityKey(context.attacker),
+    defender: abilityKey(context.defender),
+    attackerAlly: context.attackerAlly && abilityKey(context.attackerAlly),
+    defenderAlly: context.defenderAlly && abilityKey(context.defenderAlly),
+  };
+  const ignoresDefender = MOLD_BREAKER_ABILITIES.includes(abilities.attacker ?? '');
+
+  return MODIFIERS.filter((modifier) => {
+    const key = toLookupKey(modifier.ability);
+    if (key !== abilities[modifier.side]) {
+      return false;
+    }
+    const defending = modifier.side === 'defender' || modifier.side === 'defenderAlly';
+    return !defending || !ignoresDefender || UNBREAKABLE_ABILITIES.includes(key);
+  });
+}
+
+export function applyModifiers(
+  modifiers: DamageModifier[],
+  stage: ModifierStage,
+  value: number,
+  context: DamageContext
+): number {
+  return modifiers
+    .filter((modifier) => modifier.stage === stage)
+    .reduce((current, modifier) => modifier.apply(current, context), value);
+}
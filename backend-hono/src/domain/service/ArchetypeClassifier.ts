@@ -0,0 +1,227 @@
+import { z } from 'zod';
+import { Move } from '../entity/Move';
+import { toLookupKey } from '../valueobject/Identifier';
+import { Combatant } from './DamageCalculator';
+
+export const ArchetypeSchema = z.enum([
+  'hyper_offense',
+  'balance',
+  'stall',
+  'rain',
+  'sun',
+  'sand',
+  'snow',
+  'trick_room',
+  'tailwind',
+]);
+
+export type Archetype = z.infer<typeof ArchetypeSchema>;
+
+export interface ArchetypeMember {
+  combatant: Combatant;
+  moves: Move[]; // every known move, damaging or not
+}
+
+export interface ArchetypeScore {
+  archetype: Archetype;
+  confidence: number; // 0-1
+  features: string[];
+}
+
+const SETUP_MOVES = [
+  'swords-dance',
+  'nasty-plot',
+  'dragon-dance',
+  'calm-mind',
+  'quiver-dance',
+  'bulk-up',
+  'shell-smash',
+  'belly-drum',
+  'shift-gear',
+  'tidy-up',
+  'victory-dance',
+];
+const RECOVERY_MOVES = [
+  'recover',
+  'roost',
+  'soft-boiled',
+  'slack-off',
+  'wish',
+  'moonlight',
+  'synthesis',
+  'morning-sun',
+  'shore-up',
+  'strength-sap',
+  'milk-drink',
+  'rest',
+];
+const HAZARD_MOVES = ['stealth-rock', 'spikes', 'toxic-spikes', 'sticky-web'];
+
+interface WeatherRule {
+  archetype: Archetype;
+  setterAbilities: string[];
+  setterMove: string;
+  abuserAbilities: string[];
+  abuserTypes: string[];
+}
+
+const WEATHER_RULES: WeatherRule[] = [
+  {
+    archetype: 'rain',
+    setterAbilities: ['drizzle'],
+    setterMove: 'rain-dance',
+    abuserAbilities: ['swiftswim', 'raindish', 'dryskin', 'hydration'],
+    abuserTypes: ['Water'],
+  },
+  {
+    archetype: 'sun',
+    setterAbilities: ['drought', 'orichalcumpulse'],
+    setterMove: 'sunny-day',
+    abuserAbilities: ['chlorophyll', 'solarpower', 'protosynthesis'],
+    abuserTypes: ['Fire'],
+  },
+  {
+    archetype: 'sand',
+    setterAbilities: ['sandstream'],
+    setterMove: 'sandstorm',
+    abuserAbilities: ['sandrush', 'sandforce', 'sandveil'],
+    abuserTypes: ['Rock'],
+  },
+  {
+    archetype: 'snow',
+    setterAbilities: ['snowwarning'],
+    setterMove: 'snowscape',
+    abuserAbilities: ['slushrush', 'icebody', 'snowcloak'],
+    abuserTypes: ['Ice'],
+  },
+];
+
+// Base speed thresholds for "fast" and "slow" members
+const FAST_BASE_SPEED = 100;
+const SLOW_BASE_SPEED = 50;
+
+const clamp = (value: number) => Math.min(1, Math.max(0, value));
+const round = (value: number) => Math.round(value * 100) / 100;
+
+// Labels a team from member roles, speed distribution and moves. Every archetype with a
+// non-zero confidence is returned, most likely first, with the features that drove it.
+export function classifyArchetypes(members: ArchetypeMember[]): ArchetypeScore[] {
+  if (members.length === 0) {
+    return [];
+  }
+
+  const size = members.length;
+  const knows = (member: ArchetypeMember, moves: string[]) =>
+    member.moves.some((move) => moves.includes(move.getName()));
+  const ability = (member: ArchetypeMember) =>
+    toLookupKey(member.combatant.build.getAbility() ?? '');
+
+  const offensive = members.filter(
+    (member) => member.moves.filter((move) => move.isDamaging()).length >= 3
+  );
+  const setup = members.filter((member) => knows(member, SETUP_MOVES));
+  const recovery = members.filter((member) => knows(member, RECOVERY_MOVES));
+  const defensive = members.filter((member) => {
+    const ev = member.combatant.build.getEV();
+    return knows(member, RECOVERY_MOVES) || ev.hp + ev.defense + ev.specialDefense >= 400;
+  });
+  const hazards = members.filter((member) => knows(member, HAZARD_MOVES));
+  const baseSpeed = (member: ArchetypeMember) => member.combatant.form.getBaseStats().speed;
+  const fast = members.filter((member) => baseSpeed(member) >= FAST_BASE_SPEED);
+  const slow = members.filter((member) => baseSpeed(member) <= SLOW_BASE_SPEED);
+
+  const offenseShare = offensive.length / size;
+  const defenseShare = defensive.length / size;
+  const scores: ArchetypeScore[] = [];
+
+  scores.push({
+    archetype: 'hyper_offense',
+    confidence: clamp(
+      offenseShare * 0.5 +
+        (fast.length / size) * 0.3 +
+        (Math.min(setup.length, 3) / 3) * 0.2 -
+        defenseShare * 0.3
+    ),
+    features: [
+      `${offensive.length}/${size} members carry three or more attacks`,
+      `${fast.length}/${size} members have base Speed ${FAST_BASE_SPEED}+`,
+      `${setup.length} setup move user(s)`,
+    ],
+  });
+
+  scores.push({
+    archetype: 'balance',
+    confidence: clamp(1 - Math.abs(offenseShare - 0.5) * 1.5 - Math.abs(defenseShare - 0.4) * 1.5),
+    features: [
+      `${offensive.length}/${size} offensive members`,
+      `${defensive.length}/${size} defensive members`,
+    ],
+  });
+
+  scores.push({
+    archetype: 'stall',
+    confidence: clamp(
+      defenseShare * 0.6 +
+        (recovery.length / size) * 0.2 +
+        (hazards.length > 0 ? 0.2 : 0) -
+        offenseShare * 0.3
+    ),
+    features: [
+      `${defensive.length}/${size} defensive members`,
+      `${recovery.length} reliable recovery user(s)`,
+      `${hazards.length} hazard setter(s)`,
+    ],
+  });
+
+  for (const rule of WEATHER_RULES) {
+    const setters = members.filter(
+      (member) =>
+        rule.setterAbilities.includes(ability(member)) || knows(member, [rule.setterMove])
+    );
+    if (setters.length === 0) {
+      continue;
+    }
+    const abusers = members.filter(
+      (member) =>
+        rule.abuserAbilities.includes(ability(member)) ||
+        member.moves.some((move) => move.isDamaging() && rule.abuserTypes.includes(move.getType()))
+    );
+    scores.push({
+      archetype: rule.archetype,
+      confidence: clamp(0.5 + (abusers.length / size) * 0.5),
+      features: [
+        `Weather setter(s): ${setters.map((member) => member.combatant.form.getName()).join(', ')}`,
+        `${abusers.length}/${size} members benefit from the weather`,
+      ],
+    });
+  }
+
+  const trickRoomSetters = members.filter((member) => knows(member, ['trick-room']));
+  if (trickRoomSetters.length > 0) {
+    scores.push({
+      archetype: 'trick_room',
+      confidence: clamp(0.4 + (slow.length / size) * 0.6),
+      features: [
+        `${trickRoomSetters.length} Trick Room setter(s)`,
+        `${slow.length}/${size} members have base Speed ${SLOW_BASE_SPEED} or lower`,
+      ],
+    });
+  }
+
+  const tailwindSetters = members.filter((member) => knows(member, ['tailwind']));
+  if (tailwindSetters.length > 0) {
+    scores.push({
+      archetype: 'tailwind',
+      confidence: clamp(0.5 + offenseShare * 0.5),
+      features: [
+        `${tailwindSetters.length} Tailwind setter(s)`,
+        `${offensive.length}/${size} offensive members to follow up`,
+      ],
+    });
+  }
+
+  return scores
+    .filter((score) => score.confidence > 0)
+    .map((score) => ({ ...score, confidence: round(score.confidence) }))
+    .sort((a, b) => b.confidence - a.confidence);
+}
@@ -0,0 +1,316 @@
+import { Move } from '../entity/Move';
+import { toLookupKey } from '../valueobject/Identifier';
+import { PokemonType } from '../valueobject/PokemonType';
+import { calculateDamage, Combatant, DamageResult, getHitOdds } from './DamageCalculator';
+import { Rng } from './Rng';
+import { determineTurnOrder, FieldState } from './TurnOrder';
+
+// One Pokémon and the moves it may pick from
+export interface BattleSide {
+  combatant: Combatant;
+  moves: Move[];
+}
+
+// HP and status carry over when a Pokémon fights more than once, as in a team battle
+export interface BattlerState {
+  hp: number;
+  maxHp: number;
+  status?: BattleStatus;
+}
+
+export interface BattleOutcome {
+  winner?: 0 | 1;
+  turns: number;
+}
+
+export interface SimulationResult {
+  simulations: number;
+  wins: [number, number];
+  // Both sides fainted on the same action, or neither within MAX_TURNS
+  draws: number;
+  averageTurns: number;
+}
+
+const STATUSES = ['paralysis', 'burn', 'poison'] as const;
+
+export type BattleStatus = (typeof STATUSES)[number];
+
+const MAX_TURNS = 100;
+const CRITICAL_CHANCE = 1 / 24;
+const FULL_PARALYSIS_CHANCE = 0.25;
+
+// Fraction of max HP lost at the end of each turn
+const STATUS_DAMAGE: Partial<Record<BattleStatus, number>> = { burn: 1 / 16, poison: 1 / 8 };
+
+const STATUS_IMMUNITIES: Record<BattleStatus, PokemonType[]> = {
+  paralysis: ['Electric'],
+  burn: ['Fire'],
+  poison: ['Poison', 'Steel'],
+};
+
+export interface MoveOption {
+  move: Move;
+  normal: DamageResult;
+  critical: DamageResult;
+  hitOdds: [number, number][];
+}
+
+// Both sides' moves against each other; stat stages never change, so the damage is worked out
+// once per pairing
+export interface Matchup {
+  sides: [BattleSide, BattleSide];
+  options: [MoveOption[], MoveOption[]];
+}
+
+interface Battler {
+  combatant: Combatant;
+  options: MoveOption[];
+  state: BattlerState;
+}
+
+// Plays the 1v1 out `simulations` times. Each turn both sides use the move with the highest
+// expected damage; accuracy, critical hits, damage rolls, multi-hit counts, flinching, drain,
+// recoil and the paralysis, burn and poison a move can inflict are rolled. Status moves, stat
+// changes and switching are not modelled.
+export function simulateBattle(
+  first: BattleSide,
+  second: BattleSide,
+  field: FieldState,
+  simulations: number,
+  random: Rng
+): SimulationResult {
+  const matchup = prepareMatchup(first, second);
+  const wins: [number, number] = [0, 0];
+  let draws = 0;
+  let totalTurns = 0;
+
+  for (let i = 0; i < simulations; i++) {
+    const states: [BattlerState, BattlerState] = [
+      createBattlerState(first),
+      createBattlerState(second),
+    ];
+    const { winner, turns } = playBattle(matchup, states, field, random);
+    if (winner === undefined) {
+      draws++;
+    } else {
+      wins[winner]++;
+    }
+    totalTurns += turns;
+  }
+
+  return {
+    simulations,
+    wins,
+    draws,
+    averageTurns: Math.round((totalTurns / simulations) * 10) / 10,
+  };
+}
+
+export function prepareMatchup(first: BattleSide, second: BattleSide): Matchup {
+  return { sides: [first, second], options: [toOptions(first, second), toOptions(second, first)] };
+}
+
+export function createBattlerState(side: BattleSide): BattlerState {
+  const maxHp = side.combatant.getStats().hp;
+  return { hp: maxHp, maxHp };
+}
+
+// The most damage one side expects to deal in a turn, as a fraction of the opponent's max HP
+export function getExpectedDamageShare(matchup: Matchup, index: 0 | 1): number {
+  const attacker = {
+    combatant: matchup.sides[index].combatant,
+    options: matchup.options[index],
+    state: createBattlerState(matchup.sides[index]),
+  };
+  const best = chooseMove(attacker);
+  const opponentHp = matchup.sides[1 - index].combatant.getStats().hp;
+  return best ? expectedDamage(attacker, best) / opponentHp : 0;
+}
+
+// Fights until a side faints or MAX_TURNS pass, updating both states
+export function playBattle(
+  matchup: Matchup,
+  states: [BattlerState, BattlerState],
+  field: FieldState,
+  random: Rng
+): BattleOutcome {
+  const battlers = ([0, 1] as const).map((index) => ({
+    combatant: matchup.sides[index].combatant,
+    options: matchup.options[index],
+    state: states[index],
+  }));
+  const outcome = (): 0 | 1 | undefined => {
+    const [firstAlive, secondAlive] = battlers.map((battler) => battler.state.hp > 0);
+    if (firstAlive === secondAlive) {
+      return undefined;
+    }
+    return firstAlive ? 0 : 1;
+  };
+  const isOver = () => battlers.some((battler) => battler.state.hp <= 0);
+
+  for (let turn = 1; turn <= MAX_TURNS; turn++) {
+    const choices = battlers.map(chooseMove);
+    const order = getOrder(battlers, choices, field, random);
+
+    let flinched = false;
+    for (const index of order) {
+      const attacker = battlers[index];
+      const choice = choices[index];
+      if (!choice || flinched || isParalyzedThisTurn(attacker, random)) {
+        continue;
+      }
+      flinched = useMove(attacker, battlers[1 - index], choice, random);
+      if (isOver()) {
+        return { winner: outcome(), turns: turn };
+      }
+    }
+
+    for (const { state } of battlers) {
+      const fraction = state.status && STATUS_DAMAGE[state.status];
+      if (fraction) {
+        state.hp -= Math.max(1, Math.floor(state.maxHp * fraction));
+      }
+    }
+    if (isOver()) {
+      return { winner: outcome(), turns: turn };
+    }
+  }
+
+  return { turns: MAX_TURNS };
+}
+
+function toOptions(side: BattleSide, opponent: BattleSide): MoveOption[] {
+  const { combatant } = side;
+  return side.moves.map((move) => ({
+    move,
+    normal: calculateDamage(combatant, opponent.combatant, move),
+    critical: calculateDamage(combatant, opponent.combatant, move, { critical: true }),
+    hitOdds: getHitOdds(combatant, move),
+  }));
+}
+
+function chooseMove(battler: Battler): MoveOption | undefined {
+  let best: MoveOption | undefined;
+  let bestDamage = 0;
+  for (const option of battler.options) {
+    const damage = expectedDamage(battler, option);
+    if (damage > bestDamage) {
+      best = option;
+      bestDamage = damage;
+    }
+  }
+  return best;
+}
+
+function expectedDamage(battler: Battler, option: MoveOption): number {
+  const { rolls } = option.normal;
+  const averageRoll = rolls.reduce((sum, roll) => sum + roll, 0) / rolls.length;
+  const averageHits = option.hitOdds.reduce((sum, [hits, chance]) => sum + hits * chance, 0);
+  const accuracy = (option.move.getAccuracy() ?? 100) / 100;
+  return averageRoll * averageHits * accuracy * burnMultiplier(battler, option.move);
+}
+
+// Burn halves physical damage, except for Guts users
+function burnMultiplier(battler: Battler, move: Move): number {
+  const guts = toLookupKey(battler.combatant.build.getAbility() ?? '') === 'guts';
+  return battler.state.status === 'burn' && move.isPhysical() && !guts ? 0.5 : 1;
+}
+
+// Indices of the battlers in the order they act this turn
+function getOrder(
+  battlers: Battler[],
+  choices: (MoveOption | undefined)[],
+  field: FieldState,
+  random: Rng
+): (0 | 1)[] {
+  const [firstChoice, secondChoice] = choices;
+  if (!firstChoice || !secondChoice) {
+    return [0, 1];
+  }
+  const { firstMovesFirst } = determineTurnOrder(
+    {
+      combatant: battlers[0].combatant,
+      move: firstChoice.move,
+      paralyzed: battlers[0].state.status === 'paralysis',
+    },
+    {
+      combatant: battlers[1].combatant,
+      move: secondChoice.move,
+      paralyzed: battlers[1].state.status === 'paralysis',
+    },
+    field
+  );
+  return random.next() < firstMovesFirst ? [0, 1] : [1, 0];
+}
+
+function isParalyzedThisTurn(battler: Battler, random: Rng): boolean {
+  return battler.state.status === 'paralysis' && random.next() < FULL_PARALYSIS_CHANCE;
+}
+
+// Returns whether the target flinched
+function useMove(
+  attacker: Battler,
+  defender: Battler,
+  option: MoveOption,
+  random: Rng
+): boolean {
+  const { move } = option;
+  const accuracy = move.getAccuracy();
+  if (accuracy !== undefined && random.next() * 100 >= accuracy) {
+    return false;
+  }
+
+  const result = random.next() < CRITICAL_CHANCE ? option.critical : option.normal;
+  const multiplier = burnMultiplier(attacker, move);
+  const hits = rollHits(option.hitOdds, random);
+  let damage = 0;
+  for (let hit = 0; hit < hits; hit++) {
+    const roll = result.rolls[random.nextInt(result.rolls.length)];
+    damage += roll > 0 ? Math.max(1, Math.floor(roll * multiplier)) : 0;
+  }
+  const dealt = Math.min(damage, defender.state.hp);
+  defender.state.hp -= dealt;
+
+  const effects = move.getEffects();
+  if (!effects || dealt === 0) {
+    return false;
+  }
+  if (effects.drain > 0) {
+    const healed = Math.max(1, Math.floor(dealt * effects.drain));
+    attacker.state.hp = Math.min(attacker.state.maxHp, attacker.state.hp + healed);
+  }
+  if (effects.recoil > 0) {
+    attacker.state.hp -= Math.max(1, Math.floor(dealt * effects.recoil));
+  }
+  const { ailment } = effects;
+  if (defender.state.hp > 0 && ailment && isStatus(ailment)) {
+    if (random.next() * 100 < effects.ailmentChance) {
+      inflict(defender, ailment);
+    }
+  }
+  return random.next() * 100 < effects.flinchChance;
+}
+
+function rollHits(hitOdds: [number, number][], random: Rng): number {
+  let roll = random.next();
+  for (const [hits, chance] of hitOdds) {
+    roll -= chance;
+    if (roll < 0) {
+      return hits;
+    }
+  }
+  return hitOdds[hitOdds.length - 1][0];
+}
+
+function isStatus(ailment: string): ailment is BattleStatus {
+  return (STATUSES as readonly string[]).includes(ailment);
+}
+
+// A Pokémon has at most one status, and some types cannot get certain ones
+function inflict(battler: Battler, status: BattleStatus): void {
+  const types = battler.combatant.getDefensiveTypes().getTypes();
+  if (battler.state.status || STATUS_IMMUNITIES[status].some((type) => types.includes(type))) {
+    return;
+  }
+  battler.state.status = status;
+}
@@ -0,0 +1,103 @@
+import { z } from 'zod';
+
+// Text users write that other users can see
+export const ContentKindSchema = z.enum(['team_name', 'nickname', 'comment']);
+
+export type ContentKind = z.infer<typeof ContentKindSchema>;
+
+export const ContentReasonSchema = z.enum(['blocked_word', 'watched_word', 'link', 'link_spam']);
+
+export type ContentReason = z.infer<typeof ContentReasonSchema>;
+
+// allow: publish; review: publish, but queue for a moderator; reject: refuse to publish
+export type ContentAction = 'allow' | 'review' | 'reject';
+
+export interface ContentVerdict {
+  action: ContentAction;
+  reasons: ContentReason[];
+}
+
+export interface ContentPolicy {
+  check(kind: ContentKind, text: string): ContentVerdict;
+}
+
+export interface WordlistPolicyOptions {
+  // Refused outright
+  blockedWords: string[];
+  // Allowed, but a moderator takes a look
+  watchedWords: string[];
+  // A comment with more links than this is refused as spam
+  maxCommentLinks: number;
+}
+
+// Spam seen on Pokémon communities: real-money trading, account sales and hacked giveaways.
+// Deployments with their own lists pass them in.
+export const DEFAULT_WORDLIST_OPTIONS: WordlistPolicyOptions = {
+  blockedWords: [
+    'rmt',
+    'viagra',
+    'casino',
+    'account for sale',
+    '垢販売',
+    'アカウント販売',
+    '出会い系',
+  ],
+  watchedWords: ['giveaway', 'free shiny', 'discord.gg', '配布', '無料', '稼げる', 'line id'],
+  maxCommentLinks: 2,
+};
+
+const LINK_PATTERN =
+  /\b(?:https?:\/\/|www\.)\S+|\b[a-z0-9-]+\.(?:com|net|org|io|gg|xyz|top|info|biz|ru|cn|jp)\b/giu;
+
+// NFKC and lower case, so full-width and upper-case spellings match the lists
+function normalize(text: string): string {
+  return text.normalize('NFKC').toLowerCase();
+}
+
+// Latin words match whole words only, so "rmt" does not catch "hermit"; other scripts are not
+// written with spaces, so their words match anywhere
+function containsWord(text: string, word: string): boolean {
+  if (!/^[\x20-\x7e]+$/.test(word)) {
+    return text.includes(word);
+  }
+  const escaped = word.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  return new RegExp(`(?<![a-z0-9])${escaped}(?![a-z0-9])`, 'u').test(text);
+}
+
+export class WordlistContentPolicy implements ContentPolicy {
+  private readonly blockedWords: string[];
+  private readonly watchedWords: string[];
+
+  constructor(private readonly options: WordlistPolicyOptions = DEFAULT_WORDLIST_OPTIONS) {
+    this.blockedWords = options.blockedWords.map(normalize);
+    this.watchedWords = options.watchedWords.map(normalize);
+  }
+
+  check(kind: ContentKind, text: string): ContentVerdict {
+    const normalized = normalize(text);
+    const rejected: ContentReason[] = [];
+    const reviewed: ContentReason[] = [];
+
+    if (this.blockedWords.some((word) => containsWord(normalized, word))) {
+      rejected.push('blocked_word');
+    }
+    if (this.watchedWords.some((word) => containsWord(normalized, word))) {
+      reviewed.push('watched_word');
+    }
+
+    // Names have no use for links; comments may cite a guide or two
+    const links = normalized.match(LINK_PATTERN)?.length ?? 0;
+    if (links > 0 && kind !== 'comment') {
+      rejected.push('link');
+    } else if (links > this.options.maxCommentLinks) {
+      rejected.push('link_spam');
+    } else if (links > 0) {
+      reviewed.push('link');
+    }
+
+    if (rejected.length > 0) {
+      return { action: 'reject', reasons: [...rejected, ...reviewed] };
+    }
+    return { action: reviewed.length > 0 ? 'review' : 'allow', reasons: reviewed };
+  }
+}
@@ -0,0 +1,72 @@
+import { POKEMON_TYPES, PokemonType, TypeSet } from '../valueobject/PokemonType';
+import { getDefensiveProfile } from './DefensiveProfile';
+
+// A Pokémon the moveset should hit. Weight is its share of the metagame, or the same for every
+// form when the whole dex is the target.
+export interface CoverageTarget {
+  formId: number;
+  name: string;
+  nameJp: string;
+  typeset: TypeSet;
+  // Immunities such as Levitate count when known
+  ability?: string;
+  weight: number;
+}
+
+export interface CoverageCandidate {
+  type: PokemonType;
+  // Weight hit super effectively by at least one type, the candidate included
+  covered: number;
+  // Weight that resists or is immune to every type, the candidate included
+  walled: number;
+  // Targets only the candidate hits super effectively, heaviest first
+  newlyCovered: CoverageTarget[];
+}
+
+export interface CoverageSolution {
+  totalWeight: number;
+  // Coverage of the chosen types alone
+  covered: number;
+  walled: number;
+  // Every type not chosen yet, best first
+  candidates: CoverageCandidate[];
+}
+
+// Ranks each remaining attacking type by how much of the targets the moveset then hits super
+// effectively, trying every type, so the answer is exact for the given targets. Ties go to the
+// type that leaves less walled, then to type order.
+export function solveCoverage(chosen: PokemonType[], targets: CoverageTarget[]): CoverageSolution {
+  const profiles = targets.map((target) => {
+    const profile = getDefensiveProfile(target.typeset, target.ability);
+    const multipliers = new Map(profile.map((matchup) => [matchup.type, matchup.multiplier]));
+    return { target, multipliers };
+  });
+  const best = (multipliers: Map<PokemonType, number>, types: PokemonType[]) =>
+    Math.max(0, ...types.map((type) => multipliers.get(type) ?? 1));
+
+  const score = (types: PokemonType[]) => {
+    let covered = 0;
+    let walled = 0;
+    for (const { target, multipliers } of profiles) {
+      const multiplier = best(multipliers, types);
+      covered += multiplier > 1 ? target.weight : 0;
+      walled += multiplier < 1 ? target.weight : 0;
+    }
+    return { covered, walled };
+  };
+
+  const candidates = POKEMON_TYPES.filter((type) => !chosen.includes(type)).map((type) => {
+    const newlyCovered = profiles
+      .filter(({ multipliers }) => best(multipliers, chosen) <= 1 && best(multipliers, [type]) > 1)
+      .map(({ target }) => target)
+      .sort((a, b) => b.weight - a.weight);
+    return { type, ...score([...chosen, type]), newlyCovered };
+  });
+  candidates.sort((a, b) => b.covered - a.covered || a.walled - b.walled);
+
+  return {
+    totalWeight: targets.reduce((sum, target) => sum + target.weight, 0),
+    ...score(chosen),
+    candidates,
+  };
+}
@@ -0,0 +1,335 @@
+import { Move } from '../entity/Move';
+import { PokemonMaster } from '../entity/PokemonMaster';
+import { CURRENT_GENERATION, Generation, isRetroGeneration } from '../valueobject/Generation';
+import { toLookupKey } from '../valueobject/Identifier';
+import { Level } from '../valueobject/Level';
+import { PokemonBuild } from '../valueobject/PokemonBuild';
+import { PokemonType, TypeSet } from '../valueobject/PokemonType';
+import { StagedStat, StatStages } from '../valueobject/StatStages';
+import {
+  applyModifiers,
+  DamageContext,
+  getActiveModifiers,
+  ModifierStage,
+} from './AbilityModifiers';
+import { resolveMoveType } from './MoveTypeResolver';
+import { CalculatedStats, calculateStats } from './StatCalculator';
+
+// A built Pokemon on the field at a given level, in a given generation's mechanics
+export class Combatant {
+  private readonly stats: CalculatedStats;
+
+  constructor(
+    public readonly form: PokemonMaster,
+    public readonly build: PokemonBuild,
+    public readonly level: Level,
+    public readonly terastallized: boolean = false,
+    public readonly stages: StatStages = StatStages.neutral(),
+    public readonly generation: Generation = CURRENT_GENERATION
+  ) {
+    this.stats = calculateStats(
+      form.getBaseStats(),
+      build.getIV(),
+      build.getEV(),
+      level,
+      build.getNature(),
+      generation
+    );
+  }
+
+  getStats(): CalculatedStats {
+    return { ...this.stats };
+  }
+
+  // Stat after boosts and drops; critical hits skip the stages that would hurt the attacker
+  getEffectiveStat(stat: StagedStat, ignore?: 'boosts' | 'drops'): number {
+    const stage = this.stages.get(stat);
+    if ((ignore === 'boosts' && stage > 0) || (ignore === 'drops' && stage < 0)) {
+      return this.stats[stat];
+    }
+    return this.stages.applyTo(stat, this.stats[stat]);
+  }
+
+  getDefensiveTypes(): TypeSet {
+    const teraType = this.build.getTeraType();
+    return this.terastallized && teraType ? new TypeSet(teraType) : this.form.getTypeset();
+  }
+
+  // STAB multiplier for a move of the given type, including Tera STAB
+  getStab(type: PokemonType): number {
+    const original = this.form.getTypeset().getTypes().includes(type);
+    const tera = this.terastallized && this.build.getTeraType() === type;
+    if (original && tera) {
+      return 2;
+    }
+    return original || tera ? 1.5 : 1;
+  }
+}
+
+export interface DamageOptions {
+  critical?: boolean;
+  // Spread moves deal 0.75x when used in doubles
+  doubles?: boolean;
+  // Partners on the field, for abilities such as Friend Guard or Power Spot
+  attackerAlly?: Combatant;
+  defenderAlly?: Combatant;
+}
+
+export interface DamageResult {
+  moveType: PokemonType; // after Hidden Power, Tera Blast and -ate abilities
+  rolls: number[]; // 16 damage rolls for a single hit (39 in Gen 1/2), lowest first
+  hits: { min: number; max: number };
+  min: number; // total over all hits
+  max: number;
+  minPercent: number;
+  maxPercent: number;
+  effectiveness: number;
+  abilities: string[]; // abilities whose modifiers were considered
+  // Chance of knocking out the defender from full HP, over rolls and hit counts
+  koChance: number;
+}
+
+const ROLL_COUNT = 16;
+const RETRO_ROLL_COUNT = 39;
+
+// Gen 5+ odds for 2-5 hit moves
+const TWO_TO_FIVE_HIT_ODDS: [number, number][] = [
+  [2, 0.35],
+  [3, 0.35],
+  [4, 0.15],
+  [5, 0.15],
+];
+
+// Gen 1-4 odds
+const OLD_TWO_TO_FIVE_HIT_ODDS: [number, number][] = [
+  [2, 0.375],
+  [3, 0.375],
+  [4, 0.125],
+  [5, 0.125],
+];
+
+// Before the physical/special split, the move's type decided which stats it used
+const RETRO_PHYSICAL_TYPES: PokemonType[] = [
+  'Normal',
+  'Fighting',
+  'Flying',
+  'Poison',
+  'Ground',
+  'Rock',
+  'Bug',
+  'Ghost',
+  'Steel',
+];
+
+// Gen 9 damage formula with ability modifiers; field and item effects are not modelled.
+// Attackers in Gen 1/2 mechanics use the formula of those games instead.
+export function calculateDamage(
+  attacker: Combatant,
+  defender: Combatant,
+  baseMove: Move,
+  options: DamageOptions = {}
+): DamageResult {
+  if (isRetroGeneration(attacker.generation)) {
+    return calculateRetroDamage(attacker, defender, baseMove, options.critical ?? false);
+  }
+
+  const { move, convertedFrom } = resolveMoveType(attacker, baseMove);
+  const defenderHp = defender.getStats().hp;
+  const context: DamageContext = {
+    attacker,
+    defender,
+    move,
+    attackerAlly: options.attackerAlly,
+    defenderAlly: options.defenderAlly,
+    critical: options.critical ?? false,
+    effectiveness: defender.getDefensiveTypes().defendAgainst(move.getType()),
+    convertedFrom,
+  };
+  const modifiers = getActiveModifiers(context);
+  const modify = (stage: ModifierStage, value: number) =>
+    applyModifiers(modifiers, stage, value, context);
+  const abilities = [...new Set(modifiers.map((modifier) => modifier.ability))];
+
+  const effectiveness = modify('effectiveness', context.effectiveness);
+  const hitOdds = getHitOdds(attacker, move);
+  const result = (rolls: number[]): DamageResult => ({
+    moveType: move.getType(),
+    ...toResult(rolls, hitOdds, defenderHp, effectiveness, abilities),
+  });
+  if (!move.isDamaging() || effectiveness === 0) {
+    return result(new Array<number>(ROLL_COUNT).fill(0));
+  }
+
+  const fixed = move.getFixedDamage(attacker.level.getValue());
+  if (fixed !== undefined) {
+    return result(new Array<number>(ROLL_COUNT).fill(fixed));
+  }
+
+  const attack = modify(
+    'attack',
+    attacker.getEffectiveStat(
+      move.isPhysical() ? 'attack' : 'specialAttack',
+      options.critical ? 'drops' : undefined
+    )
+  );
+  const defense = modify(
+    'defense',
+    defender.getEffectiveStat(
+      move.isPhysical() ? 'defense' : 'specialDefense',
+      options.critical ? 'boosts' : undefined
+    )
+  );
+
+  const levelFactor = Math.floor((2 * attacker.level.getValue()) / 5 + 2);
+  const power = Math.floor(modify('basePower', move.getPower() ?? 0));
+  const ratio = (levelFactor * power * Math.floor(attack)) / Math.floor(defense);
+  let base = Math.floor(Math.floor(ratio) / 50) + 2;
+  if (options.doubles && move.isSpread()) {
+    base = Math.floor(base * 0.75);
+  }
+  if (options.critical) {
+    base = Math.floor(base * 1.5);
+  }
+
+  const stab = modify('stab', attacker.getStab(move.getType()));
+  const final = modify('final', 1);
+  const rolls: number[] = [];
+  for (let roll = 85; roll <= 100; roll++) {
+    let damage = Math.floor((base * roll) / 100);
+    damage = Math.floor(damage * stab);
+    damage = Math.floor(damage * effectiveness);
+    damage = Math.floor(damage * final);
+    rolls.push(Math.max(1, damage));
+  }
+
+  return result(rolls);
+}
+
+// Gen 1/2: no abilities, category by type, and a random factor of 217-255 out of 255.
+// Gen 1 critical hits double the level instead of the damage. Both ignore stat stages, though
+// Gen 2 only when the attacker's stage is no higher than the defender's. The type chart is the
+// current one.
+function calculateRetroDamage(
+  attacker: Combatant,
+  defender: Combatant,
+  baseMove: Move,
+  critical: boolean
+): DamageResult {
+  const { move } = resolveMoveType(attacker, baseMove);
+  const defenderHp = defender.getStats().hp;
+  const effectiveness = defender.getDefensiveTypes().defendAgainst(move.getType());
+  const hitOdds = getHitOdds(attacker, move);
+  const result = (rolls: number[]): DamageResult => ({
+    moveType: move.getType(),
+    ...toResult(rolls, hitOdds, defenderHp, effectiveness, []),
+  });
+  if (!move.isDamaging() || effectiveness === 0) {
+    return result(new Array<number>(RETRO_ROLL_COUNT).fill(0));
+  }
+
+  const fixed = move.getFixedDamage(attacker.level.getValue());
+  if (fixed !== undefined) {
+    return result(new Array<number>(RETRO_ROLL_COUNT).fill(fixed));
+  }
+
+  const gen1 = attacker.generation === 1;
+  const physical = RETRO_PHYSICAL_TYPES.includes(move.getType());
+  const attackStat: StagedStat = physical ? 'attack' : 'specialAttack';
+  const defenseStat: StagedStat = physical ? 'defense' : 'specialDefense';
+  const ignoreStages =
+    critical && (gen1 || attacker.stages.get(attackStat) <= defender.stages.get(defenseStat));
+  let attack = ignoreStages
+    ? attacker.getStats()[attackStat]
+    : attacker.getEffectiveStat(attackStat);
+  let defense = ignoreStages
+    ? defender.getStats()[defenseStat]
+    : defender.getEffectiveStat(defenseStat);
+  // The formula works in single bytes: stats above 255 are quartered, both together
+  if (attack > 255 || defense > 255) {
+    attack = Math.max(1, Math.floor(attack / 4));
+    defense = Math.max(1, Math.floor(defense / 4));
+  }
+
+  const level = attacker.level.getValue() * (critical && gen1 ? 2 : 1);
+  const levelFactor = Math.floor((2 * level) / 5 + 2);
+  const power = move.getPower() ?? 0;
+  let base = Math.floor(Math.floor((levelFactor * power * attack) / defense) / 50);
+  if (gen1) {
+    base = Math.min(997, base);
+  } else if (critical) {
+    base *= 2;
+  }
+  base += 2;
+
+  const stab = attacker.form.getTypeset().getTypes().includes(move.getType()) ? 1.5 : 1;
+  const damage = Math.floor(Math.floor(base * stab) * effectiveness);
+  const rolls: number[] = [];
+  for (let roll = 256 - RETRO_ROLL_COUNT; roll <= 255; roll++) {
+    rolls.push(Math.max(1, Math.floor((damage * roll) / 255)));
+  }
+
+  return result(rolls);
+}
+
+// [hit count, probability] pairs; Skill Link always hits the maximum number of times
+export function getHitOdds(attacker: Combatant, move: Move): [number, number][] {
+  const minHits = move.getMinHits();
+  const maxHits = move.getMaxHits();
+  const skillLink = toLookupKey(attacker.build.getAbility() ?? '') === 'skilllink';
+  if (minHits === 2 && maxHits === 5 && !skillLink) {
+    return attacker.generation >= 5 ? TWO_TO_FIVE_HIT_ODDS : OLD_TWO_TO_FIVE_HIT_ODDS;
+  }
+  // Fixed-count and accuracy-checked multi-hit moves are assumed to connect every time
+  return [[maxHits, 1]];
+}
+
+function toResult(
+  rolls: number[],
+  hitOdds: [number, number][],
+  defenderHp: number,
+  effectiveness: number,
+  abilities: string[]
+): Omit<DamageResult, 'moveType'> {
+  const hitCounts = hitOdds.map(([hits]) => hits);
+  const minHits = Math.min(...hitCounts);
+  const maxHits = Math.max(...hitCounts);
+  const min = rolls[0] * minHits;
+  const max = rolls[rolls.length - 1] * maxHits;
+  const percent = (damage: number) => Math.round((damage / defenderHp) * 1000) / 10;
+
+  return {
+    rolls,
+    hits: { min: minHits, max: maxHits },
+    min,
+    max,
+    minPercent: percent(min),
+    maxPercent: percent(max),
+    effectiveness,
+    abilities,
+    koChance: getKoChance(rolls, hitOdds, defenderHp),
+  };
+}
+
+// Each hit rolls independently, so the total damage distribution is built hit by hit.
+// Totals at or above the defender's HP are merged into one bucket to keep it small.
+function getKoChance(rolls: number[], hitOdds: [number, number][], defenderHp: number): number {
+  const rollChance = 1 / rolls.length;
+  let distribution = new Map<number, number>([[0, 1]]);
+  let koChance = 0;
+
+  for (let hit = 1; hit <= Math.max(...hitOdds.map(([hits]) => hits)); hit++) {
+    const next = new Map<number, number>();
+    for (const [total, chance] of distribution) {
+      for (const roll of rolls) {
+        const sum = Math.min(total + roll, defenderHp);
+        next.set(sum, (next.get(sum) ?? 0) + chance * rollChance);
+      }
+    }
+    distribution = next;
+
+    const hitChance = hitOdds.find(([hits]) => hits === hit)?.[1] ?? 0;
+    koChance += hitChance * (distribution.get(defenderHp) ?? 0);
+  }
+
+  return koChance;
+}
@@ -0,0 +1,102 @@
+import { PokemonMaster } from '../entity/PokemonMaster';
+import { POKEMON_TYPES, PokemonType } from '../valueobject/PokemonType';
+
+export type CoreSize = 2 | 3;
+
+export interface DefensiveCore {
+  members: PokemonMaster[];
+  // Attacking types more than one member is weak to
+  sharedWeaknesses: PokemonType[];
+  // Attacking types any member is weak to
+  weaknesses: PokemonType[];
+  // Sum of the members' base HP, Defense and Sp. Def
+  bulk: number;
+}
+
+interface Typing {
+  resists: number; // bit per attacking type, immunities included
+  weak: number;
+  form: PokemonMaster; // the bulkiest form with the typing
+}
+
+const ALL_TYPES = (1 << POKEMON_TYPES.length) - 1;
+
+function bulkOf(form: PokemonMaster): number {
+  const stats = form.getBaseStats();
+  return stats.hp + stats.defense + stats.specialDefense;
+}
+
+function typesIn(mask: number): PokemonType[] {
+  return POKEMON_TYPES.filter((_type, i) => mask & (1 << i));
+}
+
+// Groups of forms that between them resist every attacking type, by type chart alone. Forms
+// with the same typing are interchangeable here, so only the bulkiest of each typing is tried,
+// which keeps a whole-dex search of trios to a few hundred thousand combinations. Best first:
+// fewest shared weaknesses, then fewest weaknesses, then most bulk.
+export function findDefensiveCores(
+  forms: PokemonMaster[],
+  size: CoreSize,
+  limit: number
+): DefensiveCore[] {
+  const typings = new Map<string, Typing>();
+  for (const form of forms) {
+    const key = [...form.getTypeset().getTypes()].sort().join('/');
+    const current = typings.get(key);
+    if (current && bulkOf(current.form) >= bulkOf(form)) {
+      continue;
+    }
+    let resists = 0;
+    let weak = 0;
+    POKEMON_TYPES.forEach((attack, i) => {
+      const multiplier = form.getTypeset().defendAgainst(attack);
+      resists |= multiplier < 1 ? 1 << i : 0;
+      weak |= multiplier > 1 ? 1 << i : 0;
+    });
+    typings.set(key, { resists, weak, form });
+  }
+
+  const candidates = [...typings.values()];
+  const cores: DefensiveCore[] = [];
+  const consider = (members: Typing[]) => {
+    if (members.reduce((mask, member) => mask | member.resists, 0) !== ALL_TYPES) {
+      return;
+    }
+    let weak = 0;
+    let shared = 0;
+    for (const member of members) {
+      shared |= weak & member.weak;
+      weak |= member.weak;
+    }
+    cores.push({
+      members: members.map((member) => member.form),
+      sharedWeaknesses: typesIn(shared),
+      weaknesses: typesIn(weak),
+      bulk: members.reduce((sum, member) => sum + bulkOf(member.form), 0),
+    });
+  };
+
+  for (let i = 0; i < candidates.length; i++) {
+    for (let j = i + 1; j < candidates.length; j++) {
+      if (size === 2) {
+        consider([candidates[i], candidates[j]]);
+        continue;
+      }
+      const pair = candidates[i].resists | candidates[j].resists;
+      for (let k = j + 1; k < candidates.length; k++) {
+        if ((pair | candidates[k].resists) === ALL_TYPES) {
+          consider([candidates[i], candidates[j], candidates[k]]);
+        }
+      }
+    }
+  }
+
+  return cores
+    .sort(
+      (a, b) =>
+        a.sharedWeaknesses.length - b.sharedWeaknesses.length ||
+        a.weaknesses.length - b.weaknesses.length ||
+        b.bulk - a.bulk
+    )
+    .slice(0, limit);
+}
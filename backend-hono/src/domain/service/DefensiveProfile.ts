@@ -0,0 +1,60 @@
+import { toLookupKey } from '../valueobject/Identifier';
+import { POKEMON_TYPES, PokemonType, TypeSet } from '../valueobject/PokemonType';
+
+// Whether a multiplier comes from the type chart alone or was changed by the ability
+export type MatchupSource = 'type' | 'ability';
+
+export interface DefensiveMatchup {
+  type: PokemonType; // attacking type
+  multiplier: number;
+  source: MatchupSource;
+}
+
+type AbilityEffect = (type: PokemonType, multiplier: number) => number;
+
+function immuneTo(...types: PokemonType[]): AbilityEffect {
+  return (type, multiplier) => (types.includes(type) ? 0 : multiplier);
+}
+
+function scales(factor: number, ...types: PokemonType[]): AbilityEffect {
+  return (type, multiplier) => (types.includes(type) ? multiplier * factor : multiplier);
+}
+
+// Abilities that change how much damage an attacking type deals, keyed by lookup key. Damage
+// reductions such as Heatproof are folded into the multiplier so the profile reads like a
+// type matchup.
+const ABILITY_EFFECTS: Record<string, AbilityEffect> = {
+  levitate: immuneTo('Ground'),
+  eartheater: immuneTo('Ground'),
+  flashfire: immuneTo('Fire'),
+  wellbakedbody: immuneTo('Fire'),
+  waterabsorb: immuneTo('Water'),
+  stormdrain: immuneTo('Water'),
+  voltabsorb: immuneTo('Electric'),
+  lightningrod: immuneTo('Electric'),
+  motordrive: immuneTo('Electric'),
+  sapsipper: immuneTo('Grass'),
+  dryskin: (type, multiplier) =>
+    type === 'Water' ? 0 : type === 'Fire' ? multiplier * 1.25 : multiplier,
+  heatproof: scales(0.5, 'Fire'),
+  waterbubble: scales(0.5, 'Fire'),
+  thickfat: scales(0.5, 'Fire', 'Ice'),
+  purifyingsalt: scales(0.5, 'Ghost'),
+  wonderguard: (_type, multiplier) => (multiplier > 1 ? multiplier : 0),
+};
+
+// Multiplier of every attacking type against the given typing. With an ability, entries the
+// ability changes are marked as ability-based; unknown abilities leave the profile unchanged.
+export function getDefensiveProfile(typeset: TypeSet, ability?: string): DefensiveMatchup[] {
+  const effect = ability ? ABILITY_EFFECTS[toLookupKey(ability)] : undefined;
+
+  return POKEMON_TYPES.map((type) => {
+    const multiplier = typeset.defendAgainst(type);
+    const adjusted = effect ? effect(type, multiplier) : multiplier;
+    return {
+      type,
+      multiplier: adjusted,
+      source: adjusted === multiplier ? 'type' : 'ability',
+    };
+  });
+}
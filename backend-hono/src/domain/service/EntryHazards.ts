@@ -0,0 +1,63 @@
+import { toLookupKey } from '../valueobject/Identifier';
+import type { Combatant } from './DamageCalculator';
+
+// Fraction of max HP taken from one, two and three layers of Spikes
+const SPIKES_FRACTIONS = [1 / 8, 1 / 6, 1 / 4];
+// Poison takes 1/8 per turn; the bad poison from two layers starts at 1/16 and grows each turn
+const TOXIC_SPIKES_FRACTIONS = [1 / 8, 1 / 16];
+
+const POISON_IMMUNE_ABILITIES = ['immunity', 'pastelveil', 'comatose', 'purifyingsalt'];
+
+export interface HazardDamage {
+  stealthRock: number; // percent of max HP lost on switch-in
+  spikes: number[]; // per number of layers, 1 to 3
+  // Percent lost at the end of the first turn, per number of layers (1 to 2); 0 when immune
+  toxicSpikes: number[];
+  // Grounded Poison types remove Toxic Spikes when they switch in
+  absorbsToxicSpikes: boolean;
+}
+
+// Flying types, Levitate and Air Balloon float over Spikes and Toxic Spikes; an Iron Ball
+// grounds them again
+function isGrounded(combatant: Combatant): boolean {
+  if (toLookupKey(combatant.build.getHeldItem() ?? '') === 'ironball') {
+    return true;
+  }
+  const floating =
+    combatant.form.getTypeset().getTypes().includes('Flying') ||
+    toLookupKey(combatant.build.getAbility() ?? '') === 'levitate' ||
+    toLookupKey(combatant.build.getHeldItem() ?? '') === 'airballoon';
+  return !floating;
+}
+
+// Damage from each entry hazard as the Pokémon switches in; it has not terastallized yet,
+// so its original types apply
+export function calculateHazardDamage(combatant: Combatant): HazardDamage {
+  const hp = combatant.getStats().hp;
+  const percent = (fraction: number) =>
+    Math.round((Math.max(1, Math.floor(hp * fraction)) / hp) * 1000) / 10;
+  const types = combatant.form.getTypeset();
+  const grounded = isGrounded(combatant);
+  const ability = toLookupKey(combatant.build.getAbility() ?? '');
+  const poisonImmune =
+    types.getTypes().some((type) => type === 'Poison' || type === 'Steel') ||
+    POISON_IMMUNE_ABILITIES.includes(ability);
+
+  // Heavy-Duty Boots ignore every hazard, including the absorb; Magic Guard only the damage
+  if (toLookupKey(combatant.build.getHeldItem() ?? '') === 'heavydutyboots') {
+    return { stealthRock: 0, spikes: [0, 0, 0], toxicSpikes: [0, 0], absorbsToxicSpikes: false };
+  }
+  const absorbsToxicSpikes = grounded && types.getTypes().includes('Poison');
+  if (ability === 'magicguard') {
+    return { stealthRock: 0, spikes: [0, 0, 0], toxicSpikes: [0, 0], absorbsToxicSpikes };
+  }
+
+  return {
+    stealthRock: percent(types.defendAgainst('Rock') / 8),
+    spikes: SPIKES_FRACTIONS.map((fraction) => (grounded ? percent(fraction) : 0)),
+    toxicSpikes: TOXIC_SPIKES_FRACTIONS.map((fraction) =>
+      grounded && !poisonImmune ? percent(fraction) : 0
+    ),
+    absorbsToxicSpikes,
+  };
+}
@@ -0,0 +1,115 @@
+import { Move } from '../entity/Move';
+import { getFrustrationPower, getReturnPower } from '../valueobject/Friendship';
+import { isRetroGeneration } from '../valueobject/Generation';
+import { toLookupKey } from '../valueobject/Identifier';
+import { PokemonType } from '../valueobject/PokemonType';
+import { DVs, toDVs } from '../valueobject/RetroStats';
+import { IVStats } from '../valueobject/Stats';
+import type { Combatant } from './DamageCalculator';
+
+// Indexed by the Hidden Power type formula; Normal and Fairy are unreachable
+const HIDDEN_POWER_TYPES: PokemonType[] = [
+  'Fighting',
+  'Flying',
+  'Poison',
+  'Ground',
+  'Rock',
+  'Bug',
+  'Ghost',
+  'Steel',
+  'Fire',
+  'Water',
+  'Grass',
+  'Electric',
+  'Psychic',
+  'Ice',
+  'Dragon',
+  'Dark',
+];
+
+// Gen 6 onwards; earlier generations derived the power from IVs too
+const HIDDEN_POWER_BASE_POWER = 60;
+
+// Abilities that turn the user's Normal-type moves into another type. The accompanying
+// 1.2x power boost is an ability modifier (see AbilityModifiers).
+const TYPE_CONVERSION_ABILITIES: Record<string, PokemonType> = {
+  aerilate: 'Flying',
+  pixilate: 'Fairy',
+  refrigerate: 'Ice',
+  galvanize: 'Electric',
+};
+
+export interface ResolvedMove {
+  move: Move;
+  // The move's own type, when the attacker's ability converted it
+  convertedFrom?: PokemonType;
+}
+
+// Parity of each IV forms a 6-bit number, scaled onto the 16 possible types
+export function getHiddenPowerType(ivs: IVStats): PokemonType {
+  const order = [ivs.hp, ivs.attack, ivs.defense, ivs.speed, ivs.specialAttack, ivs.specialDefense];
+  const bits = order.reduce((sum, iv, bit) => sum + (iv % 2) * 2 ** bit, 0);
+  return HIDDEN_POWER_TYPES[Math.floor((bits * 15) / 63)];
+}
+
+// Gen 2: the type from the Attack and Defense DVs, the power (31-70) from the top bits of all
+// four and the Special DV
+export function getRetroHiddenPower(dvs: DVs): { type: PokemonType; power: number } {
+  const topBits = [dvs.attack, dvs.defense, dvs.speed, dvs.special].reduce(
+    (sum, dv) => sum * 2 + (dv >= 8 ? 1 : 0),
+    0
+  );
+  return {
+    type: HIDDEN_POWER_TYPES[4 * (dvs.attack % 4) + (dvs.defense % 4)],
+    power: Math.floor((5 * topBits + (dvs.special % 4)) / 2) + 31,
+  };
+}
+
+// The type (and for Tera Blast, the category) a move actually has when this attacker uses it.
+// Everything downstream — effectiveness, STAB, type-based abilities — reads the result.
+export function resolveMoveType(attacker: Combatant, baseMove: Move): ResolvedMove {
+  const key = toLookupKey(baseMove.getName());
+  const move = withFriendshipPower(attacker, key, baseMove);
+
+  if (isRetroGeneration(attacker.generation)) {
+    // No Terastallization or abilities yet
+    if (key === 'hiddenpower') {
+      return { move: move.withChanges(getRetroHiddenPower(toDVs(attacker.build.getIV()))) };
+    }
+    return { move };
+  }
+
+  if (key === 'hiddenpower') {
+    const type = getHiddenPowerType(attacker.build.getIV());
+    return { move: move.withChanges({ type, power: HIDDEN_POWER_BASE_POWER }) };
+  }
+
+  const teraType = attacker.build.getTeraType();
+  if (key === 'terablast' && attacker.terastallized && teraType) {
+    // Physical when the user's Attack beats its Special Attack, boosts included
+    const physical =
+      attacker.getEffectiveStat('attack') > attacker.getEffectiveStat('specialAttack');
+    return {
+      move: move.withChanges({ type: teraType, damageClass: physical ? 'physical' : 'special' }),
+    };
+  }
+
+  const converted = TYPE_CONVERSION_ABILITIES[toLookupKey(attacker.build.getAbility() ?? '')];
+  if (converted && move.getType() === 'Normal' && move.isDamaging()) {
+    return { move: move.withChanges({ type: converted }), convertedFrom: 'Normal' };
+  }
+
+  return { move };
+}
+
+// Return and Frustration have no listed power; it comes from the user's friendship
+function withFriendshipPower(attacker: Combatant, key: string, move: Move): Move {
+  const friendship = attacker.build.getFriendship();
+  if (key === 'return') {
+    return move.withChanges({ power: getReturnPower(friendship) });
+  }
+  if (key === 'frustration') {
+    return move.withChanges({ power: getFrustrationPower(friendship) });
+  }
+  return move;
+}
@@ -0,0 +1,28 @@
+// Source of randomness for the stochastic services. They take an Rng rather than calling
+// Math.random, so a SeededRandom can make their results reproducible in tests and shareable.
+export abstract class Rng {
+  // Uniform in [0, 1)
+  abstract next(): number;
+
+  // Uniform integer in [0, max)
+  nextInt(max: number): number {
+    return Math.floor(this.next() * max);
+  }
+
+  // Fisher-Yates on a copy
+  shuffle<T>(items: readonly T[]): T[] {
+    const result = [...items];
+    for (let i = result.length - 1; i > 0; i--) {
+      const j = this.nextInt(i + 1);
+      [result[i], result[j]] = [result[j], result[i]];
+    }
+    return result;
+  }
+}
+
+// Math.random: a different sequence every run, for when nothing needs to be reproduced
+export class SystemRandom extends Rng {
+  next(): number {
+    return Math.random();
+  }
+}
@@ -0,0 +1,25 @@
+import { Rng, SystemRandom } from './Rng';
+
+// Mulberry32: small and fast, and the same seed gives the same sequence on every runtime, so a
+// generated result can be reproduced from its seed. Not suitable for anything security-related.
+export class SeededRandom extends Rng {
+  private state: number;
+
+  constructor(public readonly seed: number) {
+    super();
+    this.state = seed >>> 0;
+  }
+
+  // A fresh 32-bit seed for callers that were not given one
+  static randomSeed(rng: Rng = new SystemRandom()): number {
+    return rng.nextInt(2 ** 32);
+  }
+
+  next(): number {
+    this.state = (this.state + 0x6d2b79f5) >>> 0;
+    let t = this.state;
+    t = Math.imul(t ^ (t >>> 15), t | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 2 ** 32;
+  }
+}
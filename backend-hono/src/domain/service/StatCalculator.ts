@@ -0,0 +1,109 @@
+import {
+  CURRENT_GENERATION,
+  Generation,
+  hasSplitSpecial,
+  isRetroGeneration,
+} from '../valueobject/Generation';
+import { Level } from '../valueobject/Level';
+import { getNatureMultiplier, Nature } from '../valueobject/Nature';
+import {
+  getHpDV,
+  getStatExperienceBonus,
+  toDVs,
+  toStatExperience,
+} from '../valueobject/RetroStats';
+import { BaseStats, EVStats, IVStats, StatName } from '../valueobject/Stats';
+
+export type CalculatedStats = Record<StatName, number>;
+
+const STAT_NAMES: readonly StatName[] = [
+  'hp',
+  'attack',
+  'defense',
+  'specialAttack',
+  'specialDefense',
+  'speed',
+];
+
+// Gen 3+ stat formula
+export function calculateStat(
+  stat: StatName,
+  base: number,
+  iv: number,
+  ev: number,
+  level: Level,
+  nature: Nature
+): number {
+  const core = Math.floor(((2 * base + iv + Math.floor(ev / 4)) * level.getValue()) / 100);
+  if (stat === 'hp') {
+    // Shedinja is the only form with a base HP of 1 and is always fixed at 1 HP
+    return base === 1 ? 1 : core + level.getValue() + 10;
+  }
+  return Math.floor((core + 5) * getNatureMultiplier(nature, stat));
+}
+
+// Gen 1/2 stat formula, from a DV and stat experience; there are no natures
+export function calculateRetroStat(
+  stat: StatName,
+  base: number,
+  dv: number,
+  statExperience: number,
+  level: Level
+): number {
+  const core = Math.floor(
+    (((base + dv) * 2 + getStatExperienceBonus(statExperience)) * level.getValue()) / 100
+  );
+  return stat === 'hp' ? core + level.getValue() + 10 : core + 5;
+}
+
+export function calculateStats(
+  baseStats: BaseStats,
+  iv: IVStats,
+  ev: EVStats,
+  level: Level,
+  nature: Nature,
+  generation: Generation = CURRENT_GENERATION
+): CalculatedStats {
+  if (isRetroGeneration(generation)) {
+    return calculateRetroStats(baseStats, iv, ev, level, generation);
+  }
+  return Object.fromEntries(
+    STAT_NAMES.map((stat) => [
+      stat,
+      calculateStat(stat, baseStats[stat], iv[stat], ev.get(stat), level, nature),
+    ])
+  ) as CalculatedStats;
+}
+
+// Both special stats share the Special DV and stat experience, which builds keep as their
+// Special Attack. Base stats are the current ones; Gen 1's Special is taken to be the base
+// Special Attack, which is what it became for almost every species when Gen 2 split it.
+function calculateRetroStats(
+  baseStats: BaseStats,
+  iv: IVStats,
+  ev: EVStats,
+  level: Level,
+  generation: Generation
+): CalculatedStats {
+  const dvs = toDVs(iv);
+  const dvByStat: CalculatedStats = {
+    hp: getHpDV(dvs),
+    attack: dvs.attack,
+    defense: dvs.defense,
+    specialAttack: dvs.special,
+    specialDefense: dvs.special,
+    speed: dvs.speed,
+  };
+
+  return Object.fromEntries(
+    STAT_NAMES.map((stat) => {
+      const special = stat === 'specialAttack' || stat === 'specialDefense';
+      const base =
+        stat === 'specialDefense' && !hasSplitSpecial(generation)
+          ? baseStats.specialAttack
+          : baseStats[stat];
+      const statExperience = toStatExperience(ev.get(special ? 'specialAttack' : stat));
+      return [stat, calculateRetroStat(stat, base, dvByStat[stat], statExperience, level)];
+    })
+  ) as CalculatedStats;
+}
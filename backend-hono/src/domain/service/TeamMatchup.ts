@@ -0,0 +1,154 @@
+import {
+  BattleSide,
+  BattlerState,
+  createBattlerState,
+  getExpectedDamageShare,
+  Matchup,
+  playBattle,
+  prepareMatchup,
+} from './BattleSimulator';
+import { Rng } from './Rng';
+import { FieldState } from './TurnOrder';
+
+export interface ConfidenceInterval {
+  lower: number;
+  upper: number;
+}
+
+export interface TeamMatchupEstimate {
+  games: number;
+  wins: [number, number];
+  // Both teams ran out on the same action, or the last fight stalled
+  draws: number;
+  // 95% Wilson score intervals for each team's chance of winning
+  winIntervals: [ConfidenceInterval, ConfidenceInterval];
+  // Pokémon left standing at the end of a game, on average
+  averageRemaining: [number, number];
+}
+
+const Z_95 = 1.96;
+
+// Every pairing of the two teams, and how well each side fares in it
+interface Pairings {
+  matchups: Matchup[][]; // [first team's member][second team's member]
+  // Expected damage dealt minus taken per turn, as shares of max HP, from each team's view and
+  // indexed [own member][opponent]
+  scores: [number[][], number[][]];
+}
+
+// Plays `games` full-team battles. Each game starts from a random lead on both sides; whenever
+// a Pokémon faints its trainer sends in the member that fares best against the opponent on the
+// field (or, when both fainted together, against the opponent's remaining members on average).
+// HP and status carry over between fights; voluntary switching is not modelled.
+export function estimateTeamMatchup(
+  first: BattleSide[],
+  second: BattleSide[],
+  field: FieldState,
+  games: number,
+  random: Rng
+): TeamMatchupEstimate {
+  const pairings = preparePairings(first, second);
+  const wins: [number, number] = [0, 0];
+  let draws = 0;
+  const remaining: [number, number] = [0, 0];
+
+  for (let i = 0; i < games; i++) {
+    const result = playGame(first, second, pairings, field, random);
+    if (result.winner === undefined) {
+      draws++;
+    } else {
+      wins[result.winner]++;
+    }
+    remaining[0] += result.remaining[0];
+    remaining[1] += result.remaining[1];
+  }
+
+  const average = (total: number) => Math.round((total / games) * 10) / 10;
+  return {
+    games,
+    wins,
+    draws,
+    winIntervals: [wilsonInterval(wins[0], games), wilsonInterval(wins[1], games)],
+    averageRemaining: [average(remaining[0]), average(remaining[1])],
+  };
+}
+
+function preparePairings(first: BattleSide[], second: BattleSide[]): Pairings {
+  const matchups = first.map((side) => second.map((opponent) => prepareMatchup(side, opponent)));
+  const firstScores = matchups.map((row) =>
+    row.map((matchup) => getExpectedDamageShare(matchup, 0) - getExpectedDamageShare(matchup, 1))
+  );
+  const secondScores = second.map((_, j) => first.map((_, i) => -firstScores[i][j]));
+  return { matchups, scores: [firstScores, secondScores] };
+}
+
+function playGame(
+  first: BattleSide[],
+  second: BattleSide[],
+  pairings: Pairings,
+  field: FieldState,
+  random: Rng
+): { winner?: 0 | 1; remaining: [number, number] } {
+  const states = [first.map(createBattlerState), second.map(createBattlerState)];
+  const active = [random.nextInt(first.length), random.nextInt(second.length)];
+  const standing = (team: number) =>
+    states[team].flatMap((state, index) => (state.hp > 0 ? [index] : []));
+
+  for (;;) {
+    const fighters: [BattlerState, BattlerState] = [states[0][active[0]], states[1][active[1]]];
+    playBattle(pairings.matchups[active[0]][active[1]], fighters, field, random);
+    if (fighters.every((state) => state.hp > 0)) {
+      // Neither could finish the other off
+      return { remaining: [standing(0).length, standing(1).length] };
+    }
+
+    const fainted = fighters.map((state) => state.hp <= 0);
+    const next = [0, 1].map((team) => {
+      if (!fainted[team]) {
+        return active[team];
+      }
+      const opponents = fainted[1 - team] ? standing(1 - team) : [active[1 - team]];
+      return pickReplacement(standing(team), opponents, pairings.scores[team]);
+    });
+    const [firstNext, secondNext] = next;
+    if (firstNext === undefined || secondNext === undefined) {
+      const remaining: [number, number] = [standing(0).length, standing(1).length];
+      if (firstNext === undefined && secondNext === undefined) {
+        return { remaining };
+      }
+      return { winner: firstNext === undefined ? 1 : 0, remaining };
+    }
+    active[0] = firstNext;
+    active[1] = secondNext;
+  }
+}
+
+// The member with the best average score against the given opponents
+function pickReplacement(
+  members: number[],
+  opponents: number[],
+  scores: number[][]
+): number | undefined {
+  let best: number | undefined;
+  let bestScore = -Infinity;
+  for (const member of members) {
+    const score =
+      opponents.reduce((sum, opponent) => sum + scores[member][opponent], 0) /
+      Math.max(1, opponents.length);
+    if (score > bestScore) {
+      best = member;
+      bestScore = score;
+    }
+  }
+  return best;
+}
+
+function wilsonInterval(successes: number, trials: number): ConfidenceInterval {
+  const p = successes / trials;
+  const z2 = Z_95 * Z_95;
+  const denominator = 1 + z2 / trials;
+  const center = (p + z2 / (2 * trials)) / denominator;
+  const spread = Math.sqrt((p * (1 - p)) / trials + z2 / (4 * trials * trials));
+  const margin = (Z_95 * spread) / denominator;
+  return { lower: Math.max(0, center - margin), upper: Math.min(1, center + margin) };
+}
@@ -0,0 +1,143 @@
+import { z } from 'zod';
+import { Move } from '../entity/Move';
+import { toLookupKey } from '../valueobject/Identifier';
+import type { Combatant } from './DamageCalculator';
+
+export const WeatherSchema = z.enum(['rain', 'sun', 'sand', 'snow']);
+
+export type Weather = z.infer<typeof WeatherSchema>;
+
+export interface FieldState {
+  trickRoom: boolean;
+  weather?: Weather;
+}
+
+// One Pokémon's choice for the turn and what affects its speed on its side of the field
+export interface TurnAction {
+  combatant: Combatant;
+  move: Move;
+  tailwind?: boolean;
+  paralyzed?: boolean;
+}
+
+export interface ActionTiming {
+  priority: number;
+  speed: number; // after stages, items, abilities, Tailwind and paralysis
+  // Chance of moving first in its priority bracket regardless of speed (Quick Claw)
+  quickChance: number;
+  // Moves last in its priority bracket regardless of speed (Lagging Tail, Stall)
+  movesLast: boolean;
+  // Abilities and items that changed the priority or speed
+  modifiers: string[];
+}
+
+export interface TurnOrder {
+  timings: [ActionTiming, ActionTiming];
+  // Chance that the first action goes first; the second goes first otherwise
+  firstMovesFirst: number;
+}
+
+const SPEED_ITEMS: Record<string, number> = { choicescarf: 1.5, ironball: 0.5 };
+const QUICK_CHANCES: Record<string, number> = { quickclaw: 0.2 };
+const LAST_ITEMS = ['laggingtail', 'fullincense'];
+
+// Abilities that double Speed in a weather
+const WEATHER_SPEED_ABILITIES: Record<string, Weather> = {
+  swiftswim: 'rain',
+  chlorophyll: 'sun',
+  sandrush: 'sand',
+  slushrush: 'snow',
+};
+
+// Gen 9 turn order for two Pokémon: priority bracket first, then speed, which Trick Room
+// reverses. Within a bracket Quick Claw and Quick Draw can jump ahead and Lagging Tail and Stall
+// fall behind; speed ties are a coin flip. Abilities are assumed active (Gale Wings at full HP).
+export function determineTurnOrder(
+  first: TurnAction,
+  second: TurnAction,
+  field: FieldState
+): TurnOrder {
+  const timings: [ActionTiming, ActionTiming] = [getTiming(first, field), getTiming(second, field)];
+  return { timings, firstMovesFirst: chanceToMoveFirst(timings[0], timings[1], field) };
+}
+
+function getTiming(action: TurnAction, field: FieldState): ActionTiming {
+  const { combatant, move } = action;
+  const abilityName = combatant.build.getAbility() ?? '';
+  const itemName = combatant.build.getHeldItem() ?? '';
+  const ability = toLookupKey(abilityName);
+  const item = toLookupKey(itemName);
+  const modifiers: string[] = [];
+
+  let priority = move.getPriority();
+  const effects = move.getEffects();
+  const healing = (effects?.healing ?? 0) > 0 || (effects?.drain ?? 0) > 0;
+  if (ability === 'prankster' && move.getDamageClass() === 'status') {
+    priority += 1;
+    modifiers.push(abilityName);
+  } else if (ability === 'galewings' && move.getType() === 'Flying') {
+    priority += 1;
+    modifiers.push(abilityName);
+  } else if (ability === 'triage' && healing) {
+    priority += 3;
+    modifiers.push(abilityName);
+  }
+
+  let speed = combatant.getEffectiveStat('speed');
+  if (SPEED_ITEMS[item]) {
+    speed = Math.floor(speed * SPEED_ITEMS[item]);
+    modifiers.push(itemName);
+  }
+  if (field.weather && WEATHER_SPEED_ABILITIES[ability] === field.weather) {
+    speed *= 2;
+    modifiers.push(abilityName);
+  }
+  if (action.tailwind) {
+    speed *= 2;
+    modifiers.push('tailwind');
+  }
+  // Quick Feet turns paralysis into a boost
+  if (action.paralyzed && ability === 'quickfeet') {
+    speed = Math.floor(speed * 1.5);
+    modifiers.push(abilityName);
+  } else if (action.paralyzed) {
+    speed = Math.floor(speed / 2);
+    modifiers.push('paralysis');
+  }
+
+  let quickChance = QUICK_CHANCES[item] ?? 0;
+  // Quick Draw only works with attacks
+  if (ability === 'quickdraw' && move.getDamageClass() !== 'status') {
+    quickChance = 0.3;
+  }
+  const movesLast = LAST_ITEMS.includes(item) || ability === 'stall';
+
+  return { priority, speed, quickChance, movesLast, modifiers };
+}
+
+function chanceToMoveFirst(a: ActionTiming, b: ActionTiming, field: FieldState): number {
+  if (a.priority !== b.priority) {
+    return a.priority > b.priority ? 1 : 0;
+  }
+
+  // Both, one or neither of the quick effects activate; when both do, speed decides
+  const bySpeed = compareSpeed(a, b, field);
+  const bothQuick = a.quickChance * b.quickChance;
+  const onlyA = a.quickChance * (1 - b.quickChance);
+  const neither = (1 - a.quickChance) * (1 - b.quickChance);
+
+  let withoutQuick = bySpeed;
+  if (a.movesLast !== b.movesLast) {
+    withoutQuick = a.movesLast ? 0 : 1;
+  }
+  return bothQuick * bySpeed + onlyA + neither * withoutQuick;
+}
+
+// Trick Room reverses the order of speeds, not the priority brackets
+function compareSpeed(a: ActionTiming, b: ActionTiming, field: FieldState): number {
+  if (a.speed === b.speed) {
+    return 0.5;
+  }
+  const faster = a.speed > b.speed;
+  return faster !== field.trickRoom ? 1 : 0;
+}
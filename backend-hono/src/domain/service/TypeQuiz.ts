@@ -0,0 +1,37 @@
+import { POKEMON_TYPES, PokemonType, TypeSet } from '../valueobject/PokemonType';
+import { SeededRandom } from './SeededRandom';
+
+// Every multiplier a type matchup can produce
+export const TYPE_QUIZ_MULTIPLIERS: readonly number[] = [0, 0.25, 0.5, 1, 2, 4];
+const CHOICE_COUNT = 4;
+
+export interface TypeQuizQuestion {
+  // The seed the question was built from; the same ID always gives the same question
+  questionId: number;
+  attack: PokemonType;
+  defender: TypeSet;
+  // Ascending, always including the answer
+  choices: number[];
+  answer: number;
+}
+
+// Dual-type defenders half the time, since those are where the multipliers get interesting
+export function createTypeQuizQuestion(questionId: number): TypeQuizQuestion {
+  const random = new SeededRandom(questionId);
+  const attack = POKEMON_TYPES[random.nextInt(POKEMON_TYPES.length)];
+  const [type1, type2] = random.shuffle(POKEMON_TYPES);
+  const defender = random.next() < 0.5 ? new TypeSet(type1) : new TypeSet(type1, type2);
+  const answer = defender.defendAgainst(attack);
+
+  const wrong = random
+    .shuffle(TYPE_QUIZ_MULTIPLIERS.filter((multiplier) => multiplier !== answer))
+    .slice(0, CHOICE_COUNT - 1);
+
+  return {
+    questionId,
+    attack,
+    defender,
+    choices: [answer, ...wrong].sort((a, b) => a - b),
+    answer,
+  };
+}
@@ -0,0 +1,50 @@
+import { z } from 'zod';
+import { toLookupKey } from './Identifier';
+
+export const AbilitySlotSchema = z.enum(['primary', 'secondary', 'hidden']);
+
+export type AbilitySlot = z.infer<typeof AbilitySlotSchema>;
+
+export interface AbilityEntry {
+  slot: AbilitySlot;
+  ability: string;
+}
+
+// The abilities a form can have: one or two regular slots and an optional hidden ability.
+// Names may be in any spelling (see Identifier).
+export class AbilitySlots {
+  constructor(
+    public readonly primary: string,
+    public readonly secondary?: string,
+    public readonly hidden?: string
+  ) {}
+
+  static fromEntries(entries: AbilityEntry[]): AbilitySlots | undefined {
+    const bySlot = new Map(entries.map((entry) => [entry.slot, entry.ability]));
+    const primary = bySlot.get('primary');
+    return primary
+      ? new AbilitySlots(primary, bySlot.get('secondary'), bySlot.get('hidden'))
+      : undefined;
+  }
+
+  getEntries(): AbilityEntry[] {
+    const entries: AbilityEntry[] = [{ slot: 'primary', ability: this.primary }];
+    if (this.secondary) {
+      entries.push({ slot: 'secondary', ability: this.secondary });
+    }
+    if (this.hidden) {
+      entries.push({ slot: 'hidden', ability: this.hidden });
+    }
+    return entries;
+  }
+
+  // A regular slot wins when the hidden ability repeats one of them
+  getSlotOf(ability: string): AbilitySlot | undefined {
+    const key = toLookupKey(ability);
+    return this.getEntries().find((entry) => toLookupKey(entry.ability) === key)?.slot;
+  }
+
+  has(ability: string): boolean {
+    return this.getSlotOf(ability) !== undefined;
+  }
+}
@@ -0,0 +1,7 @@
+import { z } from 'zod';
+
+// Groups of public endpoints an API key can call: the Pokédex (/api/pokemon/*, /api/moves,
+// /api/abilities, /api/items), the type chart and usage stats
+export const ApiKeyScopeSchema = z.enum(['pokemon', 'types', 'usage']);
+
+export type ApiKeyScope = z.infer<typeof ApiKeyScopeSchema>;
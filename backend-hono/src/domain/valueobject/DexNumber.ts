@@ -0,0 +1,125 @@
+import { z } from 'zod';
+import { toIdentifier } from './Identifier';
+import { SpeciesId } from './PokemonId';
+import { DexNumberValidationError } from './ValidationError';
+
+// National Dex numbers are species IDs; the alias marks values that are shown to players
+export type NationalDexNumber = SpeciesId;
+
+export const NationalDexNumber = {
+  parse(value: number): NationalDexNumber {
+    return SpeciesId.parse(value);
+  },
+
+  // "#0025", the four-digit style of the current games
+  format(value: NationalDexNumber): string {
+    return `#${String(value).padStart(4, '0')}`;
+  },
+};
+
+// Regional Pokédexes, named as in PokeAPI
+export const RegionalDexSchema = z.enum([
+  'kanto',
+  'original-johto',
+  'hoenn',
+  'original-sinnoh',
+  'original-unova',
+  'updated-unova',
+  'kalos-central',
+  'kalos-coastal',
+  'kalos-mountain',
+  'original-alola',
+  'updated-alola',
+  'galar',
+  'isle-of-armor',
+  'crown-tundra',
+  'hisui',
+  'paldea',
+  'kitakami',
+  'blueberry',
+]);
+
+export type RegionalDex = z.infer<typeof RegionalDexSchema>;
+
+// Number ranges; the Unova dexes start at 0 (Victini)
+const REGIONAL_DEX_RANGES: Record<RegionalDex, { min: number; max: number }> = {
+  kanto: { min: 1, max: 151 },
+  'original-johto': { min: 1, max: 251 },
+  hoenn: { min: 1, max: 202 },
+  'original-sinnoh': { min: 1, max: 151 },
+  'original-unova': { min: 0, max: 155 },
+  'updated-unova': { min: 0, max: 300 },
+  'kalos-central': { min: 1, max: 150 },
+  'kalos-coastal': { min: 1, max: 153 },
+  'kalos-mountain': { min: 1, max: 151 },
+  'original-alola': { min: 1, max: 302 },
+  'updated-alola': { min: 1, max: 403 },
+  galar: { min: 1, max: 400 },
+  'isle-of-armor': { min: 1, max: 211 },
+  'crown-tundra': { min: 1, max: 210 },
+  hisui: { min: 1, max: 242 },
+  paldea: { min: 1, max: 400 },
+  kitakami: { min: 1, max: 200 },
+  blueberry: { min: 1, max: 243 },
+};
+
+// Short names players use, e.g. "Paldea #25" or "Johto 152"
+const DEX_ALIASES: Record<string, RegionalDex> = {
+  johto: 'original-johto',
+  sinnoh: 'original-sinnoh',
+  unova: 'updated-unova',
+  alola: 'updated-alola',
+};
+
+export interface RegionalDexNumber {
+  dex: RegionalDex;
+  number: number;
+}
+
+export const RegionalDexNumber = {
+  parse(dex: RegionalDex, number: number): RegionalDexNumber {
+    const { min, max } = REGIONAL_DEX_RANGES[dex];
+    const result = z
+      .number()
+      .int()
+      .min(min, `${dex} dex numbers start at ${min}`)
+      .max(max, `${dex} dex numbers end at ${max}`)
+      .safeParse(number);
+    if (!result.success) {
+      throw new DexNumberValidationError(result.error);
+    }
+    return { dex, number: result.data };
+  },
+
+  // PokeAPI also lists dexes this API does not model (national, the Let's Go dexes, ...)
+  fromPokeApi(pokedex: string, number: number): RegionalDexNumber | undefined {
+    const dex = RegionalDexSchema.safeParse(pokedex);
+    return dex.success ? RegionalDexNumber.parse(dex.data, number) : undefined;
+  },
+};
+
+export function regionalDexFromName(name: string): RegionalDex | undefined {
+  const identifier = toIdentifier(name);
+  const dex = RegionalDexSchema.safeParse(identifier);
+  return dex.success ? dex.data : DEX_ALIASES[identifier];
+}
+
+export type DexNumberQuery =
+  | { kind: 'national'; number: NationalDexNumber }
+  | ({ kind: 'regional' } & RegionalDexNumber);
+
+// "Paldea #25", "kitakami 1", "#445" or "445"; null when the text is not a dex number at all
+export function parseDexNumberQuery(query: string): DexNumberQuery | null {
+  const match = /^\s*(?:([a-z][a-z\s'-]*?)\s*)?#?\s*(\d+)\s*$/i.exec(query);
+  if (!match) {
+    return null;
+  }
+
+  const number = parseInt(match[2], 10);
+  if (!match[1] || toIdentifier(match[1]) === 'national') {
+    return { kind: 'national', number: NationalDexNumber.parse(number) };
+  }
+
+  const dex = regionalDexFromName(match[1]);
+  return dex ? { kind: 'regional', ...RegionalDexNumber.parse(dex, number) } : null;
+}
@@ -0,0 +1,48 @@
+import { z } from 'zod';
+
+export const EggGroupSchema = z.enum([
+  'Monster',
+  'Water1',
+  'Bug',
+  'Flying',
+  'Field',
+  'Fairy',
+  'Grass',
+  'HumanLike',
+  'Water3',
+  'Mineral',
+  'Amorphous',
+  'Water2',
+  'Ditto',
+  'Dragon',
+  'Undiscovered',
+]);
+
+export type EggGroup = z.infer<typeof EggGroupSchema>;
+
+// PokeAPI uses kebab-case identifiers (e.g. "water1", "humanshape", "no-eggs")
+const POKEAPI_EGG_GROUPS: Record<string, EggGroup> = {
+  monster: 'Monster',
+  water1: 'Water1',
+  bug: 'Bug',
+  flying: 'Flying',
+  ground: 'Field',
+  fairy: 'Fairy',
+  plant: 'Grass',
+  humanshape: 'HumanLike',
+  water3: 'Water3',
+  mineral: 'Mineral',
+  indeterminate: 'Amorphous',
+  water2: 'Water2',
+  ditto: 'Ditto',
+  dragon: 'Dragon',
+  'no-eggs': 'Undiscovered',
+};
+
+export function eggGroupFromPokeApi(name: string): EggGroup {
+  const group = POKEAPI_EGG_GROUPS[name];
+  if (!group) {
+    throw new Error(`Unknown egg group: ${name}`);
+  }
+  return group;
+}
@@ -1,4 +1,5 @@
 import { z } from 'zod';
+import { EmailValidationError } from './ValidationError';
 
 export const EmailSchema = z.string().email('Invalid email format').max(254);
 
@@ -10,8 +11,11 @@ export class Email {
   }
 
   static create(value: string): Email {
-    const validated = EmailSchema.parse(value);
-    return new Email(validated);
+    const result = EmailSchema.safeParse(value);
+    if (!result.success) {
+      throw new EmailValidationError(result.error);
+    }
+    return new Email(result.data);
   }
 
   getValue(): string {
@@ -0,0 +1,8 @@
+import { GameVersion } from './GameVersion';
+
+// A wild encounter of a form; locations are PokeAPI location-area identifiers such as
+// "kanto-route-1-area" or "viridian-forest-area"
+export interface Encounter {
+  gameVersion: GameVersion;
+  location: string;
+}
@@ -0,0 +1,79 @@
+import { z } from 'zod';
+
+export const MediaAssetSchema = z.enum(['cry', 'legacy-cry', 'sprite', 'shiny-sprite']);
+
+export type MediaAsset = z.infer<typeof MediaAssetSchema>;
+
+// Cries and animated sprites of a form as links to the upstream asset hosts, and which 3D
+// renders (the Pokémon HOME models) exist. Links are only stored after the host answered them
+// with a file of the right kind (see resolveMediaType).
+export interface FormMedia {
+  cryUrl: string | null; // the current cry
+  legacyCryUrl: string | null; // the Game Boy era cry, for forms that old
+  spriteUrl: string | null; // animated
+  shinySpriteUrl: string | null;
+  has3dModel: boolean;
+  has3dShinyModel: boolean;
+  checkedAt: Date;
+}
+
+export const MEDIA_CONTENT_TYPES: Record<MediaAsset, readonly string[]> = {
+  cry: ['audio/ogg'],
+  'legacy-cry': ['audio/ogg'],
+  sprite: ['image/gif', 'image/png'],
+  'shiny-sprite': ['image/gif', 'image/png'],
+};
+
+// Only links to these hosts are stored, so the media proxy can never be pointed elsewhere
+export const MEDIA_HOSTS: readonly string[] = ['raw.githubusercontent.com'];
+
+export function mediaUrl(media: FormMedia, asset: MediaAsset): string | null {
+  switch (asset) {
+    case 'cry':
+      return media.cryUrl;
+    case 'legacy-cry':
+      return media.legacyCryUrl;
+    case 'sprite':
+      return media.spriteUrl;
+    case 'shiny-sprite':
+      return media.shinySpriteUrl;
+  }
+}
+
+// Some hosts serve every file as one of these, so the file itself is looked at instead
+const GENERIC_CONTENT_TYPES = ['application/octet-stream', 'text/plain'];
+
+// Leading bytes of each media format
+const SIGNATURES: { contentType: string; bytes: number[] }[] = [
+  { contentType: 'audio/ogg', bytes: [0x4f, 0x67, 0x67, 0x53] }, // OggS
+  { contentType: 'image/gif', bytes: [0x47, 0x49, 0x46, 0x38] }, // GIF8
+  { contentType: 'image/png', bytes: [0x89, 0x50, 0x4e, 0x47] }, // \x89PNG
+];
+
+// The content type to serve the asset as: the declared one when it is right for the asset,
+// else what the first bytes say when the declared one is generic; null when it is anything else
+export function resolveMediaType(
+  asset: MediaAsset,
+  declared: string | null,
+  head: Uint8Array
+): string | null {
+  // Parameters are ignored, so "audio/ogg; codecs=vorbis" is audio/ogg
+  const mimeType = declared?.split(';')[0].trim().toLowerCase() ?? null;
+  if (mimeType !== null && !GENERIC_CONTENT_TYPES.includes(mimeType)) {
+    return MEDIA_CONTENT_TYPES[asset].includes(mimeType) ? mimeType : null;
+  }
+
+  const sniffed = SIGNATURES.find(({ bytes }) => bytes.every((byte, i) => head[i] === byte));
+  return sniffed && MEDIA_CONTENT_TYPES[asset].includes(sniffed.contentType)
+    ? sniffed.contentType
+    : null;
+}
+
+export function isMediaHost(url: string): boolean {
+  try {
+    const parsed = new URL(url);
+    return parsed.protocol === 'https:' && MEDIA_HOSTS.includes(parsed.hostname);
+  } catch {
+    return false;
+  }
+}
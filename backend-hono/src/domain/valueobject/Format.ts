@@ -0,0 +1,314 @@
+import { z } from 'zod';
+import { PokemonMaster } from '../entity/PokemonMaster';
+import { PokemonSpecies } from '../entity/PokemonSpecies';
+import { GameVersion } from './GameVersion';
+import { toIdentifier } from './Identifier';
+import { Level } from './Level';
+import { LegalityViolation } from './LegalityViolation';
+import { PokemonBuild } from './PokemonBuild';
+import { SpeciesCategory } from './SpeciesCategory';
+
+export const FormatIdSchema = z.enum([
+  'vgc-2024-reg-g',
+  'vgc-2024-reg-h',
+  'smogon-sv-ou',
+  'smogon-sv-ubers',
+]);
+
+export type FormatId = z.infer<typeof FormatIdSchema>;
+
+export const ClauseSchema = z.enum(['species', 'item', 'ohko', 'evasion']);
+
+export type Clause = z.infer<typeof ClauseSchema>;
+
+export const BattleStyleSchema = z.enum(['singles', 'doubles']);
+
+export type BattleStyle = z.infer<typeof BattleStyleSchema>;
+
+export interface FormatRules {
+  level: Level;
+  bannedSpeciesIds: number[];
+  bannedCategories: SpeciesCategory[];
+  restrictedSpeciesIds: number[];
+  maxRestricted: number;
+  bannedItems: string[];
+  bannedMoves: string[];
+  bannedAbilities: string[];
+  clauses: Clause[];
+}
+
+export interface FormatMember {
+  slot: number;
+  build: PokemonBuild;
+  form: PokemonMaster;
+  species: PokemonSpecies;
+}
+
+const MAX_TEAM_SIZE = 6;
+
+const OHKO_MOVES = ['fissure', 'guillotine', 'horn-drill', 'sheer-cold'];
+const EVASION_MOVES = ['double-team', 'minimize'];
+
+// Mewtwo, Lugia, Ho-Oh, Kyogre, Groudon, Rayquaza, Dialga, Palkia, Giratina, Reshiram,
+// Zekrom, Kyurem, Xerneas, Yveltal, Zygarde, Cosmog line, Necrozma, Zacian, Zamazenta,
+// Eternatus, Calyrex, Koraidon, Miraidon, Terapagos
+const RESTRICTED_LEGENDARY_IDS = [
+  150, 249, 250, 382, 383, 384, 483, 484, 487, 643, 644, 646, 716, 717, 718, 789, 790, 791, 792,
+  800, 888, 889, 890, 898, 1007, 1008, 1024,
+];
+
+export class Format {
+  constructor(
+    public readonly id: FormatId,
+    public readonly name: string,
+    public readonly gameVersion: GameVersion,
+    public readonly battleStyle: BattleStyle,
+    public readonly rules: FormatRules
+  ) {}
+
+  hasClause(clause: Clause): boolean {
+    return this.rules.clauses.includes(clause);
+  }
+
+  isRestricted(species: PokemonSpecies): boolean {
+    return this.rules.restrictedSpeciesIds.includes(species.getSpeciesId());
+  }
+
+  validate(members: FormatMember[]): LegalityViolation[] {
+    const violations: LegalityViolation[] = [];
+
+    if (members.length > MAX_TEAM_SIZE) {
+      violations.push({
+        code: 'TEAM_TOO_LARGE',
+        message: `A team can have at most ${MAX_TEAM_SIZE} Pokémon`,
+      });
+    }
+
+    for (const member of members) {
+      violations.push(...this.validateMember(member));
+    }
+
+    const restricted = members.filter((member) => this.isRestricted(member.species));
+    if (restricted.length > this.rules.maxRestricted) {
+      violations.push({
+        code: 'TOO_MANY_RESTRICTED',
+        message: `${this.name} allows at most ${this.rules.maxRestricted} restricted Pokémon (found ${restricted.length})`,
+      });
+    }
+
+    if (this.hasClause('species')) {
+      violations.push(...this.findDuplicates(members, 'DUPLICATE_SPECIES'));
+    }
+    if (this.hasClause('item')) {
+      violations.push(...this.findDuplicates(members, 'DUPLICATE_ITEM'));
+    }
+
+    return violations;
+  }
+
+  private validateMember({ slot, build, form, species }: FormatMember): LegalityViolation[] {
+    const violations: LegalityViolation[] = [];
+    const formId = form.getFormId();
+
+    if (
+      this.rules.bannedSpeciesIds.includes(species.getSpeciesId()) ||
+      this.rules.bannedCategories.includes(species.getCategory())
+    ) {
+      violations.push({
+        code: 'BANNED_SPECIES',
+        message: `${form.getName()} is banned in ${this.name}`,
+        slot,
+        formId,
+      });
+    }
+
+    const item = build.getHeldItem();
+    if (item && this.rules.bannedItems.includes(toIdentifier(item))) {
+      violations.push({
+        code: 'BANNED_ITEM',
+        message: `${item} is banned in ${this.name}`,
+        slot,
+        formId,
+      });
+    }
+
+    const ability = build.getAbility();
+    if (ability && !build.isAbilityLegal(form.getAbilities())) {
+      violations.push({
+        code: 'ILLEGAL_ABILITY',
+        message: `${form.getName()} cannot have ${ability}`,
+        slot,
+        formId,
+      });
+    }
+    if (ability && this.rules.bannedAbilities.includes(toIdentifier(ability))) {
+      violations.push({
+        code: 'BANNED_ABILITY',
+        message: `${ability} is banned in ${this.name}`,
+        slot,
+        formId,
+      });
+    }
+
+    for (const move of build.getMoves()) {
+      if (this.isBannedMove(toIdentifier(move))) {
+        violations.push({
+          code: 'BANNED_MOVE',
+          message: `${move} is banned in ${this.name}`,
+          slot,
+          formId,
+        });
+      }
+    }
+
+    return violations;
+  }
+
+  private isBannedMove(move: string): boolean {
+    return (
+      this.rules.bannedMoves.includes(move) ||
+      (this.hasClause('ohko') && OHKO_MOVES.includes(move)) ||
+      (this.hasClause('evasion') && EVASION_MOVES.includes(move))
+    );
+  }
+
+  private findDuplicates(
+    members: FormatMember[],
+    code: 'DUPLICATE_SPECIES' | 'DUPLICATE_ITEM'
+  ): LegalityViolation[] {
+    const violations: LegalityViolation[] = [];
+    const seen = new Map<string | number, number>();
+
+    for (const member of members) {
+      const item = member.build.getHeldItem();
+      const key =
+        code === 'DUPLICATE_SPECIES' ? member.species.getSpeciesId() : item && toIdentifier(item);
+      if (key === undefined || key === '') {
+        continue;
+      }
+
+      const firstSlot = seen.get(key);
+      if (firstSlot === undefined) {
+        seen.set(key, member.slot);
+        continue;
+      }
+
+      violations.push({
+        code,
+        message:
+          code === 'DUPLICATE_SPECIES'
+            ? `${member.species.getName()} is already used in slot ${firstSlot}`
+            : `${item} is already held in slot ${firstSlot}`,
+        slot: member.slot,
+        formId: member.form.getFormId(),
+      });
+    }
+
+    return violations;
+  }
+}
+
+const FORMATS: Record<FormatId, Format> = {
+  'vgc-2024-reg-g': new Format(
+    'vgc-2024-reg-g',
+    'VGC 2024 Regulation G',
+    'scarlet-violet',
+    'doubles',
+    {
+      level: Level.forMode('level50'),
+      bannedSpeciesIds: [],
+      bannedCategories: ['mythical'],
+      restrictedSpeciesIds: RESTRICTED_LEGENDARY_IDS,
+      maxRestricted: 1,
+      bannedItems: [],
+      bannedMoves: [],
+      bannedAbilities: [],
+      clauses: ['species', 'item'],
+    }
+  ),
+  'vgc-2024-reg-h': new Format(
+    'vgc-2024-reg-h',
+    'VGC 2024 Regulation H',
+    'scarlet-violet',
+    'doubles',
+    {
+      level: Level.forMode('level50'),
+      bannedSpeciesIds: [],
+      bannedCategories: ['legendary', 'mythical', 'paradox'],
+      restrictedSpeciesIds: [],
+      maxRestricted: 0,
+      bannedItems: [],
+      bannedMoves: [],
+      bannedAbilities: [],
+      clauses: ['species', 'item'],
+    }
+  ),
+  'smogon-sv-ou': new Format(
+    'smogon-sv-ou',
+    'Smogon SV OU',
+    'scarlet-violet',
+    'singles',
+    {
+      level: Level.forMode('level100'),
+      // Ubers-tier species as of the Scarlet/Violet DLC metagame
+      bannedSpeciesIds: [
+        ...RESTRICTED_LEGENDARY_IDS,
+        493,
+        801,
+        802,
+        956,
+        964,
+        972,
+        979,
+        987,
+        991,
+        1002,
+        1004,
+      ],
+      bannedCategories: [],
+      restrictedSpeciesIds: [],
+      maxRestricted: 0,
+      bannedItems: ['kings-rock', 'razor-fang'],
+      bannedMoves: ['baton-pass', 'last-respects', 'shed-tail'],
+      bannedAbilities: ['arena-trap', 'moody', 'shadow-tag'],
+      clauses: ['species', 'ohko', 'evasion'],
+    }
+  ),
+  'smogon-sv-ubers': new Format(
+    'smogon-sv-ubers',
+    'Smogon SV Ubers',
+    'scarlet-violet',
+    'singles',
+    {
+      level: Level.forMode('level100'),
+      bannedSpeciesIds: [],
+      bannedCategories: [],
+      restrictedSpeciesIds: [],
+      maxRestricted: 0,
+      bannedItems: [],
+      bannedMoves: ['baton-pass'],
+      bannedAbilities: ['moody'],
+      clauses: ['species', 'ohko', 'evasion'],
+    }
+  ),
+};
+
+// Metagame identifiers used by Smogon's monthly usage stats
+const SMOGON_METAGAMES: Record<string, FormatId> = {
+  gen9vgc2024regg: 'vgc-2024-reg-g',
+  gen9vgc2024regh: 'vgc-2024-reg-h',
+  gen9ou: 'smogon-sv-ou',
+  gen9ubers: 'smogon-sv-ubers',
+};
+
+export function formatFromSmogonMetagame(metagame: string): FormatId | undefined {
+  return SMOGON_METAGAMES[metagame.toLowerCase()];
+}
+
+export function getFormat(id: FormatId): Format {
+  return FORMATS[id];
+}
+
+export function listFormats(): Format[] {
+  return Object.values(FORMATS);
+}
@@ -0,0 +1,26 @@
+import { z } from 'zod';
+
+// Also called happiness. Competitive sets assume the maximum, which only matters for Return.
+export const MAX_FRIENDSHIP = 255;
+
+export const FriendshipSchema = z.number().int().min(0).max(MAX_FRIENDSHIP);
+
+// Friendship at which a species that evolves by friendship does so: 220 until Sword and
+// Shield lowered it
+export function getFriendshipEvolutionThreshold(generation: number): number {
+  return generation >= 8 ? 160 : 220;
+}
+
+export function canEvolveByFriendship(friendship: number, generation: number): boolean {
+  return friendship >= getFriendshipEvolutionThreshold(generation);
+}
+
+// Return grows with friendship and Frustration with its absence, both up to 102; the moves
+// exist from Gold and Silver until Sword and Shield dropped them
+export function getReturnPower(friendship: number): number {
+  return Math.max(1, Math.floor((friendship * 10) / 25));
+}
+
+export function getFrustrationPower(friendship: number): number {
+  return Math.max(1, Math.floor(((MAX_FRIENDSHIP - friendship) * 10) / 25));
+}
@@ -0,0 +1,139 @@
+import { z } from 'zod';
+
+// Ordered by release; identifiers follow PokeAPI version groups
+export const GameVersionSchema = z.enum([
+  'red-blue',
+  'yellow',
+  'gold-silver',
+  'crystal',
+  'ruby-sapphire',
+  'emerald',
+  'firered-leafgreen',
+  'diamond-pearl',
+  'platinum',
+  'heartgold-soulsilver',
+  'black-white',
+  'black-2-white-2',
+  'x-y',
+  'omega-ruby-alpha-sapphire',
+  'sun-moon',
+  'ultra-sun-ultra-moon',
+  'lets-go-pikachu-lets-go-eevee',
+  'sword-shield',
+  'brilliant-diamond-and-shining-pearl',
+  'legends-arceus',
+  'scarlet-violet',
+]);
+
+export type GameVersion = z.infer<typeof GameVersionSchema>;
+
+export const GAME_VERSIONS: readonly GameVersion[] = GameVersionSchema.options;
+
+const GENERATIONS: Record<GameVersion, number> = {
+  'red-blue': 1,
+  yellow: 1,
+  'gold-silver': 2,
+  crystal: 2,
+  'ruby-sapphire': 3,
+  emerald: 3,
+  'firered-leafgreen': 3,
+  'diamond-pearl': 4,
+  platinum: 4,
+  'heartgold-soulsilver': 4,
+  'black-white': 5,
+  'black-2-white-2': 5,
+  'x-y': 6,
+  'omega-ruby-alpha-sapphire': 6,
+  'sun-moon': 7,
+  'ultra-sun-ultra-moon': 7,
+  'lets-go-pikachu-lets-go-eevee': 7,
+  'sword-shield': 8,
+  'brilliant-diamond-and-shining-pearl': 8,
+  'legends-arceus': 8,
+  'scarlet-violet': 9,
+};
+
+// PokeAPI encounter data is per game (red, blue, ...) rather than per version group
+const POKEAPI_VERSIONS: Record<string, GameVersion> = {
+  red: 'red-blue',
+  blue: 'red-blue',
+  yellow: 'yellow',
+  gold: 'gold-silver',
+  silver: 'gold-silver',
+  crystal: 'crystal',
+  ruby: 'ruby-sapphire',
+  sapphire: 'ruby-sapphire',
+  emerald: 'emerald',
+  firered: 'firered-leafgreen',
+  leafgreen: 'firered-leafgreen',
+  diamond: 'diamond-pearl',
+  pearl: 'diamond-pearl',
+  platinum: 'platinum',
+  heartgold: 'heartgold-soulsilver',
+  soulsilver: 'heartgold-soulsilver',
+  black: 'black-white',
+  white: 'black-white',
+  'black-2': 'black-2-white-2',
+  'white-2': 'black-2-white-2',
+  x: 'x-y',
+  y: 'x-y',
+  'omega-ruby': 'omega-ruby-alpha-sapphire',
+  'alpha-sapphire': 'omega-ruby-alpha-sapphire',
+  sun: 'sun-moon',
+  moon: 'sun-moon',
+  'ultra-sun': 'ultra-sun-ultra-moon',
+  'ultra-moon': 'ultra-sun-ultra-moon',
+  'lets-go-pikachu': 'lets-go-pikachu-lets-go-eevee',
+  'lets-go-eevee': 'lets-go-pikachu-lets-go-eevee',
+  sword: 'sword-shield',
+  shield: 'sword-shield',
+  'brilliant-diamond': 'brilliant-diamond-and-shining-pearl',
+  'shining-pearl': 'brilliant-diamond-and-shining-pearl',
+  'legends-arceus': 'legends-arceus',
+  scarlet: 'scarlet-violet',
+  violet: 'scarlet-violet',
+};
+
+// Undefined for games outside the main series, such as Colosseum
+export function gameVersionFromPokeApi(version: string): GameVersion | undefined {
+  return POKEAPI_VERSIONS[version];
+}
+
+export function getGeneration(version: GameVersion): number {
+  return GENERATIONS[version];
+}
+
+export function compareGameVersions(a: GameVersion, b: GameVersion): number {
+  return GAME_VERSIONS.indexOf(a) - GAME_VERSIONS.indexOf(b);
+}
+
+export function firstGameOfGeneration(generation: number): GameVersion {
+  const version = GAME_VERSIONS.find((v) => GENERATIONS[v] === generation);
+  if (!version) {
+    throw new Error(`Unknown generation: ${generation}`);
+  }
+  return version;
+}
+
+export class Availability {
+  private readonly obtainableIn: GameVersion[];
+
+  constructor(
+    public readonly introducedIn: GameVersion,
+    obtainableIn: GameVersion[]
+  ) {
+    this.obtainableIn = [...new Set(obtainableIn)].sort(compareGameVersions);
+  }
+
+  isObtainableIn(version: GameVersion): boolean {
+    return this.obtainableIn.includes(version);
+  }
+
+  getIntroducedGeneration(): number {
+    return getGeneration(this.introducedIn);
+  }
+
+  getObtainableIn(): GameVersion[] {
+    return [...this.obtainableIn];
+  }
+}
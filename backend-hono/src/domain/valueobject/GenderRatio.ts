@@ -0,0 +1,51 @@
+import { z } from 'zod';
+
+// Female ratio in eighths as used by the games and PokeAPI; -1 means genderless
+export const GenderRateSchema = z.number().int().min(-1).max(8);
+
+const GENDERLESS = -1;
+
+export class GenderRatio {
+  private readonly femaleEighths: number;
+
+  private constructor(femaleEighths: number) {
+    this.femaleEighths = femaleEighths;
+  }
+
+  static fromFemaleEighths(value: number): GenderRatio {
+    const validated = GenderRateSchema.parse(value);
+    return new GenderRatio(validated);
+  }
+
+  static genderless(): GenderRatio {
+    return new GenderRatio(GENDERLESS);
+  }
+
+  isGenderless(): boolean {
+    return this.femaleEighths === GENDERLESS;
+  }
+
+  canBeMale(): boolean {
+    return !this.isGenderless() && this.femaleEighths < 8;
+  }
+
+  canBeFemale(): boolean {
+    return !this.isGenderless() && this.femaleEighths > 0;
+  }
+
+  getFemaleRate(): number | null {
+    return this.isGenderless() ? null : this.femaleEighths / 8;
+  }
+
+  getMaleRate(): number | null {
+    return this.isGenderless() ? null : (8 - this.femaleEighths) / 8;
+  }
+
+  getFemaleEighths(): number {
+    return this.femaleEighths;
+  }
+
+  equals(other: GenderRatio): boolean {
+    return this.femaleEighths === other.femaleEighths;
+  }
+}
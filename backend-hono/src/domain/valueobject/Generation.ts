@@ -0,0 +1,18 @@
+import { z } from 'zod';
+
+export const GenerationSchema = z.number().int().min(1).max(9);
+
+export type Generation = z.infer<typeof GenerationSchema>;
+
+export const CURRENT_GENERATION: Generation = 9;
+
+// Gold and Silver were the last games without natures or abilities, with stats built from
+// DVs and stat experience, and with a move's category decided by its type
+export function isRetroGeneration(generation: Generation): boolean {
+  return generation <= 2;
+}
+
+// Red and Blue have one Special stat for both attacking and defending
+export function hasSplitSpecial(generation: Generation): boolean {
+  return generation >= 2;
+}
@@ -1,5 +1,6 @@
 import bcrypt from 'bcryptjs';
 import { z } from 'zod';
+import { PasswordValidationError } from './ValidationError';
 
 const SALT_ROUNDS = 10;
 
@@ -16,7 +17,10 @@ export class HashedPassword {
   }
 
   static async fromPlainText(password: string): Promise<HashedPassword> {
-    PasswordSchema.parse(password);
+    const result = PasswordSchema.safeParse(password);
+    if (!result.success) {
+      throw new PasswordValidationError(result.error);
+    }
     const hash = await bcrypt.hash(password, SALT_ROUNDS);
     return new HashedPassword(hash);
   }
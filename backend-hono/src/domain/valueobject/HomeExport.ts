@@ -0,0 +1,90 @@
+import { z } from 'zod';
+import { StatName } from './Stats';
+
+// A Pokémon HOME export as JSON: boxes and battle teams holding Pokémon identified by National
+// Dex number and HOME form number (0 is the base form).
+//
+//   {
+//     "boxes": [{ "name": "Box 1", "pokemon": [{ "dex_no": 445, "form_no": 0, ... }] }],
+//     "teams": [{ "name": "Rental", "pokemon": [...] }]
+//   }
+//
+// Names (nature, ability, moves, ...) are kept as written; mapping them is up to the caller so
+// one bad entry doesn't reject the whole export.
+
+const HOME_STAT_KEYS: Record<string, StatName> = {
+  hp: 'hp',
+  atk: 'attack',
+  def: 'defense',
+  spa: 'specialAttack',
+  spd: 'specialDefense',
+  spe: 'speed',
+};
+
+const HomeSpreadSchema = z
+  .object({
+    hp: z.number().int().min(0),
+    atk: z.number().int().min(0),
+    def: z.number().int().min(0),
+    spa: z.number().int().min(0),
+    spd: z.number().int().min(0),
+    spe: z.number().int().min(0),
+  })
+  .partial();
+
+const HomePokemonSchema = z.object({
+  dex_no: z.number().int().positive(),
+  form_no: z.number().int().min(0).default(0),
+  nickname: z.string().optional(),
+  level: z.number().int().min(1).max(100).optional(),
+  nature: z.string().optional(),
+  ability: z.string().optional(),
+  held_item: z.string().optional(),
+  tera_type: z.string().optional(),
+  moves: z.array(z.string()).default([]),
+  evs: HomeSpreadSchema.default({}),
+  ivs: HomeSpreadSchema.default({}),
+});
+
+const HomeGroupSchema = z.object({
+  name: z.string(),
+  pokemon: z.array(HomePokemonSchema),
+});
+
+const HomeExportSchema = z.object({
+  boxes: z.array(HomeGroupSchema).default([]),
+  teams: z.array(HomeGroupSchema).default([]),
+});
+
+export type HomePokemon = z.infer<typeof HomePokemonSchema>;
+export type HomeExport = z.infer<typeof HomeExportSchema>;
+
+export class HomeExportError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'HomeExportError';
+  }
+}
+
+export function parseHomeExport(json: string): HomeExport {
+  let data: unknown;
+  try {
+    data = JSON.parse(json);
+  } catch (error) {
+    throw new HomeExportError(`Not valid JSON: ${(error as Error).message}`);
+  }
+
+  const result = HomeExportSchema.safeParse(data);
+  if (!result.success) {
+    const issue = result.error.issues[0];
+    throw new HomeExportError(`${issue.path.join('.') || 'export'}: ${issue.message}`);
+  }
+  return result.data;
+}
+
+// HOME's stat keys ("atk", "spa") to the stat names builds use
+export function toStatSpread(spread: HomePokemon['evs']): Partial<Record<StatName, number>> {
+  return Object.fromEntries(
+    Object.entries(spread).map(([key, value]) => [HOME_STAT_KEYS[key], value])
+  );
+}
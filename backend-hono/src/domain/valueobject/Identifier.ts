@@ -0,0 +1,16 @@
+// Names arrive in several spellings: display names ("King's Rock"), PokeAPI identifiers
+// ("kings-rock") and Showdown/Smogon ids ("kingsrock").
+
+// Display name -> PokeAPI-style identifier
+export function toIdentifier(value: string): string {
+  return value
+    .trim()
+    .toLowerCase()
+    .replace(/['’.:]/g, '')
+    .replace(/[\s_]+/g, '-');
+}
+
+// Any spelling -> a key that compares equal across all three
+export function toLookupKey(value: string): string {
+  return value.toLowerCase().replace(/[^a-z0-9]/g, '');
+}
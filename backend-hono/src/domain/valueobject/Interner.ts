@@ -0,0 +1,38 @@
+// Hands out a small integer handle for each distinct string and keeps a single copy of it.
+// Names repeat thousands of times across the dex ("protect" is in almost every learnset), so
+// bulk data holds handles and resolves them to strings only when they are read.
+export class Interner {
+  private readonly handles = new Map<string, number>();
+  private readonly values: string[] = [];
+
+  intern(value: string): number {
+    let handle = this.handles.get(value);
+    if (handle === undefined) {
+      handle = this.values.length;
+      this.values.push(value);
+      this.handles.set(value, handle);
+    }
+    return handle;
+  }
+
+  // Undefined for a string that was never interned, without adding it; for lookups of user
+  // input, which would otherwise grow the table
+  find(value: string): number | undefined {
+    return this.handles.get(value);
+  }
+
+  resolve(handle: number): string {
+    const value = this.values[handle];
+    if (value === undefined) {
+      throw new Error(`Unknown handle: ${handle}`);
+    }
+    return value;
+  }
+
+  get size(): number {
+    return this.values.length;
+  }
+}
+
+// Handles only mean something within one process, so they are never stored or sent
+export const moveNames = new Interner();
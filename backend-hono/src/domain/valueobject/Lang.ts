@@ -0,0 +1,6 @@
+import { z } from 'zod';
+
+// Languages the API answers in; names and texts in the dataset come in both
+export const LangSchema = z.enum(['en', 'ja']);
+
+export type Lang = z.infer<typeof LangSchema>;
@@ -0,0 +1,81 @@
+import { z } from 'zod';
+import { moveNames } from './Interner';
+import { FormId, SpeciesId } from './PokemonId';
+
+export const LearnMethodSchema = z.enum(['level-up', 'egg', 'machine', 'tutor']);
+
+export type LearnMethod = z.infer<typeof LearnMethodSchema>;
+
+export interface LearnsetEntry {
+  moveName: string;
+  method: LearnMethod;
+  level?: number;
+}
+
+const METHODS = LearnMethodSchema.options;
+const NO_LEVEL = -1;
+
+// Entries are kept as parallel typed arrays of interned move names, methods and levels rather
+// than one object each: a full dex of learnsets is hundreds of thousands of entries. There are
+// under a thousand moves, so a handle fits in 16 bits.
+export class Learnset {
+  private readonly moves: Uint16Array;
+  private readonly methods: Uint8Array;
+  private readonly levels: Int8Array;
+
+  constructor(
+    public readonly formId: FormId,
+    entries: LearnsetEntry[]
+  ) {
+    this.moves = Uint16Array.from(entries, (entry) => moveNames.intern(entry.moveName));
+    this.methods = Uint8Array.from(entries, (entry) => METHODS.indexOf(entry.method));
+    this.levels = Int8Array.from(entries, (entry) => entry.level ?? NO_LEVEL);
+  }
+
+  canLearn(moveName: string): boolean {
+    const move = moveNames.find(moveName);
+    return move !== undefined && this.moves.includes(move);
+  }
+
+  getMethods(moveName: string): LearnMethod[] {
+    const move = moveNames.find(moveName);
+    const methods: LearnMethod[] = [];
+    this.moves.forEach((entryMove, index) => {
+      if (entryMove === move) {
+        methods.push(METHODS[this.methods[index]]);
+      }
+    });
+    return methods;
+  }
+
+  isEggMove(moveName: string): boolean {
+    return this.getMethods(moveName).includes('egg');
+  }
+
+  getMoveNames(): string[] {
+    return [...new Set(this.moves)].map((move) => moveNames.resolve(move));
+  }
+
+  getEntries(): LearnsetEntry[] {
+    return Array.from(this.moves, (move, index) => {
+      const level = this.levels[index];
+      return {
+        moveName: moveNames.resolve(move),
+        method: METHODS[this.methods[index]],
+        level: level === NO_LEVEL ? undefined : level,
+      };
+    });
+  }
+}
+
+// Alternate forms without a learnset of their own share the default form's
+export function findFormLearnset(
+  learnsets: Learnset[],
+  formId: FormId,
+  speciesId: SpeciesId
+): Learnset | undefined {
+  return (
+    learnsets.find((learnset) => learnset.formId === formId) ??
+    learnsets.find((learnset) => learnset.formId === FormId.defaultOf(speciesId))
+  );
+}
@@ -0,0 +1,20 @@
+export type LegalityViolationCode =
+  | 'FORM_NOT_FOUND'
+  | 'NOT_OBTAINABLE'
+  | 'ILLEGAL_ABILITY'
+  | 'TEAM_TOO_LARGE'
+  | 'BANNED_SPECIES'
+  | 'BANNED_ITEM'
+  | 'BANNED_MOVE'
+  | 'BANNED_ABILITY'
+  | 'TOO_MANY_RESTRICTED'
+  | 'DUPLICATE_SPECIES'
+  | 'DUPLICATE_ITEM'
+  | 'NOT_DRAFTED';
+
+export interface LegalityViolation {
+  code: LegalityViolationCode;
+  message: string;
+  slot?: number;
+  formId?: number;
+}
@@ -0,0 +1,53 @@
+import { z } from 'zod';
+import { LevelValidationError } from './ValidationError';
+
+export const LevelSchema = z
+  .number()
+  .int('Level must be a whole number')
+  .min(1, 'Level must be at least 1')
+  .max(100, 'Level must be at most 100');
+
+// Competitive numbers are calculated at one of two levels: 50 for VGC and the in-game Battle
+// Stadium, 100 for Smogon
+export const CalculationModeSchema = z.enum(['level50', 'level100']);
+
+export type CalculationMode = z.infer<typeof CalculationModeSchema>;
+
+const MODE_LEVELS: Record<CalculationMode, number> = { level50: 50, level100: 100 };
+
+export class Level {
+  private readonly value: number;
+
+  private constructor(value: number) {
+    this.value = value;
+  }
+
+  static create(value: number): Level {
+    const result = LevelSchema.safeParse(value);
+    if (!result.success) {
+      throw new LevelValidationError(result.error);
+    }
+    return new Level(result.data);
+  }
+
+  static forMode(mode: CalculationMode): Level {
+    return new Level(MODE_LEVELS[mode]);
+  }
+
+  // An explicit level wins over the mode
+  static resolve(value: number | undefined, mode: CalculationMode): Level {
+    return value === undefined ? Level.forMode(mode) : Level.create(value);
+  }
+
+  getValue(): number {
+    return this.value;
+  }
+
+  equals(other: Level): boolean {
+    return this.value === other.value;
+  }
+
+  toString(): string {
+    return String(this.value);
+  }
+}
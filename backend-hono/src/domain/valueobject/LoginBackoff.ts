@@ -0,0 +1,24 @@
+// Consecutive failed logins for one identifier (an email address or a client IP) push the next
+// allowed attempt further out: free for the first few, then 1s, 2s, 4s, ... up to 15 minutes.
+// Nothing is ever locked for good, so the real owner of an attacked account can still get in.
+export const FREE_LOGIN_FAILURES = 3;
+
+const BASE_DELAY_MS = 1000;
+const MAX_DELAY_MS = 15 * 60 * 1000;
+
+// Failures older than this are forgotten, and counting starts again
+export const LOGIN_FAILURE_WINDOW_MS = 24 * 60 * 60 * 1000;
+
+export interface LoginFailures {
+  identifier: string;
+  failures: number;
+  lastFailedAt: Date;
+}
+
+export function nextLoginAllowedAt({ failures, lastFailedAt }: LoginFailures): Date {
+  if (failures < FREE_LOGIN_FAILURES) {
+    return lastFailedAt;
+  }
+  const delay = Math.min(BASE_DELAY_MS * 2 ** (failures - FREE_LOGIN_FAILURES), MAX_DELAY_MS);
+  return new Date(lastFailedAt.getTime() + delay);
+}
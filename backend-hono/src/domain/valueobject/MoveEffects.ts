@@ -0,0 +1,79 @@
+import { z } from 'zod';
+
+const PercentSchema = z.number().int().min(0).max(100);
+const FractionSchema = z.number().min(0).max(1);
+
+export const MoveStatSchema = z.enum([
+  'attack',
+  'defense',
+  'specialAttack',
+  'specialDefense',
+  'speed',
+  'accuracy',
+  'evasion',
+]);
+
+export type MoveStat = z.infer<typeof MoveStatSchema>;
+
+const POKEAPI_MOVE_STATS: Record<string, MoveStat> = {
+  attack: 'attack',
+  defense: 'defense',
+  'special-attack': 'specialAttack',
+  'special-defense': 'specialDefense',
+  speed: 'speed',
+  accuracy: 'accuracy',
+  evasion: 'evasion',
+};
+
+export function moveStatFromPokeApi(name: string): MoveStat {
+  const stat = POKEAPI_MOVE_STATS[name];
+  if (!stat) {
+    throw new Error(`Unknown move stat: ${name}`);
+  }
+  return stat;
+}
+
+export const MoveStatChangeSchema = z.object({
+  stat: MoveStatSchema,
+  stages: z.number().int().min(-6).max(6),
+  // Swords Dance and Close Combat change the user's stats, Growl and Icy Wind the target's
+  target: z.enum(['user', 'target']),
+});
+
+export type MoveStatChange = z.infer<typeof MoveStatChangeSchema>;
+
+// What a move does besides its damage, from PokeAPI's move meta data
+export const MoveEffectsSchema = z.object({
+  flinchChance: PercentSchema,
+  // Chance the stat changes happen; 100 for moves that always make them
+  statChance: PercentSchema,
+  statChanges: z.array(MoveStatChangeSchema),
+  ailment: z.string().nullable(), // PokeAPI ailment, e.g. "paralysis", "burn"
+  ailmentChance: PercentSchema,
+  // Fractions of the damage dealt: Giga Drain heals 1/2, Brave Bird costs 1/3
+  drain: FractionSchema,
+  recoil: FractionSchema,
+  // Fraction of the user's max HP restored, e.g. 1/2 for Recover
+  healing: FractionSchema,
+});
+
+export type MoveEffects = z.infer<typeof MoveEffectsSchema>;
+
+export const HazardSchema = z.enum(['stealth-rock', 'spikes', 'toxic-spikes', 'sticky-web']);
+
+export type Hazard = z.infer<typeof HazardSchema>;
+
+// PokeAPI has no field for entry hazards, so the moves that lay them are listed here
+const HAZARD_MOVES: Record<string, Hazard> = {
+  'stealth-rock': 'stealth-rock',
+  'stone-axe': 'stealth-rock',
+  spikes: 'spikes',
+  'ceaseless-edge': 'spikes',
+  'toxic-spikes': 'toxic-spikes',
+  'sticky-web': 'sticky-web',
+};
+
+// The hazard a move (by PokeAPI identifier) sets on the target's side
+export function getHazardSetBy(moveName: string): Hazard | undefined {
+  return HAZARD_MOVES[moveName];
+}
@@ -0,0 +1,105 @@
+import { toLookupKey } from './Identifier';
+
+// Keys a Pokémon can be looked up by: the English name, the Japanese name and its romaji.
+// Stored keys and query keys go through the same functions, so any of "Garchomp", "gaburiasu",
+// "ガブリアス" or "がぶりあす" finds the same form.
+
+const KATAKANA = /[ァ-ヶ]/g;
+const KATAKANA_TO_HIRAGANA = 0x60;
+
+// Hepburn, one row of the kana table at a time; small kana and っ are handled in toRomaji
+const KANA_ROWS: [string, string][] = [
+  ['あいうえお', 'a i u e o'],
+  ['かきくけこがぎぐげご', 'ka ki ku ke ko ga gi gu ge go'],
+  ['さしすせそざじずぜぞ', 'sa shi su se so za ji zu ze zo'],
+  ['たちつてとだぢづでど', 'ta chi tsu te to da ji zu de do'],
+  ['なにぬねの', 'na ni nu ne no'],
+  ['はひふへほばびぶべぼぱぴぷぺぽ', 'ha hi fu he ho ba bi bu be bo pa pi pu pe po'],
+  ['まみむめも', 'ma mi mu me mo'],
+  ['やゆよ', 'ya yu yo'],
+  ['らりるれろ', 'ra ri ru re ro'],
+  ['わゐゑをんゔ', 'wa i e o n vu'],
+];
+
+// Small kana replace the vowel of the syllable before them (ファ = fa, キャ = kya)
+const SMALL_KANA_ROWS: [string, string][] = [['ぁぃぅぇぉゃゅょゎ', 'a i u e o ya yu yo wa']];
+
+function toKanaMap(rows: [string, string][]): Map<string, string> {
+  return new Map(
+    rows.flatMap(([kana, romaji]) => {
+      const syllables = romaji.split(' ');
+      return [...kana].map((char, i): [string, string] => [char, syllables[i]]);
+    })
+  );
+}
+
+const ROMAJI = toKanaMap(KANA_ROWS);
+const SMALL_KANA = toKanaMap(SMALL_KANA_ROWS);
+
+// Half-width kana and full-width Latin are folded by NFKC; katakana become hiragana
+export function toKanaKey(value: string): string {
+  return value
+    .normalize('NFKC')
+    .toLowerCase()
+    .replace(KATAKANA, (kana) => String.fromCharCode(kana.charCodeAt(0) - KATAKANA_TO_HIRAGANA))
+    .replace(/[\s・]/g, '');
+}
+
+function toRomaji(kana: string): string {
+  const syllables: string[] = [];
+  let doubleNext = false;
+
+  for (const char of kana) {
+    if (char === 'っ') {
+      doubleNext = true;
+      continue;
+    }
+
+    const small = SMALL_KANA.get(char);
+    const previous = syllables[syllables.length - 1];
+    if (small !== undefined && previous !== undefined) {
+      const consonant = previous.slice(0, -1);
+      if (/^(sh|ch|j)$/.test(consonant) && small.startsWith('y')) {
+        // しゃ = sha, not shya
+        syllables[syllables.length - 1] = consonant + small.slice(1);
+      } else {
+        // ウィ = wi; otherwise the previous vowel is dropped
+        syllables[syllables.length - 1] = (consonant === '' ? 'w' : consonant) + small;
+      }
+      continue;
+    }
+
+    // Long vowel marks are dropped; anything that is not kana (♀, digits) passes through
+    let syllable = ROMAJI.get(char) ?? small ?? (char === 'ー' ? '' : char);
+    if (doubleNext && /^[a-z]/.test(syllable)) {
+      syllable = (syllable.startsWith('ch') ? 't' : syllable[0]) + syllable;
+    }
+    doubleNext = false;
+    syllables.push(syllable);
+  }
+
+  return syllables.join('');
+}
+
+// Long vowels are spelled many ways (Ryuu, Ryū, Ryu), so they all collapse to one vowel
+function collapseVowels(romaji: string): string {
+  return romaji.replace(/ou/g, 'o').replace(/([aeiou])\1+/g, '$1');
+}
+
+export function toRomajiKey(value: string): string {
+  const kana = toKanaKey(value);
+  const latin = /[ぁ-ゖ]/.test(kana) ? toRomaji(kana) : kana;
+  return collapseVowels(toLookupKey(latin.normalize('NFD')));
+}
+
+// Every key a form is indexed under
+export function getNameKeys(name: string, nameJp: string): string[] {
+  const keys = [toLookupKey(name), toKanaKey(nameJp), toRomajiKey(nameJp)];
+  return [...new Set(keys.filter((key) => key !== ''))];
+}
+
+// Every key a search for `query` should try
+export function getQueryKeys(query: string): string[] {
+  const keys = [toLookupKey(query), toKanaKey(query), toRomajiKey(query)];
+  return [...new Set(keys.filter((key) => key !== ''))];
+}
@@ -29,3 +29,40 @@ export const NatureSchema = z.enum([
 ]);
 
 export type Nature = z.infer<typeof NatureSchema>;
+
+type NatureStat = 'attack' | 'defense' | 'specialAttack' | 'specialDefense' | 'speed';
+
+// [raised, lowered]; neutral natures are omitted
+const NATURE_MODIFIERS: Partial<Record<Nature, [NatureStat, NatureStat]>> = {
+  Lonely: ['attack', 'defense'],
+  Brave: ['attack', 'speed'],
+  Adamant: ['attack', 'specialAttack'],
+  Naughty: ['attack', 'specialDefense'],
+  Bold: ['defense', 'attack'],
+  Relaxed: ['defense', 'speed'],
+  Impish: ['defense', 'specialAttack'],
+  Lax: ['defense', 'specialDefense'],
+  Timid: ['speed', 'attack'],
+  Hasty: ['speed', 'defense'],
+  Jolly: ['speed', 'specialAttack'],
+  Naive: ['speed', 'specialDefense'],
+  Modest: ['specialAttack', 'attack'],
+  Mild: ['specialAttack', 'defense'],
+  Quiet: ['specialAttack', 'speed'],
+  Rash: ['specialAttack', 'specialDefense'],
+  Calm: ['specialDefense', 'attack'],
+  Gentle: ['specialDefense', 'defense'],
+  Sassy: ['specialDefense', 'speed'],
+  Careful: ['specialDefense', 'specialAttack'],
+};
+
+export function getNatureMultiplier(nature: Nature, stat: string): number {
+  const modifier = NATURE_MODIFIERS[nature];
+  if (!modifier) {
+    return 1;
+  }
+  if (modifier[0] === stat) {
+    return 1.1;
+  }
+  return modifier[1] === stat ? 0.9 : 1;
+}
@@ -0,0 +1,46 @@
+import { z } from 'zod';
+import { NicknameValidationError } from './ValidationError';
+
+// Characters the in-game keyboards offer: ASCII (with space), full-width ASCII, the accented
+// Latin letters of the European languages, hiragana, katakana, kanji for the Chinese keyboards,
+// and a few symbols such as ♂/♀
+const NICKNAME_PATTERN = /^[\x20-\x7E　！-～À-ÖØ-öø-ÿŒœ‘’“”…♂♀々ぁ-ゖゝゞァ-ヺー-ヾ一-鿿]+$/;
+
+export const NicknameSchema = z
+  .string()
+  .min(1, 'Nickname cannot be empty')
+  .max(12, 'Nickname must be 12 characters or less')
+  .regex(NICKNAME_PATTERN, 'Nickname contains a character the games do not allow')
+  .refine((value) => value.trim() === value, {
+    message: 'Nickname cannot start or end with a space',
+    params: { code: 'SURROUNDING_SPACE' },
+  });
+
+export class Nickname {
+  private readonly value: string;
+
+  private constructor(value: string) {
+    this.value = value;
+  }
+
+  // NFC first, so "é" typed as "e" plus a combining accent counts as one character
+  static create(value: string): Nickname {
+    const result = NicknameSchema.safeParse(value.normalize('NFC'));
+    if (!result.success) {
+      throw new NicknameValidationError(result.error);
+    }
+    return new Nickname(result.data);
+  }
+
+  getValue(): string {
+    return this.value;
+  }
+
+  equals(other: Nickname): boolean {
+    return this.value === other.value;
+  }
+
+  toString(): string {
+    return this.value;
+  }
+}
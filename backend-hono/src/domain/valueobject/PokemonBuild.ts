@@ -0,0 +1,103 @@
+import { AbilitySlots } from './AbilitySlots';
+import { FriendshipSchema, MAX_FRIENDSHIP } from './Friendship';
+import { Nature } from './Nature';
+import { Nickname } from './Nickname';
+import { FormId } from './PokemonId';
+import { PokemonType } from './PokemonType';
+import { EVStats, IVStats, IVStatsSchema, MAX_IVS } from './Stats';
+
+export interface PokemonBuildProps {
+  formId: FormId;
+  nickname?: Nickname;
+  ability?: string;
+  // Skips the ability slot check, for hacked or otherwise deliberately illegal sets
+  allowIllegalAbility?: boolean;
+  heldItem?: string;
+  moves: string[];
+  teraType?: PokemonType;
+  // Stat investment; defaults to a neutral nature, no EVs and perfect IVs
+  nature?: Nature;
+  ev?: EVStats;
+  iv?: IVStats;
+  // Defaults to the maximum
+  friendship?: number;
+}
+
+export class PokemonBuild {
+  private readonly props: PokemonBuildProps;
+
+  constructor(props: PokemonBuildProps) {
+    if (props.moves.length > 4) {
+      throw new Error('A build can have at most 4 moves');
+    }
+    if (new Set(props.moves).size !== props.moves.length) {
+      throw new Error('A build cannot have the same move twice');
+    }
+    if (props.iv) {
+      IVStatsSchema.parse(props.iv);
+    }
+    if (props.friendship !== undefined) {
+      FriendshipSchema.parse(props.friendship);
+    }
+    this.props = { ...props, moves: [...props.moves] };
+  }
+
+  static create(props: PokemonBuildProps): PokemonBuild {
+    return new PokemonBuild(props);
+  }
+
+  getFormId(): FormId {
+    return this.props.formId;
+  }
+
+  getNickname(): Nickname | undefined {
+    return this.props.nickname;
+  }
+
+  getAbility(): string | undefined {
+    return this.props.ability;
+  }
+
+  allowsIllegalAbility(): boolean {
+    return this.props.allowIllegalAbility ?? false;
+  }
+
+  // Legal when no ability is chosen, the form's slots are unknown (not imported yet) or the
+  // override is set
+  isAbilityLegal(abilities: AbilitySlots | undefined): boolean {
+    const ability = this.props.ability;
+    return !ability || !abilities || this.allowsIllegalAbility() || abilities.has(ability);
+  }
+
+  getHeldItem(): string | undefined {
+    return this.props.heldItem;
+  }
+
+  getMoves(): string[] {
+    return [...this.props.moves];
+  }
+
+  getTeraType(): PokemonType | undefined {
+    return this.props.teraType;
+  }
+
+  getNature(): Nature {
+    return this.props.nature ?? 'Hardy';
+  }
+
+  getEV(): EVStats {
+    return this.props.ev ?? EVStats.empty();
+  }
+
+  getIV(): IVStats {
+    return this.props.iv ?? MAX_IVS;
+  }
+
+  getFriendship(): number {
+    return this.props.friendship ?? MAX_FRIENDSHIP;
+  }
+
+  hasMove(moveName: string): boolean {
+    return this.props.moves.includes(moveName);
+  }
+}
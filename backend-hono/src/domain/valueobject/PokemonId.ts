@@ -0,0 +1,77 @@
+import { z } from 'zod';
+import { FormIdValidationError, SpeciesIdValidationError } from './ValidationError';
+
+// Species and form IDs are both plain integers in PokeAPI, and a base form even shares its
+// species' number, so the types are branded to keep one from being passed as the other.
+export type SpeciesId = number & { readonly __brand: 'SpeciesId' };
+export type FormId = number & { readonly __brand: 'FormId' };
+
+// National Dex size as of Scarlet/Violet (Pecharunt); raise when a new generation is imported
+export const MAX_SPECIES_ID = 1025;
+// PokeAPI numbers base forms like their species and alternate forms from 10001
+const MIN_ALTERNATE_FORM_ID = 10001;
+const MAX_ALTERNATE_FORM_ID = 10999;
+// Users' custom forms are numbered from here, clear of anything PokeAPI will ever use
+export const MIN_CUSTOM_FORM_ID = 1000001;
+const MAX_CUSTOM_FORM_ID = 2147483647; // Postgres integer
+
+export const SpeciesIdSchema = z
+  .number()
+  .int()
+  .min(1, 'Species ID must be positive')
+  .max(MAX_SPECIES_ID, `Species ID must be at most ${MAX_SPECIES_ID}`)
+  .transform((value) => value as SpeciesId);
+
+export const FormIdSchema = z
+  .number()
+  .int()
+  .refine(
+    (value) =>
+      (value >= 1 && value <= MAX_SPECIES_ID) ||
+      (value >= MIN_ALTERNATE_FORM_ID && value <= MAX_ALTERNATE_FORM_ID) ||
+      (value >= MIN_CUSTOM_FORM_ID && value <= MAX_CUSTOM_FORM_ID),
+    {
+      message: `Form ID must be 1-${MAX_SPECIES_ID}, ${MIN_ALTERNATE_FORM_ID}-${MAX_ALTERNATE_FORM_ID} or a custom form ID`,
+      params: { code: 'UNKNOWN_FORM_ID' },
+    }
+  )
+  .transform((value) => value as FormId);
+
+export const SpeciesId = {
+  parse(value: number): SpeciesId {
+    const result = SpeciesIdSchema.safeParse(value);
+    if (!result.success) {
+      throw new SpeciesIdValidationError(result.error);
+    }
+    return result.data;
+  },
+
+  // Stored IDs were validated when they were imported
+  fromRepository(value: number): SpeciesId {
+    return value as SpeciesId;
+  },
+};
+
+export const FormId = {
+  parse(value: number): FormId {
+    const result = FormIdSchema.safeParse(value);
+    if (!result.success) {
+      throw new FormIdValidationError(result.error);
+    }
+    return result.data;
+  },
+
+  fromRepository(value: number): FormId {
+    return value as FormId;
+  },
+
+  // The default form of a species has the species' own ID
+  defaultOf(speciesId: SpeciesId): FormId {
+    return speciesId as number as FormId;
+  },
+
+  // A user's custom form rather than official data
+  isCustom(formId: FormId): boolean {
+    return formId >= MIN_CUSTOM_FORM_ID;
+  },
+};
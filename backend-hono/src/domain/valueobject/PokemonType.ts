@@ -23,6 +23,96 @@ export const PokemonTypeSchema = z.enum([
 
 export type PokemonType = z.infer<typeof PokemonTypeSchema>;
 
+export const POKEMON_TYPES: readonly PokemonType[] = PokemonTypeSchema.options;
+
+// PokeAPI uses lowercase identifiers (e.g. "water")
+export function typeFromPokeApi(name: string): PokemonType {
+  return PokemonTypeSchema.parse(name.charAt(0).toUpperCase() + name.slice(1));
+}
+
+// Damage multiplier of an attacking type (row) against a single defending type (column).
+// Omitted pairs are neutral (1x). `npm run check:typechart` compares this with
+// fixtures/type-chart.csv, so a typo here fails CI.
+const TYPE_CHART: Record<PokemonType, Partial<Record<PokemonType, number>>> = {
+  Normal: { Rock: 0.5, Ghost: 0, Steel: 0.5 },
+  Fire: { Fire: 0.5, Water: 0.5, Grass: 2, Ice: 2, Bug: 2, Rock: 0.5, Dragon: 0.5, Steel: 2 },
+  Water: { Fire: 2, Water: 0.5, Grass: 0.5, Ground: 2, Rock: 2, Dragon: 0.5 },
+  Electric: { Water: 2, Electric: 0.5, Grass: 0.5, Ground: 0, Flying: 2, Dragon: 0.5 },
+  Grass: {
+    Fire: 0.5,
+    Water: 2,
+    Grass: 0.5,
+    Poison: 0.5,
+    Ground: 2,
+    Flying: 0.5,
+    Bug: 0.5,
+    Rock: 2,
+    Dragon: 0.5,
+    Steel: 0.5,
+  },
+  Ice: { Fire: 0.5, Water: 0.5, Grass: 2, Ice: 0.5, Ground: 2, Flying: 2, Dragon: 2, Steel: 0.5 },
+  Fighting: {
+    Normal: 2,
+    Ice: 2,
+    Poison: 0.5,
+    Flying: 0.5,
+    Psychic: 0.5,
+    Bug: 0.5,
+    Rock: 2,
+    Ghost: 0,
+    Dark: 2,
+    Steel: 2,
+    Fairy: 0.5,
+  },
+  Poison: { Grass: 2, Poison: 0.5, Ground: 0.5, Rock: 0.5, Ghost: 0.5, Steel: 0, Fairy: 2 },
+  Ground: { Fire: 2, Electric: 2, Grass: 0.5, Poison: 2, Flying: 0, Bug: 0.5, Rock: 2, Steel: 2 },
+  Flying: { Electric: 0.5, Grass: 2, Fighting: 2, Bug: 2, Rock: 0.5, Steel: 0.5 },
+  Psychic: { Fighting: 2, Poison: 2, Psychic: 0.5, Dark: 0, Steel: 0.5 },
+  Bug: {
+    Fire: 0.5,
+    Grass: 2,
+    Fighting: 0.5,
+    Poison: 0.5,
+    Flying: 0.5,
+    Psychic: 2,
+    Ghost: 0.5,
+    Dark: 2,
+    Steel: 0.5,
+    Fairy: 0.5,
+  },
+  Rock: { Fire: 2, Ice: 2, Fighting: 0.5, Ground: 0.5, Flying: 2, Bug: 2, Steel: 0.5 },
+  Ghost: { Normal: 0, Psychic: 2, Ghost: 2, Dark: 0.5 },
+  Dragon: { Dragon: 2, Steel: 0.5, Fairy: 0 },
+  Dark: { Fighting: 0.5, Psychic: 2, Ghost: 2, Dark: 0.5, Fairy: 0.5 },
+  Steel: { Fire: 0.5, Water: 0.5, Electric: 0.5, Ice: 2, Rock: 2, Steel: 0.5, Fairy: 2 },
+  Fairy: { Fire: 0.5, Fighting: 2, Poison: 0.5, Dragon: 2, Dark: 2, Steel: 0.5 },
+};
+
+// Bump whenever TYPE_CHART changes; clients cache the chart by this revision
+export const TYPE_CHART_REVISION = 1;
+
+export function getEffectiveness(attack: PokemonType, defend: PokemonType): number {
+  return TYPE_CHART[attack][defend] ?? 1;
+}
+
+export interface EffectivenessFactor {
+  defend: PokemonType;
+  multiplier: number;
+}
+
+// An attacking type against each of a Pokémon's types, kept apart so the overall multiplier can
+// be explained rather than just shown (see explainEffectiveness in i18n)
+export class TypeEffectiveness {
+  constructor(
+    public readonly attack: PokemonType,
+    public readonly factors: readonly EffectivenessFactor[]
+  ) {}
+
+  getMultiplier(): number {
+    return this.factors.reduce((multiplier, factor) => multiplier * factor.multiplier, 1);
+  }
+}
+
 export class TypeSet {
   constructor(
     public readonly type1: PokemonType,
@@ -40,4 +130,40 @@ export class TypeSet {
   isSingleType(): boolean {
     return this.type2 === undefined;
   }
+
+  getTypes(): PokemonType[] {
+    return this.type2 ? [this.type1, this.type2] : [this.type1];
+  }
+
+  defendAgainst(attack: PokemonType): number {
+    return this.getTypes().reduce(
+      (multiplier, type) => multiplier * getEffectiveness(attack, type),
+      1
+    );
+  }
+
+  effectivenessOf(attack: PokemonType): TypeEffectiveness {
+    return new TypeEffectiveness(
+      attack,
+      this.getTypes().map((defend) => ({ defend, multiplier: getEffectiveness(attack, defend) }))
+    );
+  }
+
+  defendAgainstAll(): Record<PokemonType, number> {
+    return Object.fromEntries(
+      POKEMON_TYPES.map((attack) => [attack, this.defendAgainst(attack)])
+    ) as Record<PokemonType, number>;
+  }
+
+  resists(attack: PokemonType): boolean {
+    return this.defendAgainst(attack) < 1;
+  }
+
+  isWeakTo(attack: PokemonType): boolean {
+    return this.defendAgainst(attack) > 1;
+  }
+
+  toString(): string {
+    return this.getTypes().join('/');
+  }
 }
@@ -0,0 +1,42 @@
+import { IVStats } from './Stats';
+
+// Gen 1/2 stat investment. Builds keep IVs and EVs, read the way Showdown exports old-gen sets:
+// a DV is half the IV, and an EV value of n is n² stat experience, so 31 IVs and 252 EVs are
+// the maximum of both.
+
+export const MAX_DV = 15;
+export const MAX_STAT_EXPERIENCE = 65535;
+
+// One DV covers both special stats; the HP DV is not chosen but derived (see getHpDV)
+export interface DVs {
+  attack: number;
+  defense: number;
+  speed: number;
+  special: number;
+}
+
+export function toDVs(iv: IVStats): DVs {
+  const toDV = (value: number) => Math.min(MAX_DV, Math.floor(value / 2));
+  return {
+    attack: toDV(iv.attack),
+    defense: toDV(iv.defense),
+    speed: toDV(iv.speed),
+    special: toDV(iv.specialAttack),
+  };
+}
+
+// The lowest bit of the Attack, Defense, Speed and Special DVs, in that order
+export function getHpDV(dvs: DVs): number {
+  return (
+    ((dvs.attack & 1) << 3) | ((dvs.defense & 1) << 2) | ((dvs.speed & 1) << 1) | (dvs.special & 1)
+  );
+}
+
+export function toStatExperience(ev: number): number {
+  return Math.min(MAX_STAT_EXPERIENCE, ev * ev);
+}
+
+// A quarter of the square root, rounded up first and capped at 255, so it tops out at 63
+export function getStatExperienceBonus(statExperience: number): number {
+  return Math.floor(Math.min(255, Math.ceil(Math.sqrt(statExperience))) / 4);
+}
@@ -0,0 +1,185 @@
+import { MAX_FRIENDSHIP } from './Friendship';
+import { Nature, NatureSchema } from './Nature';
+import { PokemonType, typeFromPokeApi } from './PokemonType';
+import { StatName } from './Stats';
+
+// One set from a Pokémon Showdown team export:
+//
+//   Garchomp (M) @ Choice Scarf
+//   Ability: Rough Skin
+//   Tera Type: Steel
+//   EVs: 252 Atk / 4 SpD / 252 Spe
+//   Jolly Nature
+//   - Earthquake
+//
+// Names are kept as written; resolving them to forms and moves is up to the caller.
+export interface ShowdownSet {
+  species: string;
+  nickname?: string;
+  item?: string;
+  ability?: string;
+  level?: number;
+  teraType?: PokemonType;
+  nature?: Nature;
+  evs: Partial<Record<StatName, number>>;
+  ivs: Partial<Record<StatName, number>>;
+  happiness?: number;
+  moves: string[];
+}
+
+export class ShowdownPasteError extends Error {
+  constructor(
+    message: string,
+    public readonly line: number
+  ) {
+    super(`Line ${line}: ${message}`);
+    this.name = 'ShowdownPasteError';
+  }
+}
+
+const MAX_MEMBERS = 6;
+
+const STAT_LABELS: Record<string, StatName> = {
+  hp: 'hp',
+  atk: 'attack',
+  def: 'defense',
+  spa: 'specialAttack',
+  spd: 'specialDefense',
+  spe: 'speed',
+};
+
+// Lines Showdown exports that have no counterpart in a build
+const IGNORED_PREFIXES = ['Shiny:', 'Gigantamax:', 'Dynamax Level:', 'Hidden Power:'];
+
+export function parseShowdownPaste(text: string): ShowdownSet[] {
+  const sets: ShowdownSet[] = [];
+  let current: ShowdownSet | undefined;
+
+  text.split(/\r?\n/).forEach((raw, index) => {
+    const line = raw.trim();
+    const lineNumber = index + 1;
+
+    // Blank lines separate sets; "=== [format] Team ===" headers start a new team in a
+    // multi-team export, which is treated as one long paste
+    if (line === '' || line.startsWith('===')) {
+      current = undefined;
+      return;
+    }
+
+    if (!current) {
+      if (sets.length === MAX_MEMBERS) {
+        throw new ShowdownPasteError(`A team has at most ${MAX_MEMBERS} members`, lineNumber);
+      }
+      current = parseHeader(line);
+      sets.push(current);
+      return;
+    }
+
+    parseDetail(current, line, lineNumber);
+  });
+
+  return sets;
+}
+
+// "Nickname (Species) (F) @ Item", where everything but the species is optional
+function parseHeader(line: string): ShowdownSet {
+  const [namePart, item] = splitOnce(line, ' @ ');
+  const name = namePart.replace(/\s+\((M|F)\)$/, '');
+  const nicknamed = name.match(/^(.+?)\s+\(([^()]+)\)$/);
+
+  return {
+    species: nicknamed ? nicknamed[2].trim() : name,
+    nickname: nicknamed ? nicknamed[1].trim() : undefined,
+    item: item || undefined,
+    evs: {},
+    ivs: {},
+    moves: [],
+  };
+}
+
+function parseDetail(set: ShowdownSet, line: string, lineNumber: number): void {
+  if (line.startsWith('-') || line.startsWith('~')) {
+    // Slash options ("- Protect / Detect") list alternatives; the first one is used
+    const move = line.slice(1).split(' / ')[0].trim();
+    if (set.moves.length === 4) {
+      throw new ShowdownPasteError(`${set.species} has more than 4 moves`, lineNumber);
+    }
+    set.moves.push(move);
+    return;
+  }
+
+  const natureMatch = line.match(/^(\w+) Nature$/);
+  if (natureMatch) {
+    const nature = NatureSchema.safeParse(natureMatch[1]);
+    if (!nature.success) {
+      throw new ShowdownPasteError(`Unknown nature "${natureMatch[1]}"`, lineNumber);
+    }
+    set.nature = nature.data;
+    return;
+  }
+
+  const [label, value] = splitOnce(line, ':');
+  switch (label) {
+    case 'Ability':
+      set.ability = value;
+      return;
+    case 'Level':
+      set.level = parseInteger(value, 1, 100, lineNumber);
+      return;
+    case 'Happiness':
+      set.happiness = parseInteger(value, 0, MAX_FRIENDSHIP, lineNumber);
+      return;
+    case 'Tera Type':
+      try {
+        set.teraType = typeFromPokeApi(value.toLowerCase());
+      } catch {
+        throw new ShowdownPasteError(`Unknown Tera Type "${value}"`, lineNumber);
+      }
+      return;
+    case 'EVs':
+      set.evs = parseSpread(value, 252, lineNumber);
+      return;
+    case 'IVs':
+      set.ivs = parseSpread(value, 31, lineNumber);
+      return;
+  }
+
+  if (IGNORED_PREFIXES.some((prefix) => line.startsWith(prefix))) {
+    return;
+  }
+  throw new ShowdownPasteError(`Unrecognised line "${line}"`, lineNumber);
+}
+
+// "252 Atk / 4 SpD / 252 Spe"
+function parseSpread(
+  value: string,
+  max: number,
+  lineNumber: number
+): Partial<Record<StatName, number>> {
+  const spread: Partial<Record<StatName, number>> = {};
+  for (const part of value.split('/')) {
+    const [amount, label] = splitOnce(part.trim(), ' ');
+    const stat = STAT_LABELS[label.toLowerCase()];
+    if (!stat) {
+      throw new ShowdownPasteError(`Unknown stat "${label}"`, lineNumber);
+    }
+    spread[stat] = parseInteger(amount, 0, max, lineNumber);
+  }
+  return spread;
+}
+
+function parseInteger(value: string, min: number, max: number, lineNumber: number): number {
+  const parsed = Number(value);
+  if (!Number.isInteger(parsed) || parsed < min || parsed > max) {
+    throw new ShowdownPasteError(`Expected a number from ${min} to ${max}`, lineNumber);
+  }
+  return parsed;
+}
+
+function splitOnce(value: string, separator: string): [string, string] {
+  const index = value.indexOf(separator);
+  if (index === -1) {
+    return [value.trim(), ''];
+  }
+  return [value.slice(0, index).trim(), value.slice(index + separator.length).trim()];
+}
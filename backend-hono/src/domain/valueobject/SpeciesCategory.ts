@@ -0,0 +1,11 @@
+import { z } from 'zod';
+
+export const SpeciesCategorySchema = z.enum(['ordinary', 'legendary', 'mythical', 'paradox']);
+
+export type SpeciesCategory = z.infer<typeof SpeciesCategorySchema>;
+
+// PokeAPI has no paradox flag, so these are listed explicitly
+export const PARADOX_SPECIES_IDS: readonly number[] = [
+  984, 985, 986, 987, 988, 989, 990, 991, 992, 993, 994, 995, 1005, 1006, 1009, 1010, 1020,
+  1021, 1022, 1023,
+];
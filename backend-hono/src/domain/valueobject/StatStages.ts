@@ -0,0 +1,51 @@
+import { z } from 'zod';
+import { StatName } from './Stats';
+
+const StageSchema = z.number().int().min(-6).max(6);
+
+export const StatStagesSchema = z.object({
+  attack: StageSchema,
+  defense: StageSchema,
+  specialAttack: StageSchema,
+  specialDefense: StageSchema,
+  speed: StageSchema,
+});
+
+export type StagedStat = Exclude<StatName, 'hp'>;
+
+// In-battle stat boosts and drops (Swords Dance +2, Intimidate -1, ...)
+export class StatStages {
+  private readonly stages: Record<StagedStat, number>;
+
+  constructor(stages: Partial<Record<StagedStat, number>> = {}) {
+    this.stages = StatStagesSchema.parse({
+      attack: stages.attack ?? 0,
+      defense: stages.defense ?? 0,
+      specialAttack: stages.specialAttack ?? 0,
+      specialDefense: stages.specialDefense ?? 0,
+      speed: stages.speed ?? 0,
+    });
+  }
+
+  static neutral(): StatStages {
+    return new StatStages();
+  }
+
+  get(stat: StagedStat): number {
+    return this.stages[stat];
+  }
+
+  // +1 is 3/2, +6 is 8/2, -1 is 2/3, -6 is 2/8
+  getMultiplier(stat: StagedStat): number {
+    const stage = this.stages[stat];
+    return stage >= 0 ? (2 + stage) / 2 : 2 / (2 - stage);
+  }
+
+  applyTo(stat: StagedStat, value: number): number {
+    return Math.floor(value * this.getMultiplier(stat));
+  }
+
+  isNeutral(): boolean {
+    return Object.values(this.stages).every((stage) => stage === 0);
+  }
+}
@@ -18,8 +18,28 @@ export const IVStatsSchema = z.object({
   speed: z.number().int().min(0).max(31),
 });
 
+export const BaseStatsSchema = z.object({
+  hp: z.number().int().min(1).max(255),
+  attack: z.number().int().min(1).max(255),
+  defense: z.number().int().min(1).max(255),
+  specialAttack: z.number().int().min(1).max(255),
+  specialDefense: z.number().int().min(1).max(255),
+  speed: z.number().int().min(1).max(255),
+});
+
 export type Stats = z.infer<typeof StatsSchema>;
 export type IVStats = z.infer<typeof IVStatsSchema>;
+export type BaseStats = z.infer<typeof BaseStatsSchema>;
+export type StatName = keyof Stats;
+
+export const MAX_IVS: IVStats = {
+  hp: 31,
+  attack: 31,
+  defense: 31,
+  specialAttack: 31,
+  specialDefense: 31,
+  speed: 31,
+};
 
 export class EVStats {
   constructor(
@@ -44,6 +64,14 @@ export class EVStats {
     });
   }
 
+  static empty(): EVStats {
+    return new EVStats(0, 0, 0, 0, 0, 0);
+  }
+
+  get(stat: StatName): number {
+    return this[stat];
+  }
+
   getTotal(): number {
     return (
       this.hp + this.attack + this.defense + this.specialAttack + this.specialDefense + this.speed
@@ -0,0 +1,205 @@
+import { Nature, NatureSchema } from './Nature';
+import { FormId, FormIdSchema } from './PokemonId';
+import { PokemonType, PokemonTypeSchema } from './PokemonType';
+import { IVStats, StatName } from './Stats';
+
+// Binary layout (v1), big-endian:
+//   u8 version | u8 member count | members... | u8 checksum
+// Each member:
+//   u16 form ID | u8 nature index | u8 flags (bit0 tera, bit1 custom IVs, bits 2-4 move count)
+//   [u8 tera type index] | u16 move ID x count | u8 EV x 6 | [u8 IV x 6]
+// Ability and held item are not encoded yet; a later version can append them.
+export const TEAM_CODE_VERSION = 1;
+
+// Prefix for QR payloads; kept within the QR alphanumeric character set
+export const TEAM_CODE_QR_PREFIX = 'POKEDICT:';
+
+const FLAG_TERA = 0b1;
+const FLAG_CUSTOM_IVS = 0b10;
+const MOVE_COUNT_SHIFT = 2;
+const MAX_MEMBERS = 6;
+
+// Crockford base32: no I, L, O or U, so codes survive being read aloud or retyped
+const BASE32_ALPHABET = '0123456789ABCDEFGHJKMNPQRSTVWXYZ';
+
+const STAT_ORDER: readonly StatName[] = [
+  'hp',
+  'attack',
+  'defense',
+  'specialAttack',
+  'specialDefense',
+  'speed',
+];
+
+export interface TeamCodeMember {
+  formId: FormId;
+  nature: Nature;
+  teraType?: PokemonType;
+  moveIds: number[];
+  evs: Record<StatName, number>;
+  ivs: IVStats;
+}
+
+export class TeamCodeError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TeamCodeError';
+  }
+}
+
+export function encodeTeam(members: TeamCodeMember[]): Uint8Array {
+  if (members.length > MAX_MEMBERS) {
+    throw new TeamCodeError(`A team code holds at most ${MAX_MEMBERS} members`);
+  }
+
+  const bytes: number[] = [TEAM_CODE_VERSION, members.length];
+  for (const member of members) {
+    if (member.moveIds.length > 4) {
+      throw new TeamCodeError('A team code member holds at most 4 moves');
+    }
+    const customIvs = STAT_ORDER.some((stat) => member.ivs[stat] !== 31);
+    const flags =
+      (member.teraType ? FLAG_TERA : 0) |
+      (customIvs ? FLAG_CUSTOM_IVS : 0) |
+      (member.moveIds.length << MOVE_COUNT_SHIFT);
+
+    bytes.push(...u16(member.formId), NatureSchema.options.indexOf(member.nature), flags);
+    if (member.teraType) {
+      bytes.push(PokemonTypeSchema.options.indexOf(member.teraType));
+    }
+    for (const moveId of member.moveIds) {
+      bytes.push(...u16(moveId));
+    }
+    bytes.push(...STAT_ORDER.map((stat) => member.evs[stat]));
+    if (customIvs) {
+      bytes.push(...STAT_ORDER.map((stat) => member.ivs[stat]));
+    }
+  }
+  bytes.push(checksum(bytes));
+
+  return Uint8Array.from(bytes);
+}
+
+export function decodeTeam(data: Uint8Array): TeamCodeMember[] {
+  if (data.length < 3 || checksum([...data.subarray(0, -1)]) !== data[data.length - 1]) {
+    throw new TeamCodeError('Team code is corrupted');
+  }
+  if (data[0] !== TEAM_CODE_VERSION) {
+    throw new TeamCodeError(`Unsupported team code version ${data[0]}`);
+  }
+
+  let offset = 2;
+  const read = (): number => {
+    if (offset >= data.length - 1) {
+      throw new TeamCodeError('Team code is truncated');
+    }
+    return data[offset++];
+  };
+  const readU16 = (): number => (read() << 8) | read();
+
+  const members: TeamCodeMember[] = [];
+  const count = data[1];
+  if (count > MAX_MEMBERS) {
+    throw new TeamCodeError('Team code is corrupted');
+  }
+  for (let i = 0; i < count; i++) {
+    const formId = FormIdSchema.safeParse(readU16());
+    const nature = NatureSchema.options[read()];
+    const flags = read();
+    const teraType = (flags & FLAG_TERA) !== 0 ? PokemonTypeSchema.options[read()] : undefined;
+    const moveIds = Array.from({ length: (flags >> MOVE_COUNT_SHIFT) & 0b111 }, readU16);
+    const evs = readStats(read);
+    const ivs = (flags & FLAG_CUSTOM_IVS) !== 0 ? readStats(read) : readStats(() => 31);
+    const evTotal = STAT_ORDER.reduce((sum, stat) => sum + evs[stat], 0);
+    const validStats =
+      evTotal <= 510 &&
+      STAT_ORDER.every((stat) => evs[stat] <= 252) &&
+      STAT_ORDER.every((stat) => ivs[stat] <= 31);
+    if (
+      !formId.success ||
+      !nature ||
+      ((flags & FLAG_TERA) !== 0 && !teraType) ||
+      moveIds.length > 4 ||
+      !validStats
+    ) {
+      throw new TeamCodeError('Team code is corrupted');
+    }
+    members.push({ formId: formId.data, nature, teraType, moveIds, evs, ivs });
+  }
+  if (offset !== data.length - 1) {
+    throw new TeamCodeError('Team code has trailing data');
+  }
+
+  return members;
+}
+
+export function toBase32(data: Uint8Array): string {
+  let bits = 0;
+  let value = 0;
+  let output = '';
+  for (const byte of data) {
+    value = ((value << 8) | byte) & 0xffff;
+    bits += 8;
+    while (bits >= 5) {
+      output += BASE32_ALPHABET[(value >>> (bits - 5)) & 31];
+      bits -= 5;
+    }
+  }
+  if (bits > 0) {
+    output += BASE32_ALPHABET[(value << (5 - bits)) & 31];
+  }
+  return output;
+}
+
+// Accepts lowercase, hyphen/space grouping and the usual look-alikes (I/L -> 1, O -> 0)
+export function fromBase32(code: string): Uint8Array {
+  const normalized = code
+    .toUpperCase()
+    .replace(/[\s-]/g, '')
+    .replace(/[IL]/g, '1')
+    .replace(/O/g, '0');
+
+  let bits = 0;
+  let value = 0;
+  const bytes: number[] = [];
+  for (const char of normalized) {
+    const index = BASE32_ALPHABET.indexOf(char);
+    if (index === -1) {
+      throw new TeamCodeError(`Invalid character in team code: ${char}`);
+    }
+    value = ((value << 5) | index) & 0xffff;
+    bits += 5;
+    if (bits >= 8) {
+      bytes.push((value >>> (bits - 8)) & 0xff);
+      bits -= 8;
+    }
+  }
+  return Uint8Array.from(bytes);
+}
+
+export function toQrPayload(code: string): string {
+  return `${TEAM_CODE_QR_PREFIX}${code}`;
+}
+
+// Accepts either a scanned QR payload or a bare short code
+export function fromQrPayload(payload: string): string {
+  const trimmed = payload.trim();
+  return trimmed.toUpperCase().startsWith(TEAM_CODE_QR_PREFIX)
+    ? trimmed.slice(TEAM_CODE_QR_PREFIX.length)
+    : trimmed;
+}
+
+function u16(value: number): [number, number] {
+  if (!Number.isInteger(value) || value < 0 || value > 0xffff) {
+    throw new TeamCodeError(`Value ${value} does not fit in a team code`);
+  }
+  return [value >> 8, value & 0xff];
+}
+
+function readStats(read: () => number): Record<StatName, number> {
+  return Object.fromEntries(STAT_ORDER.map((stat) => [stat, read()])) as Record<StatName, number>;
+}
+
+function checksum(bytes: number[]): number {
+  return bytes.reduce((sum, byte) => (sum + byte) & 0xff, 0);
+}
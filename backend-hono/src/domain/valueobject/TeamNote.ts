@@ -0,0 +1,54 @@
+import { z } from 'zod';
+import { TeamNoteValidationError } from './ValidationError';
+
+export const MAX_TEAM_NOTES_LENGTH = 2000;
+export const MAX_MEMBER_NOTES_LENGTH = 500;
+
+// HTML tags and comments, which Markdown renderers pass through to the page
+const HTML_PATTERN = /<!--[\s\S]*?-->|<\/?[a-z][a-z0-9-]*(?:\s[^>]*)?\/?>/gi;
+// Where a link points: after "](" in an inline link, after "]:" in a reference definition, or
+// inside an autolink's <...>
+const LINK_PATTERN = /(\]\(\s*<?|^ {0,3}\[[^\]\n]+\]:[ \t]*<?|<)([^\s()<>]+)/gim;
+// C0 and C1 control characters other than tab and line feed
+const CONTROL_PATTERN = /[\u0000-\u0008\u000B-\u001F\u007F-\u009F]/g;
+
+// Only http(s) and mailto links, and links without a scheme. A character reference before the
+// first "/", "?" or "#" could spell out a scheme once the renderer decodes it, so it counts as one.
+function isSafeDestination(destination: string): boolean {
+  const head = destination.split(/[/?#]/, 1)[0];
+  return !/[:&]/.test(head) || /^(https?|mailto):/i.test(destination);
+}
+
+// Notes are shown as Markdown to whoever sees the team, so anything that would run or load in
+// their browser is taken out rather than rejected: HTML, and links to other schemes (such as
+// javascript:), which are pointed at "#". Emphasis, lists and ordinary links stay as written.
+export function sanitizeNote(text: string): string {
+  let sanitized = text.normalize('NFC').replace(/\r\n?/g, '\n').replace(CONTROL_PATTERN, '');
+  // Removing a tag can join the text around it into a new one, e.g. "<scr<b>ipt>"
+  let previous: string;
+  do {
+    previous = sanitized;
+    sanitized = sanitized.replace(HTML_PATTERN, '');
+  } while (sanitized !== previous);
+
+  return sanitized
+    .replace(LINK_PATTERN, (match, prefix: string, destination: string) =>
+      isSafeDestination(destination) ? match : `${prefix}#`
+    )
+    .trim();
+}
+
+// Sanitized notes within the limit; empty notes are no notes
+export function parseNote(text: string | null, maxLength: number): string | null {
+  if (text === null) {
+    return null;
+  }
+  const result = z
+    .string()
+    .max(maxLength, `Notes must be ${maxLength} characters or less`)
+    .safeParse(sanitizeNote(text));
+  if (!result.success) {
+    throw new TeamNoteValidationError(result.error);
+  }
+  return result.data === '' ? null : result.data;
+}
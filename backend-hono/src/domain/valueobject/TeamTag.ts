@@ -0,0 +1,60 @@
+import { z } from 'zod';
+import { TeamTagValidationError } from './ValidationError';
+
+export const MAX_TEAM_TAGS = 10;
+
+export const TeamTagSchema = z
+  .string()
+  .min(1, 'Tag cannot be empty')
+  .max(20, 'Tag must be 20 characters or less')
+  .refine((value) => !value.includes(','), {
+    message: 'Tag cannot contain a comma',
+    params: { code: 'INVALID_CHARACTER' },
+  });
+
+const TeamTagListSchema = z
+  .array(TeamTagSchema)
+  .max(MAX_TEAM_TAGS, `A team can have at most ${MAX_TEAM_TAGS} tags`);
+
+export class TeamTag {
+  private readonly value: string;
+
+  private constructor(value: string) {
+    this.value = value;
+  }
+
+  // NFKC and lower case, so "VGC", "vgc" and the full-width "ＶＧＣ" are one tag
+  static normalize(value: string): string {
+    return value.normalize('NFKC').trim().toLowerCase();
+  }
+
+  static create(value: string): TeamTag {
+    const result = TeamTagSchema.safeParse(TeamTag.normalize(value));
+    if (!result.success) {
+      throw new TeamTagValidationError(result.error);
+    }
+    return new TeamTag(result.data);
+  }
+
+  // The tags of one team: duplicates after normalization are dropped, in first-seen order
+  static createList(values: string[]): TeamTag[] {
+    const unique = [...new Set(values.map(TeamTag.normalize))];
+    const result = TeamTagListSchema.safeParse(unique);
+    if (!result.success) {
+      throw new TeamTagValidationError(result.error);
+    }
+    return result.data.map((value) => new TeamTag(value));
+  }
+
+  getValue(): string {
+    return this.value;
+  }
+
+  equals(other: TeamTag): boolean {
+    return this.value === other.value;
+  }
+
+  toString(): string {
+    return this.value;
+  }
+}
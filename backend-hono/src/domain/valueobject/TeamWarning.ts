@@ -0,0 +1,59 @@
+import { toIdentifier } from './Identifier';
+import { PokemonBuild } from './PokemonBuild';
+
+// Team-building advice rather than legality: formats with an item clause reject duplicate
+// items outright (see Format), but elsewhere they are allowed and merely worth a look
+export type TeamWarningCode = 'DUPLICATE_ITEM' | 'DUPLICATE_TERA_TYPE';
+
+export interface TeamWarning {
+  code: TeamWarningCode;
+  message: string;
+  value: string; // the shared item or Tera type, as written in the first build
+  slots: number[]; // every slot sharing it
+}
+
+export function findDuplicateItems(builds: PokemonBuild[]): TeamWarning[] {
+  return findDuplicates(
+    builds,
+    'DUPLICATE_ITEM',
+    (build) => build.getHeldItem(),
+    (item, count) => `${item} is held by ${count} members`
+  );
+}
+
+export function findDuplicateTeraTypes(builds: PokemonBuild[]): TeamWarning[] {
+  return findDuplicates(
+    builds,
+    'DUPLICATE_TERA_TYPE',
+    (build) => build.getTeraType(),
+    (teraType, count) => `${count} members share the ${teraType} Tera type`
+  );
+}
+
+export function findTeamWarnings(builds: PokemonBuild[]): TeamWarning[] {
+  return [...findDuplicateItems(builds), ...findDuplicateTeraTypes(builds)];
+}
+
+function findDuplicates(
+  builds: PokemonBuild[],
+  code: TeamWarningCode,
+  getValue: (build: PokemonBuild) => string | undefined,
+  describe: (value: string, count: number) => string
+): TeamWarning[] {
+  // Keyed by identifier so "Choice Scarf" and "choice-scarf" count as the same item
+  const groups = new Map<string, { value: string; slots: number[] }>();
+  builds.forEach((build, slot) => {
+    const value = getValue(build);
+    if (!value) {
+      return;
+    }
+    const key = toIdentifier(value);
+    const group = groups.get(key) ?? { value, slots: [] };
+    group.slots.push(slot);
+    groups.set(key, group);
+  });
+
+  return [...groups.values()]
+    .filter(({ slots }) => slots.length > 1)
+    .map(({ value, slots }) => ({ code, message: describe(value, slots.length), value, slots }));
+}
@@ -0,0 +1,6 @@
+import { z } from 'zod';
+
+// New accounts are always 'user'; admins are promoted directly in the database
+export const UserRoleSchema = z.enum(['user', 'admin']);
+
+export type UserRole = z.infer<typeof UserRoleSchema>;
@@ -1,10 +1,77 @@
 import { z } from 'zod';
+import { UsernameValidationError } from './ValidationError';
 
-export const UsernameSchema = z
+// 'ascii' is the default; 'unicode' additionally allows Japanese (hiragana, katakana, kanji)
+export type UsernameMode = 'ascii' | 'unicode';
+
+const ASCII_PATTERN = /^[\x21-\x7E]+$/;
+const UNICODE_PATTERN = /^[\x21-\x7E々ぁ-ゖゝゞァ-ヺー-ヾ一-鿿]+$/;
+
+const KATAKANA = /[ァ-ヺー-ヾ]/;
+const KANJI = /[々一-鿿]/;
+
+// Kanji that render almost identically to a katakana character (and vice versa)
+const CONFUSABLES: Record<string, string> = {
+  力: 'カ',
+  口: 'ロ',
+  工: 'エ',
+  二: 'ニ',
+  八: 'ハ',
+  卜: 'ト',
+  夕: 'タ',
+  一: 'ー',
+  三: 'ミ',
+};
+const CONFUSABLE_CHARS = new Set([...Object.keys(CONFUSABLES), ...Object.values(CONFUSABLES)]);
+
+const BaseUsernameSchema = z
   .string()
   .min(1, 'Username cannot be empty')
   .max(20, 'Username must be 20 characters or less');
 
+export const UsernameSchema = BaseUsernameSchema.regex(
+  ASCII_PATTERN,
+  'Username may only contain ASCII letters, digits and symbols'
+);
+
+export const UnicodeUsernameSchema = BaseUsernameSchema.regex(
+  UNICODE_PATTERN,
+  'Username may only contain ASCII, hiragana, katakana and kanji'
+).superRefine((value, ctx) => {
+  const confusable = findConfusable(value);
+  if (confusable) {
+    ctx.addIssue({
+      code: z.ZodIssueCode.custom,
+      message: `Username contains "${confusable}", which looks like a character from another script`,
+      params: { code: 'CONFUSABLE_CHARACTER' },
+    });
+  }
+});
+
+function scriptOf(char: string): 'katakana' | 'kanji' | 'other' {
+  if (KATAKANA.test(char)) {
+    return 'katakana';
+  }
+  return KANJI.test(char) ? 'kanji' : 'other';
+}
+
+// A look-alike is suspicious when it sits among characters of the other script,
+// e.g. 力 inside a katakana word ("パワー力ード") or ロ inside kanji ("入ロ")
+function findConfusable(value: string): string | undefined {
+  const chars = [...value];
+  return chars.find((char, index) => {
+    if (!CONFUSABLE_CHARS.has(char)) {
+      return false;
+    }
+    const script = scriptOf(char);
+    const neighbours = [chars[index - 1], chars[index + 1]]
+      .filter((neighbour) => neighbour !== undefined && !CONFUSABLE_CHARS.has(neighbour))
+      .map(scriptOf)
+      .filter((neighbourScript) => neighbourScript !== 'other');
+    return neighbours.length > 0 && neighbours.every((neighbour) => neighbour !== script);
+  });
+}
+
 export class Username {
   private readonly value: string;
 
@@ -12,9 +79,22 @@ export class Username {
     this.value = value;
   }
 
-  static create(value: string): Username {
-    const validated = UsernameSchema.parse(value);
-    return new Username(validated);
+  // Unicode usernames are NFKC-normalized first, so full-width ASCII and half-width
+  // katakana collapse to their canonical forms before validation
+  static create(value: string, mode: UsernameMode = 'ascii'): Username {
+    const result =
+      mode === 'unicode'
+        ? UnicodeUsernameSchema.safeParse(value.normalize('NFKC'))
+        : UsernameSchema.safeParse(value);
+    if (!result.success) {
+      throw new UsernameValidationError(result.error);
+    }
+    return new Username(result.data);
+  }
+
+  // Stored usernames were validated when they were registered, possibly under another mode
+  static fromRepository(value: string): Username {
+    return new Username(value);
   }
 
   getValue(): string {
@@ -0,0 +1,89 @@
+import { ZodError, ZodIssue } from 'zod';
+
+// Raised by value objects so the API can report which field was rejected and why
+export class ValidationError extends Error {
+  constructor(
+    public readonly field: string,
+    public readonly issues: ZodIssue[]
+  ) {
+    super(issues[0]?.message ?? `Invalid ${field}`);
+    this.name = 'ValidationError';
+  }
+}
+
+export class EmailValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('email', error.issues);
+    this.name = 'EmailValidationError';
+  }
+}
+
+export class UsernameValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('username', error.issues);
+    this.name = 'UsernameValidationError';
+  }
+}
+
+export class PasswordValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('password', error.issues);
+    this.name = 'PasswordValidationError';
+  }
+}
+
+export class NicknameValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('nickname', error.issues);
+    this.name = 'NicknameValidationError';
+  }
+}
+
+export class SpeciesIdValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('species_id', error.issues);
+    this.name = 'SpeciesIdValidationError';
+  }
+}
+
+export class FormIdValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('form_id', error.issues);
+    this.name = 'FormIdValidationError';
+  }
+}
+
+export class DexNumberValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('dex_number', error.issues);
+    this.name = 'DexNumberValidationError';
+  }
+}
+
+export class TeamTagValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('tags', error.issues);
+    this.name = 'TeamTagValidationError';
+  }
+}
+
+export class CommentBodyValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('body', error.issues);
+    this.name = 'CommentBodyValidationError';
+  }
+}
+
+export class LevelValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('level', error.issues);
+    this.name = 'LevelValidationError';
+  }
+}
+
+export class TeamNoteValidationError extends ValidationError {
+  constructor(error: ZodError) {
+    super('notes', error.issues);
+    this.name = 'TeamNoteValidationError';
+  }
+}
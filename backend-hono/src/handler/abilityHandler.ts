@@ -0,0 +1,107 @@
+import { Context } from 'hono';
+import { Ability } from '../domain/entity/Ability';
+import { Lang, localizedName, localizedText, translateType } from '../i18n';
+import { buildETag, DEX_CACHE_CONTROL, notModified, setCacheHeaders } from '../middleware/cache';
+import { LangEnv } from '../middleware/lang';
+import { AbilityHolder, AbilityLookupService } from '../usecase/AbilityLookupService';
+import { AbilitySearchQuerySchema, DexNameParamSchema } from '../usecase/dto';
+
+export interface AbilityEnv {
+  Variables: {
+    abilityLookupService: AbilityLookupService;
+  };
+}
+
+function toAbilityResponse(lang: Lang, ability: Ability) {
+  return {
+    ability_id: ability.getId(),
+    name: ability.getName(),
+    name_jp: ability.getNameJp() ?? null,
+    display_name: localizedName(lang, ability.getName(), ability.getNameJp()),
+    effect: ability.getEffect() ?? null,
+    effect_jp: ability.getEffectJp() ?? null,
+    display_effect: localizedText(lang, ability.getEffect(), ability.getEffectJp()) ?? null,
+  };
+}
+
+function toHolderResponse(lang: Lang, { form, slot }: AbilityHolder) {
+  const typeset = form.getTypeset();
+  const types = [typeset.type1, ...(typeset.type2 ? [typeset.type2] : [])];
+  return {
+    form_id: form.getFormId(),
+    species_id: form.getSpeciesId(),
+    name: form.getName(),
+    name_jp: form.getNameJp(),
+    display_name: localizedName(lang, form.getName(), form.getNameJp()),
+    type1: typeset.type1,
+    type2: typeset.type2 ?? null,
+    type_names: types.map((type) => translateType(lang, type)),
+    slot,
+  };
+}
+
+export const search = async (c: Context<AbilityEnv & LangEnv>) => {
+  const query = AbilitySearchQuerySchema.parse(c.req.query());
+
+  const abilityLookupService = c.get('abilityLookupService');
+  const lang = c.get('lang');
+  // ETags are scoped to the URL, so the filters need not be part of the tag
+  const version = await abilityLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'abilities', lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const result = await abilityLookupService.search({ name: query.name }, query.limit, query.offset);
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json({
+    abilities: result.abilities.map((ability) => toAbilityResponse(lang, ability)),
+    total: result.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
+
+export const getAbility = async (c: Context<AbilityEnv & LangEnv>) => {
+  const name = DexNameParamSchema.parse(c.req.param('name'));
+
+  const abilityLookupService = c.get('abilityLookupService');
+  const lang = c.get('lang');
+  // ETags are scoped to the URL, so the name need not be part of the tag
+  const version = await abilityLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'ability', lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const ability = await abilityLookupService.getAbility(name);
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json(toAbilityResponse(lang, ability));
+};
+
+export const holders = async (c: Context<AbilityEnv & LangEnv>) => {
+  const name = DexNameParamSchema.parse(c.req.param('name'));
+
+  const abilityLookupService = c.get('abilityLookupService');
+  const lang = c.get('lang');
+  // ETags are scoped to the URL, so the name need not be part of the tag
+  const version = await abilityLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'ability-holders', lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const result = await abilityLookupService.getHolders(name);
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json({
+    ability: toAbilityResponse(lang, result.ability),
+    pokemon: result.holders.map((holder) => toHolderResponse(lang, holder)),
+    total: result.holders.length,
+  });
+};
@@ -0,0 +1,149 @@
+import { Context } from 'hono';
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { readJson } from '../middleware/body';
+import { AdminService } from '../usecase/AdminService';
+import { SpeciesImportPreview } from '../usecase/PokemonImportService';
+import {
+  AdminFormUpdateSchema,
+  AdminImportsQuerySchema,
+  AdminRollbackRequestSchema,
+  AdminSyncRequestSchema,
+  FormIdParamSchema,
+} from '../usecase/dto';
+
+export interface AdminEnv {
+  Variables: {
+    adminService: AdminService;
+  };
+}
+
+export const sync = async (c: Context<AdminEnv>) => {
+  const body = await readJson(c);
+  const request = AdminSyncRequestSchema.parse(body);
+
+  const adminService = c.get('adminService');
+  const to = request.to ?? request.from;
+  if (request.dry_run) {
+    const previews = await adminService.previewSync(request.kind, request.from, to);
+    return c.json({ kind: request.kind, dry_run: true, species: previews.map(toSpeciesPreview) });
+  }
+
+  const result = await adminService.sync(request.kind, request.from, to);
+
+  return c.json({
+    kind: result.kind,
+    version: result.version,
+    imported: result.imported,
+  });
+};
+
+export const imports = async (c: Context<AdminEnv>) => {
+  const query = AdminImportsQuerySchema.parse(c.req.query());
+
+  const adminService = c.get('adminService');
+  const result = await adminService.listImports(query.limit);
+
+  return c.json({
+    imports: result.map((entry) => ({
+      version: entry.version,
+      kind: entry.kind,
+      from_id: entry.fromId ?? null,
+      to_id: entry.toId ?? null,
+      imported_at: entry.importedAt.toISOString(),
+    })),
+  });
+};
+
+export const updateForm = async (c: Context<AdminEnv>) => {
+  const formId = FormIdParamSchema.parse(c.req.param('formId'));
+  const body = await readJson(c);
+  const request = AdminFormUpdateSchema.parse(body);
+  const stats = request.base_stats;
+
+  const adminService = c.get('adminService');
+  const result = await adminService.updateForm(formId, {
+    name: request.name,
+    nameJp: request.name_jp,
+    type1: request.type1,
+    type2: request.type2,
+    baseStats: stats && {
+      hp: stats.hp,
+      attack: stats.attack,
+      defense: stats.defense,
+      specialAttack: stats.special_attack,
+      specialDefense: stats.special_defense,
+      speed: stats.speed,
+    },
+    baseExperience: request.base_experience,
+    height: request.height_m === undefined ? undefined : Math.round(request.height_m * 10),
+    weight: request.weight_kg === undefined ? undefined : Math.round(request.weight_kg * 10),
+    shinyAvailable: request.shiny_available,
+    shinyLocked: request.shiny_locked,
+  });
+
+  return c.json({ version: result.version, form: toFormResponse(result.form) });
+};
+
+export const rollback = async (c: Context<AdminEnv>) => {
+  const body = await readJson(c);
+  const request = AdminRollbackRequestSchema.parse(body);
+
+  const adminService = c.get('adminService');
+  const result = await adminService.rollbackTo(request.version);
+
+  return c.json({ version: result.version, restored: result.restored });
+};
+
+export const purgeCache = async (c: Context<AdminEnv>) => {
+  const adminService = c.get('adminService');
+  const version = await adminService.purgeCaches();
+
+  return c.json({ version });
+};
+
+export const rebuildNameIndex = async (c: Context<AdminEnv>) => {
+  const adminService = c.get('adminService');
+  const result = await adminService.rebuildNameIndex();
+
+  return c.json({ version: result.version, indexed: result.indexed });
+};
+
+function toFormResponse(form: PokemonMaster) {
+  const stats = form.getBaseStats();
+  return {
+    form_id: form.getFormId(),
+    species_id: form.getSpeciesId(),
+    name: form.getName(),
+    name_jp: form.getNameJp(),
+    type1: form.getTypeset().type1,
+    type2: form.getTypeset().type2 ?? null,
+    base_stats: {
+      hp: stats.hp,
+      attack: stats.attack,
+      defense: stats.defense,
+      special_attack: stats.specialAttack,
+      special_defense: stats.specialDefense,
+      speed: stats.speed,
+    },
+    base_experience: form.getBaseExperience() ?? null,
+    height_m: form.getHeightMeters(),
+    weight_kg: form.getWeightKg(),
+    shiny_available: form.isShinyAvailable(),
+    shiny_locked: form.isShinyLocked(),
+  };
+}
+
+function toSpeciesPreview(preview: SpeciesImportPreview) {
+  return {
+    species_id: preview.speciesId,
+    name: preview.name,
+    status: preview.status,
+    changes: preview.changes,
+    forms: preview.forms.map((form) => ({
+      form_id: form.formId,
+      name: form.name,
+      status: form.status,
+      changes: form.changes,
+    })),
+  };
+}
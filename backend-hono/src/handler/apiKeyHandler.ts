@@ -0,0 +1,44 @@
+import { Context } from 'hono';
+import { ApiKey } from '../domain/entity/ApiKey';
+import { ApiKeyEnv } from '../middleware/apiKey';
+import { readJson } from '../middleware/body';
+import { ApiKeyIssueRequestSchema, UuidSchema } from '../usecase/dto';
+
+function toApiKeyResponse(apiKey: ApiKey) {
+  return {
+    key_id: apiKey.getKeyId(),
+    name: apiKey.getName(),
+    prefix: apiKey.getDisplayPrefix(),
+    scopes: apiKey.getScopes(),
+    daily_quota: apiKey.getDailyQuota(),
+    created_at: apiKey.getCreatedAt().toISOString(),
+    revoked_at: apiKey.getRevokedAt()?.toISOString() ?? null,
+  };
+}
+
+// The plain key is in this response only; it cannot be recovered later
+export const issue = async (c: Context<ApiKeyEnv>) => {
+  const body = await readJson(c);
+  const request = ApiKeyIssueRequestSchema.parse(body);
+
+  const apiKeyService = c.get('apiKeyService');
+  const result = await apiKeyService.issue(request.name, request.scopes, request.daily_quota);
+
+  return c.json({ ...toApiKeyResponse(result.apiKey), key: result.key }, 201);
+};
+
+export const list = async (c: Context<ApiKeyEnv>) => {
+  const apiKeyService = c.get('apiKeyService');
+  const apiKeys = await apiKeyService.list();
+
+  return c.json({ api_keys: apiKeys.map(toApiKeyResponse) });
+};
+
+export const revoke = async (c: Context<ApiKeyEnv>) => {
+  const keyId = UuidSchema.parse(c.req.param('keyId'));
+
+  const apiKeyService = c.get('apiKeyService');
+  const apiKey = await apiKeyService.revoke(keyId);
+
+  return c.json(toApiKeyResponse(apiKey));
+};
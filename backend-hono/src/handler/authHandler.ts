@@ -1,14 +1,56 @@
 import { Context } from 'hono';
 import { setCookie, deleteCookie, getCookie } from 'hono/cookie';
-import { AuthService } from '../usecase/AuthService';
-import { RegisterRequestSchema, LoginRequestSchema } from '../usecase/dto';
-import { AuthEnv } from '../middleware/auth';
+import { SessionClient } from '../domain/entity/RefreshToken';
+import { AuthError, AuthService, LoginThrottledError, SessionInfo } from '../usecase/AuthService';
+import {
+  CsrfTokenQuerySchema,
+  DeleteAccountRequestSchema,
+  RegisterRequestSchema,
+  LoginRequestSchema,
+  UuidSchema,
+} from '../usecase/dto';
+import { AuthEnv, SessionCookieExtractor } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { getClientIp } from '../middleware/clientIp';
+import { CSRF_HEADER, issueCsrfToken } from '../middleware/csrf';
+import { UserEnv } from './userHandler';
 
 const REFRESH_TOKEN_COOKIE_NAME = 'refresh_token';
 const COOKIE_MAX_AGE = 30 * 24 * 60 * 60; // 30 days in seconds
 
+const sessionClient = (c: Context<AuthEnv>): SessionClient => ({
+  userAgent: c.req.header('User-Agent'),
+  ip: getClientIp(c),
+});
+
+// In session mode the session cookie identifies the current session; otherwise the refresh token
+const currentSessionToken = async (c: Context<AuthEnv>): Promise<string | undefined> => {
+  const extractor = c.get('credentialExtractor');
+  return extractor instanceof SessionCookieExtractor
+    ? extractor.read(c)
+    : getCookie(c, REFRESH_TOKEN_COOKIE_NAME);
+};
+
+const clearCredentials = (c: Context<AuthEnv>) => {
+  const extractor = c.get('credentialExtractor');
+  if (extractor instanceof SessionCookieExtractor) {
+    extractor.clear(c);
+  } else {
+    deleteCookie(c, REFRESH_TOKEN_COOKIE_NAME);
+  }
+};
+
+const toSessionResponse = (session: SessionInfo) => ({
+  session_id: session.sessionId,
+  user_agent: session.userAgent ?? null,
+  ip: session.ip ?? null,
+  last_used_at: session.lastUsedAt.toISOString(),
+  expires_at: session.expiresAt.toISOString(),
+  current: session.current,
+});
+
 export const register = async (c: Context<AuthEnv>) => {
-  const body = await c.req.json();
+  const body = await readJson(c);
   const validated = RegisterRequestSchema.parse(body);
 
   const authService = c.get('authService');
@@ -26,11 +68,33 @@ export const register = async (c: Context<AuthEnv>) => {
 };
 
 export const login = async (c: Context<AuthEnv>) => {
-  const body = await c.req.json();
+  const body = await readJson(c);
   const validated = LoginRequestSchema.parse(body);
 
   const authService = c.get('authService');
-  const result = await authService.login(validated);
+  const { userAgent, ip } = sessionClient(c);
+  const request = { ...validated, clientIp: ip, userAgent };
+  const setRetryAfter = (error: unknown): never => {
+    if (error instanceof LoginThrottledError) {
+      c.header('Retry-After', String(error.retryAfter));
+    }
+    throw error;
+  };
+
+  // Session mode: the signed session cookie is the only credential
+  const extractor = c.get('credentialExtractor');
+  if (extractor instanceof SessionCookieExtractor) {
+    const session = await authService.startSession(request).catch(setRetryAfter);
+    await extractor.write(c, session.sessionToken);
+
+    return c.json({
+      user_id: session.userId,
+      username: session.username,
+      email: session.email,
+    });
+  }
+
+  const result = await authService.login(request).catch(setRetryAfter);
 
   // Set refresh token as HTTPOnly cookie
   setCookie(c, REFRESH_TOKEN_COOKIE_NAME, result.refreshToken, {
@@ -56,11 +120,11 @@ export const refresh = async (c: Context<AuthEnv>) => {
   const refreshToken = getCookie(c, REFRESH_TOKEN_COOKIE_NAME);
 
   if (!refreshToken) {
-    return c.json({ error: 'Refresh token not found' }, 401);
+    throw AuthError.missingRefreshToken();
   }
 
   const authService = c.get('authService');
-  const result = await authService.refresh(refreshToken);
+  const result = await authService.refresh(refreshToken, sessionClient(c));
 
   // Update refresh token cookie
   setCookie(c, REFRESH_TOKEN_COOKIE_NAME, result.refreshToken, {
@@ -79,15 +143,67 @@ export const refresh = async (c: Context<AuthEnv>) => {
 };
 
 export const logout = async (c: Context<AuthEnv>) => {
-  const refreshToken = getCookie(c, REFRESH_TOKEN_COOKIE_NAME);
+  const sessionToken = await currentSessionToken(c);
 
-  if (refreshToken) {
+  if (sessionToken) {
     const authService = c.get('authService');
-    await authService.logout(refreshToken);
+    await authService.logout(sessionToken);
   }
 
-  // Delete cookie
-  deleteCookie(c, REFRESH_TOKEN_COOKIE_NAME);
-
+  clearCredentials(c);
   return c.json({ message: 'Logged out successfully' });
 };
+
+// Sessions are revoked with the account; access tokens already issued expire within 15 minutes
+export const deleteAccount = async (c: Context<AuthEnv & UserEnv>) => {
+  const body = await readJson(c);
+  const validated = DeleteAccountRequestSchema.parse(body);
+
+  const personalDataService = c.get('personalDataService');
+  await personalDataService.deleteAccount(c.get('userId'), validated.password, validated.content);
+
+  clearCredentials(c);
+  return c.json({ message: 'Account deleted successfully' });
+};
+
+export const listSessions = async (c: Context<AuthEnv>) => {
+  const authService = c.get('authService');
+  const sessions = await authService.listSessions(
+    c.get('userId'),
+    await currentSessionToken(c)
+  );
+
+  return c.json({ sessions: sessions.map(toSessionResponse) });
+};
+
+export const revokeSession = async (c: Context<AuthEnv>) => {
+  const sessionId = UuidSchema.parse(c.req.param('sessionId'));
+
+  const authService = c.get('authService');
+  await authService.revokeSession(c.get('userId'), sessionId);
+
+  return c.json({ message: 'Session revoked successfully' });
+};
+
+export const revokeOtherSessions = async (c: Context<AuthEnv>) => {
+  const authService = c.get('authService');
+  const revoked = await authService.revokeOtherSessions(
+    c.get('userId'),
+    await currentSessionToken(c)
+  );
+
+  return c.json({ revoked });
+};
+
+// The frontend sends the token back in the X-CSRF-Token header on the form's unsafe requests
+export const csrfToken = async (c: Context<AuthEnv>) => {
+  const { form } = CsrfTokenQuerySchema.parse(c.req.query());
+  const { token, expiresAt } = issueCsrfToken(c, form);
+
+  c.header('Cache-Control', 'no-store');
+  return c.json({
+    csrf_token: token,
+    header: CSRF_HEADER,
+    expires_at: expiresAt.toISOString(),
+  });
+};
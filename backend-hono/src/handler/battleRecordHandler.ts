@@ -0,0 +1,136 @@
+import { Context } from 'hono';
+import { BattleRecord } from '../domain/entity/BattleRecord';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { BattleRecordService, BattleStats } from '../usecase/BattleRecordService';
+import {
+  BattleRecordListQuerySchema,
+  BattleRecordRequestSchema,
+  MatchupStatsQuerySchema,
+  UuidSchema,
+} from '../usecase/dto';
+
+export interface BattleRecordEnv {
+  Variables: {
+    battleRecordService: BattleRecordService;
+  };
+}
+
+function toBattleRecordResponse(record: BattleRecord) {
+  const opponent = record.getOpponent();
+  return {
+    record_id: record.getRecordId(),
+    team_id: record.getTeamId(),
+    format: record.getFormat(),
+    result: record.getResult(),
+    opponent: {
+      name: opponent.name,
+      archetype: opponent.archetype,
+      form_ids: opponent.formIds,
+    },
+    notes: record.getNotes(),
+    played_at: record.getPlayedAt().toISOString(),
+    created_at: record.getCreatedAt().toISOString(),
+  };
+}
+
+function toStatsResponse(stats: BattleStats) {
+  return {
+    battles: stats.battles,
+    wins: stats.wins,
+    losses: stats.losses,
+    win_rate: stats.winRate,
+  };
+}
+
+export const logBattle = async (c: Context<BattleRecordEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = BattleRecordRequestSchema.parse(body);
+
+  const battleRecordService = c.get('battleRecordService');
+  const record = await battleRecordService.log(c.get('userId'), {
+    teamId: validated.team_id,
+    format: validated.format ?? null,
+    result: validated.result,
+    opponent: {
+      name: validated.opponent.name ?? null,
+      archetype: validated.opponent.archetype ?? null,
+      formIds: validated.opponent.form_ids,
+    },
+    notes: validated.notes ?? null,
+    playedAt: validated.played_at ?? new Date(),
+  });
+
+  return c.json(toBattleRecordResponse(record), 201);
+};
+
+export const listBattles = async (c: Context<BattleRecordEnv & AuthEnv>) => {
+  const query = BattleRecordListQuerySchema.parse(c.req.query());
+
+  const battleRecordService = c.get('battleRecordService');
+  const page = await battleRecordService.list(
+    c.get('userId'),
+    {
+      teamId: query.team_id,
+      result: query.result,
+      archetype: query.archetype,
+      formId: query.form_id,
+    },
+    query.limit,
+    query.offset
+  );
+
+  return c.json({
+    records: page.records.map(toBattleRecordResponse),
+    total: page.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
+
+export const getBattle = async (c: Context<BattleRecordEnv & AuthEnv>) => {
+  const recordId = UuidSchema.parse(c.req.param('recordId'));
+
+  const battleRecordService = c.get('battleRecordService');
+  const record = await battleRecordService.get(c.get('userId'), recordId);
+
+  return c.json(toBattleRecordResponse(record));
+};
+
+export const deleteBattle = async (c: Context<BattleRecordEnv & AuthEnv>) => {
+  const recordId = UuidSchema.parse(c.req.param('recordId'));
+
+  const battleRecordService = c.get('battleRecordService');
+  await battleRecordService.delete(c.get('userId'), recordId);
+
+  return c.json({ message: 'Battle record deleted successfully' });
+};
+
+export const stats = async (c: Context<BattleRecordEnv & AuthEnv>) => {
+  const battleRecordService = c.get('battleRecordService');
+  const summary = await battleRecordService.summarize(c.get('userId'));
+
+  return c.json({
+    overall: toStatsResponse(summary.overall),
+    teams: summary.teams.map((team) => ({ team_id: team.teamId, ...toStatsResponse(team) })),
+  });
+};
+
+export const matchups = async (c: Context<BattleRecordEnv & AuthEnv>) => {
+  const query = MatchupStatsQuerySchema.parse(c.req.query());
+
+  const battleRecordService = c.get('battleRecordService');
+  const result = await battleRecordService.matchups(c.get('userId'), query.team_id ?? null);
+
+  return c.json({
+    team_id: result.teamId,
+    archetypes: result.archetypes.map((entry) => ({
+      archetype: entry.archetype,
+      ...toStatsResponse(entry),
+    })),
+    opponents: result.opponents.map((entry) => ({
+      form_id: entry.formId,
+      ...toStatsResponse(entry),
+    })),
+  });
+};
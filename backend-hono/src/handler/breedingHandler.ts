@@ -0,0 +1,48 @@
+import { Context } from 'hono';
+import { BreedingService } from '../usecase/BreedingService';
+import {
+  BreedingCompatibilityQuerySchema,
+  EggMoveParentsQuerySchema,
+  SpeciesIdParamSchema,
+} from '../usecase/dto';
+
+export interface BreedingEnv {
+  Variables: {
+    breedingService: BreedingService;
+  };
+}
+
+export const compatibility = async (c: Context<BreedingEnv>) => {
+  const query = BreedingCompatibilityQuerySchema.parse(c.req.query());
+
+  const breedingService = c.get('breedingService');
+  const result = await breedingService.checkCompatibility(query.species_a, query.species_b);
+
+  return c.json({
+    species_id_a: result.speciesIdA,
+    species_id_b: result.speciesIdB,
+    compatible: result.compatible,
+    shared_egg_groups: result.sharedEggGroups,
+    reason: result.reason,
+  });
+};
+
+export const eggMoveParents = async (c: Context<BreedingEnv>) => {
+  const speciesId = SpeciesIdParamSchema.parse(c.req.param('speciesId'));
+  const query = EggMoveParentsQuerySchema.parse(c.req.query());
+
+  const breedingService = c.get('breedingService');
+  const result = await breedingService.findEggMoveParents(speciesId, query.move);
+
+  return c.json({
+    species_id: result.speciesId,
+    move: result.moveName,
+    parents: result.parents.map((parent) => ({
+      species_id: parent.speciesId,
+      name: parent.name,
+      name_jp: parent.nameJp,
+      methods: parent.methods,
+      requires_chain: parent.requiresChain,
+    })),
+  });
+};
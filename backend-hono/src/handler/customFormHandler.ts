@@ -0,0 +1,109 @@
+import { Context } from 'hono';
+import { CustomForm, CustomFormProps } from '../domain/entity/CustomForm';
+import { AbilitySlots } from '../domain/valueobject/AbilitySlots';
+import { TypeSet } from '../domain/valueobject/PokemonType';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { CustomFormService } from '../usecase/CustomFormService';
+import { CustomFormRequestDto, CustomFormRequestSchema, FormIdParamSchema } from '../usecase/dto';
+
+export interface CustomFormEnv {
+  Variables: {
+    customFormService: CustomFormService;
+  };
+}
+
+function toCustomFormProps(dto: CustomFormRequestDto): CustomFormProps {
+  return {
+    name: dto.name,
+    typeset: new TypeSet(dto.types[0], dto.types[1]),
+    baseStats: {
+      hp: dto.base_stats.hp,
+      attack: dto.base_stats.attack,
+      defense: dto.base_stats.defense,
+      specialAttack: dto.base_stats.special_attack,
+      specialDefense: dto.base_stats.special_defense,
+      speed: dto.base_stats.speed,
+    },
+    abilities: new AbilitySlots(
+      dto.abilities.primary,
+      dto.abilities.secondary,
+      dto.abilities.hidden
+    ),
+  };
+}
+
+function toCustomFormResponse(form: CustomForm) {
+  const stats = form.getBaseStats();
+  const abilities = form.getAbilities();
+  return {
+    form_id: form.getFormId(),
+    name: form.getName(),
+    types: form.getTypeset().getTypes(),
+    base_stats: {
+      hp: stats.hp,
+      attack: stats.attack,
+      defense: stats.defense,
+      special_attack: stats.specialAttack,
+      special_defense: stats.specialDefense,
+      speed: stats.speed,
+    },
+    abilities: {
+      primary: abilities.primary,
+      secondary: abilities.secondary ?? null,
+      hidden: abilities.hidden ?? null,
+    },
+    created_at: form.getCreatedAt().toISOString(),
+    updated_at: form.getUpdatedAt().toISOString(),
+  };
+}
+
+export const createCustomForm = async (c: Context<CustomFormEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = CustomFormRequestSchema.parse(body);
+
+  const customFormService = c.get('customFormService');
+  const form = await customFormService.create(c.get('userId'), toCustomFormProps(validated));
+
+  return c.json(toCustomFormResponse(form), 201);
+};
+
+export const listCustomForms = async (c: Context<CustomFormEnv & AuthEnv>) => {
+  const customFormService = c.get('customFormService');
+  const forms = await customFormService.list(c.get('userId'));
+
+  return c.json({ forms: forms.map(toCustomFormResponse) });
+};
+
+export const getCustomForm = async (c: Context<CustomFormEnv & AuthEnv>) => {
+  const formId = FormIdParamSchema.parse(c.req.param('formId'));
+
+  const customFormService = c.get('customFormService');
+  const form = await customFormService.get(c.get('userId'), formId);
+
+  return c.json(toCustomFormResponse(form));
+};
+
+export const updateCustomForm = async (c: Context<CustomFormEnv & AuthEnv>) => {
+  const formId = FormIdParamSchema.parse(c.req.param('formId'));
+  const body = await readJson(c);
+  const validated = CustomFormRequestSchema.parse(body);
+
+  const customFormService = c.get('customFormService');
+  const form = await customFormService.update(
+    c.get('userId'),
+    formId,
+    toCustomFormProps(validated)
+  );
+
+  return c.json(toCustomFormResponse(form));
+};
+
+export const deleteCustomForm = async (c: Context<CustomFormEnv & AuthEnv>) => {
+  const formId = FormIdParamSchema.parse(c.req.param('formId'));
+
+  const customFormService = c.get('customFormService');
+  await customFormService.delete(c.get('userId'), formId);
+
+  return c.json({ message: 'Custom form deleted successfully' });
+};
@@ -0,0 +1,275 @@
+import { Context } from 'hono';
+import { DamageBenchmark } from '../domain/entity/DamageBenchmark';
+import { Move } from '../domain/entity/Move';
+import { ActionTiming } from '../domain/service/TurnOrder';
+import { Level } from '../domain/valueobject/Level';
+import { StatStages } from '../domain/valueobject/StatStages';
+import { Lang, localizedName, translateType } from '../i18n';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { LangEnv } from '../middleware/lang';
+import { PreferencesEnv, withPreferredDefaults } from '../middleware/preferences';
+import { BattleSimulationService } from '../usecase/BattleSimulationService';
+import { DamageBenchmarkService } from '../usecase/DamageBenchmarkService';
+import {
+  DamageCalculationResponse,
+  DamageCalculationService,
+} from '../usecase/DamageCalculationService';
+import { TurnOrderService, TurnOrderSide } from '../usecase/TurnOrderService';
+import {
+  BatchDamageRequestSchema,
+  BattleSimulationRequestSchema,
+  BenchmarkListQuerySchema,
+  BenchmarkRunRequestSchema,
+  CreateBenchmarkRequestSchema,
+  StatStagesDto,
+  TeamMatchupRequestSchema,
+  TurnOrderRequestSchema,
+  TurnOrderSideDto,
+  UuidSchema,
+} from '../usecase/dto';
+import { toBuildResponse, toPokemonBuild } from './teamHandler';
+
+export interface DamageEnv {
+  Variables: {
+    battleSimulationService: BattleSimulationService;
+    damageBenchmarkService: DamageBenchmarkService;
+    damageCalculationService: DamageCalculationService;
+    turnOrderService: TurnOrderService;
+  };
+}
+
+function toStatStages(dto?: StatStagesDto): StatStages {
+  if (!dto) {
+    return StatStages.neutral();
+  }
+  return new StatStages({
+    attack: dto.attack,
+    defense: dto.defense,
+    specialAttack: dto.special_attack,
+    specialDefense: dto.special_defense,
+    speed: dto.speed,
+  });
+}
+
+function toDamageResponse(result: DamageCalculationResponse, index: number, lang: Lang) {
+  return {
+    index,
+    move: result.move.getName(),
+    move_display_name: localizedName(lang, result.move.getName(), result.move.getNameJp()),
+    move_type: result.moveType,
+    move_type_name: translateType(lang, result.moveType),
+    rolls: result.rolls,
+    hits: result.hits,
+    min: result.min,
+    max: result.max,
+    min_percent: result.minPercent,
+    max_percent: result.maxPercent,
+    effectiveness: result.effectiveness,
+    abilities: result.abilities,
+    ko_chance: result.koChance,
+  };
+}
+
+function toBenchmarkResponse(benchmark: DamageBenchmark) {
+  return {
+    benchmark_id: benchmark.getBenchmarkId(),
+    format: benchmark.getFormat(),
+    name: benchmark.getName(),
+    role: benchmark.getRole(),
+    build: toBuildResponse(benchmark.getBuild()),
+    move: benchmark.getMove() ?? null,
+  };
+}
+
+export const batch = async (c: Context<DamageEnv & LangEnv & PreferencesEnv>) => {
+  const body = await readJson(c);
+  const validated = BatchDamageRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const damageCalculationService = c.get('damageCalculationService');
+  const results = await damageCalculationService.calculateBatch(
+    validated.calculations.map((calculation) => ({
+      attacker: toPokemonBuild(calculation.attacker),
+      defender: toPokemonBuild(calculation.defender),
+      moveName: calculation.move,
+      critical: calculation.critical,
+      attackerTerastallized: calculation.attacker_terastallized,
+      defenderTerastallized: calculation.defender_terastallized,
+      attackerStages: toStatStages(calculation.attacker_stages),
+      defenderStages: toStatStages(calculation.defender_stages),
+    })),
+    Level.resolve(validated.level, validated.mode),
+    validated.doubles,
+    validated.generation
+  );
+
+  const lang = c.get('lang');
+  return c.json({ results: results.map((result, index) => toDamageResponse(result, index, lang)) });
+};
+
+function toTurnOrderSide(dto: TurnOrderSideDto): TurnOrderSide {
+  return {
+    build: toPokemonBuild(dto.pokemon),
+    moveName: dto.move,
+    stages: toStatStages(dto.stages),
+    tailwind: dto.tailwind,
+    paralyzed: dto.paralyzed,
+  };
+}
+
+function toTimingResponse(lang: Lang, move: Move, timing: ActionTiming) {
+  return {
+    move: move.getName(),
+    move_display_name: localizedName(lang, move.getName(), move.getNameJp()),
+    priority: timing.priority,
+    speed: timing.speed,
+    quick_chance: timing.quickChance,
+    moves_last: timing.movesLast,
+    modifiers: timing.modifiers,
+  };
+}
+
+export const turnOrder = async (c: Context<DamageEnv & LangEnv & PreferencesEnv>) => {
+  const body = await readJson(c);
+  const validated = TurnOrderRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const turnOrderService = c.get('turnOrderService');
+  const result = await turnOrderService.determine(
+    toTurnOrderSide(validated.sides[0]),
+    toTurnOrderSide(validated.sides[1]),
+    Level.resolve(validated.level, validated.mode),
+    { trickRoom: validated.trick_room, weather: validated.weather }
+  );
+
+  const lang = c.get('lang');
+  // Both orders with their chances, the likelier first; an order that cannot happen is left out
+  const orders = [
+    { order: [0, 1], probability: result.firstMovesFirst },
+    { order: [1, 0], probability: 1 - result.firstMovesFirst },
+  ]
+    .filter(({ probability }) => probability > 0)
+    .sort((a, b) => b.probability - a.probability);
+  return c.json({
+    orders,
+    sides: result.timings.map((timing, index) =>
+      toTimingResponse(lang, result.moves[index], timing)
+    ),
+  });
+};
+
+export const simulate = async (c: Context<DamageEnv & PreferencesEnv>) => {
+  const body = await readJson(c);
+  const validated = BattleSimulationRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const battleSimulationService = c.get('battleSimulationService');
+  const result = await battleSimulationService.simulate(
+    toPokemonBuild(validated.sides[0]),
+    toPokemonBuild(validated.sides[1]),
+    {
+      level: Level.resolve(validated.level, validated.mode),
+      field: { trickRoom: validated.trick_room, weather: validated.weather },
+      simulations: validated.simulations,
+      seed: validated.seed,
+    }
+  );
+
+  const chance = (count: number) => count / result.simulations;
+  return c.json({
+    seed: result.seed,
+    simulations: result.simulations,
+    wins: result.wins,
+    draws: result.draws,
+    win_chance: result.wins.map(chance),
+    draw_chance: chance(result.draws),
+    average_turns: result.averageTurns,
+  });
+};
+
+export const simulateTeams = async (c: Context<DamageEnv & PreferencesEnv>) => {
+  const body = await readJson(c);
+  const validated = TeamMatchupRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const battleSimulationService = c.get('battleSimulationService');
+  const result = await battleSimulationService.estimateTeamMatchup(
+    validated.teams[0].map(toPokemonBuild),
+    validated.teams[1].map(toPokemonBuild),
+    {
+      level: Level.resolve(validated.level, validated.mode),
+      field: { trickRoom: validated.trick_room, weather: validated.weather },
+      simulations: validated.games,
+      seed: validated.seed,
+    }
+  );
+
+  const chance = (count: number) => count / result.games;
+  return c.json({
+    seed: result.seed,
+    games: result.games,
+    wins: result.wins,
+    draws: result.draws,
+    win_chance: result.wins.map(chance),
+    win_interval: result.winIntervals,
+    draw_chance: chance(result.draws),
+    average_remaining: result.averageRemaining,
+  });
+};
+
+export const createBenchmark = async (c: Context<DamageEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = CreateBenchmarkRequestSchema.parse(body);
+
+  const damageBenchmarkService = c.get('damageBenchmarkService');
+  const benchmark = await damageBenchmarkService.create(c.get('userId'), validated.format, {
+    name: validated.name,
+    role: validated.role,
+    build: toPokemonBuild({ ...validated.build, moves: [] }),
+    move: validated.move,
+  });
+
+  return c.json(toBenchmarkResponse(benchmark), 201);
+};
+
+export const listBenchmarks = async (c: Context<DamageEnv & AuthEnv>) => {
+  const query = BenchmarkListQuerySchema.parse(c.req.query());
+
+  const damageBenchmarkService = c.get('damageBenchmarkService');
+  const benchmarks = await damageBenchmarkService.list(c.get('userId'), query.format);
+
+  return c.json({ benchmarks: benchmarks.map(toBenchmarkResponse) });
+};
+
+export const deleteBenchmark = async (c: Context<DamageEnv & AuthEnv>) => {
+  const benchmarkId = UuidSchema.parse(c.req.param('benchmarkId'));
+
+  const damageBenchmarkService = c.get('damageBenchmarkService');
+  await damageBenchmarkService.delete(c.get('userId'), benchmarkId);
+
+  return c.json({ message: 'Benchmark deleted successfully' });
+};
+
+export const runBenchmarks = async (c: Context<DamageEnv & AuthEnv & LangEnv>) => {
+  const body = await readJson(c);
+  const validated = BenchmarkRunRequestSchema.parse(body);
+
+  const damageBenchmarkService = c.get('damageBenchmarkService');
+  const results = await damageBenchmarkService.run(
+    c.get('userId'),
+    validated.format,
+    toPokemonBuild(validated.build),
+    {
+      moves: validated.moves,
+      terastallized: validated.terastallized,
+      critical: validated.critical,
+    }
+  );
+
+  const lang = c.get('lang');
+  return c.json({
+    results: results.map(({ benchmark, result }, index) => ({
+      benchmark_id: benchmark.getBenchmarkId(),
+      benchmark_name: benchmark.getName(),
+      role: benchmark.getRole(),
+      ...toDamageResponse(result, index, lang),
+    })),
+  });
+};
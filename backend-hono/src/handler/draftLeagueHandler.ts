@@ -0,0 +1,154 @@
+import { Context } from 'hono';
+import { DraftLeague } from '../domain/entity/DraftLeague';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { DraftLeagueService } from '../usecase/DraftLeagueService';
+import {
+  CreateDraftLeagueRequestSchema,
+  DraftPickRequestSchema,
+  DraftTeamValidationRequestSchema,
+  UuidSchema,
+} from '../usecase/dto';
+import { toViolationResponse } from './teamHandler';
+
+export interface DraftLeagueEnv {
+  Variables: {
+    draftLeagueService: DraftLeagueService;
+  };
+}
+
+function toUserResponse(userId: string, usernames: Map<string, string>) {
+  return { user_id: userId, username: usernames.get(userId) ?? null };
+}
+
+function toDraftLeagueSummary(league: DraftLeague, usernames: Map<string, string>) {
+  const currentPicker = league.getCurrentPicker();
+  return {
+    league_id: league.getLeagueId(),
+    name: league.getName(),
+    format: league.getFormat(),
+    commissioner: toUserResponse(league.getOwnerId(), usernames),
+    participant_count: league.getParticipantIds().length,
+    picks_made: league.getPicks().length,
+    current_picker: currentPicker ? toUserResponse(currentPicker, usernames) : null,
+    complete: league.isComplete(),
+    created_at: league.getCreatedAt().toISOString(),
+  };
+}
+
+function toDraftLeagueResponse(league: DraftLeague, usernames: Map<string, string>) {
+  return {
+    ...toDraftLeagueSummary(league, usernames),
+    budget: league.getBudget(),
+    roster_size: league.getRosterSize(),
+    participants: league.getParticipantIds().map((userId) => ({
+      ...toUserResponse(userId, usernames),
+      remaining_budget: league.getRemainingBudget(userId),
+      roster: league.picksOf(userId).map((pick) => pick.formId),
+    })),
+    pool: league.getPool().map((entry) => ({
+      form_id: entry.formId,
+      cost: entry.cost,
+      picked: league.isPicked(entry.formId),
+    })),
+    picks: league.getPicks().map((pick) => ({
+      pick_number: pick.pickNumber,
+      user_id: pick.userId,
+      form_id: pick.formId,
+      picked_at: pick.pickedAt.toISOString(),
+    })),
+  };
+}
+
+async function respondWithLeague(
+  c: Context<DraftLeagueEnv & AuthEnv>,
+  league: DraftLeague,
+  status: 200 | 201 = 200
+) {
+  const usernames = await c.get('draftLeagueService').findUsernames([league]);
+  return c.json(toDraftLeagueResponse(league, usernames), status);
+}
+
+export const createDraftLeague = async (c: Context<DraftLeagueEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = CreateDraftLeagueRequestSchema.parse(body);
+
+  const draftLeagueService = c.get('draftLeagueService');
+  const league = await draftLeagueService.create(c.get('userId'), {
+    name: validated.name,
+    format: validated.format,
+    participants: validated.participants,
+    pool: validated.pool.map((entry) => ({ formId: entry.form_id, cost: entry.cost })),
+    budget: validated.budget,
+    rosterSize: validated.roster_size,
+  });
+
+  return respondWithLeague(c, league, 201);
+};
+
+export const listDraftLeagues = async (c: Context<DraftLeagueEnv & AuthEnv>) => {
+  const draftLeagueService = c.get('draftLeagueService');
+  const leagues = await draftLeagueService.list(c.get('userId'));
+  const usernames = await draftLeagueService.findUsernames(leagues);
+
+  return c.json({
+    leagues: leagues.map((league) => toDraftLeagueSummary(league, usernames)),
+    total: leagues.length,
+  });
+};
+
+export const getDraftLeague = async (c: Context<DraftLeagueEnv & AuthEnv>) => {
+  const leagueId = UuidSchema.parse(c.req.param('leagueId'));
+
+  const draftLeagueService = c.get('draftLeagueService');
+  const league = await draftLeagueService.get(c.get('userId'), leagueId);
+
+  return respondWithLeague(c, league);
+};
+
+export const deleteDraftLeague = async (c: Context<DraftLeagueEnv & AuthEnv>) => {
+  const leagueId = UuidSchema.parse(c.req.param('leagueId'));
+
+  const draftLeagueService = c.get('draftLeagueService');
+  await draftLeagueService.delete(c.get('userId'), leagueId);
+
+  return c.json({ message: 'Draft league deleted successfully' });
+};
+
+export const makePick = async (c: Context<DraftLeagueEnv & AuthEnv>) => {
+  const leagueId = UuidSchema.parse(c.req.param('leagueId'));
+  const body = await readJson(c);
+  const validated = DraftPickRequestSchema.parse(body);
+
+  const draftLeagueService = c.get('draftLeagueService');
+  const league = await draftLeagueService.pick(c.get('userId'), leagueId, validated.form_id);
+
+  return respondWithLeague(c, league, 201);
+};
+
+export const undoPick = async (c: Context<DraftLeagueEnv & AuthEnv>) => {
+  const leagueId = UuidSchema.parse(c.req.param('leagueId'));
+
+  const draftLeagueService = c.get('draftLeagueService');
+  const league = await draftLeagueService.undoPick(c.get('userId'), leagueId);
+
+  return respondWithLeague(c, league);
+};
+
+export const validateTeam = async (c: Context<DraftLeagueEnv & AuthEnv>) => {
+  const leagueId = UuidSchema.parse(c.req.param('leagueId'));
+  const body = await readJson(c);
+  const validated = DraftTeamValidationRequestSchema.parse(body);
+
+  const draftLeagueService = c.get('draftLeagueService');
+  const result = await draftLeagueService.validateTeam(
+    c.get('userId'),
+    leagueId,
+    validated.team_id
+  );
+
+  return c.json({
+    legal: result.legal,
+    violations: result.violations.map(toViolationResponse),
+  });
+};
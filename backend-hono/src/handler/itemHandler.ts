@@ -0,0 +1,73 @@
+import { Context } from 'hono';
+import { Item } from '../domain/entity/Item';
+import { Lang, localizedName, localizedText } from '../i18n';
+import { buildETag, DEX_CACHE_CONTROL, notModified, setCacheHeaders } from '../middleware/cache';
+import { LangEnv } from '../middleware/lang';
+import { ItemLookupService } from '../usecase/ItemLookupService';
+import { DexNameParamSchema, ItemSearchQuerySchema } from '../usecase/dto';
+
+export interface ItemEnv {
+  Variables: {
+    itemLookupService: ItemLookupService;
+  };
+}
+
+function toItemResponse(lang: Lang, item: Item) {
+  return {
+    item_id: item.getId(),
+    name: item.getName(),
+    name_jp: item.getNameJp() ?? null,
+    display_name: localizedName(lang, item.getName(), item.getNameJp()),
+    category: item.getCategory(),
+    effect: item.getEffect() ?? null,
+    effect_jp: item.getEffectJp() ?? null,
+    display_effect: localizedText(lang, item.getEffect(), item.getEffectJp()) ?? null,
+  };
+}
+
+export const search = async (c: Context<ItemEnv & LangEnv>) => {
+  const query = ItemSearchQuerySchema.parse(c.req.query());
+
+  const itemLookupService = c.get('itemLookupService');
+  const lang = c.get('lang');
+  // ETags are scoped to the URL, so the filters need not be part of the tag
+  const version = await itemLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'items', lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const result = await itemLookupService.search(
+    { name: query.name, category: query.category },
+    query.limit,
+    query.offset
+  );
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json({
+    items: result.items.map((item) => toItemResponse(lang, item)),
+    total: result.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
+
+export const getItem = async (c: Context<ItemEnv & LangEnv>) => {
+  const name = DexNameParamSchema.parse(c.req.param('name'));
+
+  const itemLookupService = c.get('itemLookupService');
+  const lang = c.get('lang');
+  // ETags are scoped to the URL, so the name need not be part of the tag
+  const version = await itemLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'item', lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const item = await itemLookupService.getItem(name);
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json(toItemResponse(lang, item));
+};
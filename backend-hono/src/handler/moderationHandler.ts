@@ -0,0 +1,55 @@
+import { Context } from 'hono';
+import { ModerationItem } from '../domain/entity/ModerationItem';
+import { ContentModerationService } from '../usecase/ContentModerationService';
+import { ModerationQueueQuerySchema, UuidSchema } from '../usecase/dto';
+
+export interface ModerationEnv {
+  Variables: {
+    contentModerationService: ContentModerationService;
+  };
+}
+
+function toModerationItemResponse(item: ModerationItem) {
+  return {
+    item_id: item.getItemId(),
+    kind: item.getKind(),
+    target_id: item.getTargetId(),
+    author_id: item.getAuthorId(),
+    text: item.getText(),
+    reasons: item.getReasons(),
+    created_at: item.getCreatedAt().toISOString(),
+  };
+}
+
+// Admin: text the content policy published but wants checked, oldest first
+export const listQueue = async (c: Context<ModerationEnv>) => {
+  const query = ModerationQueueQuerySchema.parse(c.req.query());
+
+  const contentModerationService = c.get('contentModerationService');
+  const page = await contentModerationService.listQueue(query.limit, query.offset);
+
+  return c.json({
+    items: page.items.map(toModerationItemResponse),
+    total: page.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
+
+export const approve = async (c: Context<ModerationEnv>) => {
+  const itemId = UuidSchema.parse(c.req.param('itemId'));
+
+  const contentModerationService = c.get('contentModerationService');
+  await contentModerationService.approve(itemId);
+
+  return c.json({ message: 'Content approved successfully' });
+};
+
+export const takeDown = async (c: Context<ModerationEnv>) => {
+  const itemId = UuidSchema.parse(c.req.param('itemId'));
+
+  const contentModerationService = c.get('contentModerationService');
+  await contentModerationService.takeDown(itemId);
+
+  return c.json({ message: 'Content taken down successfully' });
+};
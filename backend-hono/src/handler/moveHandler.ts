@@ -0,0 +1,101 @@
+import { Context } from 'hono';
+import { Move } from '../domain/entity/Move';
+import { MoveEffects, MoveStat } from '../domain/valueobject/MoveEffects';
+import { Lang, localizedName, translateType } from '../i18n';
+import { buildETag, DEX_CACHE_CONTROL, notModified, setCacheHeaders } from '../middleware/cache';
+import { LangEnv } from '../middleware/lang';
+import { MoveSearchService } from '../usecase/MoveSearchService';
+import { MoveSearchQuerySchema } from '../usecase/dto';
+
+export interface MoveEnv {
+  Variables: {
+    moveSearchService: MoveSearchService;
+  };
+}
+
+function toMoveResponse(lang: Lang, move: Move) {
+  return {
+    move_id: move.getId(),
+    name: move.getName(),
+    name_jp: move.getNameJp() ?? null,
+    display_name: localizedName(lang, move.getName(), move.getNameJp()),
+    type: move.getType(),
+    type_name: translateType(lang, move.getType()),
+    damage_class: move.getDamageClass(),
+    power: move.getPower() ?? null,
+    accuracy: move.getAccuracy() ?? null,
+    priority: move.getPriority(),
+    pp: move.getPp() ?? null,
+    effect: move.getEffect() ?? null,
+    effects: toEffectsResponse(move.getEffects()),
+    hazard: move.getHazard() ?? null,
+  };
+}
+
+const STAT_KEYS: Record<MoveStat, string> = {
+  attack: 'attack',
+  defense: 'defense',
+  specialAttack: 'special_attack',
+  specialDefense: 'special_defense',
+  speed: 'speed',
+  accuracy: 'accuracy',
+  evasion: 'evasion',
+};
+
+// Null for moves imported before effects were recorded
+function toEffectsResponse(effects: MoveEffects | undefined) {
+  if (!effects) {
+    return null;
+  }
+  return {
+    flinch_chance: effects.flinchChance,
+    stat_chance: effects.statChance,
+    stat_changes: effects.statChanges.map((change) => ({
+      stat: STAT_KEYS[change.stat],
+      stages: change.stages,
+      target: change.target,
+    })),
+    ailment: effects.ailment,
+    ailment_chance: effects.ailmentChance,
+    drain: effects.drain,
+    recoil: effects.recoil,
+    healing: effects.healing,
+  };
+}
+
+export const search = async (c: Context<MoveEnv & LangEnv>) => {
+  const query = MoveSearchQuerySchema.parse(c.req.query());
+
+  const moveSearchService = c.get('moveSearchService');
+  const lang = c.get('lang');
+  // ETags are scoped to the URL, so the filters need not be part of the tag
+  const version = await moveSearchService.getDataVersion();
+  const etag = buildETag('dex', version, 'moves', lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const result = await moveSearchService.search(
+    {
+      name: query.name,
+      type: query.type,
+      damageClass: query.damage_class,
+      minPower: query.min_power,
+      maxPower: query.max_power,
+      priority: query.priority,
+      effectKeywords: query.effect,
+      learnableBy: query.learnable_by,
+    },
+    query.limit,
+    query.offset
+  );
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json({
+    moves: result.moves.map((move) => toMoveResponse(lang, move)),
+    total: result.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
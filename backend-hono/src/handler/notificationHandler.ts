@@ -0,0 +1,85 @@
+import { Context } from 'hono';
+import { Notification } from '../domain/entity/Notification';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { NotificationService } from '../usecase/NotificationService';
+import { MarkNotificationsReadRequestSchema, NotificationsQuerySchema } from '../usecase/dto';
+
+export interface NotificationEnv {
+  Variables: {
+    notificationService: NotificationService;
+  };
+}
+
+function toContentResponse(notification: Notification) {
+  const content = notification.getContent();
+  switch (content.type) {
+    case 'team_comment':
+    case 'comment_reply':
+      return {
+        team_id: content.teamId,
+        team_name: content.teamName,
+        comment_id: content.commentId,
+        author_name: content.authorName,
+      };
+    case 'team_like':
+      return { team_id: content.teamId, team_name: content.teamName, likes: content.likes };
+    case 'import_finished':
+      return { created: content.created, updated: content.updated };
+  }
+}
+
+function toNotificationResponse(notification: Notification) {
+  return {
+    notification_id: notification.getNotificationId(),
+    type: notification.getType(),
+    data: toContentResponse(notification),
+    read: notification.isRead(),
+    created_at: notification.getCreatedAt().toISOString(),
+  };
+}
+
+export const list = async (c: Context<NotificationEnv & AuthEnv>) => {
+  const query = NotificationsQuerySchema.parse(c.req.query());
+
+  const notificationService = c.get('notificationService');
+  const page = await notificationService.list(
+    c.get('userId'),
+    { unreadOnly: query.unread_only },
+    query.limit,
+    query.offset
+  );
+
+  return c.json({
+    notifications: page.notifications.map(toNotificationResponse),
+    unread: page.unread,
+    total: page.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
+
+// Cheap enough to poll for a badge
+export const unreadCount = async (c: Context<NotificationEnv & AuthEnv>) => {
+  const notificationService = c.get('notificationService');
+  const unread = await notificationService.unreadCount(c.get('userId'));
+
+  return c.json({ unread });
+};
+
+export const markRead = async (c: Context<NotificationEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = MarkNotificationsReadRequestSchema.parse(body);
+
+  const notificationService = c.get('notificationService');
+  const marked = await notificationService.markRead(c.get('userId'), validated.notification_ids);
+
+  return c.json({ marked });
+};
+
+export const markAllRead = async (c: Context<NotificationEnv & AuthEnv>) => {
+  const notificationService = c.get('notificationService');
+  const marked = await notificationService.markAllRead(c.get('userId'));
+
+  return c.json({ marked });
+};
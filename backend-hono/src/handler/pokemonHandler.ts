@@ -0,0 +1,352 @@
+import { Context } from 'hono';
+import { stream } from 'hono/streaming';
+import { NationalDexNumber, RegionalDexNumber } from '../domain/valueobject/DexNumber';
+import { SpeciesId } from '../domain/valueobject/PokemonId';
+import { Lang, localizedName, translateType } from '../i18n';
+import { readJson } from '../middleware/body';
+import {
+  buildETag,
+  DEX_CACHE_CONTROL,
+  MEDIA_CACHE_CONTROL,
+  notModified,
+  setCacheHeaders,
+} from '../middleware/cache';
+import { LangEnv } from '../middleware/lang';
+import { DefensiveCoreService } from '../usecase/DefensiveCoreService';
+import { FormMediaService } from '../usecase/FormMediaService';
+import { PokemonComparisonService } from '../usecase/PokemonComparisonService';
+import { PokemonFormDetailResponse, PokemonLookupService } from '../usecase/PokemonLookupService';
+import {
+  BatchFormsRequestSchema,
+  CompareFormsQuerySchema,
+  DefensiveCoreQuerySchema,
+  DexNumberParamsSchema,
+  EncounterLocationsQuerySchema,
+  ExportFormsQuerySchema,
+  FormIdParamSchema,
+  ListFormsQuerySchema,
+  MediaAssetParamSchema,
+} from '../usecase/dto';
+
+export interface PokemonEnv {
+  Variables: {
+    defensiveCoreService: DefensiveCoreService;
+    formMediaService: FormMediaService;
+    pokemonComparisonService: PokemonComparisonService;
+    pokemonLookupService: PokemonLookupService;
+  };
+}
+
+function toFormDetailResponse(lang: Lang, result: PokemonFormDetailResponse) {
+  return {
+    form_id: result.formId,
+    species_id: result.speciesId,
+    national_dex_number: NationalDexNumber.format(SpeciesId.fromRepository(result.speciesId)),
+    regional_dex_numbers: result.regionalDexNumbers,
+    name: result.name,
+    name_jp: result.nameJp,
+    display_name: localizedName(lang, result.name, result.nameJp),
+    type1: result.type1,
+    type2: result.type2 ?? null,
+    type_names: [result.type1, ...(result.type2 ? [result.type2] : [])].map((type) =>
+      translateType(lang, type)
+    ),
+    abilities: result.abilities,
+    base_experience: result.baseExperience ?? null,
+    height_m: result.heightMeters,
+    weight_kg: result.weightKg,
+    shiny_available: result.shinyAvailable,
+    shiny_locked: result.shinyLocked,
+    gender: {
+      genderless: result.genderless,
+      female_rate: result.femaleRate,
+      male_rate: result.maleRate,
+    },
+    capture_rate: result.captureRate,
+    egg_groups: result.eggGroups,
+    introduced_in: result.introducedIn,
+    obtainable_in: result.obtainableIn,
+  };
+}
+
+export const getForm = async (c: Context<PokemonEnv & LangEnv>) => {
+  const formId = FormIdParamSchema.parse(c.req.param('formId'));
+
+  const pokemonLookupService = c.get('pokemonLookupService');
+  const lang = c.get('lang');
+  const version = await pokemonLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'form', formId, lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const result = await pokemonLookupService.getForm(formId);
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json(toFormDetailResponse(lang, result));
+};
+
+// Misses are reported in place, so a team view can render whatever was found
+export const batchForms = async (c: Context<PokemonEnv & LangEnv>) => {
+  const body = await readJson(c);
+  const validated = BatchFormsRequestSchema.parse(body);
+
+  const pokemonLookupService = c.get('pokemonLookupService');
+  const lang = c.get('lang');
+  const results = await pokemonLookupService.resolveForms(validated.pokemon);
+
+  return c.json({
+    pokemon: results.map((result) => ({
+      query: result.query,
+      found: result.form !== null,
+      form: result.form ? toFormDetailResponse(lang, result.form) : null,
+    })),
+    not_found: results.filter((result) => !result.form).map((result) => result.query),
+  });
+};
+
+export const getFormByDexNumber = async (c: Context<PokemonEnv & LangEnv>) => {
+  const params = DexNumberParamsSchema.parse(c.req.param());
+  const dexNumber = RegionalDexNumber.parse(params.dex, params.number);
+
+  const pokemonLookupService = c.get('pokemonLookupService');
+  const lang = c.get('lang');
+  const version = await pokemonLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'dex-number', dexNumber.dex, dexNumber.number, lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const result = await pokemonLookupService.findFormByRegionalDexNumber(dexNumber);
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json(toFormDetailResponse(lang, result));
+};
+
+export const listForms = async (c: Context<PokemonEnv & LangEnv>) => {
+  const query = ListFormsQuerySchema.parse(c.req.query());
+
+  const pokemonLookupService = c.get('pokemonLookupService');
+  const lang = c.get('lang');
+  // ETags are scoped to the URL, so the filters need not be part of the tag
+  const version = await pokemonLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'forms', lang);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const result = await pokemonLookupService.listForms(
+    {
+      availableIn: query.available_in,
+      maxGeneration: query.max_generation,
+      encounterGame: query.encounter_game,
+      encounterLocation: query.location,
+      firstStageOnly: query.first_stage,
+    },
+    query.limit === undefined ? undefined : { limit: query.limit, cursor: query.cursor }
+  );
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json({
+    pokemon: result.forms.map((form) => ({
+      form_id: form.formId,
+      species_id: form.speciesId,
+      name: form.name,
+      name_jp: form.nameJp,
+      display_name: localizedName(lang, form.name, form.nameJp),
+      type1: form.type1,
+      type2: form.type2 ?? null,
+      introduced_in: form.introducedIn,
+    })),
+    total: result.forms.length,
+    next_cursor: result.nextCursor ?? null,
+  });
+};
+
+// One form detail per line. Each page of forms is read only after the previous one has been
+// written, so a slow client holds back the database reads instead of buffering the whole dex.
+export const exportForms = async (c: Context<PokemonEnv & LangEnv>) => {
+  const query = ExportFormsQuerySchema.parse(c.req.query());
+
+  const pokemonLookupService = c.get('pokemonLookupService');
+  const lang = c.get('lang');
+  const version = await pokemonLookupService.getDataVersion();
+
+  c.header('Content-Type', 'application/x-ndjson; charset=utf-8');
+  c.header('Content-Disposition', `attachment; filename="pokedict-dex-v${version}.ndjson"`);
+
+  return stream(c, async (output) => {
+    const forms = pokemonLookupService.exportForms({
+      generation: query.generation,
+      availableIn: query.available_in,
+    });
+    for await (const form of forms) {
+      if (output.aborted) {
+        return;
+      }
+      await output.write(JSON.stringify(toFormDetailResponse(lang, form)) + '\n');
+    }
+  });
+};
+
+export const getFormMedia = async (c: Context<PokemonEnv>) => {
+  const formId = FormIdParamSchema.parse(c.req.param('formId'));
+
+  const pokemonLookupService = c.get('pokemonLookupService');
+  const version = await pokemonLookupService.getDataVersion();
+  const etag = buildETag('dex', version, 'media', formId);
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const formMediaService = c.get('formMediaService');
+  const media = await formMediaService.getMedia(formId);
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json({
+    form_id: formId,
+    cry_url: media?.cryUrl ?? null,
+    legacy_cry_url: media?.legacyCryUrl ?? null,
+    sprite_url: media?.spriteUrl ?? null,
+    shiny_sprite_url: media?.shinySpriteUrl ?? null,
+    has_3d_model: media?.has3dModel ?? false,
+    has_3d_shiny_model: media?.has3dShinyModel ?? false,
+    // Null until the form is synced again
+    checked_at: media?.checkedAt.toISOString() ?? null,
+  });
+};
+
+export const getFormMediaAsset = async (c: Context<PokemonEnv>) => {
+  const formId = FormIdParamSchema.parse(c.req.param('formId'));
+  const asset = MediaAssetParamSchema.parse(c.req.param('asset'));
+
+  const formMediaService = c.get('formMediaService');
+  const file = await formMediaService.fetchAsset(formId, asset);
+
+  return c.body(file.body, 200, {
+    'Content-Type': file.contentType,
+    'Cache-Control': MEDIA_CACHE_CONTROL,
+  });
+};
+
+export const defensiveCores = async (c: Context<PokemonEnv & LangEnv>) => {
+  const query = DefensiveCoreQuerySchema.parse(c.req.query());
+
+  const pokemonLookupService = c.get('pokemonLookupService');
+  const lang = c.get('lang');
+  const version = await pokemonLookupService.getDataVersion();
+  const etag = buildETag(
+    'dex',
+    version,
+    'cores',
+    query.size,
+    query.form_ids?.join('.') ?? query.available_in ?? 'all',
+    query.limit,
+    lang
+  );
+  const cached = notModified(c, etag, DEX_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  const defensiveCoreService = c.get('defensiveCoreService');
+  const cores = await defensiveCoreService.findCores(
+    { formIds: query.form_ids, availableIn: query.available_in },
+    query.size,
+    query.limit
+  );
+  setCacheHeaders(c, etag, DEX_CACHE_CONTROL);
+
+  return c.json({
+    size: query.size,
+    cores: cores.map((core) => ({
+      members: core.members.map((form) => {
+        const stats = form.getBaseStats();
+        return {
+          form_id: form.getFormId(),
+          name: form.getName(),
+          name_jp: form.getNameJp(),
+          display_name: localizedName(lang, form.getName(), form.getNameJp()),
+          types: form.getTypeset().getTypes(),
+          hp: stats.hp,
+          defense: stats.defense,
+          special_defense: stats.specialDefense,
+        };
+      }),
+      shared_weaknesses: core.sharedWeaknesses,
+      weaknesses: core.weaknesses,
+      bulk: core.bulk,
+    })),
+  });
+};
+
+export const encounterLocations = async (c: Context<PokemonEnv>) => {
+  const query = EncounterLocationsQuerySchema.parse(c.req.query());
+
+  const pokemonLookupService = c.get('pokemonLookupService');
+  const locations = await pokemonLookupService.listEncounterLocations(query.game);
+
+  return c.json({ game: query.game, locations, total: locations.length });
+};
+
+export const compare = async (c: Context<PokemonEnv & LangEnv>) => {
+  const query = CompareFormsQuerySchema.parse(c.req.query());
+
+  const pokemonComparisonService = c.get('pokemonComparisonService');
+  const result = await pokemonComparisonService.compare(query.form_ids, query.format);
+  const lang = c.get('lang');
+
+  return c.json({
+    format: result.formatId ?? null,
+    period: result.period ?? null,
+    pokemon: result.forms.map((form) => ({
+      form_id: form.formId,
+      name: form.name,
+      name_jp: form.nameJp,
+      display_name: localizedName(lang, form.name, form.nameJp),
+      types: form.types,
+      type_names: form.types.map((type) => translateType(lang, type)),
+      base_stats: {
+        hp: form.baseStats.hp,
+        attack: form.baseStats.attack,
+        defense: form.baseStats.defense,
+        special_attack: form.baseStats.specialAttack,
+        special_defense: form.baseStats.specialDefense,
+        speed: form.baseStats.speed,
+        total: form.baseStatTotal,
+      },
+      abilities: form.abilities.map((ability) => ({
+        name: ability.name,
+        percentage: ability.percentage,
+      })),
+      defenses: form.defenses.map((matchup) => ({
+        type: matchup.type,
+        multiplier: matchup.multiplier,
+        source: matchup.source,
+      })),
+      move_source: form.moveSource,
+      notable_moves: form.notableMoves.map((move) => ({
+        name: move.name,
+        type: move.type,
+        power: move.power ?? null,
+        accuracy: move.accuracy ?? null,
+        stab: move.stab,
+        usage: move.usage ?? null,
+      })),
+    })),
+    stat_leaders: {
+      hp: result.statLeaders.hp,
+      attack: result.statLeaders.attack,
+      defense: result.statLeaders.defense,
+      special_attack: result.statLeaders.specialAttack,
+      special_defense: result.statLeaders.specialDefense,
+      speed: result.statLeaders.speed,
+      total: result.statLeaders.total,
+    },
+    defensive_differences: result.defensiveDifferences,
+  });
+};
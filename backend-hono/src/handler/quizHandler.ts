@@ -0,0 +1,69 @@
+import { Context } from 'hono';
+import { TypeQuizScore } from '../domain/entity/TypeQuizScore';
+import { TypeQuizQuestion } from '../domain/service/TypeQuiz';
+import { Lang, translateType } from '../i18n';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { LangEnv } from '../middleware/lang';
+import { TypeQuizService } from '../usecase/TypeQuizService';
+import { TypeQuizAnswerRequestSchema } from '../usecase/dto';
+
+export interface QuizEnv {
+  Variables: {
+    typeQuizService: TypeQuizService;
+  };
+}
+
+// Leaves out the answer, which only comes back once the question is answered
+function toQuestionResponse(lang: Lang, question: TypeQuizQuestion) {
+  const defendTypes = question.defender.getTypes();
+  return {
+    question_id: question.questionId,
+    attack_type: question.attack,
+    attack_type_name: translateType(lang, question.attack),
+    defend_types: defendTypes,
+    defend_type_names: defendTypes.map((type) => translateType(lang, type)),
+    choices: question.choices,
+  };
+}
+
+function toScoreResponse(score: TypeQuizScore) {
+  return {
+    answered: score.getAnswered(),
+    correct: score.getCorrect(),
+    accuracy: score.getAccuracy(),
+    current_streak: score.getCurrentStreak(),
+    best_streak: score.getBestStreak(),
+  };
+}
+
+export const question = async (c: Context<QuizEnv & LangEnv>) => {
+  const typeQuizService = c.get('typeQuizService');
+  return c.json(toQuestionResponse(c.get('lang'), typeQuizService.createQuestion()));
+};
+
+export const answer = async (c: Context<QuizEnv & AuthEnv & LangEnv>) => {
+  const body = await readJson(c);
+  const validated = TypeQuizAnswerRequestSchema.parse(body);
+
+  const typeQuizService = c.get('typeQuizService');
+  const result = await typeQuizService.submitAnswer(
+    c.get('userId'),
+    validated.question_id,
+    validated.answer
+  );
+
+  return c.json({
+    ...toQuestionResponse(c.get('lang'), result.question),
+    correct: result.correct,
+    answer: result.question.answer,
+    score: toScoreResponse(result.score),
+  });
+};
+
+export const score = async (c: Context<QuizEnv & AuthEnv>) => {
+  const typeQuizService = c.get('typeQuizService');
+  const result = await typeQuizService.getScore(c.get('userId'));
+
+  return c.json(toScoreResponse(result));
+};
@@ -0,0 +1,113 @@
+import { Context } from 'hono';
+import { RunTracker } from '../domain/entity/RunTracker';
+import { localizedName } from '../i18n';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { LangEnv } from '../middleware/lang';
+import { RunTrackerService } from '../usecase/RunTrackerService';
+import {
+  CreateRunRequestSchema,
+  RunDexQuerySchema,
+  SpeciesIdParamSchema,
+  UuidSchema,
+} from '../usecase/dto';
+
+export interface RunEnv {
+  Variables: {
+    runTrackerService: RunTrackerService;
+  };
+}
+
+function toRunResponse(run: RunTracker) {
+  return {
+    run_id: run.getRunId(),
+    name: run.getName(),
+    game_version: run.getGameVersion(),
+    used_species_ids: run.getUsedSpeciesIds(),
+    created_at: run.getCreatedAt().toISOString(),
+  };
+}
+
+export const createRun = async (c: Context<RunEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = CreateRunRequestSchema.parse(body);
+
+  const runTrackerService = c.get('runTrackerService');
+  const run = await runTrackerService.createRun(
+    c.get('userId'),
+    validated.name,
+    validated.game_version
+  );
+
+  return c.json(toRunResponse(run), 201);
+};
+
+export const listRuns = async (c: Context<RunEnv & AuthEnv>) => {
+  const runTrackerService = c.get('runTrackerService');
+  const runs = await runTrackerService.listRuns(c.get('userId'));
+
+  return c.json({ runs: runs.map(toRunResponse), total: runs.length });
+};
+
+export const getRun = async (c: Context<RunEnv & AuthEnv>) => {
+  const runId = UuidSchema.parse(c.req.param('runId'));
+
+  const runTrackerService = c.get('runTrackerService');
+  const run = await runTrackerService.getRun(c.get('userId'), runId);
+
+  return c.json(toRunResponse(run));
+};
+
+export const deleteRun = async (c: Context<RunEnv & AuthEnv>) => {
+  const runId = UuidSchema.parse(c.req.param('runId'));
+
+  const runTrackerService = c.get('runTrackerService');
+  await runTrackerService.deleteRun(c.get('userId'), runId);
+
+  return c.json({ message: 'Run deleted successfully' });
+};
+
+export const markUsed = async (c: Context<RunEnv & AuthEnv>) => {
+  const runId = UuidSchema.parse(c.req.param('runId'));
+  const speciesId = SpeciesIdParamSchema.parse(c.req.param('speciesId'));
+
+  const runTrackerService = c.get('runTrackerService');
+  const run = await runTrackerService.markUsed(c.get('userId'), runId, speciesId);
+
+  return c.json(toRunResponse(run));
+};
+
+export const unmarkUsed = async (c: Context<RunEnv & AuthEnv>) => {
+  const runId = UuidSchema.parse(c.req.param('runId'));
+  const speciesId = SpeciesIdParamSchema.parse(c.req.param('speciesId'));
+
+  const runTrackerService = c.get('runTrackerService');
+  const run = await runTrackerService.unmarkUsed(c.get('userId'), runId, speciesId);
+
+  return c.json(toRunResponse(run));
+};
+
+export const availableForms = async (c: Context<RunEnv & AuthEnv & LangEnv>) => {
+  const runId = UuidSchema.parse(c.req.param('runId'));
+  const query = RunDexQuerySchema.parse(c.req.query());
+
+  const runTrackerService = c.get('runTrackerService');
+  const forms = await runTrackerService.findAvailableForms(c.get('userId'), runId, {
+    location: query.location,
+    firstStageOnly: query.first_stage,
+  });
+  const lang = c.get('lang');
+
+  return c.json({
+    pokemon: forms.map((form) => ({
+      form_id: form.getFormId(),
+      species_id: form.getSpeciesId(),
+      name: form.getName(),
+      name_jp: form.getNameJp(),
+      display_name: localizedName(lang, form.getName(), form.getNameJp()),
+      type1: form.getTypeset().type1,
+      type2: form.getTypeset().type2 ?? null,
+    })),
+    total: forms.length,
+  });
+};
@@ -0,0 +1,37 @@
+import { Context } from 'hono';
+import { Lang, localizedName } from '../i18n';
+import { LangEnv } from '../middleware/lang';
+import { SearchHit } from '../repository/interface/SearchIndex';
+import { SearchService } from '../usecase/SearchService';
+import { FullTextSearchQuerySchema } from '../usecase/dto';
+
+export interface SearchEnv {
+  Variables: {
+    searchService: SearchService;
+  };
+}
+
+function toHitResponse(lang: Lang, hit: SearchHit) {
+  return {
+    kind: hit.kind,
+    id: hit.id,
+    name: hit.name,
+    name_jp: hit.nameJp ?? null,
+    display_name: localizedName(lang, hit.name, hit.nameJp),
+    snippet: hit.snippet ?? null,
+  };
+}
+
+export const search = async (c: Context<SearchEnv & LangEnv>) => {
+  const query = FullTextSearchQuerySchema.parse(c.req.query());
+
+  const searchService = c.get('searchService');
+  const result = await searchService.search(query.q, query.kind ?? [], query.limit, query.offset);
+
+  return c.json({
+    results: result.hits.map((hit) => toHitResponse(c.get('lang'), hit)),
+    total: result.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
@@ -0,0 +1,716 @@
+import { Context } from 'hono';
+import { Team } from '../domain/entity/Team';
+import { ArchetypeScore } from '../domain/service/ArchetypeClassifier';
+import { listFormats } from '../domain/valueobject/Format';
+import { LegalityViolation } from '../domain/valueobject/LegalityViolation';
+import { Level } from '../domain/valueobject/Level';
+import { Nickname } from '../domain/valueobject/Nickname';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { PreferencesEnv, withPreferredDefaults } from '../middleware/preferences';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { EVStats } from '../domain/valueobject/Stats';
+import { AuthoredComment } from '../repository/interface/TeamCommentRepository';
+import { AnalysisCacheService, AnalysisKind } from '../usecase/AnalysisCacheService';
+import { RandomTeamService } from '../usecase/RandomTeamService';
+import { TeamAnalysisService } from '../usecase/TeamAnalysisService';
+import { TeamClipboardService } from '../usecase/TeamClipboardService';
+import { TeamCommentService } from '../usecase/TeamCommentService';
+import { GalleryEntry, TeamGalleryService } from '../usecase/TeamGalleryService';
+import { TeamLegalityService } from '../usecase/TeamLegalityService';
+import { TeamNotesService } from '../usecase/TeamNotesService';
+import { TeamOrganizerService } from '../usecase/TeamOrganizerService';
+import { TeamReportService } from '../usecase/TeamReportService';
+import { TeamShareService } from '../usecase/TeamShareService';
+import { TeamTrashService } from '../usecase/TeamTrashService';
+import { Threat, ThreatAnalysisService } from '../usecase/ThreatAnalysisService';
+import {
+  CommentThreadsQuerySchema,
+  CopyTeamMemberRequestSchema,
+  CreateCommentRequestSchema,
+  EditCommentRequestSchema,
+  FlaggedTeamsQuerySchema,
+  FlagTeamRequestSchema,
+  GalleryQuerySchema,
+  MoveTeamToFolderRequestSchema,
+  OrganizeTeamsRequestSchema,
+  PokemonBuildDto,
+  RandomTeamRequestSchema,
+  SetMemberNotesRequestSchema,
+  SetTeamNotesRequestSchema,
+  SetTeamTagsRequestSchema,
+  ShareTeamRequestSchema,
+  TeamArchetypeRequestSchema,
+  TeamCodeParamSchema,
+  TeamCodeRequestSchema,
+  TeamCommentIdParamSchema,
+  TeamIdParamSchema,
+  TeamListQuerySchema,
+  TeamReportQuerySchema,
+  TeamReportRequestSchema,
+  TeamSlotParamSchema,
+  ThreatAnalysisRequestSchema,
+  ValidateTeamForFormatRequestSchema,
+  ValidateTeamRequestSchema,
+} from '../usecase/dto';
+import { toTeamResponse } from '../usecase/dto/teamResponse';
+
+export interface TeamEnv {
+  Variables: {
+    analysisCacheService: AnalysisCacheService;
+    randomTeamService: RandomTeamService;
+    teamAnalysisService: TeamAnalysisService;
+    teamClipboardService: TeamClipboardService;
+    teamCommentService: TeamCommentService;
+    teamGalleryService: TeamGalleryService;
+    teamLegalityService: TeamLegalityService;
+    teamNotesService: TeamNotesService;
+    teamOrganizerService: TeamOrganizerService;
+    teamReportService: TeamReportService;
+    teamShareService: TeamShareService;
+    teamTrashService: TeamTrashService;
+    threatAnalysisService: ThreatAnalysisService;
+  };
+}
+
+export function toPokemonBuild(dto: PokemonBuildDto): PokemonBuild {
+  return PokemonBuild.create({
+    formId: dto.form_id,
+    nickname: dto.nickname === undefined ? undefined : Nickname.create(dto.nickname),
+    ability: dto.ability,
+    allowIllegalAbility: dto.allow_illegal_ability,
+    heldItem: dto.held_item,
+    moves: dto.moves,
+    teraType: dto.tera_type,
+    nature: dto.nature,
+    ev: dto.evs
+      ? new EVStats(
+          dto.evs.hp,
+          dto.evs.attack,
+          dto.evs.defense,
+          dto.evs.special_attack,
+          dto.evs.special_defense,
+          dto.evs.speed
+        )
+      : undefined,
+    iv: dto.ivs
+      ? {
+          hp: dto.ivs.hp,
+          attack: dto.ivs.attack,
+          defense: dto.ivs.defense,
+          specialAttack: dto.ivs.special_attack,
+          specialDefense: dto.ivs.special_defense,
+          speed: dto.ivs.speed,
+        }
+      : undefined,
+    friendship: dto.friendship,
+  });
+}
+
+export function toBuildResponse(build: PokemonBuild) {
+  const ev = build.getEV();
+  const iv = build.getIV();
+  return {
+    form_id: build.getFormId(),
+    ability: build.getAbility() ?? null,
+    held_item: build.getHeldItem() ?? null,
+    moves: build.getMoves(),
+    tera_type: build.getTeraType() ?? null,
+    nature: build.getNature(),
+    evs: {
+      hp: ev.hp,
+      attack: ev.attack,
+      defense: ev.defense,
+      special_attack: ev.specialAttack,
+      special_defense: ev.specialDefense,
+      speed: ev.speed,
+    },
+    ivs: {
+      hp: iv.hp,
+      attack: iv.attack,
+      defense: iv.defense,
+      special_attack: iv.specialAttack,
+      special_defense: iv.specialDefense,
+      speed: iv.speed,
+    },
+    friendship: build.getFriendship(),
+  };
+}
+
+export function toViolationResponse(violation: LegalityViolation) {
+  return {
+    code: violation.code,
+    message: violation.message,
+    slot: violation.slot ?? null,
+    form_id: violation.formId ?? null,
+  };
+}
+
+export const validate = async (c: Context<TeamEnv>) => {
+  const body = await readJson(c);
+  const validated = ValidateTeamRequestSchema.parse(body);
+
+  const teamLegalityService = c.get('teamLegalityService');
+  const result = await teamLegalityService.validateForGame(
+    validated.form_ids,
+    validated.game_version
+  );
+
+  return c.json({
+    legal: result.legal,
+    violations: result.violations.map(toViolationResponse),
+  });
+};
+
+export const validateForFormat = async (c: Context<TeamEnv & PreferencesEnv>) => {
+  const body = await readJson(c);
+  const validated = ValidateTeamForFormatRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const teamLegalityService = c.get('teamLegalityService');
+  const result = await teamLegalityService.validateForFormat(
+    validated.members.map(toPokemonBuild),
+    validated.format
+  );
+
+  return c.json({
+    format: result.format.id,
+    legal: result.legal,
+    violations: result.violations.map(toViolationResponse),
+  });
+};
+
+function toThreatResponse(threat: Threat) {
+  return {
+    form_id: threat.formId,
+    name: threat.name,
+    name_jp: threat.nameJp,
+    usage: threat.usage,
+    score: threat.score,
+    set: {
+      ability: threat.set.ability ?? null,
+      item: threat.set.item ?? null,
+      moves: threat.set.moves,
+      tera_type: threat.set.teraType ?? null,
+      nature: threat.set.spread?.nature ?? null,
+    },
+    reasons: threat.reasons.map((reason) => ({
+      code: reason.code,
+      message: reason.message,
+      types: reason.types ?? null,
+      hazards: reason.hazards ?? null,
+      knockouts:
+        reason.knockouts?.map((knockout) => ({
+          slot: knockout.slot,
+          move: knockout.move,
+          guaranteed: knockout.guaranteed,
+        })) ?? null,
+    })),
+  };
+}
+
+// A custom form belongs to one user and can be edited, so analyses using one are not cached
+function memoizeAnalysis<T>(
+  c: Context<TeamEnv & PreferencesEnv>,
+  kind: AnalysisKind,
+  members: PokemonBuildDto[],
+  request: unknown,
+  compute: () => Promise<T>
+): Promise<T> {
+  if (members.some((member) => FormId.isCustom(member.form_id))) {
+    return compute();
+  }
+  return c.get('analysisCacheService').memoize(kind, request, compute);
+}
+
+export const threats = async (c: Context<TeamEnv & PreferencesEnv>) => {
+  const body = await readJson(c);
+  const validated = ThreatAnalysisRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const threatAnalysisService = c.get('threatAnalysisService');
+  const response = await memoizeAnalysis(c, 'threats', validated.members, validated, async () => {
+    const result = await threatAnalysisService.analyze(
+      validated.members.map(toPokemonBuild),
+      validated.format,
+      validated.limit,
+      validated.battle_style
+    );
+    return {
+      format: result.format.id,
+      battle_style: result.battleStyle,
+      period: result.period,
+      threats: result.threats.map(toThreatResponse),
+      leads: result.leads.map((lead) => ({
+        slots: lead.slots,
+        score: lead.score,
+        offense: lead.offense,
+        exposure: lead.exposure,
+        reasons: lead.reasons,
+      })),
+    };
+  });
+
+  return c.json(response);
+};
+
+function toArchetypeResponse(score: ArchetypeScore) {
+  return {
+    archetype: score.archetype,
+    confidence: score.confidence,
+    features: score.features,
+  };
+}
+
+export const archetype = async (c: Context<TeamEnv & PreferencesEnv>) => {
+  const body = await readJson(c);
+  const validated = TeamArchetypeRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const teamAnalysisService = c.get('teamAnalysisService');
+  const response = await memoizeAnalysis(c, 'archetype', validated.members, validated, async () => {
+    const result = await teamAnalysisService.classify(
+      validated.members.map(toPokemonBuild),
+      Level.resolve(validated.level, validated.mode)
+    );
+    return {
+      primary: result.primary ? toArchetypeResponse(result.primary) : null,
+      archetypes: result.archetypes.map(toArchetypeResponse),
+    };
+  });
+
+  return c.json(response);
+};
+
+export const report = async (c: Context<TeamEnv & PreferencesEnv>) => {
+  const query = TeamReportQuerySchema.parse(c.req.query());
+  const body = await readJson(c);
+  const validated = TeamReportRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const teamReportService = c.get('teamReportService');
+  const request = { validated, query };
+  const rendered = await memoizeAnalysis(c, 'report', validated.members, request, async () => {
+    const result = await teamReportService.generate(
+      validated.members.map(toPokemonBuild),
+      validated.format,
+      validated.limit,
+      { includeAbilities: validated.include_abilities }
+    );
+    return teamReportService.render(result, query.output);
+  });
+
+  return c.body(rendered.body, 200, {
+    'Content-Type': `${rendered.contentType}; charset=utf-8`,
+    'Content-Disposition': `attachment; filename="team-report.${rendered.fileExtension}"`,
+  });
+};
+
+export const encodeCode = async (c: Context<TeamEnv>) => {
+  const body = await readJson(c);
+  const validated = TeamCodeRequestSchema.parse(body);
+
+  const teamShareService = c.get('teamShareService');
+  const result = await teamShareService.encode(validated.members.map(toPokemonBuild));
+
+  return c.json({ code: result.code, qr_payload: result.qrPayload });
+};
+
+export const decodeCode = async (c: Context<TeamEnv>) => {
+  const code = TeamCodeParamSchema.parse(c.req.param('code'));
+
+  const teamShareService = c.get('teamShareService');
+  const builds = await teamShareService.decode(code);
+
+  return c.json({ members: builds.map(toBuildResponse) });
+};
+
+export const random = async (c: Context<TeamEnv & PreferencesEnv>) => {
+  const body = await readJson(c);
+  const validated = RandomTeamRequestSchema.parse(withPreferredDefaults(c, body));
+
+  const randomTeamService = c.get('randomTeamService');
+  const result = await randomTeamService.generate({
+    formatId: validated.format,
+    size: validated.size,
+    generations: validated.generations,
+    requiredTypes: validated.required_types,
+    minBaseStatTotal: validated.min_base_stat_total,
+    seed: validated.seed,
+  });
+
+  return c.json({
+    seed: result.seed,
+    format: result.format.id,
+    members: result.members.map(({ form, build }) => ({
+      name: form.getName(),
+      name_jp: form.getNameJp(),
+      types: form.getTypeset().getTypes(),
+      base_stat_total: form.getBaseStatTotal(),
+      ...toBuildResponse(build),
+    })),
+  });
+};
+
+export const formats = (c: Context<TeamEnv>) => {
+  return c.json({
+    formats: listFormats().map((format) => ({
+      id: format.id,
+      name: format.name,
+      game_version: format.gameVersion,
+      battle_style: format.battleStyle,
+      level: format.rules.level.getValue(),
+      banned_items: format.rules.bannedItems,
+      banned_moves: format.rules.bannedMoves,
+      banned_abilities: format.rules.bannedAbilities,
+      max_restricted: format.rules.maxRestricted,
+      clauses: format.rules.clauses,
+    })),
+  });
+};
+
+function toTrashResponse(team: Team) {
+  return {
+    team_id: team.getTeamId(),
+    team_name: team.getTeamName(),
+    deleted_at: team.getDeletedAt()?.toISOString() ?? null,
+    purge_at: team.getPurgeAt()?.toISOString() ?? null,
+  };
+}
+
+export const deleteTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+
+  const teamTrashService = c.get('teamTrashService');
+  const team = await teamTrashService.deleteTeam(c.get('userId'), teamId);
+
+  return c.json(toTrashResponse(team));
+};
+
+export const listDeleted = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamTrashService = c.get('teamTrashService');
+  const teams = await teamTrashService.listDeleted(c.get('userId'));
+
+  return c.json({ teams: teams.map(toTrashResponse), total: teams.length });
+};
+
+export const restoreTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+
+  const teamTrashService = c.get('teamTrashService');
+  const team = await teamTrashService.restoreTeam(c.get('userId'), teamId);
+
+  return c.json({ team_id: team.getTeamId(), team_name: team.getTeamName() });
+};
+
+export const copyMember = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const slot = TeamSlotParamSchema.parse(c.req.param('slot'));
+  const body = await readJson(c);
+  const validated = CopyTeamMemberRequestSchema.parse(body);
+
+  const teamClipboardService = c.get('teamClipboardService');
+  const team = await teamClipboardService.copyMember(
+    c.get('userId'),
+    teamId,
+    slot,
+    validated.target_team_id,
+    validated.target_slot
+  );
+
+  return c.json(toTeamResponse(team));
+};
+
+export const cloneTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+
+  const teamClipboardService = c.get('teamClipboardService');
+  const team = await teamClipboardService.cloneTeam(c.get('userId'), teamId);
+
+  return c.json(toTeamResponse(team), 201);
+};
+
+export const listTeams = async (c: Context<TeamEnv & AuthEnv>) => {
+  const query = TeamListQuerySchema.parse(c.req.query());
+
+  const teamOrganizerService = c.get('teamOrganizerService');
+  const page = await teamOrganizerService.listTeams(
+    c.get('userId'),
+    { tag: query.tag, folder: query.folder, speciesId: query.species_id },
+    query.limit,
+    query.after
+  );
+
+  return c.json({ teams: page.teams.map(toTeamResponse), next_after: page.nextAfter });
+};
+
+export const listTags = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamOrganizerService = c.get('teamOrganizerService');
+  const tags = await teamOrganizerService.listTags(c.get('userId'));
+
+  return c.json({ tags: tags.map(({ tag, teams }) => ({ tag, teams })) });
+};
+
+export const listFolders = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamOrganizerService = c.get('teamOrganizerService');
+  const folders = await teamOrganizerService.listFolders(c.get('userId'));
+
+  return c.json({ folders: folders.map(({ folder, teams }) => ({ folder, teams })) });
+};
+
+export const setTags = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const body = await readJson(c);
+  const validated = SetTeamTagsRequestSchema.parse(body);
+
+  const teamOrganizerService = c.get('teamOrganizerService');
+  const team = await teamOrganizerService.setTags(c.get('userId'), teamId, validated.tags);
+
+  return c.json(toTeamResponse(team));
+};
+
+export const moveToFolder = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const body = await readJson(c);
+  const validated = MoveTeamToFolderRequestSchema.parse(body);
+
+  const teamOrganizerService = c.get('teamOrganizerService');
+  const team = await teamOrganizerService.moveToFolder(c.get('userId'), teamId, validated.folder);
+
+  return c.json(toTeamResponse(team));
+};
+
+export const setNotes = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const body = await readJson(c);
+  const validated = SetTeamNotesRequestSchema.parse(body);
+
+  const teamNotesService = c.get('teamNotesService');
+  const team = await teamNotesService.setTeamNotes(c.get('userId'), teamId, validated.notes);
+
+  return c.json(toTeamResponse(team));
+};
+
+export const setMemberNotes = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const slot = TeamSlotParamSchema.parse(c.req.param('slot'));
+  const body = await readJson(c);
+  const validated = SetMemberNotesRequestSchema.parse(body);
+
+  const teamNotesService = c.get('teamNotesService');
+  const team = await teamNotesService.setMemberNotes(
+    c.get('userId'),
+    teamId,
+    slot,
+    validated.notes
+  );
+
+  return c.json(toTeamResponse(team));
+};
+
+// Retags and refiles many teams in one go; either every team is changed or none is
+export const organize = async (c: Context<TeamEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = OrganizeTeamsRequestSchema.parse(body);
+
+  const teamOrganizerService = c.get('teamOrganizerService');
+  const teams = await teamOrganizerService.organize(c.get('userId'), validated.team_ids, {
+    addTags: validated.add_tags,
+    removeTags: validated.remove_tags,
+    folder: validated.folder,
+  });
+
+  return c.json({ teams: teams.map(toTeamResponse) });
+};
+
+// Only what the owner chose to make public; notes are shared with the team
+function toSharedTeamResponse(team: Team) {
+  return {
+    team_id: team.getTeamId(),
+    team_name: team.getTeamName(),
+    format: team.getSharedFormat(),
+    pokemon: team.getPokemon().map((member) => member && toTeamMemberResponse(member)),
+    notes: team.getNotes(),
+    shared_at: team.getSharedAt()?.toISOString() ?? null,
+  };
+}
+
+function toGalleryResponse(entry: GalleryEntry) {
+  return { ...toSharedTeamResponse(entry.team), likes: entry.likes, liked: entry.liked };
+}
+
+// Signed-in viewers also see which teams they like
+export const listShared = async (c: Context<TeamEnv & AuthEnv>) => {
+  const query = GalleryQuerySchema.parse(c.req.query());
+  const viewerId: string | undefined = c.get('userId');
+
+  const teamGalleryService = c.get('teamGalleryService');
+  const page = await teamGalleryService.listShared(
+    {
+      speciesId: query.species_id,
+      format: query.format,
+      sort: query.sort,
+      limit: query.limit,
+      cursor: query.cursor,
+    },
+    viewerId
+  );
+
+  return c.json({
+    teams: page.entries.map(toGalleryResponse),
+    next_cursor: page.nextCursor ?? null,
+  });
+};
+
+export const shareTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const body = await readJson(c);
+  const validated = ShareTeamRequestSchema.parse(body);
+
+  const teamGalleryService = c.get('teamGalleryService');
+  const team = await teamGalleryService.share(c.get('userId'), teamId, validated.format);
+
+  return c.json(toSharedTeamResponse(team));
+};
+
+export const unshareTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+
+  const teamGalleryService = c.get('teamGalleryService');
+  await teamGalleryService.unshare(c.get('userId'), teamId);
+
+  return c.json({ message: 'Team unshared successfully' });
+};
+
+export const likeTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+
+  const teamGalleryService = c.get('teamGalleryService');
+  const likes = await teamGalleryService.like(c.get('userId'), teamId);
+
+  return c.json({ team_id: teamId, likes, liked: true });
+};
+
+export const unlikeTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+
+  const teamGalleryService = c.get('teamGalleryService');
+  const likes = await teamGalleryService.unlike(c.get('userId'), teamId);
+
+  return c.json({ team_id: teamId, likes, liked: false });
+};
+
+export const flagTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const body = await readJson(c);
+  const validated = FlagTeamRequestSchema.parse(body);
+
+  const teamGalleryService = c.get('teamGalleryService');
+  await teamGalleryService.flag(c.get('userId'), teamId, validated.reason);
+
+  return c.json({ message: 'Team flagged for review' }, 202);
+};
+
+// Deleted comments stay in their thread without their text
+function toCommentResponse({ comment, authorName }: AuthoredComment) {
+  return {
+    comment_id: comment.getCommentId(),
+    parent_id: comment.getParentId(),
+    author_id: comment.getAuthorId(),
+    author_name: authorName,
+    body: comment.isDeleted() ? null : comment.getBody(),
+    created_at: comment.getCreatedAt().toISOString(),
+    edited_at: comment.isEdited() ? comment.getUpdatedAt().toISOString() : null,
+    deleted: comment.isDeleted(),
+  };
+}
+
+export const listComments = async (c: Context<TeamEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const query = CommentThreadsQuerySchema.parse(c.req.query());
+
+  const teamCommentService = c.get('teamCommentService');
+  const page = await teamCommentService.listThreads(teamId, query.limit, query.offset);
+
+  return c.json({
+    comments: page.threads.map((thread) => ({
+      ...toCommentResponse(thread),
+      replies: thread.replies.map(toCommentResponse),
+    })),
+    total: page.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
+
+export const createComment = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const body = await readJson(c);
+  const validated = CreateCommentRequestSchema.parse(body);
+
+  const teamCommentService = c.get('teamCommentService');
+  const comment = await teamCommentService.create(
+    c.get('userId'),
+    teamId,
+    validated.body,
+    validated.parent_id
+  );
+
+  return c.json(toCommentResponse(comment), 201);
+};
+
+export const editComment = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const commentId = TeamCommentIdParamSchema.parse(c.req.param('commentId'));
+  const body = await readJson(c);
+  const validated = EditCommentRequestSchema.parse(body);
+
+  const teamCommentService = c.get('teamCommentService');
+  const comment = await teamCommentService.edit(c.get('userId'), teamId, commentId, validated.body);
+
+  return c.json(toCommentResponse(comment));
+};
+
+export const deleteComment = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+  const commentId = TeamCommentIdParamSchema.parse(c.req.param('commentId'));
+
+  const teamCommentService = c.get('teamCommentService');
+  await teamCommentService.delete(c.get('userId'), teamId, commentId);
+
+  return c.json({ message: 'Comment deleted successfully' });
+};
+
+// Admin: the moderation queue
+export const listFlagged = async (c: Context<TeamEnv & AuthEnv>) => {
+  const query = FlaggedTeamsQuerySchema.parse(c.req.query());
+
+  const teamGalleryService = c.get('teamGalleryService');
+  const flagged = await teamGalleryService.listFlagged(query.limit, query.offset);
+
+  return c.json({
+    teams: flagged.map((entry) => ({
+      team_id: entry.teamId,
+      team: entry.team ? toSharedTeamResponse(entry.team) : null,
+      flags: entry.flags,
+      reasons: entry.reasons,
+      last_flagged_at: entry.lastFlaggedAt.toISOString(),
+      hidden: entry.hidden,
+    })),
+  });
+};
+
+export const takeDownTeam = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+
+  const teamGalleryService = c.get('teamGalleryService');
+  await teamGalleryService.takeDown(teamId);
+
+  return c.json({ message: 'Team taken down successfully' });
+};
+
+export const dismissFlags = async (c: Context<TeamEnv & AuthEnv>) => {
+  const teamId = TeamIdParamSchema.parse(c.req.param('teamId'));
+
+  const teamGalleryService = c.get('teamGalleryService');
+  await teamGalleryService.dismissFlags(teamId);
+
+  return c.json({ message: 'Flags dismissed successfully' });
+};
@@ -0,0 +1,221 @@
+import { Context } from 'hono';
+import { Tournament, TournamentEntry, TournamentMatch } from '../domain/entity/Tournament';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { TournamentService } from '../usecase/TournamentService';
+import {
+  CreateTournamentRequestSchema,
+  TournamentEntryRequestSchema,
+  TournamentListQuerySchema,
+  TournamentMatchParamsSchema,
+  TournamentResultRequestSchema,
+  UuidSchema,
+} from '../usecase/dto';
+
+export interface TournamentEnv {
+  Variables: {
+    tournamentService: TournamentService;
+  };
+}
+
+function toUserResponse(userId: string | null, usernames: Map<string, string>) {
+  return userId ? { user_id: userId, username: usernames.get(userId) ?? null } : null;
+}
+
+function toTournamentSummary(tournament: Tournament, usernames: Map<string, string>) {
+  return {
+    tournament_id: tournament.getTournamentId(),
+    name: tournament.getName(),
+    format: tournament.getFormat(),
+    organizer: toUserResponse(tournament.getOrganizerId(), usernames),
+    status: tournament.getStatus(),
+    entry_count: tournament.getEntries().length,
+    max_entries: tournament.getMaxEntries(),
+    created_at: tournament.getCreatedAt().toISOString(),
+    started_at: tournament.getStartedAt()?.toISOString() ?? null,
+  };
+}
+
+// Teams stay hidden while registration is open, except from the organizer and the entrant, so
+// nobody can tailor their own to what others entered
+function toEntryResponse(
+  tournament: Tournament,
+  entry: TournamentEntry,
+  usernames: Map<string, string>,
+  viewerId: string | undefined
+) {
+  const visible =
+    tournament.getStatus() !== 'registration' ||
+    viewerId === tournament.getOrganizerId() ||
+    viewerId === entry.userId;
+  return {
+    ...toUserResponse(entry.userId, usernames),
+    seed: entry.seed,
+    team_name: entry.teamName,
+    team_version: entry.teamVersion,
+    members: visible
+      ? entry.members.map((member) => ({
+          slot: member.slot,
+          form_id: member.formId,
+          terastal_type: member.terastalType,
+        }))
+      : null,
+    registered_at: entry.registeredAt.toISOString(),
+  };
+}
+
+function toMatchResponse(match: TournamentMatch, usernames: Map<string, string>) {
+  return {
+    round: match.round,
+    position: match.position,
+    player1: toUserResponse(match.player1Id, usernames),
+    player2: toUserResponse(match.player2Id, usernames),
+    winner: toUserResponse(match.winnerId, usernames),
+    reported_at: match.reportedAt?.toISOString() ?? null,
+  };
+}
+
+function toTournamentResponse(
+  tournament: Tournament,
+  usernames: Map<string, string>,
+  viewerId: string | undefined
+) {
+  const rounds = Array.from({ length: tournament.getRoundCount() }, (_, index) => index + 1);
+  return {
+    ...toTournamentSummary(tournament, usernames),
+    entries: tournament
+      .getEntries()
+      .map((entry) => toEntryResponse(tournament, entry, usernames, viewerId)),
+    rounds: rounds.map((round) => ({
+      round,
+      matches: tournament.getRound(round).map((match) => toMatchResponse(match, usernames)),
+    })),
+  };
+}
+
+async function respondWithTournament(
+  c: Context<TournamentEnv & AuthEnv>,
+  tournament: Tournament,
+  status: 200 | 201 = 200
+) {
+  const usernames = await c.get('tournamentService').findUsernames([tournament]);
+  return c.json(toTournamentResponse(tournament, usernames, c.get('userId')), status);
+}
+
+export const createTournament = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = CreateTournamentRequestSchema.parse(body);
+
+  const tournamentService = c.get('tournamentService');
+  const tournament = await tournamentService.create(c.get('userId'), {
+    name: validated.name,
+    format: validated.format,
+    maxEntries: validated.max_entries,
+  });
+
+  return respondWithTournament(c, tournament, 201);
+};
+
+export const listTournaments = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const query = TournamentListQuerySchema.parse(c.req.query());
+
+  const tournamentService = c.get('tournamentService');
+  const page = await tournamentService.list(query.status ?? null, query.limit, query.offset);
+  const usernames = await tournamentService.findUsernames(page.tournaments);
+
+  return c.json({
+    tournaments: page.tournaments.map((tournament) => toTournamentSummary(tournament, usernames)),
+    total: page.total,
+    limit: query.limit,
+    offset: query.offset,
+  });
+};
+
+export const getTournament = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const tournamentId = UuidSchema.parse(c.req.param('tournamentId'));
+
+  const tournamentService = c.get('tournamentService');
+  const tournament = await tournamentService.get(tournamentId);
+
+  return respondWithTournament(c, tournament);
+};
+
+export const deleteTournament = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const tournamentId = UuidSchema.parse(c.req.param('tournamentId'));
+
+  const tournamentService = c.get('tournamentService');
+  await tournamentService.delete(c.get('userId'), tournamentId);
+
+  return c.json({ message: 'Tournament deleted successfully' });
+};
+
+export const register = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const tournamentId = UuidSchema.parse(c.req.param('tournamentId'));
+  const body = await readJson(c);
+  const validated = TournamentEntryRequestSchema.parse(body);
+
+  const tournamentService = c.get('tournamentService');
+  const tournament = await tournamentService.register(
+    c.get('userId'),
+    tournamentId,
+    validated.team_id
+  );
+
+  return respondWithTournament(c, tournament);
+};
+
+export const withdraw = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const tournamentId = UuidSchema.parse(c.req.param('tournamentId'));
+
+  const tournamentService = c.get('tournamentService');
+  const tournament = await tournamentService.withdraw(c.get('userId'), tournamentId);
+
+  return respondWithTournament(c, tournament);
+};
+
+export const startTournament = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const tournamentId = UuidSchema.parse(c.req.param('tournamentId'));
+
+  const tournamentService = c.get('tournamentService');
+  const tournament = await tournamentService.start(c.get('userId'), tournamentId);
+
+  return respondWithTournament(c, tournament);
+};
+
+export const reportResult = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const tournamentId = UuidSchema.parse(c.req.param('tournamentId'));
+  const params = TournamentMatchParamsSchema.parse(c.req.param());
+  const body = await readJson(c);
+  const validated = TournamentResultRequestSchema.parse(body);
+
+  const tournamentService = c.get('tournamentService');
+  const tournament = await tournamentService.reportResult(
+    c.get('userId'),
+    tournamentId,
+    params.round,
+    params.position,
+    validated.winner_id
+  );
+
+  return respondWithTournament(c, tournament);
+};
+
+export const standings = async (c: Context<TournamentEnv & AuthEnv>) => {
+  const tournamentId = UuidSchema.parse(c.req.param('tournamentId'));
+
+  const tournamentService = c.get('tournamentService');
+  const tournament = await tournamentService.get(tournamentId);
+  const usernames = await tournamentService.findUsernames([tournament]);
+
+  return c.json({
+    tournament_id: tournamentId,
+    status: tournament.getStatus(),
+    standings: tournament.getStandings().map((standing) => ({
+      ...toUserResponse(standing.userId, usernames),
+      seed: standing.seed,
+      wins: standing.wins,
+      losses: standing.losses,
+      placement: standing.placement,
+    })),
+  });
+};
@@ -0,0 +1,100 @@
+import { Context } from 'hono';
+import {
+  getEffectiveness,
+  POKEMON_TYPES,
+  TYPE_CHART_REVISION,
+  TypeSet,
+} from '../domain/valueobject/PokemonType';
+import { explainEffectiveness, localizedName, translateType } from '../i18n';
+import { buildETag, notModified, setCacheHeaders, STATIC_CACHE_CONTROL } from '../middleware/cache';
+import { LangEnv } from '../middleware/lang';
+import { CoverageService } from '../usecase/CoverageService';
+import { CoverageQuerySchema, TypeEffectivenessQuerySchema } from '../usecase/dto';
+
+export interface TypeEnv {
+  Variables: {
+    coverageService: CoverageService;
+  };
+}
+
+// Share of the targets' total weight, 0-100
+function share(weight: number, total: number): number {
+  return total === 0 ? 0 : Math.round((weight / total) * 1000) / 10;
+}
+
+export const chart = async (c: Context<LangEnv>) => {
+  const lang = c.get('lang');
+  const etag = buildETag('types', TYPE_CHART_REVISION, lang);
+  const cached = notModified(c, etag, STATIC_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  setCacheHeaders(c, etag, STATIC_CACHE_CONTROL);
+  return c.json({
+    types: POKEMON_TYPES,
+    type_names: POKEMON_TYPES.map((type) => translateType(lang, type)),
+    // matrix[attack][defend], in the order of `types`
+    matrix: POKEMON_TYPES.map((attack) =>
+      POKEMON_TYPES.map((defend) => getEffectiveness(attack, defend))
+    ),
+  });
+};
+
+export const effectiveness = async (c: Context<LangEnv>) => {
+  const query = TypeEffectivenessQuerySchema.parse(c.req.query());
+  const lang = c.get('lang');
+  const result = new TypeSet(query.defend[0], query.defend[1]).effectivenessOf(query.attack);
+
+  const etag = buildETag('types', TYPE_CHART_REVISION, query.attack, ...query.defend, lang);
+  const cached = notModified(c, etag, STATIC_CACHE_CONTROL);
+  if (cached) {
+    return cached;
+  }
+
+  setCacheHeaders(c, etag, STATIC_CACHE_CONTROL);
+  return c.json({
+    attack: result.attack,
+    defend: query.defend,
+    multiplier: result.getMultiplier(),
+    factors: result.factors.map((factor) => ({
+      defend: factor.defend,
+      multiplier: factor.multiplier,
+    })),
+    explanation: explainEffectiveness(lang, result),
+  });
+};
+
+export const coverage = async (c: Context<TypeEnv & LangEnv>) => {
+  const query = CoverageQuerySchema.parse(c.req.query());
+
+  const coverageService = c.get('coverageService');
+  const result = await coverageService.recommendType(query.attack, {
+    formatId: query.format,
+    availableIn: query.available_in,
+  });
+  const lang = c.get('lang');
+
+  return c.json({
+    format: result.formatId,
+    period: result.period,
+    attack: query.attack,
+    covered: share(result.covered, result.totalWeight),
+    walled: share(result.walled, result.totalWeight),
+    recommendations: result.candidates.slice(0, query.limit).map((candidate) => ({
+      type: candidate.type,
+      type_name: translateType(lang, candidate.type),
+      covered: share(candidate.covered, result.totalWeight),
+      walled: share(candidate.walled, result.totalWeight),
+      newly_covered: candidate.newlyCovered.map((target) => ({
+        form_id: target.formId,
+        name: target.name,
+        name_jp: target.nameJp,
+        display_name: localizedName(lang, target.name, target.nameJp),
+        types: target.typeset.getTypes(),
+        // Null for the whole dex, where every form counts the same
+        usage: result.formatId ? target.weight : null,
+      })),
+    })),
+  });
+};
@@ -0,0 +1,101 @@
+import { Context } from 'hono';
+import { UsageDetail } from '../domain/entity/UsageSnapshot';
+import { FormatIdSchema } from '../domain/valueobject/Format';
+import { localizedName } from '../i18n';
+import { readJson } from '../middleware/body';
+import { LangEnv } from '../middleware/lang';
+import { UsageStatsService } from '../usecase/UsageStatsService';
+import {
+  FormIdParamSchema,
+  ImportUsageQuerySchema,
+  SmogonChaosSchema,
+  UsageQuerySchema,
+} from '../usecase/dto';
+
+export interface UsageEnv {
+  Variables: {
+    usageStatsService: UsageStatsService;
+  };
+}
+
+function toDetailResponse(detail: UsageDetail) {
+  return { name: detail.name, percentage: detail.percentage };
+}
+
+export const importChaos = async (c: Context<UsageEnv>) => {
+  const query = ImportUsageQuerySchema.parse(c.req.query());
+  const body = await readJson(c);
+  const chaos = SmogonChaosSchema.parse(body);
+
+  const usageStatsService = c.get('usageStatsService');
+  const result = await usageStatsService.importChaos(chaos, query.period);
+
+  return c.json(
+    {
+      format: result.formatId,
+      period: result.period,
+      imported: result.imported,
+      unmapped: result.unmapped,
+    },
+    201
+  );
+};
+
+export const top = async (c: Context<UsageEnv & LangEnv>) => {
+  const formatId = FormatIdSchema.parse(c.req.param('format'));
+  const query = UsageQuerySchema.parse(c.req.query());
+
+  const usageStatsService = c.get('usageStatsService');
+  const result = await usageStatsService.getTop(formatId, query.limit, query.period);
+  const lang = c.get('lang');
+
+  return c.json({
+    pokemon: result.map((entry) => ({
+      form_id: entry.formId,
+      fullname: entry.name,
+      fullname_ja: entry.nameJp,
+      display_name: localizedName(lang, entry.name, entry.nameJp),
+      rank: entry.rank,
+      usage: entry.usage,
+      raw_count: entry.rawCount,
+    })),
+    total: result.length,
+  });
+};
+
+export const pokemonUsage = async (c: Context<UsageEnv>) => {
+  const formatId = FormatIdSchema.parse(c.req.param('format'));
+  const formId = FormIdParamSchema.parse(c.req.param('formId'));
+  const query = UsageQuerySchema.parse(c.req.query());
+
+  const usageStatsService = c.get('usageStatsService');
+  const result = await usageStatsService.getPokemonUsage(formatId, formId, query.period);
+  const { usage, commonSet } = result;
+
+  return c.json({
+    form_id: usage.getFormId(),
+    format: result.formatId,
+    period: result.period,
+    rank: usage.getRank(),
+    usage: usage.getUsage(),
+    raw_count: usage.getRawCount(),
+    moves: usage.getDetails('move').map(toDetailResponse),
+    items: usage.getDetails('item').map(toDetailResponse),
+    abilities: usage.getDetails('ability').map(toDetailResponse),
+    tera_types: usage.getDetails('tera_type').map(toDetailResponse),
+    natures: result.natures.map(toDetailResponse),
+    spreads: result.spreads.map((spread) => ({
+      nature: spread.nature,
+      evs: spread.evs,
+      percentage: spread.percentage,
+    })),
+    common_set: {
+      ability: commonSet.ability ?? null,
+      item: commonSet.item ?? null,
+      moves: commonSet.moves,
+      tera_type: commonSet.teraType ?? null,
+      nature: commonSet.spread?.nature ?? null,
+      evs: commonSet.spread?.evs ?? null,
+    },
+  });
+};
@@ -0,0 +1,414 @@
+import { Context } from 'hono';
+import { stream } from 'hono/streaming';
+import { Team } from '../domain/entity/Team';
+import { UserPreferences } from '../domain/entity/UserPreferences';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { PersonalBuild } from '../repository/interface/PersonalDataRepository';
+import { EmailChangeService, PendingEmailChange } from '../usecase/EmailChangeService';
+import { PersonalDataExport, PersonalDataService } from '../usecase/PersonalDataService';
+import { TeamBackupService } from '../usecase/TeamBackupService';
+import { UserPreferencesService } from '../usecase/UserPreferencesService';
+import {
+  EmailChangeRequestSchema,
+  EmailChangeTokenSchema,
+  TEAM_BACKUP_VERSION,
+  TeamBackupSchema,
+  UpdatePreferencesRequestSchema,
+} from '../usecase/dto';
+import { toTeamMemberResponse } from '../usecase/dto/teamResponse';
+
+export interface UserEnv {
+  Variables: {
+    emailChangeService: EmailChangeService;
+    personalDataService: PersonalDataService;
+    teamBackupService: TeamBackupService;
+    userPreferencesService: UserPreferencesService;
+  };
+}
+
+function toPendingEmailChange(pending: PendingEmailChange) {
+  return {
+    new_email: pending.newEmail,
+    expires_at: pending.expiresAt.toISOString(),
+  };
+}
+
+function toBackupTeam(team: Team) {
+  return {
+    team_id: team.getTeamId(),
+    team_name: team.getTeamName(),
+    pokemon: team.getPokemon().map((member) => member && toTeamMemberResponse(member)),
+    tags: team.getTags(),
+    folder: team.getFolder(),
+    notes: team.getNotes(),
+  };
+}
+
+// The archive is written one team at a time, so the response starts before every team is read
+export const exportData = async (c: Context<UserEnv & AuthEnv>) => {
+  const userId = c.get('userId');
+  const teamBackupService = c.get('teamBackupService');
+  const date = new Date().toISOString().slice(0, 10);
+
+  c.header('Content-Type', 'application/json; charset=utf-8');
+  c.header('Content-Disposition', `attachment; filename="pokedict-backup-${date}.json"`);
+
+  return stream(c, async (output) => {
+    await output.write(`{"version":${TEAM_BACKUP_VERSION},"teams":[`);
+    let first = true;
+    for await (const team of teamBackupService.exportTeams(userId)) {
+      await output.write((first ? '' : ',') + JSON.stringify(toBackupTeam(team)));
+      first = false;
+    }
+    await output.write(']}');
+  });
+};
+
+function toPersonalBuild(build: PersonalBuild) {
+  return {
+    form_id: build.formId,
+    nature: build.nature,
+    ability: build.ability,
+    item: build.item,
+    tera_type: build.teraType,
+    evs: {
+      hp: build.evs.hp,
+      attack: build.evs.attack,
+      defense: build.evs.defense,
+      special_attack: build.evs.specialAttack,
+      special_defense: build.evs.specialDefense,
+      speed: build.evs.speed,
+    },
+    ivs: {
+      hp: build.ivs.hp,
+      attack: build.ivs.attack,
+      defense: build.ivs.defense,
+      special_attack: build.ivs.specialAttack,
+      special_defense: build.ivs.specialDefense,
+      speed: build.ivs.speed,
+    },
+  };
+}
+
+function toPersonalDataResponse(data: PersonalDataExport) {
+  return {
+    format: 'pokedict-personal-data',
+    version: 1,
+    exported_at: data.exportedAt.toISOString(),
+    profile: {
+      user_id: data.profile.userId,
+      username: data.profile.username,
+      email: data.profile.email,
+      role: data.profile.role,
+      created_at: data.profile.createdAt.toISOString(),
+      updated_at: data.profile.updatedAt.toISOString(),
+    },
+    preferences: data.preferences && {
+      language: data.preferences.language,
+      format: data.preferences.format,
+      level: data.preferences.level,
+      theme: data.preferences.theme,
+      updated_at: data.preferences.updatedAt.toISOString(),
+    },
+    pending_email_change: data.pendingEmailChange && {
+      new_email: data.pendingEmailChange.newEmail,
+      expires_at: data.pendingEmailChange.expiresAt.toISOString(),
+      created_at: data.pendingEmailChange.createdAt.toISOString(),
+    },
+    sessions: data.sessions.map((token) => ({
+      token_id: token.tokenId,
+      session_id: token.sessionId,
+      user_agent: token.userAgent,
+      ip: token.ip,
+      created_at: token.createdAt.toISOString(),
+      last_used_at: token.lastUsedAt.toISOString(),
+      expires_at: token.expiresAt.toISOString(),
+      revoked: token.revoked,
+    })),
+    login_failures: data.loginFailures && {
+      failures: data.loginFailures.failures,
+      last_failed_at: data.loginFailures.lastFailedAt.toISOString(),
+    },
+    teams: data.teams.map((team) => ({
+      team_id: team.teamId,
+      team_name: team.teamName,
+      members: team.members.map((member) => ({
+        slot: member.slot,
+        form_id: member.formId,
+        terastal_type: member.terastalType,
+        notes: member.notes,
+      })),
+      tags: team.tags,
+      folder: team.folder,
+      notes: team.notes,
+      created_at: team.createdAt.toISOString(),
+      updated_at: team.updatedAt.toISOString(),
+      deleted_at: team.deletedAt?.toISOString() ?? null,
+      shared_at: team.sharedAt?.toISOString() ?? null,
+      shared_format: team.sharedFormat,
+    })),
+    box_pokemon: data.boxPokemon.map((pokemon) => ({
+      pokemon_id: pokemon.pokemonId,
+      nickname: pokemon.nickname,
+      ...toPersonalBuild(pokemon),
+      moves: pokemon.moves,
+      created_at: pokemon.createdAt.toISOString(),
+    })),
+    battle_records: data.battleRecords.map((record) => ({
+      record_id: record.recordId,
+      team_id: record.teamId,
+      format: record.format,
+      result: record.result,
+      opponent: {
+        name: record.opponentName,
+        archetype: record.opponentArchetype,
+        form_ids: record.opponentFormIds,
+      },
+      notes: record.notes,
+      played_at: record.playedAt.toISOString(),
+      created_at: record.createdAt.toISOString(),
+    })),
+    damage_benchmarks: data.damageBenchmarks.map((benchmark) => ({
+      benchmark_id: benchmark.benchmarkId,
+      format: benchmark.format,
+      name: benchmark.name,
+      role: benchmark.role,
+      ...toPersonalBuild(benchmark),
+      move: benchmark.move,
+      created_at: benchmark.createdAt.toISOString(),
+    })),
+    custom_forms: data.customForms.map((form) => ({
+      form_id: form.formId,
+      name: form.name,
+      type1: form.type1,
+      type2: form.type2,
+      base_stats: {
+        hp: form.baseStats.hp,
+        attack: form.baseStats.attack,
+        defense: form.baseStats.defense,
+        special_attack: form.baseStats.specialAttack,
+        special_defense: form.baseStats.specialDefense,
+        speed: form.baseStats.speed,
+      },
+      ability1: form.ability1,
+      ability2: form.ability2,
+      hidden_ability: form.hiddenAbility,
+      created_at: form.createdAt.toISOString(),
+      updated_at: form.updatedAt.toISOString(),
+    })),
+    likes: data.likes.map((like) => ({
+      team_id: like.teamId,
+      created_at: like.createdAt.toISOString(),
+    })),
+    comments: data.comments.map((comment) => ({
+      comment_id: comment.commentId,
+      team_id: comment.teamId,
+      parent_id: comment.parentId,
+      body: comment.body,
+      created_at: comment.createdAt.toISOString(),
+      updated_at: comment.updatedAt.toISOString(),
+      deleted_at: comment.deletedAt?.toISOString() ?? null,
+    })),
+    flags: data.flags.map((flag) => ({
+      team_id: flag.teamId,
+      reason: flag.reason,
+      created_at: flag.createdAt.toISOString(),
+    })),
+    moderation_items: data.moderationItems.map((item) => ({
+      item_id: item.itemId,
+      kind: item.kind,
+      target_id: item.targetId,
+      text: item.text,
+      reasons: item.reasons.split(','),
+      created_at: item.createdAt.toISOString(),
+    })),
+    notifications: data.notifications.map((notification) => ({
+      notification_id: notification.notificationId,
+      type: notification.type,
+      data: JSON.parse(notification.data),
+      created_at: notification.createdAt.toISOString(),
+      read_at: notification.readAt?.toISOString() ?? null,
+    })),
+    runs: data.runs.map((run) => ({
+      run_id: run.runId,
+      name: run.name,
+      game_version: run.gameVersion,
+      species_ids: run.speciesIds,
+      created_at: run.createdAt.toISOString(),
+    })),
+    draft_leagues: data.draftLeagues.map((league) => ({
+      league_id: league.leagueId,
+      name: league.name,
+      format: league.format,
+      commissioner: league.commissioner,
+      position: league.position,
+      picks: league.picks.map((pick) => ({
+        pick_number: pick.pickNumber,
+        form_id: pick.formId,
+        picked_at: pick.pickedAt.toISOString(),
+      })),
+      created_at: league.createdAt.toISOString(),
+    })),
+    tournaments: data.tournaments.map((tournament) => ({
+      tournament_id: tournament.tournamentId,
+      name: tournament.name,
+      format: tournament.format,
+      status: tournament.status,
+      organizer: tournament.organizer,
+      entry: tournament.entry
+        ? {
+            team_id: tournament.entry.teamId,
+            team_name: tournament.entry.teamName,
+            team_version: tournament.entry.teamVersion,
+            members: tournament.entry.members.map((member) => ({
+              slot: member.slot,
+              form_id: member.formId,
+              terastal_type: member.terastalType,
+            })),
+            seed: tournament.entry.seed,
+            registered_at: tournament.entry.registeredAt.toISOString(),
+          }
+        : null,
+      matches: tournament.matches.map((match) => ({
+        round: match.round,
+        position: match.position,
+        won: match.won,
+        reported_at: match.reportedAt?.toISOString() ?? null,
+      })),
+      created_at: tournament.createdAt.toISOString(),
+    })),
+    webhooks: data.webhooks.map((webhook) => ({
+      webhook_id: webhook.webhookId,
+      url: webhook.url,
+      event_types: webhook.eventTypes,
+      deliveries: webhook.deliveries.map((delivery) => ({
+        delivery_id: delivery.deliveryId,
+        event_id: delivery.eventId,
+        event_type: delivery.eventType,
+        attempt: delivery.attempt,
+        status_code: delivery.statusCode,
+        error: delivery.error,
+        succeeded: delivery.succeeded,
+        attempted_at: delivery.attemptedAt.toISOString(),
+      })),
+      created_at: webhook.createdAt.toISOString(),
+    })),
+    type_quiz: data.typeQuiz && {
+      answered: data.typeQuiz.answered,
+      correct: data.typeQuiz.correct,
+      current_streak: data.typeQuiz.currentStreak,
+      best_streak: data.typeQuiz.bestStreak,
+      updated_at: data.typeQuiz.updatedAt.toISOString(),
+      answers: data.typeQuiz.answers.map((answer) => ({
+        question_id: answer.questionId,
+        correct: answer.correct,
+        answered_at: answer.answeredAt.toISOString(),
+      })),
+    },
+  };
+}
+
+// Everything stored about the signed-in user, unlike the export above which is only the teams
+// in the shape an import takes back
+export const exportPersonalData = async (c: Context<UserEnv & AuthEnv>) => {
+  const personalDataService = c.get('personalDataService');
+  const data = await personalDataService.exportUserData(c.get('userId'));
+  const date = data.exportedAt.toISOString().slice(0, 10);
+
+  c.header('Content-Disposition', `attachment; filename="pokedict-personal-data-${date}.json"`);
+  c.header('Cache-Control', 'no-store');
+  return c.json(toPersonalDataResponse(data));
+};
+
+export const importData = async (c: Context<UserEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const archive = TeamBackupSchema.parse(body);
+
+  const teamBackupService = c.get('teamBackupService');
+  const result = await teamBackupService.restore(c.get('userId'), archive);
+
+  return c.json({ created: result.created, updated: result.updated });
+};
+
+// Mails a confirmation link to the new address and a notice with a cancel link to the old one
+export const requestEmailChange = async (c: Context<UserEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = EmailChangeRequestSchema.parse(body);
+
+  const emailChangeService = c.get('emailChangeService');
+  const pending = await emailChangeService.request(
+    c.get('userId'),
+    validated.new_email,
+    validated.password
+  );
+
+  return c.json(toPendingEmailChange(pending), 202);
+};
+
+export const getEmailChange = async (c: Context<UserEnv & AuthEnv>) => {
+  const emailChangeService = c.get('emailChangeService');
+  const pending = await emailChangeService.findPending(c.get('userId'));
+
+  return c.json({ pending: pending ? toPendingEmailChange(pending) : null });
+};
+
+export const cancelOwnEmailChange = async (c: Context<UserEnv & AuthEnv>) => {
+  const emailChangeService = c.get('emailChangeService');
+  await emailChangeService.cancelForUser(c.get('userId'));
+
+  return c.json({ message: 'Email change cancelled successfully' });
+};
+
+// Confirm and cancel come from links in the mails, so the token is the only credential
+export const confirmEmailChange = async (c: Context<UserEnv>) => {
+  const body = await readJson(c);
+  const { token } = EmailChangeTokenSchema.parse(body);
+
+  const emailChangeService = c.get('emailChangeService');
+  const result = await emailChangeService.confirm(token);
+
+  return c.json({ user_id: result.userId, email: result.email });
+};
+
+export const cancelEmailChange = async (c: Context<UserEnv>) => {
+  const body = await readJson(c);
+  const { token } = EmailChangeTokenSchema.parse(body);
+
+  const emailChangeService = c.get('emailChangeService');
+  await emailChangeService.cancel(token);
+
+  return c.json({ message: 'Email change cancelled successfully' });
+};
+
+function toPreferencesResponse(preferences: UserPreferences) {
+  return {
+    language: preferences.getLanguage(),
+    format: preferences.getFormat(),
+    calculation_mode: preferences.getCalculationMode(),
+    theme: preferences.getTheme(),
+    updated_at: preferences.getUpdatedAt()?.toISOString() ?? null,
+  };
+}
+
+export const getPreferences = async (c: Context<UserEnv & AuthEnv>) => {
+  const userPreferencesService = c.get('userPreferencesService');
+  const preferences = await userPreferencesService.get(c.get('userId'));
+
+  return c.json(toPreferencesResponse(preferences));
+};
+
+export const updatePreferences = async (c: Context<UserEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = UpdatePreferencesRequestSchema.parse(body);
+
+  const userPreferencesService = c.get('userPreferencesService');
+  const preferences = await userPreferencesService.update(c.get('userId'), {
+    language: validated.language,
+    format: validated.format,
+    calculationMode: validated.calculation_mode,
+    theme: validated.theme,
+  });
+
+  return c.json(toPreferencesResponse(preferences));
+};
@@ -0,0 +1,76 @@
+import { Context } from 'hono';
+import { Webhook } from '../domain/entity/Webhook';
+import { AuthEnv } from '../middleware/auth';
+import { readJson } from '../middleware/body';
+import { WebhookDelivery } from '../repository/interface/WebhookRepository';
+import { WebhookService } from '../usecase/WebhookService';
+import {
+  CreateWebhookRequestSchema,
+  UuidSchema,
+  WebhookDeliveriesQuerySchema,
+} from '../usecase/dto';
+
+export interface WebhookEnv {
+  Variables: {
+    webhookService: WebhookService;
+  };
+}
+
+function toWebhookResponse(webhook: Webhook) {
+  return {
+    webhook_id: webhook.getWebhookId(),
+    url: webhook.getUrl(),
+    events: webhook.getEvents(),
+    created_at: webhook.getCreatedAt().toISOString(),
+  };
+}
+
+function toDeliveryResponse(delivery: WebhookDelivery) {
+  return {
+    delivery_id: delivery.deliveryId,
+    event_id: delivery.eventId,
+    event: delivery.eventType,
+    attempt: delivery.attempt,
+    status_code: delivery.statusCode ?? null,
+    error: delivery.error ?? null,
+    succeeded: delivery.succeeded,
+    attempted_at: delivery.attemptedAt.toISOString(),
+  };
+}
+
+// The signing secret is only returned here
+export const createWebhook = async (c: Context<WebhookEnv & AuthEnv>) => {
+  const body = await readJson(c);
+  const validated = CreateWebhookRequestSchema.parse(body);
+
+  const webhookService = c.get('webhookService');
+  const webhook = await webhookService.register(c.get('userId'), validated.url, validated.events);
+
+  return c.json({ ...toWebhookResponse(webhook), secret: webhook.getSecret() }, 201);
+};
+
+export const listWebhooks = async (c: Context<WebhookEnv & AuthEnv>) => {
+  const webhookService = c.get('webhookService');
+  const webhooks = await webhookService.list(c.get('userId'));
+
+  return c.json({ webhooks: webhooks.map(toWebhookResponse) });
+};
+
+export const deleteWebhook = async (c: Context<WebhookEnv & AuthEnv>) => {
+  const webhookId = UuidSchema.parse(c.req.param('webhookId'));
+
+  const webhookService = c.get('webhookService');
+  await webhookService.delete(c.get('userId'), webhookId);
+
+  return c.json({ message: 'Webhook deleted successfully' });
+};
+
+export const listDeliveries = async (c: Context<WebhookEnv & AuthEnv>) => {
+  const webhookId = UuidSchema.parse(c.req.param('webhookId'));
+  const query = WebhookDeliveriesQuerySchema.parse(c.req.query());
+
+  const webhookService = c.get('webhookService');
+  const deliveries = await webhookService.listDeliveries(c.get('userId'), webhookId, query.limit);
+
+  return c.json({ deliveries: deliveries.map(toDeliveryResponse) });
+};
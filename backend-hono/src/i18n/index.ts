@@ -0,0 +1,74 @@
+import { ZodIssue } from 'zod';
+import { Lang, LangSchema } from '../domain/valueobject/Lang';
+import { PokemonType, TypeEffectiveness } from '../domain/valueobject/PokemonType';
+import { ERROR_MESSAGES_JA, TYPE_NAMES_JA, VALIDATION_MESSAGES_JA } from './ja';
+
+// Handlers and middleware take the language from here along with the catalogs
+export { LangSchema };
+export type { Lang };
+
+export const DEFAULT_LANG: Lang = 'en';
+
+// An explicit ?lang= wins over the Accept-Language header
+export function resolveLang(queryLang?: string, acceptLanguage?: string): Lang {
+  const explicit = LangSchema.safeParse(queryLang);
+  if (explicit.success) {
+    return explicit.data;
+  }
+
+  const preferred = (acceptLanguage ?? '')
+    .split(',')
+    .map((part) => {
+      const [tag, ...params] = part.trim().split(';');
+      const q = params.find((param) => param.trim().startsWith('q='));
+      return { tag: tag.toLowerCase(), q: q ? parseFloat(q.trim().slice(2)) : 1 };
+    })
+    .filter(({ tag, q }) => tag !== '' && !Number.isNaN(q))
+    .sort((a, b) => b.q - a.q);
+
+  for (const { tag } of preferred) {
+    const lang = LangSchema.safeParse(tag.split('-')[0]);
+    if (lang.success) {
+      return lang.data;
+    }
+  }
+  return DEFAULT_LANG;
+}
+
+// English messages come from the error itself; other languages use the catalog
+export function translateError(lang: Lang, code: string, fallback: string): string {
+  return lang === 'ja' ? (ERROR_MESSAGES_JA[code] ?? fallback) : fallback;
+}
+
+export function translateValidationIssue(lang: Lang, issue: ZodIssue): string {
+  return lang === 'ja' ? (VALIDATION_MESSAGES_JA[issue.code] ?? issue.message) : issue.message;
+}
+
+export function translateType(lang: Lang, type: PokemonType): string {
+  return lang === 'ja' ? TYPE_NAMES_JA[type] : type;
+}
+
+// e.g. "Electric is 2x vs Water and 2x vs Flying → 4x"; the total is left out for one type
+export function explainEffectiveness(lang: Lang, effectiveness: TypeEffectiveness): string {
+  const attack = translateType(lang, effectiveness.attack);
+  const factors = effectiveness.factors.map(({ defend, multiplier }) =>
+    lang === 'ja'
+      ? `${translateType(lang, defend)}に${multiplier}倍`
+      : `${multiplier}x vs ${translateType(lang, defend)}`
+  );
+  const multiplier = effectiveness.getMultiplier();
+  const dual = effectiveness.factors.length > 1;
+  if (lang === 'ja') {
+    return `${attack}は${factors.join('、')}${dual ? ` → ${multiplier}倍` : ''}`;
+  }
+  return `${attack} is ${factors.join(' and ')}${dual ? ` → ${multiplier}x` : ''}`;
+}
+
+export function localizedName(lang: Lang, name: string, nameJp?: string): string {
+  return lang === 'ja' && nameJp ? nameJp : name;
+}
+
+// Descriptions may be missing in either language; the English one is the fallback
+export function localizedText(lang: Lang, text?: string, textJp?: string): string | undefined {
+  return lang === 'ja' && textJp ? textJp : text;
+}
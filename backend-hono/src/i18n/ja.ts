@@ -0,0 +1,136 @@
+import { PokemonType } from '../domain/valueobject/PokemonType';
+
+export const TYPE_NAMES_JA: Record<PokemonType, string> = {
+  Normal: 'ノーマル',
+  Fire: 'ほのお',
+  Water: 'みず',
+  Electric: 'でんき',
+  Grass: 'くさ',
+  Ice: 'こおり',
+  Fighting: 'かくとう',
+  Poison: 'どく',
+  Ground: 'じめん',
+  Flying: 'ひこう',
+  Psychic: 'エスパー',
+  Bug: 'むし',
+  Rock: 'いわ',
+  Ghost: 'ゴースト',
+  Dragon: 'ドラゴン',
+  Dark: 'あく',
+  Steel: 'はがね',
+  Fairy: 'フェアリー',
+};
+
+// Keyed by the `code` carried by service errors
+export const ERROR_MESSAGES_JA: Record<string, string> = {
+  VALIDATION_ERROR: '入力内容に誤りがあります',
+  INTERNAL_ERROR: 'サーバー内部でエラーが発生しました',
+  INVALID_CREDENTIALS: 'メールアドレスまたはパスワードが正しくありません',
+  INVALID_TOKEN: 'トークンが無効です',
+  MISSING_TOKEN: '認証情報がありません',
+  MISSING_REFRESH_TOKEN: 'リフレッシュトークンがありません',
+  TOKEN_EXPIRED: 'トークンの有効期限が切れています',
+  TOKEN_REVOKED: 'トークンは無効化されています',
+  USERNAME_EXISTS: 'このユーザー名は既に使われています',
+  EMAIL_EXISTS: 'このメールアドレスは既に登録されています',
+  LOGIN_THROTTLED: 'ログインの失敗が続いています。しばらくしてから再度お試しください',
+  USER_NOT_FOUND: 'ユーザーが見つかりません',
+  SESSION_NOT_FOUND: 'セッションが見つかりません',
+  INVALID_PASSWORD: 'パスワードが正しくありません',
+  SAME_EMAIL: '現在と同じメールアドレスです',
+  EMAIL_CHANGE_NOT_FOUND: 'メールアドレスの変更申請が見つかりません',
+  EMAIL_CHANGE_EXPIRED: 'メールアドレスの変更申請の有効期限が切れています',
+  MAIL_UNAVAILABLE: 'メール送信が設定されていません',
+  FORBIDDEN: '管理者権限が必要です',
+  CSRF_TOKEN_MISSING: 'CSRFトークンがありません',
+  CSRF_TOKEN_INVALID: 'CSRFトークンが無効か、有効期限が切れています',
+  INVALID_API_KEY: 'APIキーが無効です',
+  INSUFFICIENT_SCOPE: 'このAPIキーでは利用できないエンドポイントです',
+  QUOTA_EXCEEDED: 'APIキーの1日あたりのリクエスト上限を超えました',
+  API_KEY_NOT_FOUND: 'APIキーが見つかりません',
+  SPECIES_NOT_FOUND: 'ポケモンが見つかりません',
+  FORM_NOT_FOUND: 'フォルムが見つかりません',
+  MOVE_NOT_FOUND: '技が見つかりません',
+  ABILITY_NOT_FOUND: '特性が見つかりません',
+  ITEM_NOT_FOUND: '道具が見つかりません',
+  SEARCH_UNAVAILABLE: '全文検索は利用できません',
+  NOT_AN_EGG_MOVE: 'この技はタマゴ技ではありません',
+  UNKNOWN_METAGAME: '対応していないルールです',
+  USAGE_NOT_FOUND: '使用率データがありません',
+  INVALID_CODE: 'チームコードが正しくありません',
+  CONSTRAINTS_UNSATISFIABLE: '条件に合うパーティを作れません',
+  TEAM_NOT_FOUND: 'チームが見つかりません',
+  OWN_TEAM: '自分のチームにはいいねできません',
+  COMMENT_NOT_FOUND: 'コメントが見つかりません',
+  NOT_COMMENT_AUTHOR: '自分のコメントのみ変更できます',
+  CONTENT_REJECTED: '公開できない内容が含まれています',
+  MODERATION_ITEM_NOT_FOUND: '審査対象が見つかりません',
+  SLOT_EMPTY: '指定した枠にポケモンがいません',
+  TEAM_FULL: 'コピー先のチームに空きがありません',
+  TEAM_VERSION_CONFLICT: 'チームが他の操作で更新されました。最新の内容を確認してください',
+  TEAM_NOT_DELETED: 'このチームはゴミ箱にありません',
+  RETENTION_EXPIRED: '保存期間（30日）を過ぎたチームは復元できません',
+  RUN_NOT_FOUND: 'チャレンジ記録が見つかりません',
+  SPECIES_ALREADY_USED: 'このポケモンは既に使用済みです',
+  SPECIES_NOT_USED: 'このポケモンは使用済みではありません',
+  INVALID_CHOICE: '選択肢にない回答です',
+  QUESTION_ALREADY_ANSWERED: 'この問題には既に回答しています',
+  BENCHMARK_NOT_FOUND: 'ベンチマークが見つかりません',
+  TOO_MANY_BENCHMARKS: '登録できるベンチマークは1つのルールにつき50件までです',
+  WEBHOOK_NOT_FOUND: 'Webhookが見つかりません',
+  TOO_MANY_WEBHOOKS: '登録できるWebhookは5件までです',
+  WEBHOOK_URL_NOT_ALLOWED: 'Webhookの送信先に内部ネットワークのホストは指定できません',
+  ROUTE_NOT_FOUND: '指定されたURLは存在しません',
+  INVALID_JSON: 'リクエストの本文が正しいJSONではありません',
+  METHOD_NOT_ALLOWED: 'このURLはそのメソッドに対応していません',
+  CONTENT_TOO_LARGE: 'リクエストの本文が大きすぎます',
+  RANGE_TOO_LARGE: '一度に同期できる範囲を超えています',
+  VERSION_NOT_FOUND: '指定されたデータバージョンは存在しません',
+  DRY_RUN_UNSUPPORTED: 'この種類の同期は変更内容の確認（dry run）に対応していません',
+  DUPLICATE_TYPES: '同じタイプを2つ指定することはできません',
+  UPSTREAM_FAILED: 'PokeAPIからの取得に失敗しました',
+  INVALID_CURSOR: 'ページの指定が正しくありません。最初のページから取得し直してください',
+  MEDIA_NOT_FOUND: 'このフォルムには該当する鳴き声・画像がありません',
+  MEDIA_PROXY_DISABLED: 'メディアプロキシは有効になっていません',
+  UNEXPECTED_MEDIA_TYPE: '取得したファイルの形式が正しくありません',
+  CUSTOM_FORM_NOT_FOUND: 'カスタムポケモンが見つかりません',
+  TOO_MANY_CUSTOM_FORMS: '登録できるカスタムポケモンは50件までです',
+  LEAGUE_NOT_FOUND: 'ドラフトリーグが見つかりません',
+  POOL_TOO_SMALL: 'プールのポケモンが全員のロースターを埋めるのに足りません',
+  BUDGET_TOO_SMALL: '予算が最も安いポケモンでロースターを埋めるのに足りません',
+  NOT_COMMISSIONER: 'この操作はリーグの管理者のみ行えます',
+  NOT_PARTICIPANT: 'このドラフトリーグに参加していません',
+  DRAFT_COMPLETE: 'ドラフトは終了しています',
+  NOT_YOUR_TURN: 'あなたの指名順ではありません',
+  NOT_IN_POOL: 'このポケモンはプールにありません',
+  ALREADY_PICKED: 'このポケモンは既に指名されています',
+  OVER_BUDGET: 'このポケモンを指名するとロースターを埋める予算が足りなくなります',
+  NO_PICKS: 'まだ指名がありません',
+  PICK_CONFLICT: 'ドラフトが他の操作で進みました。最新の状態を確認してください',
+  TOURNAMENT_NOT_FOUND: '大会が見つかりません',
+  NOT_ORGANIZER: 'この操作は大会の主催者のみ行えます',
+  REGISTRATION_CLOSED: 'この大会の参加受付は終了しています',
+  TOURNAMENT_FULL: 'この大会は定員に達しています',
+  EMPTY_TEAM: 'ポケモンのいないチームでは参加できません',
+  NOT_REGISTERED: 'この大会に参加登録していません',
+  NOT_ENOUGH_ENTRIES: '大会を開始するには2人以上の参加者が必要です',
+  MATCH_NOT_FOUND: '試合が見つかりません',
+  MATCH_NOT_READY: 'この試合はまだ対戦者が決まっていません',
+  RESULT_ALREADY_REPORTED: 'この試合の結果は既に報告されています',
+  NOT_IN_MATCH: '試合結果を報告できるのは主催者と対戦者のみです',
+  INVALID_WINNER: '勝者にはこの試合の対戦者を指定してください',
+  TOURNAMENT_CONFLICT: '大会が他の操作で更新されました。最新の状態を確認してください',
+  BATTLE_RECORD_NOT_FOUND: '対戦記録が見つかりません',
+  TOO_MANY_BATTLE_RECORDS: '登録できる対戦記録は10000件までです',
+};
+
+// Keyed by zod issue code
+export const VALIDATION_MESSAGES_JA: Record<string, string> = {
+  invalid_type: '型が正しくありません',
+  invalid_string: '形式が正しくありません',
+  invalid_enum_value: '指定できない値です',
+  too_small: '値が小さすぎるか、短すぎます',
+  too_big: '値が大きすぎるか、長すぎます',
+  unrecognized_keys: '不明な項目が含まれています',
+  custom: '値が正しくありません',
+};
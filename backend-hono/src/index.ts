@@ -1,52 +1,481 @@
-import { Hono } from 'hono';
+import { Context, Hono } from 'hono';
 import { logger } from 'hono/logger';
-import { cors } from 'hono/cors';
-import { getDb } from './db/connection';
-import { PostgresUserRepository } from './repository/postgres/PostgresUserRepository';
-import { PostgresRefreshTokenRepository } from './repository/postgres/PostgresRefreshTokenRepository';
+import { parseReplicaUrls } from './db/connection';
+import {
+  createMailer,
+  createRepositories,
+  createSearchIndex,
+} from './repository/createRepositories';
+import { CustomFormOverlayRepository } from './repository/CustomFormOverlayRepository';
+import { PokeApiClient } from './repository/pokeapi/PokeApiClient';
+import { AbilityLookupService } from './usecase/AbilityLookupService';
+import { AdminService } from './usecase/AdminService';
+import { AnalysisCacheService } from './usecase/AnalysisCacheService';
+import { ApiKeyService } from './usecase/ApiKeyService';
 import { AuthService } from './usecase/AuthService';
+import { BattleRecordService } from './usecase/BattleRecordService';
+import { BattleSimulationService } from './usecase/BattleSimulationService';
+import { BreedingService } from './usecase/BreedingService';
+import { ContentModerationService } from './usecase/ContentModerationService';
+import { CoverageService } from './usecase/CoverageService';
+import { CustomFormService } from './usecase/CustomFormService';
+import { DefensiveCoreService } from './usecase/DefensiveCoreService';
+import { DamageBenchmarkService } from './usecase/DamageBenchmarkService';
+import { DamageCalculationService } from './usecase/DamageCalculationService';
+import { DraftLeagueService } from './usecase/DraftLeagueService';
+import { EmailChangeService } from './usecase/EmailChangeService';
+import { FormMediaService } from './usecase/FormMediaService';
+import { ItemLookupService } from './usecase/ItemLookupService';
+import { MoveSearchService } from './usecase/MoveSearchService';
+import { NotificationService } from './usecase/NotificationService';
+import { PageCursorCodec } from './usecase/PageCursorCodec';
+import { PersonalDataService } from './usecase/PersonalDataService';
+import { PokemonComparisonService } from './usecase/PokemonComparisonService';
+import { PokemonImportService } from './usecase/PokemonImportService';
+import { PokemonLookupService } from './usecase/PokemonLookupService';
+import { RandomTeamService } from './usecase/RandomTeamService';
+import { RunTrackerService } from './usecase/RunTrackerService';
+import { SearchService } from './usecase/SearchService';
+import { TeamAnalysisService } from './usecase/TeamAnalysisService';
+import { TeamBackupService } from './usecase/TeamBackupService';
+import { TeamClipboardService } from './usecase/TeamClipboardService';
+import { TeamCommentService } from './usecase/TeamCommentService';
+import { TeamEventRelay } from './usecase/TeamEventRelay';
+import { TeamGalleryService } from './usecase/TeamGalleryService';
+import { TeamLegalityService } from './usecase/TeamLegalityService';
+import { TeamNotesService } from './usecase/TeamNotesService';
+import { TeamOrganizerService } from './usecase/TeamOrganizerService';
+import { TeamReportService } from './usecase/TeamReportService';
+import { TeamShareService } from './usecase/TeamShareService';
+import { TeamTrashService } from './usecase/TeamTrashService';
+import { ThreatAnalysisService } from './usecase/ThreatAnalysisService';
+import { TournamentService } from './usecase/TournamentService';
+import { TurnOrderService } from './usecase/TurnOrderService';
+import { TypeQuizService } from './usecase/TypeQuizService';
+import { UsageStatsService } from './usecase/UsageStatsService';
+import { UserPreferencesService } from './usecase/UserPreferencesService';
+import { WebhookService } from './usecase/WebhookService';
+import { ActivityEvent } from './domain/event/ActivityEvent';
+import { EventBus } from './domain/event/EventBus';
+import { TeamEvent } from './domain/event/TeamEvent';
+import { WordlistContentPolicy } from './domain/service/ContentPolicy';
 import { JwtService } from './domain/valueobject/JWT';
-import { authMiddleware, AuthEnv } from './middleware/auth';
-import { errorHandler } from './middleware/error';
+import { apiKeyMiddleware, ApiKeyEnv } from './middleware/apiKey';
+import {
+  adminMiddleware,
+  authMiddleware,
+  AuthEnv,
+  createCredentialExtractor,
+  optionalAuthMiddleware,
+} from './middleware/auth';
+import { compressMiddleware } from './middleware/compress';
+import { csrfMiddleware, isSafeMethod } from './middleware/csrf';
+import { errorHandler, notFoundHandler } from './middleware/error';
+import { langMiddleware, LangEnv } from './middleware/lang';
+import { preferencesMiddleware, PreferencesEnv } from './middleware/preferences';
+import { getReadConsistency } from './middleware/readConsistency';
+import { requestIdMiddleware, RequestIdEnv } from './middleware/requestId';
+import { corsMiddleware, securityHeadersMiddleware } from './middleware/security';
+import * as abilityHandler from './handler/abilityHandler';
+import { AbilityEnv } from './handler/abilityHandler';
+import * as adminHandler from './handler/adminHandler';
+import { AdminEnv } from './handler/adminHandler';
+import * as apiKeyHandler from './handler/apiKeyHandler';
 import * as authHandler from './handler/authHandler';
+import * as battleRecordHandler from './handler/battleRecordHandler';
+import { BattleRecordEnv } from './handler/battleRecordHandler';
+import * as breedingHandler from './handler/breedingHandler';
+import { BreedingEnv } from './handler/breedingHandler';
+import * as customFormHandler from './handler/customFormHandler';
+import { CustomFormEnv } from './handler/customFormHandler';
+import * as damageHandler from './handler/damageHandler';
+import { DamageEnv } from './handler/damageHandler';
+import * as draftLeagueHandler from './handler/draftLeagueHandler';
+import { DraftLeagueEnv } from './handler/draftLeagueHandler';
+import * as itemHandler from './handler/itemHandler';
+import { ItemEnv } from './handler/itemHandler';
+import * as moderationHandler from './handler/moderationHandler';
+import { ModerationEnv } from './handler/moderationHandler';
+import * as moveHandler from './handler/moveHandler';
+import { MoveEnv } from './handler/moveHandler';
+import * as notificationHandler from './handler/notificationHandler';
+import { NotificationEnv } from './handler/notificationHandler';
+import * as pokemonHandler from './handler/pokemonHandler';
+import { PokemonEnv } from './handler/pokemonHandler';
+import * as quizHandler from './handler/quizHandler';
+import { QuizEnv } from './handler/quizHandler';
+import * as runHandler from './handler/runHandler';
+import { RunEnv } from './handler/runHandler';
+import * as searchHandler from './handler/searchHandler';
+import { SearchEnv } from './handler/searchHandler';
+import * as teamHandler from './handler/teamHandler';
+import { TeamEnv } from './handler/teamHandler';
+import * as tournamentHandler from './handler/tournamentHandler';
+import { TournamentEnv } from './handler/tournamentHandler';
+import * as typeHandler from './handler/typeHandler';
+import { TypeEnv } from './handler/typeHandler';
+import * as usageHandler from './handler/usageHandler';
+import { UsageEnv } from './handler/usageHandler';
+import * as userHandler from './handler/userHandler';
+import { UserEnv } from './handler/userHandler';
+import * as webhookHandler from './handler/webhookHandler';
+import { WebhookEnv } from './handler/webhookHandler';
 
 // Cloudflare Workers environment
 type Bindings = {
   DATABASE_URL: string;
+  // Comma-separated Postgres read replicas; reads go to the primary without them
+  DATABASE_REPLICA_URLS?: string;
   JWT_SECRET: string;
   GEMINI_API_KEY?: string;
+  // Full-text search; /api/search answers 503 without it
+  MEILISEARCH_URL?: string;
+  MEILISEARCH_API_KEY?: string;
+  // Comma-separated list of frontend origins
   ALLOWED_ORIGIN: string;
   ENVIRONMENT: string;
+  // Set to "unicode" to accept Japanese usernames
+  USERNAME_MODE?: string;
+  // Set to "on" to require CSRF tokens on cookie-based routes
+  CSRF_PROTECTION?: string;
+  // PokeAPI entries an admin sync imports at once (default 4)
+  SYNC_CONCURRENCY?: string;
+  // Set to "on" to serve cries and sprites through /api/pokemon/forms/:formId/media/:asset
+  MEDIA_PROXY?: string;
+  // Set to "session" for server-side sessions in signed cookies instead of JWTs
+  AUTH_MODE?: string;
+  // Mail via Resend; development prints mails to the console without them
+  RESEND_API_KEY?: string;
+  MAIL_FROM?: string;
+  // The frontend, for links in mails
+  APP_URL?: string;
+  // Set to "on" to compress responses here as well as at the Cloudflare edge
+  COMPRESSION?: string;
+  // Smallest response body in bytes worth compressing (default 1024)
+  COMPRESSION_MIN_SIZE?: string;
 };
 
-type Variables = AuthEnv['Variables'];
+type Variables = AuthEnv['Variables'] &
+  AbilityEnv['Variables'] &
+  AdminEnv['Variables'] &
+  ApiKeyEnv['Variables'] &
+  RequestIdEnv['Variables'] &
+  LangEnv['Variables'] &
+  BattleRecordEnv['Variables'] &
+  BreedingEnv['Variables'] &
+  CustomFormEnv['Variables'] &
+  DamageEnv['Variables'] &
+  DraftLeagueEnv['Variables'] &
+  ItemEnv['Variables'] &
+  ModerationEnv['Variables'] &
+  MoveEnv['Variables'] &
+  NotificationEnv['Variables'] &
+  PokemonEnv['Variables'] &
+  PreferencesEnv['Variables'] &
+  QuizEnv['Variables'] &
+  RunEnv['Variables'] &
+  SearchEnv['Variables'] &
+  TeamEnv['Variables'] &
+  TournamentEnv['Variables'] &
+  TypeEnv['Variables'] &
+  UsageEnv['Variables'] &
+  UserEnv['Variables'] &
+  WebhookEnv['Variables'];
 
 const app = new Hono<{ Bindings: Bindings; Variables: Variables }>();
 
+// One of the cron triggers in wrangler.toml; the other purges the trash and old sessions of
+// deleted accounts
+const TEAM_EVENT_RELAY_CRON = '*/5 * * * *';
+
+// Workers keep running a task handed to waitUntil after the response is sent; the Node.js
+// server has no execution context and simply lets the promise finish
+function runInBackground(c: Context, task: Promise<void>): void {
+  const logged = task.catch((error) =>
+    console.error(`[${c.get('requestId')}] Background task failed:`, error)
+  );
+  try {
+    c.executionCtx.waitUntil(logged);
+  } catch {
+    // Not running on Workers
+  }
+}
+
+// A positive integer, or the default when unset or invalid
+function parseSyncConcurrency(value?: string): number | undefined {
+  const concurrency = Number(value);
+  return Number.isInteger(concurrency) && concurrency > 0 ? concurrency : undefined;
+}
+
 // Global middleware
+app.use('*', requestIdMiddleware);
 app.use('*', logger());
-app.use('*', async (c, next) => {
-  const allowedOrigin = c.env.ALLOWED_ORIGIN || 'http://localhost:5173';
-
-  return cors({
-    origin: allowedOrigin,
-    credentials: true,
-    allowMethods: ['GET', 'POST', 'PUT', 'DELETE', 'OPTIONS'],
-    allowHeaders: ['Authorization', 'Content-Type'],
-    maxAge: 3600,
-  })(c, next);
-});
+app.use('*', langMiddleware);
+app.use('*', securityHeadersMiddleware);
+app.use('*', corsMiddleware);
+app.use('*', compressMiddleware);
 
 // Initialize dependencies per request
 app.use('*', async (c, next) => {
-  const db = getDb(c.env.DATABASE_URL);
-  const userRepository = new PostgresUserRepository(db);
-  const refreshTokenRepository = new PostgresRefreshTokenRepository(db);
+  const {
+    abilityRepository,
+    analysisCacheRepository,
+    apiKeyRepository,
+    battleRecordRepository,
+    customFormRepository,
+    damageBenchmarkRepository,
+    dataImportRepository,
+    datasetSnapshotRepository,
+    draftLeagueRepository,
+    emailChangeRepository,
+    itemRepository,
+    loginFailureRepository,
+    moderationQueueRepository,
+    moveRepository,
+    notificationRepository,
+    personalDataRepository,
+    pokemonFormRepository: formRepository,
+    pokemonSpeciesRepository: speciesRepository,
+    refreshTokenRepository,
+    runTrackerRepository,
+    teamCommentRepository,
+    teamEventOutboxRepository,
+    teamFlagRepository,
+    teamLikeRepository,
+    teamRepository,
+    tournamentRepository,
+    typeQuizRepository,
+    usageStatsRepository,
+    userPreferencesRepository,
+    userRepository,
+    webhookRepository,
+  } = await createRepositories(c.env.DATABASE_URL, {
+    replicaUrls: parseReplicaUrls(
+      c.env.DATABASE_REPLICA_URLS ??
+        (typeof process !== 'undefined' ? process.env.DATABASE_REPLICA_URLS : undefined)
+    ),
+    consistency: getReadConsistency(c),
+  });
+  // Teams and calculations also take the signed-in user's custom forms
+  const userFormRepository = new CustomFormOverlayRepository(
+    formRepository,
+    customFormRepository,
+    () => c.get('userId')
+  );
   const jwtService = new JwtService(c.env.JWT_SECRET);
-  const authService = new AuthService(userRepository, refreshTokenRepository, jwtService);
+  const pageCursorCodec = new PageCursorCodec(c.env.JWT_SECRET);
+  const authService = new AuthService(
+    userRepository,
+    refreshTokenRepository,
+    loginFailureRepository,
+    jwtService,
+    c.env.USERNAME_MODE === 'unicode' ? 'unicode' : 'ascii'
+  );
+  const breedingService = new BreedingService(speciesRepository);
+  const emailChangeService = new EmailChangeService(
+    userRepository,
+    emailChangeRepository,
+    createMailer(c.env.RESEND_API_KEY, c.env.MAIL_FROM, c.env.ENVIRONMENT || 'development'),
+    c.env.APP_URL
+  );
+  const pokemonLookupService = new PokemonLookupService(
+    formRepository,
+    speciesRepository,
+    dataImportRepository,
+    pageCursorCodec
+  );
+  const teamLegalityService = new TeamLegalityService(userFormRepository, speciesRepository);
+  const randomTeamService = new RandomTeamService(formRepository, speciesRepository);
+  const runTrackerService = new RunTrackerService(
+    runTrackerRepository,
+    formRepository,
+    speciesRepository
+  );
+  const draftLeagueService = new DraftLeagueService(
+    draftLeagueRepository,
+    userRepository,
+    formRepository,
+    teamRepository,
+    teamLegalityService
+  );
+  const tournamentService = new TournamentService(
+    tournamentRepository,
+    teamRepository,
+    userRepository
+  );
+  const battleRecordService = new BattleRecordService(
+    battleRecordRepository,
+    teamRepository,
+    formRepository
+  );
+  const coverageService = new CoverageService(formRepository, usageStatsRepository);
+  const defensiveCoreService = new DefensiveCoreService(formRepository);
+  const usageStatsService = new UsageStatsService(
+    usageStatsRepository,
+    formRepository,
+    analysisCacheRepository
+  );
+  const analysisCacheService = new AnalysisCacheService(
+    analysisCacheRepository,
+    dataImportRepository
+  );
+  const moveSearchService = new MoveSearchService(
+    moveRepository,
+    speciesRepository,
+    dataImportRepository
+  );
+  const abilityLookupService = new AbilityLookupService(
+    abilityRepository,
+    formRepository,
+    dataImportRepository
+  );
+  const itemLookupService = new ItemLookupService(itemRepository, dataImportRepository);
+  const threatAnalysisService = new ThreatAnalysisService(
+    userFormRepository,
+    moveRepository,
+    usageStatsRepository
+  );
+  const pokemonComparisonService = new PokemonComparisonService(
+    formRepository,
+    speciesRepository,
+    moveRepository,
+    usageStatsRepository
+  );
+  const damageCalculationService = new DamageCalculationService(userFormRepository, moveRepository);
+  const battleSimulationService = new BattleSimulationService(userFormRepository, moveRepository);
+  const damageBenchmarkService = new DamageBenchmarkService(
+    damageBenchmarkRepository,
+    userFormRepository,
+    moveRepository,
+    damageCalculationService
+  );
+  const turnOrderService = new TurnOrderService(userFormRepository, moveRepository);
+  const teamAnalysisService = new TeamAnalysisService(userFormRepository, moveRepository);
+  const teamReportService = new TeamReportService(teamAnalysisService, threatAnalysisService);
+  const teamShareService = new TeamShareService(moveRepository);
+  const webhookService = new WebhookService(webhookRepository);
+  const teamEvents = new EventBus<TeamEvent>();
+  // Deliveries retry for several seconds, so they must not hold up the response
+  teamEvents.subscribe(async (event) => runInBackground(c, webhookService.dispatch(event)));
+  const teamEventRelay = new TeamEventRelay(teamEventOutboxRepository, teamRepository, teamEvents);
+  const notificationService = new NotificationService(notificationRepository);
+  const userPreferencesService = new UserPreferencesService(userPreferencesRepository);
+  const personalDataService = new PersonalDataService(personalDataRepository, userRepository);
+  const activityEvents = new EventBus<ActivityEvent>();
+  activityEvents.subscribe((event) => notificationService.handle(event));
+  const teamBackupService = new TeamBackupService(teamRepository, activityEvents);
+  const teamClipboardService = new TeamClipboardService(teamRepository);
+  const contentModerationService = new ContentModerationService(
+    new WordlistContentPolicy(),
+    moderationQueueRepository,
+    teamRepository,
+    teamCommentRepository
+  );
+  const teamCommentService = new TeamCommentService(
+    teamRepository,
+    teamCommentRepository,
+    contentModerationService,
+    activityEvents
+  );
+  const teamGalleryService = new TeamGalleryService(
+    teamRepository,
+    teamLikeRepository,
+    teamFlagRepository,
+    contentModerationService,
+    pageCursorCodec,
+    activityEvents
+  );
+  const teamNotesService = new TeamNotesService(teamRepository);
+  const teamOrganizerService = new TeamOrganizerService(teamRepository);
+  const teamTrashService = new TeamTrashService(teamRepository);
+  const typeQuizService = new TypeQuizService(typeQuizRepository);
+  const searchIndex = createSearchIndex(c.env.MEILISEARCH_URL, c.env.MEILISEARCH_API_KEY);
+  const searchService = new SearchService(searchIndex);
+  const pokeApi = new PokeApiClient();
+  const pokemonImportService = new PokemonImportService(
+    pokeApi,
+    speciesRepository,
+    formRepository,
+    moveRepository,
+    abilityRepository,
+    itemRepository,
+    datasetSnapshotRepository,
+    searchIndex
+  );
+  const adminService = new AdminService(
+    pokemonImportService,
+    formRepository,
+    dataImportRepository,
+    datasetSnapshotRepository,
+    analysisCacheRepository,
+    parseSyncConcurrency(
+      c.env.SYNC_CONCURRENCY ??
+        (typeof process !== 'undefined' ? process.env.SYNC_CONCURRENCY : undefined)
+    )
+  );
+  const apiKeyService = new ApiKeyService(apiKeyRepository);
+  const customFormService = new CustomFormService(customFormRepository);
+  const formMediaService = new FormMediaService(
+    formRepository,
+    pokeApi,
+    (c.env.MEDIA_PROXY ??
+      (typeof process !== 'undefined' ? process.env.MEDIA_PROXY : undefined)) === 'on'
+  );
 
+  c.set('abilityLookupService', abilityLookupService);
+  c.set('adminService', adminService);
+  c.set('analysisCacheService', analysisCacheService);
+  c.set('apiKeyService', apiKeyService);
   c.set('authService', authService);
+  c.set('battleRecordService', battleRecordService);
+  c.set('battleSimulationService', battleSimulationService);
+  c.set('breedingService', breedingService);
+  c.set('contentModerationService', contentModerationService);
+  c.set('coverageService', coverageService);
+  c.set('customFormService', customFormService);
+  c.set(
+    'credentialExtractor',
+    createCredentialExtractor(c.env.AUTH_MODE === 'session' ? 'session' : 'jwt', c.env.JWT_SECRET)
+  );
+  c.set('damageBenchmarkService', damageBenchmarkService);
+  c.set('damageCalculationService', damageCalculationService);
+  c.set('draftLeagueService', draftLeagueService);
+  c.set('defensiveCoreService', defensiveCoreService);
+  c.set('emailChangeService', emailChangeService);
+  c.set('formMediaService', formMediaService);
+  c.set('itemLookupService', itemLookupService);
+  c.set('moveSearchService', moveSearchService);
+  c.set('notificationService', notificationService);
+  c.set('personalDataService', personalDataService);
+  c.set('pokemonComparisonService', pokemonComparisonService);
+  c.set('pokemonLookupService', pokemonLookupService);
+  c.set('randomTeamService', randomTeamService);
+  c.set('runTrackerService', runTrackerService);
+  c.set('searchService', searchService);
+  c.set('teamAnalysisService', teamAnalysisService);
+  c.set('teamBackupService', teamBackupService);
+  c.set('teamClipboardService', teamClipboardService);
+  c.set('teamCommentService', teamCommentService);
+  c.set('teamGalleryService', teamGalleryService);
+  c.set('teamLegalityService', teamLegalityService);
+  c.set('teamNotesService', teamNotesService);
+  c.set('teamOrganizerService', teamOrganizerService);
+  c.set('teamReportService', teamReportService);
+  c.set('teamShareService', teamShareService);
+  c.set('teamTrashService', teamTrashService);
+  c.set('threatAnalysisService', threatAnalysisService);
+  c.set('tournamentService', tournamentService);
+  c.set('turnOrderService', turnOrderService);
+  c.set('typeQuizService', typeQuizService);
+  c.set('usageStatsService', usageStatsService);
+  c.set('userPreferencesService', userPreferencesService);
+  c.set('webhookService', webhookService);
   await next();
+
+  // Team events saved by this request sit in the outbox until relayed
+  if (!isSafeMethod(c)) {
+    runInBackground(c, teamEventRelay.relay().then(() => undefined));
+  }
 });
 
 // Health check
@@ -55,18 +484,270 @@ app.get('/health', (c) => {
 });
 
 // Auth routes
+// The refresh token cookie makes these routes forgeable from other sites; more groups join as
+// the frontend moves from bearer tokens to cookies
+app.use('/api/auth/*', csrfMiddleware('auth'));
+
+app.get('/api/auth/csrf-token', authHandler.csrfToken);
 app.post('/api/auth/register', authHandler.register);
 app.post('/api/auth/login', authHandler.login);
 app.post('/api/auth/refresh', authHandler.refresh);
 app.post('/api/auth/logout', authHandler.logout);
+app.delete('/api/auth/account', authMiddleware, authHandler.deleteAccount);
+app.get('/api/auth/sessions', authMiddleware, authHandler.listSessions);
+app.post('/api/auth/sessions/revoke-others', authMiddleware, authHandler.revokeOtherSessions);
+app.delete('/api/auth/sessions/:sessionId', authMiddleware, authHandler.revokeSession);
+
+// Pokedex routes; these and the usage stats routes also serve third-party apps with API keys
+app.get('/api/pokemon/forms', apiKeyMiddleware('pokemon'), pokemonHandler.listForms);
+app.get('/api/pokemon/forms/export', apiKeyMiddleware('pokemon'), pokemonHandler.exportForms);
+app.post('/api/pokemon/batch', apiKeyMiddleware('pokemon'), pokemonHandler.batchForms);
+app.get('/api/pokemon/forms/:formId', apiKeyMiddleware('pokemon'), pokemonHandler.getForm);
+app.get(
+  '/api/pokemon/forms/:formId/media',
+  apiKeyMiddleware('pokemon'),
+  pokemonHandler.getFormMedia
+);
+app.get(
+  '/api/pokemon/forms/:formId/media/:asset',
+  apiKeyMiddleware('pokemon'),
+  pokemonHandler.getFormMediaAsset
+);
+app.get(
+  '/api/pokemon/dex/:dex/:number',
+  apiKeyMiddleware('pokemon'),
+  pokemonHandler.getFormByDexNumber
+);
+app.get('/api/pokemon/compare', apiKeyMiddleware('pokemon'), pokemonHandler.compare);
+app.get('/api/pokemon/defensive-cores', apiKeyMiddleware('pokemon'), pokemonHandler.defensiveCores);
+app.get(
+  '/api/pokemon/encounter-locations',
+  apiKeyMiddleware('pokemon'),
+  pokemonHandler.encounterLocations
+);
+app.get('/api/moves', apiKeyMiddleware('pokemon'), moveHandler.search);
+app.get('/api/abilities', apiKeyMiddleware('pokemon'), abilityHandler.search);
+app.get('/api/abilities/:name', apiKeyMiddleware('pokemon'), abilityHandler.getAbility);
+app.get('/api/abilities/:name/pokemon', apiKeyMiddleware('pokemon'), abilityHandler.holders);
+app.get('/api/items', apiKeyMiddleware('pokemon'), itemHandler.search);
+app.get('/api/items/:name', apiKeyMiddleware('pokemon'), itemHandler.getItem);
+app.get('/api/search', apiKeyMiddleware('pokemon'), searchHandler.search);
+app.get('/api/types/chart', apiKeyMiddleware('types'), typeHandler.chart);
+app.get('/api/types/effectiveness', apiKeyMiddleware('types'), typeHandler.effectiveness);
+app.get('/api/types/coverage', apiKeyMiddleware('types'), typeHandler.coverage);
+app.get('/api/quiz/types/question', quizHandler.question);
+
+// Team routes; the analysis routes fill in what a request leaves out from a signed-in user's
+// preferences
+app.get('/api/formats', teamHandler.formats);
+app.post('/api/teams/validate', optionalAuthMiddleware, teamHandler.validate);
+app.post(
+  '/api/teams/validate/format',
+  optionalAuthMiddleware,
+  preferencesMiddleware,
+  teamHandler.validateForFormat
+);
+app.post('/api/teams/threats', optionalAuthMiddleware, preferencesMiddleware, teamHandler.threats);
+app.post(
+  '/api/teams/archetype',
+  optionalAuthMiddleware,
+  preferencesMiddleware,
+  teamHandler.archetype
+);
+app.post('/api/teams/report', optionalAuthMiddleware, preferencesMiddleware, teamHandler.report);
+app.post('/api/teams/random', optionalAuthMiddleware, preferencesMiddleware, teamHandler.random);
+app.post('/api/teams/code', teamHandler.encodeCode);
+app.get('/api/teams/code/:code', teamHandler.decodeCode);
+app.get('/api/teams/shared', optionalAuthMiddleware, teamHandler.listShared);
+app.get('/api/teams/:teamId/comments', teamHandler.listComments);
+
+// Tournament routes; anyone can follow an event, signed in or not
+app.get('/api/tournaments', tournamentHandler.listTournaments);
+app.get('/api/tournaments/:tournamentId', optionalAuthMiddleware, tournamentHandler.getTournament);
+app.get('/api/tournaments/:tournamentId/standings', tournamentHandler.standings);
+
+// Breeding routes
+app.get('/api/breeding/compatibility', breedingHandler.compatibility);
+app.get('/api/breeding/species/:speciesId/egg-move-parents', breedingHandler.eggMoveParents);
+
+// Damage calculation routes
+app.post('/api/damage/batch', optionalAuthMiddleware, preferencesMiddleware, damageHandler.batch);
+app.post(
+  '/api/damage/turn-order',
+  optionalAuthMiddleware,
+  preferencesMiddleware,
+  damageHandler.turnOrder
+);
+app.post(
+  '/api/damage/simulate',
+  optionalAuthMiddleware,
+  preferencesMiddleware,
+  damageHandler.simulate
+);
+app.post(
+  '/api/damage/simulate/teams',
+  optionalAuthMiddleware,
+  preferencesMiddleware,
+  damageHandler.simulateTeams
+);
+
+// Usage stats routes
+app.get('/api/usage/:format/top', apiKeyMiddleware('usage'), usageHandler.top);
+app.get('/api/usage/:format/pokemon/:formId', apiKeyMiddleware('usage'), usageHandler.pokemonUsage);
 
 // Protected routes
 app.get('/api/users/me', authMiddleware, (c) => {
   const userId = c.get('userId');
   return c.json({ userId });
 });
+app.get('/api/users/me/export', authMiddleware, userHandler.exportData);
+app.get('/api/users/me/personal-data', authMiddleware, userHandler.exportPersonalData);
+app.get('/api/users/me/preferences', authMiddleware, userHandler.getPreferences);
+app.patch('/api/users/me/preferences', authMiddleware, userHandler.updatePreferences);
+app.get('/api/users/me/email-change', authMiddleware, userHandler.getEmailChange);
+app.post('/api/users/me/email-change', authMiddleware, userHandler.requestEmailChange);
+app.delete('/api/users/me/email-change', authMiddleware, userHandler.cancelOwnEmailChange);
+app.post('/api/users/email-change/confirm', userHandler.confirmEmailChange);
+app.post('/api/users/email-change/cancel', userHandler.cancelEmailChange);
+app.post('/api/users/me/import', authMiddleware, userHandler.importData);
+app.get('/api/notifications', authMiddleware, notificationHandler.list);
+app.get('/api/notifications/unread-count', authMiddleware, notificationHandler.unreadCount);
+app.post('/api/notifications/read', authMiddleware, notificationHandler.markRead);
+app.post('/api/notifications/read-all', authMiddleware, notificationHandler.markAllRead);
+app.get('/api/teams', authMiddleware, teamHandler.listTeams);
+app.get('/api/teams/tags', authMiddleware, teamHandler.listTags);
+app.get('/api/teams/folders', authMiddleware, teamHandler.listFolders);
+app.post('/api/teams/organize', authMiddleware, teamHandler.organize);
+app.get('/api/teams/trash', authMiddleware, teamHandler.listDeleted);
+app.delete('/api/teams/:teamId', authMiddleware, teamHandler.deleteTeam);
+app.post('/api/teams/:teamId/restore', authMiddleware, teamHandler.restoreTeam);
+app.post('/api/teams/:teamId/clone', authMiddleware, teamHandler.cloneTeam);
+app.put('/api/teams/:teamId/tags', authMiddleware, teamHandler.setTags);
+app.put('/api/teams/:teamId/folder', authMiddleware, teamHandler.moveToFolder);
+app.put('/api/teams/:teamId/notes', authMiddleware, teamHandler.setNotes);
+app.post('/api/teams/:teamId/share', authMiddleware, teamHandler.shareTeam);
+app.delete('/api/teams/:teamId/share', authMiddleware, teamHandler.unshareTeam);
+app.put('/api/teams/:teamId/like', authMiddleware, teamHandler.likeTeam);
+app.delete('/api/teams/:teamId/like', authMiddleware, teamHandler.unlikeTeam);
+app.post('/api/teams/:teamId/flags', authMiddleware, teamHandler.flagTeam);
+app.post('/api/teams/:teamId/comments', authMiddleware, teamHandler.createComment);
+app.patch('/api/teams/:teamId/comments/:commentId', authMiddleware, teamHandler.editComment);
+app.delete('/api/teams/:teamId/comments/:commentId', authMiddleware, teamHandler.deleteComment);
+app.post('/api/teams/:teamId/pokemon/:slot/copy', authMiddleware, teamHandler.copyMember);
+app.put('/api/teams/:teamId/pokemon/:slot/notes', authMiddleware, teamHandler.setMemberNotes);
+app.post('/api/runs', authMiddleware, runHandler.createRun);
+app.get('/api/runs', authMiddleware, runHandler.listRuns);
+app.get('/api/runs/:runId', authMiddleware, runHandler.getRun);
+app.delete('/api/runs/:runId', authMiddleware, runHandler.deleteRun);
+app.put('/api/runs/:runId/used/:speciesId', authMiddleware, runHandler.markUsed);
+app.delete('/api/runs/:runId/used/:speciesId', authMiddleware, runHandler.unmarkUsed);
+app.get('/api/runs/:runId/pokemon', authMiddleware, runHandler.availableForms);
+app.post('/api/quiz/types/answers', authMiddleware, quizHandler.answer);
+app.get('/api/quiz/types/score', authMiddleware, quizHandler.score);
+app.post('/api/custom-forms', authMiddleware, customFormHandler.createCustomForm);
+app.get('/api/custom-forms', authMiddleware, customFormHandler.listCustomForms);
+app.get('/api/custom-forms/:formId', authMiddleware, customFormHandler.getCustomForm);
+app.put('/api/custom-forms/:formId', authMiddleware, customFormHandler.updateCustomForm);
+app.delete('/api/custom-forms/:formId', authMiddleware, customFormHandler.deleteCustomForm);
+app.post('/api/draft-leagues', authMiddleware, draftLeagueHandler.createDraftLeague);
+app.get('/api/draft-leagues', authMiddleware, draftLeagueHandler.listDraftLeagues);
+app.get('/api/draft-leagues/:leagueId', authMiddleware, draftLeagueHandler.getDraftLeague);
+app.delete('/api/draft-leagues/:leagueId', authMiddleware, draftLeagueHandler.deleteDraftLeague);
+app.post('/api/draft-leagues/:leagueId/picks', authMiddleware, draftLeagueHandler.makePick);
+app.delete('/api/draft-leagues/:leagueId/picks/last', authMiddleware, draftLeagueHandler.undoPick);
+app.post('/api/draft-leagues/:leagueId/validate', authMiddleware, draftLeagueHandler.validateTeam);
+app.post('/api/battles', authMiddleware, battleRecordHandler.logBattle);
+app.get('/api/battles', authMiddleware, battleRecordHandler.listBattles);
+app.get('/api/battles/stats', authMiddleware, battleRecordHandler.stats);
+app.get('/api/battles/stats/matchups', authMiddleware, battleRecordHandler.matchups);
+app.get('/api/battles/:recordId', authMiddleware, battleRecordHandler.getBattle);
+app.delete('/api/battles/:recordId', authMiddleware, battleRecordHandler.deleteBattle);
+app.post('/api/tournaments', authMiddleware, tournamentHandler.createTournament);
+app.delete('/api/tournaments/:tournamentId', authMiddleware, tournamentHandler.deleteTournament);
+app.put('/api/tournaments/:tournamentId/entry', authMiddleware, tournamentHandler.register);
+app.delete('/api/tournaments/:tournamentId/entry', authMiddleware, tournamentHandler.withdraw);
+app.post('/api/tournaments/:tournamentId/start', authMiddleware, tournamentHandler.startTournament);
+app.post(
+  '/api/tournaments/:tournamentId/matches/:round/:position/result',
+  authMiddleware,
+  tournamentHandler.reportResult
+);
+app.post('/api/webhooks', authMiddleware, webhookHandler.createWebhook);
+app.get('/api/webhooks', authMiddleware, webhookHandler.listWebhooks);
+app.delete('/api/webhooks/:webhookId', authMiddleware, webhookHandler.deleteWebhook);
+app.get('/api/webhooks/:webhookId/deliveries', authMiddleware, webhookHandler.listDeliveries);
+app.post('/api/damage/benchmarks', authMiddleware, damageHandler.createBenchmark);
+app.get('/api/damage/benchmarks', authMiddleware, damageHandler.listBenchmarks);
+app.delete('/api/damage/benchmarks/:benchmarkId', authMiddleware, damageHandler.deleteBenchmark);
+app.post('/api/damage/benchmarks/run', authMiddleware, damageHandler.runBenchmarks);
+
+// Admin routes
+app.use('/api/admin/*', authMiddleware, adminMiddleware);
+app.post('/api/admin/sync', adminHandler.sync);
+app.get('/api/admin/imports', adminHandler.imports);
+app.patch('/api/admin/pokemon/forms/:formId', adminHandler.updateForm);
+app.post('/api/admin/rollback', adminHandler.rollback);
+app.post('/api/admin/cache/purge', adminHandler.purgeCache);
+app.post('/api/admin/name-index/rebuild', adminHandler.rebuildNameIndex);
+app.post('/api/admin/usage/import', usageHandler.importChaos);
+app.post('/api/admin/api-keys', apiKeyHandler.issue);
+app.get('/api/admin/api-keys', apiKeyHandler.list);
+app.delete('/api/admin/api-keys/:keyId', apiKeyHandler.revoke);
+app.get('/api/admin/teams/flagged', teamHandler.listFlagged);
+app.post('/api/admin/teams/:teamId/take-down', teamHandler.takeDownTeam);
+app.delete('/api/admin/teams/:teamId/flags', teamHandler.dismissFlags);
+app.get('/api/admin/moderation', moderationHandler.listQueue);
+app.post('/api/admin/moderation/:itemId/approve', moderationHandler.approve);
+app.post('/api/admin/moderation/:itemId/take-down', moderationHandler.takeDown);
 
 // Error handling
+app.notFound(notFoundHandler);
 app.onError(errorHandler);
 
-export default app;
+// Daily cron trigger (wrangler.toml): removes teams that have been in the trash too long
+export async function purgeDeletedTeams(databaseUrl: string): Promise<void> {
+  const { teamRepository } = await createRepositories(databaseUrl);
+  const teamTrashService = new TeamTrashService(teamRepository);
+  const purged = await teamTrashService.purgeExpired();
+  console.log(`Purged ${purged} deleted teams`);
+}
+
+// Daily cron trigger too: deletes the sessions kept from accounts deleted long enough ago
+export async function scrubDeletedAccounts(databaseUrl: string): Promise<void> {
+  const { personalDataRepository, userRepository } = await createRepositories(databaseUrl);
+  const personalDataService = new PersonalDataService(personalDataRepository, userRepository);
+  const scrubbed = await personalDataService.scrubDeletedAccounts();
+  console.log(`Scrubbed ${scrubbed} sessions of deleted accounts`);
+}
+
+// Frequent cron trigger (wrangler.toml): publishes team events a request saved but never relayed,
+// e.g. because it crashed, and forgets events published long ago
+export async function relayTeamEvents(databaseUrl: string): Promise<void> {
+  const { teamEventOutboxRepository, teamRepository, webhookRepository } =
+    await createRepositories(databaseUrl);
+  const webhookService = new WebhookService(webhookRepository);
+  const deliveries: Promise<void>[] = [];
+  const teamEvents = new EventBus<TeamEvent>();
+  // Delivered side by side, so slow receivers do not outlast the relay's claim
+  teamEvents.subscribe(async (event) => {
+    deliveries.push(webhookService.dispatch(event));
+  });
+  const relay = new TeamEventRelay(teamEventOutboxRepository, teamRepository, teamEvents);
+
+  const relayed = await relay.relayAll();
+  await Promise.all(deliveries);
+  const purged = await relay.purgePublished();
+  console.log(`Relayed ${relayed} team events, purged ${purged} published ones`);
+}
+
+export default {
+  fetch: app.fetch,
+  scheduled: async (controller: ScheduledController, env: Bindings, ctx: ExecutionContext) => {
+    if (controller.cron === TEAM_EVENT_RELAY_CRON) {
+      ctx.waitUntil(relayTeamEvents(env.DATABASE_URL));
+    } else {
+      ctx.waitUntil(purgeDeletedTeams(env.DATABASE_URL));
+      ctx.waitUntil(scrubDeletedAccounts(env.DATABASE_URL));
+    }
+  },
+};
@@ -4,14 +4,81 @@
 
 import { serve } from '@hono/node-server';
 import { env } from './config/env';
-import app from './index';
+import { closeDb, getDb, parseReplicaUrls } from './db/connection';
+import { closeSqliteDb, getSqliteDb, isSqliteUrl } from './db/sqlite/connection';
+import app, { purgeDeletedTeams, relayTeamEvents, scrubDeletedAccounts } from './index';
+
+if (isSqliteUrl(env.DATABASE_URL)) {
+  // Create and migrate the database file before the first request
+  await getSqliteDb(env.DATABASE_URL);
+} else {
+  // Create the pools up front with server-sized limits; per-request getDb() calls reuse them
+  const pool = { max: env.DB_POOL_MAX, idleTimeout: env.DB_IDLE_TIMEOUT };
+  for (const url of [env.DATABASE_URL, ...parseReplicaUrls(env.DATABASE_REPLICA_URLS)]) {
+    getDb(url, pool);
+  }
+}
 
 // Start server
 const port = parseInt(env.PORT);
 console.log(`Server is running on http://${env.HOST}:${port}`);
 
-serve({
+const server = serve({
   fetch: app.fetch,
   port,
   hostname: env.HOST,
 });
+
+// Workers run the purges and the outbox sweep from cron triggers; the Node server uses timers
+const PURGE_INTERVAL_MS = 24 * 60 * 60 * 1000;
+const purgeTimer = setInterval(() => {
+  purgeDeletedTeams(env.DATABASE_URL).catch((error) =>
+    console.error('Failed to purge deleted teams:', error)
+  );
+  scrubDeletedAccounts(env.DATABASE_URL).catch((error) =>
+    console.error('Failed to scrub deleted accounts:', error)
+  );
+}, PURGE_INTERVAL_MS);
+purgeTimer.unref();
+
+const RELAY_INTERVAL_MS = 5 * 60 * 1000;
+const relayTimer = setInterval(() => {
+  relayTeamEvents(env.DATABASE_URL).catch((error) =>
+    console.error('Failed to relay team events:', error)
+  );
+}, RELAY_INTERVAL_MS);
+relayTimer.unref();
+
+// On SIGTERM the server stops accepting connections and lets in-flight requests finish, so a
+// rolling deploy drops nothing. Whatever is still running after SHUTDOWN_TIMEOUT is cut off.
+let shuttingDown = false;
+
+function shutdown(signal: string) {
+  if (shuttingDown) {
+    return;
+  }
+  shuttingDown = true;
+  console.log(`${signal} received, draining in-flight requests`);
+  clearInterval(purgeTimer);
+  clearInterval(relayTimer);
+
+  const forceExit = setTimeout(() => {
+    console.error(`Requests still running after ${env.SHUTDOWN_TIMEOUT}s, forcing exit`);
+    process.exit(1);
+  }, env.SHUTDOWN_TIMEOUT * 1000);
+  forceExit.unref();
+
+  server.close(async (error) => {
+    if (error) {
+      console.error('Failed to close server:', error);
+    }
+    // Requests are done, so no query is left to wait for
+    await closeDb(0).catch((dbError) => console.error('Failed to close database:', dbError));
+    closeSqliteDb();
+    console.log('Shutdown complete');
+    process.exit(error ? 1 : 0);
+  });
+}
+
+process.on('SIGTERM', () => shutdown('SIGTERM'));
+process.on('SIGINT', () => shutdown('SIGINT'));
@@ -0,0 +1,34 @@
+import { Context, Next } from 'hono';
+import { ApiKeyScope } from '../domain/valueobject/ApiKeyScope';
+import { ApiKeyError, ApiKeyService } from '../usecase/ApiKeyService';
+
+export const API_KEY_HEADER = 'X-API-Key';
+
+export interface ApiKeyEnv {
+  Variables: {
+    apiKeyService: ApiKeyService;
+  };
+}
+
+// Public endpoints stay open without a key, as the frontend uses them; a request that sends a
+// key is checked against its scopes and daily quota, and gets the quota back in headers
+export const apiKeyMiddleware =
+  (scope: ApiKeyScope) => async (c: Context<ApiKeyEnv>, next: Next) => {
+    const key = c.req.header(API_KEY_HEADER);
+    if (!key) {
+      await next();
+      return;
+    }
+
+    const { apiKey, used, resetsAt } = await c.get('apiKeyService').recordRequest(key, scope);
+    const quota = apiKey.getDailyQuota();
+    c.header('X-RateLimit-Limit', String(quota));
+    c.header('X-RateLimit-Remaining', String(Math.max(quota - used, 0)));
+    c.header('X-RateLimit-Reset', String(Math.floor(resetsAt.getTime() / 1000)));
+
+    if (used > quota) {
+      c.header('Retry-After', String(Math.ceil((resetsAt.getTime() - Date.now()) / 1000)));
+      throw ApiKeyError.quotaExceeded(quota);
+    }
+    await next();
+  };
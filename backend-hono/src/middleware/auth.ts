@@ -1,48 +1,114 @@
 import { Context, Next } from 'hono';
-import { AuthService } from '../usecase/AuthService';
+import { deleteCookie, getSignedCookie, setSignedCookie } from 'hono/cookie';
+import { AuthError, AuthService } from '../usecase/AuthService';
+import { isSafeMethod, verifyCsrfToken } from './csrf';
+
+export type AuthMode = 'jwt' | 'session';
+
+const SESSION_COOKIE_NAME = 'session';
+const SESSION_COOKIE_MAX_AGE = 30 * 24 * 60 * 60; // 30 days in seconds, like the refresh token
+
+// Finds the signed-in user of a request. Returns undefined when the request carries no
+// credentials and throws AuthError when they are invalid.
+export interface CredentialExtractor {
+  readonly mode: AuthMode;
+  extract(c: Context<AuthEnv>): Promise<string | undefined>;
+}
 
 export interface AuthEnv {
   Variables: {
     userId: string;
     authService: AuthService;
+    credentialExtractor: CredentialExtractor;
   };
 }
 
-export const authMiddleware = async (c: Context<AuthEnv>, next: Next) => {
-  const authHeader = c.req.header('Authorization');
+// The default: a short-lived JWT access token in the Authorization header
+export class BearerTokenExtractor implements CredentialExtractor {
+  readonly mode = 'jwt';
 
-  if (!authHeader || !authHeader.startsWith('Bearer ')) {
-    return c.json({ error: 'Missing or invalid authorization header' }, 401);
+  async extract(c: Context<AuthEnv>): Promise<string | undefined> {
+    const authHeader = c.req.header('Authorization');
+    if (!authHeader || !authHeader.startsWith('Bearer ')) {
+      return undefined;
+    }
+
+    const token = authHeader.substring(7);
+    return c.get('authService').validateAccessToken(token);
   }
+}
 
-  const token = authHeader.substring(7);
-  const authService = c.get('authService');
+// AUTH_MODE=session: an HttpOnly cookie signed with JWT_SECRET holds the session token, and the
+// session record is checked on every request, so a revoked session is rejected at once
+export class SessionCookieExtractor implements CredentialExtractor {
+  readonly mode = 'session';
 
-  try {
-    const userId = authService.validateAccessToken(token);
-    c.set('userId', userId);
-    await next();
-  } catch (error) {
-    if (error instanceof Error) {
-      return c.json({ error: error.message }, 401);
+  constructor(private readonly secret: string) {}
+
+  async extract(c: Context<AuthEnv>): Promise<string | undefined> {
+    const sessionToken = await this.read(c);
+    if (!sessionToken) {
+      return undefined;
+    }
+
+    // The browser sends the cookie with any request to the API, so unsafe ones must prove they
+    // come from the frontend. Tokens for the auth routes are accepted, so one token covers both.
+    if (!isSafeMethod(c)) {
+      verifyCsrfToken(c, 'auth');
     }
-    return c.json({ error: 'Authentication failed' }, 401);
+    return c.get('authService').validateSessionToken(sessionToken);
   }
-};
 
-export const optionalAuthMiddleware = async (c: Context<AuthEnv>, next: Next) => {
-  const authHeader = c.req.header('Authorization');
+  // The raw session token; undefined when the cookie is missing or its signature does not match
+  async read(c: Context): Promise<string | undefined> {
+    const sessionToken = await getSignedCookie(c, this.secret, SESSION_COOKIE_NAME);
+    return sessionToken || undefined;
+  }
 
-  if (authHeader && authHeader.startsWith('Bearer ')) {
-    const token = authHeader.substring(7);
-    const authService = c.get('authService');
+  async write(c: Context, sessionToken: string): Promise<void> {
+    await setSignedCookie(c, SESSION_COOKIE_NAME, sessionToken, this.secret, {
+      httpOnly: true,
+      secure: process.env.NODE_ENV === 'production',
+      sameSite: 'Lax',
+      maxAge: SESSION_COOKIE_MAX_AGE,
+      path: '/',
+    });
+  }
+
+  clear(c: Context): void {
+    deleteCookie(c, SESSION_COOKIE_NAME, { path: '/' });
+  }
+}
+
+export function createCredentialExtractor(mode: AuthMode, secret: string): CredentialExtractor {
+  return mode === 'session' ? new SessionCookieExtractor(secret) : new BearerTokenExtractor();
+}
+
+export const authMiddleware = async (c: Context<AuthEnv>, next: Next) => {
+  // The extractor throws AuthError on bad credentials, which the error handler turns into a 401
+  const userId = await c.get('credentialExtractor').extract(c);
+  if (!userId) {
+    throw AuthError.missingToken();
+  }
 
-    try {
-      const userId = authService.validateAccessToken(token);
+  c.set('userId', userId);
+  await next();
+};
+
+// Runs after authMiddleware on admin-only routes
+export const adminMiddleware = async (c: Context<AuthEnv>, next: Next) => {
+  await c.get('authService').requireAdmin(c.get('userId'));
+  await next();
+};
+
+export const optionalAuthMiddleware = async (c: Context<AuthEnv>, next: Next) => {
+  try {
+    const userId = await c.get('credentialExtractor').extract(c);
+    if (userId) {
       c.set('userId', userId);
-    } catch {
-      // Ignore authentication errors for optional auth
     }
+  } catch {
+    // Ignore authentication errors for optional auth
   }
 
   await next();
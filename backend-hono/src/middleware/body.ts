@@ -0,0 +1,23 @@
+import { Context } from 'hono';
+
+export class InvalidJsonError extends Error {
+  readonly code = 'INVALID_JSON';
+
+  constructor() {
+    super('Request body is not valid JSON');
+    this.name = 'InvalidJsonError';
+  }
+}
+
+// c.req.json() fails with a bare SyntaxError, which a bug parsing stored JSON would throw as
+// well, so handlers read bodies through here to report a malformed one as the client's mistake
+export async function readJson(c: Context): Promise<unknown> {
+  try {
+    return await c.req.json();
+  } catch (error) {
+    if (error instanceof SyntaxError) {
+      throw new InvalidJsonError();
+    }
+    throw error;
+  }
+}
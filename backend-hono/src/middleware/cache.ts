@@ -0,0 +1,44 @@
+import { Context } from 'hono';
+
+// Dex data only changes on import, so clients may reuse it for an hour and revalidate
+// cheaply (a 304 costs one version lookup) after that
+export const DEX_CACHE_CONTROL = 'public, max-age=3600, stale-while-revalidate=86400';
+
+// Data compiled into the API itself, such as the type chart
+export const STATIC_CACHE_CONTROL = 'public, max-age=86400';
+
+// Proxied cries and sprites; upstream replaces them rarely, and a stale one is harmless
+export const MEDIA_CACHE_CONTROL = 'public, max-age=604800';
+
+// Builds a strong ETag for a response that is fully determined by its parts
+export function buildETag(...parts: (string | number)[]): string {
+  return `"${parts.join('-')}"`;
+}
+
+export function setCacheHeaders(c: Context, etag: string, cacheControl: string): void {
+  c.header('ETag', etag);
+  c.header('Cache-Control', cacheControl);
+  // Bodies are localized from Accept-Language when ?lang= is absent
+  c.header('Vary', 'Accept-Language', { append: true });
+}
+
+// Returns a 304 when the client already holds this ETag, so the handler can skip loading
+// the body; otherwise undefined
+export function notModified(c: Context, etag: string, cacheControl: string): Response | undefined {
+  const ifNoneMatch = c.req.header('If-None-Match');
+  if (!ifNoneMatch) {
+    return undefined;
+  }
+
+  // If-None-Match uses weak comparison (RFC 9110 13.1.2)
+  const matches = ifNoneMatch
+    .split(',')
+    .map((tag) => tag.trim().replace(/^W\//, ''))
+    .some((tag) => tag === '*' || tag === etag);
+  if (!matches) {
+    return undefined;
+  }
+
+  setCacheHeaders(c, etag, cacheControl);
+  return c.body(null, 304);
+}
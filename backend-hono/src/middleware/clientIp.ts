@@ -0,0 +1,32 @@
+import { getConnInfo } from '@hono/node-server/conninfo';
+import { Context } from 'hono';
+import { getRuntimeKey } from 'hono/adapter';
+
+// Proxies in front of the Node.js server whose X-Forwarded-For entries are trusted (default 0)
+function trustedProxies(): number {
+  const hops = Number(typeof process !== 'undefined' ? process.env.TRUST_PROXY : undefined);
+  return Number.isInteger(hops) && hops > 0 ? hops : 0;
+}
+
+// The address the request came from, for sessions and login throttling. Clients can send any
+// X-Forwarded-For they like, so it is only read with TRUST_PROXY set: each trusted proxy appends
+// the address it was connected from, and the client is the entry the outermost one appended.
+export function getClientIp(c: Context): string | undefined {
+  // Set by Cloudflare, which the Worker cannot be reached without
+  if (getRuntimeKey() === 'workerd') {
+    return c.req.header('CF-Connecting-IP');
+  }
+
+  const peer = getConnInfo(c).remote.address;
+  const hops = trustedProxies();
+  if (hops === 0) {
+    return peer;
+  }
+  const forwarded = (c.req.header('X-Forwarded-For') ?? '')
+    .split(',')
+    .map((address) => address.trim())
+    .filter((address) => address !== '');
+  // Nearest first; with fewer entries than trusted proxies, the farthest one known
+  const chain = [peer, ...forwarded.reverse()];
+  return chain[Math.min(hops, chain.length - 1)];
+}
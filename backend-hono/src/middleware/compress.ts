@@ -0,0 +1,168 @@
+import { Context, Next } from 'hono';
+import { getRuntimeKey } from 'hono/adapter';
+
+export interface CompressEnv {
+  Bindings: {
+    // "on" or "off"; on by default except on Workers, where Cloudflare compresses at the edge
+    COMPRESSION?: string;
+    // Bytes; smaller responses are sent as they are (default 1024)
+    COMPRESSION_MIN_SIZE?: string;
+  };
+}
+
+export type ContentEncoding = 'br' | 'gzip';
+
+// Preferred first when the client accepts several equally
+const ENCODINGS: readonly ContentEncoding[] = ['br', 'gzip'];
+
+const DEFAULT_MIN_SIZE = 1024;
+
+// Brotli's default quality (11) is meant for static assets and is too slow per request
+const BROTLI_QUALITY = 4;
+
+// JSON (problem documents included), NDJSON exports and other text; images and audio from the
+// media proxy are already compressed
+const COMPRESSIBLE_CONTENT_TYPE =
+  /^\s*(?:text\/|application\/(?:[\w.-]+\+)?(?:json|xml|x-ndjson|javascript)|image\/svg\+xml)/i;
+
+const NO_TRANSFORM = /(?:^|,)\s*no-transform\s*(?:,|$)/i;
+
+// The supported encoding the client weighs highest (RFC 9110 12.5.3), or null to send the
+// body as it is
+export function negotiateEncoding(acceptEncoding: string | undefined): ContentEncoding | null {
+  if (!acceptEncoding) {
+    return null;
+  }
+
+  const weights = new Map<string, number>();
+  for (const entry of acceptEncoding.split(',')) {
+    const [coding, ...params] = entry.split(';').map((part) => part.trim().toLowerCase());
+    const q = params.find((param) => param.startsWith('q='));
+    const weight = q ? Number(q.slice(2)) : 1;
+    if (coding) {
+      weights.set(coding, Number.isFinite(weight) ? weight : 0);
+    }
+  }
+
+  let best: ContentEncoding | null = null;
+  let bestWeight = 0;
+  for (const encoding of ENCODINGS) {
+    const weight = weights.get(encoding) ?? weights.get('*') ?? 0;
+    if (weight > bestWeight) {
+      best = encoding;
+      bestWeight = weight;
+    }
+  }
+  return best;
+}
+
+function readSetting(c: Context<CompressEnv>, name: keyof CompressEnv['Bindings']) {
+  return c.env?.[name] ?? (typeof process !== 'undefined' ? process.env[name] : undefined);
+}
+
+function parseMinSize(value?: string): number {
+  const minSize = Number(value);
+  return Number.isInteger(minSize) && minSize >= 0 ? minSize : DEFAULT_MIN_SIZE;
+}
+
+async function createEncoder(
+  encoding: ContentEncoding
+): Promise<ReadableWritablePair<Uint8Array, Uint8Array>> {
+  if (encoding === 'gzip') {
+    return new CompressionStream('gzip');
+  }
+  // CompressionStream has no brotli. Loaded on first use, as Workers never compress here.
+  const { Duplex } = await import('node:stream');
+  const { constants, createBrotliCompress } = await import('node:zlib');
+  const brotli = createBrotliCompress({
+    params: { [constants.BROTLI_PARAM_QUALITY]: BROTLI_QUALITY },
+  });
+  return Duplex.toWeb(brotli) as unknown as ReadableWritablePair<Uint8Array, Uint8Array>;
+}
+
+// Reads the body until `minSize` bytes have arrived. `rest` is the whole body again, or null
+// when it ended before that.
+async function readAtLeast(
+  body: ReadableStream<Uint8Array>,
+  minSize: number
+): Promise<{ chunks: Uint8Array[]; rest: ReadableStream<Uint8Array> | null }> {
+  const reader = body.getReader();
+  const chunks: Uint8Array[] = [];
+  let size = 0;
+  while (size < minSize) {
+    const { done, value } = await reader.read();
+    if (done) {
+      return { chunks, rest: null };
+    }
+    chunks.push(value);
+    size += value.byteLength;
+  }
+
+  const rest = new ReadableStream<Uint8Array>({
+    start(controller) {
+      chunks.forEach((chunk) => controller.enqueue(chunk));
+    },
+    async pull(controller) {
+      const { done, value } = await reader.read();
+      if (done) {
+        controller.close();
+      } else {
+        controller.enqueue(value);
+      }
+    },
+    cancel(reason) {
+      return reader.cancel(reason);
+    },
+  });
+  return { chunks, rest };
+}
+
+// Compresses text responses with the best encoding the client accepts. Streamed bodies such as
+// the dex export stay streamed; bodies without a Content-Length are measured by reading up to
+// the minimum size first.
+export const compressMiddleware = async (c: Context<CompressEnv>, next: Next) => {
+  await next();
+
+  const setting = readSetting(c, 'COMPRESSION');
+  if (setting === 'off' || (setting !== 'on' && getRuntimeKey() === 'workerd')) {
+    return;
+  }
+
+  const res = c.res;
+  if (
+    !res.body ||
+    c.req.method === 'HEAD' ||
+    res.headers.has('Content-Encoding') ||
+    !COMPRESSIBLE_CONTENT_TYPE.test(res.headers.get('Content-Type') ?? '') ||
+    NO_TRANSFORM.test(res.headers.get('Cache-Control') ?? '')
+  ) {
+    return;
+  }
+  // Caches must not hand a compressed body to a client that cannot read it, or the reverse
+  res.headers.append('Vary', 'Accept-Encoding');
+
+  const encoding = negotiateEncoding(c.req.header('Accept-Encoding'));
+  if (!encoding) {
+    return;
+  }
+  const minSize = parseMinSize(readSetting(c, 'COMPRESSION_MIN_SIZE'));
+  const contentLength = res.headers.get('Content-Length');
+  if (contentLength !== null && Number(contentLength) < minSize) {
+    return;
+  }
+
+  const { chunks, rest } = await readAtLeast(res.body, minSize);
+  if (!rest) {
+    c.res = new Response(new Blob(chunks), res);
+    return;
+  }
+
+  c.res = new Response(rest.pipeThrough(await createEncoder(encoding)), res);
+  c.res.headers.set('Content-Encoding', encoding);
+  c.res.headers.delete('Content-Length');
+  // The compressed bytes differ, so a strong ETag no longer holds; notModified compares weakly
+  const etag = c.res.headers.get('ETag');
+  if (etag && !etag.startsWith('W/')) {
+    c.res.headers.set('ETag', `W/${etag}`);
+  }
+};
@@ -0,0 +1,115 @@
+import crypto from 'crypto';
+import { Context, Next } from 'hono';
+import { getCookie, setCookie } from 'hono/cookie';
+
+export const CSRF_HEADER = 'X-CSRF-Token';
+
+// Random per browser and unreadable to scripts; tokens are signed with it, so a page on another
+// site can neither read a token nor mint one (double submit with a signed token)
+const CSRF_COOKIE_NAME = 'csrf_secret';
+const CSRF_COOKIE_MAX_AGE = 30 * 24 * 60 * 60; // 30 days in seconds, like the refresh token
+
+const CSRF_TOKEN_TTL_SECONDS = 2 * 60 * 60;
+
+const SAFE_METHODS = ['GET', 'HEAD', 'OPTIONS'];
+
+export interface CsrfEnv {
+  Bindings: {
+    // "on" to reject unsafe requests without a valid token; tokens are issued either way, so the
+    // frontend can start sending them before enforcement is switched on
+    CSRF_PROTECTION?: string;
+  };
+}
+
+export class CsrfError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'CsrfError';
+  }
+
+  static missingToken(): CsrfError {
+    return new CsrfError(`Missing ${CSRF_HEADER} header`, 'CSRF_TOKEN_MISSING');
+  }
+
+  static invalidToken(): CsrfError {
+    return new CsrfError('Invalid or expired CSRF token', 'CSRF_TOKEN_INVALID');
+  }
+}
+
+export interface CsrfToken {
+  token: string;
+  expiresAt: Date;
+}
+
+function isEnabled(c: Context<CsrfEnv>): boolean {
+  const setting =
+    c.env.CSRF_PROTECTION ??
+    (typeof process !== 'undefined' ? process.env.CSRF_PROTECTION : undefined);
+  return setting === 'on';
+}
+
+function sign(secret: string, form: string, expires: number): string {
+  return crypto.createHmac('sha256', secret).update(`${form}.${expires}`).digest('base64url');
+}
+
+// Tokens are scoped to a form (a route group), so one leaked for a low-value form does not
+// unlock another; the form name is not in the token, only in the signature
+export function issueCsrfToken(c: Context, form: string): CsrfToken {
+  let secret = getCookie(c, CSRF_COOKIE_NAME);
+  if (!secret) {
+    secret = crypto.randomBytes(32).toString('base64url');
+    setCookie(c, CSRF_COOKIE_NAME, secret, {
+      httpOnly: true,
+      secure: process.env.NODE_ENV === 'production',
+      sameSite: 'Lax',
+      maxAge: CSRF_COOKIE_MAX_AGE,
+      path: '/',
+    });
+  }
+
+  const expires = Math.floor(Date.now() / 1000) + CSRF_TOKEN_TTL_SECONDS;
+  return {
+    token: `${expires}.${sign(secret, form, expires)}`,
+    expiresAt: new Date(expires * 1000),
+  };
+}
+
+function isValid(secret: string, form: string, token: string): boolean {
+  const [expiresPart, signature] = token.split('.');
+  const expires = Number(expiresPart);
+  if (!signature || !Number.isInteger(expires) || expires * 1000 < Date.now()) {
+    return false;
+  }
+
+  const expected = Buffer.from(sign(secret, form, expires));
+  const actual = Buffer.from(signature);
+  return actual.length === expected.length && crypto.timingSafeEqual(actual, expected);
+}
+
+export function isSafeMethod(c: Context): boolean {
+  return SAFE_METHODS.includes(c.req.method);
+}
+
+export function verifyCsrfToken(c: Context, form: string): void {
+  const token = c.req.header(CSRF_HEADER);
+  if (!token) {
+    throw CsrfError.missingToken();
+  }
+  const secret = getCookie(c, CSRF_COOKIE_NAME);
+  if (!secret || !isValid(secret, form, token)) {
+    throw CsrfError.invalidToken();
+  }
+}
+
+// Guards the unsafe methods of a route group that relies on cookies. Requests authenticated by
+// an Authorization header are let through: a page on another site cannot make the browser send
+// one.
+export const csrfMiddleware = (form: string) => async (c: Context<CsrfEnv>, next: Next) => {
+  if (isEnabled(c) && !isSafeMethod(c) && !c.req.header('Authorization')) {
+    verifyCsrfToken(c, form);
+  }
+  await next();
+};
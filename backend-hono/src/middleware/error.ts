@@ -1,57 +1,466 @@
 import { Context } from 'hono';
-import { ZodError } from 'zod';
+import { HTTPException } from 'hono/http-exception';
+import { StatusCode } from 'hono/utils/http-status';
+import { ZodError, ZodIssue } from 'zod';
+import { ValidationError } from '../domain/valueobject/ValidationError';
+import { DEFAULT_LANG, Lang, translateError, translateValidationIssue } from '../i18n';
+import { ConflictError } from '../repository/interface/TeamRepository';
+import { AbilityLookupError } from '../usecase/AbilityLookupService';
+import { AdminError } from '../usecase/AdminService';
+import { ApiKeyError } from '../usecase/ApiKeyService';
 import { AuthError } from '../usecase/AuthService';
+import { BattleRecordServiceError } from '../usecase/BattleRecordService';
+import { BattleSimulationError } from '../usecase/BattleSimulationService';
+import { BreedingError } from '../usecase/BreedingService';
+import { ContentModerationError } from '../usecase/ContentModerationService';
+import { CoverageError } from '../usecase/CoverageService';
+import { CustomFormError } from '../usecase/CustomFormService';
+import { DamageBenchmarkError } from '../usecase/DamageBenchmarkService';
+import { DamageCalculationError } from '../usecase/DamageCalculationService';
+import { DefensiveCoreError } from '../usecase/DefensiveCoreService';
+import { DraftLeagueServiceError } from '../usecase/DraftLeagueService';
+import { EmailChangeError } from '../usecase/EmailChangeService';
+import { FormMediaError } from '../usecase/FormMediaService';
+import { ItemLookupError } from '../usecase/ItemLookupService';
+import { MoveSearchError } from '../usecase/MoveSearchService';
+import { PageCursorError } from '../usecase/PageCursorCodec';
+import { PersonalDataError } from '../usecase/PersonalDataService';
+import { PokemonImportError } from '../usecase/PokemonImportService';
+import { PokemonLookupError } from '../usecase/PokemonLookupService';
+import { RandomTeamError } from '../usecase/RandomTeamService';
+import { RunTrackerServiceError } from '../usecase/RunTrackerService';
+import { SearchError } from '../usecase/SearchService';
+import { TeamAnalysisError } from '../usecase/TeamAnalysisService';
+import { TeamClipboardError } from '../usecase/TeamClipboardService';
+import { TeamCommentError } from '../usecase/TeamCommentService';
+import { TeamGalleryError } from '../usecase/TeamGalleryService';
+import { TeamNotesError } from '../usecase/TeamNotesService';
+import { TeamOrganizerError } from '../usecase/TeamOrganizerService';
+import { TeamShareError } from '../usecase/TeamShareService';
+import { TeamTrashError } from '../usecase/TeamTrashService';
+import { ThreatAnalysisError } from '../usecase/ThreatAnalysisService';
+import { TournamentServiceError } from '../usecase/TournamentService';
+import { TurnOrderError } from '../usecase/TurnOrderService';
+import { TypeQuizError } from '../usecase/TypeQuizService';
+import { UsageStatsError } from '../usecase/UsageStatsService';
+import { WebhookError } from '../usecase/WebhookService';
+import { TeamResponse, toTeamResponse } from '../usecase/dto/teamResponse';
+import { InvalidJsonError } from './body';
+import { CsrfError } from './csrf';
+
+export const PROBLEM_CONTENT_TYPE = 'application/problem+json';
+
+// Problem types are relative URIs derived from the error code, e.g. /problems/form-not-found.
+// They are part of the API contract: clients branch on `type`, so never rename a code.
+export const PROBLEM_TYPE_BASE = '/problems/';
+
+interface FieldError {
+  field: string;
+  code: string;
+  message: string;
+}
+
+// RFC 7807 body; `code`, `errors`, `current` and `request_id` are extension members
+interface Problem {
+  type: string;
+  title: string;
+  status: StatusCode;
+  detail: string;
+  code: string;
+  errors?: FieldError[];
+  // The stored team after a version conflict
+  current?: TeamResponse | null;
+  request_id?: string;
+}
+
+type CodedError = Error & { code: string };
+
+// Every service error carries a stable `code`; this table decides its HTTP status
+const SERVICE_ERRORS: {
+  errorClass: new (...args: never[]) => CodedError;
+  status: (code: string) => StatusCode;
+}[] = [
+  { errorClass: AbilityLookupError, status: () => 404 },
+  {
+    errorClass: AdminError,
+    status: (code) => (['FORM_NOT_FOUND', 'VERSION_NOT_FOUND'].includes(code) ? 404 : 422),
+  },
+  { errorClass: ApiKeyError, status: getApiKeyErrorStatus },
+  { errorClass: AuthError, status: getAuthErrorStatus },
+  { errorClass: BattleRecordServiceError, status: getBattleRecordErrorStatus },
+  { errorClass: BattleSimulationError, status: () => 404 },
+  { errorClass: BreedingError, status: getBreedingErrorStatus },
+  {
+    errorClass: ContentModerationError,
+    status: (code) => (code === 'CONTENT_REJECTED' ? 422 : 404),
+  },
+  { errorClass: CoverageError, status: () => 404 },
+  { errorClass: CsrfError, status: () => 403 },
+  {
+    errorClass: CustomFormError,
+    status: (code) => (code === 'CUSTOM_FORM_NOT_FOUND' ? 404 : 409),
+  },
+  { errorClass: DamageBenchmarkError, status: getDamageBenchmarkErrorStatus },
+  { errorClass: DamageCalculationError, status: () => 404 },
+  { errorClass: DefensiveCoreError, status: () => 404 },
+  { errorClass: DraftLeagueServiceError, status: getDraftLeagueErrorStatus },
+  { errorClass: EmailChangeError, status: getEmailChangeErrorStatus },
+  { errorClass: FormMediaError, status: getFormMediaErrorStatus },
+  { errorClass: InvalidJsonError, status: () => 400 },
+  { errorClass: ItemLookupError, status: () => 404 },
+  { errorClass: MoveSearchError, status: () => 404 },
+  { errorClass: PageCursorError, status: () => 400 },
+  { errorClass: PersonalDataError, status: (code) => (code === 'INVALID_PASSWORD' ? 403 : 404) },
+  { errorClass: PokemonImportError, status: (code) => (code === 'UPSTREAM_FAILED' ? 502 : 404) },
+  { errorClass: PokemonLookupError, status: () => 404 },
+  { errorClass: RandomTeamError, status: () => 422 },
+  { errorClass: RunTrackerServiceError, status: getRunTrackerErrorStatus },
+  { errorClass: SearchError, status: () => 503 },
+  { errorClass: UsageStatsError, status: (code) => (code === 'UNKNOWN_METAGAME' ? 422 : 404) },
+  { errorClass: TeamAnalysisError, status: () => 404 },
+  { errorClass: TeamClipboardError, status: getTeamClipboardErrorStatus },
+  { errorClass: TeamCommentError, status: (code) => (code === 'NOT_COMMENT_AUTHOR' ? 403 : 404) },
+  { errorClass: TeamGalleryError, status: (code) => (code === 'OWN_TEAM' ? 422 : 404) },
+  { errorClass: TeamNotesError, status: (code) => (code === 'SLOT_EMPTY' ? 422 : 404) },
+  { errorClass: TeamOrganizerError, status: () => 404 },
+  { errorClass: TeamShareError, status: (code) => (code === 'INVALID_CODE' ? 400 : 404) },
+  { errorClass: TeamTrashError, status: getTeamTrashErrorStatus },
+  { errorClass: ThreatAnalysisError, status: () => 404 },
+  { errorClass: TournamentServiceError, status: getTournamentErrorStatus },
+  { errorClass: TurnOrderError, status: () => 404 },
+  { errorClass: TypeQuizError, status: (code) => (code === 'INVALID_CHOICE' ? 422 : 409) },
+  { errorClass: WebhookError, status: getWebhookErrorStatus },
+];
+
+// Codes for what Hono and its middleware throw as HTTPException, which carries only a status
+const HTTP_EXCEPTION_CODES: Partial<Record<StatusCode, string>> = {
+  400: 'BAD_REQUEST',
+  401: 'UNAUTHORIZED',
+  403: 'FORBIDDEN',
+  404: 'NOT_FOUND',
+  405: 'METHOD_NOT_ALLOWED',
+  413: 'CONTENT_TOO_LARGE',
+  415: 'UNSUPPORTED_MEDIA_TYPE',
+};
+
+const STATUS_TITLES: Partial<Record<StatusCode, string>> = {
+  400: 'Bad Request',
+  401: 'Unauthorized',
+  403: 'Forbidden',
+  404: 'Not Found',
+  405: 'Method Not Allowed',
+  409: 'Conflict',
+  410: 'Gone',
+  413: 'Content Too Large',
+  415: 'Unsupported Media Type',
+  422: 'Unprocessable Content',
+  429: 'Too Many Requests',
+  500: 'Internal Server Error',
+  502: 'Bad Gateway',
+  503: 'Service Unavailable',
+};
 
 export const errorHandler = (err: Error, c: Context) => {
-  console.error('Error:', err);
-
-  // Zod validation errors
-  if (err instanceof ZodError) {
-    return c.json(
-      {
-        error: 'Validation error',
-        details: err.errors.map((e) => ({
-          path: e.path.join('.'),
-          message: e.message,
-        })),
-      },
-      400
-    );
-  }
-
-  // Auth errors
-  if (err instanceof AuthError) {
-    const statusCode = getAuthErrorStatus(err.code) as 401 | 404 | 409 | 500;
-    return c.json(
-      {
-        error: err.message,
-        code: err.code,
-      },
-      statusCode
-    );
-  }
-
-  // Default error
-  return c.json(
-    {
-      error: 'Internal server error',
-    },
-    500
-  );
+  console.error(`[${c.get('requestId')}] Error:`, err);
+  const lang: Lang = c.get('lang') ?? DEFAULT_LANG;
+  return problemResponse(c, toProblem(err, lang));
+};
+
+export const notFoundHandler = (c: Context) => {
+  const lang: Lang = c.get('lang') ?? DEFAULT_LANG;
+  return problemResponse(c, problem(lang, 404, 'ROUTE_NOT_FOUND', 'Route not found'));
 };
 
-function getAuthErrorStatus(code: string): number {
+// The single mapping from anything thrown by a handler to a problem document
+function toProblem(err: Error, lang: Lang): Problem {
+  // Request DTO and value object validation errors, reported per field
+  if (err instanceof ZodError || err instanceof ValidationError) {
+    const errors =
+      err instanceof ZodError
+        ? err.errors.map((issue) => toFieldError(lang, issue.path.join('.'), issue))
+        : err.issues.map((issue) => toFieldError(lang, err.field, issue));
+    return {
+      ...problem(lang, 422, 'VALIDATION_ERROR', 'Validation error'),
+      errors,
+    };
+  }
+
+  // Someone else saved the team first; the stored state lets the client merge and retry
+  if (err instanceof ConflictError) {
+    return {
+      ...problem(lang, 409, err.code, err.message),
+      current: err.current ? toTeamResponse(err.current) : null,
+    };
+  }
+
+  const serviceError = SERVICE_ERRORS.find(({ errorClass }) => err instanceof errorClass);
+  if (serviceError) {
+    const { code, message } = err as CodedError;
+    return problem(lang, serviceError.status(code), code, message);
+  }
+
+  if (err instanceof HTTPException) {
+    const title = STATUS_TITLES[err.status] ?? 'Error';
+    const code = HTTP_EXCEPTION_CODES[err.status] ?? 'HTTP_ERROR';
+    return problem(lang, err.status, code, err.message || title);
+  }
+
+  return problem(lang, 500, 'INTERNAL_ERROR', 'Internal server error');
+}
+
+// `message` is the English detail; other languages come from the catalog
+function problem(lang: Lang, status: StatusCode, code: string, message: string): Problem {
+  return {
+    type: `${PROBLEM_TYPE_BASE}${code.toLowerCase().replace(/_/g, '-')}`,
+    title: STATUS_TITLES[status] ?? 'Error',
+    status,
+    detail: translateError(lang, code, message),
+    code,
+  };
+}
+
+// The request ID lets a user-reported error be matched to its log line
+function problemResponse(c: Context, body: Problem) {
+  const problem: Problem = { ...body, request_id: c.get('requestId') };
+  return c.body(JSON.stringify(problem), body.status, { 'Content-Type': PROBLEM_CONTENT_TYPE });
+}
+
+function toFieldError(lang: Lang, field: string, issue: ZodIssue): FieldError {
+  return {
+    field,
+    code: getFieldErrorCode(issue),
+    message: translateValidationIssue(lang, issue),
+  };
+}
+
+// Stable codes for clients; custom refinements pass their own via params.code
+function getFieldErrorCode(issue: ZodIssue): string {
+  switch (issue.code) {
+    case 'too_small':
+      return issue.type === 'string' ? 'TOO_SHORT' : 'TOO_SMALL';
+    case 'too_big':
+      return issue.type === 'string' ? 'TOO_LONG' : 'TOO_LARGE';
+    case 'invalid_string':
+      return 'INVALID_FORMAT';
+    case 'invalid_type':
+      return issue.received === 'undefined' ? 'REQUIRED' : 'INVALID_TYPE';
+    case 'invalid_enum_value':
+      return 'INVALID_VALUE';
+    case 'custom':
+      return typeof issue.params?.code === 'string' ? issue.params.code : 'INVALID';
+    default:
+      return 'INVALID';
+  }
+}
+
+function getApiKeyErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'INVALID_API_KEY':
+      return 401;
+    case 'INSUFFICIENT_SCOPE':
+      return 403;
+    case 'API_KEY_NOT_FOUND':
+      return 404;
+    case 'QUOTA_EXCEEDED':
+      return 429;
+    default:
+      return 500;
+  }
+}
+
+function getAuthErrorStatus(code: string): StatusCode {
   switch (code) {
     case 'INVALID_CREDENTIALS':
     case 'INVALID_TOKEN':
     case 'TOKEN_EXPIRED':
     case 'TOKEN_REVOKED':
+    case 'MISSING_TOKEN':
+    case 'MISSING_REFRESH_TOKEN':
       return 401;
+    case 'FORBIDDEN':
+      return 403;
     case 'USERNAME_EXISTS':
     case 'EMAIL_EXISTS':
       return 409;
     case 'USER_NOT_FOUND':
+    case 'SESSION_NOT_FOUND':
       return 404;
+    case 'LOGIN_THROTTLED':
+      return 429;
+    default:
+      return 500;
+  }
+}
+
+function getBattleRecordErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'BATTLE_RECORD_NOT_FOUND':
+    case 'TEAM_NOT_FOUND':
+    case 'FORM_NOT_FOUND':
+      return 404;
+    case 'TOO_MANY_BATTLE_RECORDS':
+      return 409;
+    default:
+      return 500;
+  }
+}
+
+function getBreedingErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'SPECIES_NOT_FOUND':
+      return 404;
+    case 'NOT_AN_EGG_MOVE':
+      return 422;
+    default:
+      return 500;
+  }
+}
+
+function getDamageBenchmarkErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'BENCHMARK_NOT_FOUND':
+    case 'FORM_NOT_FOUND':
+    case 'MOVE_NOT_FOUND':
+      return 404;
+    case 'TOO_MANY_BENCHMARKS':
+      return 409;
+    default:
+      return 500;
+  }
+}
+
+function getDraftLeagueErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'NOT_COMMISSIONER':
+    case 'NOT_PARTICIPANT':
+      return 403;
+    case 'LEAGUE_NOT_FOUND':
+    case 'USER_NOT_FOUND':
+    case 'FORM_NOT_FOUND':
+    case 'TEAM_NOT_FOUND':
+      return 404;
+    case 'DRAFT_COMPLETE':
+    case 'NOT_YOUR_TURN':
+    case 'ALREADY_PICKED':
+    case 'NO_PICKS':
+    case 'PICK_CONFLICT':
+      return 409;
+    case 'POOL_TOO_SMALL':
+    case 'BUDGET_TOO_SMALL':
+    case 'NOT_IN_POOL':
+    case 'OVER_BUDGET':
+      return 422;
+    default:
+      return 500;
+  }
+}
+
+function getFormMediaErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'FORM_NOT_FOUND':
+    case 'MEDIA_NOT_FOUND':
+    case 'MEDIA_PROXY_DISABLED':
+      return 404;
+    case 'UPSTREAM_FAILED':
+    case 'UNEXPECTED_MEDIA_TYPE':
+      return 502;
+    default:
+      return 500;
+  }
+}
+
+function getEmailChangeErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'INVALID_PASSWORD':
+      return 403;
+    case 'USER_NOT_FOUND':
+    case 'EMAIL_CHANGE_NOT_FOUND':
+      return 404;
+    case 'EMAIL_EXISTS':
+      return 409;
+    case 'EMAIL_CHANGE_EXPIRED':
+      return 410;
+    case 'SAME_EMAIL':
+      return 422;
+    case 'MAIL_UNAVAILABLE':
+      return 503;
+    default:
+      return 500;
+  }
+}
+
+function getRunTrackerErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'RUN_NOT_FOUND':
+    case 'SPECIES_NOT_FOUND':
+      return 404;
+    case 'SPECIES_ALREADY_USED':
+    case 'SPECIES_NOT_USED':
+      return 409;
+    default:
+      return 500;
+  }
+}
+
+function getTeamClipboardErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'TEAM_NOT_FOUND':
+      return 404;
+    case 'TEAM_FULL':
+      return 409;
+    case 'SLOT_EMPTY':
+      return 422;
+    default:
+      return 500;
+  }
+}
+
+function getTeamTrashErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'TEAM_NOT_FOUND':
+      return 404;
+    case 'TEAM_NOT_DELETED':
+      return 409;
+    case 'RETENTION_EXPIRED':
+      return 410;
+    default:
+      return 500;
+  }
+}
+
+function getTournamentErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'NOT_ORGANIZER':
+    case 'NOT_IN_MATCH':
+      return 403;
+    case 'TOURNAMENT_NOT_FOUND':
+    case 'TEAM_NOT_FOUND':
+    case 'MATCH_NOT_FOUND':
+      return 404;
+    case 'REGISTRATION_CLOSED':
+    case 'TOURNAMENT_FULL':
+    case 'NOT_REGISTERED':
+    case 'MATCH_NOT_READY':
+    case 'RESULT_ALREADY_REPORTED':
+    case 'TOURNAMENT_CONFLICT':
+      return 409;
+    case 'EMPTY_TEAM':
+    case 'NOT_ENOUGH_ENTRIES':
+    case 'INVALID_WINNER':
+      return 422;
+    default:
+      return 500;
+  }
+}
+
+function getWebhookErrorStatus(code: string): StatusCode {
+  switch (code) {
+    case 'WEBHOOK_NOT_FOUND':
+      return 404;
+    case 'TOO_MANY_WEBHOOKS':
+      return 409;
+    case 'WEBHOOK_URL_NOT_ALLOWED':
+      return 422;
     default:
       return 500;
   }
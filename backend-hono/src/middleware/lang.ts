@@ -0,0 +1,14 @@
+import { Context, Next } from 'hono';
+import { Lang, resolveLang } from '../i18n';
+
+export interface LangEnv {
+  Variables: {
+    lang: Lang;
+  };
+}
+
+export const langMiddleware = async (c: Context<LangEnv>, next: Next) => {
+  c.set('lang', resolveLang(c.req.query('lang'), c.req.header('Accept-Language')));
+  c.header('Content-Language', c.get('lang'));
+  await next();
+};
@@ -0,0 +1,47 @@
+import { Context, Next } from 'hono';
+import { UserPreferences } from '../domain/entity/UserPreferences';
+import { UserPreferencesService } from '../usecase/UserPreferencesService';
+import { AuthEnv } from './auth';
+import { LangEnv } from './lang';
+
+export interface PreferencesEnv {
+  Variables: {
+    userPreferencesService: UserPreferencesService;
+    // Unset for anonymous requests
+    preferences?: UserPreferences;
+  };
+}
+
+// Runs after authMiddleware or optionalAuthMiddleware. A signed-in user's saved language wins
+// over Accept-Language, but not over an explicit ?lang=.
+export const preferencesMiddleware = async (
+  c: Context<PreferencesEnv & AuthEnv & LangEnv>,
+  next: Next
+) => {
+  const userId: string | undefined = c.get('userId');
+  if (userId) {
+    const preferences = await c.get('userPreferencesService').get(userId);
+    c.set('preferences', preferences);
+
+    const language = preferences.getLanguage();
+    if (language && !c.req.query('lang')) {
+      c.set('lang', language);
+      c.header('Content-Language', language);
+    }
+  }
+  await next();
+};
+
+// Fills in the format and calculation mode an analysis request leaves out from the user's
+// preferences; anything in the request wins, and anonymous requests are left as they are
+export function withPreferredDefaults(c: Context<PreferencesEnv>, body: unknown): unknown {
+  const preferences = c.get('preferences');
+  if (!preferences || typeof body !== 'object' || body === null || Array.isArray(body)) {
+    return body;
+  }
+  return {
+    format: preferences.getFormat() ?? undefined,
+    mode: preferences.getCalculationMode(),
+    ...body,
+  };
+}
@@ -0,0 +1,16 @@
+import { Context } from 'hono';
+import { ReadConsistency } from '../db/connection';
+import { isSafeMethod } from './csrf';
+
+// "primary" on a read right after the client's own write, so it sees the write even before the
+// replicas catch up
+export const READ_CONSISTENCY_HEADER = 'X-Read-Consistency';
+
+// Unsafe requests always read from the primary: they often read a row and then update it, and a
+// stale copy would lose the update or fail the version check
+export function getReadConsistency(c: Context): ReadConsistency {
+  if (!isSafeMethod(c)) {
+    return 'primary';
+  }
+  return c.req.header(READ_CONSISTENCY_HEADER) === 'primary' ? 'primary' : 'replica';
+}
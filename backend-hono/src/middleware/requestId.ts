@@ -0,0 +1,23 @@
+import { Context, Next } from 'hono';
+
+export const REQUEST_ID_HEADER = 'X-Request-Id';
+
+// Incoming IDs are echoed into logs and headers, so only accept short, printable tokens
+const REQUEST_ID_PATTERN = /^[A-Za-z0-9._:-]{1,128}$/;
+
+export interface RequestIdEnv {
+  Variables: {
+    requestId: string;
+  };
+}
+
+// Reuses the caller's X-Request-Id (e.g. from the frontend or an upstream proxy) so one ID
+// follows a request across services; otherwise a new one is generated
+export const requestIdMiddleware = async (c: Context<RequestIdEnv>, next: Next) => {
+  const incoming = c.req.header(REQUEST_ID_HEADER);
+  const requestId = incoming && REQUEST_ID_PATTERN.test(incoming) ? incoming : crypto.randomUUID();
+
+  c.set('requestId', requestId);
+  c.header(REQUEST_ID_HEADER, requestId);
+  await next();
+};
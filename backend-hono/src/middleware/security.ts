@@ -0,0 +1,65 @@
+import { Context, Next } from 'hono';
+import { cors } from 'hono/cors';
+import { secureHeaders } from 'hono/secure-headers';
+import { CSRF_HEADER } from './csrf';
+import { READ_CONSISTENCY_HEADER } from './readConsistency';
+import { REQUEST_ID_HEADER } from './requestId';
+
+export interface SecurityEnv {
+  Bindings: {
+    // Comma-separated list, e.g. "https://pokedict.pages.dev,https://staging.pokedict.pages.dev"
+    ALLOWED_ORIGIN?: string;
+    ENVIRONMENT?: string;
+  };
+}
+
+const DEVELOPMENT_ORIGINS = ['http://localhost:5173', 'http://127.0.0.1:5173'];
+
+// One year, the minimum accepted by browser HSTS preload lists
+const HSTS_MAX_AGE = 31536000;
+
+export function parseAllowedOrigins(value: string | undefined, environment: string): string[] {
+  const origins = (value ?? '')
+    .split(',')
+    .map((origin) => origin.trim().replace(/\/$/, ''))
+    .filter((origin) => origin !== '');
+  // Deployed environments must list their frontends explicitly; only local development
+  // falls back to the Vite dev server
+  if (origins.length === 0 && environment === 'development') {
+    return DEVELOPMENT_ORIGINS;
+  }
+  return origins;
+}
+
+export const corsMiddleware = async (c: Context<SecurityEnv>, next: Next) => {
+  const environment = c.env.ENVIRONMENT || 'development';
+  const allowedOrigins = parseAllowedOrigins(c.env.ALLOWED_ORIGIN, environment);
+
+  return cors({
+    // Echo the request origin only when it is allowed; otherwise no CORS headers are sent
+    origin: (origin) => (allowedOrigins.includes(origin) ? origin : null),
+    credentials: true,
+    allowMethods: ['GET', 'POST', 'PUT', 'PATCH', 'DELETE', 'OPTIONS'],
+    allowHeaders: [
+      'Authorization',
+      'Content-Type',
+      CSRF_HEADER,
+      READ_CONSISTENCY_HEADER,
+      REQUEST_ID_HEADER,
+    ],
+    exposeHeaders: [REQUEST_ID_HEADER],
+    maxAge: 3600,
+  })(c, next);
+};
+
+// HSTS is only sent from deployed environments, which are always served over HTTPS
+export const securityHeadersMiddleware = async (c: Context<SecurityEnv>, next: Next) => {
+  const environment = c.env.ENVIRONMENT || 'development';
+
+  return secureHeaders({
+    strictTransportSecurity:
+      environment === 'development' ? false : `max-age=${HSTS_MAX_AGE}; includeSubDomains`,
+    xContentTypeOptions: 'nosniff',
+    xFrameOptions: 'DENY',
+  })(c, next);
+};
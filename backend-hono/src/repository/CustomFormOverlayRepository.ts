@@ -0,0 +1,113 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { Encounter } from '../domain/valueobject/Encounter';
+import { FormMedia } from '../domain/valueobject/FormMedia';
+import { GameVersion } from '../domain/valueobject/GameVersion';
+import { FormId, SpeciesId } from '../domain/valueobject/PokemonId';
+import { PokemonType, TypeSet } from '../domain/valueobject/PokemonType';
+import { CustomFormRepository } from './interface/CustomFormRepository';
+import { PokemonFormFilter, PokemonFormRepository } from './interface/PokemonFormRepository';
+
+// Official forms plus the signed-in user's custom forms, for the services that take forms by
+// ID: teams and calculations can then use both alike. Custom forms are only found by ID and only
+// by their owner, so listings, search and exports stay official data; writes go to official data.
+export class CustomFormOverlayRepository implements PokemonFormRepository {
+  constructor(
+    private readonly official: PokemonFormRepository,
+    private readonly customForms: CustomFormRepository,
+    // Read per call, as the user is known only once the auth middleware has run
+    private readonly ownerId: () => string | undefined
+  ) {}
+
+  async findById(formId: FormId): Promise<PokemonMaster | null> {
+    if (!FormId.isCustom(formId)) {
+      return this.official.findById(formId);
+    }
+    const [form] = await this.findCustom([formId]);
+    return form ?? null;
+  }
+
+  async findByIds(formIds: FormId[]): Promise<PokemonMaster[]> {
+    const custom = formIds.filter((formId) => FormId.isCustom(formId));
+    if (custom.length === 0) {
+      return this.official.findByIds(formIds);
+    }
+    const [officialForms, customForms] = await Promise.all([
+      this.official.findByIds(formIds.filter((formId) => !FormId.isCustom(formId))),
+      this.findCustom(custom),
+    ]);
+    return [...officialForms, ...customForms];
+  }
+
+  findBySpeciesId(speciesId: SpeciesId): Promise<PokemonMaster[]> {
+    return this.official.findBySpeciesId(speciesId);
+  }
+
+  findAll(filter?: PokemonFormFilter): Promise<PokemonMaster[]> {
+    return this.official.findAll(filter);
+  }
+
+  findPage(filter: PokemonFormFilter, limit: number, after?: FormId): Promise<PokemonMaster[]> {
+    return this.official.findPage(filter, limit, after);
+  }
+
+  findByNameKeys(keys: string[]): Promise<PokemonMaster[]> {
+    return this.official.findByNameKeys(keys);
+  }
+
+  async findByIdsOrNameKeys(formIds: FormId[], keys: string[]): Promise<PokemonMaster[]> {
+    const custom = formIds.filter((formId) => FormId.isCustom(formId));
+    if (custom.length === 0) {
+      return this.official.findByIdsOrNameKeys(formIds, keys);
+    }
+    const [officialForms, customForms] = await Promise.all([
+      this.official.findByIdsOrNameKeys(formIds.filter((formId) => !FormId.isCustom(formId)), keys),
+      this.findCustom(custom),
+    ]);
+    return [...officialForms, ...customForms];
+  }
+
+  findByTypeset(typeset: TypeSet): Promise<PokemonMaster[]> {
+    return this.official.findByTypeset(typeset);
+  }
+
+  findContainingType(type: PokemonType): Promise<PokemonMaster[]> {
+    return this.official.findContainingType(type);
+  }
+
+  findByAbility(ability: string): Promise<PokemonMaster[]> {
+    return this.official.findByAbility(ability);
+  }
+
+  save(form: PokemonMaster): Promise<void> {
+    return this.official.save(form);
+  }
+
+  rebuildNameIndex(): Promise<number> {
+    return this.official.rebuildNameIndex();
+  }
+
+  findEncounterLocations(gameVersion: GameVersion): Promise<string[]> {
+    return this.official.findEncounterLocations(gameVersion);
+  }
+
+  saveEncounters(formId: FormId, encounters: Encounter[]): Promise<void> {
+    return this.official.saveEncounters(formId, encounters);
+  }
+
+  findMedia(formId: FormId): Promise<FormMedia | null> {
+    return this.official.findMedia(formId);
+  }
+
+  saveMedia(formId: FormId, media: FormMedia): Promise<void> {
+    return this.official.saveMedia(formId, media);
+  }
+
+  private async findCustom(formIds: FormId[]): Promise<PokemonMaster[]> {
+    const ownerId = this.ownerId();
+    if (!ownerId) {
+      return [];
+    }
+    const forms = await this.customForms.findByIds(ownerId, formIds);
+    return forms.map((form) => form.toPokemonMaster());
+  }
+}
@@ -0,0 +1,205 @@
+import { getReplicatedDb, ReadConsistency } from '../db/connection';
+import * as postgresSchema from '../db/schema';
+import { getSqliteDb, isSqliteUrl } from '../db/sqlite/connection';
+import * as sqliteSchema from '../db/sqlite/schema';
+import { DrizzleAbilityRepository } from './drizzle/DrizzleAbilityRepository';
+import { DrizzleAnalysisCacheRepository } from './drizzle/DrizzleAnalysisCacheRepository';
+import { DrizzleApiKeyRepository } from './drizzle/DrizzleApiKeyRepository';
+import { DrizzleBattleRecordRepository } from './drizzle/DrizzleBattleRecordRepository';
+import { DrizzleCustomFormRepository } from './drizzle/DrizzleCustomFormRepository';
+import { DrizzleDamageBenchmarkRepository } from './drizzle/DrizzleDamageBenchmarkRepository';
+import { DrizzleDataImportRepository } from './drizzle/DrizzleDataImportRepository';
+import { DrizzleDatasetSnapshotRepository } from './drizzle/DrizzleDatasetSnapshotRepository';
+import { DrizzleDraftLeagueRepository } from './drizzle/DrizzleDraftLeagueRepository';
+import { DrizzleEmailChangeRepository } from './drizzle/DrizzleEmailChangeRepository';
+import { DrizzleItemRepository } from './drizzle/DrizzleItemRepository';
+import { DrizzleLoginFailureRepository } from './drizzle/DrizzleLoginFailureRepository';
+import { DrizzleModerationQueueRepository } from './drizzle/DrizzleModerationQueueRepository';
+import { DrizzleMoveRepository } from './drizzle/DrizzleMoveRepository';
+import { DrizzleNotificationRepository } from './drizzle/DrizzleNotificationRepository';
+import { DrizzlePersonalDataRepository } from './drizzle/DrizzlePersonalDataRepository';
+import { DrizzlePokemonFormRepository } from './drizzle/DrizzlePokemonFormRepository';
+import { DrizzlePokemonSpeciesRepository } from './drizzle/DrizzlePokemonSpeciesRepository';
+import { DrizzleRefreshTokenRepository } from './drizzle/DrizzleRefreshTokenRepository';
+import { DrizzleRunTrackerRepository } from './drizzle/DrizzleRunTrackerRepository';
+import { DrizzleTeamCommentRepository } from './drizzle/DrizzleTeamCommentRepository';
+import { DrizzleTeamEventOutboxRepository } from './drizzle/DrizzleTeamEventOutboxRepository';
+import { DrizzleTeamFlagRepository } from './drizzle/DrizzleTeamFlagRepository';
+import { DrizzleTeamLikeRepository } from './drizzle/DrizzleTeamLikeRepository';
+import { DrizzleTeamRepository } from './drizzle/DrizzleTeamRepository';
+import { DrizzleTournamentRepository } from './drizzle/DrizzleTournamentRepository';
+import { DrizzleTypeQuizRepository } from './drizzle/DrizzleTypeQuizRepository';
+import { DrizzleUsageStatsRepository } from './drizzle/DrizzleUsageStatsRepository';
+import { DrizzleUserDataArchiveRepository } from './drizzle/DrizzleUserDataArchiveRepository';
+import { DrizzleUserPreferencesRepository } from './drizzle/DrizzleUserPreferencesRepository';
+import { DrizzleUserRepository } from './drizzle/DrizzleUserRepository';
+import { DrizzleWebhookRepository } from './drizzle/DrizzleWebhookRepository';
+import { Db, Dialect, Schema, sharedSchema } from './drizzle/types';
+import { AbilityRepository } from './interface/AbilityRepository';
+import { AnalysisCacheRepository } from './interface/AnalysisCacheRepository';
+import { ApiKeyRepository } from './interface/ApiKeyRepository';
+import { BattleRecordRepository } from './interface/BattleRecordRepository';
+import { CustomFormRepository } from './interface/CustomFormRepository';
+import { DamageBenchmarkRepository } from './interface/DamageBenchmarkRepository';
+import { DataImportRepository } from './interface/DataImportRepository';
+import { DatasetSnapshotRepository } from './interface/DatasetSnapshotRepository';
+import { DraftLeagueRepository } from './interface/DraftLeagueRepository';
+import { EmailChangeRepository } from './interface/EmailChangeRepository';
+import { ItemRepository } from './interface/ItemRepository';
+import { LoginFailureRepository } from './interface/LoginFailureRepository';
+import { Mailer } from './interface/Mailer';
+import { ModerationQueueRepository } from './interface/ModerationQueueRepository';
+import { MoveRepository } from './interface/MoveRepository';
+import { NotificationRepository } from './interface/NotificationRepository';
+import { PersonalDataRepository } from './interface/PersonalDataRepository';
+import { PokemonFormRepository } from './interface/PokemonFormRepository';
+import { PokemonSpeciesRepository } from './interface/PokemonSpeciesRepository';
+import { RefreshTokenRepository } from './interface/RefreshTokenRepository';
+import { RunTrackerRepository } from './interface/RunTrackerRepository';
+import { SearchIndex } from './interface/SearchIndex';
+import { TeamCommentRepository } from './interface/TeamCommentRepository';
+import { TeamEventOutboxRepository } from './interface/TeamEventOutboxRepository';
+import { TeamFlagRepository } from './interface/TeamFlagRepository';
+import { TeamLikeRepository } from './interface/TeamLikeRepository';
+import { TeamRepository } from './interface/TeamRepository';
+import { TournamentRepository } from './interface/TournamentRepository';
+import { TypeQuizRepository } from './interface/TypeQuizRepository';
+import { UsageStatsRepository } from './interface/UsageStatsRepository';
+import { UserDataArchiveRepository } from './interface/UserDataArchiveRepository';
+import { UserPreferencesRepository } from './interface/UserPreferencesRepository';
+import { UserRepository } from './interface/UserRepository';
+import { WebhookRepository } from './interface/WebhookRepository';
+import { ConsoleMailer } from './mail/ConsoleMailer';
+import { ResendMailer } from './mail/ResendMailer';
+import { MeilisearchIndex } from './meilisearch/MeilisearchIndex';
+import { postgresDialect } from './postgres/postgresDialect';
+import { sqliteDialect } from './sqlite/sqliteDialect';
+
+export interface Repositories {
+  abilityRepository: AbilityRepository;
+  analysisCacheRepository: AnalysisCacheRepository;
+  apiKeyRepository: ApiKeyRepository;
+  battleRecordRepository: BattleRecordRepository;
+  customFormRepository: CustomFormRepository;
+  damageBenchmarkRepository: DamageBenchmarkRepository;
+  dataImportRepository: DataImportRepository;
+  datasetSnapshotRepository: DatasetSnapshotRepository;
+  draftLeagueRepository: DraftLeagueRepository;
+  emailChangeRepository: EmailChangeRepository;
+  itemRepository: ItemRepository;
+  loginFailureRepository: LoginFailureRepository;
+  moderationQueueRepository: ModerationQueueRepository;
+  moveRepository: MoveRepository;
+  notificationRepository: NotificationRepository;
+  personalDataRepository: PersonalDataRepository;
+  pokemonFormRepository: PokemonFormRepository;
+  pokemonSpeciesRepository: PokemonSpeciesRepository;
+  refreshTokenRepository: RefreshTokenRepository;
+  runTrackerRepository: RunTrackerRepository;
+  teamCommentRepository: TeamCommentRepository;
+  teamEventOutboxRepository: TeamEventOutboxRepository;
+  teamFlagRepository: TeamFlagRepository;
+  teamLikeRepository: TeamLikeRepository;
+  teamRepository: TeamRepository;
+  tournamentRepository: TournamentRepository;
+  typeQuizRepository: TypeQuizRepository;
+  usageStatsRepository: UsageStatsRepository;
+  userDataArchiveRepository: UserDataArchiveRepository;
+  userPreferencesRepository: UserPreferencesRepository;
+  userRepository: UserRepository;
+  webhookRepository: WebhookRepository;
+}
+
+export interface ReplicaOptions {
+  // Postgres read replicas; SQLite has none
+  replicaUrls: string[];
+  consistency: ReadConsistency;
+}
+
+// DATABASE_URL picks the database: a file: URL opens SQLite (self-hosting), anything else is a
+// Postgres connection string. Without replica options every query goes to the primary.
+export async function createRepositories(
+  databaseUrl?: string,
+  replicas: ReplicaOptions = { replicaUrls: [], consistency: 'primary' }
+): Promise<Repositories> {
+  // The Node.js server has no Workers bindings, so the URL comes from the environment
+  if (!databaseUrl && typeof process !== 'undefined') {
+    databaseUrl = process.env.DATABASE_URL;
+  }
+
+  if (databaseUrl && isSqliteUrl(databaseUrl)) {
+    const db = await getSqliteDb(databaseUrl);
+    // The SQLite schema mirrors the Postgres one table for table; see drizzle/types.ts
+    return createDrizzleRepositories(
+      db as unknown as Db,
+      sharedSchema(sqliteSchema),
+      sqliteDialect
+    );
+  }
+
+  const db = getReplicatedDb(databaseUrl, replicas.replicaUrls, replicas.consistency);
+  return createDrizzleRepositories(db, postgresSchema, postgresDialect);
+}
+
+function createDrizzleRepositories(db: Db, schema: Schema, dialect: Dialect): Repositories {
+  return {
+    abilityRepository: new DrizzleAbilityRepository(db, schema, dialect),
+    analysisCacheRepository: new DrizzleAnalysisCacheRepository(db, schema),
+    apiKeyRepository: new DrizzleApiKeyRepository(db, schema),
+    battleRecordRepository: new DrizzleBattleRecordRepository(db, schema),
+    customFormRepository: new DrizzleCustomFormRepository(db, schema),
+    damageBenchmarkRepository: new DrizzleDamageBenchmarkRepository(db, schema),
+    dataImportRepository: new DrizzleDataImportRepository(db, schema),
+    datasetSnapshotRepository: new DrizzleDatasetSnapshotRepository(db, schema),
+    draftLeagueRepository: new DrizzleDraftLeagueRepository(db, schema),
+    emailChangeRepository: new DrizzleEmailChangeRepository(db, schema),
+    itemRepository: new DrizzleItemRepository(db, schema, dialect),
+    loginFailureRepository: new DrizzleLoginFailureRepository(db, schema),
+    moderationQueueRepository: new DrizzleModerationQueueRepository(db, schema),
+    moveRepository: new DrizzleMoveRepository(db, schema, dialect),
+    notificationRepository: new DrizzleNotificationRepository(db, schema),
+    personalDataRepository: new DrizzlePersonalDataRepository(db, schema, dialect),
+    pokemonFormRepository: new DrizzlePokemonFormRepository(db, schema),
+    pokemonSpeciesRepository: new DrizzlePokemonSpeciesRepository(db, schema),
+    refreshTokenRepository: new DrizzleRefreshTokenRepository(db, schema),
+    runTrackerRepository: new DrizzleRunTrackerRepository(db, schema),
+    teamCommentRepository: new DrizzleTeamCommentRepository(db, schema),
+    teamEventOutboxRepository: new DrizzleTeamEventOutboxRepository(db, schema),
+    teamFlagRepository: new DrizzleTeamFlagRepository(db, schema),
+    teamLikeRepository: new DrizzleTeamLikeRepository(db, schema),
+    teamRepository: new DrizzleTeamRepository(db, schema),
+    tournamentRepository: new DrizzleTournamentRepository(db, schema),
+    typeQuizRepository: new DrizzleTypeQuizRepository(db, schema),
+    usageStatsRepository: new DrizzleUsageStatsRepository(db, schema),
+    userDataArchiveRepository: new DrizzleUserDataArchiveRepository(db, schema, dialect),
+    userPreferencesRepository: new DrizzleUserPreferencesRepository(db, schema),
+    userRepository: new DrizzleUserRepository(db, schema, dialect),
+    webhookRepository: new DrizzleWebhookRepository(db, schema),
+  };
+}
+
+// Full-text search is optional: without MEILISEARCH_URL there is no index, and imports skip it
+export function createSearchIndex(url?: string, apiKey?: string): SearchIndex | undefined {
+  if (!url && typeof process !== 'undefined') {
+    url = process.env.MEILISEARCH_URL;
+    apiKey = process.env.MEILISEARCH_API_KEY;
+  }
+  return url ? new MeilisearchIndex(url, apiKey) : undefined;
+}
+
+// Mail needs RESEND_API_KEY and MAIL_FROM; without them, development prints mails to the console
+// and other environments have no mailer, so features that send mail answer 503
+export function createMailer(
+  apiKey?: string,
+  from?: string,
+  environment = 'development'
+): Mailer | undefined {
+  if (!apiKey && typeof process !== 'undefined') {
+    apiKey = process.env.RESEND_API_KEY;
+    from = process.env.MAIL_FROM;
+  }
+  if (apiKey && from) {
+    return new ResendMailer(apiKey, from);
+  }
+  return environment === 'development' ? new ConsoleMailer() : undefined;
+}
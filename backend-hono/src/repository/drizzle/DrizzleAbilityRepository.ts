@@ -0,0 +1,90 @@
+import { and, asc, count, eq, SQL } from 'drizzle-orm';
+import { Ability } from '../../domain/entity/Ability';
+import { toLookupKey } from '../../domain/valueobject/Identifier';
+import {
+  AbilityPage,
+  AbilityRepository,
+  AbilityRepositoryError,
+  AbilitySearchFilter,
+} from '../interface/AbilityRepository';
+import { nameContains } from './search';
+import { Db, Dialect, Schema } from './types';
+
+type AbilityRow = Schema['abilities']['$inferSelect'];
+
+export class DrizzleAbilityRepository implements AbilityRepository {
+  constructor(
+    private readonly db: Db,
+    private readonly schema: Schema,
+    private readonly dialect: Dialect
+  ) {}
+
+  async findByName(name: string): Promise<Ability | null> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.abilities)
+        .where(eq(this.schema.abilities.lookupKey, toLookupKey(name)))
+        .limit(1);
+
+      return rows.length > 0 ? this.toEntity(rows[0]) : null;
+    } catch (error) {
+      throw new AbilityRepositoryError(`Failed to find ability: ${error}`);
+    }
+  }
+
+  async search(filter: AbilitySearchFilter, limit: number, offset: number): Promise<AbilityPage> {
+    const conditions: (SQL | undefined)[] = [];
+    if (filter.name) {
+      const { lookupKey, nameJp } = this.schema.abilities;
+      conditions.push(nameContains(this.dialect, lookupKey, nameJp, filter.name));
+    }
+    const where = and(...conditions);
+
+    try {
+      const [rows, [{ total }]] = await Promise.all([
+        this.db
+          .select()
+          .from(this.schema.abilities)
+          .where(where)
+          .orderBy(asc(this.schema.abilities.abilityId))
+          .limit(limit)
+          .offset(offset),
+        this.db.select({ total: count() }).from(this.schema.abilities).where(where),
+      ]);
+
+      return { abilities: rows.map((row) => this.toEntity(row)), total };
+    } catch (error) {
+      throw new AbilityRepositoryError(`Failed to search abilities: ${error}`);
+    }
+  }
+
+  async save(ability: Ability): Promise<void> {
+    const values = {
+      abilityId: ability.getId(),
+      lookupKey: toLookupKey(ability.getName()),
+      nameJp: ability.getNameJp() ?? null,
+      effect: ability.getEffect() ?? null,
+      effectJp: ability.getEffectJp() ?? null,
+    };
+
+    try {
+      await this.db
+        .insert(this.schema.abilities)
+        .values({ name: ability.getName(), ...values })
+        .onConflictDoUpdate({ target: this.schema.abilities.name, set: values });
+    } catch (error) {
+      throw new AbilityRepositoryError(`Failed to save ability: ${error}`);
+    }
+  }
+
+  private toEntity(row: AbilityRow): Ability {
+    return Ability.fromRepository({
+      id: row.abilityId,
+      name: row.name,
+      nameJp: row.nameJp ?? undefined,
+      effect: row.effect ?? undefined,
+      effectJp: row.effectJp ?? undefined,
+    });
+  }
+}
@@ -0,0 +1,58 @@
+import { and, eq } from 'drizzle-orm';
+import {
+  AnalysisCacheEntry,
+  AnalysisCacheRepository,
+  AnalysisCacheRepositoryError,
+} from '../interface/AnalysisCacheRepository';
+import { Db, Schema } from './types';
+
+export class DrizzleAnalysisCacheRepository implements AnalysisCacheRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async find(key: string, version: number): Promise<string | null> {
+    try {
+      const [row] = await this.db
+        .select({ result: this.schema.analysisCache.result })
+        .from(this.schema.analysisCache)
+        .where(
+          and(
+            eq(this.schema.analysisCache.cacheKey, key),
+            eq(this.schema.analysisCache.version, version)
+          )
+        )
+        .limit(1);
+      return row?.result ?? null;
+    } catch (error) {
+      throw new AnalysisCacheRepositoryError(`Failed to find cached analysis: ${error}`);
+    }
+  }
+
+  async save(entry: AnalysisCacheEntry): Promise<void> {
+    const row = {
+      kind: entry.kind,
+      version: entry.version,
+      result: entry.result,
+      createdAt: new Date(),
+    };
+
+    try {
+      await this.db
+        .insert(this.schema.analysisCache)
+        .values({ cacheKey: entry.key, ...row })
+        .onConflictDoUpdate({ target: this.schema.analysisCache.cacheKey, set: row });
+    } catch (error) {
+      throw new AnalysisCacheRepositoryError(`Failed to save cached analysis: ${error}`);
+    }
+  }
+
+  async deleteAll(): Promise<number> {
+    try {
+      const rows = await this.db
+        .delete(this.schema.analysisCache)
+        .returning({ cacheKey: this.schema.analysisCache.cacheKey });
+      return rows.length;
+    } catch (error) {
+      throw new AnalysisCacheRepositoryError(`Failed to clear cached analyses: ${error}`);
+    }
+  }
+}
@@ -0,0 +1,149 @@
+import { desc, eq, inArray, sql } from 'drizzle-orm';
+import { ApiKey } from '../../domain/entity/ApiKey';
+import { ApiKeyScope, ApiKeyScopeSchema } from '../../domain/valueobject/ApiKeyScope';
+import { ApiKeyRepository, ApiKeyRepositoryError } from '../interface/ApiKeyRepository';
+import { Db, Schema } from './types';
+
+type ApiKeyRow = Schema['apiKeys']['$inferSelect'];
+
+export class DrizzleApiKeyRepository implements ApiKeyRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(keyId: string): Promise<ApiKey | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.apiKeys)
+        .where(eq(this.schema.apiKeys.keyId, keyId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [apiKey] = await this.toEntities(result);
+      return apiKey;
+    } catch (error) {
+      throw new ApiKeyRepositoryError(`Failed to find API key by ID: ${error}`);
+    }
+  }
+
+  async findByHash(keyHash: string): Promise<ApiKey | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.apiKeys)
+        .where(eq(this.schema.apiKeys.keyHash, keyHash))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [apiKey] = await this.toEntities(result);
+      return apiKey;
+    } catch (error) {
+      throw new ApiKeyRepositoryError(`Failed to find API key: ${error}`);
+    }
+  }
+
+  async findAll(): Promise<ApiKey[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.apiKeys)
+        .orderBy(desc(this.schema.apiKeys.createdAt));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new ApiKeyRepositoryError(`Failed to list API keys: ${error}`);
+    }
+  }
+
+  async save(apiKey: ApiKey): Promise<void> {
+    const keyId = apiKey.getKeyId();
+    const values = {
+      name: apiKey.getName(),
+      dailyQuota: apiKey.getDailyQuota(),
+      revokedAt: apiKey.getRevokedAt() ?? null,
+    };
+
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .insert(this.schema.apiKeys)
+          .values({
+            keyId,
+            keyHash: apiKey.getKeyHash(),
+            displayPrefix: apiKey.getDisplayPrefix(),
+            createdAt: apiKey.getCreatedAt(),
+            ...values,
+          })
+          .onConflictDoUpdate({ target: this.schema.apiKeys.keyId, set: values });
+
+        await tx.delete(this.schema.apiKeyScopes).where(eq(this.schema.apiKeyScopes.keyId, keyId));
+
+        const scopes = apiKey.getScopes();
+        if (scopes.length > 0) {
+          await tx
+            .insert(this.schema.apiKeyScopes)
+            .values(scopes.map((scope) => ({ keyId, scope })));
+        }
+      });
+    } catch (error) {
+      throw new ApiKeyRepositoryError(`Failed to save API key: ${error}`);
+    }
+  }
+
+  async recordRequest(keyId: string, day: string): Promise<number> {
+    try {
+      const [row] = await this.db
+        .insert(this.schema.apiKeyRequests)
+        .values({ keyId, day, requests: 1 })
+        .onConflictDoUpdate({
+          target: [this.schema.apiKeyRequests.keyId, this.schema.apiKeyRequests.day],
+          set: { requests: sql`${this.schema.apiKeyRequests.requests} + 1` },
+        })
+        .returning({ requests: this.schema.apiKeyRequests.requests });
+
+      return row.requests;
+    } catch (error) {
+      throw new ApiKeyRepositoryError(`Failed to record API key request: ${error}`);
+    }
+  }
+
+  private async toEntities(rows: ApiKeyRow[]): Promise<ApiKey[]> {
+    const scopes = await this.findScopes(rows.map((row) => row.keyId));
+    return rows.map((row) =>
+      ApiKey.fromRepository(
+        row.keyId,
+        row.name,
+        row.keyHash,
+        row.displayPrefix,
+        scopes.get(row.keyId) ?? [],
+        row.dailyQuota,
+        row.createdAt,
+        row.revokedAt ?? undefined
+      )
+    );
+  }
+
+  private async findScopes(keyIds: string[]): Promise<Map<string, ApiKeyScope[]>> {
+    const byKey = new Map<string, ApiKeyScope[]>();
+    if (keyIds.length === 0) {
+      return byKey;
+    }
+
+    const rows = await this.db
+      .select()
+      .from(this.schema.apiKeyScopes)
+      .where(inArray(this.schema.apiKeyScopes.keyId, keyIds));
+
+    for (const row of rows) {
+      const scopes = byKey.get(row.keyId) ?? [];
+      scopes.push(ApiKeyScopeSchema.parse(row.scope));
+      byKey.set(row.keyId, scopes);
+    }
+    return byKey;
+  }
+}
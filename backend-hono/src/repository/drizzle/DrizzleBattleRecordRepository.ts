@@ -0,0 +1,260 @@
+import { and, asc, count, desc, eq, inArray, isNotNull, SQL } from 'drizzle-orm';
+import { BattleRecord, BattleResultSchema } from '../../domain/entity/BattleRecord';
+import { ArchetypeSchema } from '../../domain/service/ArchetypeClassifier';
+import { FormatIdSchema } from '../../domain/valueobject/Format';
+import { FormId } from '../../domain/valueobject/PokemonId';
+import {
+  ArchetypeBattleTally,
+  BattleRecordFilter,
+  BattleRecordRepository,
+  BattleRecordRepositoryError,
+  BattleTally,
+  OpponentBattleTally,
+  TeamBattleTally,
+} from '../interface/BattleRecordRepository';
+import { Db, Schema } from './types';
+
+type RecordRow = Schema['battleRecords']['$inferSelect'];
+
+// Folds rows counted per key and result into one tally per key, most battles first
+function toTallies<K>(rows: { key: K; result: string; battles: number }[]): [K, BattleTally][] {
+  const tallies = new Map<K, BattleTally>();
+  for (const row of rows) {
+    const tally = tallies.get(row.key) ?? { wins: 0, losses: 0 };
+    if (row.result === 'win') {
+      tally.wins += row.battles;
+    } else {
+      tally.losses += row.battles;
+    }
+    tallies.set(row.key, tally);
+  }
+  return [...tallies].sort(([, a], [, b]) => b.wins + b.losses - (a.wins + a.losses));
+}
+
+export class DrizzleBattleRecordRepository implements BattleRecordRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async create(record: BattleRecord): Promise<void> {
+    const recordId = record.getRecordId();
+    const opponent = record.getOpponent();
+
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx.insert(this.schema.battleRecords).values({
+          recordId,
+          userId: record.getUserId(),
+          teamId: record.getTeamId(),
+          format: record.getFormat(),
+          result: record.getResult(),
+          opponentName: opponent.name,
+          opponentArchetype: opponent.archetype,
+          notes: record.getNotes(),
+          playedAt: record.getPlayedAt(),
+          createdAt: record.getCreatedAt(),
+        });
+        if (opponent.formIds.length > 0) {
+          await tx
+            .insert(this.schema.battleRecordOpponents)
+            .values(opponent.formIds.map((formId, slot) => ({ recordId, slot, formId })));
+        }
+      });
+    } catch (error) {
+      throw new BattleRecordRepositoryError(`Failed to create battle record: ${error}`);
+    }
+  }
+
+  async findById(recordId: string): Promise<BattleRecord | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.battleRecords)
+        .where(eq(this.schema.battleRecords.recordId, recordId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [record] = await this.toEntities(result);
+      return record;
+    } catch (error) {
+      throw new BattleRecordRepositoryError(`Failed to find battle record by ID: ${error}`);
+    }
+  }
+
+  async findByUser(
+    userId: string,
+    filter: BattleRecordFilter,
+    limit: number,
+    offset: number
+  ): Promise<BattleRecord[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.battleRecords)
+        .where(and(...this.filterConditions(userId, filter)))
+        .orderBy(
+          desc(this.schema.battleRecords.playedAt),
+          desc(this.schema.battleRecords.createdAt)
+        )
+        .limit(limit)
+        .offset(offset);
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new BattleRecordRepositoryError(`Failed to find battle records: ${error}`);
+    }
+  }
+
+  async countByUser(userId: string, filter: BattleRecordFilter): Promise<number> {
+    try {
+      const [row] = await this.db
+        .select({ records: count() })
+        .from(this.schema.battleRecords)
+        .where(and(...this.filterConditions(userId, filter)));
+      return row?.records ?? 0;
+    } catch (error) {
+      throw new BattleRecordRepositoryError(`Failed to count battle records: ${error}`);
+    }
+  }
+
+  async delete(recordId: string): Promise<boolean> {
+    try {
+      return await this.db.transaction(async (tx) => {
+        await tx
+          .delete(this.schema.battleRecordOpponents)
+          .where(eq(this.schema.battleRecordOpponents.recordId, recordId));
+        const deleted = await tx
+          .delete(this.schema.battleRecords)
+          .where(eq(this.schema.battleRecords.recordId, recordId))
+          .returning({ recordId: this.schema.battleRecords.recordId });
+        return deleted.length > 0;
+      });
+    } catch (error) {
+      throw new BattleRecordRepositoryError(`Failed to delete battle record: ${error}`);
+    }
+  }
+
+  async tallyByTeam(userId: string): Promise<TeamBattleTally[]> {
+    try {
+      const rows = await this.db
+        .select({
+          key: this.schema.battleRecords.teamId,
+          result: this.schema.battleRecords.result,
+          battles: count(),
+        })
+        .from(this.schema.battleRecords)
+        .where(eq(this.schema.battleRecords.userId, userId))
+        .groupBy(this.schema.battleRecords.teamId, this.schema.battleRecords.result);
+
+      return toTallies(rows).map(([teamId, tally]) => ({ teamId, ...tally }));
+    } catch (error) {
+      throw new BattleRecordRepositoryError(`Failed to tally battles by team: ${error}`);
+    }
+  }
+
+  async tallyByArchetype(userId: string, teamId: string | null): Promise<ArchetypeBattleTally[]> {
+    try {
+      const rows = await this.db
+        .select({
+          key: this.schema.battleRecords.opponentArchetype,
+          result: this.schema.battleRecords.result,
+          battles: count(),
+        })
+        .from(this.schema.battleRecords)
+        .where(
+          and(
+            ...this.filterConditions(userId, teamId ? { teamId } : {}),
+            isNotNull(this.schema.battleRecords.opponentArchetype)
+          )
+        )
+        .groupBy(this.schema.battleRecords.opponentArchetype, this.schema.battleRecords.result);
+
+      return toTallies(rows).map(([archetype, tally]) => ({
+        archetype: ArchetypeSchema.parse(archetype),
+        ...tally,
+      }));
+    } catch (error) {
+      throw new BattleRecordRepositoryError(`Failed to tally battles by archetype: ${error}`);
+    }
+  }
+
+  async tallyByOpponent(userId: string, teamId: string | null): Promise<OpponentBattleTally[]> {
+    try {
+      const rows = await this.db
+        .select({
+          key: this.schema.battleRecordOpponents.formId,
+          result: this.schema.battleRecords.result,
+          battles: count(),
+        })
+        .from(this.schema.battleRecordOpponents)
+        .innerJoin(
+          this.schema.battleRecords,
+          eq(this.schema.battleRecords.recordId, this.schema.battleRecordOpponents.recordId)
+        )
+        .where(and(...this.filterConditions(userId, teamId ? { teamId } : {})))
+        .groupBy(this.schema.battleRecordOpponents.formId, this.schema.battleRecords.result);
+
+      return toTallies(rows).map(([formId, tally]) => ({
+        formId: FormId.fromRepository(formId),
+        ...tally,
+      }));
+    } catch (error) {
+      throw new BattleRecordRepositoryError(`Failed to tally battles by opponent: ${error}`);
+    }
+  }
+
+  private filterConditions(userId: string, filter: BattleRecordFilter): SQL[] {
+    const conditions = [eq(this.schema.battleRecords.userId, userId)];
+    if (filter.teamId) {
+      conditions.push(eq(this.schema.battleRecords.teamId, filter.teamId));
+    }
+    if (filter.result) {
+      conditions.push(eq(this.schema.battleRecords.result, filter.result));
+    }
+    if (filter.archetype) {
+      conditions.push(eq(this.schema.battleRecords.opponentArchetype, filter.archetype));
+    }
+    if (filter.formId !== undefined) {
+      const withForm = this.db
+        .select({ recordId: this.schema.battleRecordOpponents.recordId })
+        .from(this.schema.battleRecordOpponents)
+        .where(eq(this.schema.battleRecordOpponents.formId, filter.formId));
+      conditions.push(inArray(this.schema.battleRecords.recordId, withForm));
+    }
+    return conditions;
+  }
+
+  private async toEntities(rows: RecordRow[]): Promise<BattleRecord[]> {
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const opponentRows = await this.db
+      .select()
+      .from(this.schema.battleRecordOpponents)
+      .where(inArray(this.schema.battleRecordOpponents.recordId, rows.map((row) => row.recordId)))
+      .orderBy(asc(this.schema.battleRecordOpponents.slot));
+
+    return rows.map((row) =>
+      BattleRecord.fromRepository(
+        row.recordId,
+        row.userId,
+        row.teamId,
+        row.format === null ? null : FormatIdSchema.parse(row.format),
+        BattleResultSchema.parse(row.result),
+        {
+          name: row.opponentName,
+          archetype:
+            row.opponentArchetype === null ? null : ArchetypeSchema.parse(row.opponentArchetype),
+          formIds: opponentRows
+            .filter((opponent) => opponent.recordId === row.recordId)
+            .map((opponent) => FormId.fromRepository(opponent.formId)),
+        },
+        row.notes,
+        row.playedAt,
+        row.createdAt
+      )
+    );
+  }
+}
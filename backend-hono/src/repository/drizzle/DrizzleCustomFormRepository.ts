@@ -0,0 +1,159 @@
+import { and, asc, count, eq, inArray } from 'drizzle-orm';
+import { CustomForm, CustomFormProps } from '../../domain/entity/CustomForm';
+import { AbilitySlots } from '../../domain/valueobject/AbilitySlots';
+import { FormId } from '../../domain/valueobject/PokemonId';
+import { PokemonTypeSchema, TypeSet } from '../../domain/valueobject/PokemonType';
+import { CustomFormRepository, CustomFormRepositoryError } from '../interface/CustomFormRepository';
+import { Db, Schema } from './types';
+
+type CustomFormRow = Schema['customForms']['$inferSelect'];
+
+export class DrizzleCustomFormRepository implements CustomFormRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(formId: FormId): Promise<CustomForm | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.customForms)
+        .where(eq(this.schema.customForms.formId, formId))
+        .limit(1);
+
+      return result.length > 0 ? this.toEntity(result[0]) : null;
+    } catch (error) {
+      throw new CustomFormRepositoryError(`Failed to find custom form by ID: ${error}`);
+    }
+  }
+
+  async findByIds(ownerId: string, formIds: FormId[]): Promise<CustomForm[]> {
+    if (formIds.length === 0) {
+      return [];
+    }
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.customForms)
+        .where(
+          and(
+            eq(this.schema.customForms.ownerId, ownerId),
+            inArray(this.schema.customForms.formId, formIds)
+          )
+        );
+
+      return rows.map((row) => this.toEntity(row));
+    } catch (error) {
+      throw new CustomFormRepositoryError(`Failed to find custom forms by ID: ${error}`);
+    }
+  }
+
+  async findByOwner(ownerId: string): Promise<CustomForm[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.customForms)
+        .where(eq(this.schema.customForms.ownerId, ownerId))
+        .orderBy(asc(this.schema.customForms.formId));
+
+      return rows.map((row) => this.toEntity(row));
+    } catch (error) {
+      throw new CustomFormRepositoryError(`Failed to find custom forms by owner: ${error}`);
+    }
+  }
+
+  async countByOwner(ownerId: string): Promise<number> {
+    try {
+      const [row] = await this.db
+        .select({ forms: count() })
+        .from(this.schema.customForms)
+        .where(eq(this.schema.customForms.ownerId, ownerId));
+      return row.forms;
+    } catch (error) {
+      throw new CustomFormRepositoryError(`Failed to count custom forms: ${error}`);
+    }
+  }
+
+  async create(ownerId: string, props: CustomFormProps): Promise<CustomForm> {
+    try {
+      const [row] = await this.db
+        .insert(this.schema.customForms)
+        .values({ ownerId, ...this.toValues(props) })
+        .returning();
+      return this.toEntity(row);
+    } catch (error) {
+      throw new CustomFormRepositoryError(`Failed to create custom form: ${error}`);
+    }
+  }
+
+  async update(form: CustomForm): Promise<void> {
+    const props = {
+      name: form.getName(),
+      typeset: form.getTypeset(),
+      baseStats: form.getBaseStats(),
+      abilities: form.getAbilities(),
+    };
+    try {
+      await this.db
+        .update(this.schema.customForms)
+        .set({ ...this.toValues(props), updatedAt: form.getUpdatedAt() })
+        .where(eq(this.schema.customForms.formId, form.getFormId()));
+    } catch (error) {
+      throw new CustomFormRepositoryError(`Failed to update custom form: ${error}`);
+    }
+  }
+
+  async delete(formId: FormId): Promise<void> {
+    try {
+      await this.db
+        .delete(this.schema.customForms)
+        .where(eq(this.schema.customForms.formId, formId));
+    } catch (error) {
+      throw new CustomFormRepositoryError(`Failed to delete custom form: ${error}`);
+    }
+  }
+
+  private toValues(props: CustomFormProps) {
+    return {
+      name: props.name,
+      type1: props.typeset.type1,
+      type2: props.typeset.type2 ?? null,
+      hp: props.baseStats.hp,
+      attack: props.baseStats.attack,
+      defense: props.baseStats.defense,
+      specialAttack: props.baseStats.specialAttack,
+      specialDefense: props.baseStats.specialDefense,
+      speed: props.baseStats.speed,
+      ability1: props.abilities.primary,
+      ability2: props.abilities.secondary ?? null,
+      hiddenAbility: props.abilities.hidden ?? null,
+    };
+  }
+
+  private toEntity(row: CustomFormRow): CustomForm {
+    return CustomForm.fromRepository(
+      FormId.fromRepository(row.formId),
+      row.ownerId,
+      {
+        name: row.name,
+        typeset: new TypeSet(
+          PokemonTypeSchema.parse(row.type1),
+          row.type2 ? PokemonTypeSchema.parse(row.type2) : undefined
+        ),
+        baseStats: {
+          hp: row.hp,
+          attack: row.attack,
+          defense: row.defense,
+          specialAttack: row.specialAttack,
+          specialDefense: row.specialDefense,
+          speed: row.speed,
+        },
+        abilities: new AbilitySlots(
+          row.ability1,
+          row.ability2 ?? undefined,
+          row.hiddenAbility ?? undefined
+        ),
+      },
+      row.createdAt,
+      row.updatedAt
+    );
+  }
+}
@@ -0,0 +1,141 @@
+import { and, asc, eq } from 'drizzle-orm';
+import { BenchmarkRoleSchema, DamageBenchmark } from '../../domain/entity/DamageBenchmark';
+import { FormatId, FormatIdSchema } from '../../domain/valueobject/Format';
+import { NatureSchema } from '../../domain/valueobject/Nature';
+import { PokemonBuild } from '../../domain/valueobject/PokemonBuild';
+import { FormId } from '../../domain/valueobject/PokemonId';
+import { PokemonTypeSchema } from '../../domain/valueobject/PokemonType';
+import { EVStats } from '../../domain/valueobject/Stats';
+import {
+  DamageBenchmarkRepository,
+  DamageBenchmarkRepositoryError,
+} from '../interface/DamageBenchmarkRepository';
+import { Db, Schema } from './types';
+
+type DamageBenchmarkRow = Schema['damageBenchmarks']['$inferSelect'];
+
+export class DrizzleDamageBenchmarkRepository implements DamageBenchmarkRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(benchmarkId: string): Promise<DamageBenchmark | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.damageBenchmarks)
+        .where(eq(this.schema.damageBenchmarks.benchmarkId, benchmarkId))
+        .limit(1);
+
+      return result.length > 0 ? this.toEntity(result[0]) : null;
+    } catch (error) {
+      throw new DamageBenchmarkRepositoryError(`Failed to find benchmark by ID: ${error}`);
+    }
+  }
+
+  async findByOwner(ownerId: string, format: FormatId): Promise<DamageBenchmark[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.damageBenchmarks)
+        .where(
+          and(
+            eq(this.schema.damageBenchmarks.ownerId, ownerId),
+            eq(this.schema.damageBenchmarks.format, format)
+          )
+        )
+        .orderBy(asc(this.schema.damageBenchmarks.createdAt));
+
+      return rows.map((row) => this.toEntity(row));
+    } catch (error) {
+      throw new DamageBenchmarkRepositoryError(`Failed to find benchmarks by owner: ${error}`);
+    }
+  }
+
+  async save(benchmark: DamageBenchmark): Promise<void> {
+    const build = benchmark.getBuild();
+    const ev = build.getEV();
+    const iv = build.getIV();
+    const values = {
+      name: benchmark.getName(),
+      role: benchmark.getRole(),
+      formId: build.getFormId(),
+      nature: build.getNature(),
+      ability: build.getAbility() ?? null,
+      item: build.getHeldItem() ?? null,
+      teraType: build.getTeraType() ?? null,
+      move: benchmark.getMove() ?? null,
+      evHp: ev.hp,
+      evAttack: ev.attack,
+      evDefense: ev.defense,
+      evSpecialAttack: ev.specialAttack,
+      evSpecialDefense: ev.specialDefense,
+      evSpeed: ev.speed,
+      ivHp: iv.hp,
+      ivAttack: iv.attack,
+      ivDefense: iv.defense,
+      ivSpecialAttack: iv.specialAttack,
+      ivSpecialDefense: iv.specialDefense,
+      ivSpeed: iv.speed,
+    };
+
+    try {
+      await this.db
+        .insert(this.schema.damageBenchmarks)
+        .values({
+          benchmarkId: benchmark.getBenchmarkId(),
+          ownerId: benchmark.getOwnerId(),
+          format: benchmark.getFormat(),
+          ...values,
+        })
+        .onConflictDoUpdate({ target: this.schema.damageBenchmarks.benchmarkId, set: values });
+    } catch (error) {
+      throw new DamageBenchmarkRepositoryError(`Failed to save benchmark: ${error}`);
+    }
+  }
+
+  async delete(benchmarkId: string): Promise<void> {
+    try {
+      await this.db
+        .delete(this.schema.damageBenchmarks)
+        .where(eq(this.schema.damageBenchmarks.benchmarkId, benchmarkId));
+    } catch (error) {
+      throw new DamageBenchmarkRepositoryError(`Failed to delete benchmark: ${error}`);
+    }
+  }
+
+  private toEntity(row: DamageBenchmarkRow): DamageBenchmark {
+    const build = PokemonBuild.create({
+      formId: FormId.fromRepository(row.formId),
+      ability: row.ability ?? undefined,
+      heldItem: row.item ?? undefined,
+      moves: [],
+      teraType: row.teraType ? PokemonTypeSchema.parse(row.teraType) : undefined,
+      nature: NatureSchema.parse(row.nature),
+      ev: new EVStats(
+        row.evHp,
+        row.evAttack,
+        row.evDefense,
+        row.evSpecialAttack,
+        row.evSpecialDefense,
+        row.evSpeed
+      ),
+      iv: {
+        hp: row.ivHp,
+        attack: row.ivAttack,
+        defense: row.ivDefense,
+        specialAttack: row.ivSpecialAttack,
+        specialDefense: row.ivSpecialDefense,
+        speed: row.ivSpeed,
+      },
+    });
+
+    return DamageBenchmark.fromRepository(
+      row.benchmarkId,
+      row.ownerId,
+      FormatIdSchema.parse(row.format),
+      row.name,
+      BenchmarkRoleSchema.parse(row.role),
+      build,
+      row.move ?? undefined
+    );
+  }
+}
@@ -0,0 +1,66 @@
+import { desc } from 'drizzle-orm';
+import {
+  DataImport,
+  DataImportKind,
+  DataImportKindSchema,
+  DataImportRepository,
+  DataImportRepositoryError,
+} from '../interface/DataImportRepository';
+import { Db, Schema } from './types';
+
+type DataImportRow = Schema['dataImports']['$inferSelect'];
+
+export class DrizzleDataImportRepository implements DataImportRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async record(kind: DataImportKind, fromId?: number, toId?: number): Promise<number> {
+    try {
+      const rows = await this.db
+        .insert(this.schema.dataImports)
+        .values({ kind, fromId: fromId ?? null, toId: toId ?? null })
+        .returning({ id: this.schema.dataImports.id });
+
+      return rows[0].id;
+    } catch (error) {
+      throw new DataImportRepositoryError(`Failed to record data import: ${error}`);
+    }
+  }
+
+  async findLatestVersion(): Promise<number> {
+    try {
+      const rows = await this.db
+        .select({ id: this.schema.dataImports.id })
+        .from(this.schema.dataImports)
+        .orderBy(desc(this.schema.dataImports.id))
+        .limit(1);
+
+      return rows.length > 0 ? rows[0].id : 0;
+    } catch (error) {
+      throw new DataImportRepositoryError(`Failed to find latest data import: ${error}`);
+    }
+  }
+
+  async findRecent(limit: number): Promise<DataImport[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.dataImports)
+        .orderBy(desc(this.schema.dataImports.id))
+        .limit(limit);
+
+      return rows.map((row) => this.toDataImport(row));
+    } catch (error) {
+      throw new DataImportRepositoryError(`Failed to find data imports: ${error}`);
+    }
+  }
+
+  private toDataImport(row: DataImportRow): DataImport {
+    return {
+      version: row.id,
+      kind: DataImportKindSchema.parse(row.kind),
+      fromId: row.fromId ?? undefined,
+      toId: row.toId ?? undefined,
+      importedAt: row.importedAt,
+    };
+  }
+}
@@ -0,0 +1,52 @@
+import { asc, gte, sql } from 'drizzle-orm';
+import {
+  DatasetEntry,
+  DatasetSnapshotRepository,
+  DatasetSnapshotRepositoryError,
+} from '../interface/DatasetSnapshotRepository';
+import { fromDatasetEntryRow, toDatasetEntryRow } from './datasetEntry';
+import { Db, Schema } from './types';
+
+export class DrizzleDatasetSnapshotRepository implements DatasetSnapshotRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async save(entries: DatasetEntry[]): Promise<void> {
+    if (entries.length === 0) {
+      return;
+    }
+
+    const { dataImports } = this.schema;
+    // Read in the same statement, so the tag cannot go stale between reading and writing
+    const version = sql<number>`(select coalesce(max(${dataImports.id}), 0) from ${dataImports})`;
+
+    try {
+      await this.db
+        .insert(this.schema.datasetSnapshots)
+        .values(entries.map((entry) => ({ version, ...toDatasetEntryRow(entry) })));
+    } catch (error) {
+      throw new DatasetSnapshotRepositoryError(`Failed to save dataset snapshots: ${error}`);
+    }
+  }
+
+  async findChangedSince(version: number): Promise<DatasetEntry[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.datasetSnapshots)
+        .where(gte(this.schema.datasetSnapshots.version, version))
+        .orderBy(asc(this.schema.datasetSnapshots.id));
+
+      // The oldest copy of each entry is the one from before the first change after the version
+      const oldest = new Map<string, (typeof rows)[number]>();
+      for (const row of rows) {
+        const key = `${row.kind}:${row.entryId}`;
+        if (!oldest.has(key)) {
+          oldest.set(key, row);
+        }
+      }
+      return [...oldest.values()].map((row) => fromDatasetEntryRow(row.kind, row.data));
+    } catch (error) {
+      throw new DatasetSnapshotRepositoryError(`Failed to find dataset snapshots: ${error}`);
+    }
+  }
+}
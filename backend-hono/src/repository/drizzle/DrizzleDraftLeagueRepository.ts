@@ -0,0 +1,217 @@
+import { and, asc, desc, eq, inArray, or } from 'drizzle-orm';
+import { DraftLeague, DraftPick, DraftPoolEntry } from '../../domain/entity/DraftLeague';
+import { FormatIdSchema } from '../../domain/valueobject/Format';
+import { FormId } from '../../domain/valueobject/PokemonId';
+import {
+  DraftLeagueRepository,
+  DraftLeagueRepositoryError,
+} from '../interface/DraftLeagueRepository';
+import { Db, Schema } from './types';
+
+type LeagueRow = Schema['draftLeagues']['$inferSelect'];
+
+export class DrizzleDraftLeagueRepository implements DraftLeagueRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(leagueId: string): Promise<DraftLeague | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.draftLeagues)
+        .where(eq(this.schema.draftLeagues.leagueId, leagueId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [league] = await this.toEntities(result);
+      return league;
+    } catch (error) {
+      throw new DraftLeagueRepositoryError(`Failed to find draft league by ID: ${error}`);
+    }
+  }
+
+  async findByUser(userId: string): Promise<DraftLeague[]> {
+    try {
+      const joined = this.db
+        .select({ leagueId: this.schema.draftLeagueParticipants.leagueId })
+        .from(this.schema.draftLeagueParticipants)
+        .where(eq(this.schema.draftLeagueParticipants.userId, userId));
+      const rows = await this.db
+        .select()
+        .from(this.schema.draftLeagues)
+        .where(
+          or(
+            eq(this.schema.draftLeagues.ownerId, userId),
+            inArray(this.schema.draftLeagues.leagueId, joined)
+          )
+        )
+        .orderBy(desc(this.schema.draftLeagues.createdAt));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new DraftLeagueRepositoryError(`Failed to find draft leagues by user: ${error}`);
+    }
+  }
+
+  async create(league: DraftLeague): Promise<void> {
+    const leagueId = league.getLeagueId();
+
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx.insert(this.schema.draftLeagues).values({
+          leagueId,
+          ownerId: league.getOwnerId(),
+          name: league.getName(),
+          format: league.getFormat(),
+          budget: league.getBudget(),
+          rosterSize: league.getRosterSize(),
+          createdAt: league.getCreatedAt(),
+        });
+        await tx
+          .insert(this.schema.draftLeagueParticipants)
+          .values(
+            league.getParticipantIds().map((userId, position) => ({ leagueId, userId, position }))
+          );
+        await tx
+          .insert(this.schema.draftLeaguePool)
+          .values(league.getPool().map((entry) => ({ leagueId, ...entry })));
+      });
+    } catch (error) {
+      throw new DraftLeagueRepositoryError(`Failed to create draft league: ${error}`);
+    }
+  }
+
+  async addPick(leagueId: string, pick: DraftPick): Promise<boolean> {
+    try {
+      return await this.db.transaction(async (tx) => {
+        const claimed = await tx
+          .update(this.schema.draftLeagues)
+          .set({ pickCount: pick.pickNumber })
+          .where(
+            and(
+              eq(this.schema.draftLeagues.leagueId, leagueId),
+              eq(this.schema.draftLeagues.pickCount, pick.pickNumber - 1)
+            )
+          )
+          .returning({ leagueId: this.schema.draftLeagues.leagueId });
+        if (claimed.length === 0) {
+          return false;
+        }
+
+        await tx.insert(this.schema.draftLeaguePicks).values({ leagueId, ...pick });
+        return true;
+      });
+    } catch (error) {
+      throw new DraftLeagueRepositoryError(`Failed to add draft pick: ${error}`);
+    }
+  }
+
+  async removePick(leagueId: string, pickNumber: number): Promise<boolean> {
+    try {
+      return await this.db.transaction(async (tx) => {
+        const claimed = await tx
+          .update(this.schema.draftLeagues)
+          .set({ pickCount: pickNumber - 1 })
+          .where(
+            and(
+              eq(this.schema.draftLeagues.leagueId, leagueId),
+              eq(this.schema.draftLeagues.pickCount, pickNumber)
+            )
+          )
+          .returning({ leagueId: this.schema.draftLeagues.leagueId });
+        if (claimed.length === 0) {
+          return false;
+        }
+
+        await tx
+          .delete(this.schema.draftLeaguePicks)
+          .where(
+            and(
+              eq(this.schema.draftLeaguePicks.leagueId, leagueId),
+              eq(this.schema.draftLeaguePicks.pickNumber, pickNumber)
+            )
+          );
+        return true;
+      });
+    } catch (error) {
+      throw new DraftLeagueRepositoryError(`Failed to remove draft pick: ${error}`);
+    }
+  }
+
+  async delete(leagueId: string): Promise<void> {
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .delete(this.schema.draftLeaguePicks)
+          .where(eq(this.schema.draftLeaguePicks.leagueId, leagueId));
+        await tx
+          .delete(this.schema.draftLeaguePool)
+          .where(eq(this.schema.draftLeaguePool.leagueId, leagueId));
+        await tx
+          .delete(this.schema.draftLeagueParticipants)
+          .where(eq(this.schema.draftLeagueParticipants.leagueId, leagueId));
+        await tx
+          .delete(this.schema.draftLeagues)
+          .where(eq(this.schema.draftLeagues.leagueId, leagueId));
+      });
+    } catch (error) {
+      throw new DraftLeagueRepositoryError(`Failed to delete draft league: ${error}`);
+    }
+  }
+
+  private async toEntities(rows: LeagueRow[]): Promise<DraftLeague[]> {
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const leagueIds = rows.map((row) => row.leagueId);
+    const participantRows = await this.db
+      .select()
+      .from(this.schema.draftLeagueParticipants)
+      .where(inArray(this.schema.draftLeagueParticipants.leagueId, leagueIds))
+      .orderBy(asc(this.schema.draftLeagueParticipants.position));
+    const poolRows = await this.db
+      .select()
+      .from(this.schema.draftLeaguePool)
+      .where(inArray(this.schema.draftLeaguePool.leagueId, leagueIds))
+      .orderBy(asc(this.schema.draftLeaguePool.formId));
+    const pickRows = await this.db
+      .select()
+      .from(this.schema.draftLeaguePicks)
+      .where(inArray(this.schema.draftLeaguePicks.leagueId, leagueIds))
+      .orderBy(asc(this.schema.draftLeaguePicks.pickNumber));
+
+    return rows.map((row) => {
+      const pool: DraftPoolEntry[] = poolRows
+        .filter((entry) => entry.leagueId === row.leagueId)
+        .map((entry) => ({ formId: FormId.fromRepository(entry.formId), cost: entry.cost }));
+      const picks: DraftPick[] = pickRows
+        .filter((pick) => pick.leagueId === row.leagueId)
+        .map((pick) => ({
+          pickNumber: pick.pickNumber,
+          userId: pick.userId,
+          formId: FormId.fromRepository(pick.formId),
+          pickedAt: pick.pickedAt,
+        }));
+
+      return DraftLeague.fromRepository(
+        row.leagueId,
+        row.ownerId,
+        {
+          name: row.name,
+          format: FormatIdSchema.parse(row.format),
+          participantIds: participantRows
+            .filter((participant) => participant.leagueId === row.leagueId)
+            .map((participant) => participant.userId),
+          pool,
+          budget: row.budget,
+          rosterSize: row.rosterSize,
+        },
+        picks,
+        row.createdAt
+      );
+    });
+  }
+}
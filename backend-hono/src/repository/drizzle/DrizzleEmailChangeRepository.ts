@@ -0,0 +1,94 @@
+import { eq } from 'drizzle-orm';
+import { EmailChange } from '../../domain/entity/EmailChange';
+import {
+  EmailChangeRepository,
+  EmailChangeRepositoryError,
+} from '../interface/EmailChangeRepository';
+import { Db, Schema } from './types';
+
+type EmailChangeRow = Schema['emailChanges']['$inferSelect'];
+
+export class DrizzleEmailChangeRepository implements EmailChangeRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async save(emailChange: EmailChange): Promise<void> {
+    const row = {
+      newEmail: emailChange.getNewEmail(),
+      confirmTokenHash: emailChange.getConfirmTokenHash(),
+      cancelTokenHash: emailChange.getCancelTokenHash(),
+      expiresAt: emailChange.getExpiresAt(),
+      createdAt: emailChange.getCreatedAt(),
+    };
+
+    try {
+      await this.db
+        .insert(this.schema.emailChanges)
+        .values({ userId: emailChange.getUserId(), ...row })
+        .onConflictDoUpdate({ target: this.schema.emailChanges.userId, set: row });
+    } catch (error) {
+      throw new EmailChangeRepositoryError(`Failed to save email change: ${error}`);
+    }
+  }
+
+  async findByUser(userId: string): Promise<EmailChange | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.emailChanges)
+        .where(eq(this.schema.emailChanges.userId, userId))
+        .limit(1);
+      return result.length === 0 ? null : this.toEntity(result[0]);
+    } catch (error) {
+      throw new EmailChangeRepositoryError(`Failed to find email change: ${error}`);
+    }
+  }
+
+  async findByConfirmTokenHash(tokenHash: string): Promise<EmailChange | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.emailChanges)
+        .where(eq(this.schema.emailChanges.confirmTokenHash, tokenHash))
+        .limit(1);
+      return result.length === 0 ? null : this.toEntity(result[0]);
+    } catch (error) {
+      throw new EmailChangeRepositoryError(`Failed to find email change: ${error}`);
+    }
+  }
+
+  async findByCancelTokenHash(tokenHash: string): Promise<EmailChange | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.emailChanges)
+        .where(eq(this.schema.emailChanges.cancelTokenHash, tokenHash))
+        .limit(1);
+      return result.length === 0 ? null : this.toEntity(result[0]);
+    } catch (error) {
+      throw new EmailChangeRepositoryError(`Failed to find email change: ${error}`);
+    }
+  }
+
+  async delete(userId: string): Promise<boolean> {
+    try {
+      const deleted = await this.db
+        .delete(this.schema.emailChanges)
+        .where(eq(this.schema.emailChanges.userId, userId))
+        .returning({ userId: this.schema.emailChanges.userId });
+      return deleted.length > 0;
+    } catch (error) {
+      throw new EmailChangeRepositoryError(`Failed to delete email change: ${error}`);
+    }
+  }
+
+  private toEntity(row: EmailChangeRow): EmailChange {
+    return EmailChange.fromRepository(
+      row.userId,
+      row.newEmail,
+      row.confirmTokenHash,
+      row.cancelTokenHash,
+      row.expiresAt,
+      row.createdAt
+    );
+  }
+}
@@ -0,0 +1,95 @@
+import { and, asc, count, eq, SQL } from 'drizzle-orm';
+import { Item } from '../../domain/entity/Item';
+import { toLookupKey } from '../../domain/valueobject/Identifier';
+import {
+  ItemPage,
+  ItemRepository,
+  ItemRepositoryError,
+  ItemSearchFilter,
+} from '../interface/ItemRepository';
+import { nameContains } from './search';
+import { Db, Dialect, Schema } from './types';
+
+type ItemRow = Schema['items']['$inferSelect'];
+
+export class DrizzleItemRepository implements ItemRepository {
+  constructor(
+    private readonly db: Db,
+    private readonly schema: Schema,
+    private readonly dialect: Dialect
+  ) {}
+
+  async findByName(name: string): Promise<Item | null> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.items)
+        .where(eq(this.schema.items.lookupKey, toLookupKey(name)))
+        .limit(1);
+
+      return rows.length > 0 ? this.toEntity(rows[0]) : null;
+    } catch (error) {
+      throw new ItemRepositoryError(`Failed to find item: ${error}`);
+    }
+  }
+
+  async search(filter: ItemSearchFilter, limit: number, offset: number): Promise<ItemPage> {
+    const conditions: (SQL | undefined)[] = [];
+    if (filter.name) {
+      const { lookupKey, nameJp } = this.schema.items;
+      conditions.push(nameContains(this.dialect, lookupKey, nameJp, filter.name));
+    }
+    if (filter.category) {
+      conditions.push(eq(this.schema.items.category, filter.category));
+    }
+    const where = and(...conditions);
+
+    try {
+      const [rows, [{ total }]] = await Promise.all([
+        this.db
+          .select()
+          .from(this.schema.items)
+          .where(where)
+          .orderBy(asc(this.schema.items.itemId))
+          .limit(limit)
+          .offset(offset),
+        this.db.select({ total: count() }).from(this.schema.items).where(where),
+      ]);
+
+      return { items: rows.map((row) => this.toEntity(row)), total };
+    } catch (error) {
+      throw new ItemRepositoryError(`Failed to search items: ${error}`);
+    }
+  }
+
+  async save(item: Item): Promise<void> {
+    const values = {
+      itemId: item.getId(),
+      lookupKey: toLookupKey(item.getName()),
+      nameJp: item.getNameJp() ?? null,
+      category: item.getCategory(),
+      effect: item.getEffect() ?? null,
+      effectJp: item.getEffectJp() ?? null,
+    };
+
+    try {
+      await this.db
+        .insert(this.schema.items)
+        .values({ name: item.getName(), ...values })
+        .onConflictDoUpdate({ target: this.schema.items.name, set: values });
+    } catch (error) {
+      throw new ItemRepositoryError(`Failed to save item: ${error}`);
+    }
+  }
+
+  private toEntity(row: ItemRow): Item {
+    return Item.fromRepository({
+      id: row.itemId,
+      name: row.name,
+      nameJp: row.nameJp ?? undefined,
+      category: row.category,
+      effect: row.effect ?? undefined,
+      effectJp: row.effectJp ?? undefined,
+    });
+  }
+}
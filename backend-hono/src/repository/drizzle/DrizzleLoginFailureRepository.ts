@@ -0,0 +1,57 @@
+import { eq, inArray, lt, sql } from 'drizzle-orm';
+import { LoginFailures } from '../../domain/valueobject/LoginBackoff';
+import {
+  LoginFailureRepository,
+  LoginFailureRepositoryError,
+} from '../interface/LoginFailureRepository';
+import { Db, Schema } from './types';
+
+export class DrizzleLoginFailureRepository implements LoginFailureRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findByIdentifiers(identifiers: string[]): Promise<LoginFailures[]> {
+    if (identifiers.length === 0) {
+      return [];
+    }
+
+    try {
+      return await this.db
+        .select()
+        .from(this.schema.loginFailures)
+        .where(inArray(this.schema.loginFailures.identifier, identifiers));
+    } catch (error) {
+      throw new LoginFailureRepositoryError(`Failed to find login failures: ${error}`);
+    }
+  }
+
+  // One statement, so concurrent failures are all counted
+  async recordFailure(identifier: string, at: Date, forgetBefore: Date): Promise<void> {
+    const { loginFailures } = this.schema;
+    const forgotten = lt(loginFailures.lastFailedAt, forgetBefore);
+
+    try {
+      await this.db
+        .insert(loginFailures)
+        .values({ identifier, failures: 1, lastFailedAt: at })
+        .onConflictDoUpdate({
+          target: loginFailures.identifier,
+          set: {
+            failures: sql`case when ${forgotten} then 1 else ${loginFailures.failures} + 1 end`,
+            lastFailedAt: at,
+          },
+        });
+    } catch (error) {
+      throw new LoginFailureRepositoryError(`Failed to record login failure: ${error}`);
+    }
+  }
+
+  async clear(identifier: string): Promise<void> {
+    try {
+      await this.db
+        .delete(this.schema.loginFailures)
+        .where(eq(this.schema.loginFailures.identifier, identifier));
+    } catch (error) {
+      throw new LoginFailureRepositoryError(`Failed to clear login failures: ${error}`);
+    }
+  }
+}
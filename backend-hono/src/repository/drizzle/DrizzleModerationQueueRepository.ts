@@ -0,0 +1,112 @@
+import { and, asc, count, eq } from 'drizzle-orm';
+import { ModerationItem } from '../../domain/entity/ModerationItem';
+import {
+  ContentKind,
+  ContentKindSchema,
+  ContentReasonSchema,
+} from '../../domain/service/ContentPolicy';
+import {
+  ModerationQueueRepository,
+  ModerationQueueRepositoryError,
+} from '../interface/ModerationQueueRepository';
+import { Db, Schema } from './types';
+
+type ModerationItemRow = Schema['moderationQueue']['$inferSelect'];
+
+export class DrizzleModerationQueueRepository implements ModerationQueueRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async save(item: ModerationItem): Promise<void> {
+    try {
+      await this.db.insert(this.schema.moderationQueue).values({
+        itemId: item.getItemId(),
+        kind: item.getKind(),
+        targetId: item.getTargetId(),
+        authorId: item.getAuthorId(),
+        text: item.getText(),
+        reasons: item.getReasons().join(','),
+        createdAt: item.getCreatedAt(),
+      });
+    } catch (error) {
+      throw new ModerationQueueRepositoryError(`Failed to save moderation item: ${error}`);
+    }
+  }
+
+  async findById(itemId: string): Promise<ModerationItem | null> {
+    try {
+      const [row] = await this.db
+        .select()
+        .from(this.schema.moderationQueue)
+        .where(eq(this.schema.moderationQueue.itemId, itemId))
+        .limit(1);
+      return row ? this.toEntity(row) : null;
+    } catch (error) {
+      throw new ModerationQueueRepositoryError(`Failed to find moderation item: ${error}`);
+    }
+  }
+
+  async findPage(limit: number, offset: number): Promise<ModerationItem[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.moderationQueue)
+        .orderBy(
+          asc(this.schema.moderationQueue.createdAt),
+          asc(this.schema.moderationQueue.itemId)
+        )
+        .limit(limit)
+        .offset(offset);
+      return rows.map((row) => this.toEntity(row));
+    } catch (error) {
+      throw new ModerationQueueRepositoryError(`Failed to find moderation items: ${error}`);
+    }
+  }
+
+  async count(): Promise<number> {
+    try {
+      const [row] = await this.db.select({ items: count() }).from(this.schema.moderationQueue);
+      return row?.items ?? 0;
+    } catch (error) {
+      throw new ModerationQueueRepositoryError(`Failed to count moderation items: ${error}`);
+    }
+  }
+
+  async delete(itemId: string): Promise<boolean> {
+    try {
+      const deleted = await this.db
+        .delete(this.schema.moderationQueue)
+        .where(eq(this.schema.moderationQueue.itemId, itemId))
+        .returning({ itemId: this.schema.moderationQueue.itemId });
+      return deleted.length > 0;
+    } catch (error) {
+      throw new ModerationQueueRepositoryError(`Failed to delete moderation item: ${error}`);
+    }
+  }
+
+  async deleteByTarget(kind: ContentKind, targetId: string): Promise<void> {
+    try {
+      await this.db
+        .delete(this.schema.moderationQueue)
+        .where(
+          and(
+            eq(this.schema.moderationQueue.kind, kind),
+            eq(this.schema.moderationQueue.targetId, targetId)
+          )
+        );
+    } catch (error) {
+      throw new ModerationQueueRepositoryError(`Failed to delete moderation items: ${error}`);
+    }
+  }
+
+  private toEntity(row: ModerationItemRow): ModerationItem {
+    return ModerationItem.fromRepository(
+      row.itemId,
+      ContentKindSchema.parse(row.kind),
+      row.targetId,
+      row.authorId,
+      row.text,
+      row.reasons.split(',').map((reason) => ContentReasonSchema.parse(reason)),
+      row.createdAt
+    );
+  }
+}
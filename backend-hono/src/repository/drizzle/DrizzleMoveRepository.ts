@@ -0,0 +1,181 @@
+import { and, asc, count, eq, gte, inArray, lte, SQL } from 'drizzle-orm';
+import { DamageClassSchema, Move } from '../../domain/entity/Move';
+import { toLookupKey } from '../../domain/valueobject/Identifier';
+import { MoveEffectsSchema } from '../../domain/valueobject/MoveEffects';
+import { PokemonTypeSchema } from '../../domain/valueobject/PokemonType';
+import {
+  MovePage,
+  MoveRepository,
+  MoveRepositoryError,
+  MoveSearchFilter,
+} from '../interface/MoveRepository';
+import { contains, nameContains } from './search';
+import { Db, Dialect, Schema } from './types';
+
+type MoveRow = Schema['moves']['$inferSelect'];
+
+export class DrizzleMoveRepository implements MoveRepository {
+  constructor(
+    private readonly db: Db,
+    private readonly schema: Schema,
+    private readonly dialect: Dialect
+  ) {}
+
+  async findByName(name: string): Promise<Move | null> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.moves)
+        .where(eq(this.schema.moves.lookupKey, toLookupKey(name)))
+        .limit(1);
+
+      return rows.length > 0 ? this.toEntity(rows[0]) : null;
+    } catch (error) {
+      throw new MoveRepositoryError(`Failed to find move: ${error}`);
+    }
+  }
+
+  async findByNames(names: string[]): Promise<Move[]> {
+    if (names.length === 0) {
+      return [];
+    }
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.moves)
+        .where(inArray(this.schema.moves.lookupKey, [...new Set(names.map(toLookupKey))]));
+
+      return rows.map((row) => this.toEntity(row));
+    } catch (error) {
+      throw new MoveRepositoryError(`Failed to find moves: ${error}`);
+    }
+  }
+
+  async findByIds(moveIds: number[]): Promise<Move[]> {
+    if (moveIds.length === 0) {
+      return [];
+    }
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.moves)
+        .where(inArray(this.schema.moves.moveId, [...new Set(moveIds)]));
+
+      return rows.map((row) => this.toEntity(row));
+    } catch (error) {
+      throw new MoveRepositoryError(`Failed to find moves by IDs: ${error}`);
+    }
+  }
+
+  async search(filter: MoveSearchFilter, limit: number, offset: number): Promise<MovePage> {
+    const where = and(...this.toConditions(filter));
+
+    try {
+      const [rows, [{ total }]] = await Promise.all([
+        this.db
+          .select()
+          .from(this.schema.moves)
+          .where(where)
+          .orderBy(asc(this.schema.moves.moveId))
+          .limit(limit)
+          .offset(offset),
+        this.db.select({ total: count() }).from(this.schema.moves).where(where),
+      ]);
+
+      return { moves: rows.map((row) => this.toEntity(row)), total };
+    } catch (error) {
+      throw new MoveRepositoryError(`Failed to search moves: ${error}`);
+    }
+  }
+
+  async save(move: Move): Promise<void> {
+    const effects = move.getEffects();
+    const values = {
+      moveId: move.getId(),
+      lookupKey: toLookupKey(move.getName()),
+      nameJp: move.getNameJp() ?? null,
+      type: move.getType(),
+      damageClass: move.getDamageClass(),
+      power: move.getPower() ?? null,
+      accuracy: move.getAccuracy() ?? null,
+      priority: move.getPriority(),
+      pp: move.getPp() ?? null,
+      target: move.getTarget(),
+      minHits: move.isMultiHit() ? move.getMinHits() : null,
+      maxHits: move.isMultiHit() ? move.getMaxHits() : null,
+      effect: move.getEffect() ?? null,
+      effects: effects ? JSON.stringify(effects) : null,
+    };
+
+    try {
+      await this.db
+        .insert(this.schema.moves)
+        .values({ name: move.getName(), ...values })
+        .onConflictDoUpdate({ target: this.schema.moves.name, set: values });
+    } catch (error) {
+      throw new MoveRepositoryError(`Failed to save move: ${error}`);
+    }
+  }
+
+  private toConditions(filter: MoveSearchFilter): (SQL | undefined)[] {
+    const conditions: (SQL | undefined)[] = [];
+
+    if (filter.name) {
+      const { lookupKey, nameJp } = this.schema.moves;
+      conditions.push(nameContains(this.dialect, lookupKey, nameJp, filter.name));
+    }
+    if (filter.type) {
+      conditions.push(eq(this.schema.moves.type, filter.type));
+    }
+    if (filter.damageClass) {
+      conditions.push(eq(this.schema.moves.damageClass, filter.damageClass));
+    }
+    if (filter.minPower !== undefined) {
+      conditions.push(gte(this.schema.moves.power, filter.minPower));
+    }
+    if (filter.maxPower !== undefined) {
+      conditions.push(lte(this.schema.moves.power, filter.maxPower));
+    }
+    if (filter.priority !== undefined) {
+      conditions.push(eq(this.schema.moves.priority, filter.priority));
+    }
+    for (const keyword of filter.effectKeywords ?? []) {
+      conditions.push(contains(this.dialect, this.schema.moves.effect, keyword));
+    }
+    if (filter.learnableBy !== undefined) {
+      const learnable = this.db
+        .selectDistinct({ moveName: this.schema.pokemonLearnsets.moveName })
+        .from(this.schema.pokemonLearnsets)
+        .innerJoin(
+          this.schema.pokemonForms,
+          eq(this.schema.pokemonForms.formId, this.schema.pokemonLearnsets.formId)
+        )
+        .where(eq(this.schema.pokemonForms.speciesId, filter.learnableBy));
+      conditions.push(inArray(this.schema.moves.name, learnable));
+    }
+
+    return conditions;
+  }
+
+  private toEntity(row: MoveRow): Move {
+    return Move.fromRepository({
+      id: row.moveId,
+      name: row.name,
+      nameJp: row.nameJp ?? undefined,
+      type: PokemonTypeSchema.parse(row.type),
+      damageClass: DamageClassSchema.parse(row.damageClass),
+      power: row.power ?? undefined,
+      accuracy: row.accuracy ?? undefined,
+      priority: row.priority,
+      pp: row.pp ?? undefined,
+      target: row.target,
+      minHits: row.minHits ?? undefined,
+      maxHits: row.maxHits ?? undefined,
+      effect: row.effect ?? undefined,
+      effects:
+        row.effects === null ? undefined : MoveEffectsSchema.parse(JSON.parse(row.effects)),
+    });
+  }
+}
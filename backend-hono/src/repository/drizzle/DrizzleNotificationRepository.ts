@@ -0,0 +1,118 @@
+import { and, count, desc, eq, inArray, isNull, SQL } from 'drizzle-orm';
+import { Notification, NotificationContentSchema } from '../../domain/entity/Notification';
+import {
+  NotificationFilter,
+  NotificationRepository,
+  NotificationRepositoryError,
+} from '../interface/NotificationRepository';
+import { Db, Schema } from './types';
+
+type NotificationRow = Schema['notifications']['$inferSelect'];
+
+export class DrizzleNotificationRepository implements NotificationRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async save(notification: Notification): Promise<void> {
+    const { type, ...data } = notification.getContent();
+
+    try {
+      await this.db.insert(this.schema.notifications).values({
+        notificationId: notification.getNotificationId(),
+        userId: notification.getUserId(),
+        type,
+        data: JSON.stringify(data),
+        createdAt: notification.getCreatedAt(),
+        readAt: notification.getReadAt(),
+      });
+    } catch (error) {
+      throw new NotificationRepositoryError(`Failed to save notification: ${error}`);
+    }
+  }
+
+  async findPageByUser(
+    userId: string,
+    filter: NotificationFilter,
+    limit: number,
+    offset: number
+  ): Promise<Notification[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.notifications)
+        .where(this.matches(userId, filter))
+        .orderBy(
+          desc(this.schema.notifications.createdAt),
+          desc(this.schema.notifications.notificationId)
+        )
+        .limit(limit)
+        .offset(offset);
+      return rows.map((row) => this.toEntity(row));
+    } catch (error) {
+      throw new NotificationRepositoryError(`Failed to find notifications: ${error}`);
+    }
+  }
+
+  async countByUser(userId: string, filter: NotificationFilter): Promise<number> {
+    try {
+      const [row] = await this.db
+        .select({ notifications: count() })
+        .from(this.schema.notifications)
+        .where(this.matches(userId, filter));
+      return row?.notifications ?? 0;
+    } catch (error) {
+      throw new NotificationRepositoryError(`Failed to count notifications: ${error}`);
+    }
+  }
+
+  async markRead(userId: string, notificationIds: string[], readAt: Date): Promise<number> {
+    if (notificationIds.length === 0) {
+      return 0;
+    }
+
+    try {
+      const marked = await this.db
+        .update(this.schema.notifications)
+        .set({ readAt })
+        .where(
+          and(
+            this.matches(userId, { unreadOnly: true }),
+            inArray(this.schema.notifications.notificationId, notificationIds)
+          )
+        )
+        .returning({ notificationId: this.schema.notifications.notificationId });
+      return marked.length;
+    } catch (error) {
+      throw new NotificationRepositoryError(`Failed to mark notifications read: ${error}`);
+    }
+  }
+
+  async markAllRead(userId: string, readAt: Date): Promise<number> {
+    try {
+      const marked = await this.db
+        .update(this.schema.notifications)
+        .set({ readAt })
+        .where(this.matches(userId, { unreadOnly: true }))
+        .returning({ notificationId: this.schema.notifications.notificationId });
+      return marked.length;
+    } catch (error) {
+      throw new NotificationRepositoryError(`Failed to mark notifications read: ${error}`);
+    }
+  }
+
+  private matches(userId: string, filter: NotificationFilter): SQL | undefined {
+    return and(
+      eq(this.schema.notifications.userId, userId),
+      filter.unreadOnly ? isNull(this.schema.notifications.readAt) : undefined
+    );
+  }
+
+  private toEntity(row: NotificationRow): Notification {
+    return Notification.fromRepository(
+      row.notificationId,
+      row.userId,
+      NotificationContentSchema.parse({ ...JSON.parse(row.data), type: row.type }),
+      row.createdAt,
+      row.readAt
+    );
+  }
+}
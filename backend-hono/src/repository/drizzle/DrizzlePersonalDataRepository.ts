@@ -0,0 +1,761 @@
+import { and, asc, eq, inArray, isNotNull, isNull, lt, or, sql } from 'drizzle-orm';
+import {
+  AccountTombstone,
+  PersonalBuild,
+  PersonalData,
+  PersonalDataRepository,
+  PersonalDataRepositoryError,
+  PublishedContent,
+} from '../interface/PersonalDataRepository';
+import { ArchivedTeam } from '../interface/UserDataArchiveRepository';
+import { Db, Dialect, Schema, Transaction } from './types';
+
+type BuildRow = Omit<PersonalBuild, 'evs' | 'ivs'> & {
+  evHp: number;
+  evAttack: number;
+  evDefense: number;
+  evSpecialAttack: number;
+  evSpecialDefense: number;
+  evSpeed: number;
+  ivHp: number;
+  ivAttack: number;
+  ivDefense: number;
+  ivSpecialAttack: number;
+  ivSpecialDefense: number;
+  ivSpeed: number;
+};
+
+export class DrizzlePersonalDataRepository implements PersonalDataRepository {
+  constructor(
+    private readonly db: Db,
+    private readonly schema: Schema,
+    private readonly dialect: Dialect
+  ) {}
+
+  async findByUser(userId: string): Promise<PersonalData | null> {
+    try {
+      return await this.dialect.readSnapshot(this.db, (tx) => this.read(tx, userId));
+    } catch (error) {
+      throw new PersonalDataRepositoryError(`Failed to find personal data: ${error}`);
+    }
+  }
+
+  async erase(
+    userId: string,
+    tombstone: AccountTombstone,
+    content: PublishedContent,
+    at: Date
+  ): Promise<boolean> {
+    try {
+      return await this.db.transaction(async (tx) => {
+        const [user] = await tx
+          .select({ email: this.schema.users.email })
+          .from(this.schema.users)
+          .where(and(eq(this.schema.users.userId, userId), isNull(this.schema.users.deletedAt)));
+        if (!user) {
+          return false;
+        }
+
+        await tx
+          .update(this.schema.users)
+          .set({ ...tombstone, role: 'user', updatedAt: at, deletedAt: at })
+          .where(eq(this.schema.users.userId, userId));
+        await tx
+          .update(this.schema.refreshTokens)
+          .set({ revoked: true })
+          .where(eq(this.schema.refreshTokens.userId, userId));
+        await tx
+          .delete(this.schema.loginFailures)
+          .where(eq(this.schema.loginFailures.identifier, `email:${user.email.toLowerCase()}`));
+        await tx
+          .delete(this.schema.userPreferences)
+          .where(eq(this.schema.userPreferences.userId, userId));
+        await tx
+          .delete(this.schema.emailChanges)
+          .where(eq(this.schema.emailChanges.userId, userId));
+        await tx.delete(this.schema.userPokemon).where(eq(this.schema.userPokemon.userId, userId));
+        const records = tx
+          .select({ recordId: this.schema.battleRecords.recordId })
+          .from(this.schema.battleRecords)
+          .where(eq(this.schema.battleRecords.userId, userId));
+        await tx
+          .delete(this.schema.battleRecordOpponents)
+          .where(inArray(this.schema.battleRecordOpponents.recordId, records));
+        await tx
+          .delete(this.schema.battleRecords)
+          .where(eq(this.schema.battleRecords.userId, userId));
+        await tx
+          .delete(this.schema.damageBenchmarks)
+          .where(eq(this.schema.damageBenchmarks.ownerId, userId));
+        await tx.delete(this.schema.customForms).where(eq(this.schema.customForms.ownerId, userId));
+        await tx
+          .delete(this.schema.notifications)
+          .where(eq(this.schema.notifications.userId, userId));
+        await tx
+          .delete(this.schema.typeQuizAnswers)
+          .where(eq(this.schema.typeQuizAnswers.userId, userId));
+        await tx
+          .delete(this.schema.typeQuizScores)
+          .where(eq(this.schema.typeQuizScores.userId, userId));
+        await tx.delete(this.schema.teamLikes).where(eq(this.schema.teamLikes.userId, userId));
+        await tx.delete(this.schema.teamFlags).where(eq(this.schema.teamFlags.userId, userId));
+        await tx
+          .delete(this.schema.moderationQueue)
+          .where(eq(this.schema.moderationQueue.authorId, userId));
+
+        const runs = tx
+          .select({ runId: this.schema.challengeRuns.runId })
+          .from(this.schema.challengeRuns)
+          .where(eq(this.schema.challengeRuns.ownerId, userId));
+        await tx
+          .delete(this.schema.challengeRunSpecies)
+          .where(inArray(this.schema.challengeRunSpecies.runId, runs));
+        await tx
+          .delete(this.schema.challengeRuns)
+          .where(eq(this.schema.challengeRuns.ownerId, userId));
+
+        // Leagues the user runs go. Their picks in other leagues stay, credited to the tombstone,
+        // as taking them out would reshuffle everyone else's draft.
+        const leagues = tx
+          .select({ leagueId: this.schema.draftLeagues.leagueId })
+          .from(this.schema.draftLeagues)
+          .where(eq(this.schema.draftLeagues.ownerId, userId));
+        await tx
+          .delete(this.schema.draftLeaguePicks)
+          .where(inArray(this.schema.draftLeaguePicks.leagueId, leagues));
+        await tx
+          .delete(this.schema.draftLeaguePool)
+          .where(inArray(this.schema.draftLeaguePool.leagueId, leagues));
+        await tx
+          .delete(this.schema.draftLeagueParticipants)
+          .where(inArray(this.schema.draftLeagueParticipants.leagueId, leagues));
+        await tx
+          .delete(this.schema.draftLeagues)
+          .where(eq(this.schema.draftLeagues.ownerId, userId));
+
+        // Tournaments the user organizes go, and so do their entries in those still taking
+        // registrations. Entries in started ones stay, as the bracket is drawn around them.
+        const organized = tx
+          .select({ tournamentId: this.schema.tournaments.tournamentId })
+          .from(this.schema.tournaments)
+          .where(eq(this.schema.tournaments.organizerId, userId));
+        await tx
+          .delete(this.schema.tournamentEntryMembers)
+          .where(inArray(this.schema.tournamentEntryMembers.tournamentId, organized));
+        await tx
+          .delete(this.schema.tournamentEntries)
+          .where(inArray(this.schema.tournamentEntries.tournamentId, organized));
+        await tx
+          .delete(this.schema.tournamentMatches)
+          .where(inArray(this.schema.tournamentMatches.tournamentId, organized));
+        await tx
+          .delete(this.schema.tournaments)
+          .where(eq(this.schema.tournaments.organizerId, userId));
+
+        const entered = tx
+          .select({ tournamentId: this.schema.tournamentEntries.tournamentId })
+          .from(this.schema.tournamentEntries)
+          .where(eq(this.schema.tournamentEntries.userId, userId));
+        const open = tx
+          .select({ tournamentId: this.schema.tournaments.tournamentId })
+          .from(this.schema.tournaments)
+          .where(eq(this.schema.tournaments.status, 'registration'));
+        // Bumped so that a save of the tournament read before the entry went fails its check
+        await tx
+          .update(this.schema.tournaments)
+          .set({ version: sql`${this.schema.tournaments.version} + 1` })
+          .where(
+            and(
+              eq(this.schema.tournaments.status, 'registration'),
+              inArray(this.schema.tournaments.tournamentId, entered)
+            )
+          );
+        await tx
+          .delete(this.schema.tournamentEntryMembers)
+          .where(
+            and(
+              eq(this.schema.tournamentEntryMembers.userId, userId),
+              inArray(this.schema.tournamentEntryMembers.tournamentId, open)
+            )
+          );
+        await tx
+          .delete(this.schema.tournamentEntries)
+          .where(
+            and(
+              eq(this.schema.tournamentEntries.userId, userId),
+              inArray(this.schema.tournamentEntries.tournamentId, open)
+            )
+          );
+
+        const hooks = tx
+          .select({ webhookId: this.schema.webhooks.webhookId })
+          .from(this.schema.webhooks)
+          .where(eq(this.schema.webhooks.ownerId, userId));
+        await tx
+          .delete(this.schema.webhookDeliveries)
+          .where(inArray(this.schema.webhookDeliveries.webhookId, hooks));
+        await tx
+          .delete(this.schema.webhookEvents)
+          .where(inArray(this.schema.webhookEvents.webhookId, hooks));
+        await tx.delete(this.schema.webhooks).where(eq(this.schema.webhooks.ownerId, userId));
+
+        // Only teams on show in the gallery are worth keeping
+        const removedTeams =
+          content === 'delete'
+            ? eq(this.schema.teams.ownerId, userId)
+            : and(
+                eq(this.schema.teams.ownerId, userId),
+                or(isNull(this.schema.teams.sharedAt), isNotNull(this.schema.teams.deletedAt))
+              );
+        const removed = tx
+          .select({ teamId: this.schema.teams.teamId })
+          .from(this.schema.teams)
+          .where(removedTeams);
+        await tx
+          .delete(this.schema.teamPokemon)
+          .where(inArray(this.schema.teamPokemon.teamId, removed));
+        await tx.delete(this.schema.teamTags).where(inArray(this.schema.teamTags.teamId, removed));
+        await tx
+          .delete(this.schema.teamLikes)
+          .where(inArray(this.schema.teamLikes.teamId, removed));
+        await tx
+          .delete(this.schema.teamComments)
+          .where(inArray(this.schema.teamComments.teamId, removed));
+        await tx
+          .delete(this.schema.teamFlags)
+          .where(inArray(this.schema.teamFlags.teamId, removed));
+        await tx.delete(this.schema.teams).where(removedTeams);
+
+        // Like a comment its author deleted: the placeholder keeps the replies in their thread
+        if (content === 'delete') {
+          await tx
+            .update(this.schema.teamComments)
+            .set({ body: '', deletedAt: at })
+            .where(
+              and(
+                eq(this.schema.teamComments.authorId, userId),
+                isNull(this.schema.teamComments.deletedAt)
+              )
+            );
+        }
+        return true;
+      });
+    } catch (error) {
+      throw new PersonalDataRepositoryError(`Failed to erase personal data: ${error}`);
+    }
+  }
+
+  async scrubDeletedBefore(cutoff: Date): Promise<number> {
+    try {
+      const deletedUsers = this.db
+        .select({ userId: this.schema.users.userId })
+        .from(this.schema.users)
+        .where(lt(this.schema.users.deletedAt, cutoff));
+      const scrubbed = await this.db
+        .delete(this.schema.refreshTokens)
+        .where(inArray(this.schema.refreshTokens.userId, deletedUsers))
+        .returning({ tokenId: this.schema.refreshTokens.tokenId });
+      return scrubbed.length;
+    } catch (error) {
+      throw new PersonalDataRepositoryError(`Failed to scrub deleted accounts: ${error}`);
+    }
+  }
+
+  private async read(tx: Transaction, userId: string): Promise<PersonalData | null> {
+    const [profile] = await tx
+      .select({
+        userId: this.schema.users.userId,
+        username: this.schema.users.username,
+        email: this.schema.users.email,
+        role: this.schema.users.role,
+        createdAt: this.schema.users.createdAt,
+        updatedAt: this.schema.users.updatedAt,
+      })
+      .from(this.schema.users)
+      .where(eq(this.schema.users.userId, userId));
+    if (!profile) {
+      return null;
+    }
+
+    const [preferences] = await tx
+      .select({
+        language: this.schema.userPreferences.language,
+        format: this.schema.userPreferences.format,
+        level: this.schema.userPreferences.level,
+        theme: this.schema.userPreferences.theme,
+        updatedAt: this.schema.userPreferences.updatedAt,
+      })
+      .from(this.schema.userPreferences)
+      .where(eq(this.schema.userPreferences.userId, userId));
+    const [pendingEmailChange] = await tx
+      .select({
+        newEmail: this.schema.emailChanges.newEmail,
+        expiresAt: this.schema.emailChanges.expiresAt,
+        createdAt: this.schema.emailChanges.createdAt,
+      })
+      .from(this.schema.emailChanges)
+      .where(eq(this.schema.emailChanges.userId, userId));
+    const sessions = await tx
+      .select({
+        tokenId: this.schema.refreshTokens.tokenId,
+        sessionId: this.schema.refreshTokens.sessionId,
+        userAgent: this.schema.refreshTokens.userAgent,
+        ip: this.schema.refreshTokens.ip,
+        createdAt: this.schema.refreshTokens.createdAt,
+        lastUsedAt: this.schema.refreshTokens.lastUsedAt,
+        expiresAt: this.schema.refreshTokens.expiresAt,
+        revoked: this.schema.refreshTokens.revoked,
+      })
+      .from(this.schema.refreshTokens)
+      .where(eq(this.schema.refreshTokens.userId, userId))
+      .orderBy(asc(this.schema.refreshTokens.createdAt));
+    // Login backoff counts failures by the address people log in with
+    const [failures] = await tx
+      .select({
+        failures: this.schema.loginFailures.failures,
+        lastFailedAt: this.schema.loginFailures.lastFailedAt,
+      })
+      .from(this.schema.loginFailures)
+      .where(eq(this.schema.loginFailures.identifier, `email:${profile.email.toLowerCase()}`));
+
+    const boxPokemon = await tx
+      .select()
+      .from(this.schema.userPokemon)
+      .where(eq(this.schema.userPokemon.userId, userId))
+      .orderBy(asc(this.schema.userPokemon.createdAt));
+    const benchmarks = await tx
+      .select()
+      .from(this.schema.damageBenchmarks)
+      .where(eq(this.schema.damageBenchmarks.ownerId, userId))
+      .orderBy(asc(this.schema.damageBenchmarks.createdAt));
+    const customFormRows = await tx
+      .select()
+      .from(this.schema.customForms)
+      .where(eq(this.schema.customForms.ownerId, userId))
+      .orderBy(asc(this.schema.customForms.formId));
+
+    const likes = await tx
+      .select({ teamId: this.schema.teamLikes.teamId, createdAt: this.schema.teamLikes.createdAt })
+      .from(this.schema.teamLikes)
+      .where(eq(this.schema.teamLikes.userId, userId))
+      .orderBy(asc(this.schema.teamLikes.createdAt));
+    const comments = await tx
+      .select({
+        commentId: this.schema.teamComments.commentId,
+        teamId: this.schema.teamComments.teamId,
+        parentId: this.schema.teamComments.parentId,
+        body: this.schema.teamComments.body,
+        createdAt: this.schema.teamComments.createdAt,
+        updatedAt: this.schema.teamComments.updatedAt,
+        deletedAt: this.schema.teamComments.deletedAt,
+      })
+      .from(this.schema.teamComments)
+      .where(eq(this.schema.teamComments.authorId, userId))
+      .orderBy(asc(this.schema.teamComments.createdAt));
+    const flags = await tx
+      .select({
+        teamId: this.schema.teamFlags.teamId,
+        reason: this.schema.teamFlags.reason,
+        createdAt: this.schema.teamFlags.createdAt,
+      })
+      .from(this.schema.teamFlags)
+      .where(eq(this.schema.teamFlags.userId, userId))
+      .orderBy(asc(this.schema.teamFlags.createdAt));
+    const moderationItems = await tx
+      .select({
+        itemId: this.schema.moderationQueue.itemId,
+        kind: this.schema.moderationQueue.kind,
+        targetId: this.schema.moderationQueue.targetId,
+        text: this.schema.moderationQueue.text,
+        reasons: this.schema.moderationQueue.reasons,
+        createdAt: this.schema.moderationQueue.createdAt,
+      })
+      .from(this.schema.moderationQueue)
+      .where(eq(this.schema.moderationQueue.authorId, userId))
+      .orderBy(asc(this.schema.moderationQueue.createdAt));
+    const notificationRows = await tx
+      .select({
+        notificationId: this.schema.notifications.notificationId,
+        type: this.schema.notifications.type,
+        data: this.schema.notifications.data,
+        createdAt: this.schema.notifications.createdAt,
+        readAt: this.schema.notifications.readAt,
+      })
+      .from(this.schema.notifications)
+      .where(eq(this.schema.notifications.userId, userId))
+      .orderBy(asc(this.schema.notifications.createdAt));
+
+    const [quizScore] = await tx
+      .select({
+        answered: this.schema.typeQuizScores.answered,
+        correct: this.schema.typeQuizScores.correct,
+        currentStreak: this.schema.typeQuizScores.currentStreak,
+        bestStreak: this.schema.typeQuizScores.bestStreak,
+        updatedAt: this.schema.typeQuizScores.updatedAt,
+      })
+      .from(this.schema.typeQuizScores)
+      .where(eq(this.schema.typeQuizScores.userId, userId));
+    const quizAnswers = await tx
+      .select({
+        questionId: this.schema.typeQuizAnswers.questionId,
+        correct: this.schema.typeQuizAnswers.correct,
+        answeredAt: this.schema.typeQuizAnswers.answeredAt,
+      })
+      .from(this.schema.typeQuizAnswers)
+      .where(eq(this.schema.typeQuizAnswers.userId, userId))
+      .orderBy(asc(this.schema.typeQuizAnswers.answeredAt));
+
+    return {
+      profile,
+      preferences: preferences ?? null,
+      pendingEmailChange: pendingEmailChange ?? null,
+      sessions,
+      loginFailures: failures ?? null,
+      teams: await this.findTeams(tx, userId),
+      boxPokemon: boxPokemon.map((row) => ({
+        pokemonId: row.pokemonId,
+        nickname: row.nickname,
+        ...toBuild(row),
+        moves: [row.move1, row.move2, row.move3, row.move4].filter(
+          (move): move is string => move !== null
+        ),
+        createdAt: row.createdAt,
+      })),
+      battleRecords: await this.findBattleRecords(tx, userId),
+      damageBenchmarks: benchmarks.map((row) => ({
+        benchmarkId: row.benchmarkId,
+        format: row.format,
+        name: row.name,
+        role: row.role,
+        ...toBuild(row),
+        move: row.move,
+        createdAt: row.createdAt,
+      })),
+      customForms: customFormRows.map((row) => ({
+        formId: row.formId,
+        name: row.name,
+        type1: row.type1,
+        type2: row.type2,
+        baseStats: {
+          hp: row.hp,
+          attack: row.attack,
+          defense: row.defense,
+          specialAttack: row.specialAttack,
+          specialDefense: row.specialDefense,
+          speed: row.speed,
+        },
+        ability1: row.ability1,
+        ability2: row.ability2,
+        hiddenAbility: row.hiddenAbility,
+        createdAt: row.createdAt,
+        updatedAt: row.updatedAt,
+      })),
+      likes,
+      comments,
+      flags,
+      moderationItems,
+      notifications: notificationRows,
+      runs: await this.findRuns(tx, userId),
+      draftLeagues: await this.findDraftLeagues(tx, userId),
+      tournaments: await this.findTournaments(tx, userId),
+      webhooks: await this.findWebhooks(tx, userId),
+      typeQuiz: quizScore ? { ...quizScore, answers: quizAnswers } : null,
+    };
+  }
+
+  // Teams in the trash included
+  private async findTeams(tx: Transaction, userId: string): Promise<ArchivedTeam[]> {
+    const rows = await tx
+      .select()
+      .from(this.schema.teams)
+      .where(eq(this.schema.teams.ownerId, userId))
+      .orderBy(asc(this.schema.teams.createdAt));
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const teamIds = rows.map((row) => row.teamId);
+    const memberRows = await tx
+      .select()
+      .from(this.schema.teamPokemon)
+      .where(inArray(this.schema.teamPokemon.teamId, teamIds))
+      .orderBy(asc(this.schema.teamPokemon.slot));
+    const tagRows = await tx
+      .select()
+      .from(this.schema.teamTags)
+      .where(inArray(this.schema.teamTags.teamId, teamIds))
+      .orderBy(asc(this.schema.teamTags.tag));
+
+    return rows.map((row) => ({
+      teamId: row.teamId,
+      ownerId: row.ownerId,
+      teamName: row.teamName,
+      members: memberRows
+        .filter((member) => member.teamId === row.teamId)
+        .map(({ slot, formId, terastalType, notes }) => ({ slot, formId, terastalType, notes })),
+      tags: tagRows.filter((tag) => tag.teamId === row.teamId).map((tag) => tag.tag),
+      folder: row.folder,
+      notes: row.notes,
+      createdAt: row.createdAt,
+      updatedAt: row.updatedAt,
+      deletedAt: row.deletedAt,
+      sharedAt: row.sharedAt,
+      sharedFormat: row.sharedFormat,
+    }));
+  }
+
+  private async findBattleRecords(
+    tx: Transaction,
+    userId: string
+  ): Promise<PersonalData['battleRecords']> {
+    const rows = await tx
+      .select()
+      .from(this.schema.battleRecords)
+      .where(eq(this.schema.battleRecords.userId, userId))
+      .orderBy(asc(this.schema.battleRecords.playedAt));
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const opponentRows = await tx
+      .select()
+      .from(this.schema.battleRecordOpponents)
+      .where(inArray(this.schema.battleRecordOpponents.recordId, rows.map((row) => row.recordId)))
+      .orderBy(asc(this.schema.battleRecordOpponents.slot));
+    return rows.map((row) => ({
+      recordId: row.recordId,
+      teamId: row.teamId,
+      format: row.format,
+      result: row.result,
+      opponentName: row.opponentName,
+      opponentArchetype: row.opponentArchetype,
+      opponentFormIds: opponentRows
+        .filter((opponent) => opponent.recordId === row.recordId)
+        .map((opponent) => opponent.formId),
+      notes: row.notes,
+      playedAt: row.playedAt,
+      createdAt: row.createdAt,
+    }));
+  }
+
+  private async findRuns(tx: Transaction, userId: string): Promise<PersonalData['runs']> {
+    const rows = await tx
+      .select()
+      .from(this.schema.challengeRuns)
+      .where(eq(this.schema.challengeRuns.ownerId, userId))
+      .orderBy(asc(this.schema.challengeRuns.createdAt));
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const speciesRows = await tx
+      .select()
+      .from(this.schema.challengeRunSpecies)
+      .where(inArray(this.schema.challengeRunSpecies.runId, rows.map((row) => row.runId)))
+      .orderBy(asc(this.schema.challengeRunSpecies.speciesId));
+    return rows.map((row) => ({
+      runId: row.runId,
+      name: row.name,
+      gameVersion: row.gameVersion,
+      speciesIds: speciesRows
+        .filter((species) => species.runId === row.runId)
+        .map((species) => species.speciesId),
+      createdAt: row.createdAt,
+    }));
+  }
+
+  private async findDraftLeagues(
+    tx: Transaction,
+    userId: string
+  ): Promise<PersonalData['draftLeagues']> {
+    const participantRows = await tx
+      .select()
+      .from(this.schema.draftLeagueParticipants)
+      .where(eq(this.schema.draftLeagueParticipants.userId, userId));
+    const joined = tx
+      .select({ leagueId: this.schema.draftLeagueParticipants.leagueId })
+      .from(this.schema.draftLeagueParticipants)
+      .where(eq(this.schema.draftLeagueParticipants.userId, userId));
+    const rows = await tx
+      .select()
+      .from(this.schema.draftLeagues)
+      .where(
+        or(
+          eq(this.schema.draftLeagues.ownerId, userId),
+          inArray(this.schema.draftLeagues.leagueId, joined)
+        )
+      )
+      .orderBy(asc(this.schema.draftLeagues.createdAt));
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const pickRows = await tx
+      .select()
+      .from(this.schema.draftLeaguePicks)
+      .where(eq(this.schema.draftLeaguePicks.userId, userId))
+      .orderBy(asc(this.schema.draftLeaguePicks.pickNumber));
+    return rows.map((row) => ({
+      leagueId: row.leagueId,
+      name: row.name,
+      format: row.format,
+      commissioner: row.ownerId === userId,
+      position:
+        participantRows.find((participant) => participant.leagueId === row.leagueId)?.position ??
+        null,
+      picks: pickRows
+        .filter((pick) => pick.leagueId === row.leagueId)
+        .map(({ pickNumber, formId, pickedAt }) => ({ pickNumber, formId, pickedAt })),
+      createdAt: row.createdAt,
+    }));
+  }
+
+  private async findTournaments(
+    tx: Transaction,
+    userId: string
+  ): Promise<PersonalData['tournaments']> {
+    const entryRows = await tx
+      .select()
+      .from(this.schema.tournamentEntries)
+      .where(eq(this.schema.tournamentEntries.userId, userId));
+    const entered = tx
+      .select({ tournamentId: this.schema.tournamentEntries.tournamentId })
+      .from(this.schema.tournamentEntries)
+      .where(eq(this.schema.tournamentEntries.userId, userId));
+    const rows = await tx
+      .select()
+      .from(this.schema.tournaments)
+      .where(
+        or(
+          eq(this.schema.tournaments.organizerId, userId),
+          inArray(this.schema.tournaments.tournamentId, entered)
+        )
+      )
+      .orderBy(asc(this.schema.tournaments.createdAt));
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const memberRows = await tx
+      .select()
+      .from(this.schema.tournamentEntryMembers)
+      .where(eq(this.schema.tournamentEntryMembers.userId, userId))
+      .orderBy(asc(this.schema.tournamentEntryMembers.slot));
+    const matchRows = await tx
+      .select()
+      .from(this.schema.tournamentMatches)
+      .where(
+        or(
+          eq(this.schema.tournamentMatches.player1Id, userId),
+          eq(this.schema.tournamentMatches.player2Id, userId)
+        )
+      )
+      .orderBy(asc(this.schema.tournamentMatches.round));
+    return rows.map((row) => {
+      const entry = entryRows.find((candidate) => candidate.tournamentId === row.tournamentId);
+      return {
+        tournamentId: row.tournamentId,
+        name: row.name,
+        format: row.format,
+        status: row.status,
+        organizer: row.organizerId === userId,
+        entry: entry
+          ? {
+              teamId: entry.teamId,
+              teamName: entry.teamName,
+              teamVersion: entry.teamVersion,
+              members: memberRows
+                .filter((member) => member.tournamentId === row.tournamentId)
+                .map(({ slot, formId, terastalType }) => ({ slot, formId, terastalType })),
+              seed: entry.seed,
+              registeredAt: entry.registeredAt,
+            }
+          : null,
+        matches: matchRows
+          .filter(
+            (match) => match.tournamentId === row.tournamentId && match.player1Id && match.player2Id
+          )
+          .map((match) => ({
+            round: match.round,
+            position: match.position,
+            won: match.winnerId ? match.winnerId === userId : null,
+            reportedAt: match.reportedAt,
+          })),
+        createdAt: row.createdAt,
+      };
+    });
+  }
+
+  private async findWebhooks(tx: Transaction, userId: string): Promise<PersonalData['webhooks']> {
+    const rows = await tx
+      .select({
+        webhookId: this.schema.webhooks.webhookId,
+        url: this.schema.webhooks.url,
+        createdAt: this.schema.webhooks.createdAt,
+      })
+      .from(this.schema.webhooks)
+      .where(eq(this.schema.webhooks.ownerId, userId))
+      .orderBy(asc(this.schema.webhooks.createdAt));
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const webhookIds = rows.map((row) => row.webhookId);
+    const eventRows = await tx
+      .select()
+      .from(this.schema.webhookEvents)
+      .where(inArray(this.schema.webhookEvents.webhookId, webhookIds))
+      .orderBy(asc(this.schema.webhookEvents.eventType));
+    const deliveryRows = await tx
+      .select()
+      .from(this.schema.webhookDeliveries)
+      .where(inArray(this.schema.webhookDeliveries.webhookId, webhookIds))
+      .orderBy(asc(this.schema.webhookDeliveries.attemptedAt));
+    return rows.map((row) => ({
+      webhookId: row.webhookId,
+      url: row.url,
+      eventTypes: eventRows
+        .filter((event) => event.webhookId === row.webhookId)
+        .map((event) => event.eventType),
+      deliveries: deliveryRows
+        .filter((delivery) => delivery.webhookId === row.webhookId)
+        .map((delivery) => ({
+          deliveryId: delivery.deliveryId,
+          eventId: delivery.eventId,
+          eventType: delivery.eventType,
+          attempt: delivery.attempt,
+          statusCode: delivery.statusCode,
+          error: delivery.error,
+          succeeded: delivery.succeeded,
+          attemptedAt: delivery.attemptedAt,
+        })),
+      createdAt: row.createdAt,
+    }));
+  }
+}
+
+function toBuild(row: BuildRow): PersonalBuild {
+  return {
+    formId: row.formId,
+    nature: row.nature,
+    ability: row.ability,
+    item: row.item,
+    teraType: row.teraType,
+    evs: {
+      hp: row.evHp,
+      attack: row.evAttack,
+      defense: row.evDefense,
+      specialAttack: row.evSpecialAttack,
+      specialDefense: row.evSpecialDefense,
+      speed: row.evSpeed,
+    },
+    ivs: {
+      hp: row.ivHp,
+      attack: row.ivAttack,
+      defense: row.ivDefense,
+      specialAttack: row.ivSpecialAttack,
+      specialDefense: row.ivSpecialDefense,
+      speed: row.ivSpeed,
+    },
+  };
+}
@@ -0,0 +1,526 @@
+import { and, asc, eq, gt, inArray, isNull, or, SQL } from 'drizzle-orm';
+import { PokemonMaster } from '../../domain/entity/PokemonMaster';
+import {
+  AbilityEntry,
+  AbilitySlots,
+  AbilitySlotSchema,
+} from '../../domain/valueobject/AbilitySlots';
+import { Encounter } from '../../domain/valueobject/Encounter';
+import { FormMedia } from '../../domain/valueobject/FormMedia';
+import {
+  Availability,
+  GAME_VERSIONS,
+  GameVersion,
+  GameVersionSchema,
+  getGeneration,
+} from '../../domain/valueobject/GameVersion';
+import { getNameKeys } from '../../domain/valueobject/NameKey';
+import { FormId, SpeciesId } from '../../domain/valueobject/PokemonId';
+import { PokemonType, PokemonTypeSchema, TypeSet } from '../../domain/valueobject/PokemonType';
+import {
+  PokemonFormFilter,
+  PokemonFormRepository,
+  PokemonFormRepositoryError,
+} from '../interface/PokemonFormRepository';
+import { Db, Schema, Transaction } from './types';
+
+type FormRow = Schema['pokemonForms']['$inferSelect'];
+
+export class DrizzlePokemonFormRepository implements PokemonFormRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(formId: FormId): Promise<PokemonMaster | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(eq(this.schema.pokemonForms.formId, formId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [form] = await this.toEntities(result);
+      return form;
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find form by ID: ${error}`);
+    }
+  }
+
+  async findByIds(formIds: FormId[]): Promise<PokemonMaster[]> {
+    if (formIds.length === 0) {
+      return [];
+    }
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(inArray(this.schema.pokemonForms.formId, formIds));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find forms by IDs: ${error}`);
+    }
+  }
+
+  async findBySpeciesId(speciesId: SpeciesId): Promise<PokemonMaster[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(eq(this.schema.pokemonForms.speciesId, speciesId))
+        .orderBy(asc(this.schema.pokemonForms.formId));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find forms by species: ${error}`);
+    }
+  }
+
+  async findAll(filter: PokemonFormFilter = {}): Promise<PokemonMaster[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(and(...this.toConditions(filter)))
+        .orderBy(asc(this.schema.pokemonForms.formId));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to list forms: ${error}`);
+    }
+  }
+
+  async findPage(
+    filter: PokemonFormFilter,
+    limit: number,
+    after?: FormId
+  ): Promise<PokemonMaster[]> {
+    const conditions = this.toConditions(filter);
+    if (after !== undefined) {
+      conditions.push(gt(this.schema.pokemonForms.formId, after));
+    }
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(and(...conditions))
+        .orderBy(asc(this.schema.pokemonForms.formId))
+        .limit(limit);
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find page of forms: ${error}`);
+    }
+  }
+
+  async findByNameKeys(keys: string[]): Promise<PokemonMaster[]> {
+    if (keys.length === 0) {
+      return [];
+    }
+
+    try {
+      const indexed = this.db
+        .select({ formId: this.schema.pokemonNameIndex.formId })
+        .from(this.schema.pokemonNameIndex)
+        .where(inArray(this.schema.pokemonNameIndex.key, keys));
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(inArray(this.schema.pokemonForms.formId, indexed))
+        .orderBy(asc(this.schema.pokemonForms.formId));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find forms by name: ${error}`);
+    }
+  }
+
+  async findByIdsOrNameKeys(formIds: FormId[], keys: string[]): Promise<PokemonMaster[]> {
+    if (formIds.length === 0 && keys.length === 0) {
+      return [];
+    }
+
+    try {
+      const indexed = this.db
+        .select({ formId: this.schema.pokemonNameIndex.formId })
+        .from(this.schema.pokemonNameIndex)
+        .where(inArray(this.schema.pokemonNameIndex.key, keys));
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(
+          or(
+            inArray(this.schema.pokemonForms.formId, formIds),
+            inArray(this.schema.pokemonForms.formId, indexed)
+          )
+        )
+        .orderBy(asc(this.schema.pokemonForms.formId));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find forms by ID or name: ${error}`);
+    }
+  }
+
+  async findByTypeset(typeset: TypeSet): Promise<PokemonMaster[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(
+          and(
+            eq(this.schema.pokemonForms.type1, typeset.type1),
+            typeset.type2
+              ? eq(this.schema.pokemonForms.type2, typeset.type2)
+              : isNull(this.schema.pokemonForms.type2)
+          )
+        )
+        .orderBy(asc(this.schema.pokemonForms.formId));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find forms by typeset: ${error}`);
+    }
+  }
+
+  async findContainingType(type: PokemonType): Promise<PokemonMaster[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(
+          or(eq(this.schema.pokemonForms.type1, type), eq(this.schema.pokemonForms.type2, type))
+        )
+        .orderBy(asc(this.schema.pokemonForms.formId));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find forms by type: ${error}`);
+    }
+  }
+
+  async findByAbility(ability: string): Promise<PokemonMaster[]> {
+    const withAbility = this.db
+      .select({ formId: this.schema.pokemonFormAbilities.formId })
+      .from(this.schema.pokemonFormAbilities)
+      .where(eq(this.schema.pokemonFormAbilities.ability, ability));
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonForms)
+        .where(inArray(this.schema.pokemonForms.formId, withAbility))
+        .orderBy(asc(this.schema.pokemonForms.formId));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find forms by ability: ${error}`);
+    }
+  }
+
+  async save(form: PokemonMaster): Promise<void> {
+    const typeset = form.getTypeset();
+    const availability = form.getAvailability();
+    const baseStats = form.getBaseStats();
+    const values = {
+      speciesId: form.getSpeciesId(),
+      name: form.getName(),
+      nameJp: form.getNameJp(),
+      type1: typeset.type1,
+      type2: typeset.type2 ?? null,
+      baseHp: baseStats.hp,
+      baseAttack: baseStats.attack,
+      baseDefense: baseStats.defense,
+      baseSpecialAttack: baseStats.specialAttack,
+      baseSpecialDefense: baseStats.specialDefense,
+      baseSpeed: baseStats.speed,
+      baseExperience: form.getBaseExperience() ?? null,
+      height: form.getHeight(),
+      weight: form.getWeight(),
+      shinyAvailable: form.isShinyAvailable(),
+      shinyLocked: form.isShinyLocked(),
+      introducedIn: availability.introducedIn,
+    };
+
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .insert(this.schema.pokemonForms)
+          .values({ formId: form.getFormId(), ...values })
+          .onConflictDoUpdate({ target: this.schema.pokemonForms.formId, set: values });
+
+        await tx
+          .delete(this.schema.pokemonFormAvailability)
+          .where(eq(this.schema.pokemonFormAvailability.formId, form.getFormId()));
+
+        const obtainableIn = availability.getObtainableIn();
+        if (obtainableIn.length > 0) {
+          await tx
+            .insert(this.schema.pokemonFormAvailability)
+            .values(obtainableIn.map((gameVersion) => ({ formId: form.getFormId(), gameVersion })));
+        }
+
+        await tx
+          .delete(this.schema.pokemonFormAbilities)
+          .where(eq(this.schema.pokemonFormAbilities.formId, form.getFormId()));
+
+        const abilities = form.getAbilities()?.getEntries() ?? [];
+        if (abilities.length > 0) {
+          await tx
+            .insert(this.schema.pokemonFormAbilities)
+            .values(abilities.map((entry) => ({ formId: form.getFormId(), ...entry })));
+        }
+
+        await this.saveNameKeys(tx, form.getFormId(), form.getName(), form.getNameJp());
+      });
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to save form: ${error}`);
+    }
+  }
+
+  async rebuildNameIndex(): Promise<number> {
+    try {
+      const rows = await this.db
+        .select({
+          formId: this.schema.pokemonForms.formId,
+          name: this.schema.pokemonForms.name,
+          nameJp: this.schema.pokemonForms.nameJp,
+        })
+        .from(this.schema.pokemonForms);
+
+      await this.db.transaction(async (tx) => {
+        for (const row of rows) {
+          await this.saveNameKeys(tx, row.formId, row.name, row.nameJp);
+        }
+      });
+      return rows.length;
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to rebuild name index: ${error}`);
+    }
+  }
+
+  async findEncounterLocations(gameVersion: GameVersion): Promise<string[]> {
+    try {
+      const rows = await this.db
+        .selectDistinct({ location: this.schema.pokemonEncounters.location })
+        .from(this.schema.pokemonEncounters)
+        .where(eq(this.schema.pokemonEncounters.gameVersion, gameVersion))
+        .orderBy(asc(this.schema.pokemonEncounters.location));
+
+      return rows.map((row) => row.location);
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find encounter locations: ${error}`);
+    }
+  }
+
+  async saveEncounters(formId: FormId, encounters: Encounter[]): Promise<void> {
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .delete(this.schema.pokemonEncounters)
+          .where(eq(this.schema.pokemonEncounters.formId, formId));
+
+        if (encounters.length > 0) {
+          await tx
+            .insert(this.schema.pokemonEncounters)
+            .values(encounters.map((encounter) => ({ formId, ...encounter })))
+            .onConflictDoNothing();
+        }
+      });
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to save encounters: ${error}`);
+    }
+  }
+
+  async findMedia(formId: FormId): Promise<FormMedia | null> {
+    try {
+      const [row] = await this.db
+        .select()
+        .from(this.schema.pokemonFormMedia)
+        .where(eq(this.schema.pokemonFormMedia.formId, formId));
+      if (!row) {
+        return null;
+      }
+      return {
+        cryUrl: row.cryUrl,
+        legacyCryUrl: row.legacyCryUrl,
+        spriteUrl: row.spriteUrl,
+        shinySpriteUrl: row.shinySpriteUrl,
+        has3dModel: row.has3dModel,
+        has3dShinyModel: row.has3dShinyModel,
+        checkedAt: row.checkedAt,
+      };
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to find form media: ${error}`);
+    }
+  }
+
+  async saveMedia(formId: FormId, media: FormMedia): Promise<void> {
+    try {
+      await this.db
+        .insert(this.schema.pokemonFormMedia)
+        .values({ formId, ...media })
+        .onConflictDoUpdate({ target: this.schema.pokemonFormMedia.formId, set: media });
+    } catch (error) {
+      throw new PokemonFormRepositoryError(`Failed to save form media: ${error}`);
+    }
+  }
+
+  private toConditions(filter: PokemonFormFilter): SQL[] {
+    const conditions: SQL[] = [];
+
+    if (filter.availableIn) {
+      const available = this.db
+        .select({ formId: this.schema.pokemonFormAvailability.formId })
+        .from(this.schema.pokemonFormAvailability)
+        .where(eq(this.schema.pokemonFormAvailability.gameVersion, filter.availableIn));
+      conditions.push(inArray(this.schema.pokemonForms.formId, available));
+    }
+
+    if (filter.maxGeneration !== undefined) {
+      const maxGeneration = filter.maxGeneration;
+      const versions = GAME_VERSIONS.filter((v) => getGeneration(v) <= maxGeneration);
+      conditions.push(inArray(this.schema.pokemonForms.introducedIn, versions));
+    }
+
+    if (filter.generation !== undefined) {
+      const generation = filter.generation;
+      const versions = GAME_VERSIONS.filter((v) => getGeneration(v) === generation);
+      conditions.push(inArray(this.schema.pokemonForms.introducedIn, versions));
+    }
+
+    if (filter.encounterGame || filter.encounterLocation) {
+      const encounterConditions: SQL[] = [];
+      if (filter.encounterGame) {
+        encounterConditions.push(
+          eq(this.schema.pokemonEncounters.gameVersion, filter.encounterGame)
+        );
+      }
+      if (filter.encounterLocation) {
+        encounterConditions.push(
+          eq(this.schema.pokemonEncounters.location, filter.encounterLocation)
+        );
+      }
+      const encountered = this.db
+        .select({ formId: this.schema.pokemonEncounters.formId })
+        .from(this.schema.pokemonEncounters)
+        .where(and(...encounterConditions));
+      conditions.push(inArray(this.schema.pokemonForms.formId, encountered));
+    }
+
+    if (filter.firstStageOnly) {
+      const firstStages = this.db
+        .select({ speciesId: this.schema.pokemonSpecies.speciesId })
+        .from(this.schema.pokemonSpecies)
+        .where(isNull(this.schema.pokemonSpecies.evolvesFromSpeciesId));
+      conditions.push(inArray(this.schema.pokemonForms.speciesId, firstStages));
+    }
+
+    return conditions;
+  }
+
+  private async saveNameKeys(
+    tx: Transaction,
+    formId: number,
+    name: string,
+    nameJp: string
+  ): Promise<void> {
+    await tx
+      .delete(this.schema.pokemonNameIndex)
+      .where(eq(this.schema.pokemonNameIndex.formId, formId));
+
+    const keys = getNameKeys(name, nameJp);
+    if (keys.length > 0) {
+      await tx.insert(this.schema.pokemonNameIndex).values(keys.map((key) => ({ formId, key })));
+    }
+  }
+
+  private async toEntities(rows: FormRow[]): Promise<PokemonMaster[]> {
+    const formIds = rows.map((row) => row.formId);
+    const availability = await this.findAvailability(formIds);
+    const abilities = await this.findAbilities(formIds);
+    return rows.map((row) =>
+      this.toEntity(
+        row,
+        availability.get(row.formId) ?? [],
+        AbilitySlots.fromEntries(abilities.get(row.formId) ?? [])
+      )
+    );
+  }
+
+  private async findAvailability(formIds: number[]): Promise<Map<number, GameVersion[]>> {
+    const byForm = new Map<number, GameVersion[]>();
+    if (formIds.length === 0) {
+      return byForm;
+    }
+
+    const rows = await this.db
+      .select()
+      .from(this.schema.pokemonFormAvailability)
+      .where(inArray(this.schema.pokemonFormAvailability.formId, formIds));
+
+    for (const row of rows) {
+      const versions = byForm.get(row.formId) ?? [];
+      versions.push(GameVersionSchema.parse(row.gameVersion));
+      byForm.set(row.formId, versions);
+    }
+    return byForm;
+  }
+
+  private async findAbilities(formIds: number[]): Promise<Map<number, AbilityEntry[]>> {
+    const byForm = new Map<number, AbilityEntry[]>();
+    if (formIds.length === 0) {
+      return byForm;
+    }
+
+    const rows = await this.db
+      .select()
+      .from(this.schema.pokemonFormAbilities)
+      .where(inArray(this.schema.pokemonFormAbilities.formId, formIds));
+
+    for (const row of rows) {
+      const entries = byForm.get(row.formId) ?? [];
+      entries.push({ slot: AbilitySlotSchema.parse(row.slot), ability: row.ability });
+      byForm.set(row.formId, entries);
+    }
+    return byForm;
+  }
+
+  private toEntity(
+    row: FormRow,
+    obtainableIn: GameVersion[],
+    abilities: AbilitySlots | undefined
+  ): PokemonMaster {
+    const typeset = new TypeSet(
+      PokemonTypeSchema.parse(row.type1),
+      row.type2 ? PokemonTypeSchema.parse(row.type2) : undefined
+    );
+
+    return PokemonMaster.fromRepository({
+      formId: FormId.fromRepository(row.formId),
+      speciesId: SpeciesId.fromRepository(row.speciesId),
+      name: row.name,
+      nameJp: row.nameJp,
+      typeset,
+      baseStats: {
+        hp: row.baseHp,
+        attack: row.baseAttack,
+        defense: row.baseDefense,
+        specialAttack: row.baseSpecialAttack,
+        specialDefense: row.baseSpecialDefense,
+        speed: row.baseSpeed,
+      },
+      abilities,
+      baseExperience: row.baseExperience ?? undefined,
+      height: row.height,
+      weight: row.weight,
+      shinyAvailable: row.shinyAvailable,
+      shinyLocked: row.shinyLocked,
+      availability: new Availability(GameVersionSchema.parse(row.introducedIn), obtainableIn),
+    });
+  }
+}
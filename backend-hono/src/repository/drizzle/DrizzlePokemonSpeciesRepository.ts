@@ -0,0 +1,290 @@
+import { and, eq, inArray, or } from 'drizzle-orm';
+import { PokemonSpecies } from '../../domain/entity/PokemonSpecies';
+import { RegionalDexNumber, RegionalDexSchema } from '../../domain/valueobject/DexNumber';
+import { EggGroup, EggGroupSchema } from '../../domain/valueobject/EggGroup';
+import { GenderRatio } from '../../domain/valueobject/GenderRatio';
+import { FormId, SpeciesId } from '../../domain/valueobject/PokemonId';
+import { SpeciesCategorySchema } from '../../domain/valueobject/SpeciesCategory';
+import { Learnset, LearnMethodSchema, LearnsetEntry } from '../../domain/valueobject/Learnset';
+import {
+  MoveLearner,
+  PokemonSpeciesRepository,
+  PokemonSpeciesRepositoryError,
+} from '../interface/PokemonSpeciesRepository';
+import { Db, Schema } from './types';
+
+type SpeciesRow = Schema['pokemonSpecies']['$inferSelect'];
+
+export class DrizzlePokemonSpeciesRepository implements PokemonSpeciesRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(speciesId: SpeciesId): Promise<PokemonSpecies | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.pokemonSpecies)
+        .where(eq(this.schema.pokemonSpecies.speciesId, speciesId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [species] = await this.toEntities(result);
+      return species;
+    } catch (error) {
+      throw new PokemonSpeciesRepositoryError(`Failed to find species by ID: ${error}`);
+    }
+  }
+
+  async findByIds(speciesIds: SpeciesId[]): Promise<PokemonSpecies[]> {
+    if (speciesIds.length === 0) {
+      return [];
+    }
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonSpecies)
+        .where(inArray(this.schema.pokemonSpecies.speciesId, speciesIds));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonSpeciesRepositoryError(`Failed to find species by IDs: ${error}`);
+    }
+  }
+
+  async findByRegionalDexNumber(dexNumber: RegionalDexNumber): Promise<PokemonSpecies | null> {
+    try {
+      const result = await this.db
+        .select({ species: this.schema.pokemonSpecies })
+        .from(this.schema.pokemonSpecies)
+        .innerJoin(
+          this.schema.pokemonSpeciesDexNumbers,
+          eq(this.schema.pokemonSpeciesDexNumbers.speciesId, this.schema.pokemonSpecies.speciesId)
+        )
+        .where(
+          and(
+            eq(this.schema.pokemonSpeciesDexNumbers.dex, dexNumber.dex),
+            eq(this.schema.pokemonSpeciesDexNumbers.number, dexNumber.number)
+          )
+        )
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [species] = await this.toEntities([result[0].species]);
+      return species;
+    } catch (error) {
+      throw new PokemonSpeciesRepositoryError(`Failed to find species by dex number: ${error}`);
+    }
+  }
+
+  async findByEggGroups(groups: EggGroup[]): Promise<PokemonSpecies[]> {
+    if (groups.length === 0) {
+      return [];
+    }
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.pokemonSpecies)
+        .where(
+          or(
+            inArray(this.schema.pokemonSpecies.eggGroup1, groups),
+            inArray(this.schema.pokemonSpecies.eggGroup2, groups)
+          )
+        );
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new PokemonSpeciesRepositoryError(`Failed to find species by egg groups: ${error}`);
+    }
+  }
+
+  async findLearnset(speciesId: SpeciesId): Promise<Learnset[]> {
+    const learnsets = await this.findLearnsets([speciesId]);
+    return learnsets.get(speciesId) ?? [];
+  }
+
+  async findLearnsets(speciesIds: SpeciesId[]): Promise<Map<SpeciesId, Learnset[]>> {
+    if (speciesIds.length === 0) {
+      return new Map();
+    }
+
+    try {
+      const rows = await this.db
+        .select({
+          speciesId: this.schema.pokemonForms.speciesId,
+          formId: this.schema.pokemonLearnsets.formId,
+          moveName: this.schema.pokemonLearnsets.moveName,
+          learnMethod: this.schema.pokemonLearnsets.learnMethod,
+          level: this.schema.pokemonLearnsets.level,
+        })
+        .from(this.schema.pokemonLearnsets)
+        .innerJoin(
+          this.schema.pokemonForms,
+          eq(this.schema.pokemonForms.formId, this.schema.pokemonLearnsets.formId)
+        )
+        .where(inArray(this.schema.pokemonForms.speciesId, speciesIds));
+
+      const byForm = new Map<number, { speciesId: number; entries: LearnsetEntry[] }>();
+      for (const row of rows) {
+        const form = byForm.get(row.formId) ?? { speciesId: row.speciesId, entries: [] };
+        form.entries.push({
+          moveName: row.moveName,
+          method: LearnMethodSchema.parse(row.learnMethod),
+          level: row.level ?? undefined,
+        });
+        byForm.set(row.formId, form);
+      }
+
+      const bySpecies = new Map<SpeciesId, Learnset[]>();
+      for (const [formId, { speciesId, entries }] of byForm) {
+        const id = SpeciesId.fromRepository(speciesId);
+        const learnsets = bySpecies.get(id) ?? [];
+        learnsets.push(new Learnset(FormId.fromRepository(formId), entries));
+        bySpecies.set(id, learnsets);
+      }
+      return bySpecies;
+    } catch (error) {
+      throw new PokemonSpeciesRepositoryError(`Failed to find learnsets: ${error}`);
+    }
+  }
+
+  async findLearnersOfMove(moveName: string): Promise<MoveLearner[]> {
+    try {
+      const rows = await this.db
+        .select({
+          speciesId: this.schema.pokemonForms.speciesId,
+          formId: this.schema.pokemonLearnsets.formId,
+          learnMethod: this.schema.pokemonLearnsets.learnMethod,
+        })
+        .from(this.schema.pokemonLearnsets)
+        .innerJoin(
+          this.schema.pokemonForms,
+          eq(this.schema.pokemonForms.formId, this.schema.pokemonLearnsets.formId)
+        )
+        .where(eq(this.schema.pokemonLearnsets.moveName, moveName));
+
+      return rows.map((row) => ({
+        speciesId: SpeciesId.fromRepository(row.speciesId),
+        formId: FormId.fromRepository(row.formId),
+        method: LearnMethodSchema.parse(row.learnMethod),
+      }));
+    } catch (error) {
+      throw new PokemonSpeciesRepositoryError(`Failed to find learners of move: ${error}`);
+    }
+  }
+
+  async save(species: PokemonSpecies): Promise<void> {
+    const [eggGroup1, eggGroup2] = species.getEggGroups();
+    const values = {
+      name: species.getName(),
+      nameJp: species.getNameJp(),
+      eggGroup1,
+      eggGroup2: eggGroup2 ?? null,
+      genderRate: species.getGenderRatio().getFemaleEighths(),
+      captureRate: species.getCaptureRate(),
+      category: species.getCategory(),
+      evolvesFromSpeciesId: species.getEvolvesFromSpeciesId() ?? null,
+    };
+
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .insert(this.schema.pokemonSpecies)
+          .values({ speciesId: species.getSpeciesId(), ...values })
+          .onConflictDoUpdate({ target: this.schema.pokemonSpecies.speciesId, set: values });
+
+        await tx
+          .delete(this.schema.pokemonSpeciesDexNumbers)
+          .where(eq(this.schema.pokemonSpeciesDexNumbers.speciesId, species.getSpeciesId()));
+
+        const dexNumbers = species.getRegionalDexNumbers();
+        if (dexNumbers.length > 0) {
+          await tx
+            .insert(this.schema.pokemonSpeciesDexNumbers)
+            .values(dexNumbers.map((entry) => ({ speciesId: species.getSpeciesId(), ...entry })));
+        }
+      });
+    } catch (error) {
+      throw new PokemonSpeciesRepositoryError(`Failed to save species: ${error}`);
+    }
+  }
+
+  async saveLearnset(learnset: Learnset): Promise<void> {
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .delete(this.schema.pokemonLearnsets)
+          .where(eq(this.schema.pokemonLearnsets.formId, learnset.formId));
+
+        const entries = learnset.getEntries();
+        if (entries.length === 0) {
+          return;
+        }
+
+        await tx
+          .insert(this.schema.pokemonLearnsets)
+          .values(
+            entries.map((entry) => ({
+              formId: learnset.formId,
+              moveName: entry.moveName,
+              learnMethod: entry.method,
+              level: entry.level ?? null,
+            }))
+          )
+          .onConflictDoNothing();
+      });
+    } catch (error) {
+      throw new PokemonSpeciesRepositoryError(`Failed to save learnset: ${error}`);
+    }
+  }
+
+  private async toEntities(rows: SpeciesRow[]): Promise<PokemonSpecies[]> {
+    const dexNumbers = await this.findDexNumbers(rows.map((row) => row.speciesId));
+    return rows.map((row) => this.toEntity(row, dexNumbers.get(row.speciesId) ?? []));
+  }
+
+  private async findDexNumbers(speciesIds: number[]): Promise<Map<number, RegionalDexNumber[]>> {
+    const bySpecies = new Map<number, RegionalDexNumber[]>();
+    if (speciesIds.length === 0) {
+      return bySpecies;
+    }
+
+    const rows = await this.db
+      .select()
+      .from(this.schema.pokemonSpeciesDexNumbers)
+      .where(inArray(this.schema.pokemonSpeciesDexNumbers.speciesId, speciesIds));
+
+    for (const row of rows) {
+      const dexNumbers = bySpecies.get(row.speciesId) ?? [];
+      dexNumbers.push({ dex: RegionalDexSchema.parse(row.dex), number: row.number });
+      bySpecies.set(row.speciesId, dexNumbers);
+    }
+    return bySpecies;
+  }
+
+  private toEntity(row: SpeciesRow, dexNumbers: RegionalDexNumber[]): PokemonSpecies {
+    const eggGroups = [row.eggGroup1, row.eggGroup2]
+      .filter((group): group is string => group !== null)
+      .map((group) => EggGroupSchema.parse(group));
+
+    return PokemonSpecies.fromRepository(
+      SpeciesId.fromRepository(row.speciesId),
+      row.name,
+      row.nameJp,
+      eggGroups,
+      GenderRatio.fromFemaleEighths(row.genderRate),
+      row.captureRate,
+      SpeciesCategorySchema.parse(row.category),
+      row.evolvesFromSpeciesId === null
+        ? undefined
+        : SpeciesId.fromRepository(row.evolvesFromSpeciesId),
+      dexNumbers
+    );
+  }
+}
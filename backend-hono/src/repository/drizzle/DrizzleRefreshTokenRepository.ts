@@ -0,0 +1,149 @@
+import { and, desc, eq, gt, lt, ne, SQL } from 'drizzle-orm';
+import { RefreshToken } from '../../domain/entity/RefreshToken';
+import {
+  RefreshTokenRepository,
+  RefreshTokenRepositoryError,
+} from '../interface/RefreshTokenRepository';
+import { Db, Schema } from './types';
+
+type RefreshTokenRow = Schema['refreshTokens']['$inferSelect'];
+
+export class DrizzleRefreshTokenRepository implements RefreshTokenRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async save(token: RefreshToken): Promise<void> {
+    try {
+      await this.db.insert(this.schema.refreshTokens).values({
+        tokenId: token.getTokenId(),
+        userId: token.getUserId(),
+        sessionId: token.getSessionId(),
+        tokenHash: token.getTokenHash(),
+        userAgent: token.getClient().userAgent ?? null,
+        ip: token.getClient().ip ?? null,
+        expiresAt: token.getExpiresAt(),
+        lastUsedAt: token.getLastUsedAt(),
+        revoked: token.isRevoked(),
+      });
+    } catch (error) {
+      throw new RefreshTokenRepositoryError(`Failed to save refresh token: ${error}`);
+    }
+  }
+
+  async findByHash(tokenHash: string): Promise<RefreshToken | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.refreshTokens)
+        .where(eq(this.schema.refreshTokens.tokenHash, tokenHash))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      return this.toEntity(result[0]);
+    } catch (error) {
+      throw new RefreshTokenRepositoryError(`Failed to find refresh token: ${error}`);
+    }
+  }
+
+  async findActiveByUser(userId: string): Promise<RefreshToken[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.refreshTokens)
+        .where(and(...this.activeConditions(userId)))
+        .orderBy(desc(this.schema.refreshTokens.lastUsedAt));
+
+      return rows.map((row) => this.toEntity(row));
+    } catch (error) {
+      throw new RefreshTokenRepositoryError(`Failed to find sessions: ${error}`);
+    }
+  }
+
+  async updateLastUsed(tokenId: string, lastUsedAt: Date): Promise<void> {
+    try {
+      await this.db
+        .update(this.schema.refreshTokens)
+        .set({ lastUsedAt })
+        .where(eq(this.schema.refreshTokens.tokenId, tokenId));
+    } catch (error) {
+      throw new RefreshTokenRepositoryError(`Failed to update session last use: ${error}`);
+    }
+  }
+
+  async revoke(tokenId: string): Promise<void> {
+    try {
+      await this.db
+        .update(this.schema.refreshTokens)
+        .set({ revoked: true })
+        .where(eq(this.schema.refreshTokens.tokenId, tokenId));
+    } catch (error) {
+      throw new RefreshTokenRepositoryError(`Failed to revoke refresh token: ${error}`);
+    }
+  }
+
+  async revokeSession(userId: string, sessionId: string): Promise<boolean> {
+    try {
+      const revoked = await this.db
+        .update(this.schema.refreshTokens)
+        .set({ revoked: true })
+        .where(
+          and(...this.activeConditions(userId), eq(this.schema.refreshTokens.sessionId, sessionId))
+        )
+        .returning({ tokenId: this.schema.refreshTokens.tokenId });
+      return revoked.length > 0;
+    } catch (error) {
+      throw new RefreshTokenRepositoryError(`Failed to revoke session: ${error}`);
+    }
+  }
+
+  async revokeOtherSessions(userId: string, keepSessionId?: string): Promise<number> {
+    try {
+      const conditions = this.activeConditions(userId);
+      if (keepSessionId) {
+        conditions.push(ne(this.schema.refreshTokens.sessionId, keepSessionId));
+      }
+      const revoked = await this.db
+        .update(this.schema.refreshTokens)
+        .set({ revoked: true })
+        .where(and(...conditions))
+        .returning({ tokenId: this.schema.refreshTokens.tokenId });
+      return revoked.length;
+    } catch (error) {
+      throw new RefreshTokenRepositoryError(`Failed to revoke sessions: ${error}`);
+    }
+  }
+
+  async cleanupExpired(): Promise<void> {
+    try {
+      await this.db
+        .delete(this.schema.refreshTokens)
+        .where(lt(this.schema.refreshTokens.expiresAt, new Date()));
+    } catch (error) {
+      throw new RefreshTokenRepositoryError(`Failed to cleanup expired tokens: ${error}`);
+    }
+  }
+
+  private activeConditions(userId: string): SQL[] {
+    return [
+      eq(this.schema.refreshTokens.userId, userId),
+      eq(this.schema.refreshTokens.revoked, false),
+      gt(this.schema.refreshTokens.expiresAt, new Date()),
+    ];
+  }
+
+  private toEntity(row: RefreshTokenRow): RefreshToken {
+    return RefreshToken.fromRepository(
+      row.tokenId,
+      row.userId,
+      row.sessionId,
+      row.tokenHash,
+      { userAgent: row.userAgent ?? undefined, ip: row.ip ?? undefined },
+      row.expiresAt,
+      row.createdAt,
+      row.lastUsedAt,
+      row.revoked
+    );
+  }
+}
@@ -0,0 +1,124 @@
+import { desc, eq, inArray } from 'drizzle-orm';
+import { RunTracker } from '../../domain/entity/RunTracker';
+import { GameVersionSchema } from '../../domain/valueobject/GameVersion';
+import { SpeciesId } from '../../domain/valueobject/PokemonId';
+import { RunTrackerRepository, RunTrackerRepositoryError } from '../interface/RunTrackerRepository';
+import { Db, Schema } from './types';
+
+type RunRow = Schema['challengeRuns']['$inferSelect'];
+
+export class DrizzleRunTrackerRepository implements RunTrackerRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(runId: string): Promise<RunTracker | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.challengeRuns)
+        .where(eq(this.schema.challengeRuns.runId, runId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [run] = await this.toEntities(result);
+      return run;
+    } catch (error) {
+      throw new RunTrackerRepositoryError(`Failed to find run by ID: ${error}`);
+    }
+  }
+
+  async findByOwner(ownerId: string): Promise<RunTracker[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.challengeRuns)
+        .where(eq(this.schema.challengeRuns.ownerId, ownerId))
+        .orderBy(desc(this.schema.challengeRuns.createdAt));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new RunTrackerRepositoryError(`Failed to find runs by owner: ${error}`);
+    }
+  }
+
+  async save(run: RunTracker): Promise<void> {
+    const runId = run.getRunId();
+    const values = {
+      ownerId: run.getOwnerId(),
+      name: run.getName(),
+      gameVersion: run.getGameVersion(),
+    };
+
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .insert(this.schema.challengeRuns)
+          .values({ runId, createdAt: run.getCreatedAt(), ...values })
+          .onConflictDoUpdate({ target: this.schema.challengeRuns.runId, set: values });
+
+        await tx
+          .delete(this.schema.challengeRunSpecies)
+          .where(eq(this.schema.challengeRunSpecies.runId, runId));
+
+        const used = run.getUsedSpeciesIds();
+        if (used.length > 0) {
+          await tx
+            .insert(this.schema.challengeRunSpecies)
+            .values(used.map((speciesId) => ({ runId, speciesId })));
+        }
+      });
+    } catch (error) {
+      throw new RunTrackerRepositoryError(`Failed to save run: ${error}`);
+    }
+  }
+
+  async delete(runId: string): Promise<void> {
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .delete(this.schema.challengeRunSpecies)
+          .where(eq(this.schema.challengeRunSpecies.runId, runId));
+        await tx
+          .delete(this.schema.challengeRuns)
+          .where(eq(this.schema.challengeRuns.runId, runId));
+      });
+    } catch (error) {
+      throw new RunTrackerRepositoryError(`Failed to delete run: ${error}`);
+    }
+  }
+
+  private async toEntities(rows: RunRow[]): Promise<RunTracker[]> {
+    const used = await this.findUsedSpecies(rows.map((row) => row.runId));
+    return rows.map((row) =>
+      RunTracker.fromRepository(
+        row.runId,
+        row.ownerId,
+        row.name,
+        GameVersionSchema.parse(row.gameVersion),
+        used.get(row.runId) ?? [],
+        row.createdAt
+      )
+    );
+  }
+
+  private async findUsedSpecies(runIds: string[]): Promise<Map<string, SpeciesId[]>> {
+    const byRun = new Map<string, SpeciesId[]>();
+    if (runIds.length === 0) {
+      return byRun;
+    }
+
+    const rows = await this.db
+      .select()
+      .from(this.schema.challengeRunSpecies)
+      .where(inArray(this.schema.challengeRunSpecies.runId, runIds));
+
+    for (const row of rows) {
+      const speciesIds = byRun.get(row.runId) ?? [];
+      speciesIds.push(SpeciesId.fromRepository(row.speciesId));
+      byRun.set(row.runId, speciesIds);
+    }
+    return byRun;
+  }
+}
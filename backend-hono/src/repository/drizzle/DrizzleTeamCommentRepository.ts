@@ -0,0 +1,128 @@
+import { and, asc, count, eq, inArray, isNull } from 'drizzle-orm';
+import { TeamComment } from '../../domain/entity/TeamComment';
+import {
+  AuthoredComment,
+  TeamCommentRepository,
+  TeamCommentRepositoryError,
+} from '../interface/TeamCommentRepository';
+import { Db, Schema } from './types';
+
+type TeamCommentRow = Schema['teamComments']['$inferSelect'];
+
+export class DrizzleTeamCommentRepository implements TeamCommentRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(commentId: string): Promise<AuthoredComment | null> {
+    try {
+      const [row] = await this.selectAuthored()
+        .where(eq(this.schema.teamComments.commentId, commentId))
+        .limit(1);
+      return row ? this.toAuthored(row) : null;
+    } catch (error) {
+      throw new TeamCommentRepositoryError(`Failed to find team comment: ${error}`);
+    }
+  }
+
+  async findThreadsByTeam(
+    teamId: string,
+    limit: number,
+    offset: number
+  ): Promise<AuthoredComment[]> {
+    try {
+      const rows = await this.selectAuthored()
+        .where(
+          and(
+            eq(this.schema.teamComments.teamId, teamId),
+            isNull(this.schema.teamComments.parentId)
+          )
+        )
+        .orderBy(asc(this.schema.teamComments.createdAt), asc(this.schema.teamComments.commentId))
+        .limit(limit)
+        .offset(offset);
+      return rows.map((row) => this.toAuthored(row));
+    } catch (error) {
+      throw new TeamCommentRepositoryError(`Failed to find team comments: ${error}`);
+    }
+  }
+
+  async countThreadsByTeam(teamId: string): Promise<number> {
+    try {
+      const [row] = await this.db
+        .select({ threads: count() })
+        .from(this.schema.teamComments)
+        .where(
+          and(
+            eq(this.schema.teamComments.teamId, teamId),
+            isNull(this.schema.teamComments.parentId)
+          )
+        );
+      return row?.threads ?? 0;
+    } catch (error) {
+      throw new TeamCommentRepositoryError(`Failed to count team comments: ${error}`);
+    }
+  }
+
+  async findReplies(parentIds: string[]): Promise<AuthoredComment[]> {
+    if (parentIds.length === 0) {
+      return [];
+    }
+
+    try {
+      const rows = await this.selectAuthored()
+        .where(inArray(this.schema.teamComments.parentId, parentIds))
+        .orderBy(asc(this.schema.teamComments.createdAt), asc(this.schema.teamComments.commentId));
+      return rows.map((row) => this.toAuthored(row));
+    } catch (error) {
+      throw new TeamCommentRepositoryError(`Failed to find comment replies: ${error}`);
+    }
+  }
+
+  async save(comment: TeamComment): Promise<void> {
+    const row = {
+      body: comment.getBody(),
+      updatedAt: comment.getUpdatedAt(),
+      deletedAt: comment.getDeletedAt(),
+    };
+
+    try {
+      await this.db
+        .insert(this.schema.teamComments)
+        .values({
+          commentId: comment.getCommentId(),
+          teamId: comment.getTeamId(),
+          authorId: comment.getAuthorId(),
+          parentId: comment.getParentId(),
+          createdAt: comment.getCreatedAt(),
+          ...row,
+        })
+        .onConflictDoUpdate({ target: this.schema.teamComments.commentId, set: row });
+    } catch (error) {
+      throw new TeamCommentRepositoryError(`Failed to save team comment: ${error}`);
+    }
+  }
+
+  private selectAuthored() {
+    return this.db
+      .select({ comment: this.schema.teamComments, authorName: this.schema.users.username })
+      .from(this.schema.teamComments)
+      .innerJoin(this.schema.users, eq(this.schema.users.userId, this.schema.teamComments.authorId))
+      .$dynamic();
+  }
+
+  private toAuthored(row: { comment: TeamCommentRow; authorName: string }): AuthoredComment {
+    return { comment: this.toEntity(row.comment), authorName: row.authorName };
+  }
+
+  private toEntity(row: TeamCommentRow): TeamComment {
+    return TeamComment.fromRepository(
+      row.commentId,
+      row.teamId,
+      row.authorId,
+      row.parentId,
+      row.body,
+      row.createdAt,
+      row.updatedAt,
+      row.deletedAt
+    );
+  }
+}
@@ -0,0 +1,76 @@
+import { and, asc, inArray, isNull, lt, or } from 'drizzle-orm';
+import { TeamEventTypeSchema } from '../../domain/event/TeamEvent';
+import {
+  OutboxEntry,
+  TeamEventOutboxRepository,
+  TeamEventOutboxRepositoryError,
+} from '../interface/TeamEventOutboxRepository';
+import { Db, Schema } from './types';
+
+export class DrizzleTeamEventOutboxRepository implements TeamEventOutboxRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async claim(limit: number, leaseMs: number): Promise<OutboxEntry[]> {
+    const now = new Date();
+    // Checked again by the update, so two relays racing for an entry cannot both get it
+    const claimable = and(
+      isNull(this.schema.teamEventOutbox.publishedAt),
+      or(
+        isNull(this.schema.teamEventOutbox.claimedUntil),
+        lt(this.schema.teamEventOutbox.claimedUntil, now)
+      )
+    );
+
+    try {
+      const pending = this.db
+        .select({ eventId: this.schema.teamEventOutbox.eventId })
+        .from(this.schema.teamEventOutbox)
+        .where(claimable)
+        .orderBy(asc(this.schema.teamEventOutbox.occurredAt))
+        .limit(limit);
+      const rows = await this.db
+        .update(this.schema.teamEventOutbox)
+        .set({ claimedUntil: new Date(now.getTime() + leaseMs) })
+        .where(and(inArray(this.schema.teamEventOutbox.eventId, pending), claimable))
+        .returning();
+
+      return rows
+        .map((row) => ({
+          eventId: row.eventId,
+          type: TeamEventTypeSchema.parse(row.type),
+          teamId: row.teamId,
+          occurredAt: row.occurredAt,
+        }))
+        .sort((a, b) => a.occurredAt.getTime() - b.occurredAt.getTime());
+    } catch (error) {
+      throw new TeamEventOutboxRepositoryError(`Failed to claim outbox entries: ${error}`);
+    }
+  }
+
+  async markPublished(eventIds: string[]): Promise<void> {
+    if (eventIds.length === 0) {
+      return;
+    }
+
+    try {
+      await this.db
+        .update(this.schema.teamEventOutbox)
+        .set({ publishedAt: new Date(), claimedUntil: null })
+        .where(inArray(this.schema.teamEventOutbox.eventId, eventIds));
+    } catch (error) {
+      throw new TeamEventOutboxRepositoryError(`Failed to mark outbox entries published: ${error}`);
+    }
+  }
+
+  async deletePublishedBefore(cutoff: Date): Promise<number> {
+    try {
+      const rows = await this.db
+        .delete(this.schema.teamEventOutbox)
+        .where(lt(this.schema.teamEventOutbox.publishedAt, cutoff))
+        .returning({ eventId: this.schema.teamEventOutbox.eventId });
+      return rows.length;
+    } catch (error) {
+      throw new TeamEventOutboxRepositoryError(`Failed to delete outbox entries: ${error}`);
+    }
+  }
+}
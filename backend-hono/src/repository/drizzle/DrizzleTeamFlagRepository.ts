@@ -0,0 +1,84 @@
+import { count, desc, eq, inArray, max } from 'drizzle-orm';
+import { TeamFlag, TeamFlagReasonSchema } from '../../domain/entity/TeamFlag';
+import {
+  TeamFlagRepository,
+  TeamFlagRepositoryError,
+  TeamFlagSummary,
+} from '../interface/TeamFlagRepository';
+import { Db, Schema } from './types';
+
+export class DrizzleTeamFlagRepository implements TeamFlagRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async save(flag: TeamFlag): Promise<void> {
+    const row = { reason: flag.getReason(), createdAt: flag.getCreatedAt() };
+
+    try {
+      await this.db
+        .insert(this.schema.teamFlags)
+        .values({ teamId: flag.getTeamId(), userId: flag.getUserId(), ...row })
+        .onConflictDoUpdate({
+          target: [this.schema.teamFlags.teamId, this.schema.teamFlags.userId],
+          set: row,
+        });
+    } catch (error) {
+      throw new TeamFlagRepositoryError(`Failed to save team flag: ${error}`);
+    }
+  }
+
+  async findSummaries(limit: number, offset: number): Promise<TeamFlagSummary[]> {
+    try {
+      const flags = count();
+      const teamRows = await this.db
+        .select({
+          teamId: this.schema.teamFlags.teamId,
+          flags,
+          lastFlaggedAt: max(this.schema.teamFlags.createdAt),
+        })
+        .from(this.schema.teamFlags)
+        .groupBy(this.schema.teamFlags.teamId)
+        .orderBy(desc(flags), desc(max(this.schema.teamFlags.createdAt)))
+        .limit(limit)
+        .offset(offset);
+      if (teamRows.length === 0) {
+        return [];
+      }
+
+      const reasonRows = await this.db
+        .select({
+          teamId: this.schema.teamFlags.teamId,
+          reason: this.schema.teamFlags.reason,
+          flags: count(),
+        })
+        .from(this.schema.teamFlags)
+        .where(inArray(this.schema.teamFlags.teamId, teamRows.map((row) => row.teamId)))
+        .groupBy(this.schema.teamFlags.teamId, this.schema.teamFlags.reason);
+
+      return teamRows.map((row) => ({
+        teamId: row.teamId,
+        flags: row.flags,
+        reasons: Object.fromEntries(
+          reasonRows
+            .filter((reasonRow) => reasonRow.teamId === row.teamId)
+            .map((reasonRow) => [TeamFlagReasonSchema.parse(reasonRow.reason), reasonRow.flags])
+        ),
+        // Every group has at least one row
+        lastFlaggedAt: row.lastFlaggedAt ?? new Date(0),
+      }));
+    } catch (error) {
+      throw new TeamFlagRepositoryError(`Failed to find flagged teams: ${error}`);
+    }
+  }
+
+  async deleteByTeam(teamId: string): Promise<number> {
+    try {
+      const deleted = await this.db
+        .delete(this.schema.teamFlags)
+        .where(eq(this.schema.teamFlags.teamId, teamId))
+        .returning({ teamId: this.schema.teamFlags.teamId });
+      return deleted.length;
+    } catch (error) {
+      throw new TeamFlagRepositoryError(`Failed to delete team flags: ${error}`);
+    }
+  }
+}
@@ -0,0 +1,72 @@
+import { and, count, eq, inArray } from 'drizzle-orm';
+import { TeamLike } from '../../domain/entity/TeamLike';
+import { TeamLikeRepository, TeamLikeRepositoryError } from '../interface/TeamLikeRepository';
+import { Db, Schema } from './types';
+
+export class DrizzleTeamLikeRepository implements TeamLikeRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async save(like: TeamLike): Promise<boolean> {
+    try {
+      const inserted = await this.db
+        .insert(this.schema.teamLikes)
+        .values({
+          teamId: like.getTeamId(),
+          userId: like.getUserId(),
+          createdAt: like.getCreatedAt(),
+        })
+        .onConflictDoNothing()
+        .returning({ teamId: this.schema.teamLikes.teamId });
+      return inserted.length > 0;
+    } catch (error) {
+      throw new TeamLikeRepositoryError(`Failed to save team like: ${error}`);
+    }
+  }
+
+  async delete(teamId: string, userId: string): Promise<boolean> {
+    try {
+      const deleted = await this.db
+        .delete(this.schema.teamLikes)
+        .where(
+          and(eq(this.schema.teamLikes.teamId, teamId), eq(this.schema.teamLikes.userId, userId))
+        )
+        .returning({ teamId: this.schema.teamLikes.teamId });
+      return deleted.length > 0;
+    } catch (error) {
+      throw new TeamLikeRepositoryError(`Failed to delete team like: ${error}`);
+    }
+  }
+
+  async countByTeam(teamId: string): Promise<number> {
+    try {
+      const [{ likes }] = await this.db
+        .select({ likes: count() })
+        .from(this.schema.teamLikes)
+        .where(eq(this.schema.teamLikes.teamId, teamId));
+      return likes;
+    } catch (error) {
+      throw new TeamLikeRepositoryError(`Failed to count team likes: ${error}`);
+    }
+  }
+
+  async findLikedTeamIds(userId: string, teamIds: string[]): Promise<string[]> {
+    if (teamIds.length === 0) {
+      return [];
+    }
+
+    try {
+      const rows = await this.db
+        .select({ teamId: this.schema.teamLikes.teamId })
+        .from(this.schema.teamLikes)
+        .where(
+          and(
+            eq(this.schema.teamLikes.userId, userId),
+            inArray(this.schema.teamLikes.teamId, teamIds)
+          )
+        );
+      return rows.map((row) => row.teamId);
+    } catch (error) {
+      throw new TeamLikeRepositoryError(`Failed to find liked teams: ${error}`);
+    }
+  }
+}
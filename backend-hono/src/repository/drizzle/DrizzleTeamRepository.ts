@@ -0,0 +1,460 @@
+import {
+  and,
+  asc,
+  count,
+  desc,
+  eq,
+  gt,
+  gte,
+  inArray,
+  isNotNull,
+  isNull,
+  lt,
+  notInArray,
+  or,
+  SQL,
+} from 'drizzle-orm';
+import { PokemonForm, Team } from '../../domain/entity/Team';
+import { TeamEvent } from '../../domain/event/TeamEvent';
+import { FormatIdSchema } from '../../domain/valueobject/Format';
+import { TeamTag } from '../../domain/valueobject/TeamTag';
+import {
+  ConflictError,
+  GalleryQuery,
+  GalleryTeam,
+  TeamFilter,
+  TeamFolderCount,
+  TeamRepository,
+  TeamRepositoryError,
+  TeamTagCount,
+} from '../interface/TeamRepository';
+import { Db, Schema, Transaction } from './types';
+
+type TeamRow = Schema['teams']['$inferSelect'];
+
+export class DrizzleTeamRepository implements TeamRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(teamId: string): Promise<Team | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.teams)
+        .where(eq(this.schema.teams.teamId, teamId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [team] = await this.toEntities(result);
+      return team;
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to find team by ID: ${error}`);
+    }
+  }
+
+  async findByIds(teamIds: string[]): Promise<Team[]> {
+    if (teamIds.length === 0) {
+      return [];
+    }
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.teams)
+        .where(inArray(this.schema.teams.teamId, teamIds));
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to find teams by ID: ${error}`);
+    }
+  }
+
+  async findPageByOwner(
+    ownerId: string,
+    limit: number,
+    after?: string,
+    filter: TeamFilter = {}
+  ): Promise<Team[]> {
+    const conditions: SQL[] = [
+      eq(this.schema.teams.ownerId, ownerId),
+      isNull(this.schema.teams.deletedAt),
+    ];
+    if (after !== undefined) {
+      conditions.push(gt(this.schema.teams.teamId, after));
+    }
+    if (filter.tag !== undefined) {
+      const tagged = this.db
+        .select({ teamId: this.schema.teamTags.teamId })
+        .from(this.schema.teamTags)
+        .where(eq(this.schema.teamTags.tag, filter.tag));
+      conditions.push(inArray(this.schema.teams.teamId, tagged));
+    }
+    if (filter.folder !== undefined) {
+      conditions.push(eq(this.schema.teams.folder, filter.folder));
+    }
+    if (filter.speciesId !== undefined) {
+      conditions.push(this.containsSpecies(filter.speciesId));
+    }
+
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.teams)
+        .where(and(...conditions))
+        .orderBy(asc(this.schema.teams.teamId))
+        .limit(limit);
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to find teams by owner: ${error}`);
+    }
+  }
+
+  async findGalleryPage(query: GalleryQuery): Promise<GalleryTeam[]> {
+    const hidden = this.db
+      .select({ teamId: this.schema.teamFlags.teamId })
+      .from(this.schema.teamFlags)
+      .groupBy(this.schema.teamFlags.teamId)
+      .having(gte(count(), query.hideAtFlags));
+    const conditions: SQL[] = [
+      isNotNull(this.schema.teams.sharedAt),
+      isNull(this.schema.teams.deletedAt),
+      notInArray(this.schema.teams.teamId, hidden),
+    ];
+    if (query.format !== undefined) {
+      conditions.push(eq(this.schema.teams.sharedFormat, query.format));
+    }
+    if (query.speciesId !== undefined) {
+      conditions.push(this.containsSpecies(query.speciesId));
+    }
+
+    const likes = count(this.schema.teamLikes.userId);
+    const order =
+      query.sort === 'likes'
+        ? [desc(likes), desc(this.schema.teams.sharedAt)]
+        : [desc(this.schema.teams.sharedAt)];
+
+    // Teams after the position in (likes desc,) shared_at desc, team_id asc order
+    let afterPosition: SQL | undefined;
+    if (query.after) {
+      const { teamId, sharedAt, likes: positionLikes } = query.after;
+      afterPosition = or(
+        lt(this.schema.teams.sharedAt, sharedAt),
+        and(eq(this.schema.teams.sharedAt, sharedAt), gt(this.schema.teams.teamId, teamId))
+      );
+      if (query.sort === 'likes') {
+        afterPosition = or(lt(likes, positionLikes), and(eq(likes, positionLikes), afterPosition));
+      }
+    }
+
+    try {
+      const rows = await this.db
+        .select({ team: this.schema.teams, likes })
+        .from(this.schema.teams)
+        .leftJoin(this.schema.teamLikes, eq(this.schema.teamLikes.teamId, this.schema.teams.teamId))
+        .where(and(...conditions))
+        .groupBy(this.schema.teams.teamId)
+        .having(afterPosition)
+        .orderBy(...order, asc(this.schema.teams.teamId))
+        .limit(query.limit);
+
+      const entities = await this.toEntities(rows.map((row) => row.team));
+      return entities.map((team, index) => ({ team, likes: rows[index].likes }));
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to find gallery teams: ${error}`);
+    }
+  }
+
+  async countTagsByOwner(ownerId: string): Promise<TeamTagCount[]> {
+    try {
+      return await this.db
+        .select({ tag: this.schema.teamTags.tag, teams: count() })
+        .from(this.schema.teamTags)
+        .innerJoin(this.schema.teams, eq(this.schema.teams.teamId, this.schema.teamTags.teamId))
+        .where(and(eq(this.schema.teams.ownerId, ownerId), isNull(this.schema.teams.deletedAt)))
+        .groupBy(this.schema.teamTags.tag)
+        .orderBy(asc(this.schema.teamTags.tag));
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to count team tags: ${error}`);
+    }
+  }
+
+  async countFoldersByOwner(ownerId: string): Promise<TeamFolderCount[]> {
+    try {
+      const rows = await this.db
+        .select({ folder: this.schema.teams.folder, teams: count() })
+        .from(this.schema.teams)
+        .where(
+          and(
+            eq(this.schema.teams.ownerId, ownerId),
+            isNull(this.schema.teams.deletedAt),
+            isNotNull(this.schema.teams.folder)
+          )
+        )
+        .groupBy(this.schema.teams.folder)
+        .orderBy(asc(this.schema.teams.folder));
+
+      return rows.flatMap((row) => (row.folder === null ? [] : [{ ...row, folder: row.folder }]));
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to count team folders: ${error}`);
+    }
+  }
+
+  async findDeletedByOwner(ownerId: string): Promise<Team[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.teams)
+        .where(and(eq(this.schema.teams.ownerId, ownerId), isNotNull(this.schema.teams.deletedAt)))
+        .orderBy(desc(this.schema.teams.deletedAt));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to find deleted teams: ${error}`);
+    }
+  }
+
+  async save(team: Team, events: TeamEvent[] = []): Promise<void> {
+    const teamId = team.getTeamId();
+    const version = team.getVersion();
+    const values = {
+      ownerId: team.getOwnerId(),
+      teamName: team.getTeamName(),
+      updatedAt: new Date(),
+      deletedAt: team.getDeletedAt(),
+      version: version + 1,
+      folder: team.getFolder(),
+      sharedAt: team.getSharedAt(),
+      sharedFormat: team.getSharedFormat(),
+      notes: team.getNotes(),
+    };
+
+    let saved: boolean;
+    try {
+      saved = await this.db.transaction(async (tx) => {
+        // A new team only goes in if no team has the ID yet; an existing one only if nobody
+        // saved it since it was read
+        const written =
+          version === 0
+            ? await tx
+                .insert(this.schema.teams)
+                .values({ teamId, ...values })
+                .onConflictDoNothing()
+                .returning({ teamId: this.schema.teams.teamId })
+            : await tx
+                .update(this.schema.teams)
+                .set(values)
+                .where(
+                  and(eq(this.schema.teams.teamId, teamId), eq(this.schema.teams.version, version))
+                )
+                .returning({ teamId: this.schema.teams.teamId });
+        if (written.length === 0) {
+          return false;
+        }
+
+        await tx.delete(this.schema.teamPokemon).where(eq(this.schema.teamPokemon.teamId, teamId));
+
+        const members = team
+          .getPokemon()
+          .flatMap((pokemon, slot) => (pokemon ? [{ teamId, slot, ...pokemon }] : []));
+        if (members.length > 0) {
+          await tx.insert(this.schema.teamPokemon).values(members);
+        }
+        await this.writeTags(tx, team);
+        await this.writeOutbox(tx, events);
+        return true;
+      });
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to save team: ${error}`);
+    }
+
+    if (!saved) {
+      throw new ConflictError(teamId, await this.findById(teamId));
+    }
+    team.markSaved();
+  }
+
+  async saveOrganization(teamList: Team[], events: TeamEvent[] = []): Promise<void> {
+    let conflict: ConflictError | null = null;
+    try {
+      await this.db.transaction(async (tx) => {
+        for (const team of teamList) {
+          const version = team.getVersion();
+          const written = await tx
+            .update(this.schema.teams)
+            .set({ folder: team.getFolder(), updatedAt: new Date(), version: version + 1 })
+            .where(
+              and(
+                eq(this.schema.teams.teamId, team.getTeamId()),
+                eq(this.schema.teams.version, version)
+              )
+            )
+            .returning({ teamId: this.schema.teams.teamId });
+          if (written.length === 0) {
+            // Rolls back the teams written before this one
+            throw new ConflictError(team.getTeamId(), null);
+          }
+          await this.writeTags(tx, team);
+        }
+        await this.writeOutbox(tx, events);
+      });
+    } catch (error) {
+      if (!(error instanceof ConflictError)) {
+        throw new TeamRepositoryError(`Failed to save team organization: ${error}`);
+      }
+      conflict = error;
+    }
+
+    if (conflict) {
+      throw new ConflictError(conflict.teamId, await this.findById(conflict.teamId));
+    }
+    teamList.forEach((team) => team.markSaved());
+  }
+
+  async purgeDeletedBefore(cutoff: Date): Promise<number> {
+    try {
+      return await this.db.transaction(async (tx) => {
+        const expired = tx
+          .select({ teamId: this.schema.teams.teamId })
+          .from(this.schema.teams)
+          .where(lt(this.schema.teams.deletedAt, cutoff));
+        await tx
+          .delete(this.schema.teamPokemon)
+          .where(inArray(this.schema.teamPokemon.teamId, expired));
+        await tx.delete(this.schema.teamTags).where(inArray(this.schema.teamTags.teamId, expired));
+        await tx
+          .delete(this.schema.teamLikes)
+          .where(inArray(this.schema.teamLikes.teamId, expired));
+        await tx
+          .delete(this.schema.teamComments)
+          .where(inArray(this.schema.teamComments.teamId, expired));
+        await tx
+          .delete(this.schema.teamFlags)
+          .where(inArray(this.schema.teamFlags.teamId, expired));
+
+        const records = tx
+          .select({ recordId: this.schema.battleRecords.recordId })
+          .from(this.schema.battleRecords)
+          .where(inArray(this.schema.battleRecords.teamId, expired));
+        await tx
+          .delete(this.schema.battleRecordOpponents)
+          .where(inArray(this.schema.battleRecordOpponents.recordId, records));
+        await tx
+          .delete(this.schema.battleRecords)
+          .where(inArray(this.schema.battleRecords.teamId, expired));
+
+        const purged = await tx
+          .delete(this.schema.teams)
+          .where(lt(this.schema.teams.deletedAt, cutoff))
+          .returning({ teamId: this.schema.teams.teamId });
+        return purged.length;
+      });
+    } catch (error) {
+      throw new TeamRepositoryError(`Failed to purge deleted teams: ${error}`);
+    }
+  }
+
+  // Teams with a member of any form of the species; uses the form index on team_pokemon
+  private containsSpecies(speciesId: number): SQL {
+    const members = this.db
+      .select({ teamId: this.schema.teamPokemon.teamId })
+      .from(this.schema.teamPokemon)
+      .innerJoin(
+        this.schema.pokemonForms,
+        eq(this.schema.pokemonForms.formId, this.schema.teamPokemon.formId)
+      )
+      .where(eq(this.schema.pokemonForms.speciesId, speciesId));
+    return inArray(this.schema.teams.teamId, members);
+  }
+
+  // An event ID already in the outbox is a retried save; the first copy stands
+  private async writeOutbox(tx: Transaction, events: TeamEvent[]): Promise<void> {
+    if (events.length === 0) {
+      return;
+    }
+    await tx
+      .insert(this.schema.teamEventOutbox)
+      .values(
+        events.map((event) => ({
+          eventId: event.eventId,
+          type: event.type,
+          teamId: event.team.getTeamId(),
+          occurredAt: event.occurredAt,
+        }))
+      )
+      .onConflictDoNothing();
+  }
+
+  private async writeTags(tx: Transaction, team: Team): Promise<void> {
+    const teamId = team.getTeamId();
+    await tx.delete(this.schema.teamTags).where(eq(this.schema.teamTags.teamId, teamId));
+
+    const tags = team.getTags().map((tag) => ({ teamId, tag }));
+    if (tags.length > 0) {
+      await tx.insert(this.schema.teamTags).values(tags);
+    }
+  }
+
+  private async toEntities(rows: TeamRow[]): Promise<Team[]> {
+    const teamIds = rows.map((row) => row.teamId);
+    const [members, tags] = await Promise.all([this.findMembers(teamIds), this.findTags(teamIds)]);
+    return rows.map((row) =>
+      Team.fromRepository(
+        row.teamId,
+        row.ownerId,
+        row.teamName,
+        members.get(row.teamId) ?? new Array(6).fill(null),
+        row.deletedAt,
+        row.version,
+        TeamTag.createList(tags.get(row.teamId) ?? []),
+        row.folder,
+        row.sharedAt
+          ? { sharedAt: row.sharedAt, format: FormatIdSchema.nullable().parse(row.sharedFormat) }
+          : null,
+        row.notes
+      )
+    );
+  }
+
+  private async findTags(teamIds: string[]): Promise<Map<string, string[]>> {
+    const byTeam = new Map<string, string[]>();
+    if (teamIds.length === 0) {
+      return byTeam;
+    }
+
+    const rows = await this.db
+      .select()
+      .from(this.schema.teamTags)
+      .where(inArray(this.schema.teamTags.teamId, teamIds))
+      .orderBy(asc(this.schema.teamTags.tag));
+
+    for (const row of rows) {
+      byTeam.set(row.teamId, [...(byTeam.get(row.teamId) ?? []), row.tag]);
+    }
+    return byTeam;
+  }
+
+  private async findMembers(teamIds: string[]): Promise<Map<string, (PokemonForm | null)[]>> {
+    const byTeam = new Map<string, (PokemonForm | null)[]>();
+    if (teamIds.length === 0) {
+      return byTeam;
+    }
+
+    const rows = await this.db
+      .select()
+      .from(this.schema.teamPokemon)
+      .where(inArray(this.schema.teamPokemon.teamId, teamIds));
+
+    for (const row of rows) {
+      const slots = byTeam.get(row.teamId) ?? new Array(6).fill(null);
+      slots[row.slot] = {
+        formId: row.formId,
+        terastalType: row.terastalType,
+        notes: row.notes ?? undefined,
+      };
+      byTeam.set(row.teamId, slots);
+    }
+    return byTeam;
+  }
+}
@@ -0,0 +1,241 @@
+import { and, asc, count, desc, eq, inArray } from 'drizzle-orm';
+import {
+  Tournament,
+  TournamentEntry,
+  TournamentStatus,
+  TournamentStatusSchema,
+} from '../../domain/entity/Tournament';
+import { FormatIdSchema } from '../../domain/valueobject/Format';
+import { FormId } from '../../domain/valueobject/PokemonId';
+import { TournamentRepository, TournamentRepositoryError } from '../interface/TournamentRepository';
+import { Db, Schema } from './types';
+
+type TournamentRow = Schema['tournaments']['$inferSelect'];
+
+export class DrizzleTournamentRepository implements TournamentRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(tournamentId: string): Promise<Tournament | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.tournaments)
+        .where(eq(this.schema.tournaments.tournamentId, tournamentId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [tournament] = await this.toEntities(result);
+      return tournament;
+    } catch (error) {
+      throw new TournamentRepositoryError(`Failed to find tournament by ID: ${error}`);
+    }
+  }
+
+  async findPage(
+    status: TournamentStatus | null,
+    limit: number,
+    offset: number
+  ): Promise<Tournament[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.tournaments)
+        .where(status ? eq(this.schema.tournaments.status, status) : undefined)
+        .orderBy(desc(this.schema.tournaments.createdAt))
+        .limit(limit)
+        .offset(offset);
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new TournamentRepositoryError(`Failed to find tournaments: ${error}`);
+    }
+  }
+
+  async count(status: TournamentStatus | null): Promise<number> {
+    try {
+      const [row] = await this.db
+        .select({ tournaments: count() })
+        .from(this.schema.tournaments)
+        .where(status ? eq(this.schema.tournaments.status, status) : undefined);
+      return row?.tournaments ?? 0;
+    } catch (error) {
+      throw new TournamentRepositoryError(`Failed to count tournaments: ${error}`);
+    }
+  }
+
+  async save(tournament: Tournament): Promise<boolean> {
+    const tournamentId = tournament.getTournamentId();
+    const version = tournament.getVersion();
+    const values = {
+      organizerId: tournament.getOrganizerId(),
+      name: tournament.getName(),
+      format: tournament.getFormat(),
+      status: tournament.getStatus(),
+      maxEntries: tournament.getMaxEntries(),
+      version: version + 1,
+      createdAt: tournament.getCreatedAt(),
+      startedAt: tournament.getStartedAt(),
+    };
+
+    let saved: boolean;
+    try {
+      saved = await this.db.transaction(async (tx) => {
+        const written =
+          version === 0
+            ? await tx
+                .insert(this.schema.tournaments)
+                .values({ tournamentId, ...values })
+                .onConflictDoNothing()
+                .returning({ tournamentId: this.schema.tournaments.tournamentId })
+            : await tx
+                .update(this.schema.tournaments)
+                .set(values)
+                .where(
+                  and(
+                    eq(this.schema.tournaments.tournamentId, tournamentId),
+                    eq(this.schema.tournaments.version, version)
+                  )
+                )
+                .returning({ tournamentId: this.schema.tournaments.tournamentId });
+        if (written.length === 0) {
+          return false;
+        }
+
+        await tx
+          .delete(this.schema.tournamentEntryMembers)
+          .where(eq(this.schema.tournamentEntryMembers.tournamentId, tournamentId));
+        await tx
+          .delete(this.schema.tournamentEntries)
+          .where(eq(this.schema.tournamentEntries.tournamentId, tournamentId));
+        await tx
+          .delete(this.schema.tournamentMatches)
+          .where(eq(this.schema.tournamentMatches.tournamentId, tournamentId));
+
+        const entries = tournament.getEntries();
+        if (entries.length > 0) {
+          await tx.insert(this.schema.tournamentEntries).values(
+            entries.map((entry) => ({
+              tournamentId,
+              userId: entry.userId,
+              teamId: entry.teamId,
+              teamName: entry.teamName,
+              teamVersion: entry.teamVersion,
+              seed: entry.seed,
+              registeredAt: entry.registeredAt,
+            }))
+          );
+          const members = entries.flatMap((entry) =>
+            entry.members.map((member) => ({ tournamentId, userId: entry.userId, ...member }))
+          );
+          if (members.length > 0) {
+            await tx.insert(this.schema.tournamentEntryMembers).values(members);
+          }
+        }
+        const matches = tournament.getMatches();
+        if (matches.length > 0) {
+          await tx
+            .insert(this.schema.tournamentMatches)
+            .values(matches.map((match) => ({ tournamentId, ...match })));
+        }
+        return true;
+      });
+    } catch (error) {
+      throw new TournamentRepositoryError(`Failed to save tournament: ${error}`);
+    }
+
+    if (saved) {
+      tournament.markSaved();
+    }
+    return saved;
+  }
+
+  async delete(tournamentId: string): Promise<void> {
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .delete(this.schema.tournamentEntryMembers)
+          .where(eq(this.schema.tournamentEntryMembers.tournamentId, tournamentId));
+        await tx
+          .delete(this.schema.tournamentEntries)
+          .where(eq(this.schema.tournamentEntries.tournamentId, tournamentId));
+        await tx
+          .delete(this.schema.tournamentMatches)
+          .where(eq(this.schema.tournamentMatches.tournamentId, tournamentId));
+        await tx
+          .delete(this.schema.tournaments)
+          .where(eq(this.schema.tournaments.tournamentId, tournamentId));
+      });
+    } catch (error) {
+      throw new TournamentRepositoryError(`Failed to delete tournament: ${error}`);
+    }
+  }
+
+  private async toEntities(rows: TournamentRow[]): Promise<Tournament[]> {
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const tournamentIds = rows.map((row) => row.tournamentId);
+    const entryRows = await this.db
+      .select()
+      .from(this.schema.tournamentEntries)
+      .where(inArray(this.schema.tournamentEntries.tournamentId, tournamentIds));
+    const memberRows = await this.db
+      .select()
+      .from(this.schema.tournamentEntryMembers)
+      .where(inArray(this.schema.tournamentEntryMembers.tournamentId, tournamentIds))
+      .orderBy(asc(this.schema.tournamentEntryMembers.slot));
+    const matchRows = await this.db
+      .select()
+      .from(this.schema.tournamentMatches)
+      .where(inArray(this.schema.tournamentMatches.tournamentId, tournamentIds));
+
+    return rows.map((row) => {
+      const entries: TournamentEntry[] = entryRows
+        .filter((entry) => entry.tournamentId === row.tournamentId)
+        .map((entry) => ({
+          userId: entry.userId,
+          teamId: entry.teamId,
+          teamName: entry.teamName,
+          teamVersion: entry.teamVersion,
+          members: memberRows
+            .filter(
+              (member) => member.tournamentId === row.tournamentId && member.userId === entry.userId
+            )
+            .map((member) => ({
+              slot: member.slot,
+              formId: FormId.fromRepository(member.formId),
+              terastalType: member.terastalType,
+            })),
+          seed: entry.seed,
+          registeredAt: entry.registeredAt,
+        }));
+
+      return Tournament.fromRepository(
+        row.tournamentId,
+        row.organizerId,
+        row.name,
+        FormatIdSchema.parse(row.format),
+        row.maxEntries,
+        TournamentStatusSchema.parse(row.status),
+        entries,
+        matchRows
+          .filter((match) => match.tournamentId === row.tournamentId)
+          .map((match) => ({
+            round: match.round,
+            position: match.position,
+            player1Id: match.player1Id,
+            player2Id: match.player2Id,
+            winnerId: match.winnerId,
+            reportedAt: match.reportedAt,
+          })),
+        row.createdAt,
+        row.startedAt,
+        row.version
+      );
+    });
+  }
+}
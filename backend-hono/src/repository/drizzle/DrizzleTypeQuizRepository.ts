@@ -0,0 +1,66 @@
+import { eq } from 'drizzle-orm';
+import { TypeQuizScore } from '../../domain/entity/TypeQuizScore';
+import { TypeQuizRepository, TypeQuizRepositoryError } from '../interface/TypeQuizRepository';
+import { Db, Schema } from './types';
+
+export class DrizzleTypeQuizRepository implements TypeQuizRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findScore(userId: string): Promise<TypeQuizScore | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.typeQuizScores)
+        .where(eq(this.schema.typeQuizScores.userId, userId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const row = result[0];
+      return TypeQuizScore.fromRepository(
+        row.userId,
+        row.answered,
+        row.correct,
+        row.currentStreak,
+        row.bestStreak
+      );
+    } catch (error) {
+      throw new TypeQuizRepositoryError(`Failed to find quiz score: ${error}`);
+    }
+  }
+
+  async saveAnswer(score: TypeQuizScore, questionId: number, correct: boolean): Promise<boolean> {
+    const userId = score.getUserId();
+    const values = {
+      answered: score.getAnswered(),
+      correct: score.getCorrect(),
+      currentStreak: score.getCurrentStreak(),
+      bestStreak: score.getBestStreak(),
+      updatedAt: new Date(),
+    };
+
+    try {
+      return await this.db.transaction(async (tx) => {
+        const inserted = await tx
+          .insert(this.schema.typeQuizAnswers)
+          .values({ userId, questionId, correct })
+          .onConflictDoNothing()
+          .returning({ questionId: this.schema.typeQuizAnswers.questionId });
+
+        if (inserted.length === 0) {
+          return false;
+        }
+
+        await tx
+          .insert(this.schema.typeQuizScores)
+          .values({ userId, ...values })
+          .onConflictDoUpdate({ target: this.schema.typeQuizScores.userId, set: values });
+        return true;
+      });
+    } catch (error) {
+      throw new TypeQuizRepositoryError(`Failed to save quiz answer: ${error}`);
+    }
+  }
+}
@@ -0,0 +1,201 @@
+import { and, asc, desc, eq, inArray } from 'drizzle-orm';
+import {
+  PokemonUsage,
+  UsageCategory,
+  UsageCategorySchema,
+  UsageDetail,
+  UsageSnapshot,
+} from '../../domain/entity/UsageSnapshot';
+import { FormatId } from '../../domain/valueobject/Format';
+import { FormId } from '../../domain/valueobject/PokemonId';
+import {
+  UsageStatsRepository,
+  UsageStatsRepositoryError,
+} from '../interface/UsageStatsRepository';
+import { Db, Schema } from './types';
+
+type StatsRow = Schema['usageStats']['$inferSelect'];
+
+const INSERT_CHUNK_SIZE = 1000;
+
+export class DrizzleUsageStatsRepository implements UsageStatsRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async saveSnapshot(snapshot: UsageSnapshot): Promise<void> {
+    const { formatId, period } = snapshot;
+    const entries = snapshot.getEntries();
+
+    const statRows = entries.map((entry) => ({
+      formatId,
+      period,
+      formId: entry.getFormId(),
+      rank: entry.getRank(),
+      usage: entry.getUsage(),
+      rawCount: entry.getRawCount(),
+    }));
+
+    const detailRows = entries.flatMap((entry) =>
+      UsageCategorySchema.options.flatMap((category) =>
+        entry.getDetails(category).map((detail) => ({
+          formatId,
+          period,
+          formId: entry.getFormId(),
+          category,
+          name: detail.name,
+          percentage: detail.percentage,
+        }))
+      )
+    );
+
+    try {
+      await this.db.transaction(async (tx) => {
+        const snapshotKey = and(
+          eq(this.schema.usageStats.formatId, formatId),
+          eq(this.schema.usageStats.period, period)
+        );
+        await tx
+          .delete(this.schema.usageDetails)
+          .where(
+            and(
+              eq(this.schema.usageDetails.formatId, formatId),
+              eq(this.schema.usageDetails.period, period)
+            )
+          );
+        await tx.delete(this.schema.usageStats).where(snapshotKey);
+
+        const values = {
+          cutoff: snapshot.cutoff,
+          battles: snapshot.battles,
+          importedAt: new Date(),
+        };
+        await tx
+          .insert(this.schema.usageSnapshots)
+          .values({ formatId, period, ...values })
+          .onConflictDoUpdate({
+            target: [this.schema.usageSnapshots.formatId, this.schema.usageSnapshots.period],
+            set: values,
+          });
+
+        for (let i = 0; i < statRows.length; i += INSERT_CHUNK_SIZE) {
+          await tx.insert(this.schema.usageStats).values(statRows.slice(i, i + INSERT_CHUNK_SIZE));
+        }
+        for (let i = 0; i < detailRows.length; i += INSERT_CHUNK_SIZE) {
+          await tx
+            .insert(this.schema.usageDetails)
+            .values(detailRows.slice(i, i + INSERT_CHUNK_SIZE));
+        }
+      });
+    } catch (error) {
+      throw new UsageStatsRepositoryError(`Failed to save usage snapshot: ${error}`);
+    }
+  }
+
+  async findLatestPeriod(formatId: FormatId): Promise<string | null> {
+    try {
+      const result = await this.db
+        .select({ period: this.schema.usageSnapshots.period })
+        .from(this.schema.usageSnapshots)
+        .where(eq(this.schema.usageSnapshots.formatId, formatId))
+        .orderBy(desc(this.schema.usageSnapshots.period))
+        .limit(1);
+
+      return result.length === 0 ? null : result[0].period;
+    } catch (error) {
+      throw new UsageStatsRepositoryError(`Failed to find latest usage period: ${error}`);
+    }
+  }
+
+  async findTop(formatId: FormatId, period: string, limit: number): Promise<PokemonUsage[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.usageStats)
+        .where(
+          and(
+            eq(this.schema.usageStats.formatId, formatId),
+            eq(this.schema.usageStats.period, period)
+          )
+        )
+        .orderBy(asc(this.schema.usageStats.rank))
+        .limit(limit);
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new UsageStatsRepositoryError(`Failed to find top usage: ${error}`);
+    }
+  }
+
+  async findByFormId(
+    formatId: FormatId,
+    period: string,
+    formId: FormId
+  ): Promise<PokemonUsage | null> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.usageStats)
+        .where(
+          and(
+            eq(this.schema.usageStats.formatId, formatId),
+            eq(this.schema.usageStats.period, period),
+            eq(this.schema.usageStats.formId, formId)
+          )
+        )
+        .limit(1);
+
+      if (rows.length === 0) {
+        return null;
+      }
+
+      const [usage] = await this.toEntities(rows);
+      return usage;
+    } catch (error) {
+      throw new UsageStatsRepositoryError(`Failed to find usage by form: ${error}`);
+    }
+  }
+
+  private async toEntities(rows: StatsRow[]): Promise<PokemonUsage[]> {
+    if (rows.length === 0) {
+      return [];
+    }
+
+    const { formatId, period } = rows[0];
+    const details = await this.db
+      .select()
+      .from(this.schema.usageDetails)
+      .where(
+        and(
+          eq(this.schema.usageDetails.formatId, formatId),
+          eq(this.schema.usageDetails.period, period),
+          inArray(
+            this.schema.usageDetails.formId,
+            rows.map((row) => row.formId)
+          )
+        )
+      );
+
+    const detailsByForm = new Map<number, Record<UsageCategory, UsageDetail[]>>();
+    for (const detail of details) {
+      const grouped = detailsByForm.get(detail.formId) ?? this.emptyDetails();
+      grouped[UsageCategorySchema.parse(detail.category)].push({
+        name: detail.name,
+        percentage: detail.percentage,
+      });
+      detailsByForm.set(detail.formId, grouped);
+    }
+
+    return rows.map((row) =>
+      PokemonUsage.fromRepository({
+        formId: FormId.fromRepository(row.formId),
+        rank: row.rank,
+        usage: row.usage,
+        rawCount: row.rawCount,
+        details: detailsByForm.get(row.formId) ?? this.emptyDetails(),
+      })
+    );
+  }
+
+  private emptyDetails(): Record<UsageCategory, UsageDetail[]> {
+    return { move: [], item: [], ability: [], tera_type: [], spread: [] };
+  }
+}
@@ -0,0 +1,222 @@
+import { asc, gt, inArray } from 'drizzle-orm';
+import {
+  ArchivedPreferences,
+  ArchivedTeam,
+  ArchivedTeamMember,
+  ArchivedUser,
+  UserDataArchiveRepository,
+  UserDataArchiveRepositoryError,
+  UserDataSnapshot,
+} from '../interface/UserDataArchiveRepository';
+import { Db, Dialect, Schema, Transaction } from './types';
+
+export class DrizzleUserDataArchiveRepository implements UserDataArchiveRepository {
+  constructor(
+    private readonly db: Db,
+    private readonly schema: Schema,
+    private readonly dialect: Dialect
+  ) {}
+
+  async withSnapshot<T>(read: (snapshot: UserDataSnapshot) => Promise<T>): Promise<T> {
+    return this.dialect.readSnapshot(this.db, async (tx) =>
+      read(new DrizzleUserDataSnapshot(tx, this.schema))
+    );
+  }
+
+  async restoreUsers(rows: ArchivedUser[]): Promise<number> {
+    if (rows.length === 0) {
+      return 0;
+    }
+
+    try {
+      const written = await this.db
+        .insert(this.schema.users)
+        .values(rows.map((row) => ({ ...row, updatedAt: new Date() })))
+        .onConflictDoNothing()
+        .returning({ userId: this.schema.users.userId });
+      return written.length;
+    } catch (error) {
+      throw new UserDataArchiveRepositoryError(`Failed to restore users: ${error}`);
+    }
+  }
+
+  async restorePreferences(rows: ArchivedPreferences[]): Promise<number> {
+    if (rows.length === 0) {
+      return 0;
+    }
+
+    try {
+      return await this.db.transaction(async (tx) => {
+        const owners = await this.findExistingUsers(tx, rows.map((row) => row.userId));
+        const values = rows.filter((row) => owners.has(row.userId));
+        if (values.length === 0) {
+          return 0;
+        }
+        const written = await tx
+          .insert(this.schema.userPreferences)
+          .values(values)
+          .onConflictDoNothing()
+          .returning({ userId: this.schema.userPreferences.userId });
+        return written.length;
+      });
+    } catch (error) {
+      throw new UserDataArchiveRepositoryError(`Failed to restore preferences: ${error}`);
+    }
+  }
+
+  async restoreTeams(rows: ArchivedTeam[]): Promise<number> {
+    if (rows.length === 0) {
+      return 0;
+    }
+
+    try {
+      return await this.db.transaction(async (tx) => {
+        const owners = await this.findExistingUsers(tx, rows.map((row) => row.ownerId));
+        const candidates = rows.filter((row) => owners.has(row.ownerId));
+        if (candidates.length === 0) {
+          return 0;
+        }
+        const written = await tx
+          .insert(this.schema.teams)
+          .values(
+            candidates.map((row) => ({
+              teamId: row.teamId,
+              ownerId: row.ownerId,
+              teamName: row.teamName,
+              folder: row.folder,
+              notes: row.notes,
+              createdAt: row.createdAt,
+              updatedAt: row.updatedAt,
+              deletedAt: row.deletedAt,
+              sharedAt: row.sharedAt,
+              sharedFormat: row.sharedFormat,
+            }))
+          )
+          .onConflictDoNothing()
+          .returning({ teamId: this.schema.teams.teamId });
+
+        // Members and tags only for the teams written now, not those already there
+        const restored = new Set(written.map((row) => row.teamId));
+        const added = candidates.filter((row) => restored.has(row.teamId));
+        const members = added.flatMap((row) =>
+          row.members.map((member) => ({ teamId: row.teamId, ...member }))
+        );
+        const tags = added.flatMap((row) => row.tags.map((tag) => ({ teamId: row.teamId, tag })));
+        if (members.length > 0) {
+          await tx.insert(this.schema.teamPokemon).values(members);
+        }
+        if (tags.length > 0) {
+          await tx.insert(this.schema.teamTags).values(tags);
+        }
+        return written.length;
+      });
+    } catch (error) {
+      throw new UserDataArchiveRepositoryError(`Failed to restore teams: ${error}`);
+    }
+  }
+
+  private async findExistingUsers(tx: Transaction, userIds: string[]): Promise<Set<string>> {
+    const rows = await tx
+      .select({ userId: this.schema.users.userId })
+      .from(this.schema.users)
+      .where(inArray(this.schema.users.userId, [...new Set(userIds)]));
+    return new Set(rows.map((row) => row.userId));
+  }
+}
+
+class DrizzleUserDataSnapshot implements UserDataSnapshot {
+  constructor(private readonly tx: Transaction, private readonly schema: Schema) {}
+
+  async findUsers(limit: number, after?: string): Promise<ArchivedUser[]> {
+    try {
+      return await this.tx
+        .select({
+          userId: this.schema.users.userId,
+          username: this.schema.users.username,
+          email: this.schema.users.email,
+          passwordHash: this.schema.users.passwordHash,
+          role: this.schema.users.role,
+          createdAt: this.schema.users.createdAt,
+          deletedAt: this.schema.users.deletedAt,
+        })
+        .from(this.schema.users)
+        .where(after === undefined ? undefined : gt(this.schema.users.userId, after))
+        .orderBy(asc(this.schema.users.userId))
+        .limit(limit);
+    } catch (error) {
+      throw new UserDataArchiveRepositoryError(`Failed to read users: ${error}`);
+    }
+  }
+
+  async findPreferences(limit: number, after?: string): Promise<ArchivedPreferences[]> {
+    try {
+      return await this.tx
+        .select({
+          userId: this.schema.userPreferences.userId,
+          language: this.schema.userPreferences.language,
+          format: this.schema.userPreferences.format,
+          level: this.schema.userPreferences.level,
+          theme: this.schema.userPreferences.theme,
+        })
+        .from(this.schema.userPreferences)
+        .where(after === undefined ? undefined : gt(this.schema.userPreferences.userId, after))
+        .orderBy(asc(this.schema.userPreferences.userId))
+        .limit(limit);
+    } catch (error) {
+      throw new UserDataArchiveRepositoryError(`Failed to read preferences: ${error}`);
+    }
+  }
+
+  async findTeams(limit: number, after?: string): Promise<ArchivedTeam[]> {
+    try {
+      const rows = await this.tx
+        .select()
+        .from(this.schema.teams)
+        .where(after === undefined ? undefined : gt(this.schema.teams.teamId, after))
+        .orderBy(asc(this.schema.teams.teamId))
+        .limit(limit);
+      if (rows.length === 0) {
+        return [];
+      }
+
+      const teamIds = rows.map((row) => row.teamId);
+      const [memberRows, tagRows] = await Promise.all([
+        this.tx
+          .select()
+          .from(this.schema.teamPokemon)
+          .where(inArray(this.schema.teamPokemon.teamId, teamIds))
+          .orderBy(asc(this.schema.teamPokemon.slot)),
+        this.tx
+          .select()
+          .from(this.schema.teamTags)
+          .where(inArray(this.schema.teamTags.teamId, teamIds))
+          .orderBy(asc(this.schema.teamTags.tag)),
+      ]);
+      const members = new Map<string, ArchivedTeamMember[]>();
+      for (const { teamId, ...member } of memberRows) {
+        members.set(teamId, [...(members.get(teamId) ?? []), member]);
+      }
+      const tags = new Map<string, string[]>();
+      for (const { teamId, tag } of tagRows) {
+        tags.set(teamId, [...(tags.get(teamId) ?? []), tag]);
+      }
+
+      return rows.map((row) => ({
+        teamId: row.teamId,
+        ownerId: row.ownerId,
+        teamName: row.teamName,
+        members: members.get(row.teamId) ?? [],
+        tags: tags.get(row.teamId) ?? [],
+        folder: row.folder,
+        notes: row.notes,
+        createdAt: row.createdAt,
+        updatedAt: row.updatedAt,
+        deletedAt: row.deletedAt,
+        sharedAt: row.sharedAt,
+        sharedFormat: row.sharedFormat,
+      }));
+    } catch (error) {
+      throw new UserDataArchiveRepositoryError(`Failed to read teams: ${error}`);
+    }
+  }
+}
@@ -0,0 +1,59 @@
+import { eq } from 'drizzle-orm';
+import { ThemeSchema, UserPreferences } from '../../domain/entity/UserPreferences';
+import { FormatIdSchema } from '../../domain/valueobject/Format';
+import { Level } from '../../domain/valueobject/Level';
+import { LangSchema } from '../../i18n';
+import {
+  UserPreferencesRepository,
+  UserPreferencesRepositoryError,
+} from '../interface/UserPreferencesRepository';
+import { Db, Schema } from './types';
+
+export class DrizzleUserPreferencesRepository implements UserPreferencesRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findByUser(userId: string): Promise<UserPreferences | null> {
+    try {
+      const [row] = await this.db
+        .select()
+        .from(this.schema.userPreferences)
+        .where(eq(this.schema.userPreferences.userId, userId))
+        .limit(1);
+      if (!row) {
+        return null;
+      }
+
+      // A value that is no longer offered (a retired format) falls back to no preference
+      return UserPreferences.fromRepository(
+        row.userId,
+        LangSchema.nullable().catch(null).parse(row.language),
+        FormatIdSchema.nullable().catch(null).parse(row.format),
+        // Stored as the level itself
+        row.level === 100 ? 'level100' : 'level50',
+        ThemeSchema.catch('system').parse(row.theme),
+        row.updatedAt
+      );
+    } catch (error) {
+      throw new UserPreferencesRepositoryError(`Failed to find user preferences: ${error}`);
+    }
+  }
+
+  async save(preferences: UserPreferences): Promise<void> {
+    const row = {
+      language: preferences.getLanguage(),
+      format: preferences.getFormat(),
+      level: Level.forMode(preferences.getCalculationMode()).getValue(),
+      theme: preferences.getTheme(),
+      updatedAt: preferences.getUpdatedAt() ?? new Date(),
+    };
+
+    try {
+      await this.db
+        .insert(this.schema.userPreferences)
+        .values({ userId: preferences.getUserId(), ...row })
+        .onConflictDoUpdate({ target: this.schema.userPreferences.userId, set: row });
+    } catch (error) {
+      throw new UserPreferencesRepositoryError(`Failed to save user preferences: ${error}`);
+    }
+  }
+}
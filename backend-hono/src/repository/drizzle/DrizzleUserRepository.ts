@@ -0,0 +1,121 @@
+import { eq } from 'drizzle-orm';
+import { User } from '../../domain/entity/User';
+import { UserRoleSchema } from '../../domain/valueobject/UserRole';
+import {
+  EmailAlreadyExistsError,
+  UsernameAlreadyExistsError,
+  UserRepository,
+  UserRepositoryError,
+} from '../interface/UserRepository';
+import { Db, Dialect, Schema } from './types';
+
+type UserRow = Schema['users']['$inferSelect'];
+
+export class DrizzleUserRepository implements UserRepository {
+  constructor(
+    private readonly db: Db,
+    private readonly schema: Schema,
+    private readonly dialect: Dialect
+  ) {}
+
+  async save(user: User): Promise<void> {
+    try {
+      await this.db.insert(this.schema.users).values({
+        userId: user.getUserId(),
+        username: user.getUsername(),
+        email: user.getEmail(),
+        passwordHash: user.getPasswordHash(),
+        role: user.getRole(),
+      });
+    } catch (error) {
+      throw this.toSaveError(error);
+    }
+  }
+
+  async updateEmail(userId: string, email: string): Promise<void> {
+    try {
+      await this.db
+        .update(this.schema.users)
+        .set({ email, updatedAt: new Date() })
+        .where(eq(this.schema.users.userId, userId));
+    } catch (error) {
+      throw this.toSaveError(error);
+    }
+  }
+
+  async findById(userId: string): Promise<User | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.users)
+        .where(eq(this.schema.users.userId, userId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      return this.toEntity(result[0]);
+    } catch (error) {
+      throw new UserRepositoryError(`Failed to find user by ID: ${error}`);
+    }
+  }
+
+  async findByEmail(email: string): Promise<User | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.users)
+        .where(eq(this.schema.users.email, email.toLowerCase()))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      return this.toEntity(result[0]);
+    } catch (error) {
+      throw new UserRepositoryError(`Failed to find user by email: ${error}`);
+    }
+  }
+
+  async findByUsername(username: string): Promise<User | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.users)
+        .where(eq(this.schema.users.username, username))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      return this.toEntity(result[0]);
+    } catch (error) {
+      throw new UserRepositoryError(`Failed to find user by username: ${error}`);
+    }
+  }
+
+  private toEntity(row: UserRow): User {
+    return User.fromRepository(
+      row.userId,
+      row.username,
+      row.email,
+      row.passwordHash,
+      UserRoleSchema.parse(row.role)
+    );
+  }
+
+  // The constraint name says which column clashed
+  private toSaveError(error: unknown): Error {
+    const constraint = this.dialect.uniqueViolation(error);
+    if (constraint?.includes('email')) {
+      return new EmailAlreadyExistsError();
+    }
+    if (constraint?.includes('username')) {
+      return new UsernameAlreadyExistsError();
+    }
+    return new UserRepositoryError(`Failed to save user: ${error}`);
+  }
+}
@@ -0,0 +1,166 @@
+import { asc, desc, eq, inArray } from 'drizzle-orm';
+import { Webhook } from '../../domain/entity/Webhook';
+import { TeamEventType, TeamEventTypeSchema } from '../../domain/event/TeamEvent';
+import {
+  WebhookDelivery,
+  WebhookRepository,
+  WebhookRepositoryError,
+} from '../interface/WebhookRepository';
+import { Db, Schema } from './types';
+
+type WebhookRow = Schema['webhooks']['$inferSelect'];
+
+export class DrizzleWebhookRepository implements WebhookRepository {
+  constructor(private readonly db: Db, private readonly schema: Schema) {}
+
+  async findById(webhookId: string): Promise<Webhook | null> {
+    try {
+      const result = await this.db
+        .select()
+        .from(this.schema.webhooks)
+        .where(eq(this.schema.webhooks.webhookId, webhookId))
+        .limit(1);
+
+      if (result.length === 0) {
+        return null;
+      }
+
+      const [webhook] = await this.toEntities(result);
+      return webhook;
+    } catch (error) {
+      throw new WebhookRepositoryError(`Failed to find webhook by ID: ${error}`);
+    }
+  }
+
+  async findByOwner(ownerId: string): Promise<Webhook[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.webhooks)
+        .where(eq(this.schema.webhooks.ownerId, ownerId))
+        .orderBy(asc(this.schema.webhooks.createdAt));
+
+      return this.toEntities(rows);
+    } catch (error) {
+      throw new WebhookRepositoryError(`Failed to find webhooks by owner: ${error}`);
+    }
+  }
+
+  async save(webhook: Webhook): Promise<void> {
+    const webhookId = webhook.getWebhookId();
+    const values = { url: webhook.getUrl(), secret: webhook.getSecret() };
+
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .insert(this.schema.webhooks)
+          .values({
+            webhookId,
+            ownerId: webhook.getOwnerId(),
+            createdAt: webhook.getCreatedAt(),
+            ...values,
+          })
+          .onConflictDoUpdate({ target: this.schema.webhooks.webhookId, set: values });
+
+        await tx
+          .delete(this.schema.webhookEvents)
+          .where(eq(this.schema.webhookEvents.webhookId, webhookId));
+
+        const events = webhook.getEvents();
+        if (events.length > 0) {
+          await tx
+            .insert(this.schema.webhookEvents)
+            .values(events.map((eventType) => ({ webhookId, eventType })));
+        }
+      });
+    } catch (error) {
+      throw new WebhookRepositoryError(`Failed to save webhook: ${error}`);
+    }
+  }
+
+  async delete(webhookId: string): Promise<void> {
+    try {
+      await this.db.transaction(async (tx) => {
+        await tx
+          .delete(this.schema.webhookDeliveries)
+          .where(eq(this.schema.webhookDeliveries.webhookId, webhookId));
+        await tx
+          .delete(this.schema.webhookEvents)
+          .where(eq(this.schema.webhookEvents.webhookId, webhookId));
+        await tx.delete(this.schema.webhooks).where(eq(this.schema.webhooks.webhookId, webhookId));
+      });
+    } catch (error) {
+      throw new WebhookRepositoryError(`Failed to delete webhook: ${error}`);
+    }
+  }
+
+  async saveDelivery(delivery: WebhookDelivery): Promise<void> {
+    try {
+      await this.db.insert(this.schema.webhookDeliveries).values({
+        ...delivery,
+        statusCode: delivery.statusCode ?? null,
+        error: delivery.error ?? null,
+      });
+    } catch (error) {
+      throw new WebhookRepositoryError(`Failed to save webhook delivery: ${error}`);
+    }
+  }
+
+  async findDeliveries(webhookId: string, limit: number): Promise<WebhookDelivery[]> {
+    try {
+      const rows = await this.db
+        .select()
+        .from(this.schema.webhookDeliveries)
+        .where(eq(this.schema.webhookDeliveries.webhookId, webhookId))
+        .orderBy(desc(this.schema.webhookDeliveries.attemptedAt))
+        .limit(limit);
+
+      return rows.map((row) => ({
+        deliveryId: row.deliveryId,
+        webhookId: row.webhookId,
+        eventId: row.eventId,
+        eventType: TeamEventTypeSchema.parse(row.eventType),
+        attempt: row.attempt,
+        statusCode: row.statusCode ?? undefined,
+        error: row.error ?? undefined,
+        succeeded: row.succeeded,
+        attemptedAt: row.attemptedAt,
+      }));
+    } catch (error) {
+      throw new WebhookRepositoryError(`Failed to find webhook deliveries: ${error}`);
+    }
+  }
+
+  private async toEntities(rows: WebhookRow[]): Promise<Webhook[]> {
+    const events = await this.findEvents(rows.map((row) => row.webhookId));
+    return rows.map((row) =>
+      Webhook.fromRepository(
+        row.webhookId,
+        row.ownerId,
+        row.url,
+        row.secret,
+        events.get(row.webhookId) ?? [],
+        row.createdAt
+      )
+    );
+  }
+
+  private async findEvents(webhookIds: string[]): Promise<Map<string, TeamEventType[]>> {
+    const byWebhook = new Map<string, TeamEventType[]>();
+    if (webhookIds.length === 0) {
+      return byWebhook;
+    }
+
+    const rows = await this.db
+      .select()
+      .from(this.schema.webhookEvents)
+      .where(inArray(this.schema.webhookEvents.webhookId, webhookIds));
+
+    for (const row of rows) {
+      const events = byWebhook.get(row.webhookId) ?? [];
+      events.push(TeamEventTypeSchema.parse(row.eventType));
+      byWebhook.set(row.webhookId, events);
+    }
+    return byWebhook;
+  }
+}
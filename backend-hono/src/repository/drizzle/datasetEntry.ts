@@ -0,0 +1,287 @@
+import { z } from 'zod';
+import { Ability } from '../../domain/entity/Ability';
+import { Item } from '../../domain/entity/Item';
+import { DamageClassSchema, Move } from '../../domain/entity/Move';
+import { PokemonMaster } from '../../domain/entity/PokemonMaster';
+import { PokemonSpecies } from '../../domain/entity/PokemonSpecies';
+import { AbilitySlots, AbilitySlotSchema } from '../../domain/valueobject/AbilitySlots';
+import { RegionalDexSchema } from '../../domain/valueobject/DexNumber';
+import { EggGroupSchema } from '../../domain/valueobject/EggGroup';
+import { Availability, GameVersionSchema } from '../../domain/valueobject/GameVersion';
+import { GenderRatio } from '../../domain/valueobject/GenderRatio';
+import { MoveEffectsSchema } from '../../domain/valueobject/MoveEffects';
+import { FormId, SpeciesId } from '../../domain/valueobject/PokemonId';
+import { PokemonTypeSchema, TypeSet } from '../../domain/valueobject/PokemonType';
+import { SpeciesCategorySchema } from '../../domain/valueobject/SpeciesCategory';
+import { BaseStatsSchema } from '../../domain/valueobject/Stats';
+import { DatasetEntry } from '../interface/DatasetSnapshotRepository';
+
+// Snapshot rows hold entries as JSON, so one table covers every kind
+
+const SpeciesJsonSchema = z.object({
+  speciesId: z.number(),
+  name: z.string(),
+  nameJp: z.string(),
+  eggGroups: z.array(EggGroupSchema),
+  femaleEighths: z.number(),
+  captureRate: z.number(),
+  category: SpeciesCategorySchema,
+  evolvesFromSpeciesId: z.number().nullable(),
+  regionalDexNumbers: z.array(z.object({ dex: RegionalDexSchema, number: z.number() })),
+});
+
+const FormJsonSchema = z.object({
+  formId: z.number(),
+  speciesId: z.number(),
+  name: z.string(),
+  nameJp: z.string(),
+  type1: PokemonTypeSchema,
+  type2: PokemonTypeSchema.nullable(),
+  baseStats: BaseStatsSchema,
+  abilities: z.array(z.object({ slot: AbilitySlotSchema, ability: z.string() })),
+  baseExperience: z.number().nullable(),
+  height: z.number(),
+  weight: z.number(),
+  shinyAvailable: z.boolean(),
+  shinyLocked: z.boolean(),
+  introducedIn: GameVersionSchema,
+  obtainableIn: z.array(GameVersionSchema),
+});
+
+const MoveJsonSchema = z.object({
+  id: z.number(),
+  name: z.string(),
+  nameJp: z.string().nullable(),
+  type: PokemonTypeSchema,
+  damageClass: DamageClassSchema,
+  power: z.number().nullable(),
+  accuracy: z.number().nullable(),
+  priority: z.number(),
+  pp: z.number().nullable(),
+  target: z.string(),
+  minHits: z.number().nullable(),
+  maxHits: z.number().nullable(),
+  effect: z.string().nullable(),
+  // Missing from snapshots taken before effects were recorded
+  effects: MoveEffectsSchema.nullable().default(null),
+});
+
+const AbilityJsonSchema = z.object({
+  id: z.number(),
+  name: z.string(),
+  nameJp: z.string().nullable(),
+  effect: z.string().nullable(),
+  effectJp: z.string().nullable(),
+});
+
+const ItemJsonSchema = z.object({
+  id: z.number(),
+  name: z.string(),
+  nameJp: z.string().nullable(),
+  category: z.string(),
+  effect: z.string().nullable(),
+  effectJp: z.string().nullable(),
+});
+
+export interface DatasetEntryRow {
+  kind: DatasetEntry['kind'];
+  entryId: number;
+  data: string;
+}
+
+export function toDatasetEntryRow(entry: DatasetEntry): DatasetEntryRow {
+  switch (entry.kind) {
+    case 'species': {
+      const species = entry.entry;
+      return {
+        kind: entry.kind,
+        entryId: species.getSpeciesId(),
+        data: JSON.stringify({
+          speciesId: species.getSpeciesId(),
+          name: species.getName(),
+          nameJp: species.getNameJp(),
+          eggGroups: species.getEggGroups(),
+          femaleEighths: species.getGenderRatio().getFemaleEighths(),
+          captureRate: species.getCaptureRate(),
+          category: species.getCategory(),
+          evolvesFromSpeciesId: species.getEvolvesFromSpeciesId() ?? null,
+          regionalDexNumbers: species.getRegionalDexNumbers(),
+        }),
+      };
+    }
+    case 'form': {
+      const form = entry.entry;
+      return {
+        kind: entry.kind,
+        entryId: form.getFormId(),
+        data: JSON.stringify({
+          formId: form.getFormId(),
+          speciesId: form.getSpeciesId(),
+          name: form.getName(),
+          nameJp: form.getNameJp(),
+          type1: form.getTypeset().type1,
+          type2: form.getTypeset().type2 ?? null,
+          baseStats: form.getBaseStats(),
+          abilities: form.getAbilities()?.getEntries() ?? [],
+          baseExperience: form.getBaseExperience() ?? null,
+          height: form.getHeight(),
+          weight: form.getWeight(),
+          shinyAvailable: form.isShinyAvailable(),
+          shinyLocked: form.isShinyLocked(),
+          introducedIn: form.getAvailability().introducedIn,
+          obtainableIn: form.getAvailability().getObtainableIn(),
+        }),
+      };
+    }
+    case 'move': {
+      const move = entry.entry;
+      return {
+        kind: entry.kind,
+        entryId: move.getId(),
+        data: JSON.stringify({
+          id: move.getId(),
+          name: move.getName(),
+          nameJp: move.getNameJp() ?? null,
+          type: move.getType(),
+          damageClass: move.getDamageClass(),
+          power: move.getPower() ?? null,
+          accuracy: move.getAccuracy() ?? null,
+          priority: move.getPriority(),
+          pp: move.getPp() ?? null,
+          target: move.getTarget(),
+          minHits: move.isMultiHit() ? move.getMinHits() : null,
+          maxHits: move.isMultiHit() ? move.getMaxHits() : null,
+          effect: move.getEffect() ?? null,
+          effects: move.getEffects() ?? null,
+        }),
+      };
+    }
+    case 'ability': {
+      const ability = entry.entry;
+      return {
+        kind: entry.kind,
+        entryId: ability.getId(),
+        data: JSON.stringify({
+          id: ability.getId(),
+          name: ability.getName(),
+          nameJp: ability.getNameJp() ?? null,
+          effect: ability.getEffect() ?? null,
+          effectJp: ability.getEffectJp() ?? null,
+        }),
+      };
+    }
+    case 'item': {
+      const item = entry.entry;
+      return {
+        kind: entry.kind,
+        entryId: item.getId(),
+        data: JSON.stringify({
+          id: item.getId(),
+          name: item.getName(),
+          nameJp: item.getNameJp() ?? null,
+          category: item.getCategory(),
+          effect: item.getEffect() ?? null,
+          effectJp: item.getEffectJp() ?? null,
+        }),
+      };
+    }
+  }
+}
+
+export function fromDatasetEntryRow(kind: string, data: string): DatasetEntry {
+  const json: unknown = JSON.parse(data);
+
+  switch (kind) {
+    case 'species': {
+      const species = SpeciesJsonSchema.parse(json);
+      return {
+        kind: 'species',
+        entry: PokemonSpecies.fromRepository(
+          SpeciesId.fromRepository(species.speciesId),
+          species.name,
+          species.nameJp,
+          species.eggGroups,
+          GenderRatio.fromFemaleEighths(species.femaleEighths),
+          species.captureRate,
+          species.category,
+          species.evolvesFromSpeciesId === null
+            ? undefined
+            : SpeciesId.fromRepository(species.evolvesFromSpeciesId),
+          species.regionalDexNumbers
+        ),
+      };
+    }
+    case 'form': {
+      const form = FormJsonSchema.parse(json);
+      return {
+        kind: 'form',
+        entry: PokemonMaster.fromRepository({
+          formId: FormId.fromRepository(form.formId),
+          speciesId: SpeciesId.fromRepository(form.speciesId),
+          name: form.name,
+          nameJp: form.nameJp,
+          typeset: new TypeSet(form.type1, form.type2 ?? undefined),
+          baseStats: form.baseStats,
+          abilities: AbilitySlots.fromEntries(form.abilities),
+          baseExperience: form.baseExperience ?? undefined,
+          height: form.height,
+          weight: form.weight,
+          shinyAvailable: form.shinyAvailable,
+          shinyLocked: form.shinyLocked,
+          availability: new Availability(form.introducedIn, form.obtainableIn),
+        }),
+      };
+    }
+    case 'move': {
+      const move = MoveJsonSchema.parse(json);
+      return {
+        kind: 'move',
+        entry: Move.fromRepository({
+          id: move.id,
+          name: move.name,
+          nameJp: move.nameJp ?? undefined,
+          type: move.type,
+          damageClass: move.damageClass,
+          power: move.power ?? undefined,
+          accuracy: move.accuracy ?? undefined,
+          priority: move.priority,
+          pp: move.pp ?? undefined,
+          target: move.target,
+          minHits: move.minHits ?? undefined,
+          maxHits: move.maxHits ?? undefined,
+          effect: move.effect ?? undefined,
+          effects: move.effects ?? undefined,
+        }),
+      };
+    }
+    case 'ability': {
+      const ability = AbilityJsonSchema.parse(json);
+      return {
+        kind: 'ability',
+        entry: Ability.fromRepository({
+          id: ability.id,
+          name: ability.name,
+          nameJp: ability.nameJp ?? undefined,
+          effect: ability.effect ?? undefined,
+          effectJp: ability.effectJp ?? undefined,
+        }),
+      };
+    }
+    case 'item': {
+      const item = ItemJsonSchema.parse(json);
+      return {
+        kind: 'item',
+        entry: Item.fromRepository({
+          id: item.id,
+          name: item.name,
+          nameJp: item.nameJp ?? undefined,
+          category: item.category,
+          effect: item.effect ?? undefined,
+          effectJp: item.effectJp ?? undefined,
+        }),
+      };
+    }
+    default:
+      throw new Error(`Unknown snapshot kind: ${kind}`);
+  }
+}
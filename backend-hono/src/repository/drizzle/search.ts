@@ -0,0 +1,25 @@
+import { Column, or, SQL } from 'drizzle-orm';
+import { toLookupKey } from '../../domain/valueobject/Identifier';
+import { Dialect } from './types';
+
+// % and _ in user input match literally
+export function toContainsPattern(value: string): string {
+  return `%${value.trim().replace(/[\\%_]/g, '\\$&')}%`;
+}
+
+export function contains(dialect: Dialect, column: Column, value: string): SQL {
+  return dialect.like(column, toContainsPattern(value));
+}
+
+// Part of an English or Japanese name. English names are compared by lookup key, so
+// "close combat" finds close-combat.
+export function nameContains(
+  dialect: Dialect,
+  lookupKey: Column,
+  nameJp: Column,
+  query: string
+): SQL | undefined {
+  const key = toLookupKey(query);
+  const jp = contains(dialect, nameJp, query);
+  return key === '' ? jp : or(dialect.like(lookupKey, `%${key}%`), jp);
+}
@@ -0,0 +1,39 @@
+import { Column, InferSelectModel, SQL, Table } from 'drizzle-orm';
+import { DbType } from '../../db/connection';
+import * as schema from '../../db/schema';
+
+// The repositories are written once, against the Postgres types. The SQLite schema declares the
+// same tables and columns and drizzle's SQLite builders take the same calls, so
+// createRepositories hands the SQLite database in cast to these types, and the SQLite schema in
+// through sharedSchema, which checks that it still matches.
+export type Db = DbType;
+export type Schema = typeof schema;
+export type Transaction = Parameters<Parameters<Db['transaction']>[0]>[0];
+
+// The row type of every table in a schema module
+type Rows<S> = {
+  [K in keyof S as S[K] extends Table ? K : never]: InferSelectModel<Extract<S[K], Table>>;
+};
+
+// Rows assignable both ways: the same tables, with the same columns, types and nullability
+type SameRows<A, B> = [Rows<A>, Rows<B>] extends [Rows<B>, Rows<A>] ? true : false;
+
+// `unknown` while the schema matches the Postgres one, and a type no schema has otherwise
+type MatchesPostgres<S> =
+  SameRows<S, Schema> extends true ? unknown : 'The schema does not match the Postgres one';
+
+// Another dialect's schema as the Postgres one. A table or column that differs between them
+// fails to compile here instead of failing a query at runtime.
+export function sharedSchema<S>(dialectSchema: S & MatchesPostgres<S>): Schema {
+  return dialectSchema as unknown as Schema;
+}
+
+// The few things the databases do differently
+export interface Dialect {
+  // Case-insensitive LIKE, with backslash escaping % and _
+  like(column: Column, pattern: string): SQL;
+  // Runs the reads in one transaction that sees a single snapshot
+  readSnapshot<T>(db: Db, read: (tx: Transaction) => Promise<T>): Promise<T>;
+  // What the unique constraint an error reports is called, or null for any other error
+  uniqueViolation(error: unknown): string | null;
+}
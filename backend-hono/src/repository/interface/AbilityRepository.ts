@@ -0,0 +1,27 @@
+import { Ability } from '../../domain/entity/Ability';
+
+export class AbilityRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'AbilityRepositoryError';
+  }
+}
+
+export interface AbilitySearchFilter {
+  // Part of the English or Japanese name
+  name?: string;
+}
+
+export interface AbilityPage {
+  abilities: Ability[];
+  // Matches across all pages
+  total: number;
+}
+
+export interface AbilityRepository {
+  // Accepts any spelling ("Sand Rush", "sandrush", "sand-rush")
+  findByName(name: string): Promise<Ability | null>;
+  // Sorted by ability ID
+  search(filter: AbilitySearchFilter, limit: number, offset: number): Promise<AbilityPage>;
+  save(ability: Ability): Promise<void>;
+}
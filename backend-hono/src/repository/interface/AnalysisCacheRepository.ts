@@ -0,0 +1,22 @@
+export class AnalysisCacheRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'AnalysisCacheRepositoryError';
+  }
+}
+
+export interface AnalysisCacheEntry {
+  key: string;
+  kind: string;
+  version: number;
+  result: string; // JSON
+}
+
+export interface AnalysisCacheRepository {
+  // Null when nothing was stored under the key for this data version
+  find(key: string, version: number): Promise<string | null>;
+  // Replaces whatever was stored under the key
+  save(entry: AnalysisCacheEntry): Promise<void>;
+  // Returns the number of entries removed
+  deleteAll(): Promise<number>;
+}
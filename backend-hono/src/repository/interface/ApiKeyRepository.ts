@@ -0,0 +1,18 @@
+import { ApiKey } from '../../domain/entity/ApiKey';
+
+export class ApiKeyRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'ApiKeyRepositoryError';
+  }
+}
+
+export interface ApiKeyRepository {
+  findById(keyId: string): Promise<ApiKey | null>;
+  findByHash(keyHash: string): Promise<ApiKey | null>;
+  // Newest first, revoked keys included
+  findAll(): Promise<ApiKey[]>;
+  save(apiKey: ApiKey): Promise<void>;
+  // Counts one request for the day (YYYY-MM-DD) and returns the day's total
+  recordRequest(keyId: string, day: string): Promise<number>;
+}
@@ -0,0 +1,55 @@
+import { BattleRecord, BattleResult } from '../../domain/entity/BattleRecord';
+import { Archetype } from '../../domain/service/ArchetypeClassifier';
+import { FormId } from '../../domain/valueobject/PokemonId';
+
+export class BattleRecordRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'BattleRecordRepositoryError';
+  }
+}
+
+// Every condition given must hold
+export interface BattleRecordFilter {
+  teamId?: string;
+  result?: BattleResult;
+  archetype?: Archetype;
+  // Battles where the opponent had this Pokémon
+  formId?: FormId;
+}
+
+export interface BattleTally {
+  wins: number;
+  losses: number;
+}
+
+export interface TeamBattleTally extends BattleTally {
+  teamId: string;
+}
+
+export interface ArchetypeBattleTally extends BattleTally {
+  archetype: Archetype;
+}
+
+export interface OpponentBattleTally extends BattleTally {
+  formId: FormId;
+}
+
+export interface BattleRecordRepository {
+  create(record: BattleRecord): Promise<void>;
+  findById(recordId: string): Promise<BattleRecord | null>;
+  // Most recently played first
+  findByUser(
+    userId: string,
+    filter: BattleRecordFilter,
+    limit: number,
+    offset: number
+  ): Promise<BattleRecord[]>;
+  countByUser(userId: string, filter: BattleRecordFilter): Promise<number>;
+  delete(recordId: string): Promise<boolean>;
+  // Tallies are counted in the database, most battles first. A null teamId counts the battles
+  // of all the user's teams.
+  tallyByTeam(userId: string): Promise<TeamBattleTally[]>;
+  tallyByArchetype(userId: string, teamId: string | null): Promise<ArchetypeBattleTally[]>;
+  tallyByOpponent(userId: string, teamId: string | null): Promise<OpponentBattleTally[]>;
+}
@@ -0,0 +1,22 @@
+import { CustomForm, CustomFormProps } from '../../domain/entity/CustomForm';
+import { FormId } from '../../domain/valueobject/PokemonId';
+
+export class CustomFormRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'CustomFormRepositoryError';
+  }
+}
+
+export interface CustomFormRepository {
+  findById(formId: FormId): Promise<CustomForm | null>;
+  // Only the owner's forms; IDs of other users' forms are left out
+  findByIds(ownerId: string, formIds: FormId[]): Promise<CustomForm[]>;
+  // Oldest first
+  findByOwner(ownerId: string): Promise<CustomForm[]>;
+  countByOwner(ownerId: string): Promise<number>;
+  // The form ID is assigned here
+  create(ownerId: string, props: CustomFormProps): Promise<CustomForm>;
+  update(form: CustomForm): Promise<void>;
+  delete(formId: FormId): Promise<void>;
+}
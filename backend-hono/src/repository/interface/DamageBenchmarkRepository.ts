@@ -0,0 +1,17 @@
+import { DamageBenchmark } from '../../domain/entity/DamageBenchmark';
+import { FormatId } from '../../domain/valueobject/Format';
+
+export class DamageBenchmarkRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'DamageBenchmarkRepositoryError';
+  }
+}
+
+export interface DamageBenchmarkRepository {
+  findById(benchmarkId: string): Promise<DamageBenchmark | null>;
+  // Oldest first
+  findByOwner(ownerId: string, format: FormatId): Promise<DamageBenchmark[]>;
+  save(benchmark: DamageBenchmark): Promise<void>;
+  delete(benchmarkId: string): Promise<void>;
+}
@@ -0,0 +1,40 @@
+import { z } from 'zod';
+
+export class DataImportRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'DataImportRepositoryError';
+  }
+}
+
+// 'seed' is the bundled fixture dataset, 'edit' a manual form edit, 'purge' a bare cache purge
+// that only bumps the version and 'rollback' a return to an earlier version (recorded as fromId)
+export const DataImportKindSchema = z.enum([
+  'species',
+  'moves',
+  'abilities',
+  'items',
+  'seed',
+  'edit',
+  'purge',
+  'rollback',
+]);
+
+export type DataImportKind = z.infer<typeof DataImportKindSchema>;
+
+export interface DataImport {
+  version: number;
+  kind: DataImportKind;
+  fromId?: number;
+  toId?: number;
+  importedAt: Date;
+}
+
+export interface DataImportRepository {
+  // Returns the new data version
+  record(kind: DataImportKind, fromId?: number, toId?: number): Promise<number>;
+  // 0 when nothing has been imported yet, e.g. data loaded straight from a dump
+  findLatestVersion(): Promise<number>;
+  // Newest first
+  findRecent(limit: number): Promise<DataImport[]>;
+}
@@ -0,0 +1,28 @@
+import { Ability } from '../../domain/entity/Ability';
+import { Item } from '../../domain/entity/Item';
+import { Move } from '../../domain/entity/Move';
+import { PokemonMaster } from '../../domain/entity/PokemonMaster';
+import { PokemonSpecies } from '../../domain/entity/PokemonSpecies';
+
+export class DatasetSnapshotRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'DatasetSnapshotRepositoryError';
+  }
+}
+
+export type DatasetEntry =
+  | { kind: 'species'; entry: PokemonSpecies }
+  | { kind: 'form'; entry: PokemonMaster }
+  | { kind: 'move'; entry: Move }
+  | { kind: 'ability'; entry: Ability }
+  | { kind: 'item'; entry: Item };
+
+export interface DatasetSnapshotRepository {
+  // Keeps the entries as they are before an import, edit or rollback overwrites them, tagged with
+  // the current data version
+  save(entries: DatasetEntry[]): Promise<void>;
+  // Every entry overwritten after the version, as it was at that version. Entries first created
+  // after it are not included.
+  findChangedSince(version: number): Promise<DatasetEntry[]>;
+}
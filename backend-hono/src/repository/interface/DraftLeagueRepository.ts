@@ -0,0 +1,22 @@
+import { DraftLeague, DraftPick } from '../../domain/entity/DraftLeague';
+
+export class DraftLeagueRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'DraftLeagueRepositoryError';
+  }
+}
+
+export interface DraftLeagueRepository {
+  findById(leagueId: string): Promise<DraftLeague | null>;
+  // Leagues the user runs or takes part in, newest first
+  findByUser(userId: string): Promise<DraftLeague[]>;
+  // Inserts the league with its participants and pool; picks are added one at a time
+  create(league: DraftLeague): Promise<void>;
+  // Returns false when the league no longer has `pick.pickNumber - 1` picks, e.g. after a
+  // concurrent pick or undo
+  addPick(leagueId: string, pick: DraftPick): Promise<boolean>;
+  // Removes the last pick. Returns false when it is no longer pick `pickNumber`.
+  removePick(leagueId: string, pickNumber: number): Promise<boolean>;
+  delete(leagueId: string): Promise<void>;
+}
@@ -0,0 +1,18 @@
+import { EmailChange } from '../../domain/entity/EmailChange';
+
+export class EmailChangeRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'EmailChangeRepositoryError';
+  }
+}
+
+export interface EmailChangeRepository {
+  // Replaces the user's pending change, if any
+  save(emailChange: EmailChange): Promise<void>;
+  findByUser(userId: string): Promise<EmailChange | null>;
+  findByConfirmTokenHash(tokenHash: string): Promise<EmailChange | null>;
+  findByCancelTokenHash(tokenHash: string): Promise<EmailChange | null>;
+  // Returns false when the user has no pending change
+  delete(userId: string): Promise<boolean>;
+}
@@ -0,0 +1,29 @@
+import { Item } from '../../domain/entity/Item';
+
+export class ItemRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'ItemRepositoryError';
+  }
+}
+
+export interface ItemSearchFilter {
+  // Part of the English or Japanese name
+  name?: string;
+  // PokeAPI item category, e.g. "choice", "held-items", "type-enhancement"
+  category?: string;
+}
+
+export interface ItemPage {
+  items: Item[];
+  // Matches across all pages
+  total: number;
+}
+
+export interface ItemRepository {
+  // Accepts any spelling ("Choice Scarf", "choicescarf", "choice-scarf")
+  findByName(name: string): Promise<Item | null>;
+  // Sorted by item ID
+  search(filter: ItemSearchFilter, limit: number, offset: number): Promise<ItemPage>;
+  save(item: Item): Promise<void>;
+}
@@ -0,0 +1,16 @@
+import { LoginFailures } from '../../domain/valueobject/LoginBackoff';
+
+export class LoginFailureRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'LoginFailureRepositoryError';
+  }
+}
+
+export interface LoginFailureRepository {
+  // Identifiers without failures are left out
+  findByIdentifiers(identifiers: string[]): Promise<LoginFailures[]>;
+  // Counts one more failure, starting again from one when the last was before `forgetBefore`
+  recordFailure(identifier: string, at: Date, forgetBefore: Date): Promise<void>;
+  clear(identifier: string): Promise<void>;
+}
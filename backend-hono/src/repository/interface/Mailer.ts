@@ -0,0 +1,16 @@
+export class MailerError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'MailerError';
+  }
+}
+
+export interface MailMessage {
+  to: string;
+  subject: string;
+  text: string;
+}
+
+export interface Mailer {
+  send(message: MailMessage): Promise<void>;
+}
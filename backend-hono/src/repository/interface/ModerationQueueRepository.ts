@@ -0,0 +1,20 @@
+import { ModerationItem } from '../../domain/entity/ModerationItem';
+import { ContentKind } from '../../domain/service/ContentPolicy';
+
+export class ModerationQueueRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'ModerationQueueRepositoryError';
+  }
+}
+
+export interface ModerationQueueRepository {
+  save(item: ModerationItem): Promise<void>;
+  findById(itemId: string): Promise<ModerationItem | null>;
+  // Oldest first
+  findPage(limit: number, offset: number): Promise<ModerationItem[]>;
+  count(): Promise<number>;
+  delete(itemId: string): Promise<boolean>;
+  // Once the text is gone or replaced, its queued copies are moot
+  deleteByTarget(kind: ContentKind, targetId: string): Promise<void>;
+}
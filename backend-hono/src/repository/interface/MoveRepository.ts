@@ -0,0 +1,41 @@
+import { DamageClass, Move } from '../../domain/entity/Move';
+import { SpeciesId } from '../../domain/valueobject/PokemonId';
+import { PokemonType } from '../../domain/valueobject/PokemonType';
+
+export class MoveRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'MoveRepositoryError';
+  }
+}
+
+export interface MoveSearchFilter {
+  // Part of the English or Japanese name
+  name?: string;
+  type?: PokemonType;
+  damageClass?: DamageClass;
+  // Moves without a base power never match a power bound
+  minPower?: number;
+  maxPower?: number;
+  priority?: number;
+  // Each keyword has to appear in the effect text, e.g. ["recoil"] or ["switch", "user"]
+  effectKeywords?: string[];
+  // Moves at least one form of the species can learn
+  learnableBy?: SpeciesId;
+}
+
+export interface MovePage {
+  moves: Move[];
+  // Matches across all pages
+  total: number;
+}
+
+export interface MoveRepository {
+  // Accepts any spelling ("Close Combat", "closecombat", "close-combat")
+  findByName(name: string): Promise<Move | null>;
+  findByNames(names: string[]): Promise<Move[]>;
+  findByIds(moveIds: number[]): Promise<Move[]>;
+  // Sorted by move ID
+  search(filter: MoveSearchFilter, limit: number, offset: number): Promise<MovePage>;
+  save(move: Move): Promise<void>;
+}
@@ -0,0 +1,27 @@
+import { Notification } from '../../domain/entity/Notification';
+
+export class NotificationRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'NotificationRepositoryError';
+  }
+}
+
+export interface NotificationFilter {
+  unreadOnly?: boolean;
+}
+
+export interface NotificationRepository {
+  save(notification: Notification): Promise<void>;
+  // Newest first
+  findPageByUser(
+    userId: string,
+    filter: NotificationFilter,
+    limit: number,
+    offset: number
+  ): Promise<Notification[]>;
+  countByUser(userId: string, filter: NotificationFilter): Promise<number>;
+  // Only the user's own unread notifications are touched; returns how many were marked
+  markRead(userId: string, notificationIds: string[], readAt: Date): Promise<number>;
+  markAllRead(userId: string, readAt: Date): Promise<number>;
+}
@@ -0,0 +1,285 @@
+import { IVStats, Stats } from '../../domain/valueobject/Stats';
+import { ArchivedTeam } from './UserDataArchiveRepository';
+
+export class PersonalDataRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'PersonalDataRepositoryError';
+  }
+}
+
+export interface PersonalProfile {
+  userId: string;
+  username: string;
+  email: string;
+  role: string;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface PersonalPreferences {
+  language: string | null;
+  format: string | null;
+  level: number;
+  theme: string;
+  updatedAt: Date;
+}
+
+export interface PersonalEmailChange {
+  newEmail: string;
+  expiresAt: Date;
+  createdAt: Date;
+}
+
+// One issued refresh token; the tokens of a session share its sessionId
+export interface PersonalSessionToken {
+  tokenId: string;
+  sessionId: string;
+  userAgent: string | null;
+  ip: string | null;
+  createdAt: Date;
+  lastUsedAt: Date;
+  expiresAt: Date;
+  revoked: boolean;
+}
+
+export interface PersonalLoginFailures {
+  failures: number;
+  lastFailedAt: Date;
+}
+
+// A Pokémon build as stored, for box Pokémon and damage benchmarks
+export interface PersonalBuild {
+  formId: number;
+  nature: string;
+  ability: string | null;
+  item: string | null;
+  teraType: string | null;
+  evs: Stats;
+  ivs: IVStats;
+}
+
+export interface PersonalBoxPokemon extends PersonalBuild {
+  pokemonId: string;
+  nickname: string | null;
+  moves: string[];
+  createdAt: Date;
+}
+
+export interface PersonalDamageBenchmark extends PersonalBuild {
+  benchmarkId: string;
+  format: string;
+  name: string;
+  role: string;
+  move: string | null;
+  createdAt: Date;
+}
+
+export interface PersonalCustomForm {
+  formId: number;
+  name: string;
+  type1: string;
+  type2: string | null;
+  baseStats: Stats;
+  ability1: string;
+  ability2: string | null;
+  hiddenAbility: string | null;
+  createdAt: Date;
+  updatedAt: Date;
+}
+
+export interface PersonalTeamLike {
+  teamId: string;
+  createdAt: Date;
+}
+
+export interface PersonalComment {
+  commentId: string;
+  teamId: string;
+  parentId: string | null;
+  body: string;
+  createdAt: Date;
+  updatedAt: Date;
+  deletedAt: Date | null;
+}
+
+export interface PersonalTeamFlag {
+  teamId: string;
+  reason: string;
+  createdAt: Date;
+}
+
+// Text the user published that is waiting for a moderator
+export interface PersonalModerationItem {
+  itemId: string;
+  kind: string;
+  targetId: string;
+  text: string;
+  reasons: string;
+  createdAt: Date;
+}
+
+export interface PersonalNotification {
+  notificationId: string;
+  type: string;
+  data: string; // JSON
+  createdAt: Date;
+  readAt: Date | null;
+}
+
+export interface PersonalBattleRecord {
+  recordId: string;
+  teamId: string;
+  format: string | null;
+  result: string;
+  opponentName: string | null;
+  opponentArchetype: string | null;
+  opponentFormIds: number[];
+  notes: string | null;
+  playedAt: Date;
+  createdAt: Date;
+}
+
+export interface PersonalRun {
+  runId: string;
+  name: string;
+  gameVersion: string;
+  speciesIds: number[];
+  createdAt: Date;
+}
+
+export interface PersonalDraftPick {
+  pickNumber: number;
+  formId: number;
+  pickedAt: Date;
+}
+
+// A draft league the user runs or takes part in, with only their own picks
+export interface PersonalDraftLeague {
+  leagueId: string;
+  name: string;
+  format: string;
+  commissioner: boolean;
+  // Place in the draft order, or null when the user only runs the league
+  position: number | null;
+  picks: PersonalDraftPick[];
+  createdAt: Date;
+}
+
+// The user's copy of the team they entered with
+export interface PersonalTournamentEntry {
+  teamId: string;
+  teamName: string;
+  teamVersion: number;
+  members: { slot: number; formId: number; terastalType: string }[];
+  seed: number | null;
+  registeredAt: Date;
+}
+
+// A match the user played, without who it was against; byes are left out
+export interface PersonalTournamentMatch {
+  round: number;
+  position: number;
+  won: boolean | null;
+  reportedAt: Date | null;
+}
+
+// A tournament the user organizes or entered
+export interface PersonalTournament {
+  tournamentId: string;
+  name: string;
+  format: string;
+  status: string;
+  organizer: boolean;
+  entry: PersonalTournamentEntry | null;
+  matches: PersonalTournamentMatch[];
+  createdAt: Date;
+}
+
+export interface PersonalWebhookDelivery {
+  deliveryId: string;
+  eventId: string;
+  eventType: string;
+  attempt: number;
+  statusCode: number | null;
+  error: string | null;
+  succeeded: boolean;
+  attemptedAt: Date;
+}
+
+// The signing secret is left out; it is a credential, not something stored about the user
+export interface PersonalWebhook {
+  webhookId: string;
+  url: string;
+  eventTypes: string[];
+  deliveries: PersonalWebhookDelivery[];
+  createdAt: Date;
+}
+
+export interface PersonalQuizAnswer {
+  questionId: number;
+  correct: boolean;
+  answeredAt: Date;
+}
+
+export interface PersonalQuizScore {
+  answered: number;
+  correct: number;
+  currentStreak: number;
+  bestStreak: number;
+  updatedAt: Date;
+  answers: PersonalQuizAnswer[];
+}
+
+// Every row that is about one user. Password and token hashes are left out.
+export interface PersonalData {
+  profile: PersonalProfile;
+  preferences: PersonalPreferences | null;
+  pendingEmailChange: PersonalEmailChange | null;
+  sessions: PersonalSessionToken[];
+  loginFailures: PersonalLoginFailures | null;
+  teams: ArchivedTeam[];
+  boxPokemon: PersonalBoxPokemon[];
+  battleRecords: PersonalBattleRecord[];
+  damageBenchmarks: PersonalDamageBenchmark[];
+  customForms: PersonalCustomForm[];
+  likes: PersonalTeamLike[];
+  comments: PersonalComment[];
+  flags: PersonalTeamFlag[];
+  moderationItems: PersonalModerationItem[];
+  notifications: PersonalNotification[];
+  runs: PersonalRun[];
+  draftLeagues: PersonalDraftLeague[];
+  tournaments: PersonalTournament[];
+  webhooks: PersonalWebhook[];
+  typeQuiz: PersonalQuizScore | null;
+}
+
+// What happens to what a deleted account published: 'delete' removes its teams and blanks its
+// comments; 'anonymize' keeps shared teams and comments, credited to the tombstone
+export type PublishedContent = 'delete' | 'anonymize';
+
+// What the users row of a deleted account is scrubbed to
+export interface AccountTombstone {
+  username: string;
+  email: string;
+  passwordHash: string;
+}
+
+export interface PersonalDataRepository {
+  // Read in one transaction, so the parts agree with each other; null when the user is unknown
+  findByUser(userId: string): Promise<PersonalData | null>;
+  // Deletes the account in one transaction. The users row stays, scrubbed to the tombstone, so
+  // what is kept still has an author; everything else about the user goes, except sessions,
+  // which are revoked and kept for the audit retention period, their picks in other users'
+  // draft leagues, and their entries in tournaments that have started. Private teams are deleted
+  // either way. False when there is no such account, or it is already deleted.
+  erase(
+    userId: string,
+    tombstone: AccountTombstone,
+    content: PublishedContent,
+    at: Date
+  ): Promise<boolean>;
+  // Deletes the sessions kept from accounts deleted before the cutoff
+  scrubDeletedBefore(cutoff: Date): Promise<number>;
+}
@@ -0,0 +1,59 @@
+import { PokemonMaster } from '../../domain/entity/PokemonMaster';
+import { Encounter } from '../../domain/valueobject/Encounter';
+import { FormMedia } from '../../domain/valueobject/FormMedia';
+import { GameVersion } from '../../domain/valueobject/GameVersion';
+import { FormId, SpeciesId } from '../../domain/valueobject/PokemonId';
+import { PokemonType, TypeSet } from '../../domain/valueobject/PokemonType';
+
+export class PokemonFormRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'PokemonFormRepositoryError';
+  }
+}
+
+export interface PokemonFormFilter {
+  // Only forms obtainable in this game
+  availableIn?: GameVersion;
+  // Only forms introduced in this generation or earlier
+  maxGeneration?: number;
+  // Only forms introduced in this generation
+  generation?: number;
+  // Only forms found in the wild in this game, optionally at one location
+  encounterGame?: GameVersion;
+  encounterLocation?: string;
+  // Only forms of species that do not evolve from another
+  firstStageOnly?: boolean;
+}
+
+export interface PokemonFormRepository {
+  findById(formId: FormId): Promise<PokemonMaster | null>;
+  findByIds(formIds: FormId[]): Promise<PokemonMaster[]>;
+  findBySpeciesId(speciesId: SpeciesId): Promise<PokemonMaster[]>;
+  findAll(filter?: PokemonFormFilter): Promise<PokemonMaster[]>;
+  // Up to `limit` forms with IDs greater than `after`, by form ID
+  findPage(filter: PokemonFormFilter, limit: number, after?: FormId): Promise<PokemonMaster[]>;
+  // Forms indexed under any of the keys (see NameKey), by form ID
+  findByNameKeys(keys: string[]): Promise<PokemonMaster[]>;
+  // Forms with any of the IDs or indexed under any of the keys, in one query, by form ID
+  findByIdsOrNameKeys(formIds: FormId[], keys: string[]): Promise<PokemonMaster[]>;
+  // Type order counts, as in the games: Water/Ground does not match Ground/Water, and a single
+  // type only matches single-typed forms
+  findByTypeset(typeset: TypeSet): Promise<PokemonMaster[]>;
+  // Either slot
+  findContainingType(type: PokemonType): Promise<PokemonMaster[]>;
+  // Forms with the ability (PokeAPI identifier) in any slot, hidden included
+  findByAbility(ability: string): Promise<PokemonMaster[]>;
+  // Also rewrites the form's name index entries
+  save(form: PokemonMaster): Promise<void>;
+  // Recomputes every form's name index entries; returns the number of forms indexed
+  rebuildNameIndex(): Promise<number>;
+  // Locations with at least one encounter in the game, sorted by name
+  findEncounterLocations(gameVersion: GameVersion): Promise<string[]>;
+  // Replaces the form's encounters
+  saveEncounters(formId: FormId, encounters: Encounter[]): Promise<void>;
+  // Null until the form's media was first synced
+  findMedia(formId: FormId): Promise<FormMedia | null>;
+  // Replaces the form's media
+  saveMedia(formId: FormId, media: FormMedia): Promise<void>;
+}
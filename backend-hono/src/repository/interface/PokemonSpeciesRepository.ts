@@ -0,0 +1,31 @@
+import { PokemonSpecies } from '../../domain/entity/PokemonSpecies';
+import { RegionalDexNumber } from '../../domain/valueobject/DexNumber';
+import { EggGroup } from '../../domain/valueobject/EggGroup';
+import { Learnset, LearnMethod } from '../../domain/valueobject/Learnset';
+import { FormId, SpeciesId } from '../../domain/valueobject/PokemonId';
+
+export class PokemonSpeciesRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'PokemonSpeciesRepositoryError';
+  }
+}
+
+export interface MoveLearner {
+  speciesId: SpeciesId;
+  formId: FormId;
+  method: LearnMethod;
+}
+
+export interface PokemonSpeciesRepository {
+  findById(speciesId: SpeciesId): Promise<PokemonSpecies | null>;
+  findByIds(speciesIds: SpeciesId[]): Promise<PokemonSpecies[]>;
+  findByRegionalDexNumber(dexNumber: RegionalDexNumber): Promise<PokemonSpecies | null>;
+  findByEggGroups(groups: EggGroup[]): Promise<PokemonSpecies[]>;
+  findLearnset(speciesId: SpeciesId): Promise<Learnset[]>;
+  // Learnsets of several species in one query; species without any are left out
+  findLearnsets(speciesIds: SpeciesId[]): Promise<Map<SpeciesId, Learnset[]>>;
+  findLearnersOfMove(moveName: string): Promise<MoveLearner[]>;
+  save(species: PokemonSpecies): Promise<void>;
+  saveLearnset(learnset: Learnset): Promise<void>;
+}
@@ -10,6 +10,14 @@ export class RefreshTokenRepositoryError extends Error {
 export interface RefreshTokenRepository {
   save(token: RefreshToken): Promise<void>;
   findByHash(tokenHash: string): Promise<RefreshToken | null>;
+  // The unrevoked, unexpired tokens of the user, one per active session, most recently used
+  // first
+  findActiveByUser(userId: string): Promise<RefreshToken[]>;
+  updateLastUsed(tokenId: string, lastUsedAt: Date): Promise<void>;
   revoke(tokenId: string): Promise<void>;
+  // Returns false when the user has no active session with the ID
+  revokeSession(userId: string, sessionId: string): Promise<boolean>;
+  // Revokes every active session of the user except the one given; returns how many were revoked
+  revokeOtherSessions(userId: string, keepSessionId?: string): Promise<number>;
   cleanupExpired(): Promise<void>;
 }
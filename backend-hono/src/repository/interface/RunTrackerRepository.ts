@@ -0,0 +1,17 @@
+import { RunTracker } from '../../domain/entity/RunTracker';
+
+export class RunTrackerRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'RunTrackerRepositoryError';
+  }
+}
+
+export interface RunTrackerRepository {
+  findById(runId: string): Promise<RunTracker | null>;
+  // Newest first
+  findByOwner(ownerId: string): Promise<RunTracker[]>;
+  // Inserts or replaces the run and its used species
+  save(run: RunTracker): Promise<void>;
+  delete(runId: string): Promise<void>;
+}
@@ -0,0 +1,49 @@
+import { z } from 'zod';
+
+export class SearchIndexError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'SearchIndexError';
+  }
+}
+
+export const SearchDocumentKindSchema = z.enum(['species', 'move', 'ability']);
+
+export type SearchDocumentKind = z.infer<typeof SearchDocumentKindSchema>;
+
+// One searchable entry per species, move or ability, keyed by kind and PokeAPI ID
+export interface SearchDocument {
+  kind: SearchDocumentKind;
+  id: number;
+  name: string;
+  nameJp?: string;
+  // Free text to search: Pokédex entries for species, effect text for moves and abilities
+  text: string[];
+}
+
+export interface SearchHit {
+  kind: SearchDocumentKind;
+  id: number;
+  name: string;
+  nameJp?: string;
+  // The part of the text that matched, when the match was not in the name
+  snippet?: string;
+}
+
+export interface SearchPage {
+  hits: SearchHit[];
+  // Matches across all pages; may be an estimate
+  total: number;
+}
+
+export interface SearchIndex {
+  // Replaces documents with the same kind and ID
+  upsert(documents: SearchDocument[]): Promise<void>;
+  // Ranked by relevance; typo-tolerant where the backend supports it
+  search(
+    query: string,
+    kinds: SearchDocumentKind[],
+    limit: number,
+    offset: number
+  ): Promise<SearchPage>;
+}
@@ -0,0 +1,24 @@
+import { TeamComment } from '../../domain/entity/TeamComment';
+
+export class TeamCommentRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TeamCommentRepositoryError';
+  }
+}
+
+// A comment with its author's current username, for display
+export interface AuthoredComment {
+  comment: TeamComment;
+  authorName: string;
+}
+
+export interface TeamCommentRepository {
+  findById(commentId: string): Promise<AuthoredComment | null>;
+  // Comments that start a thread, oldest first
+  findThreadsByTeam(teamId: string, limit: number, offset: number): Promise<AuthoredComment[]>;
+  countThreadsByTeam(teamId: string): Promise<number>;
+  // Replies in the given threads, oldest first
+  findReplies(parentIds: string[]): Promise<AuthoredComment[]>;
+  save(comment: TeamComment): Promise<void>;
+}
@@ -0,0 +1,27 @@
+import { TeamEventType } from '../../domain/event/TeamEvent';
+
+export class TeamEventOutboxRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TeamEventOutboxRepositoryError';
+  }
+}
+
+// A team event waiting to be published. Only the team's ID is kept: the relay publishes the
+// team as it is when the event goes out.
+export interface OutboxEntry {
+  eventId: string;
+  type: TeamEventType;
+  teamId: string;
+  occurredAt: Date;
+}
+
+// Entries are written by TeamRepository.save, in the transaction that saves the team
+export interface TeamEventOutboxRepository {
+  // Up to `limit` unpublished entries that no other relay holds, oldest first. They are held for
+  // `leaseMs`; entries not marked published by then are handed out again.
+  claim(limit: number, leaseMs: number): Promise<OutboxEntry[]>;
+  markPublished(eventIds: string[]): Promise<void>;
+  // Returns the number of entries removed
+  deletePublishedBefore(cutoff: Date): Promise<number>;
+}
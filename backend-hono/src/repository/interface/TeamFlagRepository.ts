@@ -0,0 +1,25 @@
+import { TeamFlag, TeamFlagReason } from '../../domain/entity/TeamFlag';
+
+export class TeamFlagRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TeamFlagRepositoryError';
+  }
+}
+
+// The flags on one team, for moderators
+export interface TeamFlagSummary {
+  teamId: string;
+  flags: number;
+  reasons: Partial<Record<TeamFlagReason, number>>;
+  lastFlaggedAt: Date;
+}
+
+export interface TeamFlagRepository {
+  // Replaces the user's earlier flag on the team, if any
+  save(flag: TeamFlag): Promise<void>;
+  // Flagged teams, most flags first
+  findSummaries(limit: number, offset: number): Promise<TeamFlagSummary[]>;
+  // Clears a team's flags once a moderator has reviewed it; returns how many were removed
+  deleteByTeam(teamId: string): Promise<number>;
+}
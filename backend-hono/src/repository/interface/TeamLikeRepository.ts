@@ -0,0 +1,18 @@
+import { TeamLike } from '../../domain/entity/TeamLike';
+
+export class TeamLikeRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TeamLikeRepositoryError';
+  }
+}
+
+export interface TeamLikeRepository {
+  // Returns false when the user already likes the team
+  save(like: TeamLike): Promise<boolean>;
+  // Returns false when the user did not like the team
+  delete(teamId: string, userId: string): Promise<boolean>;
+  countByTeam(teamId: string): Promise<number>;
+  // The teams among `teamIds` the user likes
+  findLikedTeamIds(userId: string, teamIds: string[]): Promise<string[]>;
+}
@@ -0,0 +1,103 @@
+import { Team } from '../../domain/entity/Team';
+import { TeamEvent } from '../../domain/event/TeamEvent';
+import { FormatId } from '../../domain/valueobject/Format';
+
+export class TeamRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TeamRepositoryError';
+  }
+}
+
+// The team was saved by someone else since it was read. Carries the stored team, or null when
+// it no longer exists, so callers can show the user what changed.
+export class ConflictError extends Error {
+  readonly code = 'TEAM_VERSION_CONFLICT';
+
+  constructor(
+    public readonly teamId: string,
+    public readonly current: Team | null
+  ) {
+    super(`Team ${teamId} was changed by another request`);
+    this.name = 'ConflictError';
+  }
+}
+
+// Narrows a page of teams; a team must match every filter given
+export interface SharedTeamFilter {
+  // Any form of the species counts
+  speciesId?: number;
+}
+
+export interface TeamFilter extends SharedTeamFilter {
+  // A normalized TeamTag value
+  tag?: string;
+  folder?: string;
+}
+
+// A page of the gallery: shared teams outside the trash
+export interface GalleryQuery extends SharedTeamFilter {
+  format?: FormatId;
+  // Most liked first breaks ties by recency
+  sort: 'recent' | 'likes';
+  // Teams flagged by at least this many users are left out
+  hideAtFlags: number;
+  limit: number;
+  // The last team of the previous page; keyset pagination keeps deep pages fast and stops
+  // teams shared in the meantime from shifting the next page
+  after?: GalleryPosition;
+}
+
+// Where a gallery page ended, in the query's sort order
+export interface GalleryPosition {
+  teamId: string;
+  sharedAt: Date;
+  // Only compared when sorting by likes
+  likes: number;
+}
+
+export interface GalleryTeam {
+  team: Team;
+  likes: number;
+}
+
+// How many of the owner's teams (outside the trash) carry a tag or sit in a folder
+export interface TeamTagCount {
+  tag: string;
+  teams: number;
+}
+
+export interface TeamFolderCount {
+  folder: string;
+  teams: number;
+}
+
+export interface TeamRepository {
+  // Includes teams in the trash
+  findById(teamId: string): Promise<Team | null>;
+  // Includes teams in the trash; IDs that do not exist are left out
+  findByIds(teamIds: string[]): Promise<Team[]>;
+  // Keyset pagination in team ID order; pass the last ID of the previous page as `after`
+  findPageByOwner(
+    ownerId: string,
+    limit: number,
+    after?: string,
+    filter?: TeamFilter
+  ): Promise<Team[]>;
+  findGalleryPage(query: GalleryQuery): Promise<GalleryTeam[]>;
+  // In tag order
+  countTagsByOwner(ownerId: string): Promise<TeamTagCount[]>;
+  // In folder order
+  countFoldersByOwner(ownerId: string): Promise<TeamFolderCount[]>;
+  // Teams in the owner's trash, most recently deleted first
+  findDeletedByOwner(ownerId: string): Promise<Team[]>;
+  // Inserts or replaces the team, its members and tags, then bumps the team's version. Fails with
+  // a ConflictError when the stored version is no longer the one the team was read at. `events`
+  // go to the outbox in the same transaction, so they are published exactly when the save sticks.
+  save(team: Team, events?: TeamEvent[]): Promise<void>;
+  // Writes the tags and folder of existing teams in one transaction and bumps their versions.
+  // Nothing is written when any of them fails the version check (a ConflictError for the first).
+  saveOrganization(teams: Team[], events?: TeamEvent[]): Promise<void>;
+  // Permanently removes teams deleted before the cutoff and returns how many were removed
+  purgeDeletedBefore(cutoff: Date): Promise<number>;
+}
@@ -0,0 +1,19 @@
+import { Tournament, TournamentStatus } from '../../domain/entity/Tournament';
+
+export class TournamentRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TournamentRepositoryError';
+  }
+}
+
+export interface TournamentRepository {
+  findById(tournamentId: string): Promise<Tournament | null>;
+  // Newest first, optionally only those with the given status
+  findPage(status: TournamentStatus | null, limit: number, offset: number): Promise<Tournament[]>;
+  count(status: TournamentStatus | null): Promise<number>;
+  // Writes the tournament with its entries and bracket as the next version. Returns false
+  // without writing anything when the stored version is no longer the one it was read at.
+  save(tournament: Tournament): Promise<boolean>;
+  delete(tournamentId: string): Promise<void>;
+}
@@ -0,0 +1,15 @@
+import { TypeQuizScore } from '../../domain/entity/TypeQuizScore';
+
+export class TypeQuizRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'TypeQuizRepositoryError';
+  }
+}
+
+export interface TypeQuizRepository {
+  findScore(userId: string): Promise<TypeQuizScore | null>;
+  // Records the answer and saves the updated score together. Returns false, saving nothing, when
+  // the user has already answered the question.
+  saveAnswer(score: TypeQuizScore, questionId: number, correct: boolean): Promise<boolean>;
+}
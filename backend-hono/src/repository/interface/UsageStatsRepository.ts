@@ -0,0 +1,17 @@
+import { PokemonUsage, UsageSnapshot } from '../../domain/entity/UsageSnapshot';
+import { FormatId } from '../../domain/valueobject/Format';
+import { FormId } from '../../domain/valueobject/PokemonId';
+
+export class UsageStatsRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'UsageStatsRepositoryError';
+  }
+}
+
+export interface UsageStatsRepository {
+  saveSnapshot(snapshot: UsageSnapshot): Promise<void>;
+  findLatestPeriod(formatId: FormatId): Promise<string | null>;
+  findTop(formatId: FormatId, period: string, limit: number): Promise<PokemonUsage[]>;
+  findByFormId(formatId: FormatId, period: string, formId: FormId): Promise<PokemonUsage | null>;
+}
@@ -0,0 +1,69 @@
+export class UserDataArchiveRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'UserDataArchiveRepositoryError';
+  }
+}
+
+// Rows as stored, independent of the entities, so an archive restores exactly what was dumped
+
+export interface ArchivedUser {
+  userId: string;
+  username: string;
+  email: string;
+  passwordHash: string;
+  role: string;
+  createdAt: Date;
+  deletedAt: Date | null;
+}
+
+export interface ArchivedPreferences {
+  userId: string;
+  language: string | null;
+  format: string | null;
+  level: number;
+  theme: string;
+}
+
+export interface ArchivedTeamMember {
+  slot: number;
+  formId: number;
+  terastalType: string;
+  notes: string | null;
+}
+
+export interface ArchivedTeam {
+  teamId: string;
+  ownerId: string;
+  teamName: string;
+  members: ArchivedTeamMember[];
+  tags: string[];
+  folder: string | null;
+  notes: string | null;
+  createdAt: Date;
+  updatedAt: Date;
+  deletedAt: Date | null;
+  sharedAt: Date | null;
+  sharedFormat: string | null;
+}
+
+// Reads within one snapshot; each finder pages in primary key order, with the last key of the
+// previous page as `after`
+export interface UserDataSnapshot {
+  findUsers(limit: number, after?: string): Promise<ArchivedUser[]>;
+  findPreferences(limit: number, after?: string): Promise<ArchivedPreferences[]>;
+  // Teams in the trash included
+  findTeams(limit: number, after?: string): Promise<ArchivedTeam[]>;
+}
+
+// Whole-database dumps and restores of user data, for disaster recovery
+export interface UserDataArchiveRepository {
+  // Every read `read` makes sees the database as of one moment, however long it takes
+  withSnapshot<T>(read: (snapshot: UserDataSnapshot) => Promise<T>): Promise<T>;
+  // Each call is one transaction. Rows whose key (or username or email) is taken are skipped, so
+  // a restore can be run again after a failure; return how many rows were written.
+  restoreUsers(users: ArchivedUser[]): Promise<number>;
+  restorePreferences(preferences: ArchivedPreferences[]): Promise<number>;
+  // Teams of owners that do not exist are skipped too
+  restoreTeams(teams: ArchivedTeam[]): Promise<number>;
+}
@@ -0,0 +1,14 @@
+import { UserPreferences } from '../../domain/entity/UserPreferences';
+
+export class UserPreferencesRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'UserPreferencesRepositoryError';
+  }
+}
+
+export interface UserPreferencesRepository {
+  // Null for users who never saved a preference
+  findByUser(userId: string): Promise<UserPreferences | null>;
+  save(preferences: UserPreferences): Promise<void>;
+}
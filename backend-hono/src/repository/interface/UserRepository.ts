@@ -7,11 +7,28 @@ export class UserRepositoryError extends Error {
   }
 }
 
+// Thrown by save and updateEmail when the email or username is taken. Registration relies on the
+// unique constraints instead of checking first, so two concurrent sign-ups cannot both succeed.
+export class EmailAlreadyExistsError extends Error {
+  constructor() {
+    super('Email already exists');
+    this.name = 'EmailAlreadyExistsError';
+  }
+}
+
+export class UsernameAlreadyExistsError extends Error {
+  constructor() {
+    super('Username already exists');
+    this.name = 'UsernameAlreadyExistsError';
+  }
+}
+
 export interface UserRepository {
+  // Fails with EmailAlreadyExistsError or UsernameAlreadyExistsError on a duplicate
   save(user: User): Promise<void>;
+  // Fails with EmailAlreadyExistsError when another account has the address
+  updateEmail(userId: string, email: string): Promise<void>;
   findById(userId: string): Promise<User | null>;
   findByEmail(email: string): Promise<User | null>;
   findByUsername(username: string): Promise<User | null>;
-  existsByEmail(email: string): Promise<boolean>;
-  existsByUsername(username: string): Promise<boolean>;
 }
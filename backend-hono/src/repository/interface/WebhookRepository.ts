@@ -0,0 +1,33 @@
+import { Webhook } from '../../domain/entity/Webhook';
+import { TeamEventType } from '../../domain/event/TeamEvent';
+
+export class WebhookRepositoryError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'WebhookRepositoryError';
+  }
+}
+
+export interface WebhookDelivery {
+  deliveryId: string;
+  webhookId: string;
+  eventId: string;
+  eventType: TeamEventType;
+  attempt: number;
+  statusCode?: number;
+  error?: string;
+  succeeded: boolean;
+  attemptedAt: Date;
+}
+
+export interface WebhookRepository {
+  findById(webhookId: string): Promise<Webhook | null>;
+  // Oldest first
+  findByOwner(ownerId: string): Promise<Webhook[]>;
+  save(webhook: Webhook): Promise<void>;
+  // Also removes the webhook's delivery log
+  delete(webhookId: string): Promise<void>;
+  saveDelivery(delivery: WebhookDelivery): Promise<void>;
+  // Newest first
+  findDeliveries(webhookId: string, limit: number): Promise<WebhookDelivery[]>;
+}
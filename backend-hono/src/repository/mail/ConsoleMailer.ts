@@ -0,0 +1,8 @@
+import { Mailer, MailMessage } from '../interface/Mailer';
+
+// Local development: mails are printed instead of sent, links and tokens included
+export class ConsoleMailer implements Mailer {
+  async send(message: MailMessage): Promise<void> {
+    console.log(`[mail] To: ${message.to}\nSubject: ${message.subject}\n\n${message.text}`);
+  }
+}
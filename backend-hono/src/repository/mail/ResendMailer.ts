@@ -0,0 +1,34 @@
+import { Mailer, MailerError, MailMessage } from '../interface/Mailer';
+
+const RESEND_API_URL = 'https://api.resend.com/emails';
+
+// Resend's HTTP API works from Workers, which cannot open SMTP connections
+export class ResendMailer implements Mailer {
+  constructor(
+    private readonly apiKey: string,
+    private readonly from: string
+  ) {}
+
+  async send(message: MailMessage): Promise<void> {
+    try {
+      const response = await fetch(RESEND_API_URL, {
+        method: 'POST',
+        headers: {
+          'Content-Type': 'application/json',
+          Authorization: `Bearer ${this.apiKey}`,
+        },
+        body: JSON.stringify({
+          from: this.from,
+          to: [message.to],
+          subject: message.subject,
+          text: message.text,
+        }),
+      });
+      if (!response.ok) {
+        throw new Error(`Resend returned ${response.status}`);
+      }
+    } catch (error) {
+      throw new MailerError(`Failed to send mail: ${error}`);
+    }
+  }
+}
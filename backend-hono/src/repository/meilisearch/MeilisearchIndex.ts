@@ -0,0 +1,139 @@
+import {
+  SearchDocument,
+  SearchDocumentKind,
+  SearchDocumentKindSchema,
+  SearchHit,
+  SearchIndex,
+  SearchIndexError,
+  SearchPage,
+} from '../interface/SearchIndex';
+
+const DEFAULT_INDEX_UID = 'pokedict';
+
+// Wraps matched words in the formatted text, so the entry that matched can be picked out.
+// Control characters cannot appear in the indexed text.
+const MATCH_START = '\u0002';
+const MATCH_END = '\u0003';
+
+// Words of context kept around a match in a snippet
+const SNIPPET_WORDS = 24;
+
+interface MeilisearchDocument {
+  id: string; // e.g. species-25; Meilisearch IDs only allow letters, digits, - and _
+  kind: SearchDocumentKind;
+  entry_id: number;
+  name: string;
+  name_jp: string | null;
+  text: string[];
+}
+
+interface MeilisearchSearchResponse {
+  hits: (MeilisearchDocument & { _formatted?: { text?: string[] } })[];
+  estimatedTotalHits: number;
+}
+
+export class MeilisearchIndex implements SearchIndex {
+  private configured = false;
+
+  constructor(
+    private readonly url: string,
+    private readonly apiKey?: string,
+    private readonly indexUid: string = DEFAULT_INDEX_UID
+  ) {}
+
+  async upsert(documents: SearchDocument[]): Promise<void> {
+    if (documents.length === 0) {
+      return;
+    }
+
+    try {
+      await this.configure();
+      // Meilisearch applies writes as queued tasks, in order; the documents are searchable once
+      // the task is processed
+      await this.request(
+        `/indexes/${this.indexUid}/documents?primaryKey=id`,
+        'POST',
+        documents.map((document) => this.toMeilisearchDocument(document))
+      );
+    } catch (error) {
+      throw new SearchIndexError(`Failed to index documents: ${error}`);
+    }
+  }
+
+  async search(
+    query: string,
+    kinds: SearchDocumentKind[],
+    limit: number,
+    offset: number
+  ): Promise<SearchPage> {
+    try {
+      const response = (await this.request(`/indexes/${this.indexUid}/search`, 'POST', {
+        q: query,
+        limit,
+        offset,
+        filter: kinds.length > 0 ? `kind IN [${kinds.join(', ')}]` : undefined,
+        attributesToHighlight: ['text'],
+        highlightPreTag: MATCH_START,
+        highlightPostTag: MATCH_END,
+        attributesToCrop: ['text'],
+        cropLength: SNIPPET_WORDS,
+      })) as MeilisearchSearchResponse;
+
+      return {
+        hits: response.hits.map((hit) => this.toHit(hit, hit._formatted?.text ?? [])),
+        total: response.estimatedTotalHits,
+      };
+    } catch (error) {
+      throw new SearchIndexError(`Failed to search: ${error}`);
+    }
+  }
+
+  // Only `kind` is filtered on; names rank above free text
+  private async configure(): Promise<void> {
+    if (this.configured) {
+      return;
+    }
+    await this.request(`/indexes/${this.indexUid}/settings`, 'PATCH', {
+      filterableAttributes: ['kind'],
+      searchableAttributes: ['name', 'name_jp', 'text'],
+    });
+    this.configured = true;
+  }
+
+  private async request(path: string, method: string, body: unknown) {
+    const response = await fetch(`${this.url.replace(/\/$/, '')}${path}`, {
+      method,
+      headers: {
+        'Content-Type': 'application/json',
+        ...(this.apiKey ? { Authorization: `Bearer ${this.apiKey}` } : {}),
+      },
+      body: JSON.stringify(body),
+    });
+    if (!response.ok) {
+      throw new Error(`Meilisearch ${method} ${path} returned ${response.status}`);
+    }
+    return response.json();
+  }
+
+  private toMeilisearchDocument(document: SearchDocument): MeilisearchDocument {
+    return {
+      id: `${document.kind}-${document.id}`,
+      kind: document.kind,
+      entry_id: document.id,
+      name: document.name,
+      name_jp: document.nameJp ?? null,
+      text: document.text,
+    };
+  }
+
+  private toHit(document: MeilisearchDocument, formattedText: string[]): SearchHit {
+    const matched = formattedText.find((text) => text.includes(MATCH_START));
+    return {
+      kind: SearchDocumentKindSchema.parse(document.kind),
+      id: document.entry_id,
+      name: document.name,
+      nameJp: document.name_jp ?? undefined,
+      snippet: matched?.replaceAll(MATCH_START, '').replaceAll(MATCH_END, ''),
+    };
+  }
+}
@@ -0,0 +1,198 @@
+const POKEAPI_BASE_URL = 'https://pokeapi.co/api/v2';
+
+export class PokeApiError extends Error {
+  constructor(
+    message: string,
+    public readonly status?: number
+  ) {
+    super(message);
+    this.name = 'PokeApiError';
+  }
+}
+
+interface NamedResource {
+  name: string;
+  url: string;
+}
+
+export interface PokeApiSpecies {
+  id: number;
+  name: string;
+  names: { name: string; language: NamedResource }[];
+  gender_rate: number;
+  capture_rate: number;
+  is_legendary: boolean;
+  is_mythical: boolean;
+  egg_groups: NamedResource[];
+  generation: NamedResource;
+  evolves_from_species: NamedResource | null;
+  pokedex_numbers: { entry_number: number; pokedex: NamedResource }[];
+  varieties: { is_default: boolean; pokemon: NamedResource }[];
+  // Pokédex entries, one per game; oldest first
+  flavor_text_entries: { flavor_text: string; language: NamedResource; version: NamedResource }[];
+}
+
+export interface PokeApiPokemon {
+  id: number;
+  name: string;
+  base_experience: number | null;
+  height: number;
+  weight: number;
+  is_default: boolean;
+  types: { slot: number; type: NamedResource }[];
+  // Slot 3 is the hidden ability
+  abilities: { ability: NamedResource; is_hidden: boolean; slot: number }[];
+  stats: { base_stat: number; stat: NamedResource }[];
+  sprites: {
+    front_default: string | null;
+    front_shiny: string | null;
+    other?: {
+      // Animated sprites
+      showdown?: { front_default: string | null; front_shiny: string | null };
+      // Renders of the Pokémon HOME 3D models
+      home?: { front_default: string | null; front_shiny: string | null };
+    };
+  };
+  // Ogg Vorbis; missing from older PokeAPI data
+  cries?: { latest: string | null; legacy: string | null };
+  moves: {
+    move: NamedResource;
+    version_group_details: {
+      level_learned_at: number;
+      move_learn_method: NamedResource;
+      version_group: NamedResource;
+    }[];
+  }[];
+}
+
+export interface PokeApiEncounter {
+  location_area: NamedResource;
+  version_details: { version: NamedResource }[];
+}
+
+export interface PokeApiMove {
+  id: number;
+  name: string;
+  names: { name: string; language: NamedResource }[];
+  type: NamedResource;
+  damage_class: NamedResource;
+  power: number | null;
+  accuracy: number | null;
+  priority: number;
+  pp: number | null;
+  target: NamedResource;
+  meta: {
+    min_hits: number | null;
+    max_hits: number | null;
+    ailment: NamedResource;
+    // e.g. "damage+lower" or "net-good-stats"; says whose stats the changes apply to
+    category: NamedResource;
+    // Percent of the damage dealt; negative for recoil
+    drain: number;
+    healing: number;
+    ailment_chance: number;
+    flinch_chance: number;
+    stat_chance: number;
+  } | null;
+  stat_changes: { change: number; stat: NamedResource }[];
+  effect_chance: number | null;
+  effect_entries: { short_effect: string; language: NamedResource }[];
+}
+
+export interface PokeApiAbility {
+  id: number;
+  name: string;
+  names: { name: string; language: NamedResource }[];
+  effect_entries: { short_effect: string; language: NamedResource }[];
+  // Oldest version group first
+  flavor_text_entries: { flavor_text: string; language: NamedResource }[];
+}
+
+export interface PokeApiItem {
+  id: number;
+  name: string;
+  names: { name: string; language: NamedResource }[];
+  category: NamedResource;
+  effect_entries: { short_effect: string; language: NamedResource }[];
+  // Oldest version group first
+  flavor_text_entries: { text: string; language: NamedResource }[];
+}
+
+const MEDIA_PROBE_BYTES = 16;
+
+export interface MediaProbe {
+  contentType: string | null;
+  head: Uint8Array;
+}
+
+export interface MediaFile {
+  contentType: string | null;
+  body: ArrayBuffer;
+}
+
+export class PokeApiClient {
+  constructor(private readonly baseUrl: string = POKEAPI_BASE_URL) {}
+
+  async getSpecies(idOrName: number | string): Promise<PokeApiSpecies> {
+    return this.get<PokeApiSpecies>(`/pokemon-species/${idOrName}`);
+  }
+
+  async getPokemon(idOrName: number | string): Promise<PokeApiPokemon> {
+    return this.get<PokeApiPokemon>(`/pokemon/${idOrName}`);
+  }
+
+  async getEncounters(pokemonId: number): Promise<PokeApiEncounter[]> {
+    return this.get<PokeApiEncounter[]>(`/pokemon/${pokemonId}/encounters`);
+  }
+
+  async getMove(idOrName: number | string): Promise<PokeApiMove> {
+    return this.get<PokeApiMove>(`/move/${idOrName}`);
+  }
+
+  async getAbility(idOrName: number | string): Promise<PokeApiAbility> {
+    return this.get<PokeApiAbility>(`/ability/${idOrName}`);
+  }
+
+  async getItem(idOrName: number | string): Promise<PokeApiItem> {
+    return this.get<PokeApiItem>(`/item/${idOrName}`);
+  }
+
+  // The declared content type and first bytes of a cry or sprite, for checking what it is;
+  // null when the host does not have it
+  async probeMedia(url: string): Promise<MediaProbe | null> {
+    const response = await fetch(url, { headers: { Range: `bytes=0-${MEDIA_PROBE_BYTES - 1}` } });
+    if (response.status === 404) {
+      return null;
+    }
+    if (!response.ok) {
+      throw new PokeApiError(`Media request failed: ${url}`, response.status);
+    }
+    const head = new Uint8Array(await response.arrayBuffer()).slice(0, MEDIA_PROBE_BYTES);
+    return { contentType: response.headers.get('Content-Type'), head };
+  }
+
+  // The whole asset, for the media proxy; assets larger than maxBytes are refused
+  async fetchMedia(url: string, maxBytes: number): Promise<MediaFile> {
+    const response = await fetch(url);
+    if (!response.ok) {
+      throw new PokeApiError(`Media request failed: ${url}`, response.status);
+    }
+    const length = Number(response.headers.get('Content-Length') ?? 0);
+    if (length > maxBytes) {
+      throw new PokeApiError(`Media too large: ${url}`);
+    }
+    const body = await response.arrayBuffer();
+    if (body.byteLength > maxBytes) {
+      throw new PokeApiError(`Media too large: ${url}`);
+    }
+    return { contentType: response.headers.get('Content-Type'), body };
+  }
+
+  private async get<T>(path: string): Promise<T> {
+    const response = await fetch(`${this.baseUrl}${path}`);
+    if (!response.ok) {
+      throw new PokeApiError(`PokeAPI request failed: ${path}`, response.status);
+    }
+    return (await response.json()) as T;
+  }
+}
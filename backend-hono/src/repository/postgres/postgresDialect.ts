@@ -0,0 +1,15 @@
+import { ilike } from 'drizzle-orm';
+import postgres from 'postgres';
+import { Dialect } from '../drizzle/types';
+
+export const postgresDialect: Dialect = {
+  // LIKE escapes with a backslash by default
+  like: (column, pattern) => ilike(column, pattern),
+  readSnapshot: (db, read) =>
+    db.transaction(read, { isolationLevel: 'repeatable read', accessMode: 'read only' }),
+  // 23505 is unique_violation; the constraint name says which column clashed
+  uniqueViolation: (error) =>
+    error instanceof postgres.PostgresError && error.code === '23505'
+      ? (error.constraint_name ?? '')
+      : null,
+};
@@ -0,0 +1,15 @@
+import { LibsqlError } from '@libsql/client';
+import { sql } from 'drizzle-orm';
+import { Dialect } from '../drizzle/types';
+
+// e.g. "SQLITE_CONSTRAINT_UNIQUE: UNIQUE constraint failed: users.email"
+const UNIQUE_VIOLATION = /UNIQUE constraint failed: ([\w.]+)/;
+
+export const sqliteDialect: Dialect = {
+  // SQLite's LIKE already ignores ASCII case but has no default escape character
+  like: (column, pattern) => sql`${column} like ${pattern} escape '\\'`,
+  // SQLite transactions are serializable, so the first read pins the snapshot
+  readSnapshot: (db, read) => db.transaction(read),
+  uniqueViolation: (error) =>
+    error instanceof LibsqlError ? (UNIQUE_VIOLATION.exec(error.message)?.[1] ?? null) : null,
+};
@@ -0,0 +1,115 @@
+// Disaster recovery: dumps every account, preference and team to a gzipped archive, and restores
+// one into the database at DATABASE_URL
+// Usage: npm run backup -- export backup.ndjson.gz [--with-password-hashes]
+//        npm run backup -- restore backup.ndjson.gz
+
+import 'dotenv/config';
+import { once } from 'node:events';
+import { createReadStream, createWriteStream } from 'node:fs';
+import { createInterface } from 'node:readline';
+import { finished } from 'node:stream/promises';
+import { parseArgs } from 'node:util';
+import { createGunzip, createGzip } from 'node:zlib';
+import { env } from '../config/env';
+import { createRepositories } from '../repository/createRepositories';
+import { UserDataArchiveError, UserDataArchiveService } from '../usecase/UserDataArchiveService';
+
+const USAGE = `Usage: backup <command> <file> [options]
+
+Commands:
+  export <file>             Write a gzipped archive of every user, preference and team
+      --with-password-hashes  Keep password hashes, so restored accounts can log in as before
+                            (without them they stay locked until a password reset)
+  restore <file>            Restore an archive; rows that already exist are left untouched
+
+Options:
+  --help, -h                Show this help`;
+
+class UsageError extends Error {}
+
+async function exportArchive(
+  service: UserDataArchiveService,
+  file: string,
+  includePasswordHashes: boolean
+): Promise<void> {
+  const gzip = createGzip();
+  const output = gzip.pipe(createWriteStream(file, { mode: 0o600 }));
+  const counts = await service.exportArchive(
+    async (line) => {
+      if (!gzip.write(`${line}\n`)) {
+        await once(gzip, 'drain');
+      }
+    },
+    { includePasswordHashes }
+  );
+  gzip.end();
+  await finished(output);
+  console.log(
+    `Exported ${counts.users} users, ${counts.preferences} preferences and ${counts.teams} teams` +
+      ` to ${file}${includePasswordHashes ? ' (with password hashes)' : ''}`
+  );
+}
+
+async function restoreArchive(service: UserDataArchiveService, file: string): Promise<void> {
+  const lines = createInterface({
+    input: createReadStream(file).pipe(createGunzip()),
+    crlfDelay: Infinity,
+  });
+  const { read, restored } = await service.restore(lines);
+  console.log(
+    `Restored ${restored.users}/${read.users} users, ${restored.preferences}/${read.preferences}` +
+      ` preferences and ${restored.teams}/${read.teams} teams from ${file}`
+  );
+}
+
+async function main() {
+  let parsed;
+  try {
+    parsed = parseArgs({
+      args: process.argv.slice(2),
+      allowPositionals: true,
+      options: {
+        'with-password-hashes': { type: 'boolean' },
+        help: { type: 'boolean', short: 'h' },
+      },
+    });
+  } catch (error) {
+    throw new UsageError((error as Error).message);
+  }
+  const { values, positionals } = parsed;
+  const [command, file] = positionals;
+  if (values.help || !command) {
+    console.log(USAGE);
+    process.exit(0);
+  }
+  if (!file) {
+    throw new UsageError(`Missing file for "${command}"`);
+  }
+
+  const repositories = await createRepositories(env.DATABASE_URL);
+  const service = new UserDataArchiveService(repositories.userDataArchiveRepository);
+  switch (command) {
+    case 'export':
+      await exportArchive(service, file, values['with-password-hashes'] ?? false);
+      break;
+    case 'restore':
+      await restoreArchive(service, file);
+      break;
+    default:
+      throw new UsageError(`Unknown command "${command}"`);
+  }
+  process.exit(0);
+}
+
+main().catch((error) => {
+  if (error instanceof UsageError) {
+    console.error(`${error.message}\n\n${USAGE}`);
+    process.exit(2);
+  }
+  if (error instanceof UserDataArchiveError) {
+    console.error(error.message);
+    process.exit(1);
+  }
+  console.error('Backup failed:', error);
+  process.exit(1);
+});
@@ -0,0 +1,170 @@
+// Benchmarks for the type and damage hot paths, run against the bundled Gen 1 dataset so no
+// database is needed
+// Usage: npm run bench [-- <name filter>]
+//
+// Numbers are only comparable on the same machine; run before and after a change.
+
+import { performance } from 'node:perf_hooks';
+import { GEN1_SPECIES } from '../db/seed/gen1';
+import { Move } from '../domain/entity/Move';
+import { calculateDamage, Combatant } from '../domain/service/DamageCalculator';
+import { Level } from '../domain/valueobject/Level';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { getEffectiveness, POKEMON_TYPES } from '../domain/valueobject/PokemonType';
+import { EVStats } from '../domain/valueobject/Stats';
+import { toSeedForm } from '../usecase/SeedService';
+
+interface Benchmark {
+  name: string;
+  // Work units per run, e.g. damage calculations, for the per-item figure
+  items: number;
+  run: () => number;
+}
+
+const MIN_SAMPLE_MS = 500;
+
+const forms = GEN1_SPECIES.map(toSeedForm);
+
+const MOVES = [
+  new Move({
+    id: 89,
+    name: 'earthquake',
+    type: 'Ground',
+    damageClass: 'physical',
+    power: 100,
+    accuracy: 100,
+    priority: 0,
+    target: 'all-other-pokemon',
+  }),
+  new Move({
+    id: 85,
+    name: 'thunderbolt',
+    type: 'Electric',
+    damageClass: 'special',
+    power: 90,
+    accuracy: 100,
+    priority: 0,
+    target: 'selected-pokemon',
+  }),
+  new Move({
+    id: 350,
+    name: 'rock-blast',
+    type: 'Rock',
+    damageClass: 'physical',
+    power: 25,
+    accuracy: 90,
+    priority: 0,
+    target: 'selected-pokemon',
+    minHits: 2,
+    maxHits: 5,
+  }),
+];
+
+const combatants = forms.map(
+  (form) =>
+    new Combatant(
+      form,
+      PokemonBuild.create({
+        formId: form.getFormId(),
+        moves: MOVES.map((move) => move.getName()),
+        nature: 'Adamant',
+        ev: new EVStats(4, 252, 0, 0, 0, 252),
+      }),
+      Level.forMode('level50')
+    )
+);
+
+const BENCHMARKS: Benchmark[] = [
+  {
+    name: 'getEffectiveness (18x18 chart)',
+    items: POKEMON_TYPES.length ** 2,
+    run: () => {
+      let sum = 0;
+      for (const attack of POKEMON_TYPES) {
+        for (const defend of POKEMON_TYPES) {
+          sum += getEffectiveness(attack, defend);
+        }
+      }
+      return sum;
+    },
+  },
+  {
+    name: 'defendAgainstAll (full dex)',
+    items: forms.length,
+    run: () => {
+      let sum = 0;
+      for (const form of forms) {
+        sum += form.getTypeset().defendAgainstAll().Ground;
+      }
+      return sum;
+    },
+  },
+  {
+    // Which pair of attacking types hits the most of the dex super effectively
+    name: 'coverage scan (type pairs x dex)',
+    items: ((POKEMON_TYPES.length * (POKEMON_TYPES.length - 1)) / 2) * forms.length,
+    run: () => {
+      const typesets = forms.map((form) => form.getTypeset());
+      let best = 0;
+      POKEMON_TYPES.forEach((first, index) => {
+        for (const second of POKEMON_TYPES.slice(index + 1)) {
+          const hit = typesets.filter(
+            (typeset) => typeset.defendAgainst(first) > 1 || typeset.defendAgainst(second) > 1
+          ).length;
+          best = Math.max(best, hit);
+        }
+      });
+      return best;
+    },
+  },
+  {
+    name: 'calculateDamage (dex x dex x 3 moves)',
+    items: combatants.length ** 2 * MOVES.length,
+    run: () => {
+      let sum = 0;
+      for (const attacker of combatants) {
+        for (const defender of combatants) {
+          for (const move of MOVES) {
+            sum += calculateDamage(attacker, defender, move).max;
+          }
+        }
+      }
+      return sum;
+    },
+  },
+];
+
+// Runs the benchmark until MIN_SAMPLE_MS has passed, after one untimed warm-up run
+function measure(benchmark: Benchmark): { runs: number; msPerRun: number } {
+  let sink = benchmark.run();
+  let runs = 0;
+  const start = performance.now();
+  while (performance.now() - start < MIN_SAMPLE_MS) {
+    sink += benchmark.run();
+    runs++;
+  }
+  const elapsed = performance.now() - start;
+  // Keeps the results observable so the work can't be optimised away
+  if (Number.isNaN(sink)) {
+    throw new Error(`${benchmark.name} produced NaN`);
+  }
+  return { runs, msPerRun: elapsed / runs };
+}
+
+function main() {
+  const filter = process.argv[2]?.toLowerCase();
+  const selected = BENCHMARKS.filter(
+    (benchmark) => !filter || benchmark.name.toLowerCase().includes(filter)
+  );
+
+  console.log(`Node ${process.version}, ${forms.length} forms`);
+  for (const benchmark of selected) {
+    const { runs, msPerRun } = measure(benchmark);
+    const nsPerItem = (msPerRun * 1e6) / benchmark.items;
+    console.log(
+      `${benchmark.name.padEnd(40)} ${msPerRun.toFixed(3).padStart(10)} ms/run ${nsPerItem.toFixed(1).padStart(10)} ns/item (${runs} runs)`
+    );
+  }
+}
+
+main();
@@ -0,0 +1,95 @@
+// Concurrent registration check: fires two sign-ups for the same email (and then the same
+// username) at once against a fresh SQLite database, and asserts that exactly one succeeds while
+// the other gets the duplicate error
+// Usage: npm run check:registration
+//
+// AuthService does not look for an existing account first, so duplicates are only caught by the
+// unique constraints. This covers the mapping from the database's unique-violation error to
+// EMAIL_EXISTS / USERNAME_EXISTS.
+
+import { mkdtemp, rm } from 'node:fs/promises';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import { closeSqliteDb } from '../db/sqlite/connection';
+import { JwtService } from '../domain/valueobject/JWT';
+import { createRepositories } from '../repository/createRepositories';
+import { AuthError, AuthService, RegisterRequest } from '../usecase/AuthService';
+
+interface Case {
+  name: string;
+  requests: [RegisterRequest, RegisterRequest];
+  expectedCode: string;
+}
+
+const CASES: Case[] = [
+  {
+    name: 'same email',
+    requests: [
+      { username: 'racer_a', email: 'race@example.com', password: 'correct horse' },
+      { username: 'racer_b', email: 'race@example.com', password: 'correct horse' },
+    ],
+    expectedCode: 'EMAIL_EXISTS',
+  },
+  {
+    name: 'same username',
+    requests: [
+      { username: 'racer', email: 'racer1@example.com', password: 'correct horse' },
+      { username: 'racer', email: 'racer2@example.com', password: 'correct horse' },
+    ],
+    expectedCode: 'USERNAME_EXISTS',
+  },
+];
+
+async function checkCase(service: AuthService, testCase: Case, failures: string[]) {
+  const results = await Promise.allSettled(
+    testCase.requests.map((request) => service.register(request))
+  );
+
+  const succeeded = results.filter((result) => result.status === 'fulfilled');
+  if (succeeded.length !== 1) {
+    failures.push(`${testCase.name}: ${succeeded.length} of 2 registrations succeeded`);
+  }
+  for (const result of results) {
+    if (result.status === 'fulfilled') {
+      continue;
+    }
+    const error = result.reason;
+    if (!(error instanceof AuthError) || error.code !== testCase.expectedCode) {
+      failures.push(`${testCase.name}: expected ${testCase.expectedCode}, got ${error}`);
+    }
+  }
+}
+
+async function main() {
+  const dir = await mkdtemp(join(tmpdir(), 'pokedict-registration-'));
+  try {
+    const repositories = await createRepositories(`file:${join(dir, 'check.db')}`);
+    const service = new AuthService(
+      repositories.userRepository,
+      repositories.refreshTokenRepository,
+      repositories.loginFailureRepository,
+      new JwtService('check-concurrent-registration')
+    );
+
+    const failures: string[] = [];
+    for (const testCase of CASES) {
+      await checkCase(service, testCase, failures);
+    }
+
+    if (failures.length > 0) {
+      console.error(`Concurrent registration check failed (${failures.length}):`);
+      failures.forEach((failure) => console.error(`  ${failure}`));
+      process.exitCode = 1;
+      return;
+    }
+    console.log(`Concurrent registration OK: ${CASES.length} cases`);
+  } finally {
+    closeSqliteDb();
+    await rm(dir, { recursive: true, force: true });
+  }
+}
+
+main().catch((error) => {
+  console.error('Concurrent registration check failed:', error);
+  process.exit(1);
+});
@@ -0,0 +1,104 @@
+// Type chart check: compares TYPE_CHART with the canonical chart in fixtures/type-chart.csv
+// and asserts the invariants every matchup must satisfy
+// Usage: npm run check:typechart
+//
+// The whole input space (18 attacking types x 171 single and dual typings) is small enough
+// to check exhaustively, so every case is covered rather than a random sample.
+
+import { readFile } from 'node:fs/promises';
+import { getEffectiveness, POKEMON_TYPES, TypeSet } from '../domain/valueobject/PokemonType';
+
+const GOLDEN_CHART = new URL('../../fixtures/type-chart.csv', import.meta.url);
+
+const SINGLE_MULTIPLIERS = [0, 0.5, 1, 2];
+const DUAL_MULTIPLIERS = [0, 0.25, 0.5, 1, 2, 4];
+
+function allTypeSets(): TypeSet[] {
+  const typesets = POKEMON_TYPES.map((type) => new TypeSet(type));
+  POKEMON_TYPES.forEach((type1, index) => {
+    for (const type2 of POKEMON_TYPES.slice(index + 1)) {
+      typesets.push(new TypeSet(type1, type2));
+    }
+  });
+  return typesets;
+}
+
+async function checkGolden(failures: string[]): Promise<void> {
+  const [header, ...rows] = (await readFile(GOLDEN_CHART, 'utf8')).trim().split(/\r?\n/);
+
+  const columns = header.split(',').slice(1);
+  if (columns.join(',') !== POKEMON_TYPES.join(',')) {
+    failures.push(`Golden chart columns ${columns.join(',')} do not match POKEMON_TYPES`);
+    return;
+  }
+
+  const attacks = rows.map((row) => row.split(',')[0]);
+  if (attacks.join(',') !== POKEMON_TYPES.join(',')) {
+    failures.push(`Golden chart rows ${attacks.join(',')} do not match POKEMON_TYPES`);
+    return;
+  }
+
+  rows.forEach((row, index) => {
+    const attack = POKEMON_TYPES[index];
+    const cells = row.split(',').slice(1).map(Number);
+    POKEMON_TYPES.forEach((defend, column) => {
+      const actual = getEffectiveness(attack, defend);
+      if (actual !== cells[column]) {
+        failures.push(`${attack} -> ${defend}: chart has ${actual}x, golden has ${cells[column]}x`);
+      }
+    });
+  });
+}
+
+function checkInvariants(failures: string[]): void {
+  for (const attack of POKEMON_TYPES) {
+    for (const defend of POKEMON_TYPES) {
+      const multiplier = getEffectiveness(attack, defend);
+      if (!SINGLE_MULTIPLIERS.includes(multiplier)) {
+        failures.push(`${attack} -> ${defend}: ${multiplier}x is not a valid multiplier`);
+      }
+    }
+  }
+
+  for (const typeset of allTypeSets()) {
+    const all = typeset.defendAgainstAll();
+    for (const attack of POKEMON_TYPES) {
+      const singles = typeset.getTypes().map((type) => getEffectiveness(attack, type));
+      const multiplier = typeset.defendAgainst(attack);
+      const matchup = `${attack} -> ${typeset}`;
+
+      if (!DUAL_MULTIPLIERS.includes(multiplier)) {
+        failures.push(`${matchup}: ${multiplier}x is not a valid multiplier`);
+      }
+      const product = singles.reduce((total, single) => total * single, 1);
+      if (multiplier !== product) {
+        failures.push(`${matchup}: ${multiplier}x differs from the product of its types`);
+      }
+      // An immunity on either type wins over any weakness on the other
+      if (singles.includes(0) && multiplier !== 0) {
+        failures.push(`${matchup}: ${multiplier}x despite an immunity`);
+      }
+      if (all[attack] !== multiplier) {
+        failures.push(`${matchup}: defendAgainstAll disagrees with defendAgainst`);
+      }
+    }
+  }
+}
+
+async function main() {
+  const failures: string[] = [];
+  await checkGolden(failures);
+  checkInvariants(failures);
+
+  if (failures.length > 0) {
+    console.error(`Type chart check failed (${failures.length}):`);
+    failures.forEach((failure) => console.error(`  ${failure}`));
+    process.exit(1);
+  }
+  console.log(`Type chart OK: ${allTypeSets().length} typings x ${POKEMON_TYPES.length} attacks`);
+}
+
+main().catch((error) => {
+  console.error('Type chart check failed:', error);
+  process.exit(1);
+});
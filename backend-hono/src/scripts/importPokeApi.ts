@@ -0,0 +1,105 @@
+// PokeAPI import script
+// Usage: npm run import:pokeapi -- <fromSpeciesId> [toSpeciesId]
+//        npm run import:pokeapi -- --moves <fromMoveId> [toMoveId]
+//        npm run import:pokeapi -- --abilities <fromAbilityId> [toAbilityId]
+//        npm run import:pokeapi -- --items <fromItemId> [toItemId]
+//        npm run import:pokeapi -- --dry-run <fromSpeciesId> [toSpeciesId]
+
+import { env } from '../config/env';
+import { createRepositories, createSearchIndex } from '../repository/createRepositories';
+import { PokeApiClient } from '../repository/pokeapi/PokeApiClient';
+import { PokemonImportService } from '../usecase/PokemonImportService';
+
+async function main() {
+  const args = process.argv.slice(2);
+  const importMoves = args[0] === '--moves';
+  const importAbilities = args[0] === '--abilities';
+  const importItems = args[0] === '--items';
+  const dryRun = args[0] === '--dry-run';
+  if (importMoves || importAbilities || importItems || dryRun) {
+    args.shift();
+  }
+
+  const from = parseInt(args[0]);
+  const to = parseInt(args[1] ?? args[0]);
+  if (Number.isNaN(from) || Number.isNaN(to) || from > to) {
+    console.error(
+      'Usage: npm run import:pokeapi -- [--moves|--abilities|--items|--dry-run] <fromId> [toId]'
+    );
+    process.exit(1);
+  }
+
+  const repositories = await createRepositories(env.DATABASE_URL);
+  const importService = new PokemonImportService(
+    new PokeApiClient(),
+    repositories.pokemonSpeciesRepository,
+    repositories.pokemonFormRepository,
+    repositories.moveRepository,
+    repositories.abilityRepository,
+    repositories.itemRepository,
+    repositories.datasetSnapshotRepository,
+    createSearchIndex(env.MEILISEARCH_URL, env.MEILISEARCH_API_KEY)
+  );
+  // Bumps the data version so cached Pokédex responses are revalidated
+  const { dataImportRepository } = repositories;
+
+  // Prints what a species import would change; nothing is written
+  if (dryRun) {
+    for (let speciesId = from; speciesId <= to; speciesId++) {
+      const preview = await importService.previewSpecies(speciesId);
+      console.log(`Species ${preview.speciesId} ${preview.name}: ${preview.status}`);
+      for (const { field, from: before, to: after } of preview.changes) {
+        console.log(`  ${field}: ${before} -> ${after}`);
+      }
+      for (const form of preview.forms) {
+        console.log(`  Form ${form.formId} ${form.name}: ${form.status}`);
+        for (const { field, from: before, to: after } of form.changes) {
+          console.log(`    ${field}: ${before} -> ${after}`);
+        }
+      }
+    }
+    process.exit(0);
+  }
+
+  if (importMoves) {
+    for (let moveId = from; moveId <= to; moveId++) {
+      const move = await importService.importMove(moveId);
+      console.log(`Imported move ${moveId}: ${move.getName()}`);
+    }
+    await dataImportRepository.record('moves', from, to);
+    process.exit(0);
+  }
+
+  if (importAbilities) {
+    for (let abilityId = from; abilityId <= to; abilityId++) {
+      const ability = await importService.importAbility(abilityId);
+      console.log(`Imported ability ${abilityId}: ${ability.getName()}`);
+    }
+    await dataImportRepository.record('abilities', from, to);
+    process.exit(0);
+  }
+
+  if (importItems) {
+    for (let itemId = from; itemId <= to; itemId++) {
+      const item = await importService.importItem(itemId);
+      console.log(`Imported item ${itemId}: ${item.getName()}`);
+    }
+    await dataImportRepository.record('items', from, to);
+    process.exit(0);
+  }
+
+  for (let speciesId = from; speciesId <= to; speciesId++) {
+    const result = await importService.importSpecies(speciesId);
+    console.log(
+      `Imported species ${result.speciesId}: forms [${result.formIds.join(', ')}], ${result.learnsetEntries} learnset entries`
+    );
+  }
+  await dataImportRepository.record('species', from, to);
+
+  process.exit(0);
+}
+
+main().catch((error) => {
+  console.error('Import failed:', error);
+  process.exit(1);
+});
@@ -0,0 +1,326 @@
+// pokedict CLI: type matchups, team analysis and Pokédex lookups without running the server
+// Usage: npm run pokedict -- effectiveness water/flying [--attack electric]
+//        npm run pokedict -- analyze team.txt [--level 50]
+//        npm run pokedict -- lookup garchomp
+//        npm run pokedict -- lookup "paldea #25"
+//        npm run pokedict -- home home-export.json
+// Every command accepts --lang en|ja. `analyze`, `lookup` and `home` read DATABASE_URL.
+
+import 'dotenv/config';
+import { readFile } from 'node:fs/promises';
+import { parseArgs } from 'node:util';
+import { getDefensiveProfile } from '../domain/service/DefensiveProfile';
+import { Level } from '../domain/valueobject/Level';
+import { POKEMON_TYPES, PokemonType, TypeSet } from '../domain/valueobject/PokemonType';
+import {
+  DEFAULT_LANG,
+  Lang,
+  LangSchema,
+  localizedName,
+  translateError,
+  translateType,
+} from '../i18n';
+import { TYPE_NAMES_JA } from '../i18n/ja';
+import { createRepositories } from '../repository/createRepositories';
+import { HomeImportService } from '../usecase/HomeImportService';
+import { PokemonLookupService } from '../usecase/PokemonLookupService';
+import { ShowdownImportService } from '../usecase/ShowdownImportService';
+import { TeamAnalysisService } from '../usecase/TeamAnalysisService';
+
+const USAGE = `Usage: pokedict <command> [options]
+
+Commands:
+  effectiveness <type[/type]>  Type matchups of a defender, e.g. water/flying
+      --attack, -a <type>      Only the multiplier of this attacking type
+      --ability <name>         Apply the defender's ability, e.g. levitate (marked with *)
+  analyze <file>               Analyze a Pokémon Showdown team export ("-" reads stdin)
+      --level <1-100>          Level to calculate stats at (default: the paste's, else 100)
+      --abilities              Count ability-granted immunities and resistances
+  lookup <name>                Pokédex entry of a Pokémon, by English or Japanese name or by
+                               dex number ("paldea #25", "#445")
+  home <file>                  Convert a Pokémon HOME JSON export ("-" reads stdin)
+
+Options:
+  --lang, -l <en|ja>           Language of names and types (default: en)
+  --help, -h                   Show this help`;
+
+class UsageError extends Error {}
+
+interface CliOptions {
+  lang: Lang;
+  attack?: string;
+  ability?: string;
+  level?: string;
+  abilities: boolean;
+}
+
+interface CliInvocation {
+  command?: string;
+  argument?: string;
+  help: boolean;
+  options: CliOptions;
+}
+
+function parseCli(args: string[]): CliInvocation {
+  let parsed;
+  try {
+    parsed = parseArgs({
+      args,
+      allowPositionals: true,
+      options: {
+        attack: { type: 'string', short: 'a' },
+        ability: { type: 'string' },
+        level: { type: 'string' },
+        abilities: { type: 'boolean' },
+        lang: { type: 'string', short: 'l' },
+        help: { type: 'boolean', short: 'h' },
+      },
+    });
+  } catch (error) {
+    throw new UsageError((error as Error).message);
+  }
+
+  const { values, positionals } = parsed;
+  const lang = LangSchema.safeParse(values.lang ?? DEFAULT_LANG);
+  if (!lang.success) {
+    throw new UsageError(`Unsupported language "${values.lang}" (expected en or ja)`);
+  }
+
+  return {
+    command: positionals[0],
+    argument: positionals[1],
+    help: values.help ?? false,
+    options: {
+      lang: lang.data,
+      attack: values.attack,
+      ability: values.ability,
+      level: values.level,
+      abilities: values.abilities ?? false,
+    },
+  };
+}
+
+async function run({ command, argument, options }: CliInvocation): Promise<void> {
+  if (!argument) {
+    throw new UsageError(`Missing argument for "${command}"`);
+  }
+  switch (command) {
+    case 'effectiveness':
+      return effectiveness(argument, options);
+    case 'analyze':
+      return analyze(argument, options);
+    case 'lookup':
+      return lookup(argument, options);
+    case 'home':
+      return home(argument, options);
+    default:
+      throw new UsageError(`Unknown command "${command}"`);
+  }
+}
+
+function effectiveness(defender: string, { lang, attack, ability }: CliOptions): void {
+  const typeset = parseTypeSet(defender);
+  const defenderName = formatTypes(lang, typeset.getTypes()) + (ability ? ` (${ability})` : '');
+  const profile = getDefensiveProfile(typeset, ability);
+  // Ability-based entries get an asterisk, as in the team report
+  const typeName = (type: PokemonType) =>
+    translateType(lang, type) +
+    (profile.find((matchup) => matchup.type === type)?.source === 'ability' ? '*' : '');
+
+  if (attack) {
+    const attackType = parseType(attack);
+    const multiplier = profile.find((matchup) => matchup.type === attackType)!.multiplier;
+    console.log(`${typeName(attackType)} -> ${defenderName}: ${multiplier}x`);
+    return;
+  }
+
+  const byMultiplier = new Map<number, PokemonType[]>();
+  for (const { type, multiplier } of profile) {
+    byMultiplier.set(multiplier, [...(byMultiplier.get(multiplier) ?? []), type]);
+  }
+
+  console.log(defenderName);
+  for (const multiplier of [...byMultiplier.keys()].sort((a, b) => b - a)) {
+    if (multiplier !== 1) {
+      console.log(`  ${multiplier}x: ${byMultiplier.get(multiplier)!.map(typeName).join(', ')}`);
+    }
+  }
+}
+
+async function analyze(file: string, { lang, level, abilities }: CliOptions): Promise<void> {
+  const paste = await readFile(file === '-' ? process.stdin.fd : file, 'utf8');
+  const repositories = await createRepositories();
+  const showdownImportService = new ShowdownImportService(repositories.pokemonFormRepository);
+  const teamAnalysisService = new TeamAnalysisService(
+    repositories.pokemonFormRepository,
+    repositories.moveRepository
+  );
+
+  const imported = await showdownImportService.import(paste);
+  const analysisLevel = level === undefined ? imported.level : parseLevel(level);
+  const result = await teamAnalysisService.analyze(imported.builds, Level.create(analysisLevel), {
+    includeAbilities: abilities,
+  });
+  const names = result.members.map((member) => localizedName(lang, member.name, member.nameJp));
+
+  console.log(`Team (level ${analysisLevel})`);
+  for (const member of result.members) {
+    const types = formatTypes(lang, member.types);
+    console.log(`  ${member.slot + 1}. ${names[member.slot]} (${types})`);
+  }
+
+  if (result.primary) {
+    const confidence = Math.round(result.primary.confidence * 100);
+    console.log(`\nArchetype: ${result.primary.archetype} (${confidence}%)`);
+  }
+
+  // Attacking types more of the team is weak to than can take them
+  const weaknesses = result.defensiveCoverage.filter((row) => row.weak > row.resist + row.immune);
+  console.log('\nShared weaknesses:');
+  for (const row of weaknesses) {
+    console.log(
+      `  ${translateType(lang, row.type)}: ${row.weak} weak, ${row.resist} resist, ${row.immune} immune`
+    );
+  }
+  if (weaknesses.length === 0) {
+    console.log('  none');
+  }
+
+  const gaps = result.offensiveCoverage.filter((row) => row.best <= 1).map((row) => row.type);
+  const gapNames = gaps.length > 0 ? formatTypes(lang, gaps, ', ') : 'none';
+  console.log(`\nNo super-effective coverage: ${gapNames}`);
+
+  console.log('\nEntry hazards (% HP on switch-in):');
+  for (const hazard of result.hazards) {
+    const spikes = hazard.spikes.map((percent) => `${percent}%`).join('/');
+    console.log(`  ${names[hazard.slot]}: Stealth Rock ${hazard.stealthRock}%, Spikes ${spikes}`);
+  }
+
+  for (const warning of result.warnings) {
+    const members = warning.slots.map((slot) => names[slot]).join(', ');
+    console.log(`\nWarning: ${warning.message} (${members})`);
+  }
+
+  console.log('\nSpeed tiers:');
+  for (const tier of result.speedTiers) {
+    console.log(`  ${tier.speed} ${names[tier.slot]} (base ${tier.baseSpeed})`);
+  }
+}
+
+async function lookup(name: string, { lang }: CliOptions): Promise<void> {
+  const repositories = await createRepositories();
+  const lookupService = new PokemonLookupService(
+    repositories.pokemonFormRepository,
+    repositories.pokemonSpeciesRepository,
+    repositories.dataImportRepository
+  );
+
+  const form = await lookupService.findFormByName(name);
+  const types = form.type2 ? [form.type1, form.type2] : [form.type1];
+  const gender = form.genderless
+    ? 'genderless'
+    : `${form.maleRate}% male, ${form.femaleRate}% female`;
+  const displayName = localizedName(lang, form.name, form.nameJp);
+
+  console.log(`#${form.speciesId} ${displayName} (form ${form.formId})`);
+  console.log(`  Type: ${formatTypes(lang, types)}`);
+  console.log(`  Height: ${form.heightMeters} m, Weight: ${form.weightKg} kg`);
+  console.log(`  Gender: ${gender}`);
+  console.log(`  Capture rate: ${form.captureRate}`);
+  console.log(`  Egg groups: ${form.eggGroups.join(', ')}`);
+  if (form.regionalDexNumbers.length > 0) {
+    const dexNumbers = form.regionalDexNumbers.map(({ dex, number }) => `${dex} #${number}`);
+    console.log(`  Regional dex: ${dexNumbers.join(', ')}`);
+  }
+  console.log(`  Introduced in: ${form.introducedIn}`);
+  console.log(`  Shiny: ${form.shinyLocked ? 'locked' : form.shinyAvailable ? 'yes' : 'no'}`);
+}
+
+async function home(file: string, { lang }: CliOptions): Promise<void> {
+  const json = await readFile(file === '-' ? process.stdin.fd : file, 'utf8');
+  const { pokemonFormRepository } = await createRepositories();
+  const homeImportService = new HomeImportService(pokemonFormRepository);
+  const result = await homeImportService.import(json);
+
+  const kindLabel = (kind: 'box' | 'team') => (kind === 'box' ? 'Box' : 'Team');
+  for (const group of result.groups) {
+    console.log(`${kindLabel(group.kind)}: ${group.name}`);
+    for (const member of group.members) {
+      const name = localizedName(lang, member.name, member.nameJp);
+      const nickname = member.build.getNickname();
+      const label = nickname ? `${nickname} (${name})` : name;
+      const moves = member.build.getMoves().join(', ');
+      console.log(`  ${member.position + 1}. ${label}${moves ? `: ${moves}` : ''}`);
+    }
+  }
+
+  if (result.unmapped.length > 0) {
+    console.log(`\nUnmapped (${result.unmapped.length}):`);
+    for (const entry of result.unmapped) {
+      const where = `${kindLabel(entry.kind)} ${entry.group} #${entry.position + 1}`;
+      console.log(`  ${where} (dex ${entry.dexNo}, form ${entry.formNo}): ${entry.reason}`);
+    }
+  }
+}
+
+// Accepts English names in any case ("water", "Water") and Japanese names ("みず")
+function parseType(value: string): PokemonType {
+  const type = POKEMON_TYPES.find(
+    (candidate) =>
+      candidate.toLowerCase() === value.trim().toLowerCase() ||
+      TYPE_NAMES_JA[candidate] === value.trim()
+  );
+  if (!type) {
+    throw new UsageError(`Unknown type "${value}"`);
+  }
+  return type;
+}
+
+function parseTypeSet(value: string): TypeSet {
+  const types = value.split('/').map(parseType);
+  if (types.length > 2 || types[0] === types[1]) {
+    throw new UsageError(`"${value}" is not a valid type combination`);
+  }
+  return new TypeSet(types[0], types[1]);
+}
+
+function parseLevel(value: string): number {
+  const level = Number(value);
+  if (!Number.isInteger(level) || level < 1 || level > 100) {
+    throw new UsageError('--level must be a whole number from 1 to 100');
+  }
+  return level;
+}
+
+function formatTypes(lang: Lang, types: PokemonType[], separator = '/'): string {
+  return types.map((type) => translateType(lang, type)).join(separator);
+}
+
+async function main() {
+  const invocation = parseCli(process.argv.slice(2));
+  if (invocation.help || !invocation.command) {
+    console.log(USAGE);
+    process.exit(0);
+  }
+
+  try {
+    await run(invocation);
+  } catch (error) {
+    // Service errors carry a code, so they are localized the same way API responses are
+    if (error instanceof Error && 'code' in error && typeof error.code === 'string') {
+      console.error(translateError(invocation.options.lang, error.code, error.message));
+      process.exit(1);
+    }
+    throw error;
+  }
+  process.exit(0);
+}
+
+main().catch((error) => {
+  if (error instanceof UsageError) {
+    console.error(`${error.message}\n\n${USAGE}`);
+    process.exit(2);
+  }
+  console.error('pokedict failed:', error);
+  process.exit(1);
+});
@@ -0,0 +1,28 @@
+// Seed script: loads the bundled Gen 1 dataset so the API has something to serve locally
+// Usage: npm run seed
+
+import { env } from '../config/env';
+import { GEN1_SPECIES } from '../db/seed/gen1';
+import { createRepositories } from '../repository/createRepositories';
+import { SeedService } from '../usecase/SeedService';
+
+async function main() {
+  const repositories = await createRepositories(env.DATABASE_URL);
+  const seedService = new SeedService(
+    repositories.pokemonSpeciesRepository,
+    repositories.pokemonFormRepository,
+    repositories.dataImportRepository
+  );
+
+  const result = await seedService.seed(GEN1_SPECIES);
+  console.log(
+    `Seeded ${result.species} species and ${result.forms} forms (data version ${result.version})`
+  );
+
+  process.exit(0);
+}
+
+main().catch((error) => {
+  console.error('Seed failed:', error);
+  process.exit(1);
+});
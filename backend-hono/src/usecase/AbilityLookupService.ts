@@ -0,0 +1,70 @@
+import { Ability } from '../domain/entity/Ability';
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { AbilitySlot } from '../domain/valueobject/AbilitySlots';
+import {
+  AbilityPage,
+  AbilityRepository,
+  AbilitySearchFilter,
+} from '../repository/interface/AbilityRepository';
+import { DataImportRepository } from '../repository/interface/DataImportRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+
+export class AbilityLookupError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'AbilityLookupError';
+  }
+
+  static abilityNotFound(name: string): AbilityLookupError {
+    return new AbilityLookupError(`Ability "${name}" not found`, 'ABILITY_NOT_FOUND');
+  }
+}
+
+export interface AbilityHolder {
+  form: PokemonMaster;
+  slot: AbilitySlot;
+}
+
+export interface AbilityHolders {
+  ability: Ability;
+  holders: AbilityHolder[];
+}
+
+export class AbilityLookupService {
+  constructor(
+    private readonly abilityRepository: AbilityRepository,
+    private readonly formRepository: PokemonFormRepository,
+    private readonly dataImportRepository: DataImportRepository
+  ) {}
+
+  async getDataVersion(): Promise<number> {
+    return this.dataImportRepository.findLatestVersion();
+  }
+
+  async getAbility(name: string): Promise<Ability> {
+    const ability = await this.abilityRepository.findByName(name);
+    if (!ability) {
+      throw AbilityLookupError.abilityNotFound(name);
+    }
+    return ability;
+  }
+
+  async search(filter: AbilitySearchFilter, limit: number, offset: number): Promise<AbilityPage> {
+    return this.abilityRepository.search(filter, limit, offset);
+  }
+
+  // Forms that can have the ability, hidden ability included, sorted by form ID
+  async getHolders(name: string): Promise<AbilityHolders> {
+    const ability = await this.getAbility(name);
+    const forms = await this.formRepository.findByAbility(ability.getName());
+
+    const holders = forms.flatMap((form) => {
+      const slot = form.getAbilities()?.getSlotOf(ability.getName());
+      return slot ? [{ form, slot }] : [];
+    });
+    return { ability, holders };
+  }
+}
@@ -0,0 +1,239 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { PokemonType, TypeSet } from '../domain/valueobject/PokemonType';
+import { BaseStats, StatName } from '../domain/valueobject/Stats';
+import { AnalysisCacheRepository } from '../repository/interface/AnalysisCacheRepository';
+import { DataImport, DataImportRepository } from '../repository/interface/DataImportRepository';
+import { DatasetSnapshotRepository } from '../repository/interface/DatasetSnapshotRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { PokemonImportService, SpeciesImportPreview } from './PokemonImportService';
+
+export class AdminError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'AdminError';
+  }
+
+  static formNotFound(formId: number): AdminError {
+    return new AdminError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static versionNotFound(version: number): AdminError {
+    return new AdminError(`Data version ${version} not found`, 'VERSION_NOT_FOUND');
+  }
+
+  static rangeTooLarge(max: number): AdminError {
+    return new AdminError(`A sync covers at most ${max} IDs`, 'RANGE_TOO_LARGE');
+  }
+
+  static dryRunUnsupported(kind: SyncKind): AdminError {
+    return new AdminError(`Dry runs are not supported for ${kind}`, 'DRY_RUN_UNSUPPORTED');
+  }
+
+  static duplicateTypes(): AdminError {
+    return new AdminError('type1 and type2 cannot be the same', 'DUPLICATE_TYPES');
+  }
+}
+
+// Each ID costs several PokeAPI requests, so syncs are kept small enough for one Workers
+// request; bulk loads belong in `npm run import:pokeapi`
+export const MAX_SYNC_RANGE = 10;
+
+// IDs a sync imports at once; the requests are I/O-bound, so a few in flight cut the wait
+// without hammering PokeAPI
+export const DEFAULT_SYNC_CONCURRENCY = 4;
+
+export type SyncKind = 'species' | 'moves' | 'abilities' | 'items';
+
+export interface SyncResult {
+  kind: SyncKind;
+  version: number;
+  imported: number[];
+}
+
+export interface FormChanges {
+  name?: string;
+  nameJp?: string;
+  type1?: PokemonType;
+  type2?: PokemonType | null; // null removes the second type
+  baseStats?: Partial<BaseStats>;
+  baseExperience?: number | null;
+  height?: number; // decimetres
+  weight?: number; // hectograms
+  shinyAvailable?: boolean;
+  shinyLocked?: boolean;
+}
+
+export interface FormUpdateResult {
+  form: PokemonMaster;
+  version: number;
+}
+
+export interface RollbackResult {
+  restored: number;
+  version: number;
+}
+
+export interface NameIndexRebuildResult {
+  indexed: number;
+  version: number;
+}
+
+// Every change bumps the data version, which is what invalidates cached dex responses
+export class AdminService {
+  constructor(
+    private readonly importService: PokemonImportService,
+    private readonly formRepository: PokemonFormRepository,
+    private readonly dataImportRepository: DataImportRepository,
+    private readonly snapshotRepository: DatasetSnapshotRepository,
+    private readonly analysisCacheRepository: AnalysisCacheRepository,
+    private readonly syncConcurrency: number = DEFAULT_SYNC_CONCURRENCY
+  ) {}
+
+  async sync(kind: SyncKind, from: number, to: number): Promise<SyncResult> {
+    if (to - from + 1 > MAX_SYNC_RANGE) {
+      throw AdminError.rangeTooLarge(MAX_SYNC_RANGE);
+    }
+
+    // Batches run one after another, so a failure stops the sync once its batch has settled
+    const ids = Array.from({ length: to - from + 1 }, (_, index) => from + index);
+    const imported: number[] = [];
+    for (let start = 0; start < ids.length; start += this.syncConcurrency) {
+      const batch = ids.slice(start, start + this.syncConcurrency);
+      const results = await Promise.allSettled(batch.map((id) => this.importOne(kind, id)));
+      results.forEach((result, index) => {
+        if (result.status === 'fulfilled') {
+          imported.push(batch[index]);
+        }
+      });
+
+      const failure = results.find((result) => result.status === 'rejected');
+      if (failure?.status === 'rejected') {
+        // A partial sync still changed data, so caches are invalidated before rethrowing
+        if (imported.length > 0) {
+          await this.dataImportRepository.record(kind, from, Math.max(...imported));
+        }
+        throw failure.reason;
+      }
+    }
+
+    const version = await this.dataImportRepository.record(kind, from, to);
+    return { kind, version, imported };
+  }
+
+  // Reports what a species sync would change without writing anything, so the data version is
+  // left alone
+  async previewSync(kind: SyncKind, from: number, to: number): Promise<SpeciesImportPreview[]> {
+    if (to - from + 1 > MAX_SYNC_RANGE) {
+      throw AdminError.rangeTooLarge(MAX_SYNC_RANGE);
+    }
+    if (kind !== 'species') {
+      throw AdminError.dryRunUnsupported(kind);
+    }
+
+    const previews: SpeciesImportPreview[] = [];
+    for (let id = from; id <= to; id++) {
+      previews.push(await this.importService.previewSpecies(id));
+    }
+    return previews;
+  }
+
+  async listImports(limit: number): Promise<DataImport[]> {
+    return this.dataImportRepository.findRecent(limit);
+  }
+
+  async updateForm(formId: FormId, changes: FormChanges): Promise<FormUpdateResult> {
+    const form = await this.formRepository.findById(formId);
+    if (!form) {
+      throw AdminError.formNotFound(formId);
+    }
+
+    const type1 = changes.type1 ?? form.getTypeset().type1;
+    const type2 = changes.type2 === undefined ? form.getTypeset().type2 : changes.type2;
+    if (type1 === type2) {
+      throw AdminError.duplicateTypes();
+    }
+
+    const updated = form.withChanges({
+      name: changes.name ?? form.getName(),
+      nameJp: changes.nameJp ?? form.getNameJp(),
+      typeset: new TypeSet(type1, type2 ?? undefined),
+      baseStats: this.mergeBaseStats(form.getBaseStats(), changes.baseStats),
+      baseExperience:
+        changes.baseExperience === undefined
+          ? form.getBaseExperience()
+          : (changes.baseExperience ?? undefined),
+      height: changes.height ?? form.getHeight(),
+      weight: changes.weight ?? form.getWeight(),
+      shinyAvailable: changes.shinyAvailable ?? form.isShinyAvailable(),
+      shinyLocked: changes.shinyLocked ?? form.isShinyLocked(),
+    });
+    await this.snapshotRepository.save([{ kind: 'form', entry: form }]);
+    await this.formRepository.save(updated);
+
+    const version = await this.dataImportRepository.record('edit', formId, formId);
+    return { form: updated, version };
+  }
+
+  // Puts every species, form, move, ability and item changed since the version back the way it
+  // was, as a new version. Entries first imported after the version stay, as do learnsets and
+  // encounters.
+  async rollbackTo(version: number): Promise<RollbackResult> {
+    const latest = await this.dataImportRepository.findLatestVersion();
+    if (version > latest) {
+      throw AdminError.versionNotFound(version);
+    }
+
+    const entries = await this.snapshotRepository.findChangedSince(version);
+    if (entries.length === 0) {
+      return { restored: 0, version: latest };
+    }
+
+    await this.importService.restore(entries);
+    return {
+      restored: entries.length,
+      version: await this.dataImportRepository.record('rollback', version),
+    };
+  }
+
+  // Cached responses are keyed on the data version, so bumping it is the purge. Memoized
+  // analyses are deleted as well, since entries from older versions are never read again.
+  async purgeCaches(): Promise<number> {
+    await this.analysisCacheRepository.deleteAll();
+    return this.dataImportRepository.record('purge');
+  }
+
+  // Backfills the name index for forms loaded before it existed, e.g. from a dump
+  async rebuildNameIndex(): Promise<NameIndexRebuildResult> {
+    const indexed = await this.formRepository.rebuildNameIndex();
+    const version = await this.dataImportRepository.record('purge');
+    return { indexed, version };
+  }
+
+  private async importOne(kind: SyncKind, id: number): Promise<void> {
+    switch (kind) {
+      case 'moves':
+        await this.importService.importMove(id);
+        return;
+      case 'abilities':
+        await this.importService.importAbility(id);
+        return;
+      case 'items':
+        await this.importService.importItem(id);
+        return;
+      case 'species':
+        await this.importService.importSpecies(id);
+    }
+  }
+
+  private mergeBaseStats(current: BaseStats, changes: Partial<BaseStats> = {}): BaseStats {
+    const merged = { ...current };
+    for (const stat of Object.keys(merged) as StatName[]) {
+      merged[stat] = changes[stat] ?? merged[stat];
+    }
+    return merged;
+  }
+}
@@ -0,0 +1,35 @@
+import crypto from 'crypto';
+import { AnalysisCacheRepository } from '../repository/interface/AnalysisCacheRepository';
+import { DataImportRepository } from '../repository/interface/DataImportRepository';
+
+export type AnalysisKind = 'threats' | 'archetype' | 'report';
+
+// Memoizes team analysis responses by a hash of their request. Teams are analysed from their
+// contents rather than by ID, so an edited team hashes differently and its old entry is never
+// read again; imports and purges bump the data version, which every lookup must match.
+export class AnalysisCacheService {
+  constructor(
+    private readonly cacheRepository: AnalysisCacheRepository,
+    private readonly dataImportRepository: DataImportRepository
+  ) {}
+
+  // `request` must hold everything the result depends on (members, format, options, language),
+  // and the result must survive a JSON round trip
+  async memoize<T>(kind: AnalysisKind, request: unknown, compute: () => Promise<T>): Promise<T> {
+    const key = crypto.createHash('sha256').update(JSON.stringify([kind, request])).digest('hex');
+    const version = await this.dataImportRepository.findLatestVersion();
+    const cached = await this.cacheRepository.find(key, version);
+    if (cached !== null) {
+      return JSON.parse(cached) as T;
+    }
+
+    const result = await compute();
+    await this.cacheRepository.save({ key, kind, version, result: JSON.stringify(result) });
+    return result;
+  }
+
+  // Returns the number of entries removed
+  async clear(): Promise<number> {
+    return this.cacheRepository.deleteAll();
+  }
+}
@@ -0,0 +1,83 @@
+import { v4 as uuidv4 } from 'uuid';
+import { ApiKey, IssuedApiKey } from '../domain/entity/ApiKey';
+import { ApiKeyScope } from '../domain/valueobject/ApiKeyScope';
+import { ApiKeyRepository } from '../repository/interface/ApiKeyRepository';
+
+export class ApiKeyError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'ApiKeyError';
+  }
+
+  // Unknown and revoked keys look the same to the caller
+  static invalidKey(): ApiKeyError {
+    return new ApiKeyError('Invalid API key', 'INVALID_API_KEY');
+  }
+
+  static insufficientScope(scope: ApiKeyScope): ApiKeyError {
+    return new ApiKeyError(`This API key cannot call ${scope} endpoints`, 'INSUFFICIENT_SCOPE');
+  }
+
+  static quotaExceeded(quota: number): ApiKeyError {
+    return new ApiKeyError(`Daily quota of ${quota} requests exceeded`, 'QUOTA_EXCEEDED');
+  }
+
+  static keyNotFound(keyId: string): ApiKeyError {
+    return new ApiKeyError(`API key ${keyId} not found`, 'API_KEY_NOT_FOUND');
+  }
+}
+
+export interface ApiKeyUsage {
+  apiKey: ApiKey;
+  // Requests today, including the current one
+  used: number;
+  // Start of the next UTC day, when the count starts over
+  resetsAt: Date;
+}
+
+export class ApiKeyService {
+  constructor(private readonly apiKeyRepository: ApiKeyRepository) {}
+
+  async issue(name: string, scopes: ApiKeyScope[], dailyQuota: number): Promise<IssuedApiKey> {
+    const issued = ApiKey.issue(uuidv4(), name, scopes, dailyQuota);
+    await this.apiKeyRepository.save(issued.apiKey);
+    return issued;
+  }
+
+  async list(): Promise<ApiKey[]> {
+    return this.apiKeyRepository.findAll();
+  }
+
+  async revoke(keyId: string): Promise<ApiKey> {
+    const apiKey = await this.apiKeyRepository.findById(keyId);
+    if (!apiKey) {
+      throw ApiKeyError.keyNotFound(keyId);
+    }
+
+    apiKey.revoke();
+    await this.apiKeyRepository.save(apiKey);
+    return apiKey;
+  }
+
+  // Counts the request against the key's quota. Requests over the quota are still counted, so
+  // the usage reported back keeps growing until the day rolls over.
+  async recordRequest(key: string, scope: ApiKeyScope, now = new Date()): Promise<ApiKeyUsage> {
+    const apiKey = await this.apiKeyRepository.findByHash(ApiKey.hashKey(key));
+    if (!apiKey || apiKey.isRevoked()) {
+      throw ApiKeyError.invalidKey();
+    }
+    if (!apiKey.hasScope(scope)) {
+      throw ApiKeyError.insufficientScope(scope);
+    }
+
+    const day = now.toISOString().slice(0, 10);
+    const used = await this.apiKeyRepository.recordRequest(apiKey.getKeyId(), day);
+    const resetsAt = new Date(`${day}T00:00:00Z`);
+    resetsAt.setUTCDate(resetsAt.getUTCDate() + 1);
+
+    return { apiKey, used, resetsAt };
+  }
+}
@@ -1,9 +1,16 @@
 import { v4 as uuidv4 } from 'uuid';
 import { User } from '../domain/entity/User';
-import { RefreshToken } from '../domain/entity/RefreshToken';
-import { UserRepository } from '../repository/interface/UserRepository';
+import { RefreshToken, SessionClient } from '../domain/entity/RefreshToken';
+import {
+  EmailAlreadyExistsError,
+  UsernameAlreadyExistsError,
+  UserRepository,
+} from '../repository/interface/UserRepository';
 import { RefreshTokenRepository } from '../repository/interface/RefreshTokenRepository';
+import { LoginFailureRepository } from '../repository/interface/LoginFailureRepository';
+import { LOGIN_FAILURE_WINDOW_MS, nextLoginAllowedAt } from '../domain/valueobject/LoginBackoff';
 import { JwtService, TokenPair } from '../domain/valueobject/JWT';
+import { Username, UsernameMode } from '../domain/valueobject/Username';
 
 export class AuthError extends Error {
   constructor(
@@ -22,6 +29,14 @@ export class AuthError extends Error {
     return new AuthError('Invalid token', 'INVALID_TOKEN');
   }
 
+  static missingToken(): AuthError {
+    return new AuthError('Missing or invalid authorization header', 'MISSING_TOKEN');
+  }
+
+  static missingRefreshToken(): AuthError {
+    return new AuthError('Refresh token not found', 'MISSING_REFRESH_TOKEN');
+  }
+
   static tokenExpired(): AuthError {
     return new AuthError('Token expired', 'TOKEN_EXPIRED');
   }
@@ -30,9 +45,34 @@ export class AuthError extends Error {
     return new AuthError('Token has been revoked', 'TOKEN_REVOKED');
   }
 
+  static usernameExists(): AuthError {
+    return new AuthError('Username already exists', 'USERNAME_EXISTS');
+  }
+
+  static emailExists(): AuthError {
+    return new AuthError('Email already exists', 'EMAIL_EXISTS');
+  }
+
   static userNotFound(): AuthError {
     return new AuthError('User not found', 'USER_NOT_FOUND');
   }
+
+  static forbidden(): AuthError {
+    return new AuthError('Admin role required', 'FORBIDDEN');
+  }
+
+  static sessionNotFound(): AuthError {
+    return new AuthError('Session not found', 'SESSION_NOT_FOUND');
+  }
+}
+
+// Too many recent failed logins for the email address or client IP; try again after
+// `retryAfter` seconds
+export class LoginThrottledError extends AuthError {
+  constructor(public readonly retryAfter: number) {
+    super(`Too many failed logins; retry in ${retryAfter} seconds`, 'LOGIN_THROTTLED');
+    this.name = 'LoginThrottledError';
+  }
 }
 
 export interface RegisterRequest {
@@ -50,6 +90,9 @@ export interface RegisterResponse {
 export interface LoginRequest {
   email: string;
   password: string;
+  // Throttled alongside the email address when known, and shown in the session list
+  clientIp?: string;
+  userAgent?: string;
 }
 
 export interface LoginResponse {
@@ -62,31 +105,61 @@ export interface LoginResponse {
   expiresIn: number;
 }
 
+export interface SessionLoginResponse {
+  userId: string;
+  username: string;
+  email: string;
+  sessionToken: string;
+}
+
+export interface SessionInfo {
+  sessionId: string;
+  userAgent?: string;
+  ip?: string;
+  // When the session last logged in, refreshed its access token or, in session mode, made a
+  // request (to within a few minutes)
+  lastUsedAt: Date;
+  expiresAt: Date;
+  // Whether this is the session the request's refresh token belongs to
+  current: boolean;
+}
+
 export class AuthService {
   constructor(
     private readonly userRepository: UserRepository,
     private readonly refreshTokenRepository: RefreshTokenRepository,
-    private readonly jwtService: JwtService
+    private readonly loginFailureRepository: LoginFailureRepository,
+    private readonly jwtService: JwtService,
+    private readonly usernameMode: UsernameMode = 'ascii',
+    private readonly clock: () => Date = () => new Date()
   ) {}
 
   async register(request: RegisterRequest): Promise<RegisterResponse> {
-    // Check if username or email already exists
-    const usernameExists = await this.userRepository.existsByUsername(request.username);
-    if (usernameExists) {
-      throw new AuthError('Username already exists', 'USERNAME_EXISTS');
-    }
-
-    const emailExists = await this.userRepository.existsByEmail(request.email);
-    if (emailExists) {
-      throw new AuthError('Email already exists', 'EMAIL_EXISTS');
-    }
+    // Validate and normalize the username before it is stored
+    const username = Username.create(request.username, this.usernameMode).getValue();
 
     // Create user
     const userId = uuidv4();
-    const user = await User.create(userId, request.username, request.email, request.password);
+    const user = await User.create(
+      userId,
+      username,
+      request.email,
+      request.password,
+      this.usernameMode
+    );
 
-    // Save user
-    await this.userRepository.save(user);
+    // The unique constraints catch duplicates; checking first would race with other sign-ups
+    try {
+      await this.userRepository.save(user);
+    } catch (error) {
+      if (error instanceof UsernameAlreadyExistsError) {
+        throw AuthError.usernameExists();
+      }
+      if (error instanceof EmailAlreadyExistsError) {
+        throw AuthError.emailExists();
+      }
+      throw error;
+    }
 
     return {
       userId: user.getUserId(),
@@ -96,24 +169,19 @@ export class AuthService {
   }
 
   async login(request: LoginRequest): Promise<LoginResponse> {
-    // Find user by email
-    const user = await this.userRepository.findByEmail(request.email);
-    if (!user) {
-      throw AuthError.invalidCredentials();
-    }
-
-    // Verify password
-    const isValid = await user.verifyPassword(request.password);
-    if (!isValid) {
-      throw AuthError.invalidCredentials();
-    }
+    const user = await this.authenticate(request);
 
     // Generate tokens
     const tokenPair = this.jwtService.generatePair(user.getUserId());
 
-    // Save refresh token
-    const tokenId = uuidv4();
-    const refreshToken = RefreshToken.create(tokenId, user.getUserId(), tokenPair.refreshToken);
+    // Save refresh token, starting a new session
+    const refreshToken = RefreshToken.create(
+      uuidv4(),
+      user.getUserId(),
+      uuidv4(),
+      tokenPair.refreshToken,
+      { userAgent: request.userAgent, ip: request.clientIp }
+    );
     await this.refreshTokenRepository.save(refreshToken);
 
     return {
@@ -127,23 +195,39 @@ export class AuthService {
     };
   }
 
-  async refresh(refreshTokenString: string): Promise<TokenPair> {
-    // Hash the refresh token
-    const tokenHash = RefreshToken.hashToken(refreshTokenString);
+  // Server-side session mode: no JWTs, the session token is checked on every request
+  async startSession(request: LoginRequest): Promise<SessionLoginResponse> {
+    const user = await this.authenticate(request);
 
-    // Find refresh token in database
-    const refreshToken = await this.refreshTokenRepository.findByHash(tokenHash);
-    if (!refreshToken) {
-      throw AuthError.invalidToken();
-    }
+    const { refreshToken, token } = RefreshToken.issueSessionToken(
+      uuidv4(),
+      user.getUserId(),
+      uuidv4(),
+      { userAgent: request.userAgent, ip: request.clientIp }
+    );
+    await this.refreshTokenRepository.save(refreshToken);
 
-    // Validate token
-    if (!refreshToken.isValid()) {
-      if (refreshToken.isRevoked()) {
-        throw AuthError.tokenRevoked();
-      }
-      throw AuthError.tokenExpired();
+    return {
+      userId: user.getUserId(),
+      username: user.getUsername(),
+      email: user.getEmail(),
+      sessionToken: token,
+    };
+  }
+
+  async validateSessionToken(sessionToken: string): Promise<string> {
+    const session = await this.findValidToken(sessionToken);
+    if (session.markUsed()) {
+      await this.refreshTokenRepository.updateLastUsed(
+        session.getTokenId(),
+        session.getLastUsedAt()
+      );
     }
+    return session.getUserId();
+  }
+
+  async refresh(refreshTokenString: string, client: SessionClient = {}): Promise<TokenPair> {
+    const refreshToken = await this.findValidToken(refreshTokenString);
 
     // Generate new token pair
     const tokenPair = this.jwtService.generatePair(refreshToken.getUserId());
@@ -151,13 +235,8 @@ export class AuthService {
     // Revoke old refresh token
     await this.refreshTokenRepository.revoke(refreshToken.getTokenId());
 
-    // Save new refresh token
-    const newTokenId = uuidv4();
-    const newRefreshToken = RefreshToken.create(
-      newTokenId,
-      refreshToken.getUserId(),
-      tokenPair.refreshToken
-    );
+    // Save new refresh token in the same session
+    const newRefreshToken = refreshToken.rotate(uuidv4(), tokenPair.refreshToken, client);
     await this.refreshTokenRepository.save(newRefreshToken);
 
     return tokenPair;
@@ -172,6 +251,38 @@ export class AuthService {
     }
   }
 
+  async listSessions(userId: string, currentRefreshToken?: string): Promise<SessionInfo[]> {
+    const [tokens, currentSessionId] = await Promise.all([
+      this.refreshTokenRepository.findActiveByUser(userId),
+      this.findSessionId(userId, currentRefreshToken),
+    ]);
+
+    return tokens.map((token) => ({
+      sessionId: token.getSessionId(),
+      userAgent: token.getClient().userAgent,
+      ip: token.getClient().ip,
+      lastUsedAt: token.getLastUsedAt(),
+      expiresAt: token.getExpiresAt(),
+      current: token.getSessionId() === currentSessionId,
+    }));
+  }
+
+  // The session can no longer refresh; access tokens it already holds stay valid until they
+  // expire
+  async revokeSession(userId: string, sessionId: string): Promise<void> {
+    const revoked = await this.refreshTokenRepository.revokeSession(userId, sessionId);
+    if (!revoked) {
+      throw AuthError.sessionNotFound();
+    }
+  }
+
+  // Signs out everywhere else; without a valid refresh token for the current session, every
+  // session is revoked. Returns how many were.
+  async revokeOtherSessions(userId: string, currentRefreshToken?: string): Promise<number> {
+    const currentSessionId = await this.findSessionId(userId, currentRefreshToken);
+    return this.refreshTokenRepository.revokeOtherSessions(userId, currentSessionId);
+  }
+
   validateAccessToken(token: string): string {
     try {
       const claims = this.jwtService.verify(token);
@@ -191,4 +302,99 @@ export class AuthService {
       throw error;
     }
   }
+
+  // Looked up on every call rather than carried in the token, so a demotion applies immediately
+  async requireAdmin(userId: string): Promise<void> {
+    const user = await this.userRepository.findById(userId);
+    if (!user) {
+      throw AuthError.userNotFound();
+    }
+    if (!user.isAdmin()) {
+      throw AuthError.forbidden();
+    }
+  }
+
+  private async authenticate(request: LoginRequest): Promise<User> {
+    const identifiers = this.loginIdentifiers(request);
+    await this.checkLoginBackoff(identifiers);
+
+    // Find user by email
+    const user = await this.userRepository.findByEmail(request.email);
+    if (!user) {
+      await this.recordLoginFailure(identifiers);
+      throw AuthError.invalidCredentials();
+    }
+
+    // Verify password
+    const isValid = await user.verifyPassword(request.password);
+    if (!isValid) {
+      await this.recordLoginFailure(identifiers);
+      throw AuthError.invalidCredentials();
+    }
+
+    // Only the account's count is reset: logging in to one account must not clear the failures
+    // an IP has run up against others
+    await this.loginFailureRepository.clear(identifiers[0]);
+    return user;
+  }
+
+  private async findValidToken(token: string): Promise<RefreshToken> {
+    const tokenHash = RefreshToken.hashToken(token);
+    const refreshToken = await this.refreshTokenRepository.findByHash(tokenHash);
+    if (!refreshToken) {
+      throw AuthError.invalidToken();
+    }
+
+    if (!refreshToken.isValid()) {
+      if (refreshToken.isRevoked()) {
+        throw AuthError.tokenRevoked();
+      }
+      throw AuthError.tokenExpired();
+    }
+    return refreshToken;
+  }
+
+  // The email address first
+  private loginIdentifiers(request: LoginRequest): string[] {
+    const identifiers = [`email:${request.email.toLowerCase()}`];
+    if (request.clientIp) {
+      identifiers.push(`ip:${request.clientIp}`);
+    }
+    return identifiers;
+  }
+
+  private async checkLoginBackoff(identifiers: string[]): Promise<void> {
+    const now = this.clock();
+    const forgetBefore = now.getTime() - LOGIN_FAILURE_WINDOW_MS;
+    const failures = await this.loginFailureRepository.findByIdentifiers(identifiers);
+
+    const allowedAt = failures
+      .filter(({ lastFailedAt }) => lastFailedAt.getTime() >= forgetBefore)
+      .map((entry) => nextLoginAllowedAt(entry).getTime());
+    const wait = Math.max(0, ...allowedAt) - now.getTime();
+    if (wait > 0) {
+      throw new LoginThrottledError(Math.ceil(wait / 1000));
+    }
+  }
+
+  private async recordLoginFailure(identifiers: string[]): Promise<void> {
+    const now = this.clock();
+    const forgetBefore = new Date(now.getTime() - LOGIN_FAILURE_WINDOW_MS);
+    for (const identifier of identifiers) {
+      await this.loginFailureRepository.recordFailure(identifier, now, forgetBefore);
+    }
+  }
+
+  private async findSessionId(
+    userId: string,
+    refreshTokenString?: string
+  ): Promise<string | undefined> {
+    if (!refreshTokenString) {
+      return undefined;
+    }
+    const token = await this.refreshTokenRepository.findByHash(
+      RefreshToken.hashToken(refreshTokenString)
+    );
+    return token?.isValid() && token.getUserId() === userId ? token.getSessionId() : undefined;
+  }
 }
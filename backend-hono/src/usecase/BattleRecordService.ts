@@ -0,0 +1,200 @@
+import { v4 as uuidv4 } from 'uuid';
+import {
+  BattleOpponent,
+  BattleRecord,
+  BattleResult,
+  getWinRate,
+} from '../domain/entity/BattleRecord';
+import { Archetype } from '../domain/service/ArchetypeClassifier';
+import { FormatId } from '../domain/valueobject/Format';
+import { FormId } from '../domain/valueobject/PokemonId';
+import {
+  BattleRecordFilter,
+  BattleRecordRepository,
+  BattleTally,
+} from '../repository/interface/BattleRecordRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+
+export class BattleRecordServiceError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'BattleRecordServiceError';
+  }
+
+  // Also used for other users' records
+  static recordNotFound(recordId: string): BattleRecordServiceError {
+    return new BattleRecordServiceError(
+      `Battle record ${recordId} not found`,
+      'BATTLE_RECORD_NOT_FOUND'
+    );
+  }
+
+  // Also used for other users' teams, and for logging with a team in the trash
+  static teamNotFound(teamId: string): BattleRecordServiceError {
+    return new BattleRecordServiceError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+
+  static formNotFound(formId: number): BattleRecordServiceError {
+    return new BattleRecordServiceError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static tooManyRecords(max: number): BattleRecordServiceError {
+    return new BattleRecordServiceError(
+      `A user can log at most ${max} battles`,
+      'TOO_MANY_BATTLE_RECORDS'
+    );
+  }
+}
+
+export const MAX_BATTLE_RECORDS_PER_USER = 10000;
+
+export interface BattleRecordInput {
+  teamId: string;
+  format: FormatId | null;
+  result: BattleResult;
+  opponent: BattleOpponent;
+  notes: string | null;
+  playedAt: Date;
+}
+
+export interface BattleRecordPage {
+  records: BattleRecord[];
+  total: number;
+}
+
+export interface BattleStats extends BattleTally {
+  battles: number;
+  // 0-100
+  winRate: number;
+}
+
+export interface TeamBattleStats extends BattleStats {
+  teamId: string;
+}
+
+export interface BattleStatsSummary {
+  overall: BattleStats;
+  teams: TeamBattleStats[];
+}
+
+// How a team fares against each kind of opponent, or all of the user's teams when teamId is null
+export interface MatchupStats {
+  teamId: string | null;
+  archetypes: (BattleStats & { archetype: Archetype })[];
+  opponents: (BattleStats & { formId: FormId })[];
+}
+
+function toStats(tally: BattleTally): BattleStats {
+  return {
+    wins: tally.wins,
+    losses: tally.losses,
+    battles: tally.wins + tally.losses,
+    winRate: getWinRate(tally.wins, tally.losses),
+  };
+}
+
+export class BattleRecordService {
+  constructor(
+    private readonly battleRecordRepository: BattleRecordRepository,
+    private readonly teamRepository: TeamRepository,
+    // Official forms only; opponents cannot bring the user's custom forms
+    private readonly formRepository: PokemonFormRepository
+  ) {}
+
+  async log(userId: string, input: BattleRecordInput): Promise<BattleRecord> {
+    const team = await this.teamRepository.findById(input.teamId);
+    if (!team || team.getOwnerId() !== userId || team.isDeleted()) {
+      throw BattleRecordServiceError.teamNotFound(input.teamId);
+    }
+
+    const formIds = input.opponent.formIds;
+    const forms = await this.formRepository.findByIds(formIds);
+    const found = new Set(forms.map((form) => form.getFormId()));
+    const missing = formIds.find((formId) => !found.has(formId));
+    if (missing !== undefined) {
+      throw BattleRecordServiceError.formNotFound(missing);
+    }
+
+    const logged = await this.battleRecordRepository.countByUser(userId, {});
+    if (logged >= MAX_BATTLE_RECORDS_PER_USER) {
+      throw BattleRecordServiceError.tooManyRecords(MAX_BATTLE_RECORDS_PER_USER);
+    }
+
+    const record = BattleRecord.create(
+      uuidv4(),
+      userId,
+      input.teamId,
+      input.format,
+      input.result,
+      input.opponent,
+      input.notes,
+      input.playedAt
+    );
+    await this.battleRecordRepository.create(record);
+    return record;
+  }
+
+  async list(
+    userId: string,
+    filter: BattleRecordFilter,
+    limit: number,
+    offset: number
+  ): Promise<BattleRecordPage> {
+    const [records, total] = await Promise.all([
+      this.battleRecordRepository.findByUser(userId, filter, limit, offset),
+      this.battleRecordRepository.countByUser(userId, filter),
+    ]);
+    return { records, total };
+  }
+
+  async get(userId: string, recordId: string): Promise<BattleRecord> {
+    const record = await this.battleRecordRepository.findById(recordId);
+    if (!record || record.getUserId() !== userId) {
+      throw BattleRecordServiceError.recordNotFound(recordId);
+    }
+    return record;
+  }
+
+  async delete(userId: string, recordId: string): Promise<void> {
+    await this.get(userId, recordId);
+    await this.battleRecordRepository.delete(recordId);
+  }
+
+  // Win rates overall and per team, most played team first. Teams in the trash still count
+  // until they are purged.
+  async summarize(userId: string): Promise<BattleStatsSummary> {
+    const tallies = await this.battleRecordRepository.tallyByTeam(userId);
+    const overall = tallies.reduce(
+      (sum, tally) => ({ wins: sum.wins + tally.wins, losses: sum.losses + tally.losses }),
+      { wins: 0, losses: 0 }
+    );
+    return {
+      overall: toStats(overall),
+      teams: tallies.map((tally) => ({ teamId: tally.teamId, ...toStats(tally) })),
+    };
+  }
+
+  // Win rates against each archetype and each Pokémon seen on the other side. A team in the
+  // trash can still be looked at.
+  async matchups(userId: string, teamId: string | null): Promise<MatchupStats> {
+    if (teamId) {
+      const team = await this.teamRepository.findById(teamId);
+      if (!team || team.getOwnerId() !== userId) {
+        throw BattleRecordServiceError.teamNotFound(teamId);
+      }
+    }
+    const [archetypes, opponents] = await Promise.all([
+      this.battleRecordRepository.tallyByArchetype(userId, teamId),
+      this.battleRecordRepository.tallyByOpponent(userId, teamId),
+    ]);
+    return {
+      teamId,
+      archetypes: archetypes.map((tally) => ({ archetype: tally.archetype, ...toStats(tally) })),
+      opponents: opponents.map((tally) => ({ formId: tally.formId, ...toStats(tally) })),
+    };
+  }
+}
@@ -0,0 +1,105 @@
+import { BattleSide, simulateBattle, SimulationResult } from '../domain/service/BattleSimulator';
+import { Combatant } from '../domain/service/DamageCalculator';
+import { Rng, SystemRandom } from '../domain/service/Rng';
+import { SeededRandom } from '../domain/service/SeededRandom';
+import { estimateTeamMatchup, TeamMatchupEstimate } from '../domain/service/TeamMatchup';
+import { FieldState } from '../domain/service/TurnOrder';
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { Level } from '../domain/valueobject/Level';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+
+export class BattleSimulationError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'BattleSimulationError';
+  }
+
+  static formNotFound(formId: number): BattleSimulationError {
+    return new BattleSimulationError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static moveNotFound(moveName: string): BattleSimulationError {
+    return new BattleSimulationError(`Move ${moveName} not found`, 'MOVE_NOT_FOUND');
+  }
+}
+
+export interface BattleSimulationOptions {
+  level: Level;
+  field: FieldState;
+  simulations: number; // battles, or games for a team matchup
+  // A random one is chosen (and returned) when omitted
+  seed?: number;
+}
+
+export class BattleSimulationService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly moveRepository: MoveRepository,
+    // Picks the seed when none is given
+    private readonly seedSource: Rng = new SystemRandom()
+  ) {}
+
+  // Who wins a 1v1 between two builds, each attacking with its own moves
+  async simulate(
+    first: PokemonBuild,
+    second: PokemonBuild,
+    options: BattleSimulationOptions
+  ): Promise<SimulationResult & { seed: number }> {
+    const [[firstSide], [secondSide]] = await this.toSides([[first], [second]], options.level);
+    const seed = options.seed ?? SeededRandom.randomSeed(this.seedSource);
+    const random = new SeededRandom(seed);
+    return {
+      ...simulateBattle(firstSide, secondSide, options.field, options.simulations, random),
+      seed,
+    };
+  }
+
+  // A rough chance of each team winning, from sampled full-team games
+  async estimateTeamMatchup(
+    first: PokemonBuild[],
+    second: PokemonBuild[],
+    options: BattleSimulationOptions
+  ): Promise<TeamMatchupEstimate & { seed: number }> {
+    const [firstSides, secondSides] = await this.toSides([first, second], options.level);
+    const seed = options.seed ?? SeededRandom.randomSeed(this.seedSource);
+    const random = new SeededRandom(seed);
+    return {
+      ...estimateTeamMatchup(firstSides, secondSides, options.field, options.simulations, random),
+      seed,
+    };
+  }
+
+  private async toSides(teams: PokemonBuild[][], level: Level): Promise<BattleSide[][]> {
+    const builds = teams.flat();
+    const [forms, moves] = await Promise.all([
+      this.formRepository.findByIds([...new Set(builds.map((build) => build.getFormId()))]),
+      this.moveRepository.findByNames([...new Set(builds.flatMap((build) => build.getMoves()))]),
+    ]);
+    const formsById = new Map(forms.map((form) => [form.getFormId(), form]));
+    const movesByKey = new Map(moves.map((move) => [toLookupKey(move.getName()), move]));
+
+    return teams.map((team) =>
+      team.map((build) => {
+        const form = formsById.get(build.getFormId());
+        if (!form) {
+          throw BattleSimulationError.formNotFound(build.getFormId());
+        }
+        return {
+          combatant: new Combatant(form, build, level),
+          moves: build.getMoves().map((moveName) => {
+            const move = movesByKey.get(toLookupKey(moveName));
+            if (!move) {
+              throw BattleSimulationError.moveNotFound(moveName);
+            }
+            return move;
+          }),
+        };
+      })
+    );
+  }
+}
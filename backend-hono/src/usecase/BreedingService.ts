@@ -0,0 +1,144 @@
+import { PokemonSpecies } from '../domain/entity/PokemonSpecies';
+import { EggGroup } from '../domain/valueobject/EggGroup';
+import { LearnMethod } from '../domain/valueobject/Learnset';
+import { SpeciesId } from '../domain/valueobject/PokemonId';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+
+export class BreedingError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'BreedingError';
+  }
+
+  static speciesNotFound(speciesId: number): BreedingError {
+    return new BreedingError(`Species ${speciesId} not found`, 'SPECIES_NOT_FOUND');
+  }
+
+  static notAnEggMove(speciesId: number, moveName: string): BreedingError {
+    return new BreedingError(
+      `${moveName} is not an egg move for species ${speciesId}`,
+      'NOT_AN_EGG_MOVE'
+    );
+  }
+}
+
+export interface BreedingCompatibilityResponse {
+  speciesIdA: SpeciesId;
+  speciesIdB: SpeciesId;
+  compatible: boolean;
+  sharedEggGroups: EggGroup[];
+  reason: string;
+}
+
+export interface EggMoveParent {
+  speciesId: SpeciesId;
+  name: string;
+  nameJp: string;
+  methods: LearnMethod[];
+  // The parent itself only has the move as an egg move, so it must be bred first
+  requiresChain: boolean;
+}
+
+export interface EggMoveParentsResponse {
+  speciesId: SpeciesId;
+  moveName: string;
+  parents: EggMoveParent[];
+}
+
+export class BreedingService {
+  constructor(private readonly speciesRepository: PokemonSpeciesRepository) {}
+
+  async checkCompatibility(
+    speciesIdA: SpeciesId,
+    speciesIdB: SpeciesId
+  ): Promise<BreedingCompatibilityResponse> {
+    const a = await this.getSpecies(speciesIdA);
+    const b = await this.getSpecies(speciesIdB);
+
+    return {
+      speciesIdA,
+      speciesIdB,
+      compatible: a.canBreedWith(b),
+      sharedEggGroups: a.getSharedEggGroups(b),
+      reason: this.describeCompatibility(a, b),
+    };
+  }
+
+  async findEggMoveParents(
+    speciesId: SpeciesId,
+    moveName: string
+  ): Promise<EggMoveParentsResponse> {
+    const target = await this.getSpecies(speciesId);
+
+    const learnsets = await this.speciesRepository.findLearnset(speciesId);
+    if (!learnsets.some((learnset) => learnset.isEggMove(moveName))) {
+      throw BreedingError.notAnEggMove(speciesId, moveName);
+    }
+
+    const learners = await this.speciesRepository.findLearnersOfMove(moveName);
+    const methodsBySpecies = new Map<number, Set<LearnMethod>>();
+    for (const learner of learners) {
+      const methods = methodsBySpecies.get(learner.speciesId) ?? new Set<LearnMethod>();
+      methods.add(learner.method);
+      methodsBySpecies.set(learner.speciesId, methods);
+    }
+
+    const candidates = await this.speciesRepository.findByEggGroups(target.getEggGroups());
+    const parents: EggMoveParent[] = [];
+    for (const candidate of candidates) {
+      const methods = methodsBySpecies.get(candidate.getSpeciesId());
+      // Ditto never passes down moves
+      if (!methods || candidate.isDitto() || !candidate.canBreedWith(target)) {
+        continue;
+      }
+
+      parents.push({
+        speciesId: candidate.getSpeciesId(),
+        name: candidate.getName(),
+        nameJp: candidate.getNameJp(),
+        methods: [...methods],
+        requiresChain: methods.size === 1 && methods.has('egg'),
+      });
+    }
+
+    parents.sort(
+      (x, y) => Number(x.requiresChain) - Number(y.requiresChain) || x.speciesId - y.speciesId
+    );
+
+    return { speciesId, moveName, parents };
+  }
+
+  private async getSpecies(speciesId: SpeciesId): Promise<PokemonSpecies> {
+    const species = await this.speciesRepository.findById(speciesId);
+    if (!species) {
+      throw BreedingError.speciesNotFound(speciesId);
+    }
+    return species;
+  }
+
+  private describeCompatibility(a: PokemonSpecies, b: PokemonSpecies): string {
+    if (!a.canBreed() || !b.canBreed()) {
+      return 'Species in the Undiscovered egg group cannot breed';
+    }
+    if (a.isDitto() && b.isDitto()) {
+      return 'Ditto cannot breed with another Ditto';
+    }
+    if (a.isDitto() || b.isDitto()) {
+      return 'Ditto can breed with any species that can breed';
+    }
+    if (a.getGenderRatio().isGenderless() || b.getGenderRatio().isGenderless()) {
+      return 'Genderless species can only breed with Ditto';
+    }
+    if (!a.hasCompatibleGenders(b)) {
+      return 'Both species are limited to the same gender';
+    }
+    const shared = a.getSharedEggGroups(b);
+    if (shared.length === 0) {
+      return 'No shared egg group';
+    }
+    return `Shared egg group: ${shared.join(', ')}`;
+  }
+}
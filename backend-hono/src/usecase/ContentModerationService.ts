@@ -0,0 +1,129 @@
+import { v4 as uuidv4 } from 'uuid';
+import { ModerationItem } from '../domain/entity/ModerationItem';
+import { ContentKind, ContentPolicy, ContentVerdict } from '../domain/service/ContentPolicy';
+import { ModerationQueueRepository } from '../repository/interface/ModerationQueueRepository';
+import { TeamCommentRepository } from '../repository/interface/TeamCommentRepository';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+
+export class ContentModerationError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'ContentModerationError';
+  }
+
+  // The reasons are left out, so the message does not teach how to get around the lists
+  static rejected(kind: ContentKind): ContentModerationError {
+    const label = { team_name: 'Team name', nickname: 'Nickname', comment: 'Comment' }[kind];
+    return new ContentModerationError(
+      `${label} contains content that is not allowed`,
+      'CONTENT_REJECTED'
+    );
+  }
+
+  static itemNotFound(itemId: string): ContentModerationError {
+    return new ContentModerationError(
+      `Moderation item ${itemId} not found`,
+      'MODERATION_ITEM_NOT_FOUND'
+    );
+  }
+}
+
+export interface PublishedContent {
+  kind: ContentKind;
+  targetId: string;
+  authorId: string;
+  text: string;
+}
+
+export interface ModerationQueuePage {
+  items: ModerationItem[];
+  total: number;
+}
+
+// Runs text through the content policy before other users can see it. Rejected text is refused
+// with an error; text the policy is unsure of is published and queued for a moderator, who
+// either approves it or takes it down.
+export class ContentModerationService {
+  constructor(
+    private readonly policy: ContentPolicy,
+    private readonly moderationQueueRepository: ModerationQueueRepository,
+    private readonly teamRepository: TeamRepository,
+    private readonly teamCommentRepository: TeamCommentRepository
+  ) {}
+
+  // Call before saving; throws if the text is refused
+  screen(kind: ContentKind, text: string): ContentVerdict {
+    const verdict = this.policy.check(kind, text);
+    if (verdict.action === 'reject') {
+      throw ContentModerationError.rejected(kind);
+    }
+    return verdict;
+  }
+
+  // Call once the content is saved. Replaces what was queued for the target before, so an edit
+  // that fixes the text also clears it from the queue.
+  async hold(content: PublishedContent, verdict: ContentVerdict): Promise<void> {
+    await this.moderationQueueRepository.deleteByTarget(content.kind, content.targetId);
+    if (verdict.action !== 'review') {
+      return;
+    }
+
+    await this.moderationQueueRepository.save(
+      ModerationItem.create(
+        uuidv4(),
+        content.kind,
+        content.targetId,
+        content.authorId,
+        content.text,
+        verdict.reasons
+      )
+    );
+  }
+
+  // The content is gone, so nothing is left to review
+  async release(kind: ContentKind, targetId: string): Promise<void> {
+    await this.moderationQueueRepository.deleteByTarget(kind, targetId);
+  }
+
+  async listQueue(limit: number, offset: number): Promise<ModerationQueuePage> {
+    const [items, total] = await Promise.all([
+      this.moderationQueueRepository.findPage(limit, offset),
+      this.moderationQueueRepository.count(),
+    ]);
+    return { items, total };
+  }
+
+  async approve(itemId: string): Promise<void> {
+    if (!(await this.moderationQueueRepository.delete(itemId))) {
+      throw ContentModerationError.itemNotFound(itemId);
+    }
+  }
+
+  // Deletes a comment; a team with an unwanted name or nickname leaves the gallery, and can only
+  // come back once its owner shares it again
+  async takeDown(itemId: string): Promise<void> {
+    const item = await this.moderationQueueRepository.findById(itemId);
+    if (!item) {
+      throw ContentModerationError.itemNotFound(itemId);
+    }
+
+    if (item.getKind() === 'comment') {
+      const authored = await this.teamCommentRepository.findById(item.getTargetId());
+      if (authored && !authored.comment.isDeleted()) {
+        authored.comment.delete();
+        await this.teamCommentRepository.save(authored.comment);
+      }
+    } else {
+      const team = await this.teamRepository.findById(item.getTargetId());
+      if (team?.isShared()) {
+        team.unshare();
+        await this.teamRepository.save(team);
+      }
+    }
+
+    await this.moderationQueueRepository.deleteByTarget(item.getKind(), item.getTargetId());
+  }
+}
@@ -0,0 +1,85 @@
+import { CoverageSolution, CoverageTarget, solveCoverage } from '../domain/service/CoverageSolver';
+import { FormatId } from '../domain/valueobject/Format';
+import { GameVersion } from '../domain/valueobject/GameVersion';
+import { PokemonType } from '../domain/valueobject/PokemonType';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { UsageStatsRepository } from '../repository/interface/UsageStatsRepository';
+
+export class CoverageError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'CoverageError';
+  }
+
+  static noUsageData(formatId: FormatId): CoverageError {
+    return new CoverageError(`No usage data for ${formatId}`, 'USAGE_NOT_FOUND');
+  }
+}
+
+// The metagame is the most used Pokémon of the latest period; the long tail barely moves the
+// weighted coverage
+const METAGAME_SIZE = 100;
+
+export interface CoverageTargets {
+  // The format whose metagame is covered; the whole dex otherwise
+  formatId?: FormatId;
+  // For the whole dex, only forms obtainable in this game
+  availableIn?: GameVersion;
+}
+
+export interface CoverageResponse extends CoverageSolution {
+  formatId: FormatId | null;
+  period: string | null;
+}
+
+export class CoverageService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly usageRepository: UsageStatsRepository
+  ) {}
+
+  // Which attacking type to add to the chosen ones, e.g. the fourth move of a set
+  async recommendType(chosen: PokemonType[], targets: CoverageTargets): Promise<CoverageResponse> {
+    if (!targets.formatId) {
+      const forms = await this.formRepository.findAll({ availableIn: targets.availableIn });
+      const dex = forms.map((form) => ({
+        formId: form.getFormId(),
+        name: form.getName(),
+        nameJp: form.getNameJp(),
+        typeset: form.getTypeset(),
+        weight: 1,
+      }));
+      return { formatId: null, period: null, ...solveCoverage(chosen, dex) };
+    }
+
+    const formatId = targets.formatId;
+    const period = await this.usageRepository.findLatestPeriod(formatId);
+    if (!period) {
+      throw CoverageError.noUsageData(formatId);
+    }
+    const usages = await this.usageRepository.findTop(formatId, period, METAGAME_SIZE);
+    const forms = await this.formRepository.findByIds(usages.map((usage) => usage.getFormId()));
+    const formsById = new Map(forms.map((form) => [form.getFormId(), form]));
+
+    const metagame = usages.flatMap((usage): CoverageTarget[] => {
+      const form = formsById.get(usage.getFormId());
+      if (!form) {
+        return [];
+      }
+      return [
+        {
+          formId: form.getFormId(),
+          name: form.getName(),
+          nameJp: form.getNameJp(),
+          typeset: form.getTypeset(),
+          ability: usage.getCommonSet().ability,
+          weight: usage.getUsage(),
+        },
+      ];
+    });
+    return { formatId, period, ...solveCoverage(chosen, metagame) };
+  }
+}
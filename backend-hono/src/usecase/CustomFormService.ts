@@ -0,0 +1,69 @@
+import { CustomForm, CustomFormProps } from '../domain/entity/CustomForm';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { CustomFormRepository } from '../repository/interface/CustomFormRepository';
+
+export class CustomFormError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'CustomFormError';
+  }
+
+  // Also used for other users' forms
+  static customFormNotFound(formId: number): CustomFormError {
+    return new CustomFormError(`Custom form ${formId} not found`, 'CUSTOM_FORM_NOT_FOUND');
+  }
+
+  static tooManyCustomForms(max: number): CustomFormError {
+    return new CustomFormError(
+      `A user can define at most ${max} custom forms`,
+      'TOO_MANY_CUSTOM_FORMS'
+    );
+  }
+}
+
+export const MAX_CUSTOM_FORMS_PER_USER = 50;
+
+export class CustomFormService {
+  constructor(private readonly customFormRepository: CustomFormRepository) {}
+
+  async create(userId: string, props: CustomFormProps): Promise<CustomForm> {
+    const count = await this.customFormRepository.countByOwner(userId);
+    if (count >= MAX_CUSTOM_FORMS_PER_USER) {
+      throw CustomFormError.tooManyCustomForms(MAX_CUSTOM_FORMS_PER_USER);
+    }
+    return this.customFormRepository.create(userId, props);
+  }
+
+  async list(userId: string): Promise<CustomForm[]> {
+    return this.customFormRepository.findByOwner(userId);
+  }
+
+  async get(userId: string, formId: FormId): Promise<CustomForm> {
+    return this.findOwned(userId, formId);
+  }
+
+  // Teams keep the form ID, so they pick up the changes
+  async update(userId: string, formId: FormId, props: CustomFormProps): Promise<CustomForm> {
+    const form = await this.findOwned(userId, formId);
+    const updated = form.withChanges(props, new Date());
+    await this.customFormRepository.update(updated);
+    return updated;
+  }
+
+  // Team members using the form are left in place, and fail to resolve like any unknown form
+  async delete(userId: string, formId: FormId): Promise<void> {
+    await this.findOwned(userId, formId);
+    await this.customFormRepository.delete(formId);
+  }
+
+  private async findOwned(userId: string, formId: FormId): Promise<CustomForm> {
+    const form = FormId.isCustom(formId) ? await this.customFormRepository.findById(formId) : null;
+    if (!form || form.getOwnerId() !== userId) {
+      throw CustomFormError.customFormNotFound(formId);
+    }
+    return form;
+  }
+}
@@ -0,0 +1,171 @@
+import { v4 as uuidv4 } from 'uuid';
+import { BenchmarkRole, DamageBenchmark } from '../domain/entity/DamageBenchmark';
+import { FormatId, getFormat } from '../domain/valueobject/Format';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { StatStages } from '../domain/valueobject/StatStages';
+import { DamageBenchmarkRepository } from '../repository/interface/DamageBenchmarkRepository';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import {
+  DamageCalculationRequest,
+  DamageCalculationResponse,
+  DamageCalculationService,
+} from './DamageCalculationService';
+
+export class DamageBenchmarkError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'DamageBenchmarkError';
+  }
+
+  // Also used for other users' benchmarks
+  static benchmarkNotFound(benchmarkId: string): DamageBenchmarkError {
+    return new DamageBenchmarkError(`Benchmark ${benchmarkId} not found`, 'BENCHMARK_NOT_FOUND');
+  }
+
+  static tooManyBenchmarks(max: number): DamageBenchmarkError {
+    return new DamageBenchmarkError(
+      `A format can have at most ${max} benchmarks`,
+      'TOO_MANY_BENCHMARKS'
+    );
+  }
+
+  static formNotFound(formId: number): DamageBenchmarkError {
+    return new DamageBenchmarkError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static moveNotFound(moveName: string): DamageBenchmarkError {
+    return new DamageBenchmarkError(`Move ${moveName} not found`, 'MOVE_NOT_FOUND');
+  }
+}
+
+// Keeps a full run (every defensive benchmark times four moves) well inside one batch
+export const MAX_BENCHMARKS_PER_FORMAT = 50;
+
+export interface BenchmarkInput {
+  name: string;
+  role: BenchmarkRole;
+  build: PokemonBuild;
+  move?: string;
+}
+
+export interface BenchmarkRunOptions {
+  // Moves to try on defensive benchmarks; defaults to the build's own moves
+  moves?: string[];
+  terastallized: boolean;
+  critical: boolean;
+}
+
+export interface BenchmarkRunResult {
+  benchmark: DamageBenchmark;
+  result: DamageCalculationResponse;
+}
+
+export class DamageBenchmarkService {
+  constructor(
+    private readonly benchmarkRepository: DamageBenchmarkRepository,
+    private readonly formRepository: PokemonFormRepository,
+    private readonly moveRepository: MoveRepository,
+    private readonly damageCalculationService: DamageCalculationService
+  ) {}
+
+  async create(userId: string, format: FormatId, input: BenchmarkInput): Promise<DamageBenchmark> {
+    const existing = await this.benchmarkRepository.findByOwner(userId, format);
+    if (existing.length >= MAX_BENCHMARKS_PER_FORMAT) {
+      throw DamageBenchmarkError.tooManyBenchmarks(MAX_BENCHMARKS_PER_FORMAT);
+    }
+
+    const form = await this.formRepository.findById(input.build.getFormId());
+    if (!form) {
+      throw DamageBenchmarkError.formNotFound(input.build.getFormId());
+    }
+    if (input.move && !(await this.moveRepository.findByName(input.move))) {
+      throw DamageBenchmarkError.moveNotFound(input.move);
+    }
+
+    const benchmark = DamageBenchmark.create(
+      uuidv4(),
+      userId,
+      format,
+      input.name,
+      input.role,
+      input.build,
+      input.move
+    );
+    await this.benchmarkRepository.save(benchmark);
+    return benchmark;
+  }
+
+  async list(userId: string, format: FormatId): Promise<DamageBenchmark[]> {
+    return this.benchmarkRepository.findByOwner(userId, format);
+  }
+
+  async delete(userId: string, benchmarkId: string): Promise<void> {
+    const benchmark = await this.benchmarkRepository.findById(benchmarkId);
+    if (!benchmark || benchmark.getOwnerId() !== userId) {
+      throw DamageBenchmarkError.benchmarkNotFound(benchmarkId);
+    }
+    await this.benchmarkRepository.delete(benchmarkId);
+  }
+
+  // The build attacks every defensive benchmark with each move and takes the move of every
+  // offensive one, all in one batch at the format's level and battle style
+  async run(
+    userId: string,
+    formatId: FormatId,
+    build: PokemonBuild,
+    options: BenchmarkRunOptions
+  ): Promise<BenchmarkRunResult[]> {
+    const format = getFormat(formatId);
+    const benchmarks = await this.benchmarkRepository.findByOwner(userId, formatId);
+    const moves = options.moves ?? build.getMoves();
+
+    const base = {
+      critical: options.critical,
+      attackerStages: StatStages.neutral(),
+      defenderStages: StatStages.neutral(),
+    };
+    const runs: { benchmark: DamageBenchmark; request: DamageCalculationRequest }[] = [];
+    for (const benchmark of benchmarks) {
+      // Only offensive benchmarks have a move
+      const move = benchmark.getMove();
+      if (move) {
+        runs.push({
+          benchmark,
+          request: {
+            ...base,
+            attacker: benchmark.getBuild(),
+            defender: build,
+            moveName: move,
+            attackerTerastallized: false,
+            defenderTerastallized: options.terastallized,
+          },
+        });
+        continue;
+      }
+      for (const moveName of moves) {
+        runs.push({
+          benchmark,
+          request: {
+            ...base,
+            attacker: build,
+            defender: benchmark.getBuild(),
+            moveName,
+            attackerTerastallized: options.terastallized,
+            defenderTerastallized: false,
+          },
+        });
+      }
+    }
+
+    const results = await this.damageCalculationService.calculateBatch(
+      runs.map((run) => run.request),
+      format.rules.level,
+      format.battleStyle === 'doubles'
+    );
+    return results.map((result, i) => ({ benchmark: runs[i].benchmark, result }));
+  }
+}
@@ -0,0 +1,107 @@
+import { Move } from '../domain/entity/Move';
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { calculateDamage, Combatant, DamageResult } from '../domain/service/DamageCalculator';
+import { CURRENT_GENERATION, Generation } from '../domain/valueobject/Generation';
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { Level } from '../domain/valueobject/Level';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { StatStages } from '../domain/valueobject/StatStages';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+
+export class DamageCalculationError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'DamageCalculationError';
+  }
+
+  static formNotFound(formId: number): DamageCalculationError {
+    return new DamageCalculationError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static moveNotFound(moveName: string): DamageCalculationError {
+    return new DamageCalculationError(`Move ${moveName} not found`, 'MOVE_NOT_FOUND');
+  }
+}
+
+export interface DamageCalculationRequest {
+  attacker: PokemonBuild;
+  defender: PokemonBuild;
+  moveName: string;
+  critical: boolean;
+  attackerTerastallized: boolean;
+  defenderTerastallized: boolean;
+  attackerStages: StatStages;
+  defenderStages: StatStages;
+}
+
+export interface DamageCalculationResponse extends DamageResult {
+  move: Move;
+}
+
+export class DamageCalculationService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly moveRepository: MoveRepository
+  ) {}
+
+  // Master data for the whole batch is loaded up front in two queries, so a full
+  // team-vs-team matrix costs the same number of round trips as a single calculation
+  async calculateBatch(
+    requests: DamageCalculationRequest[],
+    level: Level,
+    doubles: boolean,
+    generation: Generation = CURRENT_GENERATION
+  ): Promise<DamageCalculationResponse[]> {
+    const formIds = requests.flatMap((request) => [
+      request.attacker.getFormId(),
+      request.defender.getFormId(),
+    ]);
+    const [forms, moves] = await Promise.all([
+      this.formRepository.findByIds([...new Set(formIds)]),
+      this.moveRepository.findByNames(requests.map((request) => request.moveName)),
+    ]);
+    const formsById = new Map(forms.map((form) => [form.getFormId(), form]));
+    const movesByKey = new Map(moves.map((move) => [toLookupKey(move.getName()), move]));
+
+    return requests.map((request) => {
+      const move = movesByKey.get(toLookupKey(request.moveName));
+      if (!move) {
+        throw DamageCalculationError.moveNotFound(request.moveName);
+      }
+
+      const attacker = new Combatant(
+        this.getForm(formsById, request.attacker),
+        request.attacker,
+        level,
+        request.attackerTerastallized,
+        request.attackerStages,
+        generation
+      );
+      const defender = new Combatant(
+        this.getForm(formsById, request.defender),
+        request.defender,
+        level,
+        request.defenderTerastallized,
+        request.defenderStages,
+        generation
+      );
+
+      return {
+        move,
+        ...calculateDamage(attacker, defender, move, { critical: request.critical, doubles }),
+      };
+    });
+  }
+
+  private getForm(formsById: Map<number, PokemonMaster>, build: PokemonBuild): PokemonMaster {
+    const form = formsById.get(build.getFormId());
+    if (!form) {
+      throw DamageCalculationError.formNotFound(build.getFormId());
+    }
+    return form;
+  }
+}
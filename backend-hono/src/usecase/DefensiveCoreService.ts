@@ -0,0 +1,48 @@
+import { CoreSize, DefensiveCore, findDefensiveCores } from '../domain/service/DefensiveCore';
+import { GameVersion } from '../domain/valueobject/GameVersion';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+
+export class DefensiveCoreError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'DefensiveCoreError';
+  }
+
+  static formNotFound(formId: number): DefensiveCoreError {
+    return new DefensiveCoreError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+}
+
+export interface DefensiveCorePool {
+  // Only these forms; the whole dex otherwise
+  formIds?: FormId[];
+  // For the whole dex, only forms obtainable in this game
+  availableIn?: GameVersion;
+}
+
+export class DefensiveCoreService {
+  constructor(private readonly formRepository: PokemonFormRepository) {}
+
+  async findCores(
+    pool: DefensiveCorePool,
+    size: CoreSize,
+    limit: number
+  ): Promise<DefensiveCore[]> {
+    if (!pool.formIds) {
+      const forms = await this.formRepository.findAll({ availableIn: pool.availableIn });
+      return findDefensiveCores(forms, size, limit);
+    }
+
+    const forms = await this.formRepository.findByIds(pool.formIds);
+    const found = new Set(forms.map((form) => form.getFormId()));
+    const missing = pool.formIds.find((formId) => !found.has(formId));
+    if (missing !== undefined) {
+      throw DefensiveCoreError.formNotFound(missing);
+    }
+    return findDefensiveCores(forms, size, limit);
+  }
+}
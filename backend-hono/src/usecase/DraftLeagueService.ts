@@ -0,0 +1,288 @@
+import { v4 as uuidv4 } from 'uuid';
+import { DraftLeague, DraftLeagueSettings } from '../domain/entity/DraftLeague';
+import { getFormat } from '../domain/valueobject/Format';
+import { LegalityViolation } from '../domain/valueobject/LegalityViolation';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { DraftLeagueRepository } from '../repository/interface/DraftLeagueRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+import { UserRepository } from '../repository/interface/UserRepository';
+import { TeamLegalityResponse, TeamLegalityService } from './TeamLegalityService';
+
+export class DraftLeagueServiceError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'DraftLeagueServiceError';
+  }
+
+  // Also used for leagues the user neither runs nor takes part in
+  static leagueNotFound(leagueId: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(`Draft league ${leagueId} not found`, 'LEAGUE_NOT_FOUND');
+  }
+
+  static userNotFound(username: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(`User ${username} not found`, 'USER_NOT_FOUND');
+  }
+
+  // Custom forms are private to their owner, so they cannot be drafted either
+  static formNotFound(formId: number): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static poolTooSmall(needed: number): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `The pool needs at least ${needed} Pokémon to fill every roster`,
+      'POOL_TOO_SMALL'
+    );
+  }
+
+  static budgetTooSmall(needed: number): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `The budget needs at least ${needed} points to fill a roster with the cheapest Pokémon`,
+      'BUDGET_TOO_SMALL'
+    );
+  }
+
+  static notCommissioner(leagueId: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `Only the commissioner can do this in draft league ${leagueId}`,
+      'NOT_COMMISSIONER'
+    );
+  }
+
+  static notParticipant(leagueId: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `You are not taking part in draft league ${leagueId}`,
+      'NOT_PARTICIPANT'
+    );
+  }
+
+  static draftComplete(leagueId: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `Every roster in draft league ${leagueId} is full`,
+      'DRAFT_COMPLETE'
+    );
+  }
+
+  static notYourTurn(leagueId: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `It is not your turn to pick in draft league ${leagueId}`,
+      'NOT_YOUR_TURN'
+    );
+  }
+
+  static notInPool(formId: number): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(`Form ${formId} is not in the pool`, 'NOT_IN_POOL');
+  }
+
+  static alreadyPicked(formId: number): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(`Form ${formId} is already drafted`, 'ALREADY_PICKED');
+  }
+
+  static overBudget(formId: number): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `Picking form ${formId} would leave too few points to fill the roster`,
+      'OVER_BUDGET'
+    );
+  }
+
+  static noPicks(leagueId: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `No pick has been made in draft league ${leagueId}`,
+      'NO_PICKS'
+    );
+  }
+
+  // Another pick or undo got in first; reload the league and try again
+  static pickConflict(leagueId: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(
+      `The draft in league ${leagueId} moved on in the meantime`,
+      'PICK_CONFLICT'
+    );
+  }
+
+  // Also used for other users' teams and teams in the trash
+  static teamNotFound(teamId: string): DraftLeagueServiceError {
+    return new DraftLeagueServiceError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+}
+
+// Participants are named by username, in draft order
+export type DraftLeagueInput = Omit<DraftLeagueSettings, 'participantIds'> & {
+  participants: string[];
+};
+
+export class DraftLeagueService {
+  constructor(
+    private readonly draftLeagueRepository: DraftLeagueRepository,
+    private readonly userRepository: UserRepository,
+    // Official forms only
+    private readonly formRepository: PokemonFormRepository,
+    private readonly teamRepository: TeamRepository,
+    private readonly teamLegalityService: TeamLegalityService
+  ) {}
+
+  // The caller becomes the commissioner; they only draft if they list themselves
+  async create(userId: string, input: DraftLeagueInput): Promise<DraftLeague> {
+    const participantIds: string[] = [];
+    for (const username of input.participants) {
+      const user = await this.userRepository.findByUsername(username);
+      if (!user) {
+        throw DraftLeagueServiceError.userNotFound(username);
+      }
+      participantIds.push(user.getUserId());
+    }
+
+    const formIds = input.pool.map((entry) => entry.formId);
+    const forms = await this.formRepository.findByIds(formIds);
+    const found = new Set(forms.map((form) => form.getFormId()));
+    const missing = formIds.find((formId) => !found.has(formId));
+    if (missing !== undefined) {
+      throw DraftLeagueServiceError.formNotFound(missing);
+    }
+
+    const needed = participantIds.length * input.rosterSize;
+    if (input.pool.length < needed) {
+      throw DraftLeagueServiceError.poolTooSmall(needed);
+    }
+    const cheapestRoster = input.pool
+      .map((entry) => entry.cost)
+      .sort((a, b) => a - b)
+      .slice(0, input.rosterSize)
+      .reduce((sum, cost) => sum + cost, 0);
+    if (cheapestRoster > input.budget) {
+      throw DraftLeagueServiceError.budgetTooSmall(cheapestRoster);
+    }
+
+    const league = DraftLeague.create(uuidv4(), userId, { ...input, participantIds });
+    await this.draftLeagueRepository.create(league);
+    return league;
+  }
+
+  async list(userId: string): Promise<DraftLeague[]> {
+    return this.draftLeagueRepository.findByUser(userId);
+  }
+
+  async get(userId: string, leagueId: string): Promise<DraftLeague> {
+    const league = await this.draftLeagueRepository.findById(leagueId);
+    if (!league || (league.getOwnerId() !== userId && !league.isParticipant(userId))) {
+      throw DraftLeagueServiceError.leagueNotFound(leagueId);
+    }
+    return league;
+  }
+
+  async delete(userId: string, leagueId: string): Promise<void> {
+    const league = await this.get(userId, leagueId);
+    if (league.getOwnerId() !== userId) {
+      throw DraftLeagueServiceError.notCommissioner(leagueId);
+    }
+    await this.draftLeagueRepository.delete(leagueId);
+  }
+
+  // Picks for whoever's turn it is. That is the caller, unless they are the commissioner, who
+  // can pick for an absent participant so the draft does not stall.
+  async pick(userId: string, leagueId: string, formId: FormId): Promise<DraftLeague> {
+    const league = await this.get(userId, leagueId);
+    const picker = league.getCurrentPicker();
+    if (!picker) {
+      throw DraftLeagueServiceError.draftComplete(leagueId);
+    }
+    if (picker !== userId && league.getOwnerId() !== userId) {
+      throw DraftLeagueServiceError.notYourTurn(leagueId);
+    }
+    if (league.getCost(formId) === null) {
+      throw DraftLeagueServiceError.notInPool(formId);
+    }
+    if (league.isPicked(formId)) {
+      throw DraftLeagueServiceError.alreadyPicked(formId);
+    }
+    if (!league.canAfford(picker, formId)) {
+      throw DraftLeagueServiceError.overBudget(formId);
+    }
+
+    const pick = league.makePick(picker, formId, new Date());
+    if (!(await this.draftLeagueRepository.addPick(leagueId, pick))) {
+      throw DraftLeagueServiceError.pickConflict(leagueId);
+    }
+    return league;
+  }
+
+  // Takes back the last pick, e.g. a misclick. The commissioner can undo any last pick; a
+  // participant only their own, before the next one is made.
+  async undoPick(userId: string, leagueId: string): Promise<DraftLeague> {
+    const league = await this.get(userId, leagueId);
+    const last = league.getLastPick();
+    if (!last) {
+      throw DraftLeagueServiceError.noPicks(leagueId);
+    }
+    if (league.getOwnerId() !== userId && last.userId !== userId) {
+      throw DraftLeagueServiceError.notCommissioner(leagueId);
+    }
+
+    const undone = league.undoLastPick();
+    if (!(await this.draftLeagueRepository.removePick(leagueId, undone.pickNumber))) {
+      throw DraftLeagueServiceError.pickConflict(leagueId);
+    }
+    return league;
+  }
+
+  // Checks one of the user's teams against their roster: every member must be a Pokémon they
+  // drafted, and obtainable in the game of the league's format
+  async validateTeam(
+    userId: string,
+    leagueId: string,
+    teamId: string
+  ): Promise<TeamLegalityResponse> {
+    const league = await this.get(userId, leagueId);
+    if (!league.isParticipant(userId)) {
+      throw DraftLeagueServiceError.notParticipant(leagueId);
+    }
+    const team = await this.teamRepository.findById(teamId);
+    if (!team || team.getOwnerId() !== userId || team.isDeleted()) {
+      throw DraftLeagueServiceError.teamNotFound(teamId);
+    }
+
+    const drafted = new Set(league.picksOf(userId).map((pick) => pick.formId));
+    const members = team
+      .getPokemon()
+      .flatMap((member, slot) =>
+        member ? [{ slot, formId: FormId.fromRepository(member.formId) }] : []
+      );
+
+    const violations: LegalityViolation[] = members
+      .filter((member) => !drafted.has(member.formId))
+      .map((member): LegalityViolation => ({
+        code: 'NOT_DRAFTED',
+        message: `Form ${member.formId} is not on your roster`,
+        slot: member.slot,
+        formId: member.formId,
+      }));
+    // Slots are counted among the members only, so they are mapped back to the team's own
+    const availability = await this.teamLegalityService.validateForGame(
+      members.map((member) => member.formId),
+      getFormat(league.getFormat()).gameVersion
+    );
+    violations.push(
+      ...availability.violations.map((violation) => ({
+        ...violation,
+        slot: violation.slot === undefined ? undefined : members[violation.slot].slot,
+      }))
+    );
+
+    return { legal: violations.length === 0, violations };
+  }
+
+  // Usernames of everyone in the leagues, for showing who picked what
+  async findUsernames(leagues: DraftLeague[]): Promise<Map<string, string>> {
+    const userIds = [
+      ...new Set(leagues.flatMap((league) => [league.getOwnerId(), ...league.getParticipantIds()])),
+    ];
+    const users = await Promise.all(userIds.map((id) => this.userRepository.findById(id)));
+    return new Map(
+      users.flatMap((user) => (user ? [[user.getUserId(), user.getUsername()] as const] : []))
+    );
+  }
+}
@@ -0,0 +1,226 @@
+import crypto from 'crypto';
+import { EmailChange } from '../domain/entity/EmailChange';
+import { Email } from '../domain/valueobject/Email';
+import { EmailChangeRepository } from '../repository/interface/EmailChangeRepository';
+import { Mailer, MailMessage } from '../repository/interface/Mailer';
+import { EmailAlreadyExistsError, UserRepository } from '../repository/interface/UserRepository';
+
+export class EmailChangeError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'EmailChangeError';
+  }
+
+  static userNotFound(): EmailChangeError {
+    return new EmailChangeError('User not found', 'USER_NOT_FOUND');
+  }
+
+  static invalidPassword(): EmailChangeError {
+    return new EmailChangeError('Password is incorrect', 'INVALID_PASSWORD');
+  }
+
+  static sameEmail(): EmailChangeError {
+    return new EmailChangeError('The new address is the current one', 'SAME_EMAIL');
+  }
+
+  static emailExists(): EmailChangeError {
+    return new EmailChangeError('Email already exists', 'EMAIL_EXISTS');
+  }
+
+  static notFound(): EmailChangeError {
+    return new EmailChangeError('Email change not found', 'EMAIL_CHANGE_NOT_FOUND');
+  }
+
+  static expired(): EmailChangeError {
+    return new EmailChangeError('Email change has expired', 'EMAIL_CHANGE_EXPIRED');
+  }
+
+  static mailUnavailable(): EmailChangeError {
+    return new EmailChangeError('Mail is not configured', 'MAIL_UNAVAILABLE');
+  }
+}
+
+// The confirm token goes to the new address, the cancel token to the old one
+function issueToken(): string {
+  return crypto.randomBytes(32).toString('base64url');
+}
+
+// Tokens are random, so a fast unsalted hash is enough to keep them out of the database
+function hashToken(token: string): string {
+  return crypto.createHash('sha256').update(token).digest('hex');
+}
+
+export interface PendingEmailChange {
+  newEmail: string;
+  expiresAt: Date;
+}
+
+export interface ConfirmedEmailChange {
+  userId: string;
+  email: string;
+}
+
+// Swapping the address directly would let anyone holding a session take the account over, so
+// the password is asked again, the new inbox has to confirm, and the old inbox is told and can
+// cancel until then
+export class EmailChangeService {
+  constructor(
+    private readonly userRepository: UserRepository,
+    private readonly emailChangeRepository: EmailChangeRepository,
+    private readonly mailer?: Mailer,
+    // The frontend, for links in the mails; without it the mails carry the bare tokens
+    private readonly appUrl?: string,
+    private readonly clock: () => Date = () => new Date()
+  ) {}
+
+  async request(userId: string, newEmail: string, password: string): Promise<PendingEmailChange> {
+    if (!this.mailer) {
+      throw EmailChangeError.mailUnavailable();
+    }
+
+    const user = await this.userRepository.findById(userId);
+    if (!user) {
+      throw EmailChangeError.userNotFound();
+    }
+    if (!(await user.verifyPassword(password))) {
+      throw EmailChangeError.invalidPassword();
+    }
+
+    const email = Email.create(newEmail);
+    if (email.getValue() === user.getEmail()) {
+      throw EmailChangeError.sameEmail();
+    }
+    // Checked again by the unique constraint on confirmation
+    if (await this.userRepository.findByEmail(email.getValue())) {
+      throw EmailChangeError.emailExists();
+    }
+
+    const confirmToken = issueToken();
+    const cancelToken = issueToken();
+    const emailChange = EmailChange.request(
+      userId,
+      email,
+      hashToken(confirmToken),
+      hashToken(cancelToken),
+      this.clock()
+    );
+    await this.emailChangeRepository.save(emailChange);
+
+    await this.mailer.send(this.confirmMail(emailChange, confirmToken));
+    await this.mailer.send(this.noticeMail(user.getEmail(), emailChange, cancelToken));
+
+    return this.toPending(emailChange);
+  }
+
+  async findPending(userId: string): Promise<PendingEmailChange | null> {
+    const emailChange = await this.emailChangeRepository.findByUser(userId);
+    if (!emailChange || emailChange.isExpired(this.clock())) {
+      return null;
+    }
+    return this.toPending(emailChange);
+  }
+
+  async confirm(token: string): Promise<ConfirmedEmailChange> {
+    const emailChange = await this.emailChangeRepository.findByConfirmTokenHash(hashToken(token));
+    if (!emailChange) {
+      throw EmailChangeError.notFound();
+    }
+
+    const userId = emailChange.getUserId();
+    if (emailChange.isExpired(this.clock())) {
+      await this.emailChangeRepository.delete(userId);
+      throw EmailChangeError.expired();
+    }
+
+    const user = await this.userRepository.findById(userId);
+    if (!user) {
+      throw EmailChangeError.userNotFound();
+    }
+
+    try {
+      await this.userRepository.updateEmail(userId, emailChange.getNewEmail());
+    } catch (error) {
+      if (error instanceof EmailAlreadyExistsError) {
+        // Someone registered the address after the change was requested
+        await this.emailChangeRepository.delete(userId);
+        throw EmailChangeError.emailExists();
+      }
+      throw error;
+    }
+    await this.emailChangeRepository.delete(userId);
+
+    await this.mailer?.send(this.changedMail(user.getEmail(), emailChange));
+
+    return { userId, email: emailChange.getNewEmail() };
+  }
+
+  // From the link in the notice sent to the old address; works without signing in
+  async cancel(token: string): Promise<void> {
+    const emailChange = await this.emailChangeRepository.findByCancelTokenHash(hashToken(token));
+    if (!emailChange) {
+      throw EmailChangeError.notFound();
+    }
+    await this.emailChangeRepository.delete(emailChange.getUserId());
+  }
+
+  async cancelForUser(userId: string): Promise<void> {
+    if (!(await this.emailChangeRepository.delete(userId))) {
+      throw EmailChangeError.notFound();
+    }
+  }
+
+  private toPending(emailChange: EmailChange): PendingEmailChange {
+    return { newEmail: emailChange.getNewEmail(), expiresAt: emailChange.getExpiresAt() };
+  }
+
+  private link(action: 'confirm' | 'cancel', token: string): string {
+    return this.appUrl
+      ? `${this.appUrl.replace(/\/$/, '')}/email-change/${action}?token=${token}`
+      : token;
+  }
+
+  private confirmMail(emailChange: EmailChange, confirmToken: string): MailMessage {
+    return {
+      to: emailChange.getNewEmail(),
+      subject: '【pokedict】メールアドレス変更の確認 / Confirm your new email address',
+      text: [
+        'メールアドレスの変更を完了するには、次のリンクを開いてください。',
+        'Open this link to make this your pokedict email address:',
+        '',
+        this.link('confirm', confirmToken),
+        '',
+        `有効期限 / Expires: ${emailChange.getExpiresAt().toISOString()}`,
+      ].join('\n'),
+    };
+  }
+
+  private noticeMail(to: string, emailChange: EmailChange, cancelToken: string): MailMessage {
+    return {
+      to,
+      subject: '【pokedict】メールアドレスの変更が申請されました / Email change requested',
+      text: [
+        `アカウントのメールアドレスを ${emailChange.getNewEmail()} に変更する申請がありました。`,
+        `A change of your email address to ${emailChange.getNewEmail()} was requested.`,
+        '',
+        '心当たりがない場合は、次のリンクから取り消し、パスワードを変更してください。',
+        "If this wasn't you, cancel it here and change your password:",
+        '',
+        this.link('cancel', cancelToken),
+      ].join('\n'),
+    };
+  }
+
+  private changedMail(to: string, emailChange: EmailChange): MailMessage {
+    return {
+      to,
+      subject: '【pokedict】メールアドレスを変更しました / Your email address was changed',
+      text: [
+        `アカウントのメールアドレスを ${emailChange.getNewEmail()} に変更しました。`,
+        `Your email address is now ${emailChange.getNewEmail()}.`,
+      ].join('\n'),
+    };
+  }
+}
@@ -0,0 +1,105 @@
+import { FormMedia, mediaUrl, MediaAsset, resolveMediaType } from '../domain/valueobject/FormMedia';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { PokeApiClient } from '../repository/pokeapi/PokeApiClient';
+
+export class FormMediaError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'FormMediaError';
+  }
+
+  static formNotFound(formId: number): FormMediaError {
+    return new FormMediaError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static mediaNotFound(formId: number, asset: MediaAsset): FormMediaError {
+    return new FormMediaError(`Form ${formId} has no ${asset}`, 'MEDIA_NOT_FOUND');
+  }
+
+  static proxyDisabled(): FormMediaError {
+    return new FormMediaError('The media proxy is not enabled', 'MEDIA_PROXY_DISABLED');
+  }
+
+  static upstreamFailed(message: string): FormMediaError {
+    return new FormMediaError(message, 'UPSTREAM_FAILED');
+  }
+
+  static unexpectedMediaType(asset: MediaAsset, contentType: string | null): FormMediaError {
+    return new FormMediaError(
+      `Upstream served the ${asset} as ${contentType ?? 'an unknown type'}`,
+      'UNEXPECTED_MEDIA_TYPE'
+    );
+  }
+}
+
+// Cries run to a few hundred KB and animated sprites far less; anything larger is not one
+const MAX_MEDIA_BYTES = 5 * 1024 * 1024;
+
+export interface FormMediaAsset {
+  contentType: string;
+  body: ArrayBuffer;
+}
+
+export class FormMediaService {
+  // With the proxy on, media links point at this API instead of the upstream hosts, for
+  // clients that cannot reach them or must not leak requests to third parties
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly pokeApi: PokeApiClient,
+    private readonly proxy: boolean
+  ) {}
+
+  // Null for forms last synced before media was added, which are not a 404
+  async getMedia(formId: FormId): Promise<FormMedia | null> {
+    const [form, media] = await Promise.all([
+      this.formRepository.findById(formId),
+      this.formRepository.findMedia(formId),
+    ]);
+    if (!form) {
+      throw FormMediaError.formNotFound(formId);
+    }
+    if (!media) {
+      return null;
+    }
+    if (!this.proxy) {
+      return media;
+    }
+    const proxied = (asset: MediaAsset) =>
+      mediaUrl(media, asset) ? `/api/pokemon/forms/${formId}/media/${asset}` : null;
+    return {
+      ...media,
+      cryUrl: proxied('cry'),
+      legacyCryUrl: proxied('legacy-cry'),
+      spriteUrl: proxied('sprite'),
+      shinySpriteUrl: proxied('shiny-sprite'),
+    };
+  }
+
+  // The file is checked again on every fetch, as the host may have replaced it since the sync
+  async fetchAsset(formId: FormId, asset: MediaAsset): Promise<FormMediaAsset> {
+    if (!this.proxy) {
+      throw FormMediaError.proxyDisabled();
+    }
+    const media = await this.formRepository.findMedia(formId);
+    const url = media && mediaUrl(media, asset);
+    if (!url) {
+      throw FormMediaError.mediaNotFound(formId, asset);
+    }
+
+    let file;
+    try {
+      file = await this.pokeApi.fetchMedia(url, MAX_MEDIA_BYTES);
+    } catch (error) {
+      throw FormMediaError.upstreamFailed(`${error}`);
+    }
+    const contentType = resolveMediaType(asset, file.contentType, new Uint8Array(file.body));
+    if (!contentType) {
+      throw FormMediaError.unexpectedMediaType(asset, file.contentType);
+    }
+    return { contentType, body: file.body };
+  }
+}
@@ -0,0 +1,163 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import {
+  HomeExport,
+  HomeExportError,
+  HomePokemon,
+  parseHomeExport,
+  toStatSpread,
+} from '../domain/valueobject/HomeExport';
+import { NatureSchema } from '../domain/valueobject/Nature';
+import { Nickname } from '../domain/valueobject/Nickname';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { PokemonTypeSchema } from '../domain/valueobject/PokemonType';
+import { EVStats, MAX_IVS } from '../domain/valueobject/Stats';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+
+export class HomeImportError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'HomeImportError';
+  }
+
+  static invalidExport(message: string): HomeImportError {
+    return new HomeImportError(message, 'INVALID_HOME_EXPORT');
+  }
+}
+
+export interface HomeImportedMember {
+  position: number; // index within the box or team
+  name: string;
+  nameJp: string;
+  level?: number;
+  build: PokemonBuild;
+}
+
+export interface HomeImportedGroup {
+  kind: 'box' | 'team';
+  name: string;
+  members: HomeImportedMember[];
+}
+
+// An entry that couldn't be turned into a build; the rest of the export still imports
+export interface HomeUnmappedEntry {
+  kind: 'box' | 'team';
+  group: string;
+  position: number;
+  dexNo: number;
+  formNo: number;
+  reason: string;
+}
+
+export interface HomeImportResult {
+  groups: HomeImportedGroup[];
+  unmapped: HomeUnmappedEntry[];
+}
+
+class UnmappedError extends Error {}
+
+export class HomeImportService {
+  constructor(private readonly formRepository: PokemonFormRepository) {}
+
+  async import(json: string): Promise<HomeImportResult> {
+    let data: HomeExport;
+    try {
+      data = parseHomeExport(json);
+    } catch (error) {
+      if (error instanceof HomeExportError) {
+        throw HomeImportError.invalidExport(error.message);
+      }
+      throw error;
+    }
+
+    // HOME numbers forms per species from 0 for the base form; forms sorted by ID follow the
+    // same order for most species, so the mapping is best effort
+    const formsBySpecies = new Map<number, PokemonMaster[]>();
+    for (const form of await this.formRepository.findAll()) {
+      formsBySpecies.set(form.getSpeciesId(), [
+        ...(formsBySpecies.get(form.getSpeciesId()) ?? []),
+        form,
+      ]);
+    }
+    for (const forms of formsBySpecies.values()) {
+      forms.sort((a, b) => a.getFormId() - b.getFormId());
+    }
+
+    const result: HomeImportResult = { groups: [], unmapped: [] };
+    const groups = [
+      ...data.boxes.map((group) => ({ kind: 'box' as const, ...group })),
+      ...data.teams.map((group) => ({ kind: 'team' as const, ...group })),
+    ];
+    for (const { kind, name, pokemon } of groups) {
+      const members: HomeImportedMember[] = [];
+      pokemon.forEach((entry, position) => {
+        try {
+          const form = formsBySpecies.get(entry.dex_no)?.[entry.form_no];
+          if (!form) {
+            throw new UnmappedError(`No form #${entry.form_no} of species #${entry.dex_no}`);
+          }
+          members.push({
+            position,
+            name: form.getName(),
+            nameJp: form.getNameJp(),
+            level: entry.level,
+            build: this.toBuild(entry, form),
+          });
+        } catch (error) {
+          if (!(error instanceof UnmappedError)) {
+            throw error;
+          }
+          result.unmapped.push({
+            kind,
+            group: name,
+            position,
+            dexNo: entry.dex_no,
+            formNo: entry.form_no,
+            reason: error.message,
+          });
+        }
+      });
+      result.groups.push({ kind, name, members });
+    }
+
+    return result;
+  }
+
+  private toBuild(entry: HomePokemon, form: PokemonMaster): PokemonBuild {
+    const nature = entry.nature === undefined ? undefined : NatureSchema.safeParse(entry.nature);
+    if (nature && !nature.success) {
+      throw new UnmappedError(`Unknown nature "${entry.nature}"`);
+    }
+    const teraType =
+      entry.tera_type === undefined ? undefined : PokemonTypeSchema.safeParse(entry.tera_type);
+    if (teraType && !teraType.success) {
+      throw new UnmappedError(`Unknown Tera Type "${entry.tera_type}"`);
+    }
+
+    const evs = toStatSpread(entry.evs);
+    try {
+      return PokemonBuild.create({
+        formId: form.getFormId(),
+        nickname: entry.nickname === undefined ? undefined : Nickname.create(entry.nickname),
+        ability: entry.ability,
+        heldItem: entry.held_item,
+        moves: entry.moves,
+        teraType: teraType?.data,
+        nature: nature?.data,
+        ev: new EVStats(
+          evs.hp ?? 0,
+          evs.attack ?? 0,
+          evs.defense ?? 0,
+          evs.specialAttack ?? 0,
+          evs.specialDefense ?? 0,
+          evs.speed ?? 0
+        ),
+        iv: { ...MAX_IVS, ...toStatSpread(entry.ivs) },
+      });
+    } catch (error) {
+      throw new UnmappedError((error as Error).message);
+    }
+  }
+}
@@ -0,0 +1,40 @@
+import { Item } from '../domain/entity/Item';
+import { DataImportRepository } from '../repository/interface/DataImportRepository';
+import { ItemPage, ItemRepository, ItemSearchFilter } from '../repository/interface/ItemRepository';
+
+export class ItemLookupError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'ItemLookupError';
+  }
+
+  static itemNotFound(name: string): ItemLookupError {
+    return new ItemLookupError(`Item "${name}" not found`, 'ITEM_NOT_FOUND');
+  }
+}
+
+export class ItemLookupService {
+  constructor(
+    private readonly itemRepository: ItemRepository,
+    private readonly dataImportRepository: DataImportRepository
+  ) {}
+
+  async getDataVersion(): Promise<number> {
+    return this.dataImportRepository.findLatestVersion();
+  }
+
+  async getItem(name: string): Promise<Item> {
+    const item = await this.itemRepository.findByName(name);
+    if (!item) {
+      throw ItemLookupError.itemNotFound(name);
+    }
+    return item;
+  }
+
+  async search(filter: ItemSearchFilter, limit: number, offset: number): Promise<ItemPage> {
+    return this.itemRepository.search(filter, limit, offset);
+  }
+}
@@ -0,0 +1,33 @@
+import { Learnset } from '../domain/valueobject/Learnset';
+import { SpeciesId } from '../domain/valueobject/PokemonId';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+
+// Learnsets are large and most requests need few of them, so they are fetched on first use and
+// kept for the rest of the request. prefetch() loads a known set of species in a single query.
+export class LearnsetLoader {
+  private readonly loaded = new Map<SpeciesId, Promise<Learnset[]>>();
+
+  constructor(private readonly speciesRepository: PokemonSpeciesRepository) {}
+
+  prefetch(speciesIds: SpeciesId[]): Promise<void> {
+    const missing = [...new Set(speciesIds)].filter((speciesId) => !this.loaded.has(speciesId));
+    if (missing.length === 0) {
+      return Promise.resolve();
+    }
+
+    const batch = this.speciesRepository.findLearnsets(missing);
+    for (const speciesId of missing) {
+      this.loaded.set(speciesId, batch.then((learnsets) => learnsets.get(speciesId) ?? []));
+    }
+    return batch.then(() => undefined);
+  }
+
+  get(speciesId: SpeciesId): Promise<Learnset[]> {
+    let learnsets = this.loaded.get(speciesId);
+    if (!learnsets) {
+      learnsets = this.speciesRepository.findLearnset(speciesId);
+      this.loaded.set(speciesId, learnsets);
+    }
+    return learnsets;
+  }
+}
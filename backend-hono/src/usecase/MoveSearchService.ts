@@ -0,0 +1,42 @@
+import { DataImportRepository } from '../repository/interface/DataImportRepository';
+import { MovePage, MoveRepository, MoveSearchFilter } from '../repository/interface/MoveRepository';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+
+export class MoveSearchError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'MoveSearchError';
+  }
+
+  static speciesNotFound(speciesId: number): MoveSearchError {
+    return new MoveSearchError(`Species ${speciesId} not found`, 'SPECIES_NOT_FOUND');
+  }
+}
+
+export class MoveSearchService {
+  constructor(
+    private readonly moveRepository: MoveRepository,
+    private readonly speciesRepository: PokemonSpeciesRepository,
+    private readonly dataImportRepository: DataImportRepository
+  ) {}
+
+  async getDataVersion(): Promise<number> {
+    return this.dataImportRepository.findLatestVersion();
+  }
+
+  // An unknown species is an error rather than an empty page, so typos are not mistaken for a
+  // species that learns nothing
+  async search(filter: MoveSearchFilter, limit: number, offset: number): Promise<MovePage> {
+    if (filter.learnableBy !== undefined) {
+      const species = await this.speciesRepository.findById(filter.learnableBy);
+      if (!species) {
+        throw MoveSearchError.speciesNotFound(filter.learnableBy);
+      }
+    }
+
+    return this.moveRepository.search(filter, limit, offset);
+  }
+}
@@ -0,0 +1,93 @@
+import { v4 as uuidv4 } from 'uuid';
+import { Notification, NotificationContent } from '../domain/entity/Notification';
+import { ActivityEvent } from '../domain/event/ActivityEvent';
+import {
+  NotificationFilter,
+  NotificationRepository,
+} from '../repository/interface/NotificationRepository';
+
+export interface NotificationPage {
+  notifications: Notification[];
+  // Matching the filter, for paging
+  total: number;
+  unread: number;
+}
+
+// Turns activity into notifications for the users it concerns, and lets them read and clear
+// them. Nobody is told about their own actions.
+export class NotificationService {
+  constructor(
+    private readonly notificationRepository: NotificationRepository,
+    private readonly clock: () => Date = () => new Date()
+  ) {}
+
+  async handle(event: ActivityEvent): Promise<void> {
+    switch (event.type) {
+      case 'comment.posted': {
+        const authorId = event.comment.getAuthorId();
+        const comment = {
+          teamId: event.team.getTeamId(),
+          teamName: event.team.getTeamName(),
+          commentId: event.comment.getCommentId(),
+          authorName: event.authorName,
+        };
+        // An owner answered on their own team is told once, about the reply
+        if (event.repliedToId && event.repliedToId !== authorId) {
+          await this.notify(event.repliedToId, { type: 'comment_reply', ...comment });
+        }
+        const ownerId = event.team.getOwnerId();
+        if (ownerId !== authorId && ownerId !== event.repliedToId) {
+          await this.notify(ownerId, { type: 'team_comment', ...comment });
+        }
+        return;
+      }
+      case 'team.liked':
+        await this.notify(event.team.getOwnerId(), {
+          type: 'team_like',
+          teamId: event.team.getTeamId(),
+          teamName: event.team.getTeamName(),
+          likes: event.likes,
+        });
+        return;
+      case 'import.finished':
+        await this.notify(event.userId, {
+          type: 'import_finished',
+          created: event.created,
+          updated: event.updated,
+        });
+        return;
+    }
+  }
+
+  async list(
+    userId: string,
+    filter: NotificationFilter,
+    limit: number,
+    offset: number
+  ): Promise<NotificationPage> {
+    const [notifications, total, unread] = await Promise.all([
+      this.notificationRepository.findPageByUser(userId, filter, limit, offset),
+      this.notificationRepository.countByUser(userId, filter),
+      this.unreadCount(userId),
+    ]);
+    return { notifications, total, unread };
+  }
+
+  async unreadCount(userId: string): Promise<number> {
+    return this.notificationRepository.countByUser(userId, { unreadOnly: true });
+  }
+
+  // Unknown, foreign and already read IDs are skipped; returns how many were marked
+  async markRead(userId: string, notificationIds: string[]): Promise<number> {
+    const unique = [...new Set(notificationIds)];
+    return this.notificationRepository.markRead(userId, unique, this.clock());
+  }
+
+  async markAllRead(userId: string): Promise<number> {
+    return this.notificationRepository.markAllRead(userId, this.clock());
+  }
+
+  private async notify(userId: string, content: NotificationContent): Promise<void> {
+    await this.notificationRepository.save(Notification.create(uuidv4(), userId, content));
+  }
+}
@@ -0,0 +1,60 @@
+import crypto from 'crypto';
+import { z } from 'zod';
+
+export class PageCursorError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'PageCursorError';
+  }
+
+  static invalid(): PageCursorError {
+    return new PageCursorError('Invalid page cursor', 'INVALID_CURSOR');
+  }
+}
+
+// Keyset pagination hands out where the last page ended as an opaque cursor. The position is
+// signed, so clients cannot forge one, and the signature covers the listing, so a cursor from
+// one listing (or one sort order) is rejected by another.
+export class PageCursorCodec {
+  constructor(private readonly secret: string) {}
+
+  encode(listing: string, position: unknown): string {
+    const payload = Buffer.from(JSON.stringify(position)).toString('base64url');
+    return `${payload}.${this.sign(listing, payload)}`;
+  }
+
+  decode<T>(listing: string, cursor: string, schema: z.ZodType<T, z.ZodTypeDef, unknown>): T {
+    const [payload, signature, ...rest] = cursor.split('.');
+    if (!payload || !signature || rest.length > 0) {
+      throw PageCursorError.invalid();
+    }
+
+    const expected = Buffer.from(this.sign(listing, payload));
+    const actual = Buffer.from(signature);
+    if (actual.length !== expected.length || !crypto.timingSafeEqual(actual, expected)) {
+      throw PageCursorError.invalid();
+    }
+
+    let position: unknown;
+    try {
+      position = JSON.parse(Buffer.from(payload, 'base64url').toString());
+    } catch {
+      throw PageCursorError.invalid();
+    }
+    const result = schema.safeParse(position);
+    if (!result.success) {
+      throw PageCursorError.invalid();
+    }
+    return result.data;
+  }
+
+  private sign(listing: string, payload: string): string {
+    return crypto
+      .createHmac('sha256', this.secret)
+      .update(`page-cursor.${listing}.${payload}`)
+      .digest('base64url');
+  }
+}
@@ -0,0 +1,84 @@
+import crypto from 'crypto';
+import { HashedPassword } from '../domain/valueobject/HashedPassword';
+import {
+  PersonalData,
+  PersonalDataRepository,
+  PublishedContent,
+} from '../repository/interface/PersonalDataRepository';
+import { UserRepository } from '../repository/interface/UserRepository';
+
+export class PersonalDataError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'PersonalDataError';
+  }
+
+  static userNotFound(): PersonalDataError {
+    return new PersonalDataError('User not found', 'USER_NOT_FOUND');
+  }
+
+  static invalidPassword(): PersonalDataError {
+    return new PersonalDataError('Password is incorrect', 'INVALID_PASSWORD');
+  }
+}
+
+export interface PersonalDataExport extends PersonalData {
+  exportedAt: Date;
+}
+
+// The sessions of a deleted account, with the IPs and user agents they were used from, are kept
+// this long for abuse investigations
+const AUDIT_RETENTION_MS = 30 * 24 * 60 * 60 * 1000;
+
+// Data subject requests: everything stored about a user, in one machine-readable bundle, and
+// deleting the account with all of it
+export class PersonalDataService {
+  constructor(
+    private readonly personalDataRepository: PersonalDataRepository,
+    private readonly userRepository: UserRepository,
+    private readonly clock: () => Date = () => new Date()
+  ) {}
+
+  async exportUserData(userId: string): Promise<PersonalDataExport> {
+    const data = await this.personalDataRepository.findByUser(userId);
+    if (!data) {
+      throw PersonalDataError.userNotFound();
+    }
+    return { ...data, exportedAt: this.clock() };
+  }
+
+  // The password is asked again, as for an email change, since this cannot be undone
+  async deleteAccount(userId: string, password: string, content: PublishedContent): Promise<void> {
+    const user = await this.userRepository.findById(userId);
+    if (!user) {
+      throw PersonalDataError.userNotFound();
+    }
+    if (!(await user.verifyPassword(password))) {
+      throw PersonalDataError.invalidPassword();
+    }
+
+    // Nobody knows the password behind the hash, and the address cannot receive mail, so the
+    // tombstone can never be signed into or recovered
+    const lockedPassword = await HashedPassword.fromPlainText(
+      crypto.randomBytes(24).toString('base64url')
+    );
+    const tombstone = {
+      username: `deleted_${userId.replace(/-/g, '').slice(0, 12)}`,
+      email: `${userId}@deleted.invalid`,
+      passwordHash: lockedPassword.getHash(),
+    };
+    if (!(await this.personalDataRepository.erase(userId, tombstone, content, this.clock()))) {
+      throw PersonalDataError.userNotFound();
+    }
+  }
+
+  // Run daily; returns how many sessions were deleted
+  async scrubDeletedAccounts(): Promise<number> {
+    return this.personalDataRepository.scrubDeletedBefore(
+      new Date(this.clock().getTime() - AUDIT_RETENTION_MS)
+    );
+  }
+}
@@ -0,0 +1,213 @@
+import { Move } from '../domain/entity/Move';
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { PokemonUsage, UsageDetail } from '../domain/entity/UsageSnapshot';
+import { DefensiveMatchup, getDefensiveProfile } from '../domain/service/DefensiveProfile';
+import { FormatId } from '../domain/valueobject/Format';
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { findFormLearnset } from '../domain/valueobject/Learnset';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { PokemonType } from '../domain/valueobject/PokemonType';
+import { BaseStats, StatName } from '../domain/valueobject/Stats';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+import { UsageStatsRepository } from '../repository/interface/UsageStatsRepository';
+import { LearnsetLoader } from './LearnsetLoader';
+import { PokemonLookupError } from './PokemonLookupService';
+
+const STAT_NAMES: readonly StatName[] = [
+  'hp',
+  'attack',
+  'defense',
+  'specialAttack',
+  'specialDefense',
+  'speed',
+];
+const MAX_ABILITIES = 3;
+const MAX_COVERAGE_MOVES = 2;
+const MAX_USAGE_MOVES = 4;
+
+// Usage data knows what players actually run; without it the learnset only says what is possible
+export type NotableMoveSource = 'usage' | 'learnset';
+
+export interface NotableMove {
+  name: string;
+  type: PokemonType;
+  power?: number;
+  accuracy?: number;
+  stab: boolean;
+  usage?: number;
+}
+
+export interface ComparedForm {
+  formId: FormId;
+  name: string;
+  nameJp: string;
+  types: PokemonType[];
+  baseStats: BaseStats;
+  baseStatTotal: number;
+  // Empty without a format, since abilities come from usage stats
+  abilities: UsageDetail[];
+  // Uses the most common ability when usage data is available
+  defenses: DefensiveMatchup[];
+  moveSource: NotableMoveSource;
+  notableMoves: NotableMove[];
+}
+
+export interface PokemonComparison {
+  formatId?: FormatId;
+  period?: string;
+  forms: ComparedForm[];
+  // Forms with the highest value of each stat; ties list every form
+  statLeaders: Record<StatName | 'total', FormId[]>;
+  // Attacking types whose multiplier is not the same for every compared form
+  defensiveDifferences: { type: PokemonType; multipliers: number[] }[];
+}
+
+export class PokemonComparisonService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly speciesRepository: PokemonSpeciesRepository,
+    private readonly moveRepository: MoveRepository,
+    private readonly usageRepository: UsageStatsRepository
+  ) {}
+
+  async compare(formIds: FormId[], formatId?: FormatId): Promise<PokemonComparison> {
+    const found = await this.formRepository.findByIds(formIds);
+    const byId = new Map(found.map((form) => [form.getFormId(), form]));
+    const forms = formIds.map((formId) => {
+      const form = byId.get(formId);
+      if (!form) {
+        throw PokemonLookupError.formNotFound(formId);
+      }
+      return form;
+    });
+
+    const period = formatId ? await this.usageRepository.findLatestPeriod(formatId) : null;
+    const usages = await Promise.all(
+      forms.map((form) =>
+        formatId && period
+          ? this.usageRepository.findByFormId(formatId, period, form.getFormId())
+          : null
+      )
+    );
+    // Forms without usage data fall back to their learnsets, loaded together
+    const learnsets = new LearnsetLoader(this.speciesRepository);
+    await learnsets.prefetch(
+      forms.filter((_, i) => !usages[i]).map((form) => form.getSpeciesId())
+    );
+    const compared = await Promise.all(
+      forms.map((form, i) => this.compareForm(form, usages[i], learnsets))
+    );
+
+    return {
+      formatId,
+      period: period ?? undefined,
+      forms: compared,
+      statLeaders: getStatLeaders(compared),
+      defensiveDifferences: compared[0].defenses
+        .map((matchup, i) => ({
+          type: matchup.type,
+          multipliers: compared.map((form) => form.defenses[i].multiplier),
+        }))
+        .filter(({ multipliers }) => new Set(multipliers).size > 1),
+    };
+  }
+
+  private async compareForm(
+    form: PokemonMaster,
+    usage: PokemonUsage | null,
+    learnsets: LearnsetLoader
+  ): Promise<ComparedForm> {
+    const typeset = form.getTypeset();
+    const types = [typeset.type1, ...(typeset.type2 ? [typeset.type2] : [])];
+    const abilities = usage ? usage.getDetails('ability').slice(0, MAX_ABILITIES) : [];
+
+    return {
+      formId: form.getFormId(),
+      name: form.getName(),
+      nameJp: form.getNameJp(),
+      types,
+      baseStats: form.getBaseStats(),
+      baseStatTotal: form.getBaseStatTotal(),
+      abilities,
+      defenses: getDefensiveProfile(typeset, abilities[0]?.name),
+      moveSource: usage ? 'usage' : 'learnset',
+      notableMoves: usage
+        ? await this.findUsageMoves(usage, types)
+        : await this.findLearnsetMoves(form, types, learnsets),
+    };
+  }
+
+  private async findUsageMoves(usage: PokemonUsage, types: PokemonType[]): Promise<NotableMove[]> {
+    const details = usage.getDetails('move').slice(0, MAX_USAGE_MOVES);
+    const moves = await this.moveRepository.findByNames(details.map((detail) => detail.name));
+    const movesByKey = new Map(moves.map((move) => [toLookupKey(move.getName()), move]));
+
+    return details.flatMap((detail) => {
+      const move = movesByKey.get(toLookupKey(detail.name));
+      return move ? [{ ...toNotableMove(move, types), usage: detail.percentage }] : [];
+    });
+  }
+
+  // The strongest move of each STAB type, then the strongest coverage moves of other types
+  private async findLearnsetMoves(
+    form: PokemonMaster,
+    types: PokemonType[],
+    learnsets: LearnsetLoader
+  ): Promise<NotableMove[]> {
+    const speciesLearnsets = await learnsets.get(form.getSpeciesId());
+    const learnset = findFormLearnset(speciesLearnsets, form.getFormId(), form.getSpeciesId());
+    if (!learnset) {
+      return [];
+    }
+
+    const moves = await this.moveRepository.findByNames(learnset.getMoveNames());
+    const strongestByType = new Map<PokemonType, Move>();
+    for (const move of moves) {
+      if (move.getDamageClass() === 'status' || move.getPower() === undefined) {
+        continue;
+      }
+      const best = strongestByType.get(move.getType());
+      if (!best || getExpectedPower(move) > getExpectedPower(best)) {
+        strongestByType.set(move.getType(), move);
+      }
+    }
+
+    const stab = types.flatMap((type) => strongestByType.get(type) ?? []);
+    const coverage = [...strongestByType.values()]
+      .filter((move) => !types.includes(move.getType()))
+      .sort((a, b) => getExpectedPower(b) - getExpectedPower(a))
+      .slice(0, MAX_COVERAGE_MOVES);
+
+    return [...stab, ...coverage].map((move) => toNotableMove(move, types));
+  }
+}
+
+// Base power weighted by accuracy; moves that never miss have no accuracy
+function getExpectedPower(move: Move): number {
+  return ((move.getPower() ?? 0) * (move.getAccuracy() ?? 100)) / 100;
+}
+
+function toNotableMove(move: Move, types: PokemonType[]): NotableMove {
+  return {
+    name: move.getName(),
+    type: move.getType(),
+    power: move.getPower(),
+    accuracy: move.getAccuracy(),
+    stab: types.includes(move.getType()),
+  };
+}
+
+function getLeaders(forms: ComparedForm[], value: (form: ComparedForm) => number): FormId[] {
+  const best = Math.max(...forms.map(value));
+  return forms.filter((form) => value(form) === best).map((form) => form.formId);
+}
+
+function getStatLeaders(forms: ComparedForm[]): Record<StatName | 'total', FormId[]> {
+  const leaders = { total: getLeaders(forms, (form) => form.baseStatTotal) };
+  for (const stat of STAT_NAMES) {
+    Object.assign(leaders, { [stat]: getLeaders(forms, (form) => form.baseStats[stat]) });
+  }
+  return leaders as Record<StatName | 'total', FormId[]>;
+}
@@ -0,0 +1,706 @@
+import { Ability } from '../domain/entity/Ability';
+import { Item } from '../domain/entity/Item';
+import { DamageClassSchema, Move } from '../domain/entity/Move';
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { PokemonSpecies } from '../domain/entity/PokemonSpecies';
+import { AbilitySlots } from '../domain/valueobject/AbilitySlots';
+import { RegionalDexNumber } from '../domain/valueobject/DexNumber';
+import { eggGroupFromPokeApi } from '../domain/valueobject/EggGroup';
+import { Encounter } from '../domain/valueobject/Encounter';
+import {
+  FormMedia,
+  isMediaHost,
+  MediaAsset,
+  resolveMediaType,
+} from '../domain/valueobject/FormMedia';
+import {
+  Availability,
+  compareGameVersions,
+  firstGameOfGeneration,
+  GameVersion,
+  gameVersionFromPokeApi,
+  GameVersionSchema,
+} from '../domain/valueobject/GameVersion';
+import { GenderRatio } from '../domain/valueobject/GenderRatio';
+import { Learnset, LearnMethodSchema, LearnsetEntry } from '../domain/valueobject/Learnset';
+import {
+  MoveEffects,
+  MoveStatChange,
+  moveStatFromPokeApi,
+} from '../domain/valueobject/MoveEffects';
+import { FormId, SpeciesId } from '../domain/valueobject/PokemonId';
+import { TypeSet, typeFromPokeApi } from '../domain/valueobject/PokemonType';
+import { PARADOX_SPECIES_IDS, SpeciesCategory } from '../domain/valueobject/SpeciesCategory';
+import { BaseStats } from '../domain/valueobject/Stats';
+import { AbilityRepository } from '../repository/interface/AbilityRepository';
+import {
+  DatasetEntry,
+  DatasetSnapshotRepository,
+} from '../repository/interface/DatasetSnapshotRepository';
+import { ItemRepository } from '../repository/interface/ItemRepository';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+import { SearchDocument, SearchIndex } from '../repository/interface/SearchIndex';
+import {
+  PokeApiAbility,
+  PokeApiClient,
+  PokeApiEncounter,
+  PokeApiError,
+  PokeApiItem,
+  PokeApiMove,
+  PokeApiPokemon,
+  PokeApiSpecies,
+} from '../repository/pokeapi/PokeApiClient';
+
+export class PokemonImportError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'PokemonImportError';
+  }
+
+  static speciesNotFound(speciesId: number): PokemonImportError {
+    return new PokemonImportError(`Species ${speciesId} not found upstream`, 'SPECIES_NOT_FOUND');
+  }
+
+  static moveNotFound(idOrName: number | string): PokemonImportError {
+    return new PokemonImportError(`Move ${idOrName} not found upstream`, 'MOVE_NOT_FOUND');
+  }
+
+  static abilityNotFound(idOrName: number | string): PokemonImportError {
+    return new PokemonImportError(`Ability ${idOrName} not found upstream`, 'ABILITY_NOT_FOUND');
+  }
+
+  static itemNotFound(idOrName: number | string): PokemonImportError {
+    return new PokemonImportError(`Item ${idOrName} not found upstream`, 'ITEM_NOT_FOUND');
+  }
+
+  static upstreamFailed(message: string): PokemonImportError {
+    return new PokemonImportError(message, 'UPSTREAM_FAILED');
+  }
+}
+
+export interface ImportSpeciesResult {
+  speciesId: number;
+  formIds: number[];
+  learnsetEntries: number;
+}
+
+export type ImportFieldValue = string | number | boolean | null;
+
+// Snake-cased as in API responses, e.g. special_attack
+export interface ImportFieldChange {
+  field: string;
+  from: ImportFieldValue;
+  to: ImportFieldValue;
+}
+
+// `removed` forms are stored but no longer listed by PokeAPI; an import leaves them in place
+export type ImportStatus = 'created' | 'updated' | 'unchanged' | 'removed';
+
+export interface FormImportPreview {
+  formId: number;
+  name: string;
+  status: ImportStatus;
+  changes: ImportFieldChange[];
+}
+
+export interface SpeciesImportPreview {
+  speciesId: number;
+  name: string;
+  // Of the species itself; each form has its own
+  status: ImportStatus;
+  changes: ImportFieldChange[];
+  forms: FormImportPreview[];
+}
+
+type ImportFields = Record<string, ImportFieldValue>;
+
+export class PokemonImportService {
+  constructor(
+    private readonly pokeApi: PokeApiClient,
+    private readonly speciesRepository: PokemonSpeciesRepository,
+    private readonly formRepository: PokemonFormRepository,
+    private readonly moveRepository: MoveRepository,
+    private readonly abilityRepository: AbilityRepository,
+    private readonly itemRepository: ItemRepository,
+    private readonly snapshotRepository: DatasetSnapshotRepository,
+    // Kept in sync with every import when full-text search is configured
+    private readonly searchIndex?: SearchIndex
+  ) {}
+
+  async importSpecies(speciesId: number): Promise<ImportSpeciesResult> {
+    const apiSpecies = await this.fetch(() => this.pokeApi.getSpecies(speciesId), speciesId);
+    const species = this.toSpecies(apiSpecies);
+    await this.keepStored({ kind: 'species', entry: species });
+    await this.speciesRepository.save(species);
+    await this.searchIndex?.upsert([this.toSpeciesDocument(apiSpecies)]);
+
+    const formIds: number[] = [];
+    let learnsetEntries = 0;
+    for (const variety of apiSpecies.varieties) {
+      const apiPokemon = await this.fetch(
+        () => this.pokeApi.getPokemon(variety.pokemon.name),
+        speciesId
+      );
+
+      const form = this.toForm(apiSpecies, apiPokemon);
+      await this.keepStored({ kind: 'form', entry: form });
+      await this.formRepository.save(form);
+
+      const learnset = this.toLearnset(apiPokemon);
+      await this.speciesRepository.saveLearnset(learnset);
+
+      const apiEncounters = await this.fetch(
+        () => this.pokeApi.getEncounters(apiPokemon.id),
+        speciesId
+      );
+      await this.formRepository.saveEncounters(
+        FormId.parse(apiPokemon.id),
+        this.toEncounters(apiEncounters)
+      );
+      await this.formRepository.saveMedia(
+        FormId.parse(apiPokemon.id),
+        await this.toMedia(apiPokemon)
+      );
+
+      formIds.push(apiPokemon.id);
+      learnsetEntries += learnset.getEntries().length;
+    }
+
+    return { speciesId, formIds, learnsetEntries };
+  }
+
+  // Compares what importSpecies would write with what is stored, without writing anything.
+  // Learnsets, encounters and media are not compared.
+  async previewSpecies(speciesId: number): Promise<SpeciesImportPreview> {
+    const apiSpecies = await this.fetch(() => this.pokeApi.getSpecies(speciesId), speciesId);
+    const species = this.toSpecies(apiSpecies);
+    const stored = await this.speciesRepository.findById(species.getSpeciesId());
+    const storedForms = await this.formRepository.findBySpeciesId(species.getSpeciesId());
+    const unmatched = new Map(storedForms.map((form) => [form.getFormId(), form]));
+
+    const forms: FormImportPreview[] = [];
+    for (const variety of apiSpecies.varieties) {
+      const apiPokemon = await this.fetch(
+        () => this.pokeApi.getPokemon(variety.pokemon.name),
+        speciesId
+      );
+      const form = this.toForm(apiSpecies, apiPokemon);
+      const current = unmatched.get(form.getFormId());
+      unmatched.delete(form.getFormId());
+
+      forms.push({
+        formId: form.getFormId(),
+        name: form.getName(),
+        ...this.compareFields(current && this.formFields(current), this.formFields(form)),
+      });
+    }
+    for (const form of unmatched.values()) {
+      forms.push({
+        formId: form.getFormId(),
+        name: form.getName(),
+        status: 'removed',
+        changes: [],
+      });
+    }
+
+    return {
+      speciesId: species.getSpeciesId(),
+      name: species.getName(),
+      ...this.compareFields(stored && this.speciesFields(stored), this.speciesFields(species)),
+      forms,
+    };
+  }
+
+  async importRange(fromSpeciesId: number, toSpeciesId: number): Promise<ImportSpeciesResult[]> {
+    const results: ImportSpeciesResult[] = [];
+    for (let speciesId = fromSpeciesId; speciesId <= toSpeciesId; speciesId++) {
+      results.push(await this.importSpecies(speciesId));
+    }
+    return results;
+  }
+
+  async importMove(idOrName: number | string): Promise<Move> {
+    const apiMove = await this.fetchResource(
+      () => this.pokeApi.getMove(idOrName),
+      () => PokemonImportError.moveNotFound(idOrName)
+    );
+
+    const move = this.toMove(apiMove);
+    await this.keepStored({ kind: 'move', entry: move });
+    await this.saveEntry({ kind: 'move', entry: move });
+    return move;
+  }
+
+  async importAbility(idOrName: number | string): Promise<Ability> {
+    const apiAbility = await this.fetchResource(
+      () => this.pokeApi.getAbility(idOrName),
+      () => PokemonImportError.abilityNotFound(idOrName)
+    );
+
+    const ability = this.toAbility(apiAbility);
+    await this.keepStored({ kind: 'ability', entry: ability });
+    await this.saveEntry({ kind: 'ability', entry: ability });
+    return ability;
+  }
+
+  async importItem(idOrName: number | string): Promise<Item> {
+    const apiItem = await this.fetchResource(
+      () => this.pokeApi.getItem(idOrName),
+      () => PokemonImportError.itemNotFound(idOrName)
+    );
+
+    const item = this.toItem(apiItem);
+    await this.keepStored({ kind: 'item', entry: item });
+    await this.saveEntry({ kind: 'item', entry: item });
+    return item;
+  }
+
+  // Writes entries back as they were, e.g. from dataset snapshots. Whatever they replace is kept
+  // in turn, so a rollback can itself be rolled back.
+  async restore(entries: DatasetEntry[]): Promise<void> {
+    for (const entry of entries) {
+      await this.keepStored(entry);
+      await this.saveEntry(entry);
+    }
+  }
+
+  // Keeps the stored copy of the entry before it is overwritten, so the change can be rolled
+  // back. Learnsets, encounters and media are not kept.
+  private async keepStored(entry: DatasetEntry): Promise<void> {
+    const stored = await this.findStored(entry);
+    if (stored) {
+      await this.snapshotRepository.save([stored]);
+    }
+  }
+
+  private async findStored(entry: DatasetEntry): Promise<DatasetEntry | null> {
+    switch (entry.kind) {
+      case 'species': {
+        const species = await this.speciesRepository.findById(entry.entry.getSpeciesId());
+        return species ? { kind: 'species', entry: species } : null;
+      }
+      case 'form': {
+        const form = await this.formRepository.findById(entry.entry.getFormId());
+        return form ? { kind: 'form', entry: form } : null;
+      }
+      case 'move': {
+        const move = await this.moveRepository.findByName(entry.entry.getName());
+        return move ? { kind: 'move', entry: move } : null;
+      }
+      case 'ability': {
+        const ability = await this.abilityRepository.findByName(entry.entry.getName());
+        return ability ? { kind: 'ability', entry: ability } : null;
+      }
+      case 'item': {
+        const item = await this.itemRepository.findByName(entry.entry.getName());
+        return item ? { kind: 'item', entry: item } : null;
+      }
+    }
+  }
+
+  private async saveEntry(entry: DatasetEntry): Promise<void> {
+    switch (entry.kind) {
+      case 'species':
+        await this.speciesRepository.save(entry.entry);
+        return;
+      case 'form':
+        await this.formRepository.save(entry.entry);
+        return;
+      case 'move': {
+        const move = entry.entry;
+        await this.moveRepository.save(move);
+        await this.searchIndex?.upsert([this.toEffectDocument('move', move, [move.getEffect()])]);
+        return;
+      }
+      case 'ability': {
+        const ability = entry.entry;
+        await this.abilityRepository.save(ability);
+        const effects = [ability.getEffect(), ability.getEffectJp()];
+        await this.searchIndex?.upsert([this.toEffectDocument('ability', ability, effects)]);
+        return;
+      }
+      case 'item':
+        await this.itemRepository.save(entry.entry);
+    }
+  }
+
+  private async fetch<T>(request: () => Promise<T>, speciesId: number): Promise<T> {
+    return this.fetchResource(request, () => PokemonImportError.speciesNotFound(speciesId));
+  }
+
+  private async fetchResource<T>(
+    request: () => Promise<T>,
+    notFound: () => PokemonImportError
+  ): Promise<T> {
+    try {
+      return await request();
+    } catch (error) {
+      if (error instanceof PokeApiError && error.status === 404) {
+        throw notFound();
+      }
+      throw PokemonImportError.upstreamFailed(`${error}`);
+    }
+  }
+
+  // `before` is missing when nothing is stored yet
+  private compareFields(
+    before: ImportFields | null | undefined,
+    after: ImportFields
+  ): { status: ImportStatus; changes: ImportFieldChange[] } {
+    const changes = Object.entries(after)
+      .filter(([field, value]) => (before?.[field] ?? null) !== value)
+      .map(([field, value]) => ({ field, from: before?.[field] ?? null, to: value }));
+
+    if (!before) {
+      return { status: 'created', changes };
+    }
+    return { status: changes.length > 0 ? 'updated' : 'unchanged', changes };
+  }
+
+  private speciesFields(species: PokemonSpecies): ImportFields {
+    return {
+      name: species.getName(),
+      name_jp: species.getNameJp(),
+      egg_groups: species.getEggGroups().join(','),
+      female_rate: species.getGenderRatio().getFemaleRate(),
+      capture_rate: species.getCaptureRate(),
+      category: species.getCategory(),
+      evolves_from_species_id: species.getEvolvesFromSpeciesId() ?? null,
+    };
+  }
+
+  private formFields(form: PokemonMaster): ImportFields {
+    const stats = form.getBaseStats();
+    return {
+      name: form.getName(),
+      name_jp: form.getNameJp(),
+      type1: form.getTypeset().type1,
+      type2: form.getTypeset().type2 ?? null,
+      hp: stats.hp,
+      attack: stats.attack,
+      defense: stats.defense,
+      special_attack: stats.specialAttack,
+      special_defense: stats.specialDefense,
+      speed: stats.speed,
+      abilities:
+        form
+          .getAbilities()
+          ?.getEntries()
+          .map((entry) => `${entry.slot}:${entry.ability}`)
+          .join(',') ?? null,
+      base_experience: form.getBaseExperience() ?? null,
+      height_m: form.getHeightMeters(),
+      weight_kg: form.getWeightKg(),
+      shiny_available: form.isShinyAvailable(),
+      introduced_in: form.getAvailability().introducedIn,
+    };
+  }
+
+  private toSpecies(apiSpecies: PokeApiSpecies): PokemonSpecies {
+    return PokemonSpecies.fromRepository(
+      SpeciesId.parse(apiSpecies.id),
+      this.localizedName(apiSpecies, 'en'),
+      this.localizedName(apiSpecies, 'ja-Hrkt'),
+      apiSpecies.egg_groups.map((group) => eggGroupFromPokeApi(group.name)),
+      GenderRatio.fromFemaleEighths(apiSpecies.gender_rate),
+      apiSpecies.capture_rate,
+      this.toCategory(apiSpecies),
+      apiSpecies.evolves_from_species
+        ? SpeciesId.parse(this.idFromUrl(apiSpecies.evolves_from_species.url))
+        : undefined,
+      apiSpecies.pokedex_numbers.flatMap(
+        (entry) => RegionalDexNumber.fromPokeApi(entry.pokedex.name, entry.entry_number) ?? []
+      )
+    );
+  }
+
+  // Pokédex entries are only stored in the search index. Many games reuse an entry, so repeats
+  // are dropped; line breaks follow the game's text boxes.
+  private toSpeciesDocument(apiSpecies: PokeApiSpecies): SearchDocument {
+    const entries = apiSpecies.flavor_text_entries.flatMap(({ flavor_text, language }) => {
+      if (language.name === 'en') {
+        return [flavor_text.replace(/\s+/g, ' ')];
+      }
+      return ['ja-Hrkt', 'ja'].includes(language.name)
+        ? [flavor_text.replace(/\s*\n\s*/g, '　')]
+        : [];
+    });
+
+    return {
+      kind: 'species',
+      id: apiSpecies.id,
+      name: this.localizedName(apiSpecies, 'en'),
+      nameJp: this.localizedName(apiSpecies, 'ja-Hrkt'),
+      text: [...new Set(entries)],
+    };
+  }
+
+  private toEffectDocument(
+    kind: 'move' | 'ability',
+    entry: Move | Ability,
+    effects: (string | undefined)[]
+  ): SearchDocument {
+    return {
+      kind,
+      id: entry.getId(),
+      name: entry.getName(),
+      nameJp: entry.getNameJp(),
+      text: effects.filter((effect): effect is string => effect !== undefined),
+    };
+  }
+
+  private toCategory(apiSpecies: PokeApiSpecies): SpeciesCategory {
+    if (PARADOX_SPECIES_IDS.includes(apiSpecies.id)) {
+      return 'paradox';
+    }
+    if (apiSpecies.is_mythical) {
+      return 'mythical';
+    }
+    return apiSpecies.is_legendary ? 'legendary' : 'ordinary';
+  }
+
+  private toForm(apiSpecies: PokeApiSpecies, apiPokemon: PokeApiPokemon): PokemonMaster {
+    const types = [...apiPokemon.types]
+      .sort((a, b) => a.slot - b.slot)
+      .map((t) => typeFromPokeApi(t.type.name));
+
+    return PokemonMaster.fromRepository({
+      formId: FormId.parse(apiPokemon.id),
+      speciesId: SpeciesId.parse(apiSpecies.id),
+      name: apiPokemon.is_default ? this.localizedName(apiSpecies, 'en') : apiPokemon.name,
+      nameJp: this.localizedName(apiSpecies, 'ja-Hrkt'),
+      typeset: new TypeSet(types[0], types[1]),
+      baseStats: this.toBaseStats(apiPokemon),
+      abilities: this.toAbilities(apiPokemon),
+      baseExperience: apiPokemon.base_experience ?? undefined,
+      height: apiPokemon.height,
+      weight: apiPokemon.weight,
+      // PokeAPI has no shiny-lock data; locks are curated by hand after import
+      shinyAvailable: apiPokemon.sprites.front_shiny !== null,
+      shinyLocked: false,
+      availability: this.toAvailability(apiSpecies, apiPokemon),
+    });
+  }
+
+  private toBaseStats(apiPokemon: PokeApiPokemon): BaseStats {
+    const stat = (name: string) =>
+      apiPokemon.stats.find((s) => s.stat.name === name)?.base_stat ?? 1;
+
+    return {
+      hp: stat('hp'),
+      attack: stat('attack'),
+      defense: stat('defense'),
+      specialAttack: stat('special-attack'),
+      specialDefense: stat('special-defense'),
+      speed: stat('speed'),
+    };
+  }
+
+  // Abilities keep their PokeAPI identifiers (e.g. "solar-power"); lookups ignore spelling
+  private toAbilities(apiPokemon: PokeApiPokemon): AbilitySlots | undefined {
+    return AbilitySlots.fromEntries(
+      apiPokemon.abilities.map((a) => ({
+        slot: a.is_hidden ? 'hidden' : a.slot === 1 ? 'primary' : 'secondary',
+        ability: a.ability.name,
+      }))
+    );
+  }
+
+  // A form counts as obtainable in every version group it has learnset data for
+  private toAvailability(apiSpecies: PokeApiSpecies, apiPokemon: PokeApiPokemon): Availability {
+    const versions = new Set<GameVersion>();
+    for (const { version_group_details } of apiPokemon.moves) {
+      for (const detail of version_group_details) {
+        const version = GameVersionSchema.safeParse(detail.version_group.name);
+        if (version.success) {
+          versions.add(version.data);
+        }
+      }
+    }
+
+    const obtainableIn = [...versions].sort(compareGameVersions);
+    const introducedIn =
+      obtainableIn[0] ?? firstGameOfGeneration(this.generationNumber(apiSpecies.generation.name));
+
+    return new Availability(introducedIn, obtainableIn);
+  }
+
+  // "https://pokeapi.co/api/v2/pokemon-species/172/" -> 172
+  private idFromUrl(url: string): number {
+    return Number(url.split('/').filter(Boolean).pop());
+  }
+
+  // "generation-iv" -> 4
+  private generationNumber(name: string): number {
+    const numerals: Record<string, number> = { i: 1, v: 5, x: 10 };
+    const roman = name.replace('generation-', '');
+    let total = 0;
+    for (let i = 0; i < roman.length; i++) {
+      const current = numerals[roman[i]];
+      const next = numerals[roman[i + 1]] ?? 0;
+      total += current < next ? -current : current;
+    }
+    return total;
+  }
+
+  private toLearnset(apiPokemon: PokeApiPokemon): Learnset {
+    const entries = new Map<string, LearnsetEntry>();
+    for (const { move, version_group_details } of apiPokemon.moves) {
+      for (const detail of version_group_details) {
+        const method = LearnMethodSchema.safeParse(detail.move_learn_method.name);
+        if (!method.success) {
+          continue;
+        }
+        entries.set(`${move.name}:${method.data}`, {
+          moveName: move.name,
+          method: method.data,
+          level: method.data === 'level-up' ? detail.level_learned_at : undefined,
+        });
+      }
+    }
+    return new Learnset(FormId.parse(apiPokemon.id), [...entries.values()]);
+  }
+
+  // Links to other hosts, and links the host answers with the wrong kind of file, are dropped
+  private async toMedia(apiPokemon: PokeApiPokemon): Promise<FormMedia> {
+    const animated = apiPokemon.sprites.other?.showdown;
+    const home = apiPokemon.sprites.other?.home;
+    return {
+      cryUrl: await this.checkMedia('cry', apiPokemon.cries?.latest),
+      legacyCryUrl: await this.checkMedia('legacy-cry', apiPokemon.cries?.legacy),
+      spriteUrl: await this.checkMedia('sprite', animated?.front_default),
+      shinySpriteUrl: await this.checkMedia('shiny-sprite', animated?.front_shiny),
+      has3dModel: Boolean(home?.front_default),
+      has3dShinyModel: Boolean(home?.front_shiny),
+      checkedAt: new Date(),
+    };
+  }
+
+  private async checkMedia(asset: MediaAsset, url?: string | null): Promise<string | null> {
+    if (!url || !isMediaHost(url)) {
+      return null;
+    }
+    // The probe answers a missing asset with null, so this never reports a 404
+    const probe = await this.fetchResource(
+      () => this.pokeApi.probeMedia(url),
+      () => PokemonImportError.upstreamFailed(`Media not found: ${url}`)
+    );
+    return probe && resolveMediaType(asset, probe.contentType, probe.head) ? url : null;
+  }
+
+  // Encounters in games outside the main series are dropped
+  private toEncounters(apiEncounters: PokeApiEncounter[]): Encounter[] {
+    const encounters = new Map<string, Encounter>();
+    for (const { location_area, version_details } of apiEncounters) {
+      for (const { version } of version_details) {
+        const gameVersion = gameVersionFromPokeApi(version.name);
+        if (gameVersion) {
+          encounters.set(`${gameVersion}:${location_area.name}`, {
+            gameVersion,
+            location: location_area.name,
+          });
+        }
+      }
+    }
+    return [...encounters.values()];
+  }
+
+  private toMove(apiMove: PokeApiMove): Move {
+    return Move.fromRepository({
+      id: apiMove.id,
+      name: apiMove.name,
+      nameJp: this.localizedName(apiMove, 'ja-Hrkt'),
+      type: typeFromPokeApi(apiMove.type.name),
+      damageClass: DamageClassSchema.parse(apiMove.damage_class.name),
+      power: apiMove.power ?? undefined,
+      accuracy: apiMove.accuracy ?? undefined,
+      priority: apiMove.priority,
+      pp: apiMove.pp ?? undefined,
+      target: apiMove.target.name,
+      minHits: apiMove.meta?.min_hits ?? undefined,
+      maxHits: apiMove.meta?.max_hits ?? undefined,
+      effect: this.toEffect(apiMove),
+      effects: this.toMoveEffects(apiMove),
+    });
+  }
+
+  private toMoveEffects(apiMove: PokeApiMove): MoveEffects | undefined {
+    const meta = apiMove.meta;
+    if (!meta) {
+      return undefined;
+    }
+
+    const category = meta.category.name;
+    const statChanges: MoveStatChange[] = apiMove.stat_changes.map(({ change, stat }) => ({
+      stat: moveStatFromPokeApi(stat.name),
+      stages: change,
+      // Close Combat lowers the user's own stats, yet is filed under "damage+raise"
+      target:
+        category === 'damage+raise' || (category !== 'damage+lower' && change > 0)
+          ? 'user'
+          : 'target',
+    }));
+    return {
+      flinchChance: meta.flinch_chance,
+      // 0 on moves such as Swords Dance, whose stat changes always happen
+      statChance: statChanges.length > 0 && meta.stat_chance === 0 ? 100 : meta.stat_chance,
+      statChanges,
+      ailment: meta.ailment.name === 'none' ? null : meta.ailment.name,
+      ailmentChance: meta.ailment_chance,
+      drain: Math.max(0, meta.drain) / 100,
+      recoil: Math.max(0, -meta.drain) / 100,
+      healing: Math.max(0, meta.healing) / 100,
+    };
+  }
+
+  // PokeAPI leaves the chance as a placeholder: "Has a $effect_chance% chance to burn the target"
+  private toEffect(apiMove: PokeApiMove): string | undefined {
+    const entry = apiMove.effect_entries.find((e) => e.language.name === 'en');
+    return entry?.short_effect.replace(/\$effect_chance/g, String(apiMove.effect_chance ?? ''));
+  }
+
+  private toAbility(apiAbility: PokeApiAbility): Ability {
+    return Ability.fromRepository({
+      id: apiAbility.id,
+      name: apiAbility.name,
+      nameJp: this.localizedName(apiAbility, 'ja-Hrkt'),
+      effect: apiAbility.effect_entries.find((e) => e.language.name === 'en')?.short_effect,
+      effectJp: this.latestFlavorTextJp(
+        apiAbility.flavor_text_entries.map((e) => ({ text: e.flavor_text, language: e.language }))
+      ),
+    });
+  }
+
+  private toItem(apiItem: PokeApiItem): Item {
+    return Item.fromRepository({
+      id: apiItem.id,
+      name: apiItem.name,
+      nameJp: this.localizedName(apiItem, 'ja-Hrkt'),
+      category: apiItem.category.name,
+      effect: apiItem.effect_entries.find((e) => e.language.name === 'en')?.short_effect,
+      effectJp: this.latestFlavorTextJp(apiItem.flavor_text_entries),
+    });
+  }
+
+  // PokeAPI has no Japanese effect text, so the newest in-game description stands in for it.
+  // Line breaks follow the game's text boxes and become the full-width spaces they stand for.
+  private latestFlavorTextJp(
+    entries: { text: string; language: { name: string } }[]
+  ): string | undefined {
+    const japanese = entries.filter((e) => ['ja-Hrkt', 'ja'].includes(e.language.name));
+    return japanese[japanese.length - 1]?.text.replace(/\s*\n\s*/g, '　');
+  }
+
+  private localizedName(
+    resource: PokeApiSpecies | PokeApiMove | PokeApiAbility | PokeApiItem,
+    language: string
+  ): string {
+    const localized = resource.names.find((n) => n.language.name === language);
+    return localized?.name ?? resource.name;
+  }
+}
@@ -0,0 +1,295 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { PokemonSpecies } from '../domain/entity/PokemonSpecies';
+import { AbilityEntry } from '../domain/valueobject/AbilitySlots';
+import { parseDexNumberQuery, RegionalDexNumber } from '../domain/valueobject/DexNumber';
+import { EggGroup } from '../domain/valueobject/EggGroup';
+import { PokemonType } from '../domain/valueobject/PokemonType';
+import { GameVersion } from '../domain/valueobject/GameVersion';
+import { getNameKeys, getQueryKeys } from '../domain/valueobject/NameKey';
+import { FormId, FormIdSchema } from '../domain/valueobject/PokemonId';
+import { DataImportRepository } from '../repository/interface/DataImportRepository';
+import {
+  PokemonFormFilter,
+  PokemonFormRepository,
+} from '../repository/interface/PokemonFormRepository';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+import { PageCursorCodec } from './PageCursorCodec';
+
+// Forms read per query when exporting the dex
+const EXPORT_PAGE_SIZE = 200;
+
+const FORM_LIST_CURSOR = 'dex.forms';
+
+export class PokemonLookupError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'PokemonLookupError';
+  }
+
+  static formNotFound(formId: number): PokemonLookupError {
+    return new PokemonLookupError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static nameNotFound(name: string): PokemonLookupError {
+    return new PokemonLookupError(`Pokémon "${name}" not found`, 'FORM_NOT_FOUND');
+  }
+
+  static speciesNotFound(speciesId: number): PokemonLookupError {
+    return new PokemonLookupError(`Species ${speciesId} not found`, 'SPECIES_NOT_FOUND');
+  }
+
+  static dexNumberNotFound({ dex, number }: RegionalDexNumber): PokemonLookupError {
+    return new PokemonLookupError(`No species has ${dex} #${number}`, 'SPECIES_NOT_FOUND');
+  }
+}
+
+export interface PokemonFormDetailResponse {
+  formId: number;
+  speciesId: number;
+  name: string;
+  nameJp: string;
+  type1: PokemonType;
+  type2?: PokemonType;
+  // Empty until the form's ability data has been imported
+  abilities: AbilityEntry[];
+  baseExperience?: number;
+  heightMeters: number;
+  weightKg: number;
+  shinyAvailable: boolean;
+  shinyLocked: boolean;
+  genderless: boolean;
+  femaleRate: number | null;
+  maleRate: number | null;
+  captureRate: number;
+  eggGroups: EggGroup[];
+  regionalDexNumbers: RegionalDexNumber[];
+  introducedIn: GameVersion;
+  obtainableIn: GameVersion[];
+}
+
+export interface PokemonFormSummaryResponse {
+  formId: number;
+  speciesId: number;
+  name: string;
+  nameJp: string;
+  type1: PokemonType;
+  type2?: PokemonType;
+  introducedIn: GameVersion;
+}
+
+// A form ID, or a name or dex number as findFormByName takes it
+export type FormQuery = FormId | string;
+
+export interface ResolvedForm {
+  query: FormQuery;
+  // Null when nothing matched
+  form: PokemonFormDetailResponse | null;
+}
+
+export interface FormListPage {
+  limit: number;
+  // From the previous page's nextCursor
+  cursor?: string;
+}
+
+export interface FormSummaryList {
+  forms: PokemonFormSummaryResponse[];
+  // Only for paged lists, and absent on the last page
+  nextCursor?: string;
+}
+
+export class PokemonLookupService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly speciesRepository: PokemonSpeciesRepository,
+    private readonly dataImportRepository: DataImportRepository,
+    private readonly pageCursorCodec: PageCursorCodec
+  ) {}
+
+  // Changes whenever imported Pokédex data may have changed; used to validate HTTP caches
+  async getDataVersion(): Promise<number> {
+    return this.dataImportRepository.findLatestVersion();
+  }
+
+  async getForm(formId: FormId): Promise<PokemonFormDetailResponse> {
+    const form = await this.formRepository.findById(formId);
+    if (!form) {
+      throw PokemonLookupError.formNotFound(formId);
+    }
+
+    const species = await this.speciesRepository.findById(form.getSpeciesId());
+    if (!species) {
+      throw PokemonLookupError.speciesNotFound(form.getSpeciesId());
+    }
+
+    return this.toFormDetail(form, species);
+  }
+
+  // Forms are read a page at a time, with their species, so an export never holds the whole
+  // dex in memory
+  async *exportForms(filter: PokemonFormFilter): AsyncGenerator<PokemonFormDetailResponse> {
+    let after: FormId | undefined;
+    for (;;) {
+      const page = await this.formRepository.findPage(filter, EXPORT_PAGE_SIZE, after);
+      const speciesIds = [...new Set(page.map((form) => form.getSpeciesId()))];
+      const species = await this.speciesRepository.findByIds(speciesIds);
+      const speciesById = new Map(species.map((entry) => [entry.getSpeciesId(), entry]));
+
+      for (const form of page) {
+        const formSpecies = speciesById.get(form.getSpeciesId());
+        if (!formSpecies) {
+          throw PokemonLookupError.speciesNotFound(form.getSpeciesId());
+        }
+        yield this.toFormDetail(form, formSpecies);
+      }
+
+      if (page.length < EXPORT_PAGE_SIZE) {
+        return;
+      }
+      after = page[page.length - 1].getFormId();
+    }
+  }
+
+  // The species' default form
+  async findFormByRegionalDexNumber(
+    dexNumber: RegionalDexNumber
+  ): Promise<PokemonFormDetailResponse> {
+    const species = await this.speciesRepository.findByRegionalDexNumber(dexNumber);
+    if (!species) {
+      throw PokemonLookupError.dexNumberNotFound(dexNumber);
+    }
+    return this.getForm(FormId.defaultOf(species.getSpeciesId()));
+  }
+
+  // Matches the English name in any spelling ("Rotom-Wash", "rotomwash"), the exact Japanese
+  // name, or a dex number such as "Paldea #25" or "#445"
+  async findFormByName(name: string): Promise<PokemonFormDetailResponse> {
+    const dexNumber = parseDexNumberQuery(name);
+    if (dexNumber?.kind === 'national') {
+      return this.getForm(FormId.defaultOf(dexNumber.number));
+    }
+    if (dexNumber?.kind === 'regional') {
+      return this.findFormByRegionalDexNumber(dexNumber);
+    }
+
+    // English, kana or romaji; the lowest form ID wins when keys of different forms collide
+    const [form] = await this.formRepository.findByNameKeys(getQueryKeys(name));
+    if (!form) {
+      throw PokemonLookupError.nameNotFound(name);
+    }
+    return this.getForm(form.getFormId());
+  }
+
+  // Many forms in one call, in the order asked, with one query for the forms and one for their
+  // species. Names match as in findFormByName, except that regional dex numbers are not looked up.
+  async resolveForms(queries: FormQuery[]): Promise<ResolvedForm[]> {
+    const lookups = queries.map(toFormLookup);
+    const formIds = lookups.flatMap((lookup) => ('formId' in lookup ? [lookup.formId] : []));
+    const keys = [...new Set(lookups.flatMap((lookup) => ('keys' in lookup ? lookup.keys : [])))];
+    const forms = await this.formRepository.findByIdsOrNameKeys(formIds, keys);
+
+    const speciesIds = [...new Set(forms.map((form) => form.getSpeciesId()))];
+    const species = await this.speciesRepository.findByIds(speciesIds);
+    const speciesById = new Map(species.map((entry) => [entry.getSpeciesId(), entry]));
+    const formsById = new Map(forms.map((form) => [form.getFormId(), form]));
+    // Forms come by form ID, so the first one matching a name is the one findFormByName returns
+    const formKeys = forms.map((form) => ({
+      form,
+      keys: new Set(getNameKeys(form.getName(), form.getNameJp())),
+    }));
+
+    return queries.map((query, index) => {
+      const lookup = lookups[index];
+      const form =
+        'formId' in lookup
+          ? formsById.get(lookup.formId)
+          : formKeys.find((entry) => lookup.keys.some((key) => entry.keys.has(key)))?.form;
+      if (!form) {
+        return { query, form: null };
+      }
+      const formSpecies = speciesById.get(form.getSpeciesId());
+      if (!formSpecies) {
+        throw PokemonLookupError.speciesNotFound(form.getSpeciesId());
+      }
+      return { query, form: this.toFormDetail(form, formSpecies) };
+    });
+  }
+
+  async listEncounterLocations(gameVersion: GameVersion): Promise<string[]> {
+    return this.formRepository.findEncounterLocations(gameVersion);
+  }
+
+  // The whole list without `page`; otherwise keyset pages in form ID order
+  async listForms(filter: PokemonFormFilter, page?: FormListPage): Promise<FormSummaryList> {
+    if (!page) {
+      const forms = await this.formRepository.findAll(filter);
+      return { forms: forms.map(toFormSummary) };
+    }
+
+    const after = page.cursor
+      ? this.pageCursorCodec.decode(FORM_LIST_CURSOR, page.cursor, FormIdSchema)
+      : undefined;
+    const forms = await this.formRepository.findPage(filter, page.limit, after);
+    if (forms.length < page.limit) {
+      return { forms: forms.map(toFormSummary) };
+    }
+    const last = forms[forms.length - 1].getFormId();
+    return {
+      forms: forms.map(toFormSummary),
+      nextCursor: this.pageCursorCodec.encode(FORM_LIST_CURSOR, last),
+    };
+  }
+
+  private toFormDetail(form: PokemonMaster, species: PokemonSpecies): PokemonFormDetailResponse {
+    const genderRatio = species.getGenderRatio();
+
+    return {
+      formId: form.getFormId(),
+      speciesId: form.getSpeciesId(),
+      name: form.getName(),
+      nameJp: form.getNameJp(),
+      type1: form.getTypeset().type1,
+      type2: form.getTypeset().type2,
+      abilities: form.getAbilities()?.getEntries() ?? [],
+      baseExperience: form.getBaseExperience(),
+      heightMeters: form.getHeightMeters(),
+      weightKg: form.getWeightKg(),
+      shinyAvailable: form.isShinyAvailable(),
+      shinyLocked: form.isShinyLocked(),
+      genderless: genderRatio.isGenderless(),
+      femaleRate: genderRatio.getFemaleRate(),
+      maleRate: genderRatio.getMaleRate(),
+      captureRate: species.getCaptureRate(),
+      eggGroups: species.getEggGroups(),
+      regionalDexNumbers: species.getRegionalDexNumbers(),
+      introducedIn: form.getAvailability().introducedIn,
+      obtainableIn: form.getAvailability().getObtainableIn(),
+    };
+  }
+}
+
+function toFormLookup(query: FormQuery): { formId: FormId } | { keys: string[] } {
+  if (typeof query === 'number') {
+    return { formId: query };
+  }
+  const dexNumber = parseDexNumberQuery(query);
+  if (dexNumber?.kind === 'national') {
+    return { formId: FormId.defaultOf(dexNumber.number) };
+  }
+  return { keys: getQueryKeys(query) };
+}
+
+function toFormSummary(form: PokemonMaster): PokemonFormSummaryResponse {
+  return {
+    formId: form.getFormId(),
+    speciesId: form.getSpeciesId(),
+    name: form.getName(),
+    nameJp: form.getNameJp(),
+    type1: form.getTypeset().type1,
+    type2: form.getTypeset().type2,
+    introducedIn: form.getAvailability().introducedIn,
+  };
+}
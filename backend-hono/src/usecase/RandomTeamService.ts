@@ -0,0 +1,190 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { PokemonSpecies } from '../domain/entity/PokemonSpecies';
+import { Rng, SystemRandom } from '../domain/service/Rng';
+import { SeededRandom } from '../domain/service/SeededRandom';
+import { Format, FormatId, FormatMember, getFormat } from '../domain/valueobject/Format';
+import { findFormLearnset } from '../domain/valueobject/Learnset';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { PokemonType } from '../domain/valueobject/PokemonType';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+import { LearnsetLoader } from './LearnsetLoader';
+
+const MAX_MOVES = 4;
+
+export class RandomTeamError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'RandomTeamError';
+  }
+
+  static unsatisfiable(message: string): RandomTeamError {
+    return new RandomTeamError(message, 'CONSTRAINTS_UNSATISFIABLE');
+  }
+}
+
+export interface RandomTeamConstraints {
+  formatId: FormatId;
+  size: number;
+  // Generations the Pokémon were introduced in; all when omitted
+  generations?: number[];
+  // Every type must appear on at least one member
+  requiredTypes?: PokemonType[];
+  minBaseStatTotal?: number;
+  // The same seed and constraints always give the same team while the Pokédex data is unchanged
+  seed?: number;
+}
+
+export interface RandomTeamMember {
+  form: PokemonMaster;
+  build: PokemonBuild;
+}
+
+export interface RandomTeamResult {
+  seed: number;
+  format: Format;
+  members: RandomTeamMember[];
+}
+
+interface Candidate {
+  form: PokemonMaster;
+  species: PokemonSpecies;
+}
+
+export class RandomTeamService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly speciesRepository: PokemonSpeciesRepository,
+    // Picks the seed when none is given
+    private readonly seedSource: Rng = new SystemRandom()
+  ) {}
+
+  async generate(constraints: RandomTeamConstraints): Promise<RandomTeamResult> {
+    const format = getFormat(constraints.formatId);
+    const random = new SeededRandom(constraints.seed ?? SeededRandom.randomSeed(this.seedSource));
+
+    // Candidates come back in form ID order, so shuffling them is what the seed reproduces
+    const candidates = random.shuffle(await this.findCandidates(format, constraints));
+    const team: FormatMember[] = [];
+    const tryAdd = (candidate: Candidate): boolean => {
+      if (team.some((member) => member.form === candidate.form)) {
+        return false;
+      }
+      const member = { slot: team.length, ...candidate, build: emptyBuild(candidate.form) };
+      if (format.validate([...team, member]).length > 0) {
+        return false;
+      }
+      team.push(member);
+      return true;
+    };
+
+    // Required types first, so the free slots cannot use up the only legal picks
+    for (const type of constraints.requiredTypes ?? []) {
+      if (team.some((member) => member.form.getTypeset().hasType(type))) {
+        continue;
+      }
+      const added = candidates.some(
+        (candidate) => candidate.form.getTypeset().hasType(type) && tryAdd(candidate)
+      );
+      if (!added) {
+        throw RandomTeamError.unsatisfiable(`No legal ${type}-type Pokémon fits the constraints`);
+      }
+    }
+    if (team.length > constraints.size) {
+      throw RandomTeamError.unsatisfiable(
+        `The required types need more than ${constraints.size} Pokémon`
+      );
+    }
+
+    for (const candidate of candidates) {
+      if (team.length === constraints.size) {
+        break;
+      }
+      tryAdd(candidate);
+    }
+    if (team.length < constraints.size) {
+      throw RandomTeamError.unsatisfiable(
+        `Only ${team.length} legal Pokémon match the constraints, ${constraints.size} are needed`
+      );
+    }
+
+    const learnsets = new LearnsetLoader(this.speciesRepository);
+    await learnsets.prefetch(team.map(({ species }) => species.getSpeciesId()));
+    const members: FormatMember[] = [];
+    for (const member of team) {
+      const moves = await this.pickMoves(format, member, learnsets, random);
+      const build = PokemonBuild.create({ formId: member.form.getFormId(), moves });
+      members.push({ ...member, build });
+    }
+
+    // Moves were checked one at a time, so check the finished team as a whole
+    const violations = format.validate(members);
+    if (violations.length > 0) {
+      throw RandomTeamError.unsatisfiable(violations[0].message);
+    }
+
+    return {
+      seed: random.seed,
+      format,
+      members: members.map(({ form, build }) => ({ form, build })),
+    };
+  }
+
+  private async findCandidates(
+    format: Format,
+    constraints: RandomTeamConstraints
+  ): Promise<Candidate[]> {
+    const { generations, minBaseStatTotal = 0 } = constraints;
+    const available = await this.formRepository.findAll({ availableIn: format.gameVersion });
+    const forms = available.filter(
+      (form) =>
+        (!generations || generations.includes(form.getAvailability().getIntroducedGeneration())) &&
+        form.getBaseStatTotal() >= minBaseStatTotal
+    );
+
+    const species = await this.speciesRepository.findByIds([
+      ...new Set(forms.map((form) => form.getSpeciesId())),
+    ]);
+    const speciesById = new Map(species.map((s) => [s.getSpeciesId(), s]));
+
+    return forms.flatMap((form) => {
+      const formSpecies = speciesById.get(form.getSpeciesId());
+      return formSpecies ? [{ form, species: formSpecies }] : [];
+    });
+  }
+
+  // Random moves from the learnset, skipping any the format bans
+  private async pickMoves(
+    format: Format,
+    member: FormatMember,
+    learnsets: LearnsetLoader,
+    random: Rng
+  ): Promise<string[]> {
+    const { form, species } = member;
+    const speciesLearnsets = await learnsets.get(species.getSpeciesId());
+    const learnset = findFormLearnset(speciesLearnsets, form.getFormId(), species.getSpeciesId());
+    if (!learnset) {
+      return [];
+    }
+
+    // Sorted first: the repository returns the learnset in no particular order
+    const moves: string[] = [];
+    for (const move of random.shuffle(learnset.getMoveNames().sort())) {
+      if (moves.length === MAX_MOVES) {
+        break;
+      }
+      const build = PokemonBuild.create({ formId: form.getFormId(), moves: [move] });
+      if (format.validate([{ ...member, slot: 0, build }]).length === 0) {
+        moves.push(move);
+      }
+    }
+    return moves;
+  }
+}
+
+function emptyBuild(form: PokemonMaster): PokemonBuild {
+  return PokemonBuild.create({ formId: form.getFormId(), moves: [] });
+}
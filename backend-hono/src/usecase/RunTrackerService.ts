@@ -0,0 +1,123 @@
+import { v4 as uuidv4 } from 'uuid';
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { RunTracker } from '../domain/entity/RunTracker';
+import { GameVersion } from '../domain/valueobject/GameVersion';
+import { SpeciesId } from '../domain/valueobject/PokemonId';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+import { RunTrackerRepository } from '../repository/interface/RunTrackerRepository';
+
+export class RunTrackerServiceError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'RunTrackerServiceError';
+  }
+
+  // Also used for other users' runs, so a run ID never reveals that it exists
+  static runNotFound(runId: string): RunTrackerServiceError {
+    return new RunTrackerServiceError(`Run ${runId} not found`, 'RUN_NOT_FOUND');
+  }
+
+  static speciesNotFound(speciesId: number): RunTrackerServiceError {
+    return new RunTrackerServiceError(`Species ${speciesId} not found`, 'SPECIES_NOT_FOUND');
+  }
+
+  static alreadyUsed(speciesId: number): RunTrackerServiceError {
+    return new RunTrackerServiceError(
+      `Species ${speciesId} is already used in this run`,
+      'SPECIES_ALREADY_USED'
+    );
+  }
+
+  static notUsed(speciesId: number): RunTrackerServiceError {
+    return new RunTrackerServiceError(
+      `Species ${speciesId} is not used in this run`,
+      'SPECIES_NOT_USED'
+    );
+  }
+}
+
+export interface RunDexFilter {
+  // A PokeAPI location area such as "kanto-route-1-area"
+  location?: string;
+  firstStageOnly?: boolean;
+}
+
+export class RunTrackerService {
+  constructor(
+    private readonly runRepository: RunTrackerRepository,
+    private readonly formRepository: PokemonFormRepository,
+    private readonly speciesRepository: PokemonSpeciesRepository
+  ) {}
+
+  async createRun(userId: string, name: string, gameVersion: GameVersion): Promise<RunTracker> {
+    const run = RunTracker.create(uuidv4(), userId, name, gameVersion);
+    await this.runRepository.save(run);
+    return run;
+  }
+
+  async listRuns(userId: string): Promise<RunTracker[]> {
+    return this.runRepository.findByOwner(userId);
+  }
+
+  async getRun(userId: string, runId: string): Promise<RunTracker> {
+    const run = await this.runRepository.findById(runId);
+    if (!run || run.getOwnerId() !== userId) {
+      throw RunTrackerServiceError.runNotFound(runId);
+    }
+    return run;
+  }
+
+  async deleteRun(userId: string, runId: string): Promise<void> {
+    await this.getRun(userId, runId);
+    await this.runRepository.delete(runId);
+  }
+
+  async markUsed(userId: string, runId: string, speciesId: SpeciesId): Promise<RunTracker> {
+    const run = await this.getRun(userId, runId);
+    if (run.isUsed(speciesId)) {
+      throw RunTrackerServiceError.alreadyUsed(speciesId);
+    }
+    if (!(await this.speciesRepository.findById(speciesId))) {
+      throw RunTrackerServiceError.speciesNotFound(speciesId);
+    }
+
+    run.markUsed(speciesId);
+    await this.runRepository.save(run);
+    return run;
+  }
+
+  async unmarkUsed(userId: string, runId: string, speciesId: SpeciesId): Promise<RunTracker> {
+    const run = await this.getRun(userId, runId);
+    if (!run.isUsed(speciesId)) {
+      throw RunTrackerServiceError.notUsed(speciesId);
+    }
+
+    run.unmarkUsed(speciesId);
+    await this.runRepository.save(run);
+    return run;
+  }
+
+  // Forms of species not yet used in the run. With a location, only what can be encountered
+  // there in the run's game; otherwise everything obtainable in that game.
+  async findAvailableForms(
+    userId: string,
+    runId: string,
+    filter: RunDexFilter
+  ): Promise<PokemonMaster[]> {
+    const run = await this.getRun(userId, runId);
+    const forms = await this.formRepository.findAll(
+      filter.location
+        ? {
+            encounterGame: run.getGameVersion(),
+            encounterLocation: filter.location,
+            firstStageOnly: filter.firstStageOnly,
+          }
+        : { availableIn: run.getGameVersion(), firstStageOnly: filter.firstStageOnly }
+    );
+    return forms.filter((form) => !run.isUsed(form.getSpeciesId()));
+  }
+}
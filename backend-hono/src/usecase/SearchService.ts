@@ -0,0 +1,32 @@
+import { SearchDocumentKind, SearchIndex, SearchPage } from '../repository/interface/SearchIndex';
+
+export class SearchError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'SearchError';
+  }
+
+  static unavailable(): SearchError {
+    return new SearchError('Full-text search is not configured', 'SEARCH_UNAVAILABLE');
+  }
+}
+
+// Searches species names and Pokédex entries, move effects and abilities in one query
+export class SearchService {
+  constructor(private readonly searchIndex?: SearchIndex) {}
+
+  async search(
+    query: string,
+    kinds: SearchDocumentKind[],
+    limit: number,
+    offset: number
+  ): Promise<SearchPage> {
+    if (!this.searchIndex) {
+      throw SearchError.unavailable();
+    }
+    return this.searchIndex.search(query, kinds, limit, offset);
+  }
+}
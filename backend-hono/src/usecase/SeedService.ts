@@ -0,0 +1,95 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { PokemonSpecies } from '../domain/entity/PokemonSpecies';
+import { RegionalDex, RegionalDexNumber } from '../domain/valueobject/DexNumber';
+import { EggGroup } from '../domain/valueobject/EggGroup';
+import { Availability, GameVersion } from '../domain/valueobject/GameVersion';
+import { GenderRatio } from '../domain/valueobject/GenderRatio';
+import { FormId, SpeciesId } from '../domain/valueobject/PokemonId';
+import { PokemonType, TypeSet } from '../domain/valueobject/PokemonType';
+import { SpeciesCategory } from '../domain/valueobject/SpeciesCategory';
+import { BaseStats } from '../domain/valueobject/Stats';
+import { DataImportRepository } from '../repository/interface/DataImportRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+
+// One default form per species; form ID equals species ID, as it does in PokeAPI
+export interface SeedSpecies {
+  speciesId: number;
+  name: string;
+  nameJp: string;
+  types: [PokemonType] | [PokemonType, PokemonType];
+  baseStats: BaseStats;
+  eggGroups: EggGroup[];
+  femaleEighths: number; // -1 = genderless
+  captureRate: number;
+  height: number; // decimetres
+  weight: number; // hectograms
+  category: SpeciesCategory;
+  evolvesFrom?: number; // species ID of the pre-evolution
+  dexNumbers?: { dex: RegionalDex; number: number }[];
+  introducedIn: GameVersion;
+  obtainableIn: GameVersion[];
+}
+
+export interface SeedResult {
+  species: number;
+  forms: number;
+  version: number;
+}
+
+// Loads a bundled dataset through the repository interfaces, so it works against whichever
+// storage is configured. Saves are upserts, so seeding twice is harmless.
+export class SeedService {
+  constructor(
+    private readonly speciesRepository: PokemonSpeciesRepository,
+    private readonly formRepository: PokemonFormRepository,
+    private readonly dataImportRepository: DataImportRepository
+  ) {}
+
+  async seed(dataset: SeedSpecies[]): Promise<SeedResult> {
+    for (const entry of dataset) {
+      await this.speciesRepository.save(this.toSpecies(entry));
+      await this.formRepository.save(toSeedForm(entry));
+    }
+
+    const ids = dataset.map((entry) => entry.speciesId);
+    const version = await this.dataImportRepository.record(
+      'seed',
+      Math.min(...ids),
+      Math.max(...ids)
+    );
+    return { species: dataset.length, forms: dataset.length, version };
+  }
+
+  private toSpecies(entry: SeedSpecies): PokemonSpecies {
+    return new PokemonSpecies(
+      SpeciesId.parse(entry.speciesId),
+      entry.name,
+      entry.nameJp,
+      entry.eggGroups,
+      GenderRatio.fromFemaleEighths(entry.femaleEighths),
+      entry.captureRate,
+      entry.category,
+      entry.evolvesFrom === undefined ? undefined : SpeciesId.parse(entry.evolvesFrom),
+      (entry.dexNumbers ?? []).map(({ dex, number }) => RegionalDexNumber.parse(dex, number))
+    );
+  }
+}
+
+// Also used by the benchmarks, which need realistic forms without a database
+export function toSeedForm(entry: SeedSpecies): PokemonMaster {
+  const speciesId = SpeciesId.parse(entry.speciesId);
+  return new PokemonMaster({
+    formId: FormId.defaultOf(speciesId),
+    speciesId,
+    name: entry.name,
+    nameJp: entry.nameJp,
+    typeset: new TypeSet(entry.types[0], entry.types[1]),
+    baseStats: entry.baseStats,
+    height: entry.height,
+    weight: entry.weight,
+    shinyAvailable: true,
+    shinyLocked: false,
+    availability: new Availability(entry.introducedIn, entry.obtainableIn),
+  });
+}
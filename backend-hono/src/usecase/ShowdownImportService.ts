@@ -0,0 +1,96 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { Nickname } from '../domain/valueobject/Nickname';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import {
+  parseShowdownPaste,
+  ShowdownPasteError,
+  ShowdownSet,
+} from '../domain/valueobject/ShowdownPaste';
+import { EVStats, MAX_IVS } from '../domain/valueobject/Stats';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+
+export class ShowdownImportError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'ShowdownImportError';
+  }
+
+  static invalidPaste(message: string): ShowdownImportError {
+    return new ShowdownImportError(message, 'INVALID_PASTE');
+  }
+
+  static unknownSpecies(name: string): ShowdownImportError {
+    return new ShowdownImportError(`Unknown Pokémon "${name}"`, 'UNKNOWN_SPECIES');
+  }
+}
+
+// Showdown assumes level 100 when a set has no Level line
+export const SHOWDOWN_DEFAULT_LEVEL = 100;
+
+export interface ShowdownImportResult {
+  builds: PokemonBuild[];
+  level: number;
+}
+
+export class ShowdownImportService {
+  constructor(private readonly formRepository: PokemonFormRepository) {}
+
+  async import(paste: string): Promise<ShowdownImportResult> {
+    let sets: ShowdownSet[];
+    try {
+      sets = parseShowdownPaste(paste);
+    } catch (error) {
+      if (error instanceof ShowdownPasteError) {
+        throw ShowdownImportError.invalidPaste(error.message);
+      }
+      throw error;
+    }
+    if (sets.length === 0) {
+      throw ShowdownImportError.invalidPaste('The paste contains no Pokémon');
+    }
+
+    // Showdown names forms "Rotom-Wash", which shares a lookup key with PokeAPI's "rotom-wash"
+    const forms = await this.formRepository.findAll();
+    const formsByName = new Map(forms.map((form) => [toLookupKey(form.getName()), form]));
+
+    const builds = sets.map((set) => {
+      const form = formsByName.get(toLookupKey(set.species));
+      if (!form) {
+        throw ShowdownImportError.unknownSpecies(set.species);
+      }
+      return this.toBuild(set, form);
+    });
+
+    return { builds, level: sets[0].level ?? SHOWDOWN_DEFAULT_LEVEL };
+  }
+
+  private toBuild(set: ShowdownSet, form: PokemonMaster): PokemonBuild {
+    try {
+      return PokemonBuild.create({
+        formId: form.getFormId(),
+        nickname: set.nickname === undefined ? undefined : Nickname.create(set.nickname),
+        ability: set.ability,
+        heldItem: set.item,
+        moves: set.moves,
+        teraType: set.teraType,
+        nature: set.nature,
+        ev: new EVStats(
+          set.evs.hp ?? 0,
+          set.evs.attack ?? 0,
+          set.evs.defense ?? 0,
+          set.evs.specialAttack ?? 0,
+          set.evs.specialDefense ?? 0,
+          set.evs.speed ?? 0
+        ),
+        iv: { ...MAX_IVS, ...set.ivs },
+        friendship: set.happiness,
+      });
+    } catch (error) {
+      throw ShowdownImportError.invalidPaste(`${set.species}: ${(error as Error).message}`);
+    }
+  }
+}
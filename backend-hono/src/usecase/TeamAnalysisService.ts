@@ -0,0 +1,199 @@
+import { Move } from '../domain/entity/Move';
+import {
+  ArchetypeMember,
+  ArchetypeScore,
+  classifyArchetypes,
+} from '../domain/service/ArchetypeClassifier';
+import { Combatant } from '../domain/service/DamageCalculator';
+import { getDefensiveProfile, MatchupSource } from '../domain/service/DefensiveProfile';
+import { calculateHazardDamage, HazardDamage } from '../domain/service/EntryHazards';
+import { resolveMoveType } from '../domain/service/MoveTypeResolver';
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { Level } from '../domain/valueobject/Level';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { getEffectiveness, POKEMON_TYPES, PokemonType } from '../domain/valueobject/PokemonType';
+import { findTeamWarnings, TeamWarning } from '../domain/valueobject/TeamWarning';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+
+export class TeamAnalysisError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TeamAnalysisError';
+  }
+
+  static formNotFound(formId: number): TeamAnalysisError {
+    return new TeamAnalysisError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+}
+
+export interface ArchetypeResponse {
+  // Most likely archetype, if any scored above zero
+  primary?: ArchetypeScore;
+  archetypes: ArchetypeScore[];
+}
+
+export interface AnalyzedMember {
+  slot: number;
+  name: string;
+  nameJp: string;
+  types: PokemonType[];
+}
+
+// How the team takes hits of one attacking type; multipliers and sources are per team slot
+export interface DefensiveCoverageRow {
+  type: PokemonType;
+  multipliers: number[];
+  sources: MatchupSource[];
+  weak: number;
+  resist: number;
+  immune: number;
+}
+
+// The best multiplier the team's attacks reach against a single defending type
+export interface OffensiveCoverageRow {
+  type: PokemonType;
+  best: number;
+}
+
+export interface SpeedTier {
+  slot: number;
+  name: string;
+  baseSpeed: number;
+  speed: number;
+}
+
+export interface HazardRow extends HazardDamage {
+  slot: number;
+  name: string;
+}
+
+export interface TeamAnalysisResponse extends ArchetypeResponse {
+  members: AnalyzedMember[];
+  defensiveCoverage: DefensiveCoverageRow[];
+  offensiveCoverage: OffensiveCoverageRow[];
+  // Fastest first
+  speedTiers: SpeedTier[];
+  // Percent of max HP lost to entry hazards on switch-in, in slot order
+  hazards: HazardRow[];
+  // Duplicate items and Tera types; slots refer to `members`
+  warnings: TeamWarning[];
+}
+
+export interface TeamAnalysisOptions {
+  // Count ability-granted immunities and resistances (Levitate, Storm Drain, Heatproof, ...)
+  includeAbilities?: boolean;
+}
+
+export class TeamAnalysisService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly moveRepository: MoveRepository
+  ) {}
+
+  async classify(builds: PokemonBuild[], level: Level): Promise<ArchetypeResponse> {
+    const archetypes = classifyArchetypes(await this.loadMembers(builds, level));
+    return { primary: archetypes[0], archetypes };
+  }
+
+  async analyze(
+    builds: PokemonBuild[],
+    level: Level,
+    options: TeamAnalysisOptions = {}
+  ): Promise<TeamAnalysisResponse> {
+    const members = await this.loadMembers(builds, level);
+    const archetypes = classifyArchetypes(members);
+
+    return {
+      primary: archetypes[0],
+      archetypes,
+      members: members.map(({ combatant }, slot) => ({
+        slot,
+        name: combatant.form.getName(),
+        nameJp: combatant.form.getNameJp(),
+        types: combatant.form.getTypeset().getTypes(),
+      })),
+      defensiveCoverage: this.getDefensiveCoverage(members, options.includeAbilities ?? false),
+      offensiveCoverage: this.getOffensiveCoverage(members),
+      speedTiers: members
+        .map(({ combatant }, slot) => ({
+          slot,
+          name: combatant.form.getName(),
+          baseSpeed: combatant.form.getBaseStats().speed,
+          speed: combatant.getEffectiveStat('speed'),
+        }))
+        .sort((a, b) => b.speed - a.speed),
+      hazards: members.map(({ combatant }, slot) => ({
+        slot,
+        name: combatant.form.getName(),
+        ...calculateHazardDamage(combatant),
+      })),
+      warnings: findTeamWarnings(builds),
+    };
+  }
+
+  private getDefensiveCoverage(
+    members: ArchetypeMember[],
+    includeAbilities: boolean
+  ): DefensiveCoverageRow[] {
+    const profiles = members.map(({ combatant }) =>
+      getDefensiveProfile(
+        combatant.getDefensiveTypes(),
+        includeAbilities ? combatant.build.getAbility() : undefined
+      )
+    );
+    return POKEMON_TYPES.map((type, index) => {
+      const matchups = profiles.map((profile) => profile[index]);
+      const multipliers = matchups.map((matchup) => matchup.multiplier);
+      return {
+        type,
+        multipliers,
+        sources: matchups.map((matchup) => matchup.source),
+        weak: multipliers.filter((multiplier) => multiplier > 1).length,
+        resist: multipliers.filter((multiplier) => multiplier > 0 && multiplier < 1).length,
+        immune: multipliers.filter((multiplier) => multiplier === 0).length,
+      };
+    });
+  }
+
+  private getOffensiveCoverage(members: ArchetypeMember[]): OffensiveCoverageRow[] {
+    const attackTypes = new Set(
+      members.flatMap(({ combatant, moves }) =>
+        moves
+          .map((move) => resolveMoveType(combatant, move).move)
+          .filter((move) => move.isDamaging())
+          .map((move) => move.getType())
+      )
+    );
+    return POKEMON_TYPES.map((type) => ({
+      type,
+      best: Math.max(0, ...[...attackTypes].map((attack) => getEffectiveness(attack, type))),
+    }));
+  }
+
+  private async loadMembers(builds: PokemonBuild[], level: Level): Promise<ArchetypeMember[]> {
+    const [forms, moves] = await Promise.all([
+      this.formRepository.findByIds([...new Set(builds.map((build) => build.getFormId()))]),
+      this.moveRepository.findByNames(builds.flatMap((build) => build.getMoves())),
+    ]);
+    const formsById = new Map(forms.map((form) => [form.getFormId(), form]));
+    const movesByKey = new Map(moves.map((move) => [toLookupKey(move.getName()), move]));
+
+    return builds.map((build) => {
+      const form = formsById.get(build.getFormId());
+      if (!form) {
+        throw TeamAnalysisError.formNotFound(build.getFormId());
+      }
+      return {
+        combatant: new Combatant(form, build, level),
+        moves: build
+          .getMoves()
+          .map((name) => movesByKey.get(toLookupKey(name)))
+          .filter((move): move is Move => move !== undefined),
+      };
+    });
+  }
+}
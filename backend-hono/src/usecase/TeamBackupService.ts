@@ -0,0 +1,72 @@
+import { v4 as uuidv4 } from 'uuid';
+import { Team } from '../domain/entity/Team';
+import { ActivityEvent, importFinished } from '../domain/event/ActivityEvent';
+import { EventBus } from '../domain/event/EventBus';
+import { teamCreated, teamUpdated } from '../domain/event/TeamEvent';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+import { TeamBackupDto } from './dto';
+
+// Teams are read a page at a time so an export never holds every team in memory
+const EXPORT_PAGE_SIZE = 100;
+
+export interface TeamRestoreResult {
+  created: number;
+  updated: number;
+}
+
+export class TeamBackupService {
+  constructor(
+    private readonly teamRepository: TeamRepository,
+    private readonly activityEvents: EventBus<ActivityEvent> = new EventBus()
+  ) {}
+
+  async *exportTeams(userId: string): AsyncGenerator<Team> {
+    let after: string | undefined;
+    for (;;) {
+      const page = await this.teamRepository.findPageByOwner(userId, EXPORT_PAGE_SIZE, after);
+      yield* page;
+      if (page.length < EXPORT_PAGE_SIZE) {
+        return;
+      }
+      after = page[page.length - 1].getTeamId();
+    }
+  }
+
+  // Restoring is idempotent: a team the user already owns is overwritten in place, whatever
+  // version it is at. An ID that belongs to someone else (an archive shared between accounts) is
+  // restored as a new team.
+  async restore(userId: string, archive: TeamBackupDto): Promise<TeamRestoreResult> {
+    const result: TeamRestoreResult = { created: 0, updated: 0 };
+
+    for (const entry of archive.teams) {
+      const existing = await this.teamRepository.findById(entry.team_id);
+      const owned = existing !== null && existing.getOwnerId() === userId;
+      const teamId = existing === null || owned ? entry.team_id : uuidv4();
+
+      const team = Team.fromRepository(
+        teamId,
+        userId,
+        entry.team_name,
+        entry.pokemon.map((member) =>
+          member ? { formId: member.form_id, terastalType: member.terastal_type } : null
+        ),
+        null,
+        owned ? existing.getVersion() : 0
+      );
+      team.setTags(entry.tags ?? []);
+      team.moveToFolder(entry.folder ?? null);
+      team.setNotes(entry.notes ?? null);
+      entry.pokemon.forEach((member, slot) => {
+        if (member?.notes) {
+          team.setMemberNotes(slot, member.notes);
+        }
+      });
+      const event = owned ? teamUpdated(uuidv4(), team) : teamCreated(uuidv4(), team);
+      await this.teamRepository.save(team, [event]);
+      result[owned ? 'updated' : 'created']++;
+    }
+
+    await this.activityEvents.publish(importFinished(userId, result.created, result.updated));
+    return result;
+  }
+}
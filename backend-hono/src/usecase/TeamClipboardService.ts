@@ -0,0 +1,78 @@
+import { v4 as uuidv4 } from 'uuid';
+import { Team } from '../domain/entity/Team';
+import { teamCreated, teamUpdated } from '../domain/event/TeamEvent';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+
+export class TeamClipboardError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TeamClipboardError';
+  }
+
+  // Also used for other users' teams and teams in the trash
+  static teamNotFound(teamId: string): TeamClipboardError {
+    return new TeamClipboardError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+
+  static slotEmpty(teamId: string, slot: number): TeamClipboardError {
+    return new TeamClipboardError(`Slot ${slot} of team ${teamId} is empty`, 'SLOT_EMPTY');
+  }
+
+  static teamFull(teamId: string): TeamClipboardError {
+    return new TeamClipboardError(`Team ${teamId} has no empty slot`, 'TEAM_FULL');
+  }
+}
+
+// Copies members between a user's teams. A member is everything the team stores for its slot:
+// the form, its Tera Type and its notes.
+export class TeamClipboardService {
+  constructor(private readonly teamRepository: TeamRepository) {}
+
+  // Overwrites `targetSlot` when given, otherwise fills the target team's first empty slot.
+  // Copying within one team is allowed.
+  async copyMember(
+    userId: string,
+    sourceTeamId: string,
+    sourceSlot: number,
+    targetTeamId: string,
+    targetSlot?: number
+  ): Promise<Team> {
+    const source = await this.findOwnedTeam(userId, sourceTeamId);
+    const member = source.getPokemon()[sourceSlot];
+    if (!member) {
+      throw TeamClipboardError.slotEmpty(sourceTeamId, sourceSlot);
+    }
+
+    const target =
+      targetTeamId === sourceTeamId ? source : await this.findOwnedTeam(userId, targetTeamId);
+    if (targetSlot !== undefined) {
+      target.setPokemon(targetSlot, { ...member });
+    } else if (target.isFull()) {
+      throw TeamClipboardError.teamFull(targetTeamId);
+    } else {
+      target.addPokemon({ ...member });
+    }
+
+    await this.teamRepository.save(target, [teamUpdated(uuidv4(), target)]);
+    return target;
+  }
+
+  async cloneTeam(userId: string, teamId: string): Promise<Team> {
+    const team = await this.findOwnedTeam(userId, teamId);
+    const clone = team.duplicate(uuidv4());
+
+    await this.teamRepository.save(clone, [teamCreated(uuidv4(), clone)]);
+    return clone;
+  }
+
+  private async findOwnedTeam(userId: string, teamId: string): Promise<Team> {
+    const team = await this.teamRepository.findById(teamId);
+    if (!team || team.getOwnerId() !== userId || team.isDeleted()) {
+      throw TeamClipboardError.teamNotFound(teamId);
+    }
+    return team;
+  }
+}
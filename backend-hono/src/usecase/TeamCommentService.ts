@@ -0,0 +1,160 @@
+import { v4 as uuidv4 } from 'uuid';
+import { Team } from '../domain/entity/Team';
+import { TeamComment } from '../domain/entity/TeamComment';
+import { ActivityEvent, commentPosted } from '../domain/event/ActivityEvent';
+import { EventBus } from '../domain/event/EventBus';
+import {
+  AuthoredComment,
+  TeamCommentRepository,
+} from '../repository/interface/TeamCommentRepository';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+import { ContentModerationService, PublishedContent } from './ContentModerationService';
+
+export class TeamCommentError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TeamCommentError';
+  }
+
+  // Also used for teams that are not shared or are in the trash
+  static teamNotFound(teamId: string): TeamCommentError {
+    return new TeamCommentError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+
+  // Also used for comments on another team and deleted comments
+  static commentNotFound(commentId: string): TeamCommentError {
+    return new TeamCommentError(`Comment ${commentId} not found`, 'COMMENT_NOT_FOUND');
+  }
+
+  static notAuthor(): TeamCommentError {
+    return new TeamCommentError('You can only change your own comments', 'NOT_COMMENT_AUTHOR');
+  }
+}
+
+export interface CommentThread extends AuthoredComment {
+  replies: AuthoredComment[];
+}
+
+export interface CommentThreadPage {
+  threads: CommentThread[];
+  // Threads on the team, for paging
+  total: number;
+}
+
+// Comments on shared teams. Anyone signed in can comment; authors can edit and delete their
+// comments, and the team's owner can delete any comment on it. Pages are of threads, each with
+// all its replies.
+export class TeamCommentService {
+  constructor(
+    private readonly teamRepository: TeamRepository,
+    private readonly teamCommentRepository: TeamCommentRepository,
+    private readonly contentModerationService: ContentModerationService,
+    private readonly activityEvents: EventBus<ActivityEvent> = new EventBus()
+  ) {}
+
+  async listThreads(teamId: string, limit: number, offset: number): Promise<CommentThreadPage> {
+    await this.findSharedTeam(teamId);
+
+    const [threads, total] = await Promise.all([
+      this.teamCommentRepository.findThreadsByTeam(teamId, limit, offset),
+      this.teamCommentRepository.countThreadsByTeam(teamId),
+    ]);
+    const replies = await this.teamCommentRepository.findReplies(
+      threads.map(({ comment }) => comment.getCommentId())
+    );
+
+    return {
+      threads: threads.map((thread) => ({
+        ...thread,
+        replies: replies.filter(
+          ({ comment }) => comment.getParentId() === thread.comment.getCommentId()
+        ),
+      })),
+      total,
+    };
+  }
+
+  async create(
+    userId: string,
+    teamId: string,
+    body: string,
+    replyToId?: string
+  ): Promise<AuthoredComment> {
+    const team = await this.findSharedTeam(teamId);
+    const replyTo = replyToId ? await this.findComment(teamId, replyToId) : undefined;
+
+    const comment = TeamComment.create(uuidv4(), teamId, userId, body, replyTo?.comment);
+    const verdict = this.contentModerationService.screen('comment', comment.getBody());
+    await this.teamCommentRepository.save(comment);
+    await this.contentModerationService.hold(this.toContent(comment), verdict);
+    // Read back with the author's name; the user can only vanish in between if deleted
+    const saved = (await this.teamCommentRepository.findById(comment.getCommentId())) ?? {
+      comment,
+      authorName: '',
+    };
+
+    await this.activityEvents.publish(
+      commentPosted(team, comment, saved.authorName, replyTo?.comment.getAuthorId() ?? null)
+    );
+    return saved;
+  }
+
+  async edit(
+    userId: string,
+    teamId: string,
+    commentId: string,
+    body: string
+  ): Promise<AuthoredComment> {
+    await this.findSharedTeam(teamId);
+    const authored = await this.findComment(teamId, commentId);
+    if (authored.comment.getAuthorId() !== userId) {
+      throw TeamCommentError.notAuthor();
+    }
+
+    authored.comment.edit(body);
+    const verdict = this.contentModerationService.screen('comment', authored.comment.getBody());
+    await this.teamCommentRepository.save(authored.comment);
+    await this.contentModerationService.hold(this.toContent(authored.comment), verdict);
+    return authored;
+  }
+
+  async delete(userId: string, teamId: string, commentId: string): Promise<void> {
+    const team = await this.findSharedTeam(teamId);
+    const { comment } = await this.findComment(teamId, commentId);
+    if (comment.getAuthorId() !== userId && team.getOwnerId() !== userId) {
+      throw TeamCommentError.notAuthor();
+    }
+
+    comment.delete();
+    await this.teamCommentRepository.save(comment);
+    await this.contentModerationService.release('comment', commentId);
+  }
+
+  private toContent(comment: TeamComment): PublishedContent {
+    return {
+      kind: 'comment',
+      targetId: comment.getCommentId(),
+      authorId: comment.getAuthorId(),
+      text: comment.getBody(),
+    };
+  }
+
+  private async findSharedTeam(teamId: string): Promise<Team> {
+    const team = await this.teamRepository.findById(teamId);
+    if (!team || !team.isShared() || team.isDeleted()) {
+      throw TeamCommentError.teamNotFound(teamId);
+    }
+    return team;
+  }
+
+  private async findComment(teamId: string, commentId: string): Promise<AuthoredComment> {
+    const authored = await this.teamCommentRepository.findById(commentId);
+    if (!authored || authored.comment.getTeamId() !== teamId || authored.comment.isDeleted()) {
+      throw TeamCommentError.commentNotFound(commentId);
+    }
+    return authored;
+  }
+}
@@ -0,0 +1,68 @@
+import { EventBus } from '../domain/event/EventBus';
+import { TeamEvent } from '../domain/event/TeamEvent';
+import { TeamEventOutboxRepository } from '../repository/interface/TeamEventOutboxRepository';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+
+const RELAY_BATCH_SIZE = 50;
+// Long enough for the bus to take a batch; subscribers that take longer run in the background
+const CLAIM_LEASE_MS = 60 * 1000;
+// Receivers deduplicate on event IDs, so published entries are kept a while for reference
+const PUBLISHED_RETENTION_MS = 7 * 24 * 60 * 60 * 1000;
+
+// Moves team events from the outbox to the event bus. Delivery is at least once: an entry is
+// marked published only after the bus took it, so a crash in between publishes it again (with
+// the same event ID) once its claim runs out.
+export class TeamEventRelay {
+  constructor(
+    private readonly outboxRepository: TeamEventOutboxRepository,
+    private readonly teamRepository: TeamRepository,
+    private readonly teamEvents: EventBus<TeamEvent>
+  ) {}
+
+  // Publishes one batch, oldest first, and returns how many entries it took
+  async relay(): Promise<number> {
+    const entries = await this.outboxRepository.claim(RELAY_BATCH_SIZE, CLAIM_LEASE_MS);
+    if (entries.length === 0) {
+      return 0;
+    }
+
+    const teams = await this.teamRepository.findByIds([
+      ...new Set(entries.map((entry) => entry.teamId)),
+    ]);
+    const teamsById = new Map(teams.map((team) => [team.getTeamId(), team]));
+
+    const published: string[] = [];
+    try {
+      for (const { eventId, type, teamId, occurredAt } of entries) {
+        const team = teamsById.get(teamId);
+        // A team purged since has nothing left to announce
+        if (team) {
+          await this.teamEvents.publish({ eventId, type, team, occurredAt });
+        }
+        published.push(eventId);
+      }
+    } finally {
+      // The rest are handed out again when the claim runs out
+      await this.outboxRepository.markPublished(published);
+    }
+    return entries.length;
+  }
+
+  // Until the outbox is empty; for the scheduled sweep, which picks up what requests left behind
+  async relayAll(): Promise<number> {
+    let total = 0;
+    for (;;) {
+      const relayed = await this.relay();
+      total += relayed;
+      if (relayed < RELAY_BATCH_SIZE) {
+        return total;
+      }
+    }
+  }
+
+  async purgePublished(): Promise<number> {
+    return this.outboxRepository.deletePublishedBefore(
+      new Date(Date.now() - PUBLISHED_RETENTION_MS)
+    );
+  }
+}
@@ -0,0 +1,212 @@
+import { z } from 'zod';
+import { Team } from '../domain/entity/Team';
+import { TEAM_FLAG_HIDE_THRESHOLD, TeamFlag, TeamFlagReason } from '../domain/entity/TeamFlag';
+import { TeamLike } from '../domain/entity/TeamLike';
+import { ActivityEvent, teamLiked } from '../domain/event/ActivityEvent';
+import { EventBus } from '../domain/event/EventBus';
+import { FormatId } from '../domain/valueobject/Format';
+import { TeamFlagRepository, TeamFlagSummary } from '../repository/interface/TeamFlagRepository';
+import { TeamLikeRepository } from '../repository/interface/TeamLikeRepository';
+import { GalleryQuery, TeamRepository } from '../repository/interface/TeamRepository';
+import { ContentModerationService } from './ContentModerationService';
+import { PageCursorCodec } from './PageCursorCodec';
+
+const GalleryPositionSchema = z.object({
+  teamId: z.string(),
+  sharedAt: z.coerce.date(),
+  likes: z.number().int().min(0),
+});
+
+export class TeamGalleryError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TeamGalleryError';
+  }
+
+  // Also used for other users' teams, teams in the trash and, outside the owner's own
+  // requests, teams that are not shared
+  static teamNotFound(teamId: string): TeamGalleryError {
+    return new TeamGalleryError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+
+  static ownTeam(): TeamGalleryError {
+    return new TeamGalleryError('You cannot like your own team', 'OWN_TEAM');
+  }
+}
+
+export interface GalleryEntry {
+  team: Team;
+  likes: number;
+  // Whether the signed-in viewer likes the team; false for anonymous viewers
+  liked: boolean;
+}
+
+export interface GalleryListQuery extends Omit<GalleryQuery, 'hideAtFlags' | 'after'> {
+  // From the previous page's nextCursor, with the same sort
+  cursor?: string;
+}
+
+export interface GalleryPage {
+  entries: GalleryEntry[];
+  // Absent on the last page
+  nextCursor?: string;
+}
+
+export interface FlaggedTeam extends TeamFlagSummary {
+  // Null once the team has been deleted for good
+  team: Team | null;
+  hidden: boolean;
+}
+
+// Teams their owners have chosen to show to everyone. Only the name, members and format are
+// public; tags, folders and the owner stay private. Other users can like a shared team, and
+// flag it for moderators; enough flags take it out of the gallery until a moderator decides.
+export class TeamGalleryService {
+  constructor(
+    private readonly teamRepository: TeamRepository,
+    private readonly teamLikeRepository: TeamLikeRepository,
+    private readonly teamFlagRepository: TeamFlagRepository,
+    private readonly contentModerationService: ContentModerationService,
+    private readonly pageCursorCodec: PageCursorCodec,
+    private readonly activityEvents: EventBus<ActivityEvent> = new EventBus()
+  ) {}
+
+  async share(userId: string, teamId: string, format: FormatId | null = null): Promise<Team> {
+    const team = await this.findOwnedTeam(userId, teamId);
+    if (!team.isShared() || team.getSharedFormat() !== format) {
+      // The name is only checked once it goes public; private names are the owner's business
+      const verdict = this.contentModerationService.screen('team_name', team.getTeamName());
+      team.share(format);
+      await this.teamRepository.save(team);
+      await this.contentModerationService.hold(
+        { kind: 'team_name', targetId: teamId, authorId: userId, text: team.getTeamName() },
+        verdict
+      );
+    }
+    return team;
+  }
+
+  async unshare(userId: string, teamId: string): Promise<void> {
+    const team = await this.findOwnedTeam(userId, teamId);
+    if (team.isShared()) {
+      team.unshare();
+      await this.teamRepository.save(team);
+      await this.contentModerationService.release('team_name', teamId);
+    }
+  }
+
+  async listShared(query: GalleryListQuery, viewerId?: string): Promise<GalleryPage> {
+    const { cursor, ...filter } = query;
+    // A cursor only makes sense in the order it was issued for
+    const listing = `gallery.${query.sort}`;
+    const after = cursor
+      ? this.pageCursorCodec.decode(listing, cursor, GalleryPositionSchema)
+      : undefined;
+    const page = await this.teamRepository.findGalleryPage({
+      ...filter,
+      hideAtFlags: TEAM_FLAG_HIDE_THRESHOLD,
+      after,
+    });
+
+    const liked = new Set(
+      viewerId
+        ? await this.teamLikeRepository.findLikedTeamIds(
+            viewerId,
+            page.map(({ team }) => team.getTeamId())
+          )
+        : []
+    );
+    const entries = page.map(({ team, likes }) => ({
+      team,
+      likes,
+      liked: liked.has(team.getTeamId()),
+    }));
+
+    const last = page[page.length - 1];
+    const sharedAt = last?.team.getSharedAt();
+    if (page.length < query.limit || !sharedAt) {
+      return { entries };
+    }
+    const position = { teamId: last.team.getTeamId(), sharedAt, likes: last.likes };
+    return { entries, nextCursor: this.pageCursorCodec.encode(listing, position) };
+  }
+
+  // Liking twice is not an error; returns the team's like count
+  async like(userId: string, teamId: string): Promise<number> {
+    const team = await this.findSharedTeam(teamId);
+    if (team.getOwnerId() === userId) {
+      throw TeamGalleryError.ownTeam();
+    }
+
+    const added = await this.teamLikeRepository.save(TeamLike.create(teamId, userId));
+    const likes = await this.teamLikeRepository.countByTeam(teamId);
+    if (added) {
+      await this.activityEvents.publish(teamLiked(team, likes));
+    }
+    return likes;
+  }
+
+  async unlike(userId: string, teamId: string): Promise<number> {
+    await this.findSharedTeam(teamId);
+    await this.teamLikeRepository.delete(teamId, userId);
+    return this.teamLikeRepository.countByTeam(teamId);
+  }
+
+  async flag(userId: string, teamId: string, reason: TeamFlagReason): Promise<void> {
+    await this.findSharedTeam(teamId);
+    await this.teamFlagRepository.save(TeamFlag.create(teamId, userId, reason));
+  }
+
+  // For moderators, most flagged first
+  async listFlagged(limit: number, offset: number): Promise<FlaggedTeam[]> {
+    const summaries = await this.teamFlagRepository.findSummaries(limit, offset);
+    const found = await this.teamRepository.findByIds(summaries.map((summary) => summary.teamId));
+    const teams = new Map(found.map((team) => [team.getTeamId(), team]));
+
+    return summaries.map((summary) => ({
+      ...summary,
+      team: teams.get(summary.teamId) ?? null,
+      hidden: summary.flags >= TEAM_FLAG_HIDE_THRESHOLD,
+    }));
+  }
+
+  // The moderator agrees with the flags: the team leaves the gallery. The owner can share it
+  // again, which starts a new review if users flag it again.
+  async takeDown(teamId: string): Promise<void> {
+    const team = await this.teamRepository.findById(teamId);
+    if (!team) {
+      throw TeamGalleryError.teamNotFound(teamId);
+    }
+    if (team.isShared()) {
+      team.unshare();
+      await this.teamRepository.save(team);
+    }
+    await this.teamFlagRepository.deleteByTeam(teamId);
+  }
+
+  // The moderator disagrees with the flags: the team is back in the gallery
+  async dismissFlags(teamId: string): Promise<void> {
+    if ((await this.teamFlagRepository.deleteByTeam(teamId)) === 0) {
+      throw TeamGalleryError.teamNotFound(teamId);
+    }
+  }
+
+  private async findOwnedTeam(userId: string, teamId: string): Promise<Team> {
+    const team = await this.teamRepository.findById(teamId);
+    if (!team || team.getOwnerId() !== userId || team.isDeleted()) {
+      throw TeamGalleryError.teamNotFound(teamId);
+    }
+    return team;
+  }
+
+  private async findSharedTeam(teamId: string): Promise<Team> {
+    const team = await this.teamRepository.findById(teamId);
+    if (!team || !team.isShared() || team.isDeleted()) {
+      throw TeamGalleryError.teamNotFound(teamId);
+    }
+    return team;
+  }
+}
@@ -0,0 +1,108 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { Format, FormatId, FormatMember, getFormat } from '../domain/valueobject/Format';
+import { GameVersion } from '../domain/valueobject/GameVersion';
+import { LegalityViolation } from '../domain/valueobject/LegalityViolation';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { PokemonSpeciesRepository } from '../repository/interface/PokemonSpeciesRepository';
+
+export interface TeamLegalityResponse {
+  legal: boolean;
+  violations: LegalityViolation[];
+}
+
+export interface FormatLegalityResponse extends TeamLegalityResponse {
+  format: Format;
+}
+
+export class TeamLegalityService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly speciesRepository: PokemonSpeciesRepository
+  ) {}
+
+  async validateForGame(formIds: FormId[], version: GameVersion): Promise<TeamLegalityResponse> {
+    const formsById = await this.loadForms(formIds);
+
+    const violations: LegalityViolation[] = [];
+    formIds.forEach((formId, slot) => {
+      violations.push(...this.checkAvailability(slot, formId, formsById.get(formId), version));
+    });
+
+    return { legal: violations.length === 0, violations };
+  }
+
+  async validateForFormat(
+    builds: PokemonBuild[],
+    formatId: FormatId
+  ): Promise<FormatLegalityResponse> {
+    const format = getFormat(formatId);
+    const formsById = await this.loadForms(builds.map((build) => build.getFormId()));
+    const species = await this.speciesRepository.findByIds(
+      [...formsById.values()].map((form) => form.getSpeciesId())
+    );
+    const speciesById = new Map(species.map((s) => [s.getSpeciesId(), s]));
+
+    const violations: LegalityViolation[] = [];
+    const members: FormatMember[] = [];
+    builds.forEach((build, slot) => {
+      const form = formsById.get(build.getFormId());
+      violations.push(
+        ...this.checkAvailability(slot, build.getFormId(), form, format.gameVersion)
+      );
+
+      const memberSpecies = form && speciesById.get(form.getSpeciesId());
+      if (form && memberSpecies) {
+        members.push({ slot, build, form, species: memberSpecies });
+      } else if (form) {
+        violations.push({
+          code: 'FORM_NOT_FOUND',
+          message: `Species data for ${form.getName()} is missing`,
+          slot,
+          formId: form.getFormId(),
+        });
+      }
+    });
+
+    violations.push(...format.validate(members));
+
+    return { format, legal: violations.length === 0, violations };
+  }
+
+  private async loadForms(formIds: FormId[]): Promise<Map<FormId, PokemonMaster>> {
+    const forms = await this.formRepository.findByIds([...new Set(formIds)]);
+    return new Map(forms.map((form) => [form.getFormId(), form]));
+  }
+
+  private checkAvailability(
+    slot: number,
+    formId: FormId,
+    form: PokemonMaster | undefined,
+    version: GameVersion
+  ): LegalityViolation[] {
+    if (!form) {
+      return [
+        {
+          code: 'FORM_NOT_FOUND',
+          message: `Form ${formId} does not exist`,
+          slot,
+          formId,
+        },
+      ];
+    }
+
+    if (!form.isObtainableIn(version)) {
+      return [
+        {
+          code: 'NOT_OBTAINABLE',
+          message: `${form.getName()} is not obtainable in ${version}`,
+          slot,
+          formId,
+        },
+      ];
+    }
+
+    return [];
+  }
+}
@@ -0,0 +1,59 @@
+import { v4 as uuidv4 } from 'uuid';
+import { Team } from '../domain/entity/Team';
+import { teamUpdated } from '../domain/event/TeamEvent';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+
+export class TeamNotesError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TeamNotesError';
+  }
+
+  // Also used for other users' teams and teams in the trash
+  static teamNotFound(teamId: string): TeamNotesError {
+    return new TeamNotesError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+
+  static slotEmpty(teamId: string, slot: number): TeamNotesError {
+    return new TeamNotesError(`Slot ${slot} of team ${teamId} is empty`, 'SLOT_EMPTY');
+  }
+}
+
+// Free-text notes on a team and on each member, e.g. why a set exists. They are saved with the
+// team, so they bump its version, and go wherever the team goes: exports and the gallery.
+export class TeamNotesService {
+  constructor(private readonly teamRepository: TeamRepository) {}
+
+  async setTeamNotes(userId: string, teamId: string, notes: string | null): Promise<Team> {
+    const team = await this.findOwnedTeam(userId, teamId);
+    team.setNotes(notes);
+    await this.teamRepository.save(team, [teamUpdated(uuidv4(), team)]);
+    return team;
+  }
+
+  async setMemberNotes(
+    userId: string,
+    teamId: string,
+    slot: number,
+    notes: string | null
+  ): Promise<Team> {
+    const team = await this.findOwnedTeam(userId, teamId);
+    if (!team.getPokemon()[slot]) {
+      throw TeamNotesError.slotEmpty(teamId, slot);
+    }
+    team.setMemberNotes(slot, notes);
+    await this.teamRepository.save(team, [teamUpdated(uuidv4(), team)]);
+    return team;
+  }
+
+  private async findOwnedTeam(userId: string, teamId: string): Promise<Team> {
+    const team = await this.teamRepository.findById(teamId);
+    if (!team || team.getOwnerId() !== userId || team.isDeleted()) {
+      throw TeamNotesError.teamNotFound(teamId);
+    }
+    return team;
+  }
+}
@@ -0,0 +1,120 @@
+import { v4 as uuidv4 } from 'uuid';
+import { Team } from '../domain/entity/Team';
+import { teamUpdated } from '../domain/event/TeamEvent';
+import { TeamTag } from '../domain/valueobject/TeamTag';
+import {
+  TeamFilter,
+  TeamFolderCount,
+  TeamRepository,
+  TeamTagCount,
+} from '../repository/interface/TeamRepository';
+
+export class TeamOrganizerError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TeamOrganizerError';
+  }
+
+  // Also used for other users' teams and teams in the trash
+  static teamNotFound(teamId: string): TeamOrganizerError {
+    return new TeamOrganizerError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+}
+
+export interface TeamPage {
+  teams: Team[];
+  // Pass as `after` for the next page; null on the last page
+  nextAfter: string | null;
+}
+
+export interface TeamOrganization {
+  addTags?: string[];
+  removeTags?: string[];
+  // null takes the teams out of their folder; left out, they stay where they are
+  folder?: string | null;
+}
+
+// Tags and folders a user sorts their teams with. Neither changes what a team is, but both are
+// saved with it, so changes bump the version and are announced like any other team update.
+export class TeamOrganizerService {
+  constructor(private readonly teamRepository: TeamRepository) {}
+
+  async listTeams(
+    userId: string,
+    filter: TeamFilter,
+    limit: number,
+    after?: string
+  ): Promise<TeamPage> {
+    const teams = await this.teamRepository.findPageByOwner(userId, limit, after, {
+      tag: filter.tag === undefined ? undefined : TeamTag.create(filter.tag).getValue(),
+      folder: filter.folder,
+      speciesId: filter.speciesId,
+    });
+
+    return {
+      teams,
+      nextAfter: teams.length === limit ? teams[teams.length - 1].getTeamId() : null,
+    };
+  }
+
+  async listTags(userId: string): Promise<TeamTagCount[]> {
+    return this.teamRepository.countTagsByOwner(userId);
+  }
+
+  async listFolders(userId: string): Promise<TeamFolderCount[]> {
+    return this.teamRepository.countFoldersByOwner(userId);
+  }
+
+  async setTags(userId: string, teamId: string, tags: string[]): Promise<Team> {
+    const [team] = await this.findOwnedTeams(userId, [teamId]);
+    team.setTags(tags);
+    await this.save([team]);
+    return team;
+  }
+
+  async moveToFolder(userId: string, teamId: string, folder: string | null): Promise<Team> {
+    const [team] = await this.findOwnedTeams(userId, [teamId]);
+    team.moveToFolder(folder);
+    await this.save([team]);
+    return team;
+  }
+
+  // Applies one change to many teams at once, all or nothing. Tags are removed before others are
+  // added, so a tag can be renamed across teams in one request.
+  async organize(userId: string, teamIds: string[], change: TeamOrganization): Promise<Team[]> {
+    const teams = await this.findOwnedTeams(userId, teamIds);
+    for (const team of teams) {
+      team.removeTags(change.removeTags ?? []);
+      team.addTags(change.addTags ?? []);
+      if (change.folder !== undefined) {
+        team.moveToFolder(change.folder);
+      }
+    }
+
+    await this.save(teams);
+    return teams;
+  }
+
+  private async save(teams: Team[]): Promise<void> {
+    const events = teams.map((team) => teamUpdated(uuidv4(), team));
+    await this.teamRepository.saveOrganization(teams, events);
+  }
+
+  private async findOwnedTeams(userId: string, teamIds: string[]): Promise<Team[]> {
+    const unique = [...new Set(teamIds)];
+    const found = new Map(
+      (await this.teamRepository.findByIds(unique)).map((team) => [team.getTeamId(), team])
+    );
+
+    return unique.map((teamId) => {
+      const team = found.get(teamId);
+      if (!team || team.getOwnerId() !== userId || team.isDeleted()) {
+        throw TeamOrganizerError.teamNotFound(teamId);
+      }
+      return team;
+    });
+  }
+}
@@ -0,0 +1,72 @@
+import { FormatId, getFormat } from '../domain/valueobject/Format';
+import { Level } from '../domain/valueobject/Level';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import {
+  TeamAnalysisOptions,
+  TeamAnalysisResponse,
+  TeamAnalysisService,
+} from './TeamAnalysisService';
+import { Threat, ThreatAnalysisError, ThreatAnalysisService } from './ThreatAnalysisService';
+import { renderHtmlReport } from './report/htmlReport';
+import { renderMarkdownReport } from './report/markdownReport';
+
+export type ReportOutput = 'markdown' | 'html';
+
+export interface TeamReport {
+  formatName: string;
+  level: Level;
+  // Usage period the threat list is based on; absent when no usage data was imported
+  period?: string;
+  analysis: TeamAnalysisResponse;
+  threats: Threat[];
+}
+
+export interface RenderedReport {
+  contentType: string;
+  fileExtension: string;
+  body: string;
+}
+
+export class TeamReportService {
+  constructor(
+    private readonly teamAnalysisService: TeamAnalysisService,
+    private readonly threatAnalysisService: ThreatAnalysisService
+  ) {}
+
+  async generate(
+    builds: PokemonBuild[],
+    formatId: FormatId,
+    limit: number,
+    options: TeamAnalysisOptions = {}
+  ): Promise<TeamReport> {
+    const format = getFormat(formatId);
+    const analysis = await this.teamAnalysisService.analyze(builds, format.rules.level, options);
+
+    try {
+      const threats = await this.threatAnalysisService.analyze(builds, formatId, limit);
+      return {
+        formatName: format.name,
+        level: format.rules.level,
+        period: threats.period,
+        analysis,
+        threats: threats.threats,
+      };
+    } catch (error) {
+      if (error instanceof ThreatAnalysisError && error.code === 'USAGE_NOT_FOUND') {
+        return { formatName: format.name, level: format.rules.level, analysis, threats: [] };
+      }
+      throw error;
+    }
+  }
+
+  render(report: TeamReport, output: ReportOutput): RenderedReport {
+    if (output === 'html') {
+      return { contentType: 'text/html', fileExtension: 'html', body: renderHtmlReport(report) };
+    }
+    return {
+      contentType: 'text/markdown',
+      fileExtension: 'md',
+      body: renderMarkdownReport(report),
+    };
+  }
+}
@@ -0,0 +1,118 @@
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { EVStats } from '../domain/valueobject/Stats';
+import {
+  decodeTeam,
+  encodeTeam,
+  fromBase32,
+  fromQrPayload,
+  TeamCodeError,
+  TeamCodeMember,
+  toBase32,
+  toQrPayload,
+} from '../domain/valueobject/TeamCode';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+
+export class TeamShareError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TeamShareError';
+  }
+
+  static invalidCode(message: string): TeamShareError {
+    return new TeamShareError(message, 'INVALID_CODE');
+  }
+
+  static moveNotFound(moveName: string): TeamShareError {
+    return new TeamShareError(`Move ${moveName} not found`, 'MOVE_NOT_FOUND');
+  }
+}
+
+export interface TeamCodeResponse {
+  code: string;
+  qrPayload: string;
+}
+
+export class TeamShareService {
+  constructor(private readonly moveRepository: MoveRepository) {}
+
+  async encode(builds: PokemonBuild[]): Promise<TeamCodeResponse> {
+    const moves = await this.moveRepository.findByNames(
+      builds.flatMap((build) => build.getMoves())
+    );
+    const movesByKey = new Map(moves.map((move) => [toLookupKey(move.getName()), move]));
+
+    const members: TeamCodeMember[] = builds.map((build) => {
+      const ev = build.getEV();
+      return {
+        formId: build.getFormId(),
+        nature: build.getNature(),
+        teraType: build.getTeraType(),
+        moveIds: build.getMoves().map((name) => {
+          const move = movesByKey.get(toLookupKey(name));
+          if (!move) {
+            throw TeamShareError.moveNotFound(name);
+          }
+          return move.getId();
+        }),
+        evs: {
+          hp: ev.hp,
+          attack: ev.attack,
+          defense: ev.defense,
+          specialAttack: ev.specialAttack,
+          specialDefense: ev.specialDefense,
+          speed: ev.speed,
+        },
+        ivs: build.getIV(),
+      };
+    });
+
+    const code = toBase32(this.wrap(() => encodeTeam(members)));
+    return { code, qrPayload: toQrPayload(code) };
+  }
+
+  // Accepts a short code or a scanned QR payload
+  async decode(codeOrPayload: string): Promise<PokemonBuild[]> {
+    const members = this.wrap(() => decodeTeam(fromBase32(fromQrPayload(codeOrPayload))));
+    const moves = await this.moveRepository.findByIds(members.flatMap((member) => member.moveIds));
+    const movesById = new Map(moves.map((move) => [move.getId(), move]));
+
+    return members.map((member) =>
+      PokemonBuild.create({
+        formId: member.formId,
+        nature: member.nature,
+        teraType: member.teraType,
+        moves: member.moveIds.map((moveId) => {
+          const move = movesById.get(moveId);
+          if (!move) {
+            throw TeamShareError.moveNotFound(`#${moveId}`);
+          }
+          return move.getName();
+        }),
+        ev: new EVStats(
+          member.evs.hp,
+          member.evs.attack,
+          member.evs.defense,
+          member.evs.specialAttack,
+          member.evs.specialDefense,
+          member.evs.speed
+        ),
+        iv: member.ivs,
+      })
+    );
+  }
+
+  private wrap<T>(operation: () => T): T {
+    try {
+      return operation();
+    } catch (error) {
+      if (error instanceof TeamCodeError) {
+        throw TeamShareError.invalidCode(error.message);
+      }
+      throw error;
+    }
+  }
+}
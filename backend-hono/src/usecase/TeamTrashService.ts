@@ -0,0 +1,76 @@
+import { getRetentionCutoff, Team, TEAM_RETENTION_DAYS } from '../domain/entity/Team';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+
+export class TeamTrashError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TeamTrashError';
+  }
+
+  // Also used for other users' teams, so a team ID never reveals that it exists
+  static teamNotFound(teamId: string): TeamTrashError {
+    return new TeamTrashError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+
+  static notDeleted(teamId: string): TeamTrashError {
+    return new TeamTrashError(`Team ${teamId} is not in the trash`, 'TEAM_NOT_DELETED');
+  }
+
+  static retentionExpired(teamId: string): TeamTrashError {
+    return new TeamTrashError(
+      `Team ${teamId} was deleted more than ${TEAM_RETENTION_DAYS} days ago`,
+      'RETENTION_EXPIRED'
+    );
+  }
+}
+
+export class TeamTrashService {
+  constructor(private readonly teamRepository: TeamRepository) {}
+
+  async deleteTeam(userId: string, teamId: string): Promise<Team> {
+    const team = await this.findOwnedTeam(userId, teamId);
+    if (team.isDeleted()) {
+      throw TeamTrashError.teamNotFound(teamId);
+    }
+
+    team.delete();
+    await this.teamRepository.save(team);
+    return team;
+  }
+
+  // Teams past the retention window are left out even if the purge job has not removed them yet
+  async listDeleted(userId: string): Promise<Team[]> {
+    const teams = await this.teamRepository.findDeletedByOwner(userId);
+    return teams.filter((team) => team.isRestorable());
+  }
+
+  async restoreTeam(userId: string, teamId: string): Promise<Team> {
+    const team = await this.findOwnedTeam(userId, teamId);
+    if (!team.isDeleted()) {
+      throw TeamTrashError.notDeleted(teamId);
+    }
+    if (!team.isRestorable()) {
+      throw TeamTrashError.retentionExpired(teamId);
+    }
+
+    team.restore();
+    await this.teamRepository.save(team);
+    return team;
+  }
+
+  // Run by the scheduled job; returns the number of teams removed for good
+  async purgeExpired(): Promise<number> {
+    return this.teamRepository.purgeDeletedBefore(getRetentionCutoff());
+  }
+
+  private async findOwnedTeam(userId: string, teamId: string): Promise<Team> {
+    const team = await this.teamRepository.findById(teamId);
+    if (!team || team.getOwnerId() !== userId) {
+      throw TeamTrashError.teamNotFound(teamId);
+    }
+    return team;
+  }
+}
@@ -0,0 +1,363 @@
+import { Move } from '../domain/entity/Move';
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import { CommonSet, PokemonUsage } from '../domain/entity/UsageSnapshot';
+import { calculateDamage, Combatant } from '../domain/service/DamageCalculator';
+import { resolveMoveType } from '../domain/service/MoveTypeResolver';
+import { BattleStyle, Format, FormatId, getFormat } from '../domain/valueobject/Format';
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { Hazard } from '../domain/valueobject/MoveEffects';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { PokemonType, PokemonTypeSchema } from '../domain/valueobject/PokemonType';
+import { EVStats } from '../domain/valueobject/Stats';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { UsageStatsRepository } from '../repository/interface/UsageStatsRepository';
+
+export class ThreatAnalysisError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'ThreatAnalysisError';
+  }
+
+  static formNotFound(formId: number): ThreatAnalysisError {
+    return new ThreatAnalysisError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static noUsageData(formatId: FormatId): ThreatAnalysisError {
+    return new ThreatAnalysisError(`No usage data for ${formatId}`, 'USAGE_NOT_FOUND');
+  }
+}
+
+export type ThreatReasonCode = 'UNRESISTED_TYPES' | 'OUTSPEEDS_TEAM' | 'OHKO' | 'SETS_HAZARDS';
+
+export interface ThreatReason {
+  code: ThreatReasonCode;
+  message: string;
+  types?: PokemonType[];
+  hazards?: Hazard[];
+  // Team slots knocked out in one hit; guaranteed means every damage roll KOs
+  knockouts?: { slot: number; move: string; guaranteed: boolean }[];
+}
+
+export interface Threat {
+  formId: number;
+  name: string;
+  nameJp: string;
+  usage: number;
+  score: number;
+  set: CommonSet;
+  reasons: ThreatReason[];
+}
+
+// A pair of team members to open with in doubles
+export interface LeadRecommendation {
+  slots: [number, number];
+  score: number;
+  // Usage-weighted share (0-100) of threats the pair can knock out in one hit
+  offense: number;
+  // Usage-weighted share (0-100) of threats that can knock out either lead in one hit
+  exposure: number;
+  reasons: string[];
+}
+
+export interface ThreatAnalysisResponse {
+  format: Format;
+  battleStyle: BattleStyle;
+  period: string;
+  threats: Threat[];
+  // Only populated for doubles
+  leads: LeadRecommendation[];
+}
+
+interface Attacker {
+  combatant: Combatant;
+  moves: Move[];
+}
+
+const MAX_LEAD_RECOMMENDATIONS = 3;
+
+// Turn-one speed control is worth a flat bonus on top of the damage-based score
+const SPEED_CONTROL_MOVES = ['tailwind', 'trick-room', 'icy-wind', 'electroweb'];
+const SPEED_CONTROL_BONUS = 10;
+
+export class ThreatAnalysisService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly moveRepository: MoveRepository,
+    private readonly usageRepository: UsageStatsRepository
+  ) {}
+
+  // battleStyle overrides the format's own style, e.g. to analyse a VGC team as singles
+  async analyze(
+    builds: PokemonBuild[],
+    formatId: FormatId,
+    limit: number,
+    battleStyle?: BattleStyle
+  ): Promise<ThreatAnalysisResponse> {
+    const format = getFormat(formatId);
+    const style = battleStyle ?? format.battleStyle;
+    const period = await this.usageRepository.findLatestPeriod(formatId);
+    if (!period) {
+      throw ThreatAnalysisError.noUsageData(formatId);
+    }
+
+    const usages = await this.usageRepository.findTop(formatId, period, limit);
+    const formsById = await this.loadForms([
+      ...builds.map((build) => build.getFormId()),
+      ...usages.map((usage) => usage.getFormId()),
+    ]);
+    const movesByKey = await this.loadMoves([
+      ...builds.flatMap((build) => build.getMoves()),
+      ...usages.flatMap((usage) => usage.getCommonSet().moves),
+    ]);
+
+    const team = builds.map((build) => {
+      const form = formsById.get(build.getFormId());
+      if (!form) {
+        throw ThreatAnalysisError.formNotFound(build.getFormId());
+      }
+      const combatant = new Combatant(form, build, format.rules.level);
+      return { combatant, moves: this.damagingMoves(combatant, movesByKey) };
+    });
+
+    const threats: Threat[] = [];
+    const opponents: { usage: PokemonUsage; attacker: Attacker }[] = [];
+    for (const usage of usages) {
+      const form = formsById.get(usage.getFormId());
+      if (!form) {
+        continue;
+      }
+
+      const set = usage.getCommonSet();
+      const threat = new Combatant(form, this.toBuild(usage, set, movesByKey), format.rules.level);
+      const moves = this.damagingMoves(threat, movesByKey);
+      opponents.push({ usage, attacker: { combatant: threat, moves } });
+
+      const reasons = this.findReasons(
+        threat,
+        moves,
+        this.hazardsSetBy(threat, movesByKey),
+        team.map((member) => member.combatant),
+        style === 'doubles'
+      );
+      if (reasons.length === 0) {
+        continue;
+      }
+
+      threats.push({
+        formId: form.getFormId(),
+        name: form.getName(),
+        nameJp: form.getNameJp(),
+        usage: usage.getUsage(),
+        // More reasons make a common threat more pressing
+        score: usage.getUsage() * reasons.length,
+        set,
+        reasons,
+      });
+    }
+
+    threats.sort((a, b) => b.score - a.score);
+    const leads = style === 'doubles' ? this.recommendLeads(team, opponents) : [];
+    return { format, battleStyle: style, period, threats, leads };
+  }
+
+  // Scores every pair of team members by how much of the metagame they can remove on turn one
+  // versus how much of it can remove one of them, with partner abilities taken into account
+  private recommendLeads(
+    team: Attacker[],
+    opponents: { usage: PokemonUsage; attacker: Attacker }[]
+  ): LeadRecommendation[] {
+    const totalUsage = opponents.reduce((sum, { usage }) => sum + usage.getUsage(), 0);
+    if (totalUsage === 0) {
+      return [];
+    }
+
+    const leads: LeadRecommendation[] = [];
+    for (let i = 0; i < team.length; i++) {
+      for (let j = i + 1; j < team.length; j++) {
+        const pair = [team[i], team[j]];
+        let offense = 0;
+        let exposure = 0;
+        for (const { usage, attacker: opponent } of opponents) {
+          const canKo = pair.some((lead, index) =>
+            this.canOneHitKo(lead, opponent.combatant, pair[1 - index].combatant, undefined)
+          );
+          const isKoed = pair.some((lead, index) =>
+            this.canOneHitKo(opponent, lead.combatant, undefined, pair[1 - index].combatant)
+          );
+          offense += canKo ? usage.getUsage() : 0;
+          exposure += isKoed ? usage.getUsage() : 0;
+        }
+
+        const offenseShare = (offense / totalUsage) * 100;
+        const exposureShare = (exposure / totalUsage) * 100;
+        const reasons = [
+          `Can knock out ${offenseShare.toFixed(1)}% of the top threats in one hit`,
+          `${exposureShare.toFixed(1)}% of the top threats can knock out one of them in one hit`,
+        ];
+        const speedControl = pair.some(({ combatant }) =>
+          SPEED_CONTROL_MOVES.some((move) => combatant.build.hasMove(move))
+        );
+        if (speedControl) {
+          reasons.push('Brings speed control');
+        }
+
+        leads.push({
+          slots: [i, j],
+          score: offenseShare - exposureShare / 2 + (speedControl ? SPEED_CONTROL_BONUS : 0),
+          offense: offenseShare,
+          exposure: exposureShare,
+          reasons,
+        });
+      }
+    }
+
+    return leads.sort((a, b) => b.score - a.score).slice(0, MAX_LEAD_RECOMMENDATIONS);
+  }
+
+  private canOneHitKo(
+    attacker: Attacker,
+    defender: Combatant,
+    attackerAlly: Combatant | undefined,
+    defenderAlly: Combatant | undefined
+  ): boolean {
+    return attacker.moves.some(
+      (move) =>
+        calculateDamage(attacker.combatant, defender, move, {
+          doubles: true,
+          attackerAlly,
+          defenderAlly,
+        }).koChance > 0
+    );
+  }
+
+  private damagingMoves(combatant: Combatant, movesByKey: Map<string, Move>): Move[] {
+    return this.knownMoves(combatant, movesByKey).filter((move) =>
+      resolveMoveType(combatant, move).move.isDamaging()
+    );
+  }
+
+  private hazardsSetBy(combatant: Combatant, movesByKey: Map<string, Move>): Hazard[] {
+    const hazards = this.knownMoves(combatant, movesByKey).map((move) => move.getHazard());
+    return [...new Set(hazards.filter((hazard): hazard is Hazard => hazard !== undefined))];
+  }
+
+  private knownMoves(combatant: Combatant, movesByKey: Map<string, Move>): Move[] {
+    return combatant.build
+      .getMoves()
+      .map((name) => movesByKey.get(toLookupKey(name)))
+      .filter((move): move is Move => move !== undefined);
+  }
+
+  private findReasons(
+    threat: Combatant,
+    moves: Move[],
+    hazards: Hazard[],
+    team: Combatant[],
+    doubles: boolean
+  ): ThreatReason[] {
+    const reasons: ThreatReason[] = [];
+
+    const attackingTypes = [
+      ...new Set(moves.map((move) => resolveMoveType(threat, move).move.getType())),
+    ];
+    const unresisted = attackingTypes.filter(
+      (type) => !team.some((member) => member.getDefensiveTypes().resists(type))
+    );
+    if (unresisted.length > 0) {
+      reasons.push({
+        code: 'UNRESISTED_TYPES',
+        message: `No team member resists ${unresisted.join(', ')}`,
+        types: unresisted,
+      });
+    }
+
+    const threatSpeed = threat.getEffectiveStat('speed');
+    const fastest = Math.max(...team.map((member) => member.getEffectiveStat('speed')));
+    if (threatSpeed > fastest) {
+      reasons.push({
+        code: 'OUTSPEEDS_TEAM',
+        message: `Outspeeds the whole team (${threatSpeed} vs ${fastest})`,
+      });
+    }
+
+    const knockouts: NonNullable<ThreatReason['knockouts']> = [];
+    team.forEach((member, slot) => {
+      let best: { move: Move; koChance: number; guaranteed: boolean } | undefined;
+      for (const move of moves) {
+        const { koChance, min } = calculateDamage(threat, member, move, { doubles });
+        if (koChance > 0 && (!best || koChance > best.koChance)) {
+          best = { move, koChance, guaranteed: min >= member.getStats().hp };
+        }
+      }
+      if (best) {
+        knockouts.push({ slot, move: best.move.getName(), guaranteed: best.guaranteed });
+      }
+    });
+    if (knockouts.length > 0) {
+      reasons.push({
+        code: 'OHKO',
+        message: `Can knock out ${knockouts.length} team member(s) in one hit`,
+        knockouts,
+      });
+    }
+
+    if (hazards.length > 0) {
+      reasons.push({
+        code: 'SETS_HAZARDS',
+        message: `Sets ${hazards.join(', ')}`,
+        hazards,
+      });
+    }
+
+    return reasons;
+  }
+
+  // Builds the threat's most common set; unknown moves are dropped rather than failing
+  private toBuild(
+    usage: PokemonUsage,
+    set: CommonSet,
+    movesByKey: Map<string, Move>
+  ): PokemonBuild {
+    const moves = set.moves
+      .map((name) => movesByKey.get(toLookupKey(name))?.getName())
+      .filter((name): name is string => name !== undefined);
+    const teraType = set.teraType
+      ? PokemonTypeSchema.safeParse(set.teraType.charAt(0).toUpperCase() + set.teraType.slice(1))
+      : undefined;
+    const evs = set.spread?.evs;
+
+    return PokemonBuild.create({
+      formId: usage.getFormId(),
+      ability: set.ability,
+      heldItem: set.item,
+      moves: [...new Set(moves)],
+      teraType: teraType?.success ? teraType.data : undefined,
+      nature: set.spread?.nature,
+      ev: evs
+        ? new EVStats(
+            evs.hp,
+            evs.attack,
+            evs.defense,
+            evs.specialAttack,
+            evs.specialDefense,
+            evs.speed
+          )
+        : undefined,
+    });
+  }
+
+  private async loadForms(formIds: FormId[]): Promise<Map<FormId, PokemonMaster>> {
+    const forms = await this.formRepository.findByIds([...new Set(formIds)]);
+    return new Map(forms.map((form) => [form.getFormId(), form]));
+  }
+
+  private async loadMoves(names: string[]): Promise<Map<string, Move>> {
+    const moves = await this.moveRepository.findByNames(names);
+    return new Map(moves.map((move) => [toLookupKey(move.getName()), move]));
+  }
+}
@@ -0,0 +1,301 @@
+import { v4 as uuidv4 } from 'uuid';
+import { MIN_TOURNAMENT_ENTRIES, Tournament, TournamentStatus } from '../domain/entity/Tournament';
+import { FormatId } from '../domain/valueobject/Format';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { TeamRepository } from '../repository/interface/TeamRepository';
+import { TournamentRepository } from '../repository/interface/TournamentRepository';
+import { UserRepository } from '../repository/interface/UserRepository';
+
+export class TournamentServiceError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TournamentServiceError';
+  }
+
+  static tournamentNotFound(tournamentId: string): TournamentServiceError {
+    return new TournamentServiceError(
+      `Tournament ${tournamentId} not found`,
+      'TOURNAMENT_NOT_FOUND'
+    );
+  }
+
+  static notOrganizer(tournamentId: string): TournamentServiceError {
+    return new TournamentServiceError(
+      `Only the organizer can do this in tournament ${tournamentId}`,
+      'NOT_ORGANIZER'
+    );
+  }
+
+  static registrationClosed(tournamentId: string): TournamentServiceError {
+    return new TournamentServiceError(
+      `Registration for tournament ${tournamentId} is closed`,
+      'REGISTRATION_CLOSED'
+    );
+  }
+
+  static tournamentFull(maxEntries: number): TournamentServiceError {
+    return new TournamentServiceError(
+      `The tournament is full (${maxEntries} entries)`,
+      'TOURNAMENT_FULL'
+    );
+  }
+
+  // Also used for other users' teams and teams in the trash
+  static teamNotFound(teamId: string): TournamentServiceError {
+    return new TournamentServiceError(`Team ${teamId} not found`, 'TEAM_NOT_FOUND');
+  }
+
+  static emptyTeam(teamId: string): TournamentServiceError {
+    return new TournamentServiceError(`Team ${teamId} has no Pokémon`, 'EMPTY_TEAM');
+  }
+
+  static notRegistered(tournamentId: string): TournamentServiceError {
+    return new TournamentServiceError(
+      `You are not registered for tournament ${tournamentId}`,
+      'NOT_REGISTERED'
+    );
+  }
+
+  static notEnoughEntries(): TournamentServiceError {
+    return new TournamentServiceError(
+      `A tournament needs at least ${MIN_TOURNAMENT_ENTRIES} entries to start`,
+      'NOT_ENOUGH_ENTRIES'
+    );
+  }
+
+  static matchNotFound(round: number, position: number): TournamentServiceError {
+    return new TournamentServiceError(
+      `There is no match ${position} in round ${round}`,
+      'MATCH_NOT_FOUND'
+    );
+  }
+
+  // One of the players has not won their way into the match yet
+  static matchNotReady(round: number, position: number): TournamentServiceError {
+    return new TournamentServiceError(
+      `Match ${position} in round ${round} is still waiting for a player`,
+      'MATCH_NOT_READY'
+    );
+  }
+
+  static resultAlreadyReported(round: number, position: number): TournamentServiceError {
+    return new TournamentServiceError(
+      `The result of match ${position} in round ${round} is already in`,
+      'RESULT_ALREADY_REPORTED'
+    );
+  }
+
+  static notInMatch(round: number, position: number): TournamentServiceError {
+    return new TournamentServiceError(
+      `Only the organizer or a player can report match ${position} in round ${round}`,
+      'NOT_IN_MATCH'
+    );
+  }
+
+  static invalidWinner(winnerId: string): TournamentServiceError {
+    return new TournamentServiceError(
+      `User ${winnerId} is not playing in this match`,
+      'INVALID_WINNER'
+    );
+  }
+
+  // Someone else changed the tournament after it was read; reload it and try again
+  static tournamentConflict(tournamentId: string): TournamentServiceError {
+    return new TournamentServiceError(
+      `Tournament ${tournamentId} changed in the meantime`,
+      'TOURNAMENT_CONFLICT'
+    );
+  }
+}
+
+export interface TournamentInput {
+  name: string;
+  format: FormatId;
+  maxEntries: number;
+}
+
+export interface TournamentPage {
+  tournaments: Tournament[];
+  total: number;
+}
+
+export class TournamentService {
+  constructor(
+    private readonly tournamentRepository: TournamentRepository,
+    private readonly teamRepository: TeamRepository,
+    private readonly userRepository: UserRepository
+  ) {}
+
+  async create(userId: string, input: TournamentInput): Promise<Tournament> {
+    const tournament = Tournament.create(
+      uuidv4(),
+      userId,
+      input.name,
+      input.format,
+      input.maxEntries
+    );
+    await this.save(tournament);
+    return tournament;
+  }
+
+  // Tournaments are public, so anyone can browse and follow them
+  async list(
+    status: TournamentStatus | null,
+    limit: number,
+    offset: number
+  ): Promise<TournamentPage> {
+    const [tournaments, total] = await Promise.all([
+      this.tournamentRepository.findPage(status, limit, offset),
+      this.tournamentRepository.count(status),
+    ]);
+    return { tournaments, total };
+  }
+
+  async get(tournamentId: string): Promise<Tournament> {
+    const tournament = await this.tournamentRepository.findById(tournamentId);
+    if (!tournament) {
+      throw TournamentServiceError.tournamentNotFound(tournamentId);
+    }
+    return tournament;
+  }
+
+  async delete(userId: string, tournamentId: string): Promise<void> {
+    const tournament = await this.get(tournamentId);
+    if (tournament.getOrganizerId() !== userId) {
+      throw TournamentServiceError.notOrganizer(tournamentId);
+    }
+    await this.tournamentRepository.delete(tournamentId);
+  }
+
+  // Enters one of the user's teams, or swaps the one they entered. The team is copied as it is
+  // now, so editing it afterwards does not change the entry.
+  async register(userId: string, tournamentId: string, teamId: string): Promise<Tournament> {
+    const tournament = await this.get(tournamentId);
+    if (tournament.getStatus() !== 'registration') {
+      throw TournamentServiceError.registrationClosed(tournamentId);
+    }
+    const existing = tournament.getEntry(userId);
+    if (!existing && tournament.isFull()) {
+      throw TournamentServiceError.tournamentFull(tournament.getMaxEntries());
+    }
+
+    const team = await this.teamRepository.findById(teamId);
+    if (!team || team.getOwnerId() !== userId || team.isDeleted()) {
+      throw TournamentServiceError.teamNotFound(teamId);
+    }
+    const members = team.getPokemon().flatMap((member, slot) =>
+      member
+        ? [
+            {
+              slot,
+              formId: FormId.fromRepository(member.formId),
+              terastalType: member.terastalType,
+            },
+          ]
+        : []
+    );
+    if (members.length === 0) {
+      throw TournamentServiceError.emptyTeam(teamId);
+    }
+
+    tournament.register({
+      userId,
+      teamId,
+      teamName: team.getTeamName(),
+      teamVersion: team.getVersion(),
+      members,
+      registeredAt: new Date(),
+    });
+    await this.save(tournament);
+    return tournament;
+  }
+
+  async withdraw(userId: string, tournamentId: string): Promise<Tournament> {
+    const tournament = await this.get(tournamentId);
+    if (tournament.getStatus() !== 'registration') {
+      throw TournamentServiceError.registrationClosed(tournamentId);
+    }
+    if (!tournament.getEntry(userId)) {
+      throw TournamentServiceError.notRegistered(tournamentId);
+    }
+
+    tournament.withdraw(userId);
+    await this.save(tournament);
+    return tournament;
+  }
+
+  // Closes registration and draws the bracket
+  async start(userId: string, tournamentId: string): Promise<Tournament> {
+    const tournament = await this.get(tournamentId);
+    if (tournament.getOrganizerId() !== userId) {
+      throw TournamentServiceError.notOrganizer(tournamentId);
+    }
+    if (tournament.getStatus() !== 'registration') {
+      throw TournamentServiceError.registrationClosed(tournamentId);
+    }
+    if (tournament.getEntries().length < MIN_TOURNAMENT_ENTRIES) {
+      throw TournamentServiceError.notEnoughEntries();
+    }
+
+    tournament.start(new Date());
+    await this.save(tournament);
+    return tournament;
+  }
+
+  // Either player or the organizer can report who won
+  async reportResult(
+    userId: string,
+    tournamentId: string,
+    round: number,
+    position: number,
+    winnerId: string
+  ): Promise<Tournament> {
+    const tournament = await this.get(tournamentId);
+    const match = tournament.getMatch(round, position);
+    if (!match) {
+      throw TournamentServiceError.matchNotFound(round, position);
+    }
+    const players = [match.player1Id, match.player2Id];
+    if (tournament.getOrganizerId() !== userId && !players.includes(userId)) {
+      throw TournamentServiceError.notInMatch(round, position);
+    }
+    if (match.winnerId) {
+      throw TournamentServiceError.resultAlreadyReported(round, position);
+    }
+    if (!match.player1Id || !match.player2Id) {
+      throw TournamentServiceError.matchNotReady(round, position);
+    }
+    if (!players.includes(winnerId)) {
+      throw TournamentServiceError.invalidWinner(winnerId);
+    }
+
+    tournament.reportResult(round, position, winnerId, new Date());
+    await this.save(tournament);
+    return tournament;
+  }
+
+  // Usernames of the organizers and everyone entered, for showing the bracket
+  async findUsernames(tournaments: Tournament[]): Promise<Map<string, string>> {
+    const userIds = [
+      ...new Set(
+        tournaments.flatMap((tournament) => [
+          tournament.getOrganizerId(),
+          ...tournament.getEntries().map((entry) => entry.userId),
+        ])
+      ),
+    ];
+    const users = await Promise.all(userIds.map((id) => this.userRepository.findById(id)));
+    return new Map(
+      users.flatMap((user) => (user ? [[user.getUserId(), user.getUsername()] as const] : []))
+    );
+  }
+
+  private async save(tournament: Tournament): Promise<void> {
+    if (!(await this.tournamentRepository.save(tournament))) {
+      throw TournamentServiceError.tournamentConflict(tournament.getTournamentId());
+    }
+  }
+}
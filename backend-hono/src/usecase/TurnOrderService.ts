@@ -0,0 +1,84 @@
+import { Move } from '../domain/entity/Move';
+import { Combatant } from '../domain/service/DamageCalculator';
+import { determineTurnOrder, FieldState, TurnOrder } from '../domain/service/TurnOrder';
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { Level } from '../domain/valueobject/Level';
+import { PokemonBuild } from '../domain/valueobject/PokemonBuild';
+import { StatStages } from '../domain/valueobject/StatStages';
+import { MoveRepository } from '../repository/interface/MoveRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+
+export class TurnOrderError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TurnOrderError';
+  }
+
+  static formNotFound(formId: number): TurnOrderError {
+    return new TurnOrderError(`Form ${formId} not found`, 'FORM_NOT_FOUND');
+  }
+
+  static moveNotFound(moveName: string): TurnOrderError {
+    return new TurnOrderError(`Move ${moveName} not found`, 'MOVE_NOT_FOUND');
+  }
+}
+
+export interface TurnOrderSide {
+  build: PokemonBuild;
+  moveName: string;
+  stages: StatStages;
+  tailwind: boolean;
+  paralyzed: boolean;
+}
+
+export interface TurnOrderResponse extends TurnOrder {
+  moves: [Move, Move];
+}
+
+export class TurnOrderService {
+  constructor(
+    private readonly formRepository: PokemonFormRepository,
+    private readonly moveRepository: MoveRepository
+  ) {}
+
+  // Who moves first when two Pokémon pick these moves on the same turn
+  async determine(
+    first: TurnOrderSide,
+    second: TurnOrderSide,
+    level: Level,
+    field: FieldState
+  ): Promise<TurnOrderResponse> {
+    const sides = [first, second];
+    const [forms, moves] = await Promise.all([
+      this.formRepository.findByIds([...new Set(sides.map((side) => side.build.getFormId()))]),
+      this.moveRepository.findByNames(sides.map((side) => side.moveName)),
+    ]);
+    const formsById = new Map(forms.map((form) => [form.getFormId(), form]));
+    const movesByKey = new Map(moves.map((move) => [toLookupKey(move.getName()), move]));
+
+    const [firstAction, secondAction] = sides.map((side) => {
+      const form = formsById.get(side.build.getFormId());
+      if (!form) {
+        throw TurnOrderError.formNotFound(side.build.getFormId());
+      }
+      const move = movesByKey.get(toLookupKey(side.moveName));
+      if (!move) {
+        throw TurnOrderError.moveNotFound(side.moveName);
+      }
+      return {
+        combatant: new Combatant(form, side.build, level, false, side.stages),
+        move,
+        tailwind: side.tailwind,
+        paralyzed: side.paralyzed,
+      };
+    });
+
+    return {
+      ...determineTurnOrder(firstAction, secondAction, field),
+      moves: [firstAction.move, secondAction.move],
+    };
+  }
+}
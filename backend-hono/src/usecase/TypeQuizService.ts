@@ -0,0 +1,68 @@
+import { TypeQuizScore } from '../domain/entity/TypeQuizScore';
+import { Rng, SystemRandom } from '../domain/service/Rng';
+import { SeededRandom } from '../domain/service/SeededRandom';
+import { createTypeQuizQuestion, TypeQuizQuestion } from '../domain/service/TypeQuiz';
+import { TypeQuizRepository } from '../repository/interface/TypeQuizRepository';
+
+export class TypeQuizError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'TypeQuizError';
+  }
+
+  static invalidChoice(questionId: number, choice: number): TypeQuizError {
+    return new TypeQuizError(
+      `${choice} is not one of the choices for question ${questionId}`,
+      'INVALID_CHOICE'
+    );
+  }
+
+  static alreadyAnswered(questionId: number): TypeQuizError {
+    return new TypeQuizError(
+      `Question ${questionId} has already been answered`,
+      'QUESTION_ALREADY_ANSWERED'
+    );
+  }
+}
+
+export interface TypeQuizResult {
+  question: TypeQuizQuestion;
+  correct: boolean;
+  score: TypeQuizScore;
+}
+
+// Questions are rebuilt from their ID instead of being stored, so only answers hit the database
+export class TypeQuizService {
+  constructor(
+    private readonly quizRepository: TypeQuizRepository,
+    // Picks the ID of each new question
+    private readonly seedSource: Rng = new SystemRandom()
+  ) {}
+
+  createQuestion(): TypeQuizQuestion {
+    return createTypeQuizQuestion(SeededRandom.randomSeed(this.seedSource));
+  }
+
+  async submitAnswer(userId: string, questionId: number, choice: number): Promise<TypeQuizResult> {
+    const question = createTypeQuizQuestion(questionId);
+    if (!question.choices.includes(choice)) {
+      throw TypeQuizError.invalidChoice(questionId, choice);
+    }
+
+    const correct = choice === question.answer;
+    const score = await this.getScore(userId);
+    score.record(correct);
+    if (!(await this.quizRepository.saveAnswer(score, questionId, correct))) {
+      throw TypeQuizError.alreadyAnswered(questionId);
+    }
+
+    return { question, correct, score };
+  }
+
+  async getScore(userId: string): Promise<TypeQuizScore> {
+    return (await this.quizRepository.findScore(userId)) ?? TypeQuizScore.create(userId);
+  }
+}
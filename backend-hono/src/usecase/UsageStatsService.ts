@@ -0,0 +1,192 @@
+import { PokemonMaster } from '../domain/entity/PokemonMaster';
+import {
+  CommonSet,
+  PokemonUsage,
+  SpreadUsage,
+  UsageDetail,
+  UsageSnapshot,
+} from '../domain/entity/UsageSnapshot';
+import { FormatId, formatFromSmogonMetagame } from '../domain/valueobject/Format';
+import { toLookupKey } from '../domain/valueobject/Identifier';
+import { FormId } from '../domain/valueobject/PokemonId';
+import { AnalysisCacheRepository } from '../repository/interface/AnalysisCacheRepository';
+import { PokemonFormRepository } from '../repository/interface/PokemonFormRepository';
+import { UsageStatsRepository } from '../repository/interface/UsageStatsRepository';
+import { SmogonChaosDto } from './dto';
+
+// Only the most common entries are kept; the long tail is noise for set recommendations
+const MAX_DETAILS_PER_CATEGORY = 20;
+
+export class UsageStatsError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'UsageStatsError';
+  }
+
+  static unknownMetagame(metagame: string): UsageStatsError {
+    return new UsageStatsError(`Unsupported metagame: ${metagame}`, 'UNKNOWN_METAGAME');
+  }
+
+  static noUsageData(formatId: FormatId): UsageStatsError {
+    return new UsageStatsError(`No usage data for ${formatId}`, 'USAGE_NOT_FOUND');
+  }
+
+  static pokemonNotFound(formatId: FormatId, formId: number): UsageStatsError {
+    return new UsageStatsError(
+      `No usage data for form ${formId} in ${formatId}`,
+      'USAGE_NOT_FOUND'
+    );
+  }
+}
+
+export interface UsageImportResult {
+  formatId: FormatId;
+  period: string;
+  imported: number;
+  unmapped: string[];
+}
+
+export interface UsageRankingEntry {
+  formId: number;
+  name: string;
+  nameJp: string;
+  rank: number;
+  usage: number;
+  rawCount: number;
+}
+
+export interface PokemonUsageResponse {
+  formatId: FormatId;
+  period: string;
+  usage: PokemonUsage;
+  natures: UsageDetail[];
+  spreads: SpreadUsage[];
+  commonSet: CommonSet;
+}
+
+export class UsageStatsService {
+  constructor(
+    private readonly usageRepository: UsageStatsRepository,
+    private readonly formRepository: PokemonFormRepository,
+    private readonly analysisCacheRepository: AnalysisCacheRepository
+  ) {}
+
+  async importChaos(chaos: SmogonChaosDto, period: string): Promise<UsageImportResult> {
+    const formatId = formatFromSmogonMetagame(chaos.info.metagame);
+    if (!formatId) {
+      throw UsageStatsError.unknownMetagame(chaos.info.metagame);
+    }
+
+    const formsByName = this.indexByName(await this.formRepository.findAll());
+
+    const unmapped: string[] = [];
+    const mapped: { form: PokemonMaster; data: SmogonChaosDto['data'][string] }[] = [];
+    for (const [name, data] of Object.entries(chaos.data)) {
+      const form = formsByName.get(toLookupKey(name));
+      if (form) {
+        mapped.push({ form, data });
+      } else {
+        unmapped.push(name);
+      }
+    }
+
+    mapped.sort((a, b) => b.data.usage - a.data.usage);
+    const entries = mapped.map(({ form, data }, index) => {
+      const total = Object.values(data.Abilities).reduce((sum, weight) => sum + weight, 0);
+      return PokemonUsage.fromRepository({
+        formId: form.getFormId(),
+        rank: index + 1,
+        usage: data.usage * 100,
+        rawCount: data['Raw count'],
+        details: {
+          move: this.toDetails(data.Moves, total),
+          item: this.toDetails(data.Items, total),
+          ability: this.toDetails(data.Abilities, total),
+          tera_type: this.toDetails(data['Tera Types'] ?? {}, total),
+          spread: this.toDetails(data.Spreads, total),
+        },
+      });
+    });
+
+    const snapshot = new UsageSnapshot(
+      formatId,
+      period,
+      chaos.info.cutoff,
+      chaos.info['number of battles'],
+      entries
+    );
+    await this.usageRepository.saveSnapshot(snapshot);
+    // Threat lists come from usage data, which the data version does not track
+    await this.analysisCacheRepository.deleteAll();
+
+    return { formatId, period, imported: entries.length, unmapped };
+  }
+
+  async getTop(formatId: FormatId, limit: number, period?: string): Promise<UsageRankingEntry[]> {
+    const resolvedPeriod = await this.resolvePeriod(formatId, period);
+    const usages = await this.usageRepository.findTop(formatId, resolvedPeriod, limit);
+
+    const forms = await this.formRepository.findByIds(usages.map((usage) => usage.getFormId()));
+    const formsById = new Map(forms.map((form) => [form.getFormId(), form]));
+
+    return usages.map((usage) => {
+      const form = formsById.get(usage.getFormId());
+      return {
+        formId: usage.getFormId(),
+        name: form?.getName() ?? '',
+        nameJp: form?.getNameJp() ?? '',
+        rank: usage.getRank(),
+        usage: usage.getUsage(),
+        rawCount: usage.getRawCount(),
+      };
+    });
+  }
+
+  async getPokemonUsage(
+    formatId: FormatId,
+    formId: FormId,
+    period?: string
+  ): Promise<PokemonUsageResponse> {
+    const resolvedPeriod = await this.resolvePeriod(formatId, period);
+    const usage = await this.usageRepository.findByFormId(formatId, resolvedPeriod, formId);
+    if (!usage) {
+      throw UsageStatsError.pokemonNotFound(formatId, formId);
+    }
+
+    return {
+      formatId,
+      period: resolvedPeriod,
+      usage,
+      natures: usage.getNatures(),
+      spreads: usage.getSpreads(),
+      commonSet: usage.getCommonSet(),
+    };
+  }
+
+  private async resolvePeriod(formatId: FormatId, period?: string): Promise<string> {
+    const resolved = period ?? (await this.usageRepository.findLatestPeriod(formatId));
+    if (!resolved) {
+      throw UsageStatsError.noUsageData(formatId);
+    }
+    return resolved;
+  }
+
+  private toDetails(weights: Record<string, number>, total: number): UsageDetail[] {
+    if (total <= 0) {
+      return [];
+    }
+
+    return Object.entries(weights)
+      .filter(([name]) => name !== '')
+      .map(([name, weight]) => ({ name, percentage: (weight / total) * 100 }))
+      .sort((a, b) => b.percentage - a.percentage)
+      .slice(0, MAX_DETAILS_PER_CATEGORY);
+  }
+
+  private indexByName(forms: PokemonMaster[]): Map<string, PokemonMaster> {
+    return new Map(forms.map((form) => [toLookupKey(form.getName()), form]));
+  }
+}
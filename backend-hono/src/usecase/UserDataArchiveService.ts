@@ -0,0 +1,391 @@
+import crypto from 'crypto';
+import { z } from 'zod';
+import { HashedPassword } from '../domain/valueobject/HashedPassword';
+import {
+  ArchivedPreferences,
+  ArchivedTeam,
+  ArchivedUser,
+  UserDataArchiveRepository,
+} from '../repository/interface/UserDataArchiveRepository';
+
+export class UserDataArchiveError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'UserDataArchiveError';
+  }
+
+  static malformed(line: number, reason: string): UserDataArchiveError {
+    return new UserDataArchiveError(
+      `Line ${line} of the archive is invalid: ${reason}`,
+      'INVALID_ARCHIVE'
+    );
+  }
+
+  static truncated(): UserDataArchiveError {
+    return new UserDataArchiveError(
+      'The archive ends before its trailer; it was cut off while being written or copied',
+      'TRUNCATED_ARCHIVE'
+    );
+  }
+}
+
+export const USER_DATA_ARCHIVE_FORMAT = 'pokedict-user-data';
+export const USER_DATA_ARCHIVE_VERSION = 1;
+
+// Rows are read and restored a page at a time, so neither side holds the whole database
+const ARCHIVE_PAGE_SIZE = 500;
+
+const timestamp = z
+  .string()
+  .datetime()
+  .transform((value) => new Date(value));
+
+// One JSON object per line: a header, every user, every user's preferences, every team, then a
+// trailer with the counts, so a truncated archive is caught rather than half restored
+const HeaderLineSchema = z.object({
+  kind: z.literal('header'),
+  format: z.literal(USER_DATA_ARCHIVE_FORMAT),
+  version: z.literal(USER_DATA_ARCHIVE_VERSION),
+  created_at: timestamp,
+  password_hashes: z.boolean(),
+});
+
+const UserLineSchema = z.object({
+  kind: z.literal('user'),
+  user_id: z.string().uuid(),
+  username: z.string().min(1).max(20),
+  email: z.string().min(1).max(254),
+  password_hash: z.string().min(1).max(60).optional(),
+  role: z.string().min(1).max(20),
+  created_at: timestamp,
+  // Set on the tombstones of deleted accounts
+  deleted_at: timestamp.nullable(),
+});
+
+const PreferencesLineSchema = z.object({
+  kind: z.literal('preferences'),
+  user_id: z.string().uuid(),
+  language: z.string().max(10).nullable(),
+  format: z.string().max(50).nullable(),
+  level: z.number().int(),
+  theme: z.string().max(10),
+});
+
+const TeamLineSchema = z.object({
+  kind: z.literal('team'),
+  team_id: z.string().uuid(),
+  owner_id: z.string().uuid(),
+  team_name: z.string().min(1).max(50),
+  members: z.array(
+    z.object({
+      slot: z.number().int().min(0).max(5),
+      form_id: z.number().int().positive(),
+      terastal_type: z.string().min(1).max(20),
+      // Archives written before notes have none
+      notes: z.string().nullable().optional(),
+    })
+  ),
+  tags: z.array(z.string().min(1).max(20)),
+  folder: z.string().max(50).nullable(),
+  notes: z.string().nullable().optional(),
+  created_at: timestamp,
+  updated_at: timestamp,
+  deleted_at: timestamp.nullable(),
+  shared_at: timestamp.nullable(),
+  shared_format: z.string().max(50).nullable(),
+});
+
+const TrailerLineSchema = z.object({
+  kind: z.literal('end'),
+  users: z.number().int().nonnegative(),
+  preferences: z.number().int().nonnegative(),
+  teams: z.number().int().nonnegative(),
+});
+
+const RowLineSchema = z.discriminatedUnion('kind', [
+  UserLineSchema,
+  PreferencesLineSchema,
+  TeamLineSchema,
+  TrailerLineSchema,
+]);
+
+export interface UserDataArchiveCounts {
+  users: number;
+  preferences: number;
+  teams: number;
+}
+
+export interface UserDataRestoreResult {
+  // Rows in the archive
+  read: UserDataArchiveCounts;
+  // Rows written; the rest were already there (or, for teams, their owner was not)
+  restored: UserDataArchiveCounts;
+}
+
+export interface UserDataExportOptions {
+  // Without the hashes, restored accounts cannot log in until their passwords are reset
+  includePasswordHashes: boolean;
+}
+
+type RowKind = 'user' | 'preferences' | 'team';
+
+// Disaster recovery dumps of every account and team, and the way back. The export reads one
+// consistent snapshot, so a team is never archived without its owner however long it runs.
+export class UserDataArchiveService {
+  constructor(private readonly archiveRepository: UserDataArchiveRepository) {}
+
+  async exportArchive(
+    write: (line: string) => Promise<void>,
+    options: UserDataExportOptions
+  ): Promise<UserDataArchiveCounts> {
+    return this.archiveRepository.withSnapshot(async (snapshot) => {
+      await write(
+        JSON.stringify({
+          kind: 'header',
+          format: USER_DATA_ARCHIVE_FORMAT,
+          version: USER_DATA_ARCHIVE_VERSION,
+          created_at: new Date().toISOString(),
+          password_hashes: options.includePasswordHashes,
+        })
+      );
+
+      const counts: UserDataArchiveCounts = {
+        users: await this.exportRows(
+          (after) => snapshot.findUsers(ARCHIVE_PAGE_SIZE, after),
+          (user) => user.userId,
+          (user) => toUserLine(user, options.includePasswordHashes),
+          write
+        ),
+        preferences: await this.exportRows(
+          (after) => snapshot.findPreferences(ARCHIVE_PAGE_SIZE, after),
+          (preferences) => preferences.userId,
+          toPreferencesLine,
+          write
+        ),
+        teams: await this.exportRows(
+          (after) => snapshot.findTeams(ARCHIVE_PAGE_SIZE, after),
+          (team) => team.teamId,
+          toTeamLine,
+          write
+        ),
+      };
+      await write(JSON.stringify({ kind: 'end', ...counts }));
+      return counts;
+    });
+  }
+
+  // Restoring is idempotent: rows that already exist are left as they are, so a restore that
+  // failed part way can simply be run again
+  async restore(lines: AsyncIterable<string>): Promise<UserDataRestoreResult> {
+    const result: UserDataRestoreResult = {
+      read: { users: 0, preferences: 0, teams: 0 },
+      restored: { users: 0, preferences: 0, teams: 0 },
+    };
+    let passwordHashes: boolean | undefined;
+    let lockedHash: string | undefined;
+    let trailer: UserDataArchiveCounts | undefined;
+    let users: ArchivedUser[] = [];
+    let preferences: ArchivedPreferences[] = [];
+    let teams: ArchivedTeam[] = [];
+
+    // Users go in before anything that refers to them; the archive lists them first
+    const flush = async (kind?: RowKind) => {
+      if (kind !== 'user' && users.length > 0) {
+        result.restored.users += await this.archiveRepository.restoreUsers(users);
+        users = [];
+      }
+      if (kind !== 'preferences' && preferences.length > 0) {
+        result.restored.preferences += await this.archiveRepository.restorePreferences(preferences);
+        preferences = [];
+      }
+      if (kind !== 'team' && teams.length > 0) {
+        result.restored.teams += await this.archiveRepository.restoreTeams(teams);
+        teams = [];
+      }
+    };
+
+    let lineNumber = 0;
+    for await (const line of lines) {
+      lineNumber++;
+      if (line.trim() === '') {
+        continue;
+      }
+      if (trailer) {
+        throw UserDataArchiveError.malformed(lineNumber, 'content after the trailer');
+      }
+
+      const json = parseJson(line, lineNumber);
+      if (passwordHashes === undefined) {
+        const header = HeaderLineSchema.safeParse(json);
+        if (!header.success) {
+          throw UserDataArchiveError.malformed(lineNumber, 'not a pokedict user data archive');
+        }
+        passwordHashes = header.data.password_hashes;
+        continue;
+      }
+
+      const parsed = RowLineSchema.safeParse(json);
+      if (!parsed.success) {
+        throw UserDataArchiveError.malformed(lineNumber, parsed.error.issues[0].message);
+      }
+      const row = parsed.data;
+      switch (row.kind) {
+        case 'user': {
+          if (!row.password_hash && passwordHashes) {
+            throw UserDataArchiveError.malformed(lineNumber, 'missing password hash');
+          }
+          // Accounts archived without a hash all get the hash of one random password, which
+          // nobody knows, so they stay locked until a password reset
+          const passwordHash = row.password_hash ?? (lockedHash ??= await generateLockedHash());
+          users.push({
+            userId: row.user_id,
+            username: row.username,
+            email: row.email,
+            passwordHash,
+            role: row.role,
+            createdAt: row.created_at,
+            deletedAt: row.deleted_at,
+          });
+          result.read.users++;
+          break;
+        }
+        case 'preferences':
+          preferences.push({
+            userId: row.user_id,
+            language: row.language,
+            format: row.format,
+            level: row.level,
+            theme: row.theme,
+          });
+          result.read.preferences++;
+          break;
+        case 'team':
+          teams.push({
+            teamId: row.team_id,
+            ownerId: row.owner_id,
+            teamName: row.team_name,
+            members: row.members.map((member) => ({
+              slot: member.slot,
+              formId: member.form_id,
+              terastalType: member.terastal_type,
+              notes: member.notes ?? null,
+            })),
+            tags: row.tags,
+            folder: row.folder,
+            notes: row.notes ?? null,
+            createdAt: row.created_at,
+            updatedAt: row.updated_at,
+            deletedAt: row.deleted_at,
+            sharedAt: row.shared_at,
+            sharedFormat: row.shared_format,
+          });
+          result.read.teams++;
+          break;
+        case 'end':
+          trailer = { users: row.users, preferences: row.preferences, teams: row.teams };
+          break;
+      }
+
+      const kind = row.kind === 'end' ? undefined : row.kind;
+      const pending = users.length + preferences.length + teams.length;
+      await (pending >= ARCHIVE_PAGE_SIZE ? flush() : flush(kind));
+    }
+    await flush();
+
+    if (!trailer) {
+      throw UserDataArchiveError.truncated();
+    }
+    if (
+      trailer.users !== result.read.users ||
+      trailer.preferences !== result.read.preferences ||
+      trailer.teams !== result.read.teams
+    ) {
+      throw UserDataArchiveError.malformed(lineNumber, 'row counts do not match the trailer');
+    }
+    return result;
+  }
+
+  private async exportRows<T>(
+    findPage: (after?: string) => Promise<T[]>,
+    keyOf: (row: T) => string,
+    toLine: (row: T) => object,
+    write: (line: string) => Promise<void>
+  ): Promise<number> {
+    let count = 0;
+    let after: string | undefined;
+    for (;;) {
+      const page = await findPage(after);
+      for (const row of page) {
+        await write(JSON.stringify(toLine(row)));
+      }
+      count += page.length;
+      if (page.length < ARCHIVE_PAGE_SIZE) {
+        return count;
+      }
+      after = keyOf(page[page.length - 1]);
+    }
+  }
+}
+
+function parseJson(line: string, lineNumber: number): unknown {
+  try {
+    return JSON.parse(line);
+  } catch {
+    throw UserDataArchiveError.malformed(lineNumber, 'not JSON');
+  }
+}
+
+async function generateLockedHash(): Promise<string> {
+  const password = await HashedPassword.fromPlainText(crypto.randomBytes(24).toString('base64url'));
+  return password.getHash();
+}
+
+function toUserLine(user: ArchivedUser, includePasswordHash: boolean) {
+  return {
+    kind: 'user',
+    user_id: user.userId,
+    username: user.username,
+    email: user.email,
+    password_hash: includePasswordHash ? user.passwordHash : undefined,
+    role: user.role,
+    created_at: user.createdAt.toISOString(),
+    deleted_at: user.deletedAt?.toISOString() ?? null,
+  };
+}
+
+function toPreferencesLine(preferences: ArchivedPreferences) {
+  return {
+    kind: 'preferences',
+    user_id: preferences.userId,
+    language: preferences.language,
+    format: preferences.format,
+    level: preferences.level,
+    theme: preferences.theme,
+  };
+}
+
+function toTeamLine(team: ArchivedTeam) {
+  return {
+    kind: 'team',
+    team_id: team.teamId,
+    owner_id: team.ownerId,
+    team_name: team.teamName,
+    members: team.members.map((member) => ({
+      slot: member.slot,
+      form_id: member.formId,
+      terastal_type: member.terastalType,
+      notes: member.notes,
+    })),
+    tags: team.tags,
+    folder: team.folder,
+    notes: team.notes,
+    created_at: team.createdAt.toISOString(),
+    updated_at: team.updatedAt.toISOString(),
+    deleted_at: team.deletedAt?.toISOString() ?? null,
+    shared_at: team.sharedAt?.toISOString() ?? null,
+    shared_format: team.sharedFormat,
+  };
+}
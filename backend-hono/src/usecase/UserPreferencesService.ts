@@ -0,0 +1,20 @@
+import { PreferenceChanges, UserPreferences } from '../domain/entity/UserPreferences';
+import { UserPreferencesRepository } from '../repository/interface/UserPreferencesRepository';
+
+export class UserPreferencesService {
+  constructor(private readonly userPreferencesRepository: UserPreferencesRepository) {}
+
+  async get(userId: string): Promise<UserPreferences> {
+    return (
+      (await this.userPreferencesRepository.findByUser(userId)) ?? UserPreferences.defaults(userId)
+    );
+  }
+
+  // Fields left out keep their value
+  async update(userId: string, changes: PreferenceChanges): Promise<UserPreferences> {
+    const preferences = await this.get(userId);
+    preferences.update(changes);
+    await this.userPreferencesRepository.save(preferences);
+    return preferences;
+  }
+}
@@ -0,0 +1,210 @@
+import { v4 as uuidv4 } from 'uuid';
+import { Webhook } from '../domain/entity/Webhook';
+import { TeamEvent, TeamEventType } from '../domain/event/TeamEvent';
+import { WebhookDelivery, WebhookRepository } from '../repository/interface/WebhookRepository';
+import { HostResolver, isPublicWebhookTarget, resolveHost } from './WebhookTarget';
+
+export class WebhookError extends Error {
+  constructor(
+    message: string,
+    public readonly code: string
+  ) {
+    super(message);
+    this.name = 'WebhookError';
+  }
+
+  // Also used for other users' webhooks
+  static webhookNotFound(webhookId: string): WebhookError {
+    return new WebhookError(`Webhook ${webhookId} not found`, 'WEBHOOK_NOT_FOUND');
+  }
+
+  static tooManyWebhooks(max: number): WebhookError {
+    return new WebhookError(`A user can register at most ${max} webhooks`, 'TOO_MANY_WEBHOOKS');
+  }
+
+  static urlNotAllowed(): WebhookError {
+    return new WebhookError('Webhook URLs must point at a public host', 'WEBHOOK_URL_NOT_ALLOWED');
+  }
+}
+
+export const MAX_WEBHOOKS_PER_USER = 5;
+
+// Waits before the second and third attempts
+const RETRY_DELAYS_MS = [1000, 5000];
+const DELIVERY_TIMEOUT_MS = 10000;
+
+// What the delivery log records instead of a status code. Network error messages can reveal
+// which hosts and ports exist, so they are only logged on the server.
+const DELIVERY_ERRORS = {
+  blockedHost: 'blocked_host',
+  timeout: 'timeout',
+  connectionFailed: 'connection_failed',
+} as const;
+
+export const WEBHOOK_HEADERS = {
+  event: 'X-Pokedict-Event',
+  eventId: 'X-Pokedict-Event-Id',
+  timestamp: 'X-Pokedict-Timestamp',
+  signature: 'X-Pokedict-Signature',
+} as const;
+
+export type WebhookSender = (url: string, init: RequestInit) => Promise<Response>;
+
+// Workers reject a detached `fetch` ("Illegal invocation"), so it is wrapped
+const defaultSender: WebhookSender = (url, init) => fetch(url, init);
+
+export class WebhookService {
+  constructor(
+    private readonly webhookRepository: WebhookRepository,
+    private readonly send: WebhookSender = defaultSender,
+    private readonly resolve: HostResolver = resolveHost
+  ) {}
+
+  async register(userId: string, url: string, events: TeamEventType[]): Promise<Webhook> {
+    const existing = await this.webhookRepository.findByOwner(userId);
+    if (existing.length >= MAX_WEBHOOKS_PER_USER) {
+      throw WebhookError.tooManyWebhooks(MAX_WEBHOOKS_PER_USER);
+    }
+    // A host that does not resolve yet cannot be checked either
+    const allowed = await isPublicWebhookTarget(url, this.resolve).catch(() => false);
+    if (!allowed) {
+      throw WebhookError.urlNotAllowed();
+    }
+
+    const webhook = Webhook.create(uuidv4(), userId, url, events);
+    await this.webhookRepository.save(webhook);
+    return webhook;
+  }
+
+  async list(userId: string): Promise<Webhook[]> {
+    return this.webhookRepository.findByOwner(userId);
+  }
+
+  async delete(userId: string, webhookId: string): Promise<void> {
+    await this.findOwned(userId, webhookId);
+    await this.webhookRepository.delete(webhookId);
+  }
+
+  async listDeliveries(
+    userId: string,
+    webhookId: string,
+    limit: number
+  ): Promise<WebhookDelivery[]> {
+    await this.findOwned(userId, webhookId);
+    return this.webhookRepository.findDeliveries(webhookId, limit);
+  }
+
+  // Subscribed to the team event bus. Takes as long as the retries do, so callers run it in the
+  // background rather than in the request.
+  async dispatch(event: TeamEvent): Promise<void> {
+    const webhooks = await this.webhookRepository.findByOwner(event.team.getOwnerId());
+    const body = JSON.stringify(toPayload(event));
+
+    await Promise.all(
+      webhooks
+        .filter((webhook) => webhook.subscribesTo(event.type))
+        .map((webhook) => this.deliver(webhook, event, body))
+    );
+  }
+
+  // Network errors, timeouts, 408, 429 and 5xx are retried; any other status, and a host that
+  // now resolves to a non-public address, is final
+  private async deliver(webhook: Webhook, event: TeamEvent, body: string): Promise<void> {
+    for (let attempt = 1; attempt <= RETRY_DELAYS_MS.length + 1; attempt++) {
+      if (attempt > 1) {
+        await sleep(RETRY_DELAYS_MS[attempt - 2]);
+      }
+
+      const delivery = await this.attempt(webhook, event, body, attempt);
+      await this.webhookRepository.saveDelivery(delivery);
+
+      const status = delivery.statusCode;
+      const retryable =
+        status === undefined
+          ? delivery.error !== DELIVERY_ERRORS.blockedHost
+          : status === 408 || status === 429 || status >= 500;
+      if (delivery.succeeded || !retryable) {
+        return;
+      }
+    }
+  }
+
+  private async attempt(
+    webhook: Webhook,
+    event: TeamEvent,
+    body: string,
+    attempt: number
+  ): Promise<WebhookDelivery> {
+    const timestamp = Math.floor(Date.now() / 1000);
+    const delivery = {
+      deliveryId: uuidv4(),
+      webhookId: webhook.getWebhookId(),
+      eventId: event.eventId,
+      eventType: event.type,
+      attempt,
+      attemptedAt: new Date(),
+    };
+
+    try {
+      // Checked on every attempt: the host's DNS record may have changed since registration
+      if (!(await isPublicWebhookTarget(webhook.getUrl(), this.resolve))) {
+        return { ...delivery, error: DELIVERY_ERRORS.blockedHost, succeeded: false };
+      }
+
+      const response = await this.send(webhook.getUrl(), {
+        method: 'POST',
+        headers: {
+          'Content-Type': 'application/json',
+          [WEBHOOK_HEADERS.event]: event.type,
+          [WEBHOOK_HEADERS.eventId]: event.eventId,
+          [WEBHOOK_HEADERS.timestamp]: String(timestamp),
+          [WEBHOOK_HEADERS.signature]: `sha256=${webhook.sign(timestamp, body)}`,
+        },
+        body,
+        redirect: 'manual',
+        signal: AbortSignal.timeout(DELIVERY_TIMEOUT_MS),
+      });
+      return { ...delivery, statusCode: response.status, succeeded: response.ok };
+    } catch (error) {
+      console.error(`Webhook ${webhook.getWebhookId()} delivery failed:`, error);
+      const timedOut = error instanceof Error && error.name === 'TimeoutError';
+      return {
+        ...delivery,
+        error: timedOut ? DELIVERY_ERRORS.timeout : DELIVERY_ERRORS.connectionFailed,
+        succeeded: false,
+      };
+    }
+  }
+
+  private async findOwned(userId: string, webhookId: string): Promise<Webhook> {
+    const webhook = await this.webhookRepository.findById(webhookId);
+    if (!webhook || webhook.getOwnerId() !== userId) {
+      throw WebhookError.webhookNotFound(webhookId);
+    }
+    return webhook;
+  }
+}
+
+function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+function toPayload(event: TeamEvent) {
+  const team = event.team;
+  return {
+    id: event.eventId,
+    type: event.type,
+    occurred_at: event.occurredAt.toISOString(),
+    data: {
+      team_id: team.getTeamId(),
+      team_name: team.getTeamName(),
+      pokemon: team
+        .getPokemon()
+        .map((member) =>
+          member ? { form_id: member.formId, terastal_type: member.terastalType } : null
+        ),
+      tags: team.getTags(),
+      folder: team.getFolder(),
+    },
+  };
+}
@@ -0,0 +1,151 @@
+// Webhook URLs are fetched by the server, so a URL pointing at the server itself or the network
+// it runs in would let users probe internal hosts and read the results from the delivery log.
+// Only public hosts are allowed, both when registering and again before each delivery, since
+// a DNS record can change in between.
+
+// Resolves a hostname to the IP addresses a request to it could connect to
+export type HostResolver = (hostname: string) => Promise<string[]>;
+
+// IPv4 ranges that are not on the public internet, as [network, prefix length]
+const NON_PUBLIC_IPV4: [string, number][] = [
+  ['0.0.0.0', 8], // "this" network
+  ['10.0.0.0', 8], // private
+  ['100.64.0.0', 10], // carrier-grade NAT
+  ['127.0.0.0', 8], // loopback
+  ['169.254.0.0', 16], // link-local, including cloud metadata endpoints
+  ['172.16.0.0', 12], // private
+  ['192.0.0.0', 24], // IETF protocol assignments
+  ['192.168.0.0', 16], // private
+  ['198.18.0.0', 15], // benchmarking
+  ['224.0.0.0', 3], // multicast, reserved and broadcast
+];
+
+// Names that only resolve inside a network; single-label names such as "localhost" or a
+// container name are rejected as well
+const INTERNAL_SUFFIXES = ['localhost', 'local', 'internal', 'lan', 'home.arpa'];
+
+// Node.js asks the system resolver. Workers cannot connect to private addresses at all and have
+// no resolver to ask, so there only the URL itself is checked.
+export const resolveHost: HostResolver = async (hostname) => {
+  if (typeof navigator !== 'undefined' && navigator.userAgent === 'Cloudflare-Workers') {
+    return [];
+  }
+  const { lookup } = await import('node:dns/promises');
+  const addresses = await lookup(hostname, { all: true, verbatim: true });
+  return addresses.map(({ address }) => address);
+};
+
+// Checks the URL alone: HTTPS, and no internal hostname or non-public IP literal
+export function isPublicWebhookUrl(url: string): boolean {
+  let parsed: URL;
+  try {
+    parsed = new URL(url);
+  } catch {
+    return false;
+  }
+  if (parsed.protocol !== 'https:') {
+    return false;
+  }
+
+  const hostname = parsed.hostname.toLowerCase().replace(/\.$/, '');
+  if (isIpLiteral(hostname)) {
+    return isPublicAddress(hostname);
+  }
+  return (
+    hostname.includes('.') &&
+    !INTERNAL_SUFFIXES.some((suffix) => hostname === suffix || hostname.endsWith(`.${suffix}`))
+  );
+}
+
+// Also resolves the host; every address it resolves to has to be public. Throws when the host
+// cannot be resolved.
+export async function isPublicWebhookTarget(url: string, resolve: HostResolver): Promise<boolean> {
+  if (!isPublicWebhookUrl(url)) {
+    return false;
+  }
+  const hostname = new URL(url).hostname;
+  if (isIpLiteral(hostname)) {
+    return true;
+  }
+  const addresses = await resolve(hostname);
+  return addresses.every(isPublicAddress);
+}
+
+// Anything that does not parse as an IP address is treated as not public
+export function isPublicAddress(address: string): boolean {
+  const ipv4 = parseIpv4(address);
+  if (ipv4 !== null) {
+    return isPublicIpv4(ipv4);
+  }
+  const ipv6 = parseIpv6(address);
+  return ipv6 !== null && isPublicIpv6(ipv6);
+}
+
+function isIpLiteral(hostname: string): boolean {
+  return parseIpv4(hostname) !== null || hostname.startsWith('[');
+}
+
+function isPublicIpv4(address: number): boolean {
+  return !NON_PUBLIC_IPV4.some(([network, prefix]) => {
+    const size = 2 ** (32 - prefix);
+    return Math.floor(address / size) === Math.floor(parseIpv4(network)! / size);
+  });
+}
+
+function isPublicIpv6(groups: number[]): boolean {
+  const [first] = groups;
+  // Unique local (fc00::/7), link-local (fe80::/10), site-local (fec0::/10) and multicast
+  if ((first & 0xfe00) === 0xfc00 || (first & 0xff80) === 0xfe80 || (first & 0xff00) === 0xff00) {
+    return false;
+  }
+
+  // Addresses that carry an IPv4 address are judged by it: IPv4-compatible (which covers
+  // :: and ::1), IPv4-mapped (::ffff:0:0/96), NAT64 (64:ff9b::/96) and 6to4 (2002::/16)
+  const embedded = (high: number, low: number) => isPublicIpv4(high * 0x10000 + low);
+  if (groups.slice(0, 5).every((group) => group === 0)) {
+    return (groups[5] === 0 || groups[5] === 0xffff) && embedded(groups[6], groups[7]);
+  }
+  if (first === 0x64 && groups[1] === 0xff9b && groups.slice(2, 6).every((group) => group === 0)) {
+    return embedded(groups[6], groups[7]);
+  }
+  if (first === 0x2002) {
+    return embedded(groups[1], groups[2]);
+  }
+  return true;
+}
+
+function parseIpv4(address: string): number | null {
+  const parts = address.split('.');
+  if (parts.length !== 4 || !parts.every((part) => /^\d{1,3}$/.test(part) && +part <= 255)) {
+    return null;
+  }
+  return parts.reduce((value, part) => value * 256 + Number(part), 0);
+}
+
+// The eight 16-bit groups; accepts brackets, "::", a trailing dotted IPv4 part and a zone ID
+function parseIpv6(address: string): number[] | null {
+  const halves = address
+    .replace(/^\[(.*)\]$/, '$1')
+    .replace(/%.*$/, '')
+    .split('::');
+  if (halves.length > 2) {
+    return null;
+  }
+
+  const parts = halves.map((half) => (half === '' ? [] : half.split(':')));
+  const last = parts[parts.length - 1];
+  const ipv4 = last.length > 0 ? parseIpv4(last[last.length - 1]) : null;
+  if (ipv4 !== null) {
+    last.splice(-1, 1, (ipv4 >>> 16).toString(16), (ipv4 & 0xffff).toString(16));
+  }
+  if (!parts.flat().every((part) => /^[0-9a-f]{1,4}$/i.test(part))) {
+    return null;
+  }
+
+  const [head, tail = []] = parts.map((half) => half.map((part) => parseInt(part, 16)));
+  const missing = 8 - head.length - tail.length;
+  if (halves.length === 1 ? missing !== 0 : missing < 1) {
+    return null;
+  }
+  return [...head, ...new Array<number>(missing).fill(0), ...tail];
+}
@@ -1,7 +1,59 @@
 import { z } from 'zod';
+import { DEFAULT_DAILY_QUOTA } from '../../domain/entity/ApiKey';
+import { CustomFormNameSchema } from '../../domain/entity/CustomForm';
+import {
+  BattleResultSchema,
+  MAX_BATTLE_NOTES_LENGTH,
+  MAX_OPPONENT_POKEMON,
+  OpponentNameSchema,
+} from '../../domain/entity/BattleRecord';
+import { BenchmarkRoleSchema } from '../../domain/entity/DamageBenchmark';
+import {
+  MAX_DRAFT_PARTICIPANTS,
+  MAX_DRAFT_POOL_SIZE,
+  MAX_DRAFT_ROSTER_SIZE,
+  MIN_DRAFT_PARTICIPANTS,
+} from '../../domain/entity/DraftLeague';
+import { DamageClassSchema } from '../../domain/entity/Move';
+import { TeamFlagReasonSchema } from '../../domain/entity/TeamFlag';
+import {
+  MAX_TOURNAMENT_ENTRIES,
+  MIN_TOURNAMENT_ENTRIES,
+  TournamentStatusSchema,
+} from '../../domain/entity/Tournament';
+import { ThemeSchema } from '../../domain/entity/UserPreferences';
+import { TeamEventTypeSchema } from '../../domain/event/TeamEvent';
+import { ArchetypeSchema } from '../../domain/service/ArchetypeClassifier';
+import { WeatherSchema } from '../../domain/service/TurnOrder';
+import { ApiKeyScopeSchema } from '../../domain/valueobject/ApiKeyScope';
+import { regionalDexFromName, RegionalDexSchema } from '../../domain/valueobject/DexNumber';
+import { MediaAssetSchema } from '../../domain/valueobject/FormMedia';
+import { BattleStyleSchema, FormatIdSchema } from '../../domain/valueobject/Format';
+import { FriendshipSchema } from '../../domain/valueobject/Friendship';
+import { GameVersionSchema } from '../../domain/valueobject/GameVersion';
+import { CURRENT_GENERATION, GenerationSchema } from '../../domain/valueobject/Generation';
+import { CalculationModeSchema, LevelSchema } from '../../domain/valueobject/Level';
+import { NatureSchema } from '../../domain/valueobject/Nature';
+import { FormIdSchema, SpeciesIdSchema } from '../../domain/valueobject/PokemonId';
+import { PokemonTypeSchema } from '../../domain/valueobject/PokemonType';
+import { MAX_MEMBER_NOTES_LENGTH, MAX_TEAM_NOTES_LENGTH } from '../../domain/valueobject/TeamNote';
+import { MAX_TEAM_TAGS } from '../../domain/valueobject/TeamTag';
+import { LangSchema } from '../../i18n';
+import { isPublicWebhookUrl } from '../WebhookTarget';
 
 // Common schemas
 export const UuidSchema = z.string().uuid();
+// z.coerce.boolean() would read "false" as true
+export const QueryFlagSchema = z.enum(['true', 'false']).transform((value) => value === 'true');
+const EncounterLocationSchema = z
+  .string()
+  .regex(/^[a-z0-9-]+$/, 'Location must be a PokeAPI location-area identifier')
+  .max(100);
+export const SpeciesIdParamSchema = z.coerce.number().pipe(SpeciesIdSchema);
+export const FormIdParamSchema = z.coerce.number().pipe(FormIdSchema);
+export const MediaAssetParamSchema = MediaAssetSchema;
+// Opaque; checked by PageCursorCodec
+const PageCursorSchema = z.string().min(1).max(500);
 
 // Auth DTOs
 export const RegisterRequestSchema = z.object({
@@ -15,6 +67,39 @@ export const LoginRequestSchema = z.object({
   password: z.string().min(1),
 });
 
+// `form` names the route group the CSRF token is for
+export const CsrfTokenQuerySchema = z.object({
+  form: z
+    .string()
+    .regex(/^[a-z][a-z-]*$/)
+    .max(50)
+    .default('auth'),
+});
+
+export const EmailChangeRequestSchema = z.object({
+  new_email: z.string().email().max(254),
+  password: z.string().min(1),
+});
+
+// The token from the link in a confirmation or cancellation mail
+export const EmailChangeTokenSchema = z.object({
+  token: z.string().min(1).max(100),
+});
+
+// 'anonymize' keeps shared teams and comments, credited to a deleted user
+export const DeleteAccountRequestSchema = z.object({
+  password: z.string().min(1),
+  content: z.enum(['delete', 'anonymize']).default('delete'),
+});
+
+// Fields left out keep their value; null clears language and format
+export const UpdatePreferencesRequestSchema = z.object({
+  language: LangSchema.nullable().optional(),
+  format: FormatIdSchema.nullable().optional(),
+  calculation_mode: CalculationModeSchema.optional(),
+  theme: ThemeSchema.optional(),
+});
+
 // Pokemon DTOs
 export const CreatePokemonRequestSchema = z.object({
   pokemon_name: z.string(),
@@ -39,6 +124,817 @@ export const CreatePokemonRequestSchema = z.object({
   moves: z.array(z.string()).min(1).max(4),
 });
 
+// Breeding DTOs
+export const BreedingCompatibilityQuerySchema = z.object({
+  species_a: SpeciesIdParamSchema,
+  species_b: SpeciesIdParamSchema,
+});
+
+export const EggMoveParentsQuerySchema = z.object({
+  move: z.string().min(1),
+});
+
+// Pokedex DTOs
+export const ListFormsQuerySchema = z
+  .object({
+    available_in: GameVersionSchema.optional(),
+    max_generation: z.coerce.number().int().min(1).max(9).optional(),
+    encounter_game: GameVersionSchema.optional(),
+    location: EncounterLocationSchema.optional(),
+    first_stage: QueryFlagSchema.optional(),
+    // Every matching form when omitted
+    limit: z.coerce.number().int().min(1).max(500).optional(),
+    // next_cursor of the previous page
+    cursor: PageCursorSchema.optional(),
+  })
+  .refine((value) => value.cursor === undefined || value.limit !== undefined, {
+    message: 'cursor requires limit',
+    path: ['cursor'],
+  });
+
+export const ExportFormsQuerySchema = z.object({
+  generation: z.coerce.number().int().min(1).max(9).optional(),
+  available_in: GameVersionSchema.optional(),
+});
+
+// PokeAPI dex names ("original-johto") or the short ones players use ("johto"); the number's
+// range is checked by RegionalDexNumber
+export const DexNumberParamsSchema = z.object({
+  dex: z
+    .string()
+    .transform((value) => regionalDexFromName(value) ?? value)
+    .pipe(RegionalDexSchema),
+  number: z.coerce.number().int(),
+});
+
+export const EncounterLocationsQuerySchema = z.object({
+  game: GameVersionSchema,
+});
+
+// One or two comma-separated defending types, e.g. ?attack=Electric&defend=Water,Flying
+export const TypeEffectivenessQuerySchema = z.object({
+  attack: PokemonTypeSchema,
+  defend: z
+    .string()
+    .transform((value) => value.split(',').map((type) => type.trim()))
+    .pipe(z.array(PokemonTypeSchema).min(1).max(2))
+    .refine((types) => new Set(types).size === types.length, {
+      message: 'type1 and type2 cannot be the same',
+      params: { code: 'DUPLICATE_TYPES' },
+    }),
+});
+
+// Pairs or trios that resist every attacking type, from the whole dex or ?form_ids=445,6,...
+export const DefensiveCoreQuerySchema = z.object({
+  size: z
+    .enum(['2', '3'])
+    .default('3')
+    .transform((value) => (value === '2' ? 2 : 3)),
+  form_ids: z
+    .string()
+    .transform((value) => value.split(',').map((id) => id.trim()))
+    .pipe(z.array(FormIdParamSchema).min(2).max(50))
+    .refine((formIds) => new Set(formIds).size === formIds.length, {
+      message: 'Form IDs must not repeat',
+      params: { code: 'DUPLICATE_FORM_ID' },
+    })
+    .optional(),
+  available_in: GameVersionSchema.optional(),
+  limit: z.coerce.number().int().min(1).max(50).default(10),
+});
+
+// The attacking types already chosen, e.g. ?attack=Fire,Ice,Ground for the fourth move
+export const CoverageQuerySchema = z.object({
+  attack: z
+    .string()
+    .transform((value) => value.split(',').map((type) => type.trim()))
+    .pipe(z.array(PokemonTypeSchema).min(1).max(3))
+    .refine((types) => new Set(types).size === types.length, {
+      message: 'Types must not repeat',
+      params: { code: 'DUPLICATE_TYPES' },
+    }),
+  // The metagame to cover; the whole dex without it
+  format: FormatIdSchema.optional(),
+  available_in: GameVersionSchema.optional(),
+  limit: z.coerce.number().int().min(1).max(15).default(3),
+});
+
+// Comma-separated form IDs, e.g. ?form_ids=445,6
+export const CompareFormsQuerySchema = z.object({
+  form_ids: z
+    .string()
+    .transform((value) => value.split(',').map((id) => id.trim()))
+    .pipe(z.array(FormIdParamSchema).min(2).max(6))
+    .refine((formIds) => new Set(formIds).size === formIds.length, {
+      message: 'Form IDs must not repeat',
+      params: { code: 'DUPLICATE_FORM_ID' },
+    }),
+  format: FormatIdSchema.optional(),
+});
+
+// Form IDs, names and national dex numbers ("#445") may be mixed; duplicates are answered twice
+export const BatchFormsRequestSchema = z.object({
+  pokemon: z
+    .array(z.union([FormIdSchema, z.string().trim().min(1).max(100)]))
+    .min(1)
+    .max(50),
+});
+
+// Move search DTOs
+// `effect` is comma-separated keywords that must all appear, e.g. ?effect=switch,out
+export const MoveSearchQuerySchema = z
+  .object({
+    name: z.string().trim().min(1).max(100).optional(),
+    type: PokemonTypeSchema.optional(),
+    damage_class: DamageClassSchema.optional(),
+    min_power: z.coerce.number().int().min(0).max(250).optional(),
+    max_power: z.coerce.number().int().min(0).max(250).optional(),
+    priority: z.coerce.number().int().min(-7).max(5).optional(),
+    effect: z
+      .string()
+      .transform((value) =>
+        value
+          .split(',')
+          .map((keyword) => keyword.trim())
+          .filter((keyword) => keyword !== '')
+      )
+      .pipe(z.array(z.string().max(50)).max(5))
+      .optional(),
+    learnable_by: SpeciesIdParamSchema.optional(),
+    limit: z.coerce.number().int().min(1).max(100).default(20),
+    offset: z.coerce.number().int().min(0).default(0),
+  })
+  .refine(
+    (value) =>
+      value.min_power === undefined ||
+      value.max_power === undefined ||
+      value.min_power <= value.max_power,
+    { message: 'max_power must not be less than min_power', path: ['max_power'] }
+  );
+
+// Ability and item DTOs
+// Names are taken in any spelling: "Sand Rush", "sandrush", "sand-rush"
+export const DexNameParamSchema = z.string().trim().min(1).max(100);
+
+export const AbilitySearchQuerySchema = z.object({
+  name: z.string().trim().min(1).max(100).optional(),
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+export const ItemSearchQuerySchema = z.object({
+  name: z.string().trim().min(1).max(100).optional(),
+  category: z.string().trim().min(1).max(50).optional(),
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+// Full-text search DTOs
+// `kind` is a comma-separated list, e.g. ?kind=move,ability; every kind when omitted
+export const FullTextSearchQuerySchema = z.object({
+  q: z.string().trim().min(1).max(200),
+  kind: z
+    .string()
+    .transform((value) => value.split(',').map((kind) => kind.trim()))
+    .pipe(z.array(z.enum(['species', 'move', 'ability'])).max(3))
+    .optional(),
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+// Team DTOs
+export const ValidateTeamRequestSchema = z.object({
+  form_ids: z.array(FormIdSchema).min(1).max(6),
+  game_version: GameVersionSchema,
+});
+
+function buildStatsSchema(max: number, fallback: number) {
+  const stat = z.number().int().min(0).max(max).default(fallback);
+  return z.object({
+    hp: stat,
+    attack: stat,
+    defense: stat,
+    special_attack: stat,
+    special_defense: stat,
+    speed: stat,
+  });
+}
+
+export const PokemonBuildSchema = z.object({
+  form_id: FormIdSchema,
+  nickname: z.string().optional(), // validated by the Nickname value object
+  ability: z.string().min(1).optional(),
+  allow_illegal_ability: z.boolean().optional(),
+  held_item: z.string().min(1).optional(),
+  moves: z.array(z.string().min(1)).max(4).default([]),
+  tera_type: PokemonTypeSchema.optional(),
+  nature: NatureSchema.optional(),
+  evs: buildStatsSchema(252, 0).optional(),
+  ivs: buildStatsSchema(31, 31).optional(),
+  friendship: FriendshipSchema.optional(),
+});
+
+export const ValidateTeamForFormatRequestSchema = z.object({
+  format: FormatIdSchema,
+  members: z.array(PokemonBuildSchema).min(1).max(6),
+});
+
+export const ThreatAnalysisRequestSchema = z.object({
+  format: FormatIdSchema,
+  members: z.array(PokemonBuildSchema).min(1).max(6),
+  limit: z.number().int().min(1).max(50).default(20),
+  battle_style: BattleStyleSchema.optional(),
+});
+
+export const TeamArchetypeRequestSchema = z.object({
+  members: z.array(PokemonBuildSchema).min(1).max(6),
+  // An explicit level wins over the mode
+  level: LevelSchema.optional(),
+  mode: CalculationModeSchema.default('level50'),
+});
+
+export const TeamReportRequestSchema = z.object({
+  format: FormatIdSchema,
+  members: z.array(PokemonBuildSchema).min(1).max(6),
+  limit: z.number().int().min(1).max(50).default(20),
+  // Count ability-granted immunities and resistances in the defensive coverage table
+  include_abilities: z.boolean().default(false),
+});
+
+export const RandomTeamRequestSchema = z.object({
+  format: FormatIdSchema,
+  size: z.number().int().min(1).max(6).default(6),
+  generations: z.array(z.number().int().min(1).max(9)).min(1).optional(),
+  required_types: z.array(PokemonTypeSchema).max(6).optional(),
+  min_base_stat_total: z.number().int().min(0).max(1530).optional(),
+  // Any unsigned 32-bit integer; a random one is chosen (and returned) when omitted
+  seed: z.number().int().min(0).max(2 ** 32 - 1).optional(),
+});
+
+export const TeamReportQuerySchema = z.object({
+  output: z.enum(['markdown', 'html']).default('markdown'),
+});
+
+export const TeamCodeRequestSchema = z.object({
+  members: z.array(PokemonBuildSchema).min(1).max(6),
+});
+
+// Base32 short code or a "POKEDICT:<code>" QR payload
+export const TeamCodeParamSchema = z.string().min(1).max(200);
+
+export const TeamIdParamSchema = UuidSchema;
+
+export const TeamCommentIdParamSchema = UuidSchema;
+
+// Team slots are 0-5
+const TeamSlotSchema = z.number().int().min(0).max(5);
+export const TeamSlotParamSchema = z.coerce.number().pipe(TeamSlotSchema);
+
+export const CopyTeamMemberRequestSchema = z.object({
+  target_team_id: UuidSchema,
+  // Omit to fill the first empty slot
+  target_slot: TeamSlotSchema.optional(),
+});
+
+// Tags and folder names are validated by the team; these only bound the request size
+const TeamTagListSchema = z.array(z.string().max(100)).max(MAX_TEAM_TAGS);
+const TeamFolderSchema = z.string().max(100);
+
+export const TeamListQuerySchema = z.object({
+  tag: z.string().min(1).max(100).optional(),
+  folder: z.string().min(1).max(100).optional(),
+  // Teams with any form of the species
+  species_id: SpeciesIdParamSchema.optional(),
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  after: UuidSchema.optional(),
+});
+
+export const GalleryQuerySchema = z.object({
+  species_id: SpeciesIdParamSchema.optional(),
+  format: FormatIdSchema.optional(),
+  sort: z.enum(['recent', 'likes']).default('recent'),
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  // next_cursor of the previous page
+  cursor: PageCursorSchema.optional(),
+});
+
+export const ShareTeamRequestSchema = z.object({
+  // The format the team is listed under in the gallery
+  format: FormatIdSchema.nullable().default(null),
+});
+
+export const FlagTeamRequestSchema = z.object({
+  reason: TeamFlagReasonSchema,
+});
+
+export const CommentThreadsQuerySchema = z.object({
+  limit: z.coerce.number().int().min(1).max(50).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+// Lengths are checked by the comment itself, after trimming
+export const CreateCommentRequestSchema = z.object({
+  body: z.string(),
+  // Replies to a reply join the thread it belongs to
+  parent_id: UuidSchema.optional(),
+});
+
+export const EditCommentRequestSchema = z.object({
+  body: z.string(),
+});
+
+export const FlaggedTeamsQuerySchema = z.object({
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+export const SetTeamTagsRequestSchema = z.object({
+  tags: TeamTagListSchema,
+});
+
+export const MoveTeamToFolderRequestSchema = z.object({
+  // null takes the team out of its folder
+  folder: TeamFolderSchema.nullable(),
+});
+
+// null removes the notes
+export const SetTeamNotesRequestSchema = z.object({
+  notes: z.string().max(MAX_TEAM_NOTES_LENGTH).nullable(),
+});
+
+export const SetMemberNotesRequestSchema = z.object({
+  notes: z.string().max(MAX_MEMBER_NOTES_LENGTH).nullable(),
+});
+
+export const OrganizeTeamsRequestSchema = z.object({
+  team_ids: z.array(UuidSchema).min(1).max(100),
+  add_tags: TeamTagListSchema.default([]),
+  remove_tags: TeamTagListSchema.default([]),
+  // Omit to leave the teams in their folders; null takes them out
+  folder: TeamFolderSchema.nullable().optional(),
+});
+
+// Damage calculation DTOs
+const StageSchema = z.number().int().min(-6).max(6).default(0);
+
+export const StatStagesSchema = z.object({
+  attack: StageSchema,
+  defense: StageSchema,
+  special_attack: StageSchema,
+  special_defense: StageSchema,
+  speed: StageSchema,
+});
+
+export const DamageCalculationSchema = z.object({
+  attacker: PokemonBuildSchema,
+  defender: PokemonBuildSchema,
+  move: z.string().min(1),
+  critical: z.boolean().default(false),
+  attacker_terastallized: z.boolean().default(false),
+  defender_terastallized: z.boolean().default(false),
+  attacker_stages: StatStagesSchema.optional(),
+  defender_stages: StatStagesSchema.optional(),
+});
+
+export const BatchDamageRequestSchema = z.object({
+  level: LevelSchema.optional(),
+  mode: CalculationModeSchema.default('level50'),
+  doubles: z.boolean().default(false),
+  // 1 and 2 switch to the stats and damage formula of those games
+  generation: GenerationSchema.default(CURRENT_GENERATION),
+  calculations: z.array(DamageCalculationSchema).min(1).max(1000),
+});
+
+// Turn order DTOs
+export const TurnOrderSideSchema = z.object({
+  pokemon: PokemonBuildSchema,
+  move: z.string().min(1),
+  stages: StatStagesSchema.optional(),
+  tailwind: z.boolean().default(false),
+  paralyzed: z.boolean().default(false),
+});
+
+export const TurnOrderRequestSchema = z.object({
+  level: LevelSchema.optional(),
+  mode: CalculationModeSchema.default('level50'),
+  trick_room: z.boolean().default(false),
+  weather: WeatherSchema.optional(),
+  sides: z.tuple([TurnOrderSideSchema, TurnOrderSideSchema]),
+});
+
+// Battle simulation DTOs
+export const BattleSimulationRequestSchema = z.object({
+  level: LevelSchema.optional(),
+  mode: CalculationModeSchema.default('level50'),
+  trick_room: z.boolean().default(false),
+  weather: WeatherSchema.optional(),
+  simulations: z.number().int().min(1).max(1000).default(100),
+  // Any unsigned 32-bit integer; a random one is chosen (and returned) when omitted
+  seed: z.number().int().min(0).max(2 ** 32 - 1).optional(),
+  sides: z.tuple([PokemonBuildSchema, PokemonBuildSchema]),
+});
+
+const SimulatedTeamSchema = z.array(PokemonBuildSchema).min(1).max(6);
+
+export const TeamMatchupRequestSchema = z.object({
+  level: LevelSchema.optional(),
+  mode: CalculationModeSchema.default('level50'),
+  trick_room: z.boolean().default(false),
+  weather: WeatherSchema.optional(),
+  games: z.number().int().min(1).max(1000).default(200),
+  seed: z.number().int().min(0).max(2 ** 32 - 1).optional(),
+  teams: z.tuple([SimulatedTeamSchema, SimulatedTeamSchema]),
+});
+
+// Damage benchmark DTOs
+// A benchmark's own moves are never used; offensive benchmarks attack with `move`
+export const CreateBenchmarkRequestSchema = z
+  .object({
+    format: FormatIdSchema,
+    name: z.string().min(1).max(50),
+    role: BenchmarkRoleSchema,
+    build: PokemonBuildSchema,
+    move: z.string().min(1).optional(),
+  })
+  .refine((value) => value.role !== 'offensive' || value.move !== undefined, {
+    message: 'Offensive benchmarks need a move',
+    path: ['move'],
+  });
+
+export const BenchmarkListQuerySchema = z.object({
+  format: FormatIdSchema,
+});
+
+// Level and singles/doubles come from the format
+export const BenchmarkRunRequestSchema = z.object({
+  format: FormatIdSchema,
+  build: PokemonBuildSchema,
+  // Defaults to the build's moves
+  moves: z.array(z.string().min(1)).min(1).max(4).optional(),
+  terastallized: z.boolean().default(false),
+  critical: z.boolean().default(false),
+});
+
+// Usage stats DTOs
+const UsageWeightsSchema = z.record(z.number());
+
+// Smogon "chaos" JSON (https://www.smogon.com/stats/<period>/chaos/<metagame>-<cutoff>.json)
+export const SmogonChaosSchema = z.object({
+  info: z.object({
+    metagame: z.string(),
+    cutoff: z.number(),
+    'number of battles': z.number().int(),
+  }),
+  data: z.record(
+    z.object({
+      'Raw count': z.number().int(),
+      usage: z.number(),
+      Abilities: UsageWeightsSchema,
+      Items: UsageWeightsSchema,
+      Moves: UsageWeightsSchema,
+      Spreads: UsageWeightsSchema,
+      'Tera Types': UsageWeightsSchema.optional(),
+    })
+  ),
+});
+
+export const UsagePeriodSchema = z.string().regex(/^\d{4}-\d{2}$/, 'Period must be YYYY-MM');
+
+export const ImportUsageQuerySchema = z.object({
+  period: UsagePeriodSchema,
+});
+
+export const UsageQuerySchema = z.object({
+  limit: z.coerce.number().int().min(1).max(500).default(50),
+  period: UsagePeriodSchema.optional(),
+});
+
+// Backup DTOs
+export const TEAM_BACKUP_VERSION = 1;
+// Restoring is a single request, so the archive size is capped
+export const MAX_BACKUP_TEAMS = 1000;
+
+const BackupTeamMemberSchema = z.object({
+  form_id: FormIdSchema,
+  terastal_type: PokemonTypeSchema,
+  notes: z.string().max(MAX_MEMBER_NOTES_LENGTH).nullable().optional(),
+});
+
+export const TeamBackupSchema = z.object({
+  version: z.literal(TEAM_BACKUP_VERSION),
+  teams: z
+    .array(
+      z.object({
+        team_id: UuidSchema,
+        team_name: z.string().min(1).max(50),
+        pokemon: z.array(BackupTeamMemberSchema.nullable()).length(6),
+        // Archives exported before tags, folders and notes have none of them
+        tags: TeamTagListSchema.optional(),
+        folder: TeamFolderSchema.nullable().optional(),
+        notes: z.string().max(MAX_TEAM_NOTES_LENGTH).nullable().optional(),
+      })
+    )
+    .max(MAX_BACKUP_TEAMS),
+});
+
+// Challenge run DTOs
+export const CreateRunRequestSchema = z.object({
+  name: z.string().min(1).max(50),
+  game_version: GameVersionSchema,
+});
+
+export const RunDexQuerySchema = z.object({
+  location: EncounterLocationSchema.optional(),
+  first_stage: QueryFlagSchema.optional(),
+});
+
+// Draft league DTOs
+const DraftPointsSchema = z.number().int().min(0).max(10000);
+
+export const CreateDraftLeagueRequestSchema = z.object({
+  name: z.string().min(1).max(50),
+  format: FormatIdSchema,
+  // Usernames in draft order
+  participants: z
+    .array(z.string().min(1).max(20))
+    .min(MIN_DRAFT_PARTICIPANTS)
+    .max(MAX_DRAFT_PARTICIPANTS)
+    .refine((usernames) => new Set(usernames).size === usernames.length, {
+      message: 'Participants must not repeat',
+      params: { code: 'DUPLICATE_PARTICIPANT' },
+    }),
+  budget: DraftPointsSchema,
+  roster_size: z.number().int().min(1).max(MAX_DRAFT_ROSTER_SIZE),
+  pool: z
+    .array(z.object({ form_id: FormIdSchema, cost: DraftPointsSchema }))
+    .min(1)
+    .max(MAX_DRAFT_POOL_SIZE)
+    .refine((pool) => new Set(pool.map((entry) => entry.form_id)).size === pool.length, {
+      message: 'Form IDs must not repeat',
+      params: { code: 'DUPLICATE_FORM_ID' },
+    }),
+});
+
+export const DraftPickRequestSchema = z.object({
+  form_id: FormIdSchema,
+});
+
+export const DraftTeamValidationRequestSchema = z.object({
+  team_id: UuidSchema,
+});
+
+// Tournament DTOs
+export const CreateTournamentRequestSchema = z.object({
+  name: z.string().min(1).max(50),
+  format: FormatIdSchema,
+  max_entries: z.number().int().min(MIN_TOURNAMENT_ENTRIES).max(MAX_TOURNAMENT_ENTRIES),
+});
+
+export const TournamentListQuerySchema = z.object({
+  status: TournamentStatusSchema.optional(),
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+export const TournamentEntryRequestSchema = z.object({
+  team_id: UuidSchema,
+});
+
+export const TournamentMatchParamsSchema = z.object({
+  round: z.coerce.number().int().min(1),
+  position: z.coerce.number().int().min(0),
+});
+
+export const TournamentResultRequestSchema = z.object({
+  winner_id: UuidSchema,
+});
+
+// Battle record DTOs
+export const BattleRecordRequestSchema = z.object({
+  team_id: UuidSchema,
+  format: FormatIdSchema.nullable().optional(),
+  result: BattleResultSchema,
+  // The opponent's archetype, their Pokémon as far as they were seen, or both
+  opponent: z
+    .object({
+      name: OpponentNameSchema.nullable().optional(),
+      archetype: ArchetypeSchema.nullable().optional(),
+      form_ids: z
+        .array(FormIdSchema)
+        .max(MAX_OPPONENT_POKEMON)
+        .refine((formIds) => new Set(formIds).size === formIds.length, {
+          message: 'Form IDs must not repeat',
+          params: { code: 'DUPLICATE_FORM_ID' },
+        })
+        .default([]),
+    })
+    .refine((opponent) => opponent.archetype || opponent.form_ids.length > 0, {
+      message: 'Give the opponent an archetype or at least one Pokémon',
+      params: { code: 'OPPONENT_REQUIRED' },
+    }),
+  notes: z.string().max(MAX_BATTLE_NOTES_LENGTH).nullable().optional(),
+  // When the battle was played; now if left out
+  played_at: z
+    .string()
+    .datetime({ offset: true })
+    .transform((value) => new Date(value))
+    .refine((date) => date.getTime() <= Date.now(), {
+      message: 'played_at must not be in the future',
+    })
+    .optional(),
+});
+
+export const BattleRecordListQuerySchema = z.object({
+  team_id: UuidSchema.optional(),
+  result: BattleResultSchema.optional(),
+  archetype: ArchetypeSchema.optional(),
+  // Battles where the opponent had this Pokémon
+  form_id: FormIdParamSchema.optional(),
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+export const MatchupStatsQuerySchema = z.object({
+  team_id: UuidSchema.optional(),
+});
+
+// Webhook DTOs
+// Payloads carry team data, so they are only sent over HTTPS. Hosts on the server's own network
+// are rejected here by name or address, and by what they resolve to in WebhookService.
+export const CreateWebhookRequestSchema = z.object({
+  url: z
+    .string()
+    .url()
+    .max(2000)
+    .refine((url) => url.startsWith('https://'), { message: 'Webhook URLs must use HTTPS' })
+    .refine(isPublicWebhookUrl, {
+      message: 'Webhook URLs must point at a public host',
+      params: { code: 'PRIVATE_HOST' },
+    }),
+  events: z.array(TeamEventTypeSchema).min(1),
+});
+
+export const WebhookDeliveriesQuerySchema = z.object({
+  limit: z.coerce.number().int().min(1).max(100).default(50),
+});
+
+// Type quiz DTOs
+export const TypeQuizAnswerRequestSchema = z.object({
+  question_id: z.number().int().min(0).max(2 ** 32 - 1),
+  answer: z.number().min(0).max(4),
+});
+
+// Admin DTOs
+export const AdminSyncRequestSchema = z
+  .object({
+    kind: z.enum(['species', 'moves', 'abilities', 'items']),
+    from: z.number().int().positive(),
+    to: z.number().int().positive().optional(),
+    // Report the changes instead of applying them (species only)
+    dry_run: z.boolean().default(false),
+  })
+  .refine((value) => value.to === undefined || value.to >= value.from, {
+    message: 'to must not be less than from',
+    path: ['to'],
+  });
+
+export const AdminRollbackRequestSchema = z.object({
+  version: z.number().int().min(0),
+});
+
+export const AdminImportsQuerySchema = z.object({
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+});
+
+export const NotificationsQuerySchema = z.object({
+  unread_only: QueryFlagSchema.optional(),
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+export const MarkNotificationsReadRequestSchema = z.object({
+  notification_ids: z.array(UuidSchema).min(1).max(100),
+});
+
+export const ModerationQueueQuerySchema = z.object({
+  limit: z.coerce.number().int().min(1).max(100).default(20),
+  offset: z.coerce.number().int().min(0).default(0),
+});
+
+const BaseStatSchema = z.number().int().min(1).max(255).optional();
+
+// Every field is optional; omitted fields keep their current value and null clears type2
+export const AdminFormUpdateSchema = z
+  .object({
+    name: z.string().min(1).max(100).optional(),
+    name_jp: z.string().min(1).max(100).optional(),
+    type1: PokemonTypeSchema.optional(),
+    type2: PokemonTypeSchema.nullable().optional(),
+    base_stats: z
+      .object({
+        hp: BaseStatSchema,
+        attack: BaseStatSchema,
+        defense: BaseStatSchema,
+        special_attack: BaseStatSchema,
+        special_defense: BaseStatSchema,
+        speed: BaseStatSchema,
+      })
+      .optional(),
+    base_experience: z.number().int().min(0).nullable().optional(),
+    height_m: z.number().min(0).optional(),
+    weight_kg: z.number().min(0).optional(),
+    shiny_available: z.boolean().optional(),
+    shiny_locked: z.boolean().optional(),
+  })
+  .refine((value) => Object.keys(value).length > 0, { message: 'No changes given' });
+
+export const ApiKeyIssueRequestSchema = z.object({
+  name: z.string().trim().min(1).max(50),
+  scopes: z.array(ApiKeyScopeSchema).min(1),
+  daily_quota: z.number().int().min(1).max(1000000).default(DEFAULT_DAILY_QUOTA),
+});
+
+// Custom form DTOs
+const CustomStatSchema = z.number().int().min(1).max(255);
+
+// Replaces the whole form on update, like on create
+export const CustomFormRequestSchema = z.object({
+  name: CustomFormNameSchema,
+  types: z
+    .array(PokemonTypeSchema)
+    .min(1)
+    .max(2)
+    .refine((types) => new Set(types).size === types.length, {
+      message: 'type1 and type2 cannot be the same',
+      params: { code: 'DUPLICATE_TYPES' },
+    }),
+  base_stats: z.object({
+    hp: CustomStatSchema,
+    attack: CustomStatSchema,
+    defense: CustomStatSchema,
+    special_attack: CustomStatSchema,
+    special_defense: CustomStatSchema,
+    speed: CustomStatSchema,
+  }),
+  abilities: z.object({
+    primary: z.string().trim().min(1).max(50),
+    secondary: z.string().trim().min(1).max(50).optional(),
+    hidden: z.string().trim().min(1).max(50).optional(),
+  }),
+});
+
 export type RegisterRequestDto = z.infer<typeof RegisterRequestSchema>;
 export type LoginRequestDto = z.infer<typeof LoginRequestSchema>;
+export type EmailChangeRequestDto = z.infer<typeof EmailChangeRequestSchema>;
+export type DeleteAccountRequestDto = z.infer<typeof DeleteAccountRequestSchema>;
+export type UpdatePreferencesRequestDto = z.infer<typeof UpdatePreferencesRequestSchema>;
 export type CreatePokemonRequestDto = z.infer<typeof CreatePokemonRequestSchema>;
+export type BreedingCompatibilityQueryDto = z.infer<typeof BreedingCompatibilityQuerySchema>;
+export type EggMoveParentsQueryDto = z.infer<typeof EggMoveParentsQuerySchema>;
+export type ListFormsQueryDto = z.infer<typeof ListFormsQuerySchema>;
+export type ExportFormsQueryDto = z.infer<typeof ExportFormsQuerySchema>;
+export type MoveSearchQueryDto = z.infer<typeof MoveSearchQuerySchema>;
+export type AbilitySearchQueryDto = z.infer<typeof AbilitySearchQuerySchema>;
+export type ItemSearchQueryDto = z.infer<typeof ItemSearchQuerySchema>;
+export type FullTextSearchQueryDto = z.infer<typeof FullTextSearchQuerySchema>;
+export type CompareFormsQueryDto = z.infer<typeof CompareFormsQuerySchema>;
+export type BatchFormsRequestDto = z.infer<typeof BatchFormsRequestSchema>;
+export type ValidateTeamRequestDto = z.infer<typeof ValidateTeamRequestSchema>;
+export type PokemonBuildDto = z.infer<typeof PokemonBuildSchema>;
+export type ValidateTeamForFormatRequestDto = z.infer<typeof ValidateTeamForFormatRequestSchema>;
+export type ThreatAnalysisRequestDto = z.infer<typeof ThreatAnalysisRequestSchema>;
+export type TeamArchetypeRequestDto = z.infer<typeof TeamArchetypeRequestSchema>;
+export type RandomTeamRequestDto = z.infer<typeof RandomTeamRequestSchema>;
+export type TeamReportRequestDto = z.infer<typeof TeamReportRequestSchema>;
+export type TeamCodeRequestDto = z.infer<typeof TeamCodeRequestSchema>;
+export type CopyTeamMemberRequestDto = z.infer<typeof CopyTeamMemberRequestSchema>;
+export type TeamListQueryDto = z.infer<typeof TeamListQuerySchema>;
+export type GalleryQueryDto = z.infer<typeof GalleryQuerySchema>;
+export type ShareTeamRequestDto = z.infer<typeof ShareTeamRequestSchema>;
+export type FlagTeamRequestDto = z.infer<typeof FlagTeamRequestSchema>;
+export type CreateCommentRequestDto = z.infer<typeof CreateCommentRequestSchema>;
+export type EditCommentRequestDto = z.infer<typeof EditCommentRequestSchema>;
+export type SetTeamTagsRequestDto = z.infer<typeof SetTeamTagsRequestSchema>;
+export type MoveTeamToFolderRequestDto = z.infer<typeof MoveTeamToFolderRequestSchema>;
+export type SetTeamNotesRequestDto = z.infer<typeof SetTeamNotesRequestSchema>;
+export type SetMemberNotesRequestDto = z.infer<typeof SetMemberNotesRequestSchema>;
+export type OrganizeTeamsRequestDto = z.infer<typeof OrganizeTeamsRequestSchema>;
+export type StatStagesDto = z.infer<typeof StatStagesSchema>;
+export type TurnOrderSideDto = z.infer<typeof TurnOrderSideSchema>;
+export type DamageCalculationDto = z.infer<typeof DamageCalculationSchema>;
+export type BatchDamageRequestDto = z.infer<typeof BatchDamageRequestSchema>;
+export type CreateBenchmarkRequestDto = z.infer<typeof CreateBenchmarkRequestSchema>;
+export type BenchmarkRunRequestDto = z.infer<typeof BenchmarkRunRequestSchema>;
+export type SmogonChaosDto = z.infer<typeof SmogonChaosSchema>;
+export type UsageQueryDto = z.infer<typeof UsageQuerySchema>;
+export type TeamBackupDto = z.infer<typeof TeamBackupSchema>;
+export type CreateRunRequestDto = z.infer<typeof CreateRunRequestSchema>;
+export type CreateDraftLeagueRequestDto = z.infer<typeof CreateDraftLeagueRequestSchema>;
+export type CreateTournamentRequestDto = z.infer<typeof CreateTournamentRequestSchema>;
+export type CreateWebhookRequestDto = z.infer<typeof CreateWebhookRequestSchema>;
+export type TypeQuizAnswerRequestDto = z.infer<typeof TypeQuizAnswerRequestSchema>;
+export type AdminSyncRequestDto = z.infer<typeof AdminSyncRequestSchema>;
+export type AdminRollbackRequestDto = z.infer<typeof AdminRollbackRequestSchema>;
+export type AdminFormUpdateDto = z.infer<typeof AdminFormUpdateSchema>;
+export type ApiKeyIssueRequestDto = z.infer<typeof ApiKeyIssueRequestSchema>;
+export type CustomFormRequestDto = z.infer<typeof CustomFormRequestSchema>;
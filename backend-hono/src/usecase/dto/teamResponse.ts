@@ -0,0 +1,27 @@
+import { PokemonForm, Team } from '../../domain/entity/Team';
+
+// The team as every endpoint returns it. Kept apart from the handlers because the error
+// middleware returns it too, with a version conflict.
+export type TeamResponse = ReturnType<typeof toTeamResponse>;
+
+export function toTeamMemberResponse(member: PokemonForm) {
+  return {
+    form_id: member.formId,
+    terastal_type: member.terastalType,
+    notes: member.notes ?? null,
+  };
+}
+
+export function toTeamResponse(team: Team) {
+  return {
+    team_id: team.getTeamId(),
+    team_name: team.getTeamName(),
+    version: team.getVersion(),
+    pokemon: team.getPokemon().map((member) => member && toTeamMemberResponse(member)),
+    notes: team.getNotes(),
+    tags: team.getTags(),
+    folder: team.getFolder(),
+    shared_at: team.getSharedAt()?.toISOString() ?? null,
+    shared_format: team.getSharedFormat(),
+  };
+}
@@ -0,0 +1,128 @@
+import type { TeamReport } from '../TeamReportService';
+import { formatCoverageCell, formatPercents } from './markdownReport';
+
+function escapeHtml(value: string | number): string {
+  return `${value}`
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;');
+}
+
+function table(header: string[], rows: (string | number)[][]): string {
+  const head = header.map((cell) => `<th>${escapeHtml(cell)}</th>`).join('');
+  const body = rows
+    .map((cells) => `<tr>${cells.map((cell) => `<td>${escapeHtml(cell)}</td>`).join('')}</tr>`)
+    .join('');
+  return `<table><thead><tr>${head}</tr></thead><tbody>${body}</tbody></table>`;
+}
+
+function list(items: string[]): string {
+  return `<ul>${items.map((item) => `<li>${escapeHtml(item)}</li>`).join('')}</ul>`;
+}
+
+// Standalone page with inline styles so the downloaded file needs nothing else
+export function renderHtmlReport(report: TeamReport): string {
+  const { analysis } = report;
+  const names = analysis.members.map((member) => member.name);
+  const title = `Team report — ${report.formatName}`;
+  const sections: string[] = [
+    `<h1>${escapeHtml(title)}</h1>`,
+    `<p>Level ${report.level}. ${escapeHtml(names.join(' / '))}</p>`,
+  ];
+
+  sections.push('<h2>Archetype</h2>');
+  sections.push(
+    analysis.archetypes.length === 0
+      ? '<p>No clear archetype.</p>'
+      : analysis.archetypes
+          .map(
+            (score) =>
+              `<h3>${escapeHtml(score.archetype)} (${Math.round(score.confidence * 100)}%)</h3>` +
+              list(score.features)
+          )
+          .join('')
+  );
+
+  sections.push('<h2>Defensive coverage</h2>');
+  sections.push(
+    table(
+      ['Type', ...names, 'Weak', 'Resist', 'Immune'],
+      analysis.defensiveCoverage.map((coverage) => [
+        coverage.type,
+        ...coverage.multipliers.map((multiplier, slot) =>
+          formatCoverageCell(multiplier, coverage.sources[slot])
+        ),
+        coverage.weak,
+        coverage.resist,
+        coverage.immune,
+      ])
+    )
+  );
+  if (analysis.defensiveCoverage.some((coverage) => coverage.sources.includes('ability'))) {
+    sections.push("<p>* changed by the Pokémon's ability</p>");
+  }
+
+  const unhit = analysis.offensiveCoverage.filter((coverage) => coverage.best <= 1);
+  sections.push('<h2>Offensive coverage</h2>');
+  sections.push(
+    unhit.length === 0
+      ? '<p>Every type is hit super effectively.</p>'
+      : `<p>Not hit super effectively: ${escapeHtml(unhit.map((c) => c.type).join(', '))}</p>`
+  );
+
+  sections.push('<h2>Speed tiers</h2>');
+  sections.push(
+    table(
+      ['Pokémon', 'Base', 'Speed'],
+      analysis.speedTiers.map((tier) => [tier.name, tier.baseSpeed, tier.speed])
+    )
+  );
+
+  sections.push('<h2>Entry hazards</h2>');
+  sections.push(
+    table(
+      ['Pokémon', 'Stealth Rock', 'Spikes (1/2/3)', 'Toxic Spikes (1/2)'],
+      analysis.hazards.map((hazard) => [
+        hazard.name,
+        `${hazard.stealthRock}%`,
+        formatPercents(hazard.spikes),
+        hazard.absorbsToxicSpikes ? 'absorbs' : formatPercents(hazard.toxicSpikes),
+      ])
+    )
+  );
+
+  if (analysis.warnings.length > 0) {
+    sections.push('<h2>Warnings</h2>');
+    sections.push(
+      list(
+        analysis.warnings.map(
+          (warning) =>
+            `${warning.message} (${warning.slots.map((slot) => names[slot]).join(', ')})`
+        )
+      )
+    );
+  }
+
+  sections.push(`<h2>Threats${report.period ? ` (usage ${escapeHtml(report.period)})` : ''}</h2>`);
+  if (report.threats.length === 0) {
+    sections.push(`<p>${report.period ? 'No threats found.' : 'No usage data available.'}</p>`);
+  }
+  for (const threat of report.threats) {
+    sections.push(`<h3>${escapeHtml(threat.name)} — ${threat.usage.toFixed(2)}% usage</h3>`);
+    sections.push(list(threat.reasons.map((reason) => reason.message)));
+  }
+
+  return [
+    '<!DOCTYPE html>',
+    '<html lang="en">',
+    '<head>',
+    '<meta charset="utf-8">',
+    `<title>${escapeHtml(title)}</title>`,
+    '<style>body{font-family:sans-serif;max-width:960px;margin:auto}' +
+      'table{border-collapse:collapse}th,td{border:1px solid #ccc;padding:2px 8px}</style>',
+    '</head>',
+    `<body>${sections.join('\n')}</body>`,
+    '</html>',
+  ].join('\n');
+}
@@ -0,0 +1,123 @@
+import type { MatchupSource } from '../../domain/service/DefensiveProfile';
+import type { TeamReport } from '../TeamReportService';
+
+export function formatMultiplier(multiplier: number): string {
+  if (multiplier === 0.25) {
+    return '¼';
+  }
+  if (multiplier === 0.5) {
+    return '½';
+  }
+  return `${multiplier}`;
+}
+
+// Multipliers changed by an ability are marked, e.g. "0*" for Levitate against Ground
+export function formatCoverageCell(multiplier: number, source: MatchupSource): string {
+  return `${formatMultiplier(multiplier)}${source === 'ability' ? '*' : ''}`;
+}
+
+export function formatPercents(percents: number[]): string {
+  return percents.map((percent) => `${percent}%`).join(' / ');
+}
+
+function row(cells: (string | number)[]): string {
+  return `| ${cells.join(' | ')} |`;
+}
+
+function table(header: string[], rows: (string | number)[][]): string[] {
+  return [row(header), row(header.map(() => '---')), ...rows.map(row)];
+}
+
+// Forum-friendly Markdown; tables render on GitHub, Reddit and Smogon's forums
+export function renderMarkdownReport(report: TeamReport): string {
+  const { analysis } = report;
+  const names = analysis.members.map((member) => member.name);
+  const lines: string[] = [
+    `# Team report — ${report.formatName}`,
+    '',
+    `Level ${report.level}. ${names.join(' / ')}`,
+    '',
+  ];
+
+  lines.push('## Archetype', '');
+  if (analysis.archetypes.length === 0) {
+    lines.push('No clear archetype.', '');
+  }
+  for (const score of analysis.archetypes) {
+    lines.push(`- **${score.archetype}** (${Math.round(score.confidence * 100)}%)`);
+    lines.push(...score.features.map((feature) => `  - ${feature}`));
+  }
+  lines.push('');
+
+  lines.push('## Defensive coverage', '');
+  lines.push(
+    ...table(
+      ['Type', ...names, 'Weak', 'Resist', 'Immune'],
+      analysis.defensiveCoverage.map((coverage) => [
+        coverage.type,
+        ...coverage.multipliers.map((multiplier, slot) =>
+          formatCoverageCell(multiplier, coverage.sources[slot])
+        ),
+        coverage.weak,
+        coverage.resist,
+        coverage.immune,
+      ])
+    ),
+    ''
+  );
+  if (analysis.defensiveCoverage.some((coverage) => coverage.sources.includes('ability'))) {
+    lines.push("\\* changed by the Pokémon's ability", '');
+  }
+
+  const unhit = analysis.offensiveCoverage.filter((coverage) => coverage.best <= 1);
+  lines.push('## Offensive coverage', '');
+  lines.push(
+    unhit.length === 0
+      ? 'Every type is hit super effectively.'
+      : `Not hit super effectively: ${unhit.map((coverage) => coverage.type).join(', ')}`,
+    ''
+  );
+
+  lines.push('## Speed tiers', '');
+  lines.push(
+    ...table(
+      ['Pokémon', 'Base', 'Speed'],
+      analysis.speedTiers.map((tier) => [tier.name, tier.baseSpeed, tier.speed])
+    ),
+    ''
+  );
+
+  lines.push('## Entry hazards', '');
+  lines.push(
+    ...table(
+      ['Pokémon', 'Stealth Rock', 'Spikes (1/2/3)', 'Toxic Spikes (1/2)'],
+      analysis.hazards.map((hazard) => [
+        hazard.name,
+        `${hazard.stealthRock}%`,
+        formatPercents(hazard.spikes),
+        hazard.absorbsToxicSpikes ? 'absorbs' : formatPercents(hazard.toxicSpikes),
+      ])
+    ),
+    ''
+  );
+
+  if (analysis.warnings.length > 0) {
+    lines.push('## Warnings', '');
+    for (const warning of analysis.warnings) {
+      const members = warning.slots.map((slot) => names[slot]).join(', ');
+      lines.push(`- ${warning.message} (${members})`);
+    }
+    lines.push('');
+  }
+
+  lines.push(`## Threats${report.period ? ` (usage ${report.period})` : ''}`, '');
+  if (report.threats.length === 0) {
+    lines.push(report.period ? 'No threats found.' : 'No usage data available.', '');
+  }
+  for (const threat of report.threats) {
+    lines.push(`### ${threat.name} — ${threat.usage.toFixed(2)}% usage`, '');
+    lines.push(...threat.reasons.map((reason) => `- ${reason.message}`), '');
+  }
+
+  return lines.join('\n');
+}
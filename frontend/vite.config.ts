@@ -8,6 +8,14 @@ export default defineConfig({
   resolve: {
     alias: {
       '$lib': path.resolve(__dirname, './src/lib'),
+      // Backend domain library (type chart, stat and damage calculation) for offline calcs
+      '$domain': path.resolve(__dirname, '../backend-hono/src/domain/index.ts'),
+    }
+  },
+  server: {
+    fs: {
+      // $domain lives outside the frontend root
+      allow: ['..'],
     }
   }
 })